@@ -100,7 +100,7 @@ fn download_and_extract_pdfium(
 
     // Download the file.
     let output = Command::new("curl")
-        .args(&["-L", "-o", &lib_dir.join(filename).to_str().unwrap(), url])
+        .args(["-L", "-o", lib_dir.join(filename).to_str().unwrap(), url])
         .output()?;
 
     if !output.status.success() {
@@ -117,9 +117,9 @@ fn download_and_extract_pdfium(
 
     // Extract the archive.
     let output = Command::new("tar")
-        .args(&[
+        .args([
             "-xzf",
-            &lib_dir.join(filename).to_str().unwrap(),
+            lib_dir.join(filename).to_str().unwrap(),
             "-C",
             lib_dir.to_str().unwrap(),
         ])
@@ -0,0 +1,329 @@
+//! # Associations Module
+//!
+//! This module handles the association analysis between pairs of categorical features with
+//! contingency tables (cross-tabulations) and Cramér's V.
+//!
+//! The pairs can be selected explicitly. Otherwise every pair of low-cardinality categorical
+//! features (string, categorical, enum, and boolean features with at most `MAX_CATEGORIES`
+//! distinct values) is considered, and the `MAX_AUTO_PAIRS` most associated pairs are kept.
+//!
+//! Missing values form their own category. For explicitly selected pairs with more than
+//! `MAX_CATEGORIES` categories, the least frequent categories are lumped into an `(other)`
+//! category so the tables stay readable.
+
+use indexmap::IndexMap;
+use polars::prelude::*;
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Maximum number of categories per feature shown in a contingency table.
+pub const MAX_CATEGORIES: usize = 10;
+/// Maximum number of automatically selected pairs.
+pub const MAX_AUTO_PAIRS: usize = 6;
+/// The category label for missing values.
+pub const MISSING_LABEL: &str = "(missing)";
+/// The category label for the lumped least frequent categories.
+pub const OTHER_LABEL: &str = "(other)";
+
+/// The error types for the associations module.
+#[derive(Error, Debug)]
+pub enum AssociationError {
+    /// Occurs when Polars fails to complete an operation.
+    #[error("Polars error: {0}")]
+    Polars(#[from] PolarsError),
+
+    /// Occurs when a selected column isn't in the dataset.
+    #[error("Cross-tabulation column not found in the dataset: {0}")]
+    UnknownColumn(String),
+}
+
+/// A pair of columns to cross-tabulate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnPair {
+    /// The column shown on the rows of the contingency table.
+    pub rows: String,
+    /// The column shown on the columns of the contingency table.
+    pub columns: String,
+}
+
+impl FromStr for ColumnPair {
+    type Err = String;
+
+    /// Parses `<rows>:<columns>`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once(':') {
+            Some((rows, columns)) if !rows.is_empty() && !columns.is_empty() => Ok(ColumnPair {
+                rows: rows.to_owned(),
+                columns: columns.to_owned(),
+            }),
+            _ => Err(format!(
+                "invalid column pair '{}', expected <column>:<column>",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for ColumnPair {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.rows, self.columns)
+    }
+}
+
+/// A contingency table between two categorical features.
+#[derive(Debug, Clone)]
+pub struct ContingencyTable {
+    /// The cross-tabulated columns.
+    pub pair: ColumnPair,
+    /// The category labels of the rows feature, sorted by frequency.
+    pub row_labels: Vec<String>,
+    /// The category labels of the columns feature, sorted by frequency.
+    pub column_labels: Vec<String>,
+    /// The row counts, indexed by `[row][column]`.
+    pub counts: Vec<Vec<u64>>,
+    /// Cramér's V association between the features, from 0 (independent) to 1.
+    pub cramers_v: f64,
+}
+
+/// Struct to hold the association analysis results for a dataset.
+#[derive(Debug, Default)]
+pub struct AssociationAnalysis {
+    /// The contingency tables, in the selected order or sorted by Cramér's V for automatically
+    /// selected pairs.
+    pub tables: Vec<ContingencyTable>,
+}
+
+impl AssociationAnalysis {
+    /// Constructor for the AssociationAnalysis struct.
+    ///
+    /// ### Parameters
+    ///
+    /// - `lazy_df`: Reference to the LazyFrame.
+    /// - `schema`: Reference to the lazy frame's schema.
+    /// - `pairs`: The pairs to cross-tabulate. If empty, the most associated pairs of
+    ///   low-cardinality categorical features are selected.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<Self, AssociationError>`: A new AssociationAnalysis instance or an error.
+    pub fn new(
+        lazy_df: &LazyFrame,
+        schema: &Schema,
+        pairs: &[ColumnPair],
+    ) -> Result<Self, AssociationError> {
+        if let Some(unknown) = pairs
+            .iter()
+            .flat_map(|pair| [&pair.rows, &pair.columns])
+            .find(|column| schema.get(column).is_none())
+        {
+            return Err(AssociationError::UnknownColumn(unknown.clone()));
+        }
+
+        let (columns, candidate_pairs) = if pairs.is_empty() {
+            let columns = low_cardinality_columns(lazy_df, schema)?;
+            let candidate_pairs = columns
+                .iter()
+                .enumerate()
+                .flat_map(|(i, rows)| {
+                    columns[i + 1..].iter().map(|columns| ColumnPair {
+                        rows: rows.clone(),
+                        columns: columns.clone(),
+                    })
+                })
+                .collect();
+            (columns, candidate_pairs)
+        } else {
+            let mut columns: Vec<String> = Vec::new();
+            for column in pairs.iter().flat_map(|pair| [&pair.rows, &pair.columns]) {
+                if !columns.contains(column) {
+                    columns.push(column.clone());
+                }
+            }
+            (columns, pairs.to_vec())
+        };
+        if candidate_pairs.is_empty() {
+            return Ok(Self::default());
+        }
+
+        let df = lazy_df
+            .clone()
+            .select(
+                columns
+                    .iter()
+                    .map(|name| col(name).cast(DataType::String))
+                    .collect::<Vec<Expr>>(),
+            )
+            .collect()?;
+        let mut categories: HashMap<&str, Vec<String>> = HashMap::new();
+        for name in &columns {
+            categories.insert(name, lumped_categories(df.column(name)?)?);
+        }
+
+        let mut tables: Vec<ContingencyTable> = candidate_pairs
+            .into_iter()
+            .map(|pair| {
+                contingency_table(
+                    &categories[pair.rows.as_str()],
+                    &categories[pair.columns.as_str()],
+                    pair,
+                )
+            })
+            .collect();
+        if pairs.is_empty() {
+            tables.sort_by(|a, b| b.cramers_v.total_cmp(&a.cramers_v));
+            tables.truncate(MAX_AUTO_PAIRS);
+        }
+
+        Ok(Self { tables })
+    }
+}
+
+/// Gets the categorical features with at most `MAX_CATEGORIES` distinct values (including
+/// missing values).
+fn low_cardinality_columns(
+    lazy_df: &LazyFrame,
+    schema: &Schema,
+) -> Result<Vec<String>, AssociationError> {
+    let categorical_columns: Vec<&str> = schema
+        .iter()
+        .filter(|(_, dtype)| {
+            matches!(
+                dtype,
+                DataType::String
+                    | DataType::Boolean
+                    | DataType::Categorical(_, _)
+                    | DataType::Enum(_, _)
+            )
+        })
+        .map(|(name, _)| name.as_str())
+        .collect();
+    if categorical_columns.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let df = lazy_df
+        .clone()
+        .select(
+            categorical_columns
+                .iter()
+                .map(|name| col(name).n_unique().cast(DataType::UInt64))
+                .collect::<Vec<Expr>>(),
+        )
+        .collect()?;
+    let mut columns = Vec::new();
+    for name in categorical_columns {
+        let n_unique = df.column(name)?.u64()?.get(0).unwrap_or(0);
+        if (2..=MAX_CATEGORIES as u64).contains(&n_unique) {
+            columns.push(name.to_owned());
+        }
+    }
+    Ok(columns)
+}
+
+/// Gets the category label of each row, with missing values labeled `MISSING_LABEL` and the
+/// categories beyond the `MAX_CATEGORIES` most frequent lumped into `OTHER_LABEL`.
+fn lumped_categories(series: &Series) -> Result<Vec<String>, AssociationError> {
+    let labels: Vec<String> = series
+        .str()?
+        .into_iter()
+        .map(|value| value.unwrap_or(MISSING_LABEL).to_owned())
+        .collect();
+
+    let frequencies = category_frequencies(&labels);
+    if frequencies.len() <= MAX_CATEGORIES {
+        return Ok(labels);
+    }
+    let kept: Vec<&String> = frequencies.keys().take(MAX_CATEGORIES - 1).collect();
+    Ok(labels
+        .iter()
+        .map(|label| {
+            if kept.contains(&label) {
+                label.clone()
+            } else {
+                OTHER_LABEL.to_owned()
+            }
+        })
+        .collect())
+}
+
+/// Counts the categories, sorted from the most to the least frequent (ties by label).
+fn category_frequencies(labels: &[String]) -> IndexMap<String, u64> {
+    let mut frequencies: IndexMap<String, u64> = IndexMap::new();
+    for label in labels {
+        *frequencies.entry(label.clone()).or_default() += 1;
+    }
+    frequencies.sort_by(|label_a, count_a, label_b, count_b| {
+        count_b.cmp(count_a).then_with(|| label_a.cmp(label_b))
+    });
+    frequencies
+}
+
+/// Builds the contingency table between two categorical features.
+fn contingency_table(rows: &[String], columns: &[String], pair: ColumnPair) -> ContingencyTable {
+    let row_labels: Vec<String> = category_frequencies(rows).into_keys().collect();
+    let column_labels: Vec<String> = category_frequencies(columns).into_keys().collect();
+    let row_index: HashMap<&String, usize> = row_labels
+        .iter()
+        .enumerate()
+        .map(|(index, label)| (label, index))
+        .collect();
+    let column_index: HashMap<&String, usize> = column_labels
+        .iter()
+        .enumerate()
+        .map(|(index, label)| (label, index))
+        .collect();
+
+    let mut counts = vec![vec![0u64; column_labels.len()]; row_labels.len()];
+    for (row, column) in rows.iter().zip(columns) {
+        counts[row_index[row]][column_index[column]] += 1;
+    }
+    let cramers_v = cramers_v(&counts);
+
+    ContingencyTable {
+        pair,
+        row_labels,
+        column_labels,
+        counts,
+        cramers_v,
+    }
+}
+
+/// Computes Cramér's V from a contingency table, or 0 if either feature has a single category.
+fn cramers_v(counts: &[Vec<u64>]) -> f64 {
+    let n_rows = counts.len();
+    let n_columns = counts.first().map_or(0, |row| row.len());
+    let total: u64 = counts.iter().flatten().sum();
+    if n_rows < 2 || n_columns < 2 || total == 0 {
+        return 0.0;
+    }
+
+    let row_totals: Vec<f64> = counts
+        .iter()
+        .map(|row| row.iter().sum::<u64>() as f64)
+        .collect();
+    let column_totals: Vec<f64> = (0..n_columns)
+        .map(|column| counts.iter().map(|row| row[column]).sum::<u64>() as f64)
+        .collect();
+    let total = total as f64;
+
+    let chi_squared: f64 = counts
+        .iter()
+        .enumerate()
+        .flat_map(|(row, row_counts)| {
+            row_counts.iter().enumerate().map({
+                let row_total = row_totals[row];
+                let column_totals = &column_totals;
+                move |(column, count)| {
+                    let expected = row_total * column_totals[column] / total;
+                    (*count as f64 - expected).powi(2) / expected
+                }
+            })
+        })
+        .sum();
+
+    (chi_squared / (total * (n_rows.min(n_columns) - 1) as f64))
+        .sqrt()
+        .min(1.0)
+}
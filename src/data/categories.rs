@@ -0,0 +1,185 @@
+//! # Categories Module
+//!
+//! This module handles suggesting an "Other" grouping for categorical features with a long tail
+//! of rare levels. Rare levels carry little signal on their own, inflate one-hot encodings, and
+//! often show up in the test data without having been seen in training, so lumping them into a
+//! single level is a common preprocessing step.
+//!
+//! ## Approach
+//!
+//! For each string, categorical, or enum feature with at least `MIN_LEVELS` levels, the levels
+//! whose share of the non-missing values is below the rare threshold are the merge candidates. A
+//! merge is suggested when at least two levels are rare, and the suggestion records how many
+//! levels the grouping removes and how many values end up in "Other".
+//!
+//! Features whose levels are mostly unique (e.g. IDs or free text) are skipped, since grouping
+//! them would lump nearly every value into "Other".
+
+use indexmap::IndexMap;
+use polars::prelude::*;
+use thiserror::Error;
+
+/// The default share of the non-missing values below which a level is rare.
+pub const DEFAULT_RARE_THRESHOLD: f64 = 0.01;
+/// The minimum number of levels of a feature for a merge to be suggested.
+pub const MIN_LEVELS: usize = 10;
+/// The ratio of levels to non-missing values above which a feature is considered mostly unique.
+pub const MAX_LEVEL_RATIO: f64 = 0.5;
+/// The label of the merged level.
+pub const OTHER_LABEL: &str = "Other";
+
+/// The error types for the categories module.
+#[derive(Error, Debug)]
+pub enum CategoryError {
+    /// Occurs when Polars fails to complete an operation.
+    #[error("Polars error: {0}")]
+    Polars(#[from] PolarsError),
+}
+
+/// A suggestion to merge the rare levels of a categorical feature into "Other".
+#[derive(Debug, Clone)]
+pub struct CategoryMergeSuggestion {
+    /// Every level of the feature with its number of values, by descending count.
+    pub levels: Vec<(String, usize)>,
+    /// The number of frequent levels kept, the first `n_kept` of the levels.
+    pub n_kept: usize,
+}
+
+impl CategoryMergeSuggestion {
+    /// Gets the number of levels of the feature.
+    pub fn n_levels(&self) -> usize {
+        self.levels.len()
+    }
+
+    /// Gets the number of rare levels merged into "Other".
+    pub fn n_rare(&self) -> usize {
+        self.levels.len() - self.n_kept
+    }
+
+    /// Gets the number of levels after the merge, the kept levels and "Other".
+    pub fn n_merged_levels(&self) -> usize {
+        self.n_kept + 1
+    }
+
+    /// Gets the fraction of the levels the merge removes.
+    pub fn cardinality_reduction(&self) -> f64 {
+        1.0 - self.n_merged_levels() as f64 / self.n_levels() as f64
+    }
+
+    /// Gets the number of values merged into "Other".
+    pub fn n_other_values(&self) -> usize {
+        self.levels[self.n_kept..]
+            .iter()
+            .map(|(_, count)| count)
+            .sum()
+    }
+
+    /// Gets the share of the non-missing values merged into "Other".
+    pub fn other_share(&self) -> f64 {
+        let total: usize = self.levels.iter().map(|(_, count)| count).sum();
+        self.n_other_values() as f64 / total as f64
+    }
+
+    /// Gets the levels after the merge with their number of values, the kept levels followed by
+    /// "Other".
+    pub fn merged_levels(&self) -> Vec<(String, usize)> {
+        self.levels[..self.n_kept]
+            .iter()
+            .cloned()
+            .chain([(OTHER_LABEL.to_owned(), self.n_other_values())])
+            .collect()
+    }
+}
+
+/// Struct to hold the category merging suggestions for a dataset.
+#[derive(Debug, Default)]
+pub struct CategoryMergeAnalysis {
+    /// The suggestions, keyed by feature name. Features without a suggestion are omitted.
+    pub suggestions: IndexMap<String, CategoryMergeSuggestion>,
+    /// The share of the non-missing values below which a level is rare.
+    pub threshold: f64,
+}
+
+impl CategoryMergeAnalysis {
+    /// Constructor for the CategoryMergeAnalysis struct.
+    ///
+    /// ### Parameters
+    ///
+    /// - `lazy_df`: Reference to the LazyFrame.
+    /// - `schema`: Reference to the lazy frame's schema.
+    /// - `threshold`: The share of the non-missing values below which a level is rare.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<Self, CategoryError>`: A new CategoryMergeAnalysis instance or an error.
+    pub fn new(
+        lazy_df: &LazyFrame,
+        schema: &Schema,
+        threshold: f64,
+    ) -> Result<Self, CategoryError> {
+        let categorical_columns: Vec<&str> = schema
+            .iter()
+            .filter(|(_, dtype)| {
+                matches!(
+                    dtype,
+                    DataType::String | DataType::Categorical(_, _) | DataType::Enum(_, _)
+                )
+            })
+            .map(|(name, _)| name.as_str())
+            .collect();
+
+        let mut suggestions = IndexMap::new();
+        for name in categorical_columns {
+            let counts = lazy_df
+                .clone()
+                .select([col(name).cast(DataType::String)])
+                .drop_nulls(None)
+                .group_by([col(name)])
+                .agg([len().cast(DataType::UInt64).alias("count")])
+                .collect()?;
+            let mut levels: Vec<(String, usize)> = counts
+                .column(name)?
+                .str()?
+                .into_iter()
+                .zip(counts.column("count")?.u64()?)
+                .filter_map(|(level, count)| Some((level?.to_owned(), count? as usize)))
+                .collect();
+            // Ties are broken by level, since the group order varies from run to run.
+            levels.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+            if let Some(suggestion) = suggest_merge(levels, threshold) {
+                suggestions.insert(name.to_owned(), suggestion);
+            }
+        }
+
+        Ok(Self {
+            suggestions,
+            threshold,
+        })
+    }
+}
+
+/// Suggests merging the rare levels of a feature.
+///
+/// ### Parameters
+///
+/// - `levels`: The levels of the feature with their number of values, by descending count.
+/// - `threshold`: The share of the non-missing values below which a level is rare.
+///
+/// ### Returns
+///
+/// - `Option<CategoryMergeSuggestion>`: The suggestion, or None if the feature has too few
+///   levels, is mostly unique, or has fewer than two rare levels.
+pub fn suggest_merge(
+    levels: Vec<(String, usize)>,
+    threshold: f64,
+) -> Option<CategoryMergeSuggestion> {
+    let total: usize = levels.iter().map(|(_, count)| count).sum();
+    if levels.len() < MIN_LEVELS || levels.len() as f64 > MAX_LEVEL_RATIO * total as f64 {
+        return None;
+    }
+    let n_kept = levels
+        .iter()
+        .take_while(|(_, count)| *count as f64 / total as f64 >= threshold)
+        .count();
+    (levels.len() - n_kept >= 2).then_some(CategoryMergeSuggestion { levels, n_kept })
+}
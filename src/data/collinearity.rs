@@ -0,0 +1,167 @@
+//! # Collinearity Module
+//!
+//! This module handles detecting correlated predictors with the variance inflation factor (VIF)
+//! of each numerical feature. The VIF of a feature is `1 / (1 - R^2)`, where `R^2` is from
+//! regressing the feature on all the other numerical features, and is read off the diagonal of
+//! the inverse of the correlation matrix.
+//!
+//! Rows with a missing value in any numerical feature are dropped, and constant features are
+//! skipped since their VIF is undefined. When the features are perfectly collinear the
+//! correlation matrix is singular and every VIF is reported as infinite.
+
+use indexmap::IndexMap;
+use polars::prelude::*;
+use thiserror::Error;
+
+/// The default VIF above which a feature is flagged as collinear.
+pub const DEFAULT_VIF_THRESHOLD: f64 = 10.0;
+/// Pivot magnitude below which the correlation matrix is considered singular.
+const SINGULAR_TOLERANCE: f64 = 1e-10;
+
+/// The error types for the collinearity module.
+#[derive(Error, Debug)]
+pub enum CollinearityError {
+    /// Occurs when Polars fails to complete an operation.
+    #[error("Polars error: {0}")]
+    Polars(#[from] PolarsError),
+}
+
+/// Struct to hold the collinearity analysis results for a dataset.
+#[derive(Debug, Default)]
+pub struct CollinearityAnalysis {
+    /// The variance inflation factor, keyed by feature name. Empty if there are fewer than two
+    /// non-constant numerical features or not enough complete rows.
+    pub vif: IndexMap<String, f64>,
+    /// The VIF above which a feature is flagged.
+    pub threshold: f64,
+}
+
+impl CollinearityAnalysis {
+    /// Constructor for the CollinearityAnalysis struct.
+    ///
+    /// ### Parameters
+    ///
+    /// - `lazy_df`: Reference to the LazyFrame.
+    /// - `schema`: Reference to the lazy frame's schema.
+    /// - `threshold`: The VIF above which a feature is flagged.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<Self, CollinearityError>`: A new CollinearityAnalysis instance or an error.
+    pub fn new(
+        lazy_df: &LazyFrame,
+        schema: &Schema,
+        threshold: f64,
+    ) -> Result<Self, CollinearityError> {
+        let numeric_columns: Vec<&str> = schema
+            .iter()
+            .filter(|(_, dtype)| dtype.is_numeric() || dtype.is_decimal())
+            .map(|(name, _)| name.as_str())
+            .collect();
+
+        let df = lazy_df
+            .clone()
+            .select(
+                numeric_columns
+                    .iter()
+                    .map(|name| col(name).cast(DataType::Float64))
+                    .collect::<Vec<Expr>>(),
+            )
+            .drop_nulls(None)
+            .collect()?;
+
+        let mut names: Vec<&str> = Vec::with_capacity(numeric_columns.len());
+        let mut standardized: Vec<Vec<f64>> = Vec::with_capacity(numeric_columns.len());
+        for name in numeric_columns {
+            let values: Vec<f64> = df.column(name)?.f64()?.into_no_null_iter().collect();
+            if let Some(values) = standardize(&values) {
+                names.push(name);
+                standardized.push(values);
+            }
+        }
+
+        let vif = if names.len() < 2 || df.height() <= names.len() {
+            IndexMap::new()
+        } else {
+            let correlations = correlation_matrix(&standardized);
+            let diagonal = match invert(correlations) {
+                Some(inverse) => (0..names.len()).map(|i| inverse[i][i]).collect(),
+                None => vec![f64::INFINITY; names.len()],
+            };
+            names
+                .iter()
+                .zip(diagonal)
+                .map(|(name, vif)| (name.to_string(), vif))
+                .collect()
+        };
+
+        Ok(Self { vif, threshold })
+    }
+
+    /// Gets the features with a VIF above the threshold.
+    pub fn high_vif(&self) -> impl Iterator<Item = (&String, &f64)> {
+        self.vif.iter().filter(|(_, vif)| **vif > self.threshold)
+    }
+}
+
+/// Standardizes the values to zero mean and unit variance, or None if the values are constant.
+pub(crate) fn standardize(values: &[f64]) -> Option<Vec<f64>> {
+    let n = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / n;
+    let std_dev = (values.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n).sqrt();
+    if !std_dev.is_finite() || std_dev <= f64::EPSILON * mean.abs().max(1.0) {
+        return None;
+    }
+    Some(values.iter().map(|x| (x - mean) / std_dev).collect())
+}
+
+/// Computes the Pearson correlation matrix of standardized columns.
+pub(crate) fn correlation_matrix(columns: &[Vec<f64>]) -> Vec<Vec<f64>> {
+    let n = columns.first().map_or(0, |column| column.len()) as f64;
+    columns
+        .iter()
+        .map(|x| {
+            columns
+                .iter()
+                .map(|y| x.iter().zip(y).map(|(x, y)| x * y).sum::<f64>() / n)
+                .collect()
+        })
+        .collect()
+}
+
+/// Inverts a square matrix with Gauss-Jordan elimination and partial pivoting, or None if the
+/// matrix is singular.
+fn invert(mut matrix: Vec<Vec<f64>>) -> Option<Vec<Vec<f64>>> {
+    let size = matrix.len();
+    let mut inverse: Vec<Vec<f64>> = (0..size)
+        .map(|i| (0..size).map(|j| if i == j { 1.0 } else { 0.0 }).collect())
+        .collect();
+
+    for pivot in 0..size {
+        let best = (pivot..size)
+            .max_by(|a, b| matrix[*a][pivot].abs().total_cmp(&matrix[*b][pivot].abs()))?;
+        if matrix[best][pivot].abs() < SINGULAR_TOLERANCE {
+            return None;
+        }
+        matrix.swap(pivot, best);
+        inverse.swap(pivot, best);
+
+        let scale = matrix[pivot][pivot];
+        for j in 0..size {
+            matrix[pivot][j] /= scale;
+            inverse[pivot][j] /= scale;
+        }
+        for row in 0..size {
+            if row == pivot {
+                continue;
+            }
+            let factor = matrix[row][pivot];
+            for j in 0..size {
+                matrix[row][j] -= factor * matrix[pivot][j];
+                inverse[row][j] -= factor * inverse[pivot][j];
+            }
+        }
+    }
+
+    Some(inverse)
+}
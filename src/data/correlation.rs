@@ -0,0 +1,219 @@
+//! # Correlation Module
+//!
+//! This module handles the pairwise correlations between the numerical features of a dataset,
+//! with both the Pearson correlation (linear dependence) and the Spearman rank correlation (any
+//! monotonic dependence, and robust to outliers). A pair is flagged as highly correlated when
+//! either absolute correlation reaches the threshold, since one of the two features is then
+//! largely redundant.
+//!
+//! Each pair is computed over the rows where both features are present (pairwise deletion), so a
+//! sparse feature doesn't shrink the rows of every other pair. Constant features are left out
+//! since their correlation is undefined, and only the first `MAX_CORRELATION_FEATURES` numerical
+//! features are correlated, past which the heatmaps are unreadable.
+
+use super::row_order::ranks;
+use polars::prelude::*;
+use thiserror::Error;
+
+/// The default absolute correlation from which a pair of features is flagged.
+pub const DEFAULT_CORRELATION_THRESHOLD: f64 = 0.8;
+/// The maximum number of numerical features correlated.
+pub const MAX_CORRELATION_FEATURES: usize = 50;
+/// The minimum number of rows with both features present to correlate a pair.
+const MIN_PAIR_ROWS: usize = 3;
+
+/// The error types for the correlation module.
+#[derive(Error, Debug)]
+pub enum CorrelationError {
+    /// Occurs when Polars fails to complete an operation.
+    #[error("Polars error: {0}")]
+    Polars(#[from] PolarsError),
+}
+
+/// A pair of features with their correlations.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CorrelatedPair<'a> {
+    /// The first feature, in schema order.
+    pub first: &'a str,
+    /// The second feature, in schema order.
+    pub second: &'a str,
+    /// The Pearson correlation, NaN if undefined.
+    pub pearson: f64,
+    /// The Spearman rank correlation, NaN if undefined.
+    pub spearman: f64,
+}
+
+impl CorrelatedPair<'_> {
+    /// Gets the larger of the absolute Pearson and Spearman correlations, 0 if both are undefined.
+    pub fn strength(&self) -> f64 {
+        [self.pearson, self.spearman]
+            .into_iter()
+            .filter(|correlation| correlation.is_finite())
+            .map(f64::abs)
+            .fold(0.0, f64::max)
+    }
+}
+
+/// Struct to hold the correlation analysis results for a dataset.
+#[derive(Debug, Default)]
+pub struct CorrelationAnalysis {
+    /// The correlated features, in schema order.
+    pub features: Vec<String>,
+    /// The Pearson correlation matrix, in feature order. Undefined correlations (too few rows
+    /// with both features present) are NaN.
+    pub pearson: Vec<Vec<f64>>,
+    /// The Spearman rank correlation matrix, in feature order.
+    pub spearman: Vec<Vec<f64>>,
+    /// The absolute correlation from which a pair is flagged.
+    pub threshold: f64,
+    /// The number of numerical features left out past `MAX_CORRELATION_FEATURES`.
+    pub n_skipped: usize,
+}
+
+impl CorrelationAnalysis {
+    /// Constructor for the CorrelationAnalysis struct.
+    ///
+    /// ### Parameters
+    ///
+    /// - `lazy_df`: Reference to the LazyFrame.
+    /// - `schema`: Reference to the lazy frame's schema.
+    /// - `threshold`: The absolute correlation from which a pair is flagged.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<Self, CorrelationError>`: A new CorrelationAnalysis instance or an error.
+    pub fn new(
+        lazy_df: &LazyFrame,
+        schema: &Schema,
+        threshold: f64,
+    ) -> Result<Self, CorrelationError> {
+        let numeric_columns: Vec<&str> = schema
+            .iter()
+            .filter(|(_, dtype)| dtype.is_numeric() || dtype.is_decimal())
+            .map(|(name, _)| name.as_str())
+            .collect();
+        let n_skipped = numeric_columns
+            .len()
+            .saturating_sub(MAX_CORRELATION_FEATURES);
+        let numeric_columns = &numeric_columns[..numeric_columns.len() - n_skipped];
+
+        let df = lazy_df
+            .clone()
+            .select(
+                numeric_columns
+                    .iter()
+                    .map(|name| col(name).cast(DataType::Float64))
+                    .collect::<Vec<Expr>>(),
+            )
+            .collect()?;
+
+        let mut features = Vec::with_capacity(numeric_columns.len());
+        let mut columns: Vec<Vec<Option<f64>>> = Vec::with_capacity(numeric_columns.len());
+        for name in numeric_columns {
+            let values: Vec<Option<f64>> = df
+                .column(name)?
+                .f64()?
+                .into_iter()
+                .map(|value| value.filter(|value| value.is_finite()))
+                .collect();
+            let mut present = values.iter().flatten();
+            let is_constant = match present.next() {
+                Some(first) => present.all(|value| value == first),
+                None => true,
+            };
+            if !is_constant {
+                features.push(name.to_string());
+                columns.push(values);
+            }
+        }
+
+        // Features without missing values share their ranks across all their pairs.
+        let full_ranks: Vec<Option<Vec<f64>>> = columns
+            .iter()
+            .map(|values| {
+                values
+                    .iter()
+                    .copied()
+                    .collect::<Option<Vec<f64>>>()
+                    .map(|values| ranks(&values))
+            })
+            .collect();
+
+        let n_features = features.len();
+        let mut pearson = vec![vec![1.0; n_features]; n_features];
+        let mut spearman = vec![vec![1.0; n_features]; n_features];
+        for i in 0..n_features {
+            for j in (i + 1)..n_features {
+                let (x, y): (Vec<f64>, Vec<f64>) = columns[i]
+                    .iter()
+                    .zip(&columns[j])
+                    .filter_map(|(x, y)| x.zip(*y))
+                    .unzip();
+                let pearson_correlation = pearson_coefficient(&x, &y);
+                let spearman_correlation = match (&full_ranks[i], &full_ranks[j]) {
+                    (Some(x_ranks), Some(y_ranks)) => pearson_coefficient(x_ranks, y_ranks),
+                    _ => pearson_coefficient(&ranks(&x), &ranks(&y)),
+                };
+                pearson[i][j] = pearson_correlation;
+                pearson[j][i] = pearson_correlation;
+                spearman[i][j] = spearman_correlation;
+                spearman[j][i] = spearman_correlation;
+            }
+        }
+
+        Ok(Self {
+            features,
+            pearson,
+            spearman,
+            threshold,
+            n_skipped,
+        })
+    }
+
+    /// Gets every pair of features, sorted by descending strength.
+    pub fn pairs(&self) -> Vec<CorrelatedPair<'_>> {
+        let mut pairs = Vec::new();
+        for (i, first) in self.features.iter().enumerate() {
+            for (j, second) in self.features.iter().enumerate().skip(i + 1) {
+                pairs.push(CorrelatedPair {
+                    first,
+                    second,
+                    pearson: self.pearson[i][j],
+                    spearman: self.spearman[i][j],
+                });
+            }
+        }
+        pairs.sort_by(|a, b| b.strength().total_cmp(&a.strength()));
+        pairs
+    }
+
+    /// Gets the pairs of features whose Pearson or Spearman absolute correlation is at least the
+    /// threshold, sorted by descending strength.
+    pub fn high_pairs(&self) -> Vec<CorrelatedPair<'_>> {
+        self.pairs()
+            .into_iter()
+            .filter(|pair| pair.strength() >= self.threshold)
+            .collect()
+    }
+}
+
+/// Computes the Pearson correlation of two equally long samples, or NaN if there are fewer than
+/// `MIN_PAIR_ROWS` values or either sample is constant.
+fn pearson_coefficient(x: &[f64], y: &[f64]) -> f64 {
+    if x.len() < MIN_PAIR_ROWS {
+        return f64::NAN;
+    }
+    let n = x.len() as f64;
+    let (x_mean, y_mean) = (x.iter().sum::<f64>() / n, y.iter().sum::<f64>() / n);
+    let (mut covariance, mut x_variance, mut y_variance) = (0.0, 0.0, 0.0);
+    for (x, y) in x.iter().zip(y) {
+        let (x, y) = (x - x_mean, y - y_mean);
+        covariance += x * y;
+        x_variance += x * x;
+        y_variance += y * y;
+    }
+    if x_variance == 0.0 || y_variance == 0.0 {
+        return f64::NAN;
+    }
+    (covariance / (x_variance * y_variance).sqrt()).clamp(-1.0, 1.0)
+}
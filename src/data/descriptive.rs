@@ -2,13 +2,56 @@
 //!
 //! This module handles the calculation and storage of basic descriptive analysis results for datasets.
 //! It provides structures and methods to compute and access various statistical measures for numerical
-//! features in a given dataset.
+//! features in a given dataset, along with frequency statistics for categorical features and element-count statistics for
+//! list and array features.
 //!
 //! ## Design Overview
 //!
 //! The module is built around two main structures:
 //! - `DescriptiveAnalysis`: Represents the overall analysis results for a dataset.
 //! - `FeatureStats`: Holds the detailed statistical measures for all numerical features.
+//! - `CategoricalStats`: Holds the frequency statistics for a single categorical or enum feature.
+//! - `NestedStats`: Holds the element-count statistics and element summary for a single list or
+//!   array feature.
+//! - `HighCardinalityStats`: Holds the uniqueness, affix, length, and monotonicity summary for a
+//!   single ID-like string, categorical, or enum feature.
+//! - `BinaryStats`: Holds the byte-length statistics and content type guesses for a single binary
+//!   feature.
+//! - `DistinctStats`: Holds the distinct value count and uniqueness ratio of a single non-nested
+//!   feature.
+//!
+//! The content type of each non-empty binary value is guessed from its leading magic bytes (PNG,
+//! JPEG, GIF, WebP, PDF, gzip, zip, and zstd), falling back to UTF-8 text when the bytes are
+//! printable text and to unknown otherwise.
+//!
+//! String, categorical, and enum features with at least `MIN_HIGH_CARDINALITY` distinct values
+//! making up at least `HIGH_CARDINALITY_RATIO` of their values are treated as ID-like. Frequency
+//! tables are meaningless for them, so they're summarized by their uniqueness ratio, most common
+//! prefixes and suffixes, length distribution, and whether the values are monotonic in row order.
+//! Values are ordered by length first and then lexicographically, so counters without zero
+//! padding (e.g. `ID-9` and `ID-10`) are ordered numerically.
+//!
+//! Every non-nested feature (i.e. not a list, array, or struct) gets its number of distinct
+//! values, computed in a single pass. Features with a single distinct value are flagged as
+//! constant, and non-floating point features whose values are all present and distinct (over at
+//! least two rows) as likely keys.
+//!
+//! Each numerical feature also gets an equal width sparkline of `HISTOGRAM_BINS` bins from its
+//! minimum to its maximum, drawn in the report's column overview, and a normality test and
+//! distribution label (see the normality module).
+//!
+//! Independently of the plots, each numerical feature gets a `Histogram` with its bin edges and
+//! counts for machine-readable output. The bin width follows the Freedman-Diaconis rule
+//! (`2 * IQR / n^(1/3)`), which is robust to outliers, falling back to Sturges' rule
+//! (`log2(n) + 1` bins) when the interquartile range is zero. At most `MAX_HISTOGRAM_BINS` bins
+//! are used, so long tails don't blow up the bin count.
+//!
+//! Decimal features are treated as numerical features. Their values are cast to floats for the
+//! calculations and the location statistics are rounded back to the decimal scale of the column.
+//!
+//! The skewness, kurtosis, histogram, and normality test of a numerical feature are recovered per
+//! feature: when they fail (or panic), the failure is recorded and only that feature is left
+//! without them (see the recovery module).
 //!
 //! ### DescriptiveAnalysis Structure
 //!
@@ -65,10 +108,33 @@
 //! ```
 //! ```
 
+use crate::data::normality::NormalityStats;
+use crate::data::recovery::{
+    catch_failure, run_recoverable_column, AnalysisFailure, RecoverableAnalysis,
+};
 use indexmap::IndexMap;
 use polars::{lazy::dsl::*, prelude::*};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
 use thiserror::Error;
 
+/// The number of most frequent categories kept for each categorical feature.
+pub const TOP_CATEGORIES: usize = 5;
+/// The minimum number of distinct values for a feature to be treated as ID-like.
+pub const MIN_HIGH_CARDINALITY: u64 = 1000;
+/// The minimum ratio of distinct to non-null values for a feature to be treated as ID-like.
+pub const HIGH_CARDINALITY_RATIO: f64 = 0.9;
+/// The number of characters in the prefixes and suffixes of ID-like features.
+pub const AFFIX_LENGTH: usize = 3;
+/// The number of most common prefixes, suffixes, and lengths kept for each ID-like feature.
+pub const TOP_AFFIXES: usize = 3;
+/// The number of bins of the histogram of each numerical feature.
+pub const HISTOGRAM_BINS: usize = 20;
+/// The maximum number of bins of the Freedman-Diaconis or Sturges histogram of a numerical
+/// feature.
+pub const MAX_HISTOGRAM_BINS: usize = 100;
+
 /// The error types for the descriptive analysis module.
 #[derive(Error, Debug)]
 pub enum DescriptiveError {
@@ -94,7 +160,7 @@ pub enum DescriptiveError {
 }
 
 /// Struct to hold the overall descriptive analysis results.
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct DescriptiveAnalysis {
     /// The number of rows in the data.
     pub n_rows: u64,
@@ -106,6 +172,26 @@ pub struct DescriptiveAnalysis {
     pub column_map: IndexMap<String, usize>,
     /// Offset indices for each feature in the FeatureStats Dataframe.
     pub feature_indices: IndexMap<String, usize>,
+    /// The scale of each decimal feature, used to round its statistics.
+    pub decimal_scales: IndexMap<String, usize>,
+    /// The bin counts of the `HISTOGRAM_BINS` bin sparkline of each numerical feature.
+    pub sparklines: IndexMap<String, Vec<u64>>,
+    /// The Freedman-Diaconis or Sturges histogram of each numerical feature with finite values.
+    pub histograms: IndexMap<String, Histogram>,
+    /// The normality test result and distribution label of each numerical feature with at least
+    /// three values and some variance.
+    pub normality: IndexMap<String, NormalityStats>,
+    /// The frequency statistics for each categorical feature.
+    pub categorical_stats: IndexMap<String, CategoricalStats>,
+    /// The element-count statistics for each list and array feature.
+    pub nested_stats: IndexMap<String, NestedStats>,
+    /// The byte-length statistics and content type guesses for each binary feature.
+    pub binary_stats: IndexMap<String, BinaryStats>,
+    /// The summary for each ID-like string, categorical, and enum feature and each feature tagged
+    /// as an identifier.
+    pub high_cardinality_stats: IndexMap<String, HighCardinalityStats>,
+    /// The distinct value counts of each non-nested feature.
+    pub distinct_stats: IndexMap<String, DistinctStats>,
 }
 
 impl DescriptiveAnalysis {
@@ -115,58 +201,146 @@ impl DescriptiveAnalysis {
     ///
     /// - `lazy_df`: Reference to the LazyFrame.
     /// - `schema`: Reference to the lazy frame's schema.
+    /// - `id_columns`: The columns tagged as identifiers, which are summarized as high-cardinality
+    ///   features instead of getting numerical or frequency statistics.
+    /// - `failed_analyses`: The failures so far, which the features whose distribution shape
+    ///   failed are appended to.
     ///
     /// ### Returns
     ///
     /// - `Result<Self, DescriptiveError>`: A new DescriptiveAnalysis instance or an error.
-    pub fn new(lazy_df: &LazyFrame, schema: &Schema) -> Result<Self, DescriptiveError> {
+    pub fn new(
+        lazy_df: &LazyFrame,
+        schema: &Schema,
+        id_columns: &[String],
+        failed_analyses: &mut Vec<AnalysisFailure>,
+    ) -> Result<Self, DescriptiveError> {
         let n_cols = schema.len() as u64;
+        let is_id = |name: &str| id_columns.iter().any(|id| id == name);
         let numeric_columns: Vec<String> = schema
             .iter()
-            .filter(|(_, dtype)| dtype.is_numeric())
+            .filter(|(name, dtype)| !is_id(name) && (dtype.is_numeric() || dtype.is_decimal()))
             .map(|(name, _)| name.to_string())
             .collect();
 
-        let stats_df = lazy_df
-            .clone()
-            .select(
-                numeric_columns
+        // Decimal columns are profiled as floats, with the location statistics rounded back to the
+        // column's scale.
+        let decimal_scales: IndexMap<String, usize> = schema
+            .iter()
+            .filter_map(|(name, dtype)| match dtype {
+                DataType::Decimal(_, Some(scale)) => Some((name.to_string(), *scale)),
+                _ => None,
+            })
+            .collect();
+
+        let feature_column = |col_name: &str| match schema.get(col_name) {
+            Some(dtype) if dtype.is_decimal() => col(col_name).cast(DataType::Float64),
+            _ => col(col_name),
+        };
+        let moment_exprs = |col_name: &str| {
+            let column = feature_column(col_name);
+            vec![
+                column
+                    .clone()
+                    .skew(true)
+                    .alias(&format!("{}_skew_bias", col_name)),
+                column
+                    .clone()
+                    .skew(false)
+                    .alias(&format!("{}_skew_raw", col_name)),
+                column
+                    .kurtosis(true, false)
+                    .alias(&format!("{}_kurtosis", col_name)),
+            ]
+        };
+        // The features left out of `moment_columns` get missing moments.
+        let feature_exprs = |col_name: &String, moment_columns: &[&String]| {
+            let column = feature_column(col_name);
+            let scaled = |expr: Expr| match decimal_scales.get(col_name) {
+                Some(scale) => expr.round(*scale as u32),
+                None => expr,
+            };
+            let moments = if moment_columns.contains(&col_name) {
+                moment_exprs(col_name)
+            } else {
+                ["skew_bias", "skew_raw", "kurtosis"]
                     .iter()
-                    .flat_map(|col_name| {
-                        vec![
-                            lit(col_name.to_owned()).alias(&format!("{}", col_name)),
-                            col(col_name).min().alias(&format!("{}_min", col_name)),
-                            col(col_name).max().alias(&format!("{}_max", col_name)),
-                            col(col_name).mean().alias(&format!("{}_mean", col_name)),
-                            col(col_name)
-                                .median()
-                                .alias(&format!("{}_median", col_name)),
-                            col(col_name).std(1).alias(&format!("{}_std_dev", col_name)),
-                            col(col_name)
-                                .quantile(lit(0.25), QuantileInterpolOptions::Linear)
-                                .alias(&format!("{}_q1", col_name)),
-                            col(col_name)
-                                .quantile(lit(0.75), QuantileInterpolOptions::Linear)
-                                .alias(&format!("{}_q3", col_name)),
-                            (col(col_name).quantile(lit(0.75), QuantileInterpolOptions::Linear)
-                                - col(col_name)
-                                    .quantile(lit(0.75), QuantileInterpolOptions::Linear))
-                            .alias(&format!("{}_iqr", col_name)),
-                            col(col_name)
-                                .skew(true)
-                                .alias(&format!("{}_skew_bias", col_name)),
-                            col(col_name)
-                                .skew(false)
-                                .alias(&format!("{}_skew_raw", col_name)),
-                            col(col_name)
-                                .kurtosis(true, false)
-                                .alias(&format!("{}_kurtosis", col_name)),
-                            col(&col_name).count().alias(&format!("{}_count", col_name)),
-                        ]
+                    .map(|statistic| {
+                        lit(NULL)
+                            .cast(DataType::Float64)
+                            .alias(&format!("{}_{}", col_name, statistic))
                     })
-                    .collect::<Vec<Expr>>(),
-            )
-            .collect()?;
+                    .collect()
+            };
+            let mut exprs = vec![
+                lit(col_name.to_owned()).alias(col_name),
+                scaled(column.clone().min()).alias(&format!("{}_min", col_name)),
+                scaled(column.clone().max()).alias(&format!("{}_max", col_name)),
+                scaled(column.clone().mean()).alias(&format!("{}_mean", col_name)),
+                scaled(column.clone().median()).alias(&format!("{}_median", col_name)),
+                column
+                    .clone()
+                    .std(1)
+                    .alias(&format!("{}_std_dev", col_name)),
+                scaled(
+                    column
+                        .clone()
+                        .quantile(lit(0.25), QuantileInterpolOptions::Linear),
+                )
+                .alias(&format!("{}_q1", col_name)),
+                scaled(
+                    column
+                        .clone()
+                        .quantile(lit(0.75), QuantileInterpolOptions::Linear),
+                )
+                .alias(&format!("{}_q3", col_name)),
+                scaled(
+                    column
+                        .clone()
+                        .quantile(lit(0.75), QuantileInterpolOptions::Linear)
+                        - column
+                            .clone()
+                            .quantile(lit(0.25), QuantileInterpolOptions::Linear),
+                )
+                .alias(&format!("{}_iqr", col_name)),
+            ];
+            exprs.extend(moments);
+            exprs.push(column.count().alias(&format!("{}_count", col_name)));
+            exprs
+        };
+        let collect_stats = |moment_columns: &[&String]| {
+            lazy_df
+                .clone()
+                .select(
+                    numeric_columns
+                        .iter()
+                        .flat_map(|col_name| feature_exprs(col_name, moment_columns))
+                        .collect::<Vec<Expr>>(),
+                )
+                .collect()
+        };
+
+        let all_columns: Vec<&String> = numeric_columns.iter().collect();
+        let stats_df = match catch_failure(|| collect_stats(&all_columns)) {
+            Ok(stats_df) => stats_df,
+            // The moments are retried per feature, so the features they fail on only lose their
+            // own moments.
+            Err(_) => {
+                let moment_columns: Vec<&String> = numeric_columns
+                    .iter()
+                    .filter(|col_name| {
+                        run_recoverable_column(
+                            RecoverableAnalysis::DistributionShape,
+                            col_name,
+                            failed_analyses,
+                            || lazy_df.clone().select(moment_exprs(col_name)).collect(),
+                        )
+                        .is_some()
+                    })
+                    .collect();
+                collect_stats(&moment_columns)?
+            }
+        };
 
         let feature_stats = FeatureStats::new(stats_df)?;
 
@@ -192,13 +366,46 @@ impl DescriptiveAnalysis {
             .map(|(index, name)| (name.clone(), index * column_map.len()))
             .collect();
 
-        let n_rows = feature_stats.get_count(
-            numeric_columns
-                .get(0)
-                .ok_or_else(|| DescriptiveError::InvalidIndex(format!("0")))?,
-            &feature_indices,
-            &column_map,
-        )?;
+        // The row count is taken from the frame rather than a numerical feature, since there may
+        // be none (e.g. a frame of strings and identifiers).
+        let n_rows = row_count(lazy_df)?;
+
+        let (sparklines, histograms, normality) =
+            distributions(lazy_df, &numeric_columns, failed_analyses)?;
+
+        let categorical_stats = schema
+            .iter()
+            .filter(|(name, dtype)| {
+                !is_id(name) && matches!(dtype, DataType::Categorical(_, _) | DataType::Enum(_, _))
+            })
+            .map(|(name, _)| {
+                CategoricalStats::new(lazy_df, name).map(|stats| (name.to_string(), stats))
+            })
+            .collect::<Result<IndexMap<String, CategoricalStats>, DescriptiveError>>()?;
+
+        let nested_stats = schema
+            .iter()
+            .filter(|(_, dtype)| dtype.is_list() || dtype.is_array())
+            .map(|(name, dtype)| {
+                NestedStats::new(lazy_df, name, dtype).map(|stats| (name.to_string(), stats))
+            })
+            .collect::<Result<IndexMap<String, NestedStats>, DescriptiveError>>()?;
+
+        let binary_stats = schema
+            .iter()
+            .filter(|(_, dtype)| matches!(dtype, DataType::Binary | DataType::BinaryOffset))
+            .map(|(name, _)| BinaryStats::new(lazy_df, name).map(|stats| (name.to_string(), stats)))
+            .collect::<Result<IndexMap<String, BinaryStats>, DescriptiveError>>()?;
+
+        let detected_columns = high_cardinality_columns(lazy_df, schema)?;
+        let high_cardinality_stats = schema
+            .iter_names()
+            .filter(|name| is_id(name) || detected_columns.iter().any(|column| column == *name))
+            .map(|name| name.to_string())
+            .map(|name| HighCardinalityStats::new(lazy_df, &name).map(|stats| (name, stats)))
+            .collect::<Result<IndexMap<String, HighCardinalityStats>, DescriptiveError>>()?;
+
+        let distinct_stats = distinct_stats(lazy_df, schema)?;
 
         Ok(Self {
             n_rows,
@@ -206,12 +413,886 @@ impl DescriptiveAnalysis {
             column_stats: feature_stats,
             column_map,
             feature_indices,
+            decimal_scales,
+            sparklines,
+            histograms,
+            normality,
+            categorical_stats,
+            nested_stats,
+            binary_stats,
+            high_cardinality_stats,
+            distinct_stats,
         })
     }
+
+    /// Gets the features with a single distinct value, in column order.
+    pub fn constant_columns(&self) -> Vec<&str> {
+        self.distinct_stats
+            .iter()
+            .filter(|(_, stats)| stats.is_constant())
+            .map(|(name, _)| name.as_str())
+            .collect()
+    }
+
+    /// Gets the likely key features, in column order.
+    pub fn likely_keys(&self) -> Vec<&str> {
+        self.distinct_stats
+            .iter()
+            .filter(|(_, stats)| stats.is_likely_key())
+            .map(|(name, _)| name.as_str())
+            .collect()
+    }
 }
 
-/// Struct to hold descriptive analysis results for all features.
+/// Distinct value count statistics for a feature.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DistinctStats {
+    /// The number of non-null values.
+    pub count: u64,
+    /// The number of null values.
+    pub null_count: u64,
+    /// The number of distinct non-null values.
+    pub n_unique: u64,
+    /// Whether the feature holds floating point values, which are never likely keys.
+    pub is_float: bool,
+}
+
+impl DistinctStats {
+    /// Gets the number of distinct values divided by the number of non-null values, or 0 for an
+    /// entirely missing feature.
+    pub fn uniqueness_ratio(&self) -> f64 {
+        if self.count == 0 {
+            0.0
+        } else {
+            self.n_unique as f64 / self.count as f64
+        }
+    }
+
+    /// Checks whether the feature has a single distinct value.
+    pub fn is_constant(&self) -> bool {
+        self.n_unique == 1
+    }
+
+    /// Checks whether every value of a non-floating point feature is present and distinct, over at
+    /// least two rows.
+    pub fn is_likely_key(&self) -> bool {
+        !self.is_float && self.null_count == 0 && self.count > 1 && self.n_unique == self.count
+    }
+}
+
+/// Counts the non-null, null, and distinct values of each non-nested feature in a single pass.
+fn distinct_stats(
+    lazy_df: &LazyFrame,
+    schema: &Schema,
+) -> Result<IndexMap<String, DistinctStats>, DescriptiveError> {
+    let columns: Vec<(&str, bool)> = schema
+        .iter()
+        .filter(|(_, dtype)| !dtype.is_nested())
+        .map(|(name, dtype)| (name.as_str(), dtype.is_float()))
+        .collect();
+    if columns.is_empty() {
+        return Ok(IndexMap::new());
+    }
+
+    let df = lazy_df
+        .clone()
+        .select(
+            columns
+                .iter()
+                .flat_map(|(name, _)| {
+                    [
+                        col(name)
+                            .count()
+                            .cast(DataType::UInt64)
+                            .alias(&format!("{}_count", name)),
+                        col(name)
+                            .null_count()
+                            .cast(DataType::UInt64)
+                            .alias(&format!("{}_null_count", name)),
+                        col(name)
+                            .drop_nulls()
+                            .n_unique()
+                            .cast(DataType::UInt64)
+                            .alias(&format!("{}_n_unique", name)),
+                    ]
+                })
+                .collect::<Vec<Expr>>(),
+        )
+        .collect()?;
+
+    let get = |name: String| -> Result<u64, DescriptiveError> {
+        Ok(df.column(&name)?.u64()?.get(0).unwrap_or(0))
+    };
+    columns
+        .into_iter()
+        .map(|(name, is_float)| {
+            Ok((
+                name.to_owned(),
+                DistinctStats {
+                    count: get(format!("{}_count", name))?,
+                    null_count: get(format!("{}_null_count", name))?,
+                    n_unique: get(format!("{}_n_unique", name))?,
+                    is_float,
+                },
+            ))
+        })
+        .collect()
+}
+
+/// The sparklines, histograms, and normality results of the numerical features, keyed by
+/// feature name.
+type Distributions = (
+    IndexMap<String, Vec<u64>>,
+    IndexMap<String, Histogram>,
+    IndexMap<String, NormalityStats>,
+);
+
+/// Counts the rows of a LazyFrame.
+///
+/// ### Parameters
+///
+/// - `lazy_df`: Reference to the LazyFrame.
+///
+/// ### Returns
+///
+/// - `Result<u64, DescriptiveError>`: The number of rows or an error.
+pub fn row_count(lazy_df: &LazyFrame) -> Result<u64, DescriptiveError> {
+    let df = lazy_df.clone().select([len()]).collect()?;
+    Ok(df.get_columns()[0]
+        .cast(&DataType::UInt64)?
+        .u64()?
+        .get(0)
+        .unwrap_or(0))
+}
+
+/// Computes the equal width sparkline of each numerical feature, from its minimum to its maximum
+/// finite value, bins the finite values into a histogram, and tests them for normality. Features
+/// without finite values get an empty sparkline and no histogram, and the features whose histogram
+/// or normality test fails are recorded in `failed_analyses` and left without them.
+fn distributions(
+    lazy_df: &LazyFrame,
+    numeric_columns: &[String],
+    failed_analyses: &mut Vec<AnalysisFailure>,
+) -> Result<Distributions, DescriptiveError> {
+    let df = lazy_df
+        .clone()
+        .select(
+            numeric_columns
+                .iter()
+                .map(|name| col(name).cast(DataType::Float64))
+                .collect::<Vec<Expr>>(),
+        )
+        .collect()?;
+
+    let mut sparklines = IndexMap::with_capacity(numeric_columns.len());
+    let mut histograms = IndexMap::with_capacity(numeric_columns.len());
+    let mut normality = IndexMap::new();
+    for name in numeric_columns {
+        let values: Vec<f64> = df
+            .column(name)?
+            .f64()?
+            .into_iter()
+            .flatten()
+            .filter(|value| value.is_finite())
+            .collect();
+        let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+        let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        let mut counts = if values.is_empty() {
+            Vec::new()
+        } else {
+            vec![0_u64; HISTOGRAM_BINS]
+        };
+        let width = (max - min) / HISTOGRAM_BINS as f64;
+        for value in &values {
+            let bin = if width > 0.0 {
+                (((value - min) / width) as usize).min(HISTOGRAM_BINS - 1)
+            } else {
+                0
+            };
+            counts[bin] += 1;
+        }
+        let shape = run_recoverable_column(
+            RecoverableAnalysis::DistributionShape,
+            name,
+            failed_analyses,
+            || {
+                Ok::<_, DescriptiveError>((
+                    NormalityStats::new(&values, &counts),
+                    Histogram::new(&values),
+                ))
+            },
+        );
+        if let Some((stats, histogram)) = shape {
+            if let Some(stats) = stats {
+                normality.insert(name.clone(), stats);
+            }
+            if let Some(histogram) = histogram {
+                histograms.insert(name.clone(), histogram);
+            }
+        }
+        sparklines.insert(name.clone(), counts);
+    }
+    Ok((sparklines, histograms, normality))
+}
+
+/// The rule the bin width of a histogram was chosen with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BinRule {
+    /// A bin width of `2 * IQR / n^(1/3)`.
+    FreedmanDiaconis,
+    /// `ceil(log2(n)) + 1` bins, used when the interquartile range is zero.
+    Sturges,
+}
+
+impl fmt::Display for BinRule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BinRule::FreedmanDiaconis => write!(f, "Freedman-Diaconis"),
+            BinRule::Sturges => write!(f, "Sturges"),
+        }
+    }
+}
+
+/// The equal width histogram of the finite values of a numerical feature.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Histogram {
+    /// The rule the bin width was chosen with.
+    pub rule: BinRule,
+    /// The bin edges, from the minimum to the maximum value, one more than the number of bins.
+    /// Every bin includes its lower edge, and the last one its upper edge too.
+    pub edges: Vec<f64>,
+    /// The number of values in each bin.
+    pub counts: Vec<u64>,
+}
+
+impl Histogram {
+    /// Bins the values of a feature, with a Freedman-Diaconis bin width or Sturges' number of
+    /// bins if the interquartile range is zero, capped at `MAX_HISTOGRAM_BINS` bins. Values that
+    /// are all equal get a single zero width bin.
+    ///
+    /// ### Parameters
+    ///
+    /// - `values`: The finite values of the feature.
+    ///
+    /// ### Returns
+    ///
+    /// - `Option<Self>`: The histogram, or None if there are no values.
+    pub fn new(values: &[f64]) -> Option<Self> {
+        let mut sorted = values.to_vec();
+        sorted.sort_by(f64::total_cmp);
+        let (min, max) = (*sorted.first()?, *sorted.last()?);
+        let n = sorted.len() as f64;
+        let iqr = quantile(&sorted, 0.75) - quantile(&sorted, 0.25);
+        let (rule, n_bins) = if iqr > 0.0 {
+            let width = 2.0 * iqr / n.cbrt();
+            (BinRule::FreedmanDiaconis, ((max - min) / width).ceil())
+        } else {
+            (BinRule::Sturges, n.log2().ceil() + 1.0)
+        };
+        let n_bins = if max > min {
+            (n_bins as usize).clamp(1, MAX_HISTOGRAM_BINS)
+        } else {
+            1
+        };
+
+        let width = (max - min) / n_bins as f64;
+        let mut edges: Vec<f64> = (0..n_bins).map(|bin| min + bin as f64 * width).collect();
+        edges.push(max);
+        let mut counts = vec![0_u64; n_bins];
+        for value in &sorted {
+            let bin = if width > 0.0 {
+                (((value - min) / width) as usize).min(n_bins - 1)
+            } else {
+                0
+            };
+            counts[bin] += 1;
+        }
+        Some(Self {
+            rule,
+            edges,
+            counts,
+        })
+    }
+
+    /// Formats the number of bins and the rule they were chosen with, e.g.
+    /// `24 (Freedman-Diaconis)`.
+    pub fn format_bins(&self) -> String {
+        format!("{} ({})", self.counts.len(), self.rule)
+    }
+}
+
+/// Gets a quantile of sorted values, interpolating linearly between the closest ranks.
+fn quantile(sorted: &[f64], probability: f64) -> f64 {
+    let position = probability * (sorted.len() - 1) as f64;
+    let (lower, upper) = (position.floor() as usize, position.ceil() as usize);
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (position - lower as f64)
+}
+
+/// Frequency statistics for a categorical (or enum) feature.
 #[derive(Debug)]
+pub struct CategoricalStats {
+    /// The number of non-null values.
+    pub count: u64,
+    /// The number of distinct categories present in the data.
+    pub n_unique: u64,
+    /// The most frequent categories and their counts, ordered from most to least frequent.
+    pub top_frequencies: Vec<(String, u64)>,
+}
+
+impl CategoricalStats {
+    /// Constructor for the CategoricalStats struct.
+    ///
+    /// ### Parameters
+    ///
+    /// - `lazy_df`: Reference to the LazyFrame.
+    /// - `column`: The name of the categorical column.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<Self, DescriptiveError>`: The frequency statistics or an error.
+    pub fn new(lazy_df: &LazyFrame, column: &str) -> Result<Self, DescriptiveError> {
+        let frequencies = lazy_df
+            .clone()
+            .select([col(column).cast(DataType::String).alias("category")])
+            .drop_nulls(None)
+            .group_by([col("category")])
+            .agg([len().cast(DataType::UInt64).alias("frequency")])
+            .sort(
+                ["frequency", "category"],
+                SortMultipleOptions::default().with_order_descending_multi([true, false]),
+            )
+            .collect()?;
+
+        let categories = frequencies.column("category")?.str()?;
+        let counts = frequencies.column("frequency")?.u64()?;
+        let count = counts.sum().unwrap_or(0);
+        let top_frequencies = categories
+            .into_iter()
+            .zip(counts)
+            .take(TOP_CATEGORIES)
+            .map(|(category, frequency)| {
+                (
+                    category.unwrap_or_default().to_owned(),
+                    frequency.unwrap_or(0),
+                )
+            })
+            .collect();
+
+        Ok(Self {
+            count,
+            n_unique: frequencies.height() as u64,
+            top_frequencies,
+        })
+    }
+
+    /// Returns the most frequent category and its count, if the column has any values.
+    pub fn mode(&self) -> Option<&(String, u64)> {
+        self.top_frequencies.first()
+    }
+}
+
+/// The order of the values of a feature in row order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Monotonicity {
+    /// Every value is greater than or equal to the previous one.
+    Increasing,
+    /// Every value is less than or equal to the previous one.
+    Decreasing,
+    /// The values go up and down.
+    NotMonotonic,
+}
+
+impl fmt::Display for Monotonicity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let monotonicity = match self {
+            Monotonicity::Increasing => "increasing",
+            Monotonicity::Decreasing => "decreasing",
+            Monotonicity::NotMonotonic => "none",
+        };
+        write!(f, "{}", monotonicity)
+    }
+}
+
+/// Summary statistics for an ID-like string, categorical, or enum feature.
+#[derive(Debug)]
+pub struct HighCardinalityStats {
+    /// The number of non-null values.
+    pub count: u64,
+    /// The number of distinct values.
+    pub n_unique: u64,
+    /// The ratio of distinct to non-null values.
+    pub uniqueness_ratio: f64,
+    /// The `TOP_AFFIXES` most common prefixes and their percentage of the values.
+    pub top_prefixes: Vec<(String, f64)>,
+    /// The `TOP_AFFIXES` most common suffixes and their percentage of the values.
+    pub top_suffixes: Vec<(String, f64)>,
+    /// The smallest number of characters in a value.
+    pub min_length: usize,
+    /// The average number of characters in a value.
+    pub mean_length: f64,
+    /// The largest number of characters in a value.
+    pub max_length: usize,
+    /// The `TOP_AFFIXES` most common numbers of characters and their percentage of the values.
+    pub top_lengths: Vec<(usize, f64)>,
+    /// Whether the values are monotonic in row order.
+    pub monotonicity: Monotonicity,
+}
+
+impl HighCardinalityStats {
+    /// Constructor for the HighCardinalityStats struct.
+    ///
+    /// ### Parameters
+    ///
+    /// - `lazy_df`: Reference to the LazyFrame.
+    /// - `column`: The name of the string, categorical, or enum column.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<Self, DescriptiveError>`: The summary statistics or an error.
+    pub fn new(lazy_df: &LazyFrame, column: &str) -> Result<Self, DescriptiveError> {
+        let df = lazy_df
+            .clone()
+            .select([col(column).cast(DataType::String)])
+            .drop_nulls(None)
+            .collect()?;
+        let values: Vec<&str> = df.column(column)?.str()?.into_iter().flatten().collect();
+        let count = values.len() as u64;
+        let n_unique = df.column(column)?.n_unique()? as u64;
+
+        let mut prefixes: HashMap<String, u64> = HashMap::new();
+        let mut suffixes: HashMap<String, u64> = HashMap::new();
+        let mut lengths: HashMap<usize, u64> = HashMap::new();
+        for value in &values {
+            let chars: Vec<char> = value.chars().collect();
+            let affix_length = AFFIX_LENGTH.min(chars.len());
+            *prefixes
+                .entry(chars[..affix_length].iter().collect())
+                .or_default() += 1;
+            *suffixes
+                .entry(chars[chars.len() - affix_length..].iter().collect())
+                .or_default() += 1;
+            *lengths.entry(chars.len()).or_default() += 1;
+        }
+
+        let mut increasing = true;
+        let mut decreasing = true;
+        for pair in values.windows(2) {
+            let previous = (pair[0].chars().count(), pair[0]);
+            let current = (pair[1].chars().count(), pair[1]);
+            increasing &= current >= previous;
+            decreasing &= current <= previous;
+        }
+        let monotonicity = match (increasing, decreasing) {
+            (true, false) => Monotonicity::Increasing,
+            (false, true) => Monotonicity::Decreasing,
+            _ => Monotonicity::NotMonotonic,
+        };
+
+        Ok(Self {
+            count,
+            n_unique,
+            uniqueness_ratio: n_unique as f64 / count as f64,
+            top_prefixes: top_shares(prefixes, count),
+            top_suffixes: top_shares(suffixes, count),
+            min_length: lengths.keys().min().copied().unwrap_or(0),
+            mean_length: lengths
+                .iter()
+                .map(|(length, frequency)| (length * *frequency as usize) as f64)
+                .sum::<f64>()
+                / count as f64,
+            max_length: lengths.keys().max().copied().unwrap_or(0),
+            top_lengths: top_shares(lengths, count),
+            monotonicity,
+        })
+    }
+}
+
+/// Finds the string, categorical, and enum columns that are ID-like.
+///
+/// ### Parameters
+///
+/// - `lazy_df`: Reference to the LazyFrame.
+/// - `schema`: Reference to the lazy frame's schema.
+///
+/// ### Returns
+///
+/// - `Result<Vec<String>, DescriptiveError>`: The names of the ID-like columns or an error.
+fn high_cardinality_columns(
+    lazy_df: &LazyFrame,
+    schema: &Schema,
+) -> Result<Vec<String>, DescriptiveError> {
+    let candidates: Vec<&str> = schema
+        .iter()
+        .filter(|(_, dtype)| {
+            matches!(
+                dtype,
+                DataType::String | DataType::Categorical(_, _) | DataType::Enum(_, _)
+            )
+        })
+        .map(|(name, _)| name.as_str())
+        .collect();
+    if candidates.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let exprs: Vec<Expr> = candidates
+        .iter()
+        .flat_map(|name| {
+            let values = col(name).cast(DataType::String).drop_nulls();
+            [
+                values
+                    .clone()
+                    .n_unique()
+                    .cast(DataType::UInt64)
+                    .alias(&format!("{}_n_unique", name)),
+                values
+                    .count()
+                    .cast(DataType::UInt64)
+                    .alias(&format!("{}_count", name)),
+            ]
+        })
+        .collect();
+    let df = lazy_df.clone().select(exprs).collect()?;
+
+    let mut columns = Vec::new();
+    for name in candidates {
+        let n_unique = df
+            .column(&format!("{}_n_unique", name))?
+            .u64()?
+            .get(0)
+            .unwrap_or(0);
+        let count = df
+            .column(&format!("{}_count", name))?
+            .u64()?
+            .get(0)
+            .unwrap_or(0);
+        if n_unique >= MIN_HIGH_CARDINALITY
+            && n_unique as f64 >= HIGH_CARDINALITY_RATIO * count as f64
+        {
+            columns.push(name.to_owned());
+        }
+    }
+
+    Ok(columns)
+}
+
+/// Ranks the most common keys by their percentage of the `count` values, keeping the top
+/// `TOP_AFFIXES`.
+fn top_shares<K: Ord>(frequencies: HashMap<K, u64>, count: u64) -> Vec<(K, f64)> {
+    let mut shares: Vec<(K, u64)> = frequencies.into_iter().collect();
+    shares.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    shares.truncate(TOP_AFFIXES);
+    shares
+        .into_iter()
+        .map(|(key, frequency)| (key, frequency as f64 / count as f64 * 100.0))
+        .collect()
+}
+
+/// Element-count statistics for a list or array feature.
+#[derive(Debug)]
+pub struct NestedStats {
+    /// The data type of the elements.
+    pub inner_type: DataType,
+    /// The number of non-null values.
+    pub count: u64,
+    /// The smallest number of elements in a value.
+    pub min_length: Option<u64>,
+    /// The average number of elements in a value.
+    pub mean_length: Option<f64>,
+    /// The largest number of elements in a value.
+    pub max_length: Option<u64>,
+    /// The number of empty values.
+    pub empty_count: u64,
+    /// The percentage of non-null values that are empty.
+    pub empty_percentage: f64,
+    /// The total number of non-null elements across all values.
+    pub n_elements: u64,
+    /// The number of distinct elements, if the elements are not nested themselves.
+    pub element_n_unique: Option<u64>,
+    /// The smallest element, if the elements are numeric.
+    pub element_min: Option<f64>,
+    /// The average element, if the elements are numeric.
+    pub element_mean: Option<f64>,
+    /// The largest element, if the elements are numeric.
+    pub element_max: Option<f64>,
+}
+
+impl NestedStats {
+    /// Constructor for the NestedStats struct.
+    ///
+    /// ### Parameters
+    ///
+    /// - `lazy_df`: Reference to the LazyFrame.
+    /// - `column`: The name of the list or array column.
+    /// - `dtype`: The data type of the column.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<Self, DescriptiveError>`: The element-count statistics or an error.
+    pub fn new(
+        lazy_df: &LazyFrame,
+        column: &str,
+        dtype: &DataType,
+    ) -> Result<Self, DescriptiveError> {
+        // Arrays are profiled through the list namespace, so fixed width columns share the logic.
+        let (values, inner_type) = match dtype {
+            DataType::List(inner) => (col(column), inner.as_ref().clone()),
+            DataType::Array(inner, _) => (
+                col(column).cast(DataType::List(inner.clone())),
+                inner.as_ref().clone(),
+            ),
+            _ => {
+                return Err(DescriptiveError::InvalidConversion(
+                    column.to_owned(),
+                    dtype.to_string(),
+                    "list".to_owned(),
+                ))
+            }
+        };
+        let lengths = values.clone().list().len();
+        let elements = values.clone().explode().drop_nulls();
+
+        let mut exprs = vec![
+            values.count().cast(DataType::UInt64).alias("count"),
+            lengths
+                .clone()
+                .min()
+                .cast(DataType::UInt64)
+                .alias("min_length"),
+            lengths.clone().mean().alias("mean_length"),
+            lengths
+                .clone()
+                .max()
+                .cast(DataType::UInt64)
+                .alias("max_length"),
+            lengths
+                .clone()
+                .eq(lit(0))
+                .sum()
+                .cast(DataType::UInt64)
+                .alias("empty_count"),
+            elements
+                .clone()
+                .count()
+                .cast(DataType::UInt64)
+                .alias("n_elements"),
+        ];
+        let is_flat = !(inner_type.is_list() || inner_type.is_array());
+        if is_flat {
+            exprs.push(
+                elements
+                    .clone()
+                    .n_unique()
+                    .cast(DataType::UInt64)
+                    .alias("element_n_unique"),
+            );
+        }
+        let is_numeric = inner_type.is_numeric() || inner_type.is_decimal();
+        if is_numeric {
+            let elements = elements.cast(DataType::Float64);
+            exprs.extend([
+                elements.clone().min().alias("element_min"),
+                elements.clone().mean().alias("element_mean"),
+                elements.max().alias("element_max"),
+            ]);
+        }
+
+        let stats_df = lazy_df.clone().select(exprs).collect()?;
+        let get_u64 = |name: &str| -> Result<Option<u64>, DescriptiveError> {
+            Ok(stats_df.column(name)?.u64()?.get(0))
+        };
+        let get_f64 = |name: &str| -> Result<Option<f64>, DescriptiveError> {
+            Ok(stats_df.column(name)?.f64()?.get(0))
+        };
+
+        let count = get_u64("count")?.unwrap_or(0);
+        let empty_count = get_u64("empty_count")?.unwrap_or(0);
+        let empty_percentage = if count > 0 {
+            (empty_count as f64 / count as f64) * 100.0
+        } else {
+            0.0
+        };
+
+        Ok(Self {
+            inner_type,
+            count,
+            min_length: get_u64("min_length")?,
+            mean_length: get_f64("mean_length")?,
+            max_length: get_u64("max_length")?,
+            empty_count,
+            empty_percentage,
+            n_elements: get_u64("n_elements")?.unwrap_or(0),
+            element_n_unique: if is_flat {
+                get_u64("element_n_unique")?
+            } else {
+                None
+            },
+            element_min: if is_numeric {
+                get_f64("element_min")?
+            } else {
+                None
+            },
+            element_mean: if is_numeric {
+                get_f64("element_mean")?
+            } else {
+                None
+            },
+            element_max: if is_numeric {
+                get_f64("element_max")?
+            } else {
+                None
+            },
+        })
+    }
+}
+
+/// The content type of a binary value, guessed from its magic bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ContentType {
+    /// A PNG image.
+    Png,
+    /// A JPEG image.
+    Jpeg,
+    /// A GIF image.
+    Gif,
+    /// A WebP image.
+    Webp,
+    /// A PDF document.
+    Pdf,
+    /// A gzip compressed stream.
+    Gzip,
+    /// A zip archive (including Office documents and JARs).
+    Zip,
+    /// A zstd compressed stream.
+    Zstd,
+    /// Printable UTF-8 text.
+    Text,
+    /// Anything else.
+    Unknown,
+}
+
+impl ContentType {
+    /// Guesses the content type of a binary value from its leading magic bytes.
+    ///
+    /// ### Parameters
+    ///
+    /// - `bytes`: The binary value.
+    ///
+    /// ### Returns
+    ///
+    /// - `ContentType`: The guessed content type.
+    pub fn detect(bytes: &[u8]) -> Self {
+        match bytes {
+            [0x89, b'P', b'N', b'G', ..] => ContentType::Png,
+            [0xFF, 0xD8, 0xFF, ..] => ContentType::Jpeg,
+            [b'G', b'I', b'F', b'8', ..] => ContentType::Gif,
+            [b'R', b'I', b'F', b'F', _, _, _, _, b'W', b'E', b'B', b'P', ..] => ContentType::Webp,
+            [b'%', b'P', b'D', b'F', ..] => ContentType::Pdf,
+            [0x1F, 0x8B, ..] => ContentType::Gzip,
+            [b'P', b'K', 0x03, 0x04, ..] => ContentType::Zip,
+            [0x28, 0xB5, 0x2F, 0xFD, ..] => ContentType::Zstd,
+            _ => match std::str::from_utf8(bytes) {
+                Ok(text)
+                    if text
+                        .chars()
+                        .all(|c| !c.is_control() || c.is_ascii_whitespace()) =>
+                {
+                    ContentType::Text
+                }
+                _ => ContentType::Unknown,
+            },
+        }
+    }
+}
+
+impl fmt::Display for ContentType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            ContentType::Png => "PNG image",
+            ContentType::Jpeg => "JPEG image",
+            ContentType::Gif => "GIF image",
+            ContentType::Webp => "WebP image",
+            ContentType::Pdf => "PDF document",
+            ContentType::Gzip => "gzip stream",
+            ContentType::Zip => "zip archive",
+            ContentType::Zstd => "zstd stream",
+            ContentType::Text => "UTF-8 text",
+            ContentType::Unknown => "unknown",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Byte-length statistics and content type guesses for a binary feature.
+#[derive(Debug)]
+pub struct BinaryStats {
+    /// The number of non-null values.
+    pub count: u64,
+    /// The smallest number of bytes in a value.
+    pub min_bytes: Option<u64>,
+    /// The average number of bytes in a value.
+    pub mean_bytes: Option<f64>,
+    /// The largest number of bytes in a value.
+    pub max_bytes: Option<u64>,
+    /// The total number of bytes across all values.
+    pub total_bytes: u64,
+    /// The number of empty values.
+    pub empty_count: u64,
+    /// The guessed content types of the non-empty values and their shares of the non-empty
+    /// values in percent, ordered from most to least common.
+    pub content_types: Vec<(ContentType, f64)>,
+}
+
+impl BinaryStats {
+    /// Constructor for the BinaryStats struct.
+    ///
+    /// ### Parameters
+    ///
+    /// - `lazy_df`: Reference to the LazyFrame.
+    /// - `column`: The name of the binary column.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<Self, DescriptiveError>`: The byte-length statistics or an error.
+    pub fn new(lazy_df: &LazyFrame, column: &str) -> Result<Self, DescriptiveError> {
+        let df = lazy_df
+            .clone()
+            .select([col(column).cast(DataType::Binary)])
+            .drop_nulls(None)
+            .collect()?;
+        let values: Vec<&[u8]> = df.column(column)?.binary()?.into_iter().flatten().collect();
+        let count = values.len() as u64;
+        let lengths = values.iter().map(|value| value.len() as u64);
+        let total_bytes: u64 = lengths.clone().sum();
+
+        let mut type_counts: HashMap<ContentType, u64> = HashMap::new();
+        for value in values.iter().filter(|value| !value.is_empty()) {
+            *type_counts.entry(ContentType::detect(value)).or_default() += 1;
+        }
+        let n_non_empty: u64 = type_counts.values().sum();
+        let mut content_types: Vec<(ContentType, f64)> = type_counts
+            .into_iter()
+            .map(|(content_type, type_count)| {
+                (content_type, type_count as f64 / n_non_empty as f64 * 100.0)
+            })
+            .collect();
+        content_types.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+
+        Ok(Self {
+            count,
+            min_bytes: lengths.clone().min(),
+            mean_bytes: (count > 0).then(|| total_bytes as f64 / count as f64),
+            max_bytes: lengths.max(),
+            total_bytes,
+            empty_count: count - n_non_empty,
+            content_types,
+        })
+    }
+}
+
+/// Struct to hold descriptive analysis results for all features.
+#[derive(Debug, Default)]
 pub struct FeatureStats(DataFrame);
 
 impl FeatureStats {
@@ -241,6 +1322,9 @@ impl FeatureStats {
         column_map: &IndexMap<String, usize>,
     ) -> Result<Vec<IndexMap<String, String>>, DescriptiveError> {
         let mut result = Vec::with_capacity(feature_indices.len());
+        if feature_indices.is_empty() {
+            return Ok(result);
+        }
 
         let row = self
             .0
@@ -255,10 +1339,15 @@ impl FeatureStats {
                 let value = row.get(column_index).ok_or_else(|| {
                     DescriptiveError::InvalidIndex(format!(
                         "attempted to index FeatureStats Vec with index {}",
-                        column_index.to_string()
+                        column_index
                     ))
                 })?;
-                feature_stats.insert(statistic.to_owned(), value.to_string());
+                // String values are unwrapped so the column name isn't displayed with quotes.
+                let value = match value {
+                    AnyValue::String(value) => value.to_string(),
+                    value => value.to_string(),
+                };
+                feature_stats.insert(statistic.to_owned(), value);
             }
             result.push(feature_stats);
         }
@@ -302,7 +1391,7 @@ impl FeatureStats {
         statistic: &str,
         feature_indices: &IndexMap<String, usize>,
         column_map: &IndexMap<String, usize>,
-    ) -> Result<AnyValue, DescriptiveError> {
+    ) -> Result<AnyValue<'_>, DescriptiveError> {
         let feature_index = feature_indices
             .get(feature)
             .ok_or_else(|| DescriptiveError::InvalidCol(feature.to_owned()))?;
@@ -0,0 +1,547 @@
+//! # Data Dictionary Module
+//!
+//! This module handles loading a user supplied data dictionary, which documents each column of
+//! the dataset with a description, a unit, an owner, and tags. The dictionary entries are rendered
+//! alongside the columns throughout the report so the generated report is self-documenting.
+//!
+//! ## Column Tags
+//!
+//! Tags change how the analyses and the report handle a column:
+//!
+//! - `id`: The column is an identifier. It's summarized as a high-cardinality feature and skipped
+//!   by the statistical analyses.
+//! - `pii`: The column holds personally identifiable information. Its example values (frequency
+//!   tables, cross-tabulation labels, invalid examples, class labels, and the groups its missing
+//!   values concentrate in) are masked or hashed in the report, following the privacy mode.
+//! - `target`: The column is the target for supervised mode, unless a target is passed directly.
+//! - `ignore`: The column is dropped before the analyses.
+//! - `currency`: The column holds monetary amounts. Its location statistics are formatted as
+//!   currency amounts, in the column unit if set.
+//!
+//! ## Analysis Opt-Outs
+//!
+//! A column can opt out of specific analyses (`outliers`, `transformations`, `categories`,
+//! `correlations`, `text`, `drift`, and `plots`) while still being profiled by the others, e.g.
+//! outlier detection on a free text column or the plots of a raw payload. The opted out
+//! analyses are listed in the report so their results aren't silently absent.
+//!
+//! ## Supported Formats
+//!
+//! YAML dictionaries map each column name to its entry, all fields are optional:
+//!
+//! ```yaml
+//! income:
+//!   description: Annual household income before tax.
+//!   unit: USD
+//!   owner: finance-team
+//!   tags: [currency, pii]
+//!   skip: [outliers]
+//! ```
+//!
+//! CSV dictionaries have a header row with a required `column` column and optional
+//! `description`, `unit`, `owner`, `tags`, and `skip` columns, with the tags and opted out
+//! analyses separated by semicolons:
+//!
+//! ```csv
+//! column,description,unit,owner,tags,skip
+//! income,Annual household income before tax.,USD,finance-team,currency;pii,outliers
+//! ```
+
+use indexmap::IndexMap;
+use polars::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::ffi::OsStr;
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// The error types for the data dictionary module.
+#[derive(Error, Debug)]
+pub enum DictionaryError {
+    /// Occurs when an I/O operation fails.
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// Occurs when Polars fails to read a CSV dictionary.
+    #[error("Polars error: {0}")]
+    Polars(#[from] PolarsError),
+
+    /// Occurs when a YAML dictionary can't be parsed.
+    #[error("YAML error: {0}")]
+    Yaml(#[from] serde_yaml::Error),
+
+    /// Occurs when a CSV dictionary doesn't have the required `column` column.
+    #[error("Data dictionary is missing the required column: {0}")]
+    MissingColumn(String),
+
+    /// Occurs when an unsupported file format is passed.
+    #[error("Unsupported data dictionary format: {0}")]
+    UnsupportedFormat(String),
+
+    /// Occurs when a CSV dictionary has an unknown column tag.
+    #[error("Invalid column tag: {0}")]
+    InvalidTag(String),
+
+    /// Occurs when a CSV dictionary opts a column out of an unknown analysis.
+    #[error("Invalid skipped analysis: {0}")]
+    InvalidAnalysis(String),
+}
+
+/// The tags a column can be annotated with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ColumnTag {
+    /// An identifier column.
+    Id,
+    /// A column with personally identifiable information.
+    Pii,
+    /// The target column for supervised mode.
+    Target,
+    /// A column to drop before the analyses.
+    Ignore,
+    /// A column of monetary amounts.
+    Currency,
+}
+
+impl FromStr for ColumnTag {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "id" => Ok(ColumnTag::Id),
+            "pii" => Ok(ColumnTag::Pii),
+            "target" => Ok(ColumnTag::Target),
+            "ignore" => Ok(ColumnTag::Ignore),
+            "currency" => Ok(ColumnTag::Currency),
+            other => Err(format!(
+                "unsupported column tag '{}', expected one of: id, pii, target, ignore, currency",
+                other
+            )),
+        }
+    }
+}
+
+impl fmt::Display for ColumnTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            ColumnTag::Id => "id",
+            ColumnTag::Pii => "pii",
+            ColumnTag::Target => "target",
+            ColumnTag::Ignore => "ignore",
+            ColumnTag::Currency => "currency",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// The per-column analyses a column can opt out of.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ColumnAnalysis {
+    /// The outlier fences and outlier counts.
+    Outliers,
+    /// The transformation suggestions for skewed features.
+    Transformations,
+    /// The "Other" grouping suggestions for categorical features with rare levels.
+    Categories,
+    /// The Pearson and Spearman correlations.
+    Correlations,
+    /// The text profiling of string columns.
+    Text,
+    /// The drift against the baseline profile.
+    Drift,
+    /// The per-column plots (histograms, transformation histograms, category merge charts, and
+    /// outlier strip plots).
+    Plots,
+}
+
+impl FromStr for ColumnAnalysis {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "outliers" => Ok(ColumnAnalysis::Outliers),
+            "transformations" => Ok(ColumnAnalysis::Transformations),
+            "categories" => Ok(ColumnAnalysis::Categories),
+            "correlations" => Ok(ColumnAnalysis::Correlations),
+            "text" => Ok(ColumnAnalysis::Text),
+            "drift" => Ok(ColumnAnalysis::Drift),
+            "plots" => Ok(ColumnAnalysis::Plots),
+            other => Err(format!(
+                "unsupported analysis '{}', expected one of: outliers, transformations, \
+                 categories, correlations, text, drift, plots",
+                other
+            )),
+        }
+    }
+}
+
+impl fmt::Display for ColumnAnalysis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            ColumnAnalysis::Outliers => "outliers",
+            ColumnAnalysis::Transformations => "transformations",
+            ColumnAnalysis::Categories => "categories",
+            ColumnAnalysis::Correlations => "correlations",
+            ColumnAnalysis::Text => "text",
+            ColumnAnalysis::Drift => "drift",
+            ColumnAnalysis::Plots => "plots",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// How the example values of PII-tagged columns are anonymized in the report.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PrivacyMode {
+    /// Keeps the first character and masks the rest (e.g. "j***************").
+    #[default]
+    Mask,
+    /// Replaces the value with a short stable hash (e.g. "#3f9a1c07"), so the same value can
+    /// still be matched across the report tables.
+    Hash,
+}
+
+impl PrivacyMode {
+    /// Anonymizes an example value of a PII-tagged column.
+    ///
+    /// ### Parameters
+    ///
+    /// - `value`: The example value.
+    ///
+    /// ### Returns
+    ///
+    /// - `String`: The masked or hashed value.
+    pub fn apply(&self, value: &str) -> String {
+        match self {
+            PrivacyMode::Mask => mask_value(value),
+            PrivacyMode::Hash => hash_value(value),
+        }
+    }
+}
+
+impl FromStr for PrivacyMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "mask" => Ok(PrivacyMode::Mask),
+            "hash" => Ok(PrivacyMode::Hash),
+            other => Err(format!(
+                "unsupported privacy mode '{}', expected one of: mask, hash",
+                other
+            )),
+        }
+    }
+}
+
+impl fmt::Display for PrivacyMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            PrivacyMode::Mask => "mask",
+            PrivacyMode::Hash => "hash",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// A tag assigned to a column, parsed from `<column>:<tag>` (e.g. on the command line).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaggedColumn {
+    /// The column name.
+    pub column: String,
+    /// The tag.
+    pub tag: ColumnTag,
+}
+
+impl FromStr for TaggedColumn {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (column, tag) = s
+            .rsplit_once(':')
+            .ok_or_else(|| format!("invalid column tag '{}', expected <column>:<tag>", s))?;
+        if column.is_empty() {
+            return Err(format!(
+                "invalid column tag '{}', expected <column>:<tag>",
+                s
+            ));
+        }
+        Ok(Self {
+            column: column.to_owned(),
+            tag: tag.parse()?,
+        })
+    }
+}
+
+/// An analysis a column opts out of, parsed from `<column>:<analysis>` (e.g. on the command
+/// line).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkippedAnalysis {
+    /// The column name.
+    pub column: String,
+    /// The analysis the column opts out of.
+    pub analysis: ColumnAnalysis,
+}
+
+impl FromStr for SkippedAnalysis {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || {
+            format!(
+                "invalid skipped analysis '{}', expected <column>:<analysis>",
+                s
+            )
+        };
+        let (column, analysis) = s.rsplit_once(':').ok_or_else(invalid)?;
+        if column.is_empty() {
+            return Err(invalid());
+        }
+        Ok(Self {
+            column: column.to_owned(),
+            analysis: analysis.parse()?,
+        })
+    }
+}
+
+/// The documentation for a single column.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ColumnDefinition {
+    /// Human readable description of the column.
+    pub description: Option<String>,
+    /// Unit of measurement for the column values.
+    pub unit: Option<String>,
+    /// Person or team responsible for the column.
+    pub owner: Option<String>,
+    /// Tags changing how the analyses and report handle the column.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<ColumnTag>,
+    /// The analyses the column opts out of.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub skip: Vec<ColumnAnalysis>,
+}
+
+impl ColumnDefinition {
+    /// Formats the unit, owner, tags, and opted out analyses as a single line (e.g. "Unit: USD |
+    /// Owner: finance-team | Tags: currency, pii | Skips: outliers").
+    ///
+    /// ### Returns
+    ///
+    /// - `Option<String>`: The formatted line, or None if neither the unit, owner, tags, nor
+    ///   opted out analyses are set.
+    pub fn details(&self) -> Option<String> {
+        let tags = (!self.tags.is_empty()).then(|| {
+            self.tags
+                .iter()
+                .map(ColumnTag::to_string)
+                .collect::<Vec<String>>()
+                .join(", ")
+        });
+        let skips = self.format_skips();
+        let details: Vec<String> = [
+            ("Unit", &self.unit),
+            ("Owner", &self.owner),
+            ("Tags", &tags),
+            ("Skips", &skips),
+        ]
+        .iter()
+        .filter_map(|(label, value)| value.as_ref().map(|v| format!("{}: {}", label, v)))
+        .collect();
+        if details.is_empty() {
+            None
+        } else {
+            Some(details.join(" | "))
+        }
+    }
+
+    /// Formats the opted out analyses as comma separated names, or None if there are none.
+    pub fn format_skips(&self) -> Option<String> {
+        (!self.skip.is_empty()).then(|| {
+            self.skip
+                .iter()
+                .map(ColumnAnalysis::to_string)
+                .collect::<Vec<String>>()
+                .join(", ")
+        })
+    }
+}
+
+/// A data dictionary mapping column names to their definitions.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct DataDictionary {
+    /// The column definitions, keyed by column name.
+    pub columns: IndexMap<String, ColumnDefinition>,
+}
+
+impl DataDictionary {
+    /// Reads a data dictionary file, dispatching on the file extension.
+    ///
+    /// ### Parameters
+    ///
+    /// - `path`: The path to the `.yaml`, `.yml`, or `.csv` data dictionary.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<Self, DictionaryError>`: The parsed data dictionary or an error.
+    pub fn from_file(path: &Path) -> Result<Self, DictionaryError> {
+        match path.extension().and_then(OsStr::to_str) {
+            Some("yaml") | Some("yml") => {
+                let contents = std::fs::read_to_string(path)?;
+                Ok(serde_yaml::from_str(&contents)?)
+            }
+            Some("csv") => Self::from_csv(path),
+            Some(ext) => Err(DictionaryError::UnsupportedFormat(ext.to_owned())),
+            None => Err(DictionaryError::UnsupportedFormat(
+                "No file extension".to_owned(),
+            )),
+        }
+    }
+
+    /// Gets the definition for a column, if the dictionary documents it.
+    pub fn get(&self, column: &str) -> Option<&ColumnDefinition> {
+        self.columns.get(column)
+    }
+
+    /// Tags a column, adding an entry for it if the dictionary doesn't document it yet.
+    ///
+    /// ### Parameters
+    ///
+    /// - `column`: The column name.
+    /// - `tag`: The tag to add.
+    pub fn add_tag(&mut self, column: &str, tag: ColumnTag) {
+        let definition = self.columns.entry(column.to_owned()).or_default();
+        if !definition.tags.contains(&tag) {
+            definition.tags.push(tag);
+        }
+    }
+
+    /// Checks whether a column has a tag.
+    pub fn has_tag(&self, column: &str, tag: ColumnTag) -> bool {
+        self.get(column)
+            .is_some_and(|definition| definition.tags.contains(&tag))
+    }
+
+    /// Opts a column out of an analysis, adding an entry for it if the dictionary doesn't
+    /// document it yet.
+    ///
+    /// ### Parameters
+    ///
+    /// - `column`: The column name.
+    /// - `analysis`: The analysis to skip.
+    pub fn add_skip(&mut self, column: &str, analysis: ColumnAnalysis) {
+        let definition = self.columns.entry(column.to_owned()).or_default();
+        if !definition.skip.contains(&analysis) {
+            definition.skip.push(analysis);
+        }
+    }
+
+    /// Gets the columns opting out of an analysis, in dictionary order.
+    pub fn columns_skipping(&self, analysis: ColumnAnalysis) -> Vec<String> {
+        self.columns
+            .iter()
+            .filter(|(_, definition)| definition.skip.contains(&analysis))
+            .map(|(name, _)| name.clone())
+            .collect()
+    }
+
+    /// Gets the columns with a tag, in dictionary order.
+    pub fn columns_with_tag(&self, tag: ColumnTag) -> Vec<String> {
+        self.columns
+            .iter()
+            .filter(|(_, definition)| definition.tags.contains(&tag))
+            .map(|(name, _)| name.clone())
+            .collect()
+    }
+
+    fn from_csv(path: &Path) -> Result<Self, DictionaryError> {
+        // Read every column as a string so values such as units aren't coerced.
+        let df = LazyCsvReader::new(path)
+            .with_has_header(true)
+            .with_infer_schema_length(Some(0))
+            .finish()?
+            .collect()?;
+
+        let names = df
+            .column("column")
+            .map_err(|_| DictionaryError::MissingColumn("column".to_owned()))?
+            .str()?;
+        let optional_field = |name: &str| -> Result<Option<&StringChunked>, DictionaryError> {
+            match df.column(name) {
+                Ok(series) => Ok(Some(series.str()?)),
+                Err(_) => Ok(None),
+            }
+        };
+        let descriptions = optional_field("description")?;
+        let units = optional_field("unit")?;
+        let owners = optional_field("owner")?;
+        let tags = optional_field("tags")?;
+        let skips = optional_field("skip")?;
+        let value_at = |field: Option<&StringChunked>, index: usize| {
+            field
+                .and_then(|values| values.get(index))
+                .map(str::to_owned)
+        };
+
+        let mut columns = IndexMap::new();
+        for (index, name) in names.into_iter().enumerate() {
+            let Some(name) = name else {
+                continue;
+            };
+            let tags = value_at(tags, index)
+                .map(|tags| {
+                    tags.split(';')
+                        .filter(|tag| !tag.trim().is_empty())
+                        .map(|tag| tag.parse().map_err(DictionaryError::InvalidTag))
+                        .collect::<Result<Vec<ColumnTag>, DictionaryError>>()
+                })
+                .transpose()?
+                .unwrap_or_default();
+            let skip = value_at(skips, index)
+                .map(|skips| {
+                    skips
+                        .split(';')
+                        .filter(|analysis| !analysis.trim().is_empty())
+                        .map(|analysis| analysis.parse().map_err(DictionaryError::InvalidAnalysis))
+                        .collect::<Result<Vec<ColumnAnalysis>, DictionaryError>>()
+                })
+                .transpose()?
+                .unwrap_or_default();
+            columns.insert(
+                name.to_owned(),
+                ColumnDefinition {
+                    description: value_at(descriptions, index),
+                    unit: value_at(units, index),
+                    owner: value_at(owners, index),
+                    tags,
+                    skip,
+                },
+            );
+        }
+
+        Ok(Self { columns })
+    }
+}
+
+/// Masks a value of a PII-tagged column for display, keeping only its first character (e.g.
+/// "jane@example.com" becomes "j***************"). Single character values are masked entirely.
+pub fn mask_value(value: &str) -> String {
+    let n_chars = value.chars().count();
+    match value.chars().next() {
+        Some(first) if n_chars > 1 => std::iter::once(first)
+            .chain(std::iter::repeat_n('*', n_chars - 1))
+            .collect(),
+        _ => "*".repeat(n_chars),
+    }
+}
+
+/// Hashes a value of a PII-tagged column for display, as the first 8 hex digits of its 64-bit
+/// FNV-1a hash (e.g. "#3f9a1c07"). The hash is stable across runs and platforms.
+pub fn hash_value(value: &str) -> String {
+    let hash = value.bytes().fold(0xcbf29ce484222325_u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+    });
+    format!("#{:08x}", hash >> 32)
+}
@@ -0,0 +1,505 @@
+//! # Drift Module
+//!
+//! This module handles the distribution profile of a dataset and the population stability index
+//! (PSI) of each column against a baseline profile from a previous run.
+//!
+//! A profile stores the distribution of each column as the proportions of its rows in a fixed set
+//! of buckets, with missing values in their own bucket:
+//!
+//! - Numerical columns are split into `PROFILE_BINS` equal frequency bins, by the inner quantiles
+//!   of the values (deduplicated so ties stay in the same bin).
+//! - Categorical columns keep their `MAX_PROFILE_CATEGORIES` most frequent categories, with the
+//!   rest grouped into an other bucket. The categories of PII-tagged columns are stored hashed, so
+//!   the exported profile doesn't hold their values.
+//!
+//! The current data is bucketed with the baseline bins and categories, and the PSI of a column is
+//! `sum((actual - expected) * ln(actual / expected))` over the buckets, with the proportions
+//! floored at `MIN_PROPORTION`. A PSI of at least `MODERATE_DRIFT` is a moderate shift and a PSI
+//! of at least `SIGNIFICANT_DRIFT` a significant one.
+
+use super::dictionary::hash_value;
+use chrono::Utc;
+use indexmap::IndexMap;
+use polars::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::path::Path;
+use thiserror::Error;
+
+/// Number of equal frequency bins of the numerical columns.
+pub const PROFILE_BINS: usize = 10;
+/// Maximum number of categories kept per categorical column.
+pub const MAX_PROFILE_CATEGORIES: usize = 20;
+/// PSI from which a column has moderately drifted.
+pub const MODERATE_DRIFT: f64 = 0.1;
+/// PSI from which a column has significantly drifted.
+pub const SIGNIFICANT_DRIFT: f64 = 0.25;
+/// Floor for the bucket proportions, so empty buckets don't make the PSI infinite.
+pub const MIN_PROPORTION: f64 = 1e-4;
+
+/// The error types for the drift module.
+#[derive(Error, Debug)]
+pub enum DriftError {
+    /// Occurs when Polars fails to complete an operation.
+    #[error("Polars error: {0}")]
+    Polars(#[from] PolarsError),
+
+    /// Occurs when an I/O operation fails.
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// Occurs when a profile can't be serialized or parsed.
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+/// The distribution of a single column.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ColumnProfile {
+    /// A numerical column.
+    Numeric {
+        /// The inner bin edges, in ascending order. A value falls in the first bin whose upper
+        /// edge is greater than it.
+        edges: Vec<f64>,
+        /// The proportion of rows in each bin.
+        proportions: Vec<f64>,
+        /// The proportion of missing (or non-finite) values.
+        missing: f64,
+    },
+    /// A categorical column.
+    Categorical {
+        /// The proportion of rows of each kept category (hashed for PII-tagged columns).
+        proportions: IndexMap<String, f64>,
+        /// The proportion of rows in the other categories.
+        other: f64,
+        /// The proportion of missing values.
+        missing: f64,
+    },
+}
+
+impl ColumnProfile {
+    /// Profiles the values of a column, or None if the data type isn't supported.
+    fn new(series: &Series, hashed: bool) -> Result<Option<Self>, DriftError> {
+        let dtype = series.dtype();
+        if dtype.is_numeric() || dtype.is_decimal() {
+            let values = finite_values(series)?;
+            let mut sorted: Vec<f64> = values.iter().flatten().copied().collect();
+            sorted.sort_by(|a, b| a.total_cmp(b));
+            let mut edges: Vec<f64> = (1..PROFILE_BINS)
+                .filter_map(|bin| sorted.get(bin * sorted.len() / PROFILE_BINS).copied())
+                .collect();
+            edges.dedup();
+            let profile = ColumnProfile::Numeric {
+                proportions: vec![0.0; edges.len() + 1],
+                edges,
+                missing: 0.0,
+            };
+            Ok(Some(profile.bucket_numeric(&values)))
+        } else if is_categorical(dtype) {
+            let counts = category_counts(series, hashed)?;
+            let mut categories: Vec<(&String, &u64)> = counts.iter().collect();
+            categories.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.cmp(b.0)));
+            let profile = ColumnProfile::Categorical {
+                proportions: categories
+                    .into_iter()
+                    .take(MAX_PROFILE_CATEGORIES)
+                    .map(|(category, _)| (category.clone(), 0.0))
+                    .collect(),
+                other: 0.0,
+                missing: 0.0,
+            };
+            Ok(Some(profile.bucket_categories(
+                &counts,
+                series.null_count(),
+                series.len(),
+            )))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Buckets the values of a column with the bins or categories of the profile, or None if the
+    /// column's data type doesn't match the profile.
+    fn project(&self, series: &Series, hashed: bool) -> Result<Option<Self>, DriftError> {
+        let dtype = series.dtype();
+        match self {
+            ColumnProfile::Numeric { .. } if dtype.is_numeric() || dtype.is_decimal() => {
+                Ok(Some(self.bucket_numeric(&finite_values(series)?)))
+            }
+            ColumnProfile::Categorical { .. } if is_categorical(dtype) => {
+                Ok(Some(self.bucket_categories(
+                    &category_counts(series, hashed)?,
+                    series.null_count(),
+                    series.len(),
+                )))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Gets the proportions of all the buckets, including the other and missing buckets.
+    pub fn buckets(&self) -> Vec<f64> {
+        match self {
+            ColumnProfile::Numeric {
+                proportions,
+                missing,
+                ..
+            } => proportions.iter().copied().chain([*missing]).collect(),
+            ColumnProfile::Categorical {
+                proportions,
+                other,
+                missing,
+            } => proportions
+                .values()
+                .copied()
+                .chain([*other, *missing])
+                .collect(),
+        }
+    }
+
+    /// Gets the name of the profile kind.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            ColumnProfile::Numeric { .. } => "numeric",
+            ColumnProfile::Categorical { .. } => "categorical",
+        }
+    }
+
+    /// Buckets numerical values with the bin edges of the profile.
+    fn bucket_numeric(&self, values: &[Option<f64>]) -> Self {
+        let ColumnProfile::Numeric { edges, .. } = self else {
+            return self.clone();
+        };
+        let mut counts = vec![0_u64; edges.len() + 1];
+        let mut n_missing = 0_u64;
+        for value in values {
+            match value {
+                Some(value) => counts[edges.partition_point(|edge| edge <= value)] += 1,
+                None => n_missing += 1,
+            }
+        }
+        let n_rows = values.len().max(1) as f64;
+        ColumnProfile::Numeric {
+            edges: edges.clone(),
+            proportions: counts
+                .into_iter()
+                .map(|count| count as f64 / n_rows)
+                .collect(),
+            missing: n_missing as f64 / n_rows,
+        }
+    }
+
+    /// Buckets category counts with the categories of the profile.
+    fn bucket_categories(
+        &self,
+        counts: &HashMap<String, u64>,
+        n_missing: usize,
+        n_rows: usize,
+    ) -> Self {
+        let ColumnProfile::Categorical { proportions, .. } = self else {
+            return self.clone();
+        };
+        let n_rows = n_rows.max(1) as f64;
+        let kept: u64 = proportions
+            .keys()
+            .filter_map(|category| counts.get(category))
+            .sum();
+        let total: u64 = counts.values().sum();
+        ColumnProfile::Categorical {
+            proportions: proportions
+                .keys()
+                .map(|category| {
+                    let count = counts.get(category).copied().unwrap_or(0);
+                    (category.clone(), count as f64 / n_rows)
+                })
+                .collect(),
+            other: (total - kept) as f64 / n_rows,
+            missing: n_missing as f64 / n_rows,
+        }
+    }
+}
+
+/// The distribution profile of a dataset, saved after a run to serve as the baseline of a later
+/// one.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Profile {
+    /// Name of the dataset.
+    pub dataset: String,
+    /// When the profile was created, as an RFC 3339 timestamp.
+    pub created_at: String,
+    /// The number of rows in the data.
+    pub n_rows: u64,
+    /// The distribution of each supported column, keyed by column name.
+    pub columns: IndexMap<String, ColumnProfile>,
+}
+
+impl Profile {
+    /// Constructor for the Profile struct.
+    ///
+    /// ### Parameters
+    ///
+    /// - `lazy_df`: The dataset.
+    /// - `schema`: The schema of the columns to profile.
+    /// - `dataset`: The name of the dataset.
+    /// - `hashed_columns`: The columns whose categories are stored hashed.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<Profile, DriftError>`: The profile or a propagated DriftError.
+    pub fn new(
+        lazy_df: &LazyFrame,
+        schema: &Schema,
+        dataset: &str,
+        hashed_columns: &[String],
+    ) -> Result<Self, DriftError> {
+        let df = lazy_df.clone().select(columns(schema)).collect()?;
+        let mut columns = IndexMap::new();
+        for series in df.get_columns() {
+            let hashed = hashed_columns.iter().any(|name| name == series.name());
+            if let Some(profile) = ColumnProfile::new(series, hashed)? {
+                columns.insert(series.name().to_owned(), profile);
+            }
+        }
+        Ok(Self {
+            dataset: dataset.to_owned(),
+            created_at: Utc::now().to_rfc3339(),
+            n_rows: df.height() as u64,
+            columns,
+        })
+    }
+
+    /// Loads a profile from a JSON file.
+    ///
+    /// ### Parameters
+    ///
+    /// - `path`: The path to the profile.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<Profile, DriftError>`: The profile or a propagated DriftError.
+    pub fn from_file(path: &Path) -> Result<Self, DriftError> {
+        Ok(serde_json::from_reader(BufReader::new(File::open(path)?))?)
+    }
+
+    /// Saves the profile as JSON.
+    ///
+    /// ### Parameters
+    ///
+    /// - `path`: The path to save the profile to.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<(), DriftError>`: Unit type or a propagated DriftError.
+    pub fn save_to_file(&self, path: &Path) -> Result<(), DriftError> {
+        serde_json::to_writer_pretty(BufWriter::new(File::create(path)?), self)?;
+        Ok(())
+    }
+}
+
+/// How much a column has drifted from the baseline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DriftLevel {
+    /// A PSI below `MODERATE_DRIFT`.
+    Stable,
+    /// A PSI from `MODERATE_DRIFT` up to `SIGNIFICANT_DRIFT`.
+    Moderate,
+    /// A PSI of at least `SIGNIFICANT_DRIFT`.
+    Significant,
+}
+
+impl DriftLevel {
+    /// Gets the drift level of a PSI.
+    pub fn from_psi(psi: f64) -> Self {
+        if psi >= SIGNIFICANT_DRIFT {
+            DriftLevel::Significant
+        } else if psi >= MODERATE_DRIFT {
+            DriftLevel::Moderate
+        } else {
+            DriftLevel::Stable
+        }
+    }
+}
+
+impl fmt::Display for DriftLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            DriftLevel::Stable => "stable",
+            DriftLevel::Moderate => "moderate",
+            DriftLevel::Significant => "significant",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// The drift of a single column from the baseline.
+#[derive(Debug, Clone)]
+pub struct ColumnDrift {
+    /// The population stability index.
+    pub psi: f64,
+    /// The drift level of the PSI.
+    pub level: DriftLevel,
+    /// The bucket proportions of the baseline.
+    pub expected: Vec<f64>,
+    /// The bucket proportions of the current data.
+    pub actual: Vec<f64>,
+}
+
+/// Holds the drift of the dataset columns from a baseline profile.
+#[derive(Debug, Clone)]
+pub struct DriftAnalysis {
+    /// Name of the baseline dataset.
+    pub baseline: String,
+    /// When the baseline profile was created.
+    pub baseline_created_at: String,
+    /// The number of rows in the baseline.
+    pub baseline_rows: u64,
+    /// The drift of each column in both the baseline and the current data, keyed by column name.
+    pub columns: IndexMap<String, ColumnDrift>,
+    /// The baseline columns that aren't in the current data.
+    pub missing_columns: Vec<String>,
+    /// The current columns that aren't in the baseline.
+    pub new_columns: Vec<String>,
+    /// The columns whose kind changed between the baseline and the current data, with the
+    /// baseline kind.
+    pub changed_columns: Vec<(String, String)>,
+}
+
+impl DriftAnalysis {
+    /// Constructor for the DriftAnalysis struct.
+    ///
+    /// ### Parameters
+    ///
+    /// - `lazy_df`: The dataset.
+    /// - `schema`: The schema of the columns to compare.
+    /// - `baseline`: The baseline profile.
+    /// - `hashed_columns`: The columns whose categories are stored hashed.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<DriftAnalysis, DriftError>`: The drift analysis or a propagated DriftError.
+    pub fn new(
+        lazy_df: &LazyFrame,
+        schema: &Schema,
+        baseline: &Profile,
+        hashed_columns: &[String],
+    ) -> Result<Self, DriftError> {
+        let shared_schema: Schema = schema
+            .iter()
+            .filter(|(name, _)| baseline.columns.contains_key(name.as_str()))
+            .map(|(name, dtype)| Field::new(name, dtype.clone()))
+            .collect();
+        let df = lazy_df.clone().select(columns(&shared_schema)).collect()?;
+
+        let mut columns = IndexMap::new();
+        let mut changed_columns = Vec::new();
+        for series in df.get_columns() {
+            let Some(expected) = baseline.columns.get(series.name()) else {
+                continue;
+            };
+            let hashed = hashed_columns.iter().any(|name| name == series.name());
+            match expected.project(series, hashed)? {
+                Some(actual) => {
+                    let expected = expected.buckets();
+                    let actual = actual.buckets();
+                    let psi = population_stability_index(&expected, &actual);
+                    columns.insert(
+                        series.name().to_owned(),
+                        ColumnDrift {
+                            psi,
+                            level: DriftLevel::from_psi(psi),
+                            expected,
+                            actual,
+                        },
+                    );
+                }
+                None => {
+                    changed_columns.push((series.name().to_owned(), expected.kind().to_owned()))
+                }
+            }
+        }
+
+        Ok(Self {
+            baseline: baseline.dataset.clone(),
+            baseline_created_at: baseline.created_at.clone(),
+            baseline_rows: baseline.n_rows,
+            columns,
+            missing_columns: baseline
+                .columns
+                .keys()
+                .filter(|name| schema.get(name).is_none())
+                .cloned()
+                .collect(),
+            new_columns: schema
+                .iter_names()
+                .filter(|name| !baseline.columns.contains_key(name.as_str()))
+                .map(|name| name.to_string())
+                .collect(),
+            changed_columns,
+        })
+    }
+
+    /// Gets the columns that drifted at least moderately, with the most drifted first.
+    pub fn drifted(&self) -> Vec<(&String, &ColumnDrift)> {
+        let mut drifted: Vec<(&String, &ColumnDrift)> = self
+            .columns
+            .iter()
+            .filter(|(_, drift)| drift.level > DriftLevel::Stable)
+            .collect();
+        drifted.sort_by(|a, b| b.1.psi.total_cmp(&a.1.psi));
+        drifted
+    }
+}
+
+/// Computes the population stability index between the expected and actual bucket proportions.
+pub fn population_stability_index(expected: &[f64], actual: &[f64]) -> f64 {
+    expected
+        .iter()
+        .zip(actual)
+        .map(|(expected, actual)| {
+            let expected = expected.max(MIN_PROPORTION);
+            let actual = actual.max(MIN_PROPORTION);
+            (actual - expected) * (actual / expected).ln()
+        })
+        .sum()
+}
+
+/// Selects the columns of a schema.
+fn columns(schema: &Schema) -> Vec<Expr> {
+    schema.iter_names().map(|name| col(name)).collect()
+}
+
+/// Checks whether a data type is profiled as categorical.
+fn is_categorical(dtype: &DataType) -> bool {
+    matches!(
+        dtype,
+        DataType::String | DataType::Boolean | DataType::Categorical(_, _) | DataType::Enum(_, _)
+    )
+}
+
+/// Gets the finite values of a numerical column, with missing and non-finite values as None.
+fn finite_values(series: &Series) -> Result<Vec<Option<f64>>, DriftError> {
+    Ok(series
+        .cast(&DataType::Float64)?
+        .f64()?
+        .into_iter()
+        .map(|value| value.filter(|value| value.is_finite()))
+        .collect())
+}
+
+/// Counts the non-missing values of a categorical column, hashing them if required.
+fn category_counts(series: &Series, hashed: bool) -> Result<HashMap<String, u64>, DriftError> {
+    let mut counts: HashMap<String, u64> = HashMap::new();
+    for value in series.cast(&DataType::String)?.str()?.into_iter().flatten() {
+        let category = if hashed {
+            hash_value(value)
+        } else {
+            value.to_owned()
+        };
+        *counts.entry(category).or_default() += 1;
+    }
+    Ok(counts)
+}
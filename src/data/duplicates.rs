@@ -0,0 +1,636 @@
+//! # Duplicates Module
+//!
+//! This module handles detecting duplicated rows, both full-row duplicates and violations of a
+//! user-specified uniqueness key (e.g. a primary key).
+//!
+//! A full-row duplicate is a row identical to an earlier row across all the columns that can be
+//! compared (list, array, and binary columns are left out). A key violation is a row that
+//! shares its key values with another row. Rows with a missing key value are counted separately,
+//! since they can't be identified by the key at all. The `MAX_OFFENDERS` key values shared by the
+//! most rows are kept as the worst offenders.
+//!
+//! Duplicated columns are also detected: a column whose values are identical to an earlier
+//! column, or for numerical columns, equal within a relative tolerance (e.g. the same measure
+//! stored with a different precision) or a constant multiple of them (e.g. a column B = 1000 × A
+//! converting units), which suggests a derived column. Missing values must line up in both
+//! columns. The candidates are screened on the first `COLUMN_SCREEN_ROWS` rows and confirmed on the
+//! whole dataset, and columns with fewer than two distinct values among the screened rows are left
+//! out (constant columns are flagged by the descriptive analysis).
+//!
+//! Key candidates are the columns, or pairs of columns, whose values identify every row, found
+//! without a user-specified key. Floating point columns are left out, since they make poor keys.
+//! Pairs are only tried among the `MAX_PAIR_COLUMNS` columns with the most distinct values that
+//! aren't candidates on their own, and only if their numbers of distinct values allow it. Columns
+//! and pairs whose duplicated and missing values make up at most `NEAR_KEY_RATIO` of the rows are
+//! kept as near candidates, e.g. a key broken by a few duplicated records.
+
+use polars::prelude::*;
+use std::fmt;
+use thiserror::Error;
+
+/// Maximum number of duplicated key values listed as the worst offenders.
+pub const MAX_OFFENDERS: usize = 10;
+
+/// The default relative tolerance within which numerical values are considered equal when
+/// comparing columns.
+pub const DEFAULT_COLUMN_TOLERANCE: f64 = 1e-6;
+
+/// The number of rows the duplicated column candidates are screened on.
+pub const COLUMN_SCREEN_ROWS: IdxSize = 10_000;
+
+/// The maximum share of the rows a near key candidate can't identify.
+pub const NEAR_KEY_RATIO: f64 = 0.01;
+
+/// The maximum number of columns the key candidate pairs are made of.
+pub const MAX_PAIR_COLUMNS: usize = 20;
+
+/// The maximum number of key candidates listed.
+pub const MAX_KEY_CANDIDATES: usize = 10;
+
+/// The error types for the duplicates module.
+#[derive(Error, Debug)]
+pub enum DuplicateError {
+    /// Occurs when Polars fails to complete an operation.
+    #[error("Polars error: {0}")]
+    Polars(#[from] PolarsError),
+
+    /// Occurs when a key column doesn't exist.
+    #[error("Non-existent key column: {0}")]
+    InvalidCol(String),
+}
+
+/// The violations of a uniqueness key.
+#[derive(Debug, Clone)]
+pub struct KeyViolations {
+    /// The key columns.
+    pub columns: Vec<String>,
+    /// The number of distinct key values shared by more than one row.
+    pub n_duplicate_keys: u64,
+    /// The number of rows that share their key values with another row.
+    pub n_violating_rows: u64,
+    /// The number of rows with a missing value in any key column.
+    pub n_null_keys: u64,
+    /// The key values shared by the most rows, with their values per key column and row count,
+    /// ordered from most to least rows.
+    pub worst_offenders: Vec<(Vec<String>, u64)>,
+}
+
+/// How a duplicated column relates to the earlier column it duplicates.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ColumnRelation {
+    /// The values are identical.
+    Identical,
+    /// The values are equal within the tolerance.
+    Equal,
+    /// The values are the source values times a factor.
+    Scaled(f64),
+}
+
+impl fmt::Display for ColumnRelation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ColumnRelation::Identical => write!(f, "identical"),
+            ColumnRelation::Equal => write!(f, "equal within tolerance"),
+            ColumnRelation::Scaled(factor) => write!(f, "{} × source", round_factor(*factor)),
+        }
+    }
+}
+
+/// Rounds a scaling factor to 7 significant digits, dropping the floating point noise of its
+/// estimate (e.g. 999.9999999999999 for 1000).
+pub fn round_factor(factor: f64) -> f64 {
+    format!("{:.6e}", factor).parse().unwrap_or(factor)
+}
+
+/// A column duplicating an earlier column.
+#[derive(Debug, Clone)]
+pub struct DuplicateColumn {
+    /// The duplicated (or derived) column.
+    pub column: String,
+    /// The earlier column it duplicates.
+    pub source: String,
+    /// How the column relates to its source.
+    pub relation: ColumnRelation,
+}
+
+/// A column, or pair of columns, that uniquely identifies the rows or nearly does.
+#[derive(Debug, Clone)]
+pub struct KeyCandidate {
+    /// The key columns.
+    pub columns: Vec<String>,
+    /// The number of rows whose key values repeat those of an earlier row.
+    pub n_duplicate_rows: u64,
+    /// The number of rows with a missing value in any key column.
+    pub n_null_keys: u64,
+}
+
+impl KeyCandidate {
+    /// Checks whether the columns uniquely identify every row.
+    pub fn is_unique(&self) -> bool {
+        self.n_duplicate_rows == 0 && self.n_null_keys == 0
+    }
+}
+
+impl KeyViolations {
+    /// Checks whether the key uniquely identifies every row.
+    pub fn is_valid(&self) -> bool {
+        self.n_violating_rows == 0 && self.n_null_keys == 0
+    }
+}
+
+/// Holds the duplicate detection results for a dataset.
+#[derive(Debug, Clone, Default)]
+pub struct DuplicateAnalysis {
+    /// The number of rows in the data.
+    pub n_rows: u64,
+    /// The number of rows identical to an earlier row.
+    pub n_duplicate_rows: u64,
+    /// The columns left out of the full-row comparison.
+    pub skipped_columns: Vec<String>,
+    /// The violations of the uniqueness key (if one was specified).
+    pub key_violations: Option<KeyViolations>,
+    /// The columns and column pairs that uniquely identify the rows or nearly do, the unique ones
+    /// first and single columns before pairs.
+    pub key_candidates: Vec<KeyCandidate>,
+    /// The columns duplicating an earlier column, in column order.
+    pub duplicate_columns: Vec<DuplicateColumn>,
+    /// The relative tolerance within which numerical values are considered equal.
+    pub column_tolerance: f64,
+}
+
+impl DuplicateAnalysis {
+    /// Constructor for the DuplicateAnalysis struct.
+    ///
+    /// ### Parameters
+    ///
+    /// - `lazy_df`: The dataset.
+    /// - `schema`: The schema of the dataset.
+    /// - `key_columns`: The columns of the uniqueness key, empty to only check full-row
+    ///   duplicates.
+    /// - `column_tolerance`: The relative tolerance within which numerical values are considered
+    ///   equal when comparing columns.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<DuplicateAnalysis, DuplicateError>`: The duplicate detection results or a
+    ///   propagated DuplicateError.
+    pub fn new(
+        lazy_df: &LazyFrame,
+        schema: &Schema,
+        key_columns: &[String],
+        column_tolerance: f64,
+    ) -> Result<Self, DuplicateError> {
+        if let Some(column) = key_columns
+            .iter()
+            .find(|column| schema.get(column).is_none())
+        {
+            return Err(DuplicateError::InvalidCol(column.clone()));
+        }
+
+        let (compared_columns, skipped_columns): (Vec<_>, Vec<_>) =
+            schema.iter().partition(|(_, dtype)| is_comparable(dtype));
+        let n_rows = count(lazy_df.clone())?;
+        let n_duplicate_rows = if compared_columns.is_empty() {
+            0
+        } else {
+            let compared_columns: Vec<Expr> =
+                compared_columns.iter().map(|(name, _)| col(name)).collect();
+            n_rows
+                - count(
+                    lazy_df
+                        .clone()
+                        .select(compared_columns)
+                        .unique(None, UniqueKeepStrategy::First),
+                )?
+        };
+
+        let key_violations = if key_columns.is_empty() {
+            None
+        } else {
+            Some(key_violations(lazy_df, key_columns)?)
+        };
+        let key_candidates = key_candidates(lazy_df, &compared_columns, n_rows)?;
+        let duplicate_columns = duplicate_columns(lazy_df, &compared_columns, column_tolerance)?;
+
+        Ok(Self {
+            n_rows,
+            n_duplicate_rows,
+            skipped_columns: skipped_columns
+                .into_iter()
+                .map(|(name, _)| name.to_string())
+                .collect(),
+            key_violations,
+            key_candidates,
+            duplicate_columns,
+            column_tolerance,
+        })
+    }
+
+    /// Gets the percentage of rows identical to an earlier row.
+    pub fn duplicate_percentage(&self) -> f64 {
+        if self.n_rows == 0 {
+            0.0
+        } else {
+            self.n_duplicate_rows as f64 / self.n_rows as f64 * 100.0
+        }
+    }
+}
+
+/// Checks the uniqueness of the key columns.
+fn key_violations(
+    lazy_df: &LazyFrame,
+    key_columns: &[String],
+) -> Result<KeyViolations, DuplicateError> {
+    let keys: Vec<Expr> = key_columns
+        .iter()
+        .map(|column| col(column).cast(DataType::String))
+        .collect();
+    let key_exprs: Vec<Expr> = key_columns.iter().map(|column| col(column)).collect();
+    let n_null_keys = count(
+        lazy_df.clone().select(keys.clone()).filter(any_horizontal(
+            key_exprs
+                .iter()
+                .map(|column| column.clone().is_null())
+                .collect::<Vec<Expr>>(),
+        )?),
+    )?;
+
+    let duplicates = lazy_df
+        .clone()
+        .select(keys)
+        .drop_nulls(None)
+        .group_by(key_exprs)
+        .agg([len().cast(DataType::UInt64).alias("__n_rows")])
+        .filter(col("__n_rows").gt(lit(1)))
+        .sort(
+            ["__n_rows"],
+            SortMultipleOptions::default().with_order_descending(true),
+        )
+        .collect()?;
+    let n_rows = duplicates.column("__n_rows")?.u64()?;
+    let key_values = key_columns
+        .iter()
+        .map(|column| Ok(duplicates.column(column)?.str()?.clone()))
+        .collect::<Result<Vec<StringChunked>, DuplicateError>>()?;
+    let worst_offenders = (0..duplicates.height().min(MAX_OFFENDERS))
+        .map(|row| {
+            (
+                key_values
+                    .iter()
+                    .map(|values| values.get(row).unwrap_or_default().to_owned())
+                    .collect(),
+                n_rows.get(row).unwrap_or(0),
+            )
+        })
+        .collect();
+
+    Ok(KeyViolations {
+        columns: key_columns.to_vec(),
+        n_duplicate_keys: duplicates.height() as u64,
+        n_violating_rows: n_rows.sum().unwrap_or(0),
+        n_null_keys,
+        worst_offenders,
+    })
+}
+
+/// Finds the columns and column pairs that uniquely identify the rows, or nearly do.
+fn key_candidates(
+    lazy_df: &LazyFrame,
+    columns: &[(&SmartString, &DataType)],
+    n_rows: u64,
+) -> Result<Vec<KeyCandidate>, DuplicateError> {
+    let columns: Vec<&str> = columns
+        .iter()
+        .filter(|(_, dtype)| !dtype.is_float())
+        .map(|(name, _)| name.as_str())
+        .collect();
+    if n_rows < 2 || columns.is_empty() {
+        return Ok(Vec::new());
+    }
+    let max_unidentified = (n_rows as f64 * NEAR_KEY_RATIO).floor() as u64;
+
+    let singles: Vec<Vec<&str>> = columns.iter().map(|column| vec![*column]).collect();
+    let stats = key_stats(lazy_df, &singles)?;
+    let mut candidates = Vec::new();
+    let mut pair_columns = Vec::new();
+    for (key, (n_distinct, n_null_keys)) in singles.into_iter().zip(stats) {
+        let n_duplicate_rows = n_rows - n_null_keys - n_distinct;
+        if n_duplicate_rows + n_null_keys <= max_unidentified {
+            candidates.push(KeyCandidate {
+                columns: vec![key[0].to_owned()],
+                n_duplicate_rows,
+                n_null_keys,
+            });
+        } else if n_distinct > 1 {
+            // Pairs with a candidate column add little to the column on its own, and a single
+            // distinct value adds nothing to the other column.
+            pair_columns.push((key[0], n_distinct));
+        }
+    }
+
+    pair_columns.sort_by_key(|(_, n_distinct)| std::cmp::Reverse(*n_distinct));
+    pair_columns.truncate(MAX_PAIR_COLUMNS);
+    let mut pairs = Vec::new();
+    for (i, (first, first_distinct)) in pair_columns.iter().enumerate() {
+        for (second, second_distinct) in &pair_columns[i + 1..] {
+            // A pair can't tell apart more rows than it has combinations of values.
+            if first_distinct.saturating_mul(*second_distinct) + max_unidentified >= n_rows {
+                pairs.push(vec![*first, *second]);
+            }
+        }
+    }
+    let stats = key_stats(lazy_df, &pairs)?;
+    for (key, (n_distinct, n_null_keys)) in pairs.into_iter().zip(stats) {
+        let n_duplicate_rows = n_rows - n_null_keys - n_distinct;
+        if n_duplicate_rows + n_null_keys <= max_unidentified {
+            candidates.push(KeyCandidate {
+                columns: key.into_iter().map(str::to_owned).collect(),
+                n_duplicate_rows,
+                n_null_keys,
+            });
+        }
+    }
+
+    // The sort is stable, so single columns stay ahead of pairs and in column order.
+    candidates.sort_by_key(|candidate| {
+        (
+            !candidate.is_unique(),
+            candidate.columns.len(),
+            candidate.n_duplicate_rows + candidate.n_null_keys,
+        )
+    });
+    candidates.truncate(MAX_KEY_CANDIDATES);
+    Ok(candidates)
+}
+
+/// Counts the distinct values and the rows with a missing value of each key, in one pass.
+///
+/// ### Parameters
+///
+/// - `lazy_df`: The dataset.
+/// - `keys`: The keys, each a list of columns.
+///
+/// ### Returns
+///
+/// - `Result<Vec<(u64, u64)>, DuplicateError>`: The number of distinct non-missing key values and
+///   the number of rows with a missing key value, per key.
+fn key_stats(lazy_df: &LazyFrame, keys: &[Vec<&str>]) -> Result<Vec<(u64, u64)>, DuplicateError> {
+    if keys.is_empty() {
+        return Ok(Vec::new());
+    }
+    let mut exprs = Vec::with_capacity(keys.len() * 2);
+    for (i, key) in keys.iter().enumerate() {
+        let is_null = key
+            .iter()
+            .map(|column| col(column).is_null())
+            .reduce(|a, b| a.or(b))
+            .unwrap_or(lit(false));
+        let values = match key.as_slice() {
+            [column] => col(column),
+            _ => as_struct(key.iter().map(|column| col(column)).collect()),
+        };
+        exprs.push(
+            values
+                .filter(is_null.clone().not())
+                .n_unique()
+                .cast(DataType::UInt64)
+                .alias(&format!("__distinct_{}", i)),
+        );
+        exprs.push(
+            is_null
+                .sum()
+                .cast(DataType::UInt64)
+                .alias(&format!("__nulls_{}", i)),
+        );
+    }
+    let stats = lazy_df.clone().select(exprs).collect()?;
+    (0..keys.len())
+        .map(|i| {
+            let get = |name: String| -> Result<u64, DuplicateError> {
+                Ok(stats.column(&name)?.u64()?.get(0).unwrap_or(0))
+            };
+            Ok((
+                get(format!("__distinct_{}", i))?,
+                get(format!("__nulls_{}", i))?,
+            ))
+        })
+        .collect()
+}
+
+/// Finds the columns duplicating an earlier column.
+fn duplicate_columns(
+    lazy_df: &LazyFrame,
+    columns: &[(&SmartString, &DataType)],
+    tolerance: f64,
+) -> Result<Vec<DuplicateColumn>, DuplicateError> {
+    if columns.len() < 2 {
+        return Ok(Vec::new());
+    }
+    let sample = lazy_df
+        .clone()
+        .select(
+            columns
+                .iter()
+                .map(|(name, _)| col(name))
+                .collect::<Vec<Expr>>(),
+        )
+        .limit(COLUMN_SCREEN_ROWS)
+        .collect()?;
+
+    let mut numeric = Vec::new();
+    let mut others = Vec::new();
+    for (name, dtype) in columns {
+        let series = sample.column(name)?;
+        if series.drop_nulls().n_unique()? < 2 {
+            continue;
+        }
+        if dtype.is_numeric() || dtype.is_decimal() {
+            let values: Vec<Option<f64>> = series
+                .cast(&DataType::Float64)?
+                .f64()?
+                .into_iter()
+                .collect();
+            // The factor of a scaled column is estimated where the source is largest.
+            let pivot = values
+                .iter()
+                .enumerate()
+                .filter_map(|(row, value)| value.map(|value| (row, value.abs())))
+                .max_by(|a, b| a.1.total_cmp(&b.1))
+                .map(|(row, _)| row);
+            numeric.push((name.as_str(), values, pivot));
+        } else {
+            others.push((name.as_str(), series.cast(&DataType::String)?));
+        }
+    }
+
+    // Each column is matched against the earliest earlier column it duplicates, skipping the
+    // columns already matched, so a group of duplicates is reported against its first column.
+    // The candidates are kept with whether they're numerical.
+    let mut candidates: Vec<(DuplicateColumn, bool)> = Vec::new();
+    let is_source = |candidates: &[(DuplicateColumn, bool)], name: &str| {
+        !candidates
+            .iter()
+            .any(|(candidate, _)| candidate.column == name)
+    };
+    for (j, (name, values, _)) in numeric.iter().enumerate() {
+        let source = numeric[..j]
+            .iter()
+            .filter(|(source, _, _)| is_source(&candidates, source))
+            .find_map(|(source, source_values, pivot)| {
+                numeric_relation(source_values, values, (*pivot)?, tolerance)
+                    .map(|relation| (source, relation))
+            });
+        if let Some((source, relation)) = source {
+            candidates.push((
+                DuplicateColumn {
+                    column: name.to_string(),
+                    source: source.to_string(),
+                    relation,
+                },
+                true,
+            ));
+        }
+    }
+    for (j, (name, values)) in others.iter().enumerate() {
+        let source = others[..j]
+            .iter()
+            .filter(|(source, _)| is_source(&candidates, source))
+            .find(|(_, source_values)| source_values.equals_missing(values));
+        if let Some((source, _)) = source {
+            candidates.push((
+                DuplicateColumn {
+                    column: name.to_string(),
+                    source: source.to_string(),
+                    relation: ColumnRelation::Identical,
+                },
+                false,
+            ));
+        }
+    }
+    if candidates.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    // Confirm the candidates on the whole dataset by counting the rows breaking the relation.
+    let mismatches = lazy_df
+        .clone()
+        .select(
+            candidates
+                .iter()
+                .enumerate()
+                .map(|(i, (candidate, numeric))| {
+                    mismatched_rows(candidate, *numeric, tolerance)
+                        .sum()
+                        .cast(DataType::UInt64)
+                        .alias(&format!("__mismatches_{}", i))
+                })
+                .collect::<Vec<Expr>>(),
+        )
+        .collect()?;
+    let mut duplicate_columns = Vec::with_capacity(candidates.len());
+    for (i, (candidate, _)) in candidates.into_iter().enumerate() {
+        let n_mismatches = mismatches
+            .column(&format!("__mismatches_{}", i))?
+            .u64()?
+            .get(0)
+            .unwrap_or(0);
+        if n_mismatches == 0 {
+            duplicate_columns.push(candidate);
+        }
+    }
+    Ok(duplicate_columns)
+}
+
+/// Finds how the values of a numerical column relate to the values of a source column, if at all.
+///
+/// ### Parameters
+///
+/// - `source`: The source values.
+/// - `values`: The column values.
+/// - `pivot`: The row of the largest absolute source value, which the scaling factor is estimated
+///   from.
+/// - `tolerance`: The relative tolerance within which values are considered equal.
+///
+/// ### Returns
+///
+/// - `Option<ColumnRelation>`: The relation, or None if the column isn't derived from the source.
+fn numeric_relation(
+    source: &[Option<f64>],
+    values: &[Option<f64>],
+    pivot: usize,
+    tolerance: f64,
+) -> Option<ColumnRelation> {
+    let factor = values[pivot]? / source[pivot]?;
+    if factor == 0.0 || !factor.is_finite() {
+        return None;
+    }
+    let (mut identical, mut equal) = (true, true);
+    for (source, value) in source.iter().zip(values) {
+        match (source, value) {
+            (None, None) => {}
+            (Some(source), Some(value)) => {
+                if !approx_eq(*value, factor * source, tolerance) {
+                    return None;
+                }
+                identical &= value == source;
+                equal &= approx_eq(*value, *source, tolerance);
+            }
+            _ => return None,
+        }
+    }
+    Some(if identical {
+        ColumnRelation::Identical
+    } else if equal {
+        ColumnRelation::Equal
+    } else {
+        ColumnRelation::Scaled(factor)
+    })
+}
+
+/// Checks whether two values are equal within a relative tolerance.
+fn approx_eq(a: f64, b: f64, tolerance: f64) -> bool {
+    a == b || (a - b).abs() <= tolerance * a.abs().max(b.abs())
+}
+
+/// Builds the expression flagging the rows that break the relation of a duplicated column.
+fn mismatched_rows(candidate: &DuplicateColumn, numeric: bool, tolerance: f64) -> Expr {
+    let (source, column) = (col(&candidate.source), col(&candidate.column));
+    if !numeric {
+        return source
+            .cast(DataType::String)
+            .eq_missing(column.cast(DataType::String))
+            .not();
+    }
+    let (factor, tolerance) = match candidate.relation {
+        ColumnRelation::Identical => (1.0, 0.0),
+        ColumnRelation::Equal => (1.0, tolerance),
+        ColumnRelation::Scaled(factor) => (factor, tolerance),
+    };
+    let expected = source.clone().cast(DataType::Float64) * lit(factor);
+    let actual = column.clone().cast(DataType::Float64);
+    // The tolerance check `|actual - expected| > tolerance * max(|actual|, |expected|)` is
+    // compared squared, so no absolute values are needed.
+    let square = |expr: Expr| expr.clone() * expr;
+    let largest_square = when(square(actual.clone()).gt(square(expected.clone())))
+        .then(square(actual.clone()))
+        .otherwise(square(expected.clone()));
+    let value_mismatch = actual
+        .clone()
+        .neq(expected.clone())
+        .and(square(actual - expected).gt(lit(tolerance * tolerance) * largest_square))
+        .fill_null(lit(false));
+    source.is_null().neq(column.is_null()).or(value_mismatch)
+}
+
+/// Checks whether the values of a data type can be compared to find duplicated rows.
+fn is_comparable(dtype: &DataType) -> bool {
+    !(dtype.is_nested() || matches!(dtype, DataType::Binary | DataType::BinaryOffset))
+}
+
+/// Counts the rows of a lazy frame.
+fn count(lazy_df: LazyFrame) -> Result<u64, DuplicateError> {
+    let df = lazy_df
+        .select([len().cast(DataType::UInt64).alias("__count")])
+        .collect()?;
+    Ok(df.column("__count")?.u64()?.get(0).unwrap_or(0))
+}
@@ -0,0 +1,222 @@
+//! # Freshness Module
+//!
+//! This module handles the data freshness analysis of a dataset, where one datetime column is
+//! designated as the record timestamp (when the event a record describes happened). It answers
+//! the data-ops questions of how recent the newest record is and how long records take to land.
+//!
+//! - Staleness: the time between the newest record timestamp and the time of the run. The data
+//!   is stale when the staleness is above the freshness threshold.
+//! - Ingestion lag: the time between the record timestamp and when the record was ingested, read
+//!   from an ingestion timestamp column if one is given. Without one, the lag of each record is
+//!   its age at the time of the run.
+//!
+//! The lag distribution is summarized by its quantiles, along with the number of records lagging
+//! more than the threshold and the number of records timestamped after their ingestion (or after
+//! the run), which point to clock skew or time zone issues.
+//!
+//! The timestamp columns can be date, datetime, or string columns, parsed the same way as the
+//! time series index. Rows with a missing or unparseable timestamp are excluded.
+
+use crate::data::time_series::{format_duration, parse_duration, timestamps, TimeSeriesError};
+use indexmap::IndexMap;
+use polars::prelude::*;
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// The default freshness threshold (one day), in milliseconds.
+pub const DEFAULT_FRESHNESS_THRESHOLD: FreshnessThreshold = FreshnessThreshold(86_400_000);
+/// The quantiles of the lag distribution, with their labels.
+pub const LAG_QUANTILES: [(&str, f64); 5] = [
+    ("min", 0.0),
+    ("p50", 0.5),
+    ("p90", 0.9),
+    ("p99", 0.99),
+    ("max", 1.0),
+];
+
+/// The error types for the freshness module.
+#[derive(Error, Debug)]
+pub enum FreshnessError {
+    /// Occurs when Polars fails to complete an operation.
+    #[error("Polars error: {0}")]
+    Polars(#[from] PolarsError),
+
+    /// Occurs when a timestamp column can't be converted to timestamps.
+    #[error("Timestamp error: {0}")]
+    Timestamp(#[from] TimeSeriesError),
+
+    /// Occurs when a timestamp column isn't in the dataset.
+    #[error("Timestamp column not found in the dataset: {0}")]
+    UnknownColumn(String),
+
+    /// Occurs when a timestamp column can't hold timestamps.
+    #[error("Timestamp column '{0}' has unsupported data type: {1}")]
+    UnsupportedColumn(String, DataType),
+}
+
+/// The maximum staleness of fresh data, in milliseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FreshnessThreshold(pub i64);
+
+impl Default for FreshnessThreshold {
+    fn default() -> Self {
+        DEFAULT_FRESHNESS_THRESHOLD
+    }
+}
+
+impl FromStr for FreshnessThreshold {
+    type Err = String;
+
+    /// Parses a duration made of amounts with a unit (`d`, `h`, `m`, `s`, or `ms`), e.g. `24h`,
+    /// `1d 6h`, or `90m`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match parse_duration(s) {
+            Some(duration) if duration > 0 => Ok(FreshnessThreshold(duration)),
+            _ => Err(format!(
+                "Invalid freshness threshold: {}. Expected a duration like 24h, 1d 6h, or 90m.",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for FreshnessThreshold {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", format_duration(self.0))
+    }
+}
+
+/// Struct to hold the freshness analysis results.
+#[derive(Debug)]
+pub struct FreshnessAnalysis {
+    /// The record timestamp column name.
+    pub column: String,
+    /// The ingestion timestamp column name, if the lags are measured against one.
+    pub ingested_column: Option<String>,
+    /// The time of the run, in milliseconds since the Unix epoch.
+    pub reference_time: i64,
+    /// The freshness threshold, in milliseconds.
+    pub threshold: i64,
+    /// The number of rows with a valid record timestamp (and ingestion timestamp, if given).
+    pub n_timestamps: usize,
+    /// The oldest record timestamp, in milliseconds since the Unix epoch.
+    pub oldest: Option<i64>,
+    /// The newest record timestamp, in milliseconds since the Unix epoch.
+    pub newest: Option<i64>,
+    /// The quantiles of the lag distribution in milliseconds, keyed by their `LAG_QUANTILES`
+    /// label. Empty if there are no valid timestamps.
+    pub lag_quantiles: IndexMap<String, i64>,
+    /// The number of records with a lag above the threshold.
+    pub n_late: usize,
+    /// The number of records timestamped after their ingestion (or after the run).
+    pub n_future: usize,
+}
+
+impl FreshnessAnalysis {
+    /// Constructor for the FreshnessAnalysis struct.
+    ///
+    /// ### Parameters
+    ///
+    /// - `lazy_df`: Reference to the LazyFrame.
+    /// - `schema`: Reference to the lazy frame's schema.
+    /// - `column`: The record timestamp column name.
+    /// - `ingested_column`: The ingestion timestamp column name, None to measure the lags against
+    ///   the time of the run.
+    /// - `threshold`: The freshness threshold.
+    /// - `reference_time`: The time of the run, in milliseconds since the Unix epoch.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<Self, FreshnessError>`: A new FreshnessAnalysis instance or an error.
+    pub fn new(
+        lazy_df: &LazyFrame,
+        schema: &Schema,
+        column: &str,
+        ingested_column: Option<&str>,
+        threshold: FreshnessThreshold,
+        reference_time: i64,
+    ) -> Result<Self, FreshnessError> {
+        let record_times = column_timestamps(lazy_df, schema, column)?;
+        let ingested_times = ingested_column
+            .map(|ingested_column| column_timestamps(lazy_df, schema, ingested_column))
+            .transpose()?;
+
+        let pairs: Vec<(i64, i64)> = match &ingested_times {
+            Some(ingested_times) => record_times
+                .iter()
+                .zip(ingested_times)
+                .filter_map(|(record, ingested)| Some(((*record)?, (*ingested)?)))
+                .collect(),
+            None => record_times
+                .iter()
+                .flatten()
+                .map(|record| (*record, reference_time))
+                .collect(),
+        };
+        let mut lags: Vec<i64> = pairs
+            .iter()
+            .map(|(record, ingested)| ingested - record)
+            .collect();
+        lags.sort_unstable();
+
+        let lag_quantiles = if lags.is_empty() {
+            IndexMap::new()
+        } else {
+            LAG_QUANTILES
+                .iter()
+                .map(|(label, quantile)| {
+                    let index = ((lags.len() - 1) as f64 * quantile).round() as usize;
+                    (label.to_string(), lags[index])
+                })
+                .collect()
+        };
+
+        Ok(Self {
+            column: column.to_owned(),
+            ingested_column: ingested_column.map(str::to_owned),
+            reference_time,
+            threshold: threshold.0,
+            n_timestamps: pairs.len(),
+            oldest: pairs.iter().map(|(record, _)| *record).min(),
+            newest: pairs.iter().map(|(record, _)| *record).max(),
+            lag_quantiles,
+            n_late: lags.iter().filter(|lag| **lag > threshold.0).count(),
+            n_future: lags.iter().filter(|lag| **lag < 0).count(),
+        })
+    }
+
+    /// Gets the time between the newest record and the run in milliseconds, or None if there are
+    /// no valid timestamps.
+    pub fn staleness(&self) -> Option<i64> {
+        self.newest.map(|newest| self.reference_time - newest)
+    }
+
+    /// Checks whether the newest record is older than the freshness threshold.
+    pub fn is_stale(&self) -> bool {
+        self.staleness()
+            .is_some_and(|staleness| staleness > self.threshold)
+    }
+}
+
+/// Reads the values of a timestamp column as milliseconds since the Unix epoch.
+fn column_timestamps(
+    lazy_df: &LazyFrame,
+    schema: &Schema,
+    column: &str,
+) -> Result<Vec<Option<i64>>, FreshnessError> {
+    let dtype = schema
+        .get(column)
+        .ok_or_else(|| FreshnessError::UnknownColumn(column.to_owned()))?;
+    if !matches!(
+        dtype,
+        DataType::Date | DataType::Datetime(_, _) | DataType::String
+    ) {
+        return Err(FreshnessError::UnsupportedColumn(
+            column.to_owned(),
+            dtype.clone(),
+        ));
+    }
+    let df = lazy_df.clone().select([col(column)]).collect()?;
+    Ok(timestamps(df.column(column)?, dtype)?)
+}
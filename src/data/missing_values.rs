@@ -1,11 +1,26 @@
 //! # Missing Values Module
 //!
 //! This module handles the detection of missing values in the dataset.
+//!
+//! For the columns with at least `CONCENTRATION_MIN_MISSING` percent missing values, it also
+//! checks whether the missing values are concentrated in the rows where a low-cardinality
+//! categorical column has a given value. The missing values are concentrated in a group when the
+//! group holds at least `MIN_CONCENTRATION_SHARE` percent of them and the missing rate within the
+//! group is at least `MIN_CONCENTRATION_LIFT` times the overall missing rate.
 
 use indexmap::IndexMap;
 use polars::{lazy::dsl::*, prelude::*};
 use thiserror::Error;
 
+/// Missing value percentage from which the concentration of a column's missing values is checked.
+pub const CONCENTRATION_MIN_MISSING: f64 = 5.0;
+/// Maximum number of distinct values of a column to group the rows by in the concentration check.
+pub const MAX_GROUPS: u64 = 20;
+/// Minimum percentage of a column's missing values in a group for them to be concentrated in it.
+pub const MIN_CONCENTRATION_SHARE: f64 = 50.0;
+/// Minimum ratio of the missing rate within a group to the overall missing rate.
+pub const MIN_CONCENTRATION_LIFT: f64 = 2.0;
+
 /// The error types for the missing values module.
 #[derive(Error, Debug)]
 pub enum MissingValueError {
@@ -19,12 +34,28 @@ pub enum MissingValueError {
 }
 
 /// Holds the results of missing value analysis for each column in a dataset.
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct MissingValueAnalysis {
     /// A map where each key is a column name and the value is a tuple containing:
     /// - the count of missing values
     /// - the percentage of missing values relative to the total number of rows
     pub column_missing_values: IndexMap<String, (u64, f64)>,
+    /// The group of rows each column's missing values are concentrated in, for the columns whose
+    /// missing values are concentrated.
+    pub concentrations: IndexMap<String, MissingConcentration>,
+}
+
+/// A group of rows a column's missing values are concentrated in.
+#[derive(Debug, Clone)]
+pub struct MissingConcentration {
+    /// The column the rows are grouped by.
+    pub group_column: String,
+    /// The value of the grouping column in the rows of the group.
+    pub group_value: String,
+    /// The percentage of the column's missing values that fall in the group.
+    pub share: f64,
+    /// The percentage of missing values within the group.
+    pub group_missing_percentage: f64,
 }
 
 impl MissingValueAnalysis {
@@ -80,8 +111,126 @@ impl MissingValueAnalysis {
             column_missing_values.insert(column_name, (missing_count, missing_percentage));
         }
 
+        let group_columns = group_columns(lazy_df, schema)?;
+        let mut concentrations = IndexMap::new();
+        for (column_name, (_, missing_percentage)) in &column_missing_values {
+            if *missing_percentage < CONCENTRATION_MIN_MISSING || *missing_percentage >= 100.0 {
+                continue;
+            }
+            let mut best: Option<MissingConcentration> = None;
+            for group_column in group_columns.iter().filter(|name| *name != column_name) {
+                let concentration =
+                    concentration(lazy_df, column_name, *missing_percentage, group_column)?;
+                if let Some(concentration) = concentration {
+                    if best
+                        .as_ref()
+                        .is_none_or(|best| concentration.share > best.share)
+                    {
+                        best = Some(concentration);
+                    }
+                }
+            }
+            if let Some(best) = best {
+                concentrations.insert(column_name.clone(), best);
+            }
+        }
+
         Ok(MissingValueAnalysis {
             column_missing_values,
+            concentrations,
         })
     }
 }
+
+/// Gets the categorical columns with between 2 and `MAX_GROUPS` distinct values to group the rows
+/// by.
+fn group_columns(lazy_df: &LazyFrame, schema: &Schema) -> Result<Vec<String>, MissingValueError> {
+    let categorical_columns: Vec<&str> = schema
+        .iter()
+        .filter(|(_, dtype)| {
+            matches!(
+                dtype,
+                DataType::String
+                    | DataType::Boolean
+                    | DataType::Categorical(_, _)
+                    | DataType::Enum(_, _)
+            )
+        })
+        .map(|(name, _)| name.as_str())
+        .collect();
+    if categorical_columns.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let df = lazy_df
+        .clone()
+        .select(
+            categorical_columns
+                .iter()
+                .map(|name| col(name).drop_nulls().n_unique().cast(DataType::UInt64))
+                .collect::<Vec<Expr>>(),
+        )
+        .collect()?;
+    let mut columns = Vec::new();
+    for name in categorical_columns {
+        let n_unique = df.column(name)?.u64()?.get(0).unwrap_or(0);
+        if (2..=MAX_GROUPS).contains(&n_unique) {
+            columns.push(name.to_owned());
+        }
+    }
+    Ok(columns)
+}
+
+/// Finds the group of rows, by the values of the grouping column, that holds the largest share of
+/// a column's missing values, if the missing values are concentrated in it. Rows where the
+/// grouping column is missing aren't a group.
+fn concentration(
+    lazy_df: &LazyFrame,
+    column_name: &str,
+    missing_percentage: f64,
+    group_column: &str,
+) -> Result<Option<MissingConcentration>, MissingValueError> {
+    let df = lazy_df
+        .clone()
+        .group_by([col(group_column).cast(DataType::String).alias("__group")])
+        .agg([
+            len().cast(DataType::UInt64).alias("__n_rows"),
+            col(column_name)
+                .is_null()
+                .sum()
+                .cast(DataType::UInt64)
+                .alias("__n_missing"),
+        ])
+        .collect()?;
+    let groups = df.column("__group")?.str()?;
+    let n_rows = df.column("__n_rows")?.u64()?;
+    let n_missing = df.column("__n_missing")?.u64()?;
+    let total_missing: u64 = n_missing.into_iter().flatten().sum();
+    if total_missing == 0 {
+        return Ok(None);
+    }
+
+    let mut best: Option<MissingConcentration> = None;
+    for ((group, n_rows), n_missing) in groups.into_iter().zip(n_rows).zip(n_missing) {
+        let (Some(group), Some(n_rows), Some(n_missing)) = (group, n_rows, n_missing) else {
+            continue;
+        };
+        if n_rows == 0 {
+            continue;
+        }
+        let share = n_missing as f64 / total_missing as f64 * 100.0;
+        let group_missing_percentage = n_missing as f64 / n_rows as f64 * 100.0;
+        if share >= MIN_CONCENTRATION_SHARE
+            && group_missing_percentage >= MIN_CONCENTRATION_LIFT * missing_percentage
+            && best.as_ref().is_none_or(|best| share > best.share)
+        {
+            best = Some(MissingConcentration {
+                group_column: group_column.to_owned(),
+                group_value: group.to_owned(),
+                share,
+                group_missing_percentage,
+            });
+        }
+    }
+    Ok(best)
+}
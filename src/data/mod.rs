@@ -1,5 +1,39 @@
+pub mod associations;
 pub mod base;
+pub mod categories;
+pub mod collinearity;
+pub mod correlation;
 pub mod descriptive;
+pub mod dictionary;
+pub mod drift;
+pub mod duplicates;
+pub mod freshness;
 pub mod missing_values;
+pub mod normality;
+pub mod null_if;
+pub mod outliers;
+pub mod partitions;
+pub mod pca;
+pub mod prep;
+pub mod problem_rows;
+pub mod provenance;
+pub mod query;
+pub mod recovery;
+pub mod references;
+#[cfg(feature = "remote")]
+pub mod remote;
+pub mod robust;
+pub mod row_order;
+pub mod sessions;
+#[cfg(feature = "sql")]
+pub mod sql;
+pub mod tables;
+pub mod target;
+pub mod temporal;
+pub mod text;
+pub mod throughput;
+pub mod time_series;
+pub mod timeout;
+pub mod transformations;
 pub mod visualizations;
-mod viz_lib;
+pub mod viz_lib;
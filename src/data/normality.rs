@@ -0,0 +1,460 @@
+//! # Normality Module
+//!
+//! This module handles the distribution shape diagnostics of the numerical features: a normality
+//! test and a plain label of the shape, since raw skewness and kurtosis numbers are hard to act on.
+//!
+//! ## Tests
+//!
+//! - Shapiro-Wilk, for features with 3 to `SHAPIRO_WILK_MAX_N` values. The W statistic and its
+//!   p-value use Royston's approximation (algorithm AS R94), as in R and SciPy.
+//! - D'Agostino-Pearson, for larger features. The K² statistic combines the z-scores of the
+//!   sample skewness and kurtosis and follows a chi-squared distribution with 2 degrees of
+//!   freedom under normality.
+//!
+//! A feature is consistent with a normal distribution when the p-value is at least
+//! `NORMALITY_ALPHA`. With many values, the tests reject departures too small to matter, so the
+//! label relies on the shape of the distribution rather than on the p-value alone.
+//!
+//! ## Labels
+//!
+//! The first matching label is picked:
+//!
+//! 1. Bimodal: the bimodality coefficient is above 5/9 (the value of a uniform distribution) and
+//!    the smoothed histogram has two peaks, each around at least `MIN_MODE_SHARE` of the values,
+//!    separated by a dip of at least `MIN_DIP` of the lower peak.
+//! 2. Right- or left-skewed: the absolute skewness is at least `SKEWED_THRESHOLD`.
+//! 3. Heavy- or light-tailed: the absolute excess kurtosis is at least `TAILED_THRESHOLD`.
+//! 4. Non-normal: the Shapiro-Wilk test rejects normality.
+//! 5. Normal otherwise.
+//!
+//! The shape labels (1 to 3) need `MIN_SHAPE_N` values, and `MIN_BIMODAL_N` for bimodal, so
+//! smaller features are only labeled by their test result. The skewness and excess kurtosis are the biased sample moments.
+
+use crate::data::transformations::moments;
+use std::f64::consts::{FRAC_1_SQRT_2, PI};
+use std::fmt;
+
+/// The maximum number of values tested with Shapiro-Wilk, above which D'Agostino-Pearson is used.
+pub const SHAPIRO_WILK_MAX_N: usize = 5000;
+/// The minimum number of values tested with D'Agostino-Pearson.
+pub const DAGOSTINO_MIN_N: usize = 20;
+/// The significance level below which a test rejects normality.
+pub const NORMALITY_ALPHA: f64 = 0.05;
+/// Absolute skewness from which a feature is labeled as skewed.
+pub const SKEWED_THRESHOLD: f64 = 1.0;
+/// Absolute excess kurtosis from which a feature is labeled as heavy- or light-tailed.
+pub const TAILED_THRESHOLD: f64 = 1.0;
+/// The minimum number of values for a feature to be labeled as skewed or tailed, below which the
+/// sample moments are too noisy and only the test result is labeled.
+pub const MIN_SHAPE_N: usize = 20;
+/// The minimum number of values for a feature to be labeled as bimodal, below which histograms
+/// are too noisy.
+pub const MIN_BIMODAL_N: usize = 50;
+/// The minimum depth of the dip between two histogram peaks, as a fraction of the lower peak.
+pub const MIN_DIP: f64 = 0.25;
+/// The minimum share of the values in the three bins around a histogram peak for it to count as
+/// a mode, so a few isolated extreme values don't make a second peak.
+pub const MIN_MODE_SHARE: f64 = 0.05;
+
+/// The normality test run on a feature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NormalityTest {
+    /// The Shapiro-Wilk test, whose statistic W is 1 for perfectly normal values.
+    ShapiroWilk,
+    /// The D'Agostino-Pearson omnibus test, whose statistic K² is 0 for perfectly normal values.
+    DAgostinoPearson,
+}
+
+impl fmt::Display for NormalityTest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NormalityTest::ShapiroWilk => write!(f, "Shapiro-Wilk"),
+            NormalityTest::DAgostinoPearson => write!(f, "D'Agostino-Pearson"),
+        }
+    }
+}
+
+/// The label of the shape of a feature's distribution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DistributionLabel {
+    /// Consistent with a normal distribution.
+    Normal,
+    /// A long tail of high values.
+    RightSkewed,
+    /// A long tail of low values.
+    LeftSkewed,
+    /// More extreme values on both sides than a normal distribution.
+    HeavyTailed,
+    /// Fewer extreme values than a normal distribution, e.g. uniform values.
+    LightTailed,
+    /// Two separate clusters of values.
+    Bimodal,
+    /// Not normal, without a more specific shape.
+    NonNormal,
+}
+
+impl fmt::Display for DistributionLabel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            DistributionLabel::Normal => "normal",
+            DistributionLabel::RightSkewed => "right-skewed",
+            DistributionLabel::LeftSkewed => "left-skewed",
+            DistributionLabel::HeavyTailed => "heavy-tailed",
+            DistributionLabel::LightTailed => "light-tailed",
+            DistributionLabel::Bimodal => "bimodal",
+            DistributionLabel::NonNormal => "non-normal",
+        };
+        write!(f, "{}", label)
+    }
+}
+
+/// The normality test result and distribution label of a numerical feature.
+#[derive(Debug, Clone)]
+pub struct NormalityStats {
+    /// The test run on the feature, picked by its number of values.
+    pub test: NormalityTest,
+    /// The test statistic, W for Shapiro-Wilk and K² for D'Agostino-Pearson.
+    pub statistic: f64,
+    /// The p-value of the test.
+    pub p_value: f64,
+    /// The label of the shape of the distribution.
+    pub label: DistributionLabel,
+}
+
+impl NormalityStats {
+    /// Runs the normality test on the values of a feature and labels their distribution.
+    ///
+    /// ### Parameters
+    ///
+    /// - `values`: The finite values of the feature.
+    /// - `histogram`: The bin counts of the equal width histogram of the values.
+    ///
+    /// ### Returns
+    ///
+    /// - `Option<Self>`: The results, or None if there are fewer than three values, too few
+    ///   values for D'Agostino-Pearson, or no variance.
+    pub fn new(values: &[f64], histogram: &[u64]) -> Option<Self> {
+        let (skewness, kurtosis) = moments(values)?;
+        let n = values.len();
+        let (test, statistic, p_value) = if n <= SHAPIRO_WILK_MAX_N {
+            let mut sorted = values.to_vec();
+            sorted.sort_by(f64::total_cmp);
+            let (w, p_value) = shapiro_wilk(&sorted)?;
+            (NormalityTest::ShapiroWilk, w, p_value)
+        } else {
+            let (k2, p_value) = dagostino_pearson(n, skewness, kurtosis)?;
+            (NormalityTest::DAgostinoPearson, k2, p_value)
+        };
+
+        let has_shape = n >= MIN_SHAPE_N;
+        let label = if is_bimodal(n, skewness, kurtosis, histogram) {
+            DistributionLabel::Bimodal
+        } else if has_shape && skewness >= SKEWED_THRESHOLD {
+            DistributionLabel::RightSkewed
+        } else if has_shape && skewness <= -SKEWED_THRESHOLD {
+            DistributionLabel::LeftSkewed
+        } else if has_shape && kurtosis >= TAILED_THRESHOLD {
+            DistributionLabel::HeavyTailed
+        } else if has_shape && kurtosis <= -TAILED_THRESHOLD {
+            DistributionLabel::LightTailed
+        } else if test == NormalityTest::ShapiroWilk && p_value < NORMALITY_ALPHA {
+            DistributionLabel::NonNormal
+        } else {
+            DistributionLabel::Normal
+        };
+
+        Some(Self {
+            test,
+            statistic,
+            p_value,
+            label,
+        })
+    }
+
+    /// Checks whether the test is consistent with a normal distribution.
+    pub fn is_normal(&self) -> bool {
+        self.p_value >= NORMALITY_ALPHA
+    }
+
+    /// Formats the p-value with four decimals, or as `< 0.0001` when it rounds to zero.
+    pub fn format_p_value(&self) -> String {
+        if self.p_value < 1e-4 {
+            "< 0.0001".to_owned()
+        } else {
+            format!("{:.4}", self.p_value)
+        }
+    }
+
+    /// Formats the test result, e.g. `Shapiro-Wilk W = 0.9812, p = 0.2140`.
+    pub fn format_test(&self) -> String {
+        let statistic = match self.test {
+            NormalityTest::ShapiroWilk => "W",
+            NormalityTest::DAgostinoPearson => "K²",
+        };
+        let p_value = self.format_p_value();
+        let separator = if p_value.starts_with('<') { " " } else { " = " };
+        format!(
+            "{} {} = {:.4}, p{}{}",
+            self.test, statistic, self.statistic, separator, p_value
+        )
+    }
+}
+
+/// Evaluates a polynomial with the coefficients in increasing order of degree.
+fn polynomial(coefficients: &[f64], x: f64) -> f64 {
+    coefficients.iter().rev().fold(0.0, |sum, c| sum * x + c)
+}
+
+/// Runs the Shapiro-Wilk test with Royston's approximation of the coefficients and p-value.
+///
+/// ### Parameters
+///
+/// - `sorted`: The values in ascending order, 3 to `SHAPIRO_WILK_MAX_N` of them.
+///
+/// ### Returns
+///
+/// - `Option<(f64, f64)>`: The W statistic and its p-value, or None if the values have no range.
+fn shapiro_wilk(sorted: &[f64]) -> Option<(f64, f64)> {
+    const C1: [f64; 6] = [0.0, 0.221157, -0.147981, -2.07119, 4.434685, -2.706056];
+    const C2: [f64; 6] = [0.0, 0.042981, -0.293762, -1.752461, 5.682633, -3.582633];
+    const C3: [f64; 4] = [0.544, -0.39978, 0.025054, -6.714e-4];
+    const C4: [f64; 4] = [1.3822, -0.77857, 0.062767, -0.0020322];
+    const C5: [f64; 4] = [-1.5861, -0.31082, -0.083751, 0.0038915];
+    const C6: [f64; 3] = [-0.4803, -0.082676, 0.0030302];
+    const G: [f64; 2] = [-2.273, 0.459];
+
+    let n = sorted.len();
+    let range = sorted[n - 1] - sorted[0];
+    if n < 3 || range <= 0.0 {
+        return None;
+    }
+    let an = n as f64;
+    let half = n / 2;
+
+    // The coefficients of the lower half of the order statistics, antisymmetric in the upper half.
+    let mut a = vec![0.0; half];
+    if n == 3 {
+        a[0] = FRAC_1_SQRT_2;
+    } else {
+        let m: Vec<f64> = (1..=half)
+            .map(|i| normal_quantile((i as f64 - 0.375) / (an + 0.25)))
+            .collect();
+        let summ2 = 2.0 * m.iter().map(|mi| mi * mi).sum::<f64>();
+        let ssumm2 = summ2.sqrt();
+        let rsn = 1.0 / an.sqrt();
+        let a1 = polynomial(&C1, rsn) - m[0] / ssumm2;
+        let (first, fac) = if n > 5 {
+            let a2 = -m[1] / ssumm2 + polynomial(&C2, rsn);
+            a[1] = a2;
+            let fac = ((summ2 - 2.0 * m[0] * m[0] - 2.0 * m[1] * m[1])
+                / (1.0 - 2.0 * a1 * a1 - 2.0 * a2 * a2))
+                .sqrt();
+            (2, fac)
+        } else {
+            let fac = ((summ2 - 2.0 * m[0] * m[0]) / (1.0 - 2.0 * a1 * a1)).sqrt();
+            (1, fac)
+        };
+        a[0] = a1;
+        for i in first..half {
+            a[i] = -m[i] / fac;
+        }
+    }
+
+    // The values are scaled by their range for numerical stability.
+    let scaled: Vec<f64> = sorted.iter().map(|value| value / range).collect();
+    let mean = scaled.iter().sum::<f64>() / an;
+    let ssq: f64 = scaled.iter().map(|value| (value - mean).powi(2)).sum();
+    let numerator: f64 = a
+        .iter()
+        .enumerate()
+        .map(|(i, ai)| ai * (scaled[n - 1 - i] - scaled[i]))
+        .sum();
+    let w = (numerator * numerator / ssq).min(1.0);
+
+    let p_value = if n == 3 {
+        (6.0 / PI * (w.sqrt().asin() - PI / 3.0)).max(0.0)
+    } else if w >= 1.0 {
+        1.0
+    } else {
+        let w1 = (1.0 - w).ln();
+        let (y, mean, std_dev) = if n <= 11 {
+            let gamma = polynomial(&G, an);
+            if w1 >= gamma {
+                return Some((w, 0.0));
+            }
+            (
+                -(gamma - w1).ln(),
+                polynomial(&C3, an),
+                polynomial(&C4, an).exp(),
+            )
+        } else {
+            let ln_n = an.ln();
+            (w1, polynomial(&C5, ln_n), polynomial(&C6, ln_n).exp())
+        };
+        normal_upper_tail((y - mean) / std_dev)
+    };
+    Some((w, p_value.clamp(0.0, 1.0)))
+}
+
+/// Runs the D'Agostino-Pearson omnibus test from the sample moments.
+///
+/// ### Parameters
+///
+/// - `n`: The number of values, at least `DAGOSTINO_MIN_N`.
+/// - `skewness`: The biased sample skewness.
+/// - `kurtosis`: The biased sample excess kurtosis.
+///
+/// ### Returns
+///
+/// - `Option<(f64, f64)>`: The K² statistic and its p-value, or None if there are too few values.
+fn dagostino_pearson(n: usize, skewness: f64, kurtosis: f64) -> Option<(f64, f64)> {
+    if n < DAGOSTINO_MIN_N {
+        return None;
+    }
+    let n = n as f64;
+
+    // The z-score of the skewness.
+    let y = skewness * ((n + 1.0) * (n + 3.0) / (6.0 * (n - 2.0))).sqrt();
+    let beta2 = 3.0 * (n * n + 27.0 * n - 70.0) * (n + 1.0) * (n + 3.0)
+        / ((n - 2.0) * (n + 5.0) * (n + 7.0) * (n + 9.0));
+    let w2 = -1.0 + (2.0 * (beta2 - 1.0)).sqrt();
+    let delta = 1.0 / (0.5 * w2.ln()).sqrt();
+    let alpha = (2.0 / (w2 - 1.0)).sqrt();
+    let y = if y == 0.0 { 1.0 } else { y };
+    let z_skewness = delta * (y / alpha + ((y / alpha).powi(2) + 1.0).sqrt()).ln();
+
+    // The z-score of the kurtosis, with the Pearson (non-excess) kurtosis.
+    let expected = 3.0 * (n - 1.0) / (n + 1.0);
+    let variance = 24.0 * n * (n - 2.0) * (n - 3.0) / ((n + 1.0).powi(2) * (n + 3.0) * (n + 5.0));
+    let x = (kurtosis + 3.0 - expected) / variance.sqrt();
+    let sqrt_beta1 = 6.0 * (n * n - 5.0 * n + 2.0) / ((n + 7.0) * (n + 9.0))
+        * (6.0 * (n + 3.0) * (n + 5.0) / (n * (n - 2.0) * (n - 3.0))).sqrt();
+    let a = 6.0
+        + 8.0 / sqrt_beta1 * (2.0 / sqrt_beta1 + (1.0 + 4.0 / (sqrt_beta1 * sqrt_beta1)).sqrt());
+    let denominator = 1.0 + x * (2.0 / (a - 4.0)).sqrt();
+    if denominator == 0.0 {
+        return None;
+    }
+    let term = ((1.0 - 2.0 / a) / denominator.abs()).cbrt() * denominator.signum();
+    let z_kurtosis = (1.0 - 2.0 / (9.0 * a) - term) / (2.0 / (9.0 * a)).sqrt();
+
+    let k2 = z_skewness * z_skewness + z_kurtosis * z_kurtosis;
+    // The survival function of the chi-squared distribution with 2 degrees of freedom.
+    Some((k2, (-k2 / 2.0).exp()))
+}
+
+/// Checks whether a distribution looks bimodal: its bimodality coefficient is above that of a
+/// uniform distribution and its smoothed histogram has two peaks with a clear dip between them.
+fn is_bimodal(n: usize, skewness: f64, kurtosis: f64, histogram: &[u64]) -> bool {
+    if n < MIN_BIMODAL_N || histogram.len() < 3 {
+        return false;
+    }
+    let n = n as f64;
+    let coefficient = (skewness * skewness + 1.0)
+        / (kurtosis + 3.0 * (n - 1.0).powi(2) / ((n - 2.0) * (n - 3.0)));
+    if coefficient <= 5.0 / 9.0 {
+        return false;
+    }
+
+    // A moving average over three bins smooths out the sampling noise.
+    let smoothed: Vec<f64> = (0..histogram.len())
+        .map(|i| {
+            let window = &histogram[i.saturating_sub(1)..(i + 2).min(histogram.len())];
+            window.iter().sum::<u64>() as f64 / window.len() as f64
+        })
+        .collect();
+    let peaks: Vec<usize> = (0..smoothed.len())
+        .filter(|&i| {
+            let left = if i == 0 { 0.0 } else { smoothed[i - 1] };
+            let right = smoothed.get(i + 1).copied().unwrap_or(0.0);
+            let mass = histogram[i.saturating_sub(1)..(i + 2).min(histogram.len())]
+                .iter()
+                .sum::<u64>();
+            smoothed[i] > left && smoothed[i] >= right && mass as f64 >= MIN_MODE_SHARE * n
+        })
+        .collect();
+    peaks.iter().enumerate().any(|(i, &left)| {
+        peaks[i + 1..].iter().any(|&right| {
+            let dip = smoothed[left..=right]
+                .iter()
+                .copied()
+                .fold(f64::INFINITY, f64::min);
+            dip <= (1.0 - MIN_DIP) * smoothed[left].min(smoothed[right])
+        })
+    })
+}
+
+/// Computes the probability that a standard normal variable is above a value.
+fn normal_upper_tail(z: f64) -> f64 {
+    0.5 * erfc(z * FRAC_1_SQRT_2)
+}
+
+/// Computes the complementary error function, with a fractional error below 1.2e-7.
+fn erfc(x: f64) -> f64 {
+    let t = 1.0 / (1.0 + 0.5 * x.abs());
+    let y = t
+        * (-x * x - 1.26551223
+            + t * (1.00002368
+                + t * (0.37409196
+                    + t * (0.09678418
+                        + t * (-0.18628806
+                            + t * (0.27886807
+                                + t * (-1.13520398
+                                    + t * (1.48851587 + t * (-0.82215223 + t * 0.17087277)))))))))
+            .exp();
+    if x >= 0.0 {
+        y
+    } else {
+        2.0 - y
+    }
+}
+
+/// Computes the quantile of the standard normal distribution at a probability, with Acklam's
+/// rational approximation (relative error below 1.15e-9).
+fn normal_quantile(p: f64) -> f64 {
+    const A: [f64; 6] = [
+        -3.969683028665376e1,
+        2.209460984245205e2,
+        -2.759285104469687e2,
+        1.38357751867269e2,
+        -3.066479806614716e1,
+        2.506628277459239,
+    ];
+    const B: [f64; 5] = [
+        -5.447609879822406e1,
+        1.615858368580409e2,
+        -1.556989798598866e2,
+        6.680131188771972e1,
+        -1.328068155288572e1,
+    ];
+    const C: [f64; 6] = [
+        -7.784894002430293e-3,
+        -3.223964580411365e-1,
+        -2.400758277161838,
+        -2.549732539343734,
+        4.374664141464968,
+        2.938163982698783,
+    ];
+    const D: [f64; 4] = [
+        7.784695709041462e-3,
+        3.224671290700398e-1,
+        2.445134137142996,
+        3.754408661907416,
+    ];
+    const P_LOW: f64 = 0.02425;
+
+    let tail = |q: f64| {
+        (((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+            / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.0)
+    };
+    if p < P_LOW {
+        tail((-2.0 * p.ln()).sqrt())
+    } else if p > 1.0 - P_LOW {
+        -tail((-2.0 * (1.0 - p).ln()).sqrt())
+    } else {
+        let q = p - 0.5;
+        let r = q * q;
+        (((((A[0] * r + A[1]) * r + A[2]) * r + A[3]) * r + A[4]) * r + A[5]) * q
+            / (((((B[0] * r + B[1]) * r + B[2]) * r + B[3]) * r + B[4]) * r + 1.0)
+    }
+}
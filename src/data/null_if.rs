@@ -0,0 +1,202 @@
+//! # Null-If Module
+//!
+//! This module handles the null-if rules of a dataset, which convert sentinel values of a column
+//! to nulls before the analyses, e.g. an `age` of 0 or a `temp` of -273 standing in for a missing
+//! reading. Left in, such values skew the statistics and hide the missingness of the column.
+//!
+//! The rules are applied after the data type overrides and before the dates are parsed, so a
+//! placeholder date like `0000-00-00` can be nulled before it fails to parse. Numerical columns
+//! match the rule value as a number (so `0` also matches `0.0`), boolean columns as a boolean, and
+//! other columns as text. The number of values each rule nulled is recorded for the report.
+
+use polars::prelude::*;
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// The error types for the null-if module.
+#[derive(Error, Debug)]
+pub enum NullIfError {
+    /// Occurs when Polars fails to complete an operation.
+    #[error("Polars error: {0}")]
+    Polars(#[from] PolarsError),
+
+    /// Occurs when a rule's column isn't in the dataset.
+    #[error("Null-if column not found: {0}")]
+    MissingColumn(String),
+
+    /// Occurs when a rule's value can't be compared with its column's data type.
+    #[error("Invalid null-if value for the {dtype} column {column}: {value}")]
+    InvalidValue {
+        column: String,
+        value: String,
+        dtype: DataType,
+    },
+}
+
+/// A rule converting a value of a column to null.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NullIfRule {
+    /// The name of the column.
+    pub column: String,
+    /// The value converted to null.
+    pub value: String,
+}
+
+impl FromStr for NullIfRule {
+    type Err = String;
+
+    /// Parses a `<column>=<value>` rule, e.g. `age=0` or `temp=-273`. The value is everything
+    /// after the first `=`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once('=') {
+            Some((column, value)) if !column.is_empty() => Ok(NullIfRule {
+                column: column.to_owned(),
+                value: value.to_owned(),
+            }),
+            _ => Err(format!(
+                "Invalid null-if rule: {}. Expected <column>=<value>, e.g. age=0.",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for NullIfRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}={}", self.column, self.value)
+    }
+}
+
+/// Struct to hold the null-if rules applied to a dataset.
+#[derive(Debug)]
+pub struct NullIfAnalysis {
+    /// Each rule with the number of values it converted to null, in rule order.
+    pub rules: Vec<(NullIfRule, usize)>,
+}
+
+impl NullIfAnalysis {
+    /// Gets the total number of values converted to null.
+    pub fn total_nulled(&self) -> usize {
+        self.rules.iter().map(|(_, count)| count).sum()
+    }
+}
+
+/// Applies the null-if rules to a dataset, counting the values each rule converts to null.
+///
+/// ### Parameters
+/// - `lazy_df`: The dataset.
+/// - `schema`: The dataset schema.
+/// - `rules`: The null-if rules.
+///
+/// ### Returns
+/// - `Result<(LazyFrame, NullIfAnalysis), NullIfError>`: The dataset with the values nulled and
+///   the counts of each rule, or an error.
+///
+/// ### Errors
+/// This function can return a NullIfError if:
+/// - A rule's column isn't in the dataset.
+/// - A rule's value isn't a number for a numerical column, or a boolean for a boolean column.
+/// - The counts can't be computed.
+pub fn apply_rules(
+    lazy_df: LazyFrame,
+    schema: &Schema,
+    rules: &[NullIfRule],
+) -> Result<(LazyFrame, NullIfAnalysis), NullIfError> {
+    let matches: Vec<Expr> = rules
+        .iter()
+        .map(|rule| {
+            let dtype = schema
+                .get(&rule.column)
+                .ok_or_else(|| NullIfError::MissingColumn(rule.column.clone()))?;
+            matches_rule(rule, dtype)
+        })
+        .collect::<Result<_, _>>()?;
+
+    let counts = lazy_df
+        .clone()
+        .select(
+            matches
+                .iter()
+                .enumerate()
+                .map(|(i, matched)| {
+                    matched
+                        .clone()
+                        .cast(DataType::UInt64)
+                        .sum()
+                        .alias(&format!("rule_{}", i))
+                })
+                .collect::<Vec<Expr>>(),
+        )
+        .collect()?;
+    let rules_with_counts = rules
+        .iter()
+        .enumerate()
+        .map(|(i, rule)| {
+            let count = counts
+                .column(&format!("rule_{}", i))?
+                .u64()?
+                .get(0)
+                .unwrap_or(0);
+            Ok((rule.clone(), count as usize))
+        })
+        .collect::<Result<Vec<_>, NullIfError>>()?;
+
+    // The rules of a column are combined so each column is rewritten once.
+    let mut columns: Vec<(&str, Expr)> = Vec::new();
+    for (rule, matched) in rules.iter().zip(matches) {
+        match columns
+            .iter_mut()
+            .find(|(column, _)| *column == rule.column)
+        {
+            Some((_, any_match)) => *any_match = any_match.clone().or(matched),
+            None => columns.push((rule.column.as_str(), matched)),
+        }
+    }
+    let lazy_df = lazy_df.with_columns(
+        columns
+            .into_iter()
+            .map(|(column, any_match)| {
+                when(any_match)
+                    .then(lit(NULL).cast(schema.get(column).cloned().unwrap_or(DataType::Null)))
+                    .otherwise(col(column))
+                    .alias(column)
+            })
+            .collect::<Vec<Expr>>(),
+    );
+
+    Ok((
+        lazy_df,
+        NullIfAnalysis {
+            rules: rules_with_counts,
+        },
+    ))
+}
+
+/// Builds the expression matching the values of a rule's column equal to the rule value.
+fn matches_rule(rule: &NullIfRule, dtype: &DataType) -> Result<Expr, NullIfError> {
+    let invalid = || NullIfError::InvalidValue {
+        column: rule.column.clone(),
+        value: rule.value.clone(),
+        dtype: dtype.clone(),
+    };
+    let column = col(&rule.column);
+    let matched = match dtype {
+        dtype if dtype.is_numeric() => {
+            let value: f64 = rule.value.trim().parse().map_err(|_| invalid())?;
+            column.cast(DataType::Float64).eq(lit(value))
+        }
+        DataType::Boolean => {
+            let value: bool = rule
+                .value
+                .trim()
+                .to_lowercase()
+                .parse()
+                .map_err(|_| invalid())?;
+            column.eq(lit(value))
+        }
+        DataType::String => column.eq(lit(rule.value.clone())),
+        _ => column.cast(DataType::String).eq(lit(rule.value.clone())),
+    };
+    Ok(matched)
+}
@@ -0,0 +1,240 @@
+//! # Outliers Module
+//!
+//! This module handles flagging outliers in the numerical features of a dataset. Two methods are
+//! used:
+//!
+//! - IQR fences: values below `Q1 - IQR_MULTIPLIER * IQR` or above `Q3 + IQR_MULTIPLIER * IQR`.
+//! - Z-score fences: values more than `Z_SCORE_THRESHOLD` standard deviations from the mean.
+//!
+//! Along with the counts, the `MAX_EXTREME_VALUES` distinct IQR outliers furthest from the middle of the
+//! IQR are kept as examples, since a handful of values (e.g. a 999 sentinel or a unit mix-up)
+//! often tells more than the count.
+
+use indexmap::IndexMap;
+use polars::prelude::*;
+use thiserror::Error;
+
+/// The IQR multiplier for the outlier fences (Tukey's fences).
+pub const IQR_MULTIPLIER: f64 = 1.5;
+/// The number of standard deviations from the mean beyond which a value is an outlier.
+pub const Z_SCORE_THRESHOLD: f64 = 3.0;
+/// The maximum number of example extreme values kept per feature.
+pub const MAX_EXTREME_VALUES: usize = 5;
+
+/// The error types for the outliers module.
+#[derive(Error, Debug)]
+pub enum OutlierError {
+    /// Occurs when Polars fails to complete an operation.
+    #[error("Polars error: {0}")]
+    Polars(#[from] PolarsError),
+}
+
+/// The outlier fences and counts for a single feature.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutlierFences {
+    /// The lower IQR fence.
+    pub iqr_lower: f64,
+    /// The upper IQR fence.
+    pub iqr_upper: f64,
+    /// The number of values beyond the IQR fences.
+    pub iqr_count: u64,
+    /// The lower z-score fence.
+    pub z_lower: f64,
+    /// The upper z-score fence.
+    pub z_upper: f64,
+    /// The number of values beyond the z-score fences.
+    pub z_count: u64,
+    /// The number of non-null values.
+    pub n_values: u64,
+    /// The distinct IQR outliers furthest from the middle of the IQR, most extreme first.
+    pub extreme_values: Vec<f64>,
+}
+
+impl OutlierFences {
+    /// Checks whether a value is beyond the IQR fences.
+    pub fn is_iqr_outlier(&self, value: f64) -> bool {
+        value < self.iqr_lower || value > self.iqr_upper
+    }
+
+    /// Checks whether a value is beyond the z-score fences.
+    pub fn is_z_outlier(&self, value: f64) -> bool {
+        value < self.z_lower || value > self.z_upper
+    }
+
+    /// Gets the percentage of the non-null values beyond the IQR fences.
+    pub fn iqr_percentage(&self) -> f64 {
+        percentage(self.iqr_count, self.n_values)
+    }
+
+    /// Gets the percentage of the non-null values beyond the z-score fences.
+    pub fn z_percentage(&self) -> f64 {
+        percentage(self.z_count, self.n_values)
+    }
+}
+
+/// Gets the percentage of a count of values, 0 if there are no values.
+fn percentage(count: u64, n_values: u64) -> f64 {
+    if n_values == 0 {
+        0.0
+    } else {
+        count as f64 / n_values as f64 * 100.0
+    }
+}
+
+/// Struct to hold the outlier analysis results for a dataset.
+#[derive(Debug, Default)]
+pub struct OutlierAnalysis {
+    /// The outlier fences, keyed by feature name. Features without any non-null values are
+    /// omitted.
+    pub fences: IndexMap<String, OutlierFences>,
+}
+
+impl OutlierAnalysis {
+    /// Constructor for the OutlierAnalysis struct.
+    ///
+    /// ### Parameters
+    ///
+    /// - `lazy_df`: Reference to the LazyFrame.
+    /// - `schema`: Reference to the lazy frame's schema.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<Self, OutlierError>`: A new OutlierAnalysis instance or an error.
+    pub fn new(lazy_df: &LazyFrame, schema: &Schema) -> Result<Self, OutlierError> {
+        let numeric_columns: Vec<&str> = schema
+            .iter()
+            .filter(|(_, dtype)| dtype.is_numeric() || dtype.is_decimal())
+            .map(|(name, _)| name.as_str())
+            .collect();
+        if numeric_columns.is_empty() {
+            return Ok(Self::default());
+        }
+
+        let stats_df = lazy_df
+            .clone()
+            .select(
+                numeric_columns
+                    .iter()
+                    .flat_map(|name| {
+                        let column = col(name).cast(DataType::Float64);
+                        vec![
+                            column
+                                .clone()
+                                .quantile(lit(0.25), QuantileInterpolOptions::Linear)
+                                .alias(&format!("{}_q1", name)),
+                            column
+                                .clone()
+                                .quantile(lit(0.75), QuantileInterpolOptions::Linear)
+                                .alias(&format!("{}_q3", name)),
+                            column.clone().mean().alias(&format!("{}_mean", name)),
+                            column.clone().std(1).alias(&format!("{}_std_dev", name)),
+                            column
+                                .count()
+                                .cast(DataType::Float64)
+                                .alias(&format!("{}_n_values", name)),
+                        ]
+                    })
+                    .collect::<Vec<Expr>>(),
+            )
+            .collect()?;
+        let stat = |name: &str, statistic: &str| -> Result<Option<f64>, OutlierError> {
+            Ok(stats_df
+                .column(&format!("{}_{}", name, statistic))?
+                .f64()?
+                .get(0))
+        };
+
+        let mut fences = IndexMap::new();
+        for name in numeric_columns {
+            let (Some(q1), Some(q3), Some(mean)) =
+                (stat(name, "q1")?, stat(name, "q3")?, stat(name, "mean")?)
+            else {
+                continue;
+            };
+            // A single value has no standard deviation, so nothing is a z-score outlier.
+            let std_dev = stat(name, "std_dev")?.unwrap_or(0.0);
+            let n_values = stat(name, "n_values")?.unwrap_or(0.0) as u64;
+            let iqr = q3 - q1;
+            fences.insert(
+                name.to_owned(),
+                OutlierFences {
+                    iqr_lower: q1 - IQR_MULTIPLIER * iqr,
+                    iqr_upper: q3 + IQR_MULTIPLIER * iqr,
+                    iqr_count: 0,
+                    z_lower: mean - Z_SCORE_THRESHOLD * std_dev,
+                    z_upper: mean + Z_SCORE_THRESHOLD * std_dev,
+                    z_count: 0,
+                    n_values,
+                    extreme_values: Vec::new(),
+                },
+            );
+        }
+
+        let counts_df = lazy_df
+            .clone()
+            .select(
+                fences
+                    .iter()
+                    .flat_map(|(name, fence)| {
+                        let column = col(name).cast(DataType::Float64);
+                        let beyond = |lower: f64, upper: f64| {
+                            column
+                                .clone()
+                                .lt(lit(lower))
+                                .or(column.clone().gt(lit(upper)))
+                                .cast(DataType::UInt64)
+                                .sum()
+                        };
+                        let iqr_outliers = column
+                            .clone()
+                            .filter(
+                                column
+                                    .clone()
+                                    .lt(lit(fence.iqr_lower))
+                                    .or(column.clone().gt(lit(fence.iqr_upper))),
+                            )
+                            .unique_stable();
+                        let middle = (fence.iqr_lower + fence.iqr_upper) / 2.0;
+                        vec![
+                            beyond(fence.iqr_lower, fence.iqr_upper)
+                                .alias(&format!("{}_iqr_count", name)),
+                            beyond(fence.z_lower, fence.z_upper)
+                                .alias(&format!("{}_z_count", name)),
+                            iqr_outliers
+                                .clone()
+                                .sort_by(
+                                    [(iqr_outliers - lit(middle)).abs()],
+                                    SortMultipleOptions::default().with_order_descending(true),
+                                )
+                                .head(Some(MAX_EXTREME_VALUES))
+                                .implode()
+                                .alias(&format!("{}_extreme_values", name)),
+                        ]
+                    })
+                    .collect::<Vec<Expr>>(),
+            )
+            .collect()?;
+        for (name, fence) in fences.iter_mut() {
+            let count = |statistic: &str| -> Result<u64, OutlierError> {
+                Ok(counts_df
+                    .column(&format!("{}_{}", name, statistic))?
+                    .u64()?
+                    .get(0)
+                    .unwrap_or(0))
+            };
+            fence.iqr_count = count("iqr_count")?;
+            fence.z_count = count("z_count")?;
+            fence.extreme_values = counts_df
+                .column(&format!("{}_extreme_values", name))?
+                .list()?
+                .get_as_series(0)
+                .map(|values| -> Result<Vec<f64>, OutlierError> {
+                    Ok(values.f64()?.into_iter().flatten().collect())
+                })
+                .transpose()?
+                .unwrap_or_default();
+        }
+
+        Ok(Self { fences })
+    }
+}
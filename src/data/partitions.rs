@@ -0,0 +1,251 @@
+//! # Partitions Module
+//!
+//! This module handles the per-file quality breakdown of datasets read from a directory or a glob
+//! pattern, where every matching file is a partition of the dataset.
+//!
+//! The reference schema is the schema shared by the most partitions (the earliest one on ties).
+//! Each partition is compared against it for missing columns, extra columns, and columns with a
+//! different data type. A partition is flagged as anomalous when:
+//!
+//! - Its schema differs from the reference schema.
+//! - It has no rows.
+//! - Its row count is below `1 / ROW_COUNT_RATIO` or above `ROW_COUNT_RATIO` times the median row
+//!   count of the partitions.
+//! - Its percentage of missing cells is more than `MISSINGNESS_TOLERANCE` percentage points above
+//!   the median missing percentage of the partitions.
+
+use polars::prelude::*;
+use std::path::PathBuf;
+use thiserror::Error;
+
+/// Ratio to the median row count beyond which a partition is flagged.
+pub const ROW_COUNT_RATIO: f64 = 2.0;
+/// Percentage points above the median missing percentage from which a partition is flagged.
+pub const MISSINGNESS_TOLERANCE: f64 = 10.0;
+
+/// The error types for the partitions module.
+#[derive(Error, Debug)]
+pub enum PartitionError {
+    /// Occurs when Polars fails to complete an operation.
+    #[error("Polars error: {0}")]
+    Polars(#[from] PolarsError),
+}
+
+/// The quality statistics of a single partition.
+#[derive(Debug, Clone)]
+pub struct PartitionStats {
+    /// The path of the partition file.
+    pub path: PathBuf,
+    /// The number of rows.
+    pub n_rows: u64,
+    /// The number of columns.
+    pub n_cols: usize,
+    /// The percentage of missing cells.
+    pub missing_percentage: f64,
+    /// The reference schema columns the partition doesn't have.
+    pub missing_columns: Vec<String>,
+    /// The partition columns that aren't in the reference schema.
+    pub extra_columns: Vec<String>,
+    /// The columns whose data type differs from the reference schema, with the reference and the
+    /// partition data types.
+    pub type_mismatches: Vec<(String, DataType, DataType)>,
+    /// The human readable reasons the partition is anomalous, empty if it isn't.
+    pub anomalies: Vec<String>,
+}
+
+impl PartitionStats {
+    /// Checks whether the partition's schema matches the reference schema.
+    pub fn schema_matches(&self) -> bool {
+        self.missing_columns.is_empty()
+            && self.extra_columns.is_empty()
+            && self.type_mismatches.is_empty()
+    }
+
+    /// Lists the human readable differences between the partition's schema and the reference
+    /// schema, empty if the schemas match.
+    pub fn schema_differences(&self) -> Vec<String> {
+        let mut differences = Vec::new();
+        if !self.missing_columns.is_empty() {
+            differences.push(format!(
+                "missing columns: {}",
+                self.missing_columns.join(", ")
+            ));
+        }
+        if !self.extra_columns.is_empty() {
+            differences.push(format!("extra columns: {}", self.extra_columns.join(", ")));
+        }
+        for (name, expected, actual) in &self.type_mismatches {
+            differences.push(format!(
+                "column '{}' is {} instead of {}",
+                name, actual, expected
+            ));
+        }
+        differences
+    }
+
+    /// Checks whether the partition was flagged as anomalous.
+    pub fn is_anomalous(&self) -> bool {
+        !self.anomalies.is_empty()
+    }
+}
+
+/// Struct to hold the per-partition quality breakdown of a dataset.
+#[derive(Debug)]
+pub struct PartitionAnalysis {
+    /// The statistics of each partition, in file order.
+    pub partitions: Vec<PartitionStats>,
+    /// The median row count of the partitions.
+    pub median_rows: f64,
+    /// The median missing percentage of the partitions.
+    pub median_missing_percentage: f64,
+}
+
+impl PartitionAnalysis {
+    /// Constructor for the PartitionAnalysis struct.
+    ///
+    /// ### Parameters
+    ///
+    /// - `partitions`: The path and LazyFrame of each partition.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<Self, PartitionError>`: A new PartitionAnalysis instance or an error.
+    pub fn new(partitions: &[(PathBuf, LazyFrame)]) -> Result<Self, PartitionError> {
+        let mut schemas: Vec<SchemaRef> = Vec::with_capacity(partitions.len());
+        for (_, lazy_df) in partitions {
+            schemas.push(lazy_df.clone().schema()?);
+        }
+        let reference = schemas
+            .iter()
+            .max_by_key(|schema| {
+                // The reversed position makes the earliest schema win ties.
+                let occurrences = schemas.iter().filter(|other| other == schema).count();
+                (
+                    occurrences,
+                    std::cmp::Reverse(schemas.iter().position(|other| other == *schema)),
+                )
+            })
+            .cloned()
+            .unwrap_or_default();
+
+        let mut stats = Vec::with_capacity(partitions.len());
+        for ((path, lazy_df), schema) in partitions.iter().zip(&schemas) {
+            let (n_rows, n_missing) = counts(lazy_df, schema)?;
+            let n_cells = n_rows * schema.len() as u64;
+            stats.push(PartitionStats {
+                path: path.clone(),
+                n_rows,
+                n_cols: schema.len(),
+                missing_percentage: if n_cells == 0 {
+                    0.0
+                } else {
+                    n_missing as f64 / n_cells as f64 * 100.0
+                },
+                missing_columns: reference
+                    .iter_names()
+                    .filter(|name| !schema.contains(name))
+                    .map(|name| name.to_string())
+                    .collect(),
+                extra_columns: schema
+                    .iter_names()
+                    .filter(|name| !reference.contains(name))
+                    .map(|name| name.to_string())
+                    .collect(),
+                type_mismatches: schema
+                    .iter()
+                    .filter_map(|(name, dtype)| {
+                        let expected = reference.get(name)?;
+                        (expected != dtype)
+                            .then(|| (name.to_string(), expected.clone(), dtype.clone()))
+                    })
+                    .collect(),
+                anomalies: Vec::new(),
+            });
+        }
+
+        let median_rows = median(stats.iter().map(|partition| partition.n_rows as f64));
+        let median_missing_percentage =
+            median(stats.iter().map(|partition| partition.missing_percentage));
+        for partition in &mut stats {
+            partition.anomalies = anomalies(partition, median_rows, median_missing_percentage);
+        }
+
+        Ok(Self {
+            partitions: stats,
+            median_rows,
+            median_missing_percentage,
+        })
+    }
+
+    /// Gets the partitions flagged as anomalous.
+    pub fn anomalous_partitions(&self) -> impl Iterator<Item = &PartitionStats> {
+        self.partitions
+            .iter()
+            .filter(|partition| partition.is_anomalous())
+    }
+}
+
+/// Counts the rows and the missing cells of a partition.
+fn counts(lazy_df: &LazyFrame, schema: &Schema) -> Result<(u64, u64), PartitionError> {
+    let mut exprs = vec![len().cast(DataType::UInt64).alias("__n_rows")];
+    exprs.extend(
+        schema
+            .iter_names()
+            .map(|name| col(name).null_count().cast(DataType::UInt64)),
+    );
+    let df = lazy_df.clone().select(exprs).collect()?;
+
+    let n_rows = df.column("__n_rows")?.u64()?.get(0).unwrap_or(0);
+    let mut n_missing = 0;
+    for name in schema.iter_names() {
+        n_missing += df.column(name)?.u64()?.get(0).unwrap_or(0);
+    }
+
+    Ok((n_rows, n_missing))
+}
+
+/// Lists the reasons a partition is anomalous compared to the medians of all the partitions.
+fn anomalies(
+    partition: &PartitionStats,
+    median_rows: f64,
+    median_missing_percentage: f64,
+) -> Vec<String> {
+    let mut anomalies = partition.schema_differences();
+
+    let n_rows = partition.n_rows as f64;
+    if partition.n_rows == 0 {
+        anomalies.push("no rows".to_owned());
+    } else if median_rows > 0.0
+        && (n_rows > ROW_COUNT_RATIO * median_rows || n_rows * ROW_COUNT_RATIO < median_rows)
+    {
+        anomalies.push(format!(
+            "{} rows is {:.2}x the median of {}",
+            partition.n_rows,
+            n_rows / median_rows,
+            median_rows
+        ));
+    }
+    if partition.missing_percentage > median_missing_percentage + MISSINGNESS_TOLERANCE {
+        anomalies.push(format!(
+            "{:.2}% missing cells against a median of {:.2}%",
+            partition.missing_percentage, median_missing_percentage
+        ));
+    }
+
+    anomalies
+}
+
+/// Computes the median of the values, or 0 if there are none.
+fn median(values: impl Iterator<Item = f64>) -> f64 {
+    let mut values: Vec<f64> = values.collect();
+    if values.is_empty() {
+        return 0.0;
+    }
+    values.sort_by(|a, b| a.total_cmp(b));
+    let middle = values.len() / 2;
+    if values.len().is_multiple_of(2) {
+        (values[middle - 1] + values[middle]) / 2.0
+    } else {
+        values[middle]
+    }
+}
@@ -0,0 +1,194 @@
+//! # PCA Module
+//!
+//! This module handles the principal component analysis (PCA) of the numerical features, giving
+//! a quick sense of the intrinsic dimensionality of the dataset.
+//!
+//! The features are standardized, so the components are the eigenvectors of the correlation
+//! matrix and the explained variance of each component is its eigenvalue divided by the number
+//! of features. Rows with a missing value in any numerical feature are dropped and constant
+//! features are skipped.
+//!
+//! The analysis is compiled with the `pca` feature. Without it, the results type is kept for the
+//! report but requesting the analysis is an error.
+
+#[cfg(feature = "pca")]
+use super::collinearity::{correlation_matrix, standardize};
+use polars::prelude::*;
+use thiserror::Error;
+
+/// The number of loadings (by absolute value) reported for each component.
+pub const TOP_LOADINGS: usize = 3;
+/// The cumulative explained variance ratio used to estimate the intrinsic dimensionality.
+pub const EXPLAINED_VARIANCE_TARGET: f64 = 0.9;
+/// The maximum number of Jacobi sweeps for the eigendecomposition.
+#[cfg(feature = "pca")]
+const MAX_SWEEPS: usize = 100;
+
+/// The error types for the PCA module.
+#[derive(Error, Debug)]
+pub enum PcaError {
+    /// Occurs when Polars fails to complete an operation.
+    #[error("Polars error: {0}")]
+    Polars(#[from] PolarsError),
+}
+
+/// A single principal component.
+#[derive(Debug, Clone)]
+pub struct PrincipalComponent {
+    /// The fraction of the total variance explained by the component.
+    pub explained_variance_ratio: f64,
+    /// The cumulative fraction of the total variance explained up to and including the component.
+    pub cumulative_variance_ratio: f64,
+    /// The `TOP_LOADINGS` largest (by absolute value) feature loadings of the component.
+    pub top_loadings: Vec<(String, f64)>,
+}
+
+/// Struct to hold the PCA results for a dataset.
+#[derive(Debug, Default)]
+pub struct PcaAnalysis {
+    /// The numerical features the PCA was run on.
+    pub features: Vec<String>,
+    /// The principal components, sorted by explained variance. Empty if there are fewer than two
+    /// non-constant numerical features or complete rows.
+    pub components: Vec<PrincipalComponent>,
+}
+
+#[cfg(feature = "pca")]
+impl PcaAnalysis {
+    /// Constructor for the PcaAnalysis struct.
+    ///
+    /// ### Parameters
+    ///
+    /// - `lazy_df`: Reference to the LazyFrame.
+    /// - `schema`: Reference to the lazy frame's schema.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<Self, PcaError>`: A new PcaAnalysis instance or an error.
+    pub fn new(lazy_df: &LazyFrame, schema: &Schema) -> Result<Self, PcaError> {
+        let numeric_columns: Vec<&str> = schema
+            .iter()
+            .filter(|(_, dtype)| dtype.is_numeric() || dtype.is_decimal())
+            .map(|(name, _)| name.as_str())
+            .collect();
+
+        let df = lazy_df
+            .clone()
+            .select(
+                numeric_columns
+                    .iter()
+                    .map(|name| col(name).cast(DataType::Float64))
+                    .collect::<Vec<Expr>>(),
+            )
+            .drop_nulls(None)
+            .collect()?;
+
+        let mut features: Vec<String> = Vec::with_capacity(numeric_columns.len());
+        let mut standardized: Vec<Vec<f64>> = Vec::with_capacity(numeric_columns.len());
+        for name in numeric_columns {
+            let values: Vec<f64> = df.column(name)?.f64()?.into_no_null_iter().collect();
+            if let Some(values) = standardize(&values) {
+                features.push(name.to_owned());
+                standardized.push(values);
+            }
+        }
+        if features.len() < 2 || df.height() < 2 {
+            return Ok(Self::default());
+        }
+
+        let (eigenvalues, eigenvectors) = symmetric_eigen(correlation_matrix(&standardized));
+        let mut order: Vec<usize> = (0..eigenvalues.len()).collect();
+        order.sort_by(|a, b| eigenvalues[*b].total_cmp(&eigenvalues[*a]));
+
+        let total: f64 = eigenvalues.iter().map(|value| value.max(0.0)).sum();
+        let mut cumulative = 0.0;
+        let components = order
+            .into_iter()
+            .map(|component| {
+                let explained_variance_ratio = eigenvalues[component].max(0.0) / total;
+                cumulative += explained_variance_ratio;
+                let mut loadings: Vec<(String, f64)> = features
+                    .iter()
+                    .enumerate()
+                    .map(|(feature, name)| (name.clone(), eigenvectors[feature][component]))
+                    .collect();
+                loadings.sort_by(|a, b| b.1.abs().total_cmp(&a.1.abs()));
+                loadings.truncate(TOP_LOADINGS);
+                PrincipalComponent {
+                    explained_variance_ratio,
+                    cumulative_variance_ratio: cumulative.min(1.0),
+                    top_loadings: loadings,
+                }
+            })
+            .collect();
+
+        Ok(Self {
+            features,
+            components,
+        })
+    }
+}
+
+impl PcaAnalysis {
+    /// Gets the number of components needed to explain `EXPLAINED_VARIANCE_TARGET` of the total
+    /// variance, or None if there are no components.
+    pub fn intrinsic_dimensionality(&self) -> Option<usize> {
+        self.components
+            .iter()
+            .position(|component| component.cumulative_variance_ratio >= EXPLAINED_VARIANCE_TARGET)
+            .map(|index| index + 1)
+            .or_else(|| (!self.components.is_empty()).then_some(self.components.len()))
+    }
+}
+
+/// Computes the eigenvalues and eigenvectors (as the columns of the returned matrix) of a
+/// symmetric matrix with the cyclic Jacobi method.
+#[cfg(feature = "pca")]
+fn symmetric_eigen(mut matrix: Vec<Vec<f64>>) -> (Vec<f64>, Vec<Vec<f64>>) {
+    let size = matrix.len();
+    let mut vectors: Vec<Vec<f64>> = (0..size)
+        .map(|i| (0..size).map(|j| if i == j { 1.0 } else { 0.0 }).collect())
+        .collect();
+
+    for _ in 0..MAX_SWEEPS {
+        let off_diagonal: f64 = (0..size)
+            .flat_map(|i| (0..size).filter(move |j| *j != i).map(move |j| (i, j)))
+            .map(|(i, j)| matrix[i][j] * matrix[i][j])
+            .sum();
+        if off_diagonal < 1e-20 {
+            break;
+        }
+
+        for p in 0..size {
+            for q in (p + 1)..size {
+                if matrix[p][q].abs() < 1e-300 {
+                    continue;
+                }
+                // Rotate to zero out matrix[p][q].
+                let theta = (matrix[q][q] - matrix[p][p]) / (2.0 * matrix[p][q]);
+                let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                let c = 1.0 / (t * t + 1.0).sqrt();
+                let s = t * c;
+
+                // A' = J^T A J, first rotating the columns and then the rows.
+                for row in matrix.iter_mut() {
+                    let (kp, kq) = (row[p], row[q]);
+                    row[p] = c * kp - s * kq;
+                    row[q] = s * kp + c * kq;
+                }
+                let (row_p, row_q) = (matrix[p].clone(), matrix[q].clone());
+                for (k, (pk, qk)) in row_p.iter().zip(&row_q).enumerate() {
+                    matrix[p][k] = c * pk - s * qk;
+                    matrix[q][k] = s * pk + c * qk;
+                }
+                for row in vectors.iter_mut() {
+                    let (vp, vq) = (row[p], row[q]);
+                    row[p] = c * vp - s * vq;
+                    row[q] = s * vp + c * vq;
+                }
+            }
+        }
+    }
+
+    ((0..size).map(|i| matrix[i][i]).collect(), vectors)
+}
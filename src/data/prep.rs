@@ -0,0 +1,219 @@
+//! # Prep Module
+//!
+//! This module handles the light data preparation applied to a dataset before it's analyzed:
+//! filtering rows, deriving or rewriting columns, casting columns, and dropping columns. It saves
+//! writing a separate preprocessing script for the small fixes a dataset often needs before its
+//! report is useful.
+//!
+//! The steps run in order, after the data type overrides and date parsing and before the
+//! columns tagged `ignore` in the data dictionary are dropped, so they can use the parsed columns.
+//!
+//! The row filter is the one-off form of a filter step, e.g. `--filter "country = 'US'"`. It runs
+//! before the prep steps and is echoed on the report title page, since the report then only
+//! describes the matching rows.
+//!
+//! ## Prep Files
+//!
+//! Through the library, the steps are built from Polars expressions. On the command line, they're
+//! read from a YAML or JSON file holding a list of steps, each a single-key map, with the
+//! expressions written in SQL:
+//!
+//! ```yaml
+//! - filter: amount > 0 AND status <> 'test'
+//! - with_columns:
+//!     total: price * quantity
+//!     region: UPPER(region)
+//! - cast:
+//!     zip: String
+//! - drop: [internal_notes]
+//! ```
+
+use super::base::parse_dtype;
+use indexmap::IndexMap;
+use polars::prelude::*;
+use polars::sql::sql_expr;
+use serde::Deserialize;
+use std::ffi::OsStr;
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// The error types for the prep module.
+#[derive(Error, Debug)]
+pub enum PrepError {
+    /// Occurs when an I/O operation fails.
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// Occurs when a YAML prep file can't be parsed.
+    #[error("YAML error: {0}")]
+    Yaml(#[from] serde_yaml::Error),
+
+    /// Occurs when a JSON prep file can't be parsed.
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+
+    /// Occurs when an unsupported file format is passed.
+    #[error("Unsupported prep file format: {0}")]
+    UnsupportedFormat(String),
+
+    /// Occurs when an SQL expression of a prep step or row filter can't be parsed.
+    #[error("Invalid SQL expression `{expression}`: {source}")]
+    Expression {
+        expression: String,
+        source: PolarsError,
+    },
+
+    /// Occurs when a cast step has an unknown data type.
+    #[error("{0}")]
+    DataType(String),
+
+    /// Occurs when the row filter can't be applied to the dataset, e.g. because it references a
+    /// missing column.
+    #[error("Unable to apply the row filter: {0}")]
+    Filter(PolarsError),
+
+    /// Occurs when the prep steps can't be applied to the dataset, e.g. because they reference
+    /// a missing column.
+    #[error("Unable to apply the prep steps: {0}")]
+    Apply(PolarsError),
+}
+
+/// A data preparation step applied to the dataset before it's analyzed.
+#[derive(Debug, Clone)]
+pub enum PrepStep {
+    /// Keeps the rows for which the predicate is true.
+    Filter(Expr),
+    /// Adds or replaces columns, each named after its expression's output name.
+    WithColumns(Vec<Expr>),
+    /// Drops columns by name.
+    Drop(Vec<String>),
+}
+
+impl PrepStep {
+    /// Applies the step to a dataset.
+    ///
+    /// ### Parameters
+    /// - `lazy_df`: The dataset.
+    ///
+    /// ### Returns
+    /// - `LazyFrame`: The prepared dataset.
+    pub fn apply(&self, lazy_df: LazyFrame) -> LazyFrame {
+        match self {
+            PrepStep::Filter(predicate) => lazy_df.filter(predicate.clone()),
+            PrepStep::WithColumns(exprs) => lazy_df.with_columns(exprs.clone()),
+            PrepStep::Drop(columns) => lazy_df.drop(columns.clone()),
+        }
+    }
+}
+
+/// A row filter restricting the analyses to the rows matching an SQL predicate.
+#[derive(Debug, Clone)]
+pub struct RowFilter {
+    /// The SQL predicate as written, e.g. `country = 'US' AND age >= 18`.
+    pub expression: String,
+    /// The parsed predicate.
+    pub predicate: Expr,
+}
+
+impl FromStr for RowFilter {
+    type Err = String;
+
+    /// Parses an SQL predicate, e.g. `country = 'US'` or `amount > 0 AND status <> 'test'`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(RowFilter {
+            expression: s.to_owned(),
+            predicate: parse_expression(s).map_err(|e| e.to_string())?,
+        })
+    }
+}
+
+impl fmt::Display for RowFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.expression)
+    }
+}
+
+/// A prep step as written in a prep file.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum PrepFileStep {
+    Filter(String),
+    WithColumns(IndexMap<String, String>),
+    Cast(IndexMap<String, String>),
+    Drop(Vec<String>),
+}
+
+impl TryFrom<PrepFileStep> for PrepStep {
+    type Error = PrepError;
+
+    fn try_from(step: PrepFileStep) -> Result<Self, Self::Error> {
+        let step = match step {
+            PrepFileStep::Filter(predicate) => PrepStep::Filter(parse_expression(&predicate)?),
+            PrepFileStep::WithColumns(columns) => PrepStep::WithColumns(
+                columns
+                    .iter()
+                    .map(|(name, expression)| Ok(parse_expression(expression)?.alias(name)))
+                    .collect::<Result<_, PrepError>>()?,
+            ),
+            PrepFileStep::Cast(columns) => PrepStep::WithColumns(
+                columns
+                    .iter()
+                    .map(|(name, dtype)| {
+                        Ok(col(name).cast(parse_dtype(dtype).map_err(PrepError::DataType)?))
+                    })
+                    .collect::<Result<_, PrepError>>()?,
+            ),
+            PrepFileStep::Drop(columns) => PrepStep::Drop(columns),
+        };
+        Ok(step)
+    }
+}
+
+/// Parses an SQL expression into a Polars expression.
+fn parse_expression(expression: &str) -> Result<Expr, PrepError> {
+    sql_expr(expression).map_err(|source| PrepError::Expression {
+        expression: expression.to_owned(),
+        source,
+    })
+}
+
+/// Loads the prep steps from a YAML or JSON prep file.
+///
+/// ### Parameters
+/// - `path`: The path to the prep file.
+///
+/// ### Returns
+/// - `Result<Vec<PrepStep>, PrepError>`: The prep steps in file order, or an error.
+///
+/// ### Errors
+/// This function can return a PrepError if:
+/// - The file can't be read or parsed, or has an unsupported extension.
+/// - An expression or data type of a step is invalid.
+pub fn load_from_file(path: &Path) -> Result<Vec<PrepStep>, PrepError> {
+    let steps: Vec<PrepFileStep> = match path.extension().and_then(OsStr::to_str) {
+        // serde_yaml reads enums from YAML tags, so the single-key maps go through JSON values.
+        Some("yaml") | Some("yml") => {
+            serde_json::from_value(serde_yaml::from_str(&std::fs::read_to_string(path)?)?)?
+        }
+        Some("json") => serde_json::from_str(&std::fs::read_to_string(path)?)?,
+        Some(ext) => return Err(PrepError::UnsupportedFormat(ext.to_owned())),
+        None => return Err(PrepError::UnsupportedFormat("No file extension".to_owned())),
+    };
+    steps.into_iter().map(PrepStep::try_from).collect()
+}
+
+/// Applies the prep steps to a dataset, in order.
+///
+/// ### Parameters
+/// - `lazy_df`: The dataset.
+/// - `steps`: The prep steps.
+///
+/// ### Returns
+/// - `LazyFrame`: The prepared dataset.
+pub fn apply_steps(lazy_df: LazyFrame, steps: &[PrepStep]) -> LazyFrame {
+    steps
+        .iter()
+        .fold(lazy_df, |lazy_df, step| step.apply(lazy_df))
+}
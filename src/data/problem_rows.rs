@@ -0,0 +1,262 @@
+//! # Problem Rows Module
+//!
+//! This module handles sampling the rows behind the row-level alerts, so readers can see what the
+//! offending values look like in context instead of only their counts. Two kinds of problems are
+//! sampled:
+//!
+//! - Outliers: rows whose value is beyond the IQR fences of a numerical feature.
+//! - Invalid formats: rows whose value fails the validation of a column detected as a structured
+//!   string (e.g. an email or URL column).
+//!
+//! Each sample is a seeded random sample of at most the requested number of rows (capped at
+//! `MAX_PROBLEM_ROWS`), in row order. Besides the one-based row number and the offending column,
+//! up to `MAX_CONTEXT_COLUMNS` other columns are shown, identifier columns first, and values are
+//! truncated to `MAX_VALUE_LENGTH` characters so the appendix stays readable for wide datasets.
+
+use crate::data::{
+    outliers::OutlierAnalysis,
+    text::{StructuredKind, TextAnalysis},
+};
+use polars::prelude::*;
+use std::fmt;
+use thiserror::Error;
+
+/// The maximum number of rows sampled per problem.
+pub const MAX_PROBLEM_ROWS: usize = 20;
+/// The maximum number of columns shown next to the offending column.
+pub const MAX_CONTEXT_COLUMNS: usize = 4;
+/// The maximum number of characters shown per value.
+pub const MAX_VALUE_LENGTH: usize = 32;
+/// The seed of the row samples, so reruns show the same rows.
+const SAMPLE_SEED: u64 = 0;
+/// The name of the temporary row number column.
+const ROW_COLUMN: &str = "__row_number";
+
+/// The error types for the problem rows module.
+#[derive(Error, Debug)]
+pub enum ProblemRowError {
+    /// Occurs when Polars fails to complete an operation.
+    #[error("Polars error: {0}")]
+    Polars(#[from] PolarsError),
+}
+
+/// The kind of problem of the sampled rows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProblemKind {
+    /// The values are beyond the IQR fences.
+    Outlier,
+    /// The values fail the validation of a structured string kind.
+    InvalidFormat(StructuredKind),
+}
+
+impl fmt::Display for ProblemKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProblemKind::Outlier => write!(f, "IQR outliers"),
+            ProblemKind::InvalidFormat(kind) => write!(f, "invalid {} values", kind),
+        }
+    }
+}
+
+/// A sample of the rows with a problem in a column.
+#[derive(Debug, Clone)]
+pub struct ProblemRowSample {
+    /// The offending column.
+    pub column: String,
+    /// The kind of problem.
+    pub kind: ProblemKind,
+    /// The total number of rows with the problem.
+    pub n_rows: u64,
+    /// The headers of the sample: `Row` (the one-based row number), the offending column, then the
+    /// context columns.
+    pub headers: Vec<String>,
+    /// The sampled rows, in row order, with a value per header.
+    pub rows: Vec<Vec<String>>,
+}
+
+/// Struct to hold the problem row samples for a dataset.
+#[derive(Debug, Default)]
+pub struct ProblemRowAnalysis {
+    /// The samples, outliers first, then invalid formats, each in column order.
+    pub samples: Vec<ProblemRowSample>,
+}
+
+impl ProblemRowAnalysis {
+    /// Constructor for the ProblemRowAnalysis struct.
+    ///
+    /// ### Parameters
+    ///
+    /// - `lazy_df`: Reference to the LazyFrame.
+    /// - `schema`: Reference to the lazy frame's schema.
+    /// - `outlier_analysis`: The outlier fences of the numerical features.
+    /// - `text_analysis`: The text profiles of the string columns.
+    /// - `id_columns`: The identifier columns, shown first among the context columns.
+    /// - `n_rows`: The number of rows to sample per problem, capped at `MAX_PROBLEM_ROWS`.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<Self, ProblemRowError>`: A new ProblemRowAnalysis instance or an error.
+    pub fn new(
+        lazy_df: &LazyFrame,
+        schema: &Schema,
+        outlier_analysis: &OutlierAnalysis,
+        text_analysis: &TextAnalysis,
+        id_columns: &[String],
+        n_rows: usize,
+    ) -> Result<Self, ProblemRowError> {
+        let n_rows = n_rows.min(MAX_PROBLEM_ROWS);
+        let mut samples = Vec::new();
+        if n_rows == 0 {
+            return Ok(Self { samples });
+        }
+
+        for (name, fences) in outlier_analysis
+            .fences
+            .iter()
+            .filter(|(_, fences)| fences.iqr_count > 0)
+        {
+            let column = col(name).cast(DataType::Float64);
+            let predicate = column
+                .clone()
+                .lt(lit(fences.iqr_lower))
+                .or(column.gt(lit(fences.iqr_upper)));
+            samples.push(sample_rows(
+                lazy_df,
+                schema,
+                name,
+                ProblemKind::Outlier,
+                predicate,
+                id_columns,
+                n_rows,
+            )?);
+        }
+        for (name, profile) in &text_analysis.columns {
+            let Some(validity) = profile
+                .validity
+                .as_ref()
+                .filter(|validity| validity.invalid_percentage > 0.0)
+            else {
+                continue;
+            };
+            let kind = validity.kind;
+            let predicate = col(name).map(
+                move |series| {
+                    let invalid: BooleanChunked = series
+                        .str()?
+                        .into_iter()
+                        .map(|value| value.map(|value| !kind.validate(value)))
+                        .collect();
+                    Ok(Some(invalid.into_series()))
+                },
+                GetOutput::from_type(DataType::Boolean),
+            );
+            samples.push(sample_rows(
+                lazy_df,
+                schema,
+                name,
+                ProblemKind::InvalidFormat(kind),
+                predicate,
+                id_columns,
+                n_rows,
+            )?);
+        }
+
+        Ok(Self { samples })
+    }
+}
+
+/// Samples the rows matching a problem predicate.
+fn sample_rows(
+    lazy_df: &LazyFrame,
+    schema: &Schema,
+    column: &str,
+    kind: ProblemKind,
+    predicate: Expr,
+    id_columns: &[String],
+    n_rows: usize,
+) -> Result<ProblemRowSample, ProblemRowError> {
+    let mut columns = vec![ROW_COLUMN, column];
+    for name in id_columns
+        .iter()
+        .map(String::as_str)
+        .chain(schema.iter_names().map(|name| name.as_str()))
+    {
+        if columns.len() == MAX_CONTEXT_COLUMNS + 2 {
+            break;
+        }
+        if schema.contains(name) && !columns.contains(&name) {
+            columns.push(name);
+        }
+    }
+
+    let offending = lazy_df
+        .clone()
+        .with_row_index(ROW_COLUMN, Some(1))
+        .filter(predicate)
+        .select(columns.iter().map(|name| col(name)).collect::<Vec<Expr>>())
+        .collect()?;
+    let n_offending = offending.height();
+    let sample = if n_offending > n_rows {
+        offending
+            .sample_n_literal(n_rows, false, false, Some(SAMPLE_SEED))?
+            .sort([ROW_COLUMN], SortMultipleOptions::default())?
+    } else {
+        offending
+    };
+
+    let values = sample
+        .get_columns()
+        .iter()
+        .map(|series| {
+            let values = if series.dtype().is_nested()
+                || matches!(series.dtype(), DataType::Binary | DataType::BinaryOffset)
+            {
+                series
+                    .iter()
+                    .map(|value| (!value.is_null()).then(|| value.to_string()))
+                    .collect()
+            } else {
+                series
+                    .cast(&DataType::String)?
+                    .str()?
+                    .into_iter()
+                    .map(|value| value.map(str::to_owned))
+                    .collect()
+            };
+            Ok(values)
+        })
+        .collect::<Result<Vec<Vec<Option<String>>>, ProblemRowError>>()?;
+    let rows = (0..sample.height())
+        .map(|row| {
+            values
+                .iter()
+                .map(|column| match &column[row] {
+                    Some(value) => truncate_value(value),
+                    None => "null".to_owned(),
+                })
+                .collect()
+        })
+        .collect();
+
+    Ok(ProblemRowSample {
+        column: column.to_owned(),
+        kind,
+        n_rows: n_offending as u64,
+        headers: ["Row"]
+            .into_iter()
+            .chain(columns[1..].iter().copied())
+            .map(str::to_owned)
+            .collect(),
+        rows,
+    })
+}
+
+/// Truncates a value to `MAX_VALUE_LENGTH` characters, marking the cut with an ellipsis.
+fn truncate_value(value: &str) -> String {
+    if value.chars().count() <= MAX_VALUE_LENGTH {
+        value.to_owned()
+    } else {
+        let truncated: String = value.chars().take(MAX_VALUE_LENGTH - 1).collect();
+        format!("{}…", truncated)
+    }
+}
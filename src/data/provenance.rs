@@ -0,0 +1,275 @@
+//! # Provenance Module
+//!
+//! This module fingerprints the input of a run so a report can be traced back to the exact data
+//! and configuration it was generated from:
+//!
+//! - Content hash: the SHA-256 digest of the input file, or of the partition files one after the
+//!   other in path order. Inputs that can't be read twice (the standard input, remote files, and
+//!   database queries) aren't hashed.
+//! - File size and modification time: the total size of the files and the latest time any of
+//!   them was modified.
+//! - Option set: the LEADS options the data was read and analyzed with that differ from their
+//!   defaults, along with the LEADS version.
+
+use crate::data::base::DataOptions;
+use chrono::{DateTime, SecondsFormat, Utc};
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fmt::Display;
+use std::fs::File;
+use std::io;
+use std::path::PathBuf;
+use thiserror::Error;
+
+/// The error types for the provenance module.
+#[derive(Error, Debug)]
+pub enum ProvenanceError {
+    /// Occurs when an input file or its metadata can't be read.
+    #[error("Failed to fingerprint {path}: {source}")]
+    Io {
+        /// The input file.
+        path: PathBuf,
+        /// The underlying error.
+        source: io::Error,
+    },
+}
+
+/// The fingerprint of the input of a run and the options it was run with.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Provenance {
+    /// Path or URL the dataset was read from, or the connection string without its password for
+    /// database sources.
+    pub source: String,
+    /// The number of files hashed, more than one for partitioned datasets.
+    pub n_files: usize,
+    /// The total size of the files in bytes, if they were read from disk.
+    pub file_size: Option<u64>,
+    /// The latest modification time of the files, as an RFC 3339 timestamp.
+    pub modified_at: Option<String>,
+    /// The hex SHA-256 digest of the file contents, if they were read from disk.
+    pub content_hash: Option<String>,
+    /// The options that differ from their defaults, keyed by option name.
+    pub options: IndexMap<String, String>,
+    /// The version of LEADS the report was generated with.
+    pub leads_version: String,
+}
+
+impl Provenance {
+    /// Constructor for the Provenance struct.
+    ///
+    /// ### Parameters
+    ///
+    /// - `source`: The path, URL, or redacted connection string the dataset was read from.
+    /// - `files`: The files the dataset was read from, in path order. Empty if it wasn't read
+    ///   from disk.
+    /// - `options`: The options the dataset was read and analyzed with.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<Self, ProvenanceError>`: A new Provenance instance or an error.
+    ///
+    /// ### Errors
+    ///
+    /// This method can return a ProvenanceError if a file or its metadata can't be read.
+    pub fn new(
+        source: &str,
+        files: &[PathBuf],
+        options: &DataOptions,
+    ) -> Result<Self, ProvenanceError> {
+        let mut provenance = Self {
+            source: source.to_owned(),
+            n_files: files.len(),
+            options: option_set(options),
+            leads_version: env!("CARGO_PKG_VERSION").to_owned(),
+            ..Default::default()
+        };
+        if files.is_empty() {
+            return Ok(provenance);
+        }
+
+        let mut hasher = Sha256::new();
+        let mut file_size = 0;
+        let mut modified_at: Option<DateTime<Utc>> = None;
+        for path in files {
+            let io_error = |source| ProvenanceError::Io {
+                path: path.clone(),
+                source,
+            };
+            let mut file = File::open(path).map_err(io_error)?;
+            let metadata = file.metadata().map_err(io_error)?;
+            file_size += metadata.len();
+            if let Ok(modified) = metadata.modified() {
+                let modified = DateTime::<Utc>::from(modified);
+                modified_at = Some(modified_at.map_or(modified, |latest| latest.max(modified)));
+            }
+            io::copy(&mut file, &mut hasher).map_err(io_error)?;
+        }
+        provenance.file_size = Some(file_size);
+        provenance.modified_at =
+            modified_at.map(|modified| modified.to_rfc3339_opts(SecondsFormat::Secs, true));
+        provenance.content_hash = Some(format!("{:x}", hasher.finalize()));
+        Ok(provenance)
+    }
+
+    /// Formats the option set as `name=value` pairs, e.g. `pca=true, vif_threshold=5`, or
+    /// `defaults` if every option has its default value.
+    pub fn format_options(&self) -> String {
+        if self.options.is_empty() {
+            return "defaults".to_owned();
+        }
+        self.options
+            .iter()
+            .map(|(name, value)| format!("{}={}", name, value))
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
+}
+
+/// Formats a file size in bytes with a binary unit, e.g. `1.50 MiB`.
+pub fn format_file_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut size = bytes as f64;
+    let mut unit = "B";
+    for next_unit in UNITS {
+        if size < 1024.0 {
+            break;
+        }
+        size /= 1024.0;
+        unit = next_unit;
+    }
+    format!("{:.2} {}", size, unit)
+}
+
+/// Gets the options that differ from their defaults. The progress callback, the stage timing
+/// flag, and the plot directory are left out since they don't change the analysis results.
+///
+/// ### Parameters
+///
+/// - `options`: The options the dataset was read and analyzed with.
+///
+/// ### Returns
+///
+/// - `IndexMap<String, String>`: The formatted values of the changed options, keyed by option
+///   name.
+pub fn option_set(options: &DataOptions) -> IndexMap<String, String> {
+    option_values(options)
+        .into_iter()
+        .zip(option_values(&DataOptions::default()))
+        .filter(|((_, value), (_, default))| value != default)
+        .map(|((name, value), _)| (name.to_owned(), value))
+        .collect()
+}
+
+/// Formats the value of every option that changes what's read or how it's analyzed.
+fn option_values(options: &DataOptions) -> Vec<(&'static str, String)> {
+    vec![
+        ("headers", options.headers.to_string()),
+        ("skip_rows", options.skip_rows.to_string()),
+        ("sheet", optional(&options.sheet)),
+        ("all_sheets", options.all_sheets.to_string()),
+        ("format", optional(&options.format)),
+        ("delimiter", optional_char(options.csv.delimiter)),
+        ("quote_char", optional_char(options.csv.quote_char)),
+        ("comment_char", optional_char(options.csv.comment_char)),
+        ("null_values", list(&options.csv.null_values)),
+        ("fixed_width", optional(&options.fixed_width)),
+        ("encoding", optional(&options.encoding)),
+        ("dtype_overrides", list(&options.dtype_overrides)),
+        ("include_columns", list(&options.columns.include)),
+        ("exclude_columns", list(&options.columns.exclude)),
+        ("null_if", list(&options.null_if)),
+        ("references", list(&options.references)),
+        ("parse_dates", options.parse_dates.to_string()),
+        ("date_formats", list(&options.date_formats)),
+        (
+            "data_dictionary",
+            match &options.data_dictionary {
+                Some(dictionary) => format!("{} columns", dictionary.columns.len()),
+                None => "none".to_owned(),
+            },
+        ),
+        ("privacy", options.privacy.to_string()),
+        ("sample_mode", format!("{:?}", options.sample_mode)),
+        ("heatmap_order", options.heatmap_order.to_string()),
+        (
+            "plot_dpi",
+            format!(
+                "{}/{}",
+                options.plot_resolution.embedded_dpi, options.plot_resolution.exported_dpi
+            ),
+        ),
+        ("wide_mode", options.wide_mode.to_string()),
+        ("max_column_plots", options.max_column_plots.to_string()),
+        ("target", optional(&options.target)),
+        ("statistics", options.statistics.to_string()),
+        ("vif_threshold", options.vif_threshold.to_string()),
+        (
+            "correlation_threshold",
+            options.correlation_threshold.to_string(),
+        ),
+        (
+            "rare_category_threshold",
+            options.rare_category_threshold.to_string(),
+        ),
+        (
+            "row_order_threshold",
+            options.row_order_threshold.to_string(),
+        ),
+        ("pca", options.pca.to_string()),
+        ("analysis_timeout", optional(&options.analysis_timeout)),
+        ("crosstab_pairs", list(&options.crosstab_pairs)),
+        ("time_index", optional(&options.time_index)),
+        ("rolling_window", options.rolling_window.to_string()),
+        ("record_timestamp", optional(&options.record_timestamp)),
+        ("ingested_at", optional(&options.ingested_at)),
+        (
+            "freshness_threshold",
+            options.freshness_threshold.to_string(),
+        ),
+        ("session_entity", optional(&options.session_entity)),
+        ("session_timestamp", optional(&options.session_timestamp)),
+        ("session_gap", options.session_gap.to_string()),
+        ("expand_json", options.expand_json.to_string()),
+        ("flatten_nested", options.flatten_nested.to_string()),
+        ("row_filter", optional(&options.row_filter)),
+        ("prep_steps", options.prep.len().to_string()),
+        ("strict_schema", options.strict_schema.to_string()),
+        ("row_limit", optional(&options.row_limit)),
+        (
+            "baseline",
+            match &options.baseline {
+                Some(baseline) => format!("{} ({})", baseline.dataset, baseline.created_at),
+                None => "none".to_owned(),
+            },
+        ),
+        ("key_columns", list(&options.key_columns)),
+        ("column_tolerance", options.column_tolerance.to_string()),
+        ("example_values", options.example_values.to_string()),
+        ("problem_rows", options.problem_rows.to_string()),
+    ]
+}
+
+/// Formats an optional option value, `none` if unset.
+fn optional<T: Display>(value: &Option<T>) -> String {
+    value
+        .as_ref()
+        .map_or_else(|| "none".to_owned(), ToString::to_string)
+}
+
+/// Formats an optional character given as a byte, `none` if unset.
+fn optional_char(value: Option<u8>) -> String {
+    optional(&value.map(char::from))
+}
+
+/// Formats a list option value as comma separated values.
+fn list<T: Display>(values: &[T]) -> String {
+    values
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<String>>()
+        .join(",")
+}
@@ -0,0 +1,78 @@
+//! # Query Module
+//!
+//! This module handles running an SQL query over a loaded dataset (with Polars SQL), e.g. to
+//! inspect a few rows or profile a subset of the data without writing it to a file first. It's
+//! unrelated to the `sql` feature, which reads the data from a database instead.
+//!
+//! The dataset is registered as the table `data` and under its dataset name, so the query for
+//! `orders.csv` can read from either `data` or `orders`. Dataset names that aren't plain
+//! identifiers have to be quoted, e.g. `SELECT * FROM "orders 2024"`.
+
+use polars::prelude::*;
+use polars::sql::SQLContext;
+use thiserror::Error;
+
+/// The table name the dataset is always registered under.
+pub const DATA_TABLE: &str = "data";
+/// The default number of result rows printed.
+pub const DEFAULT_PRINTED_ROWS: usize = 20;
+
+/// The error types for the query module.
+#[derive(Error, Debug)]
+pub enum QueryError {
+    /// Occurs when the query can't be parsed or planned, e.g. because it references a missing
+    /// table or column.
+    #[error("Invalid SQL query `{query}`: {source}")]
+    Query { query: String, source: PolarsError },
+
+    /// Occurs when Polars fails to complete an operation.
+    #[error("Polars error: {0}")]
+    Polars(#[from] PolarsError),
+}
+
+/// Runs an SQL query over a dataset.
+///
+/// ### Parameters
+/// - `lazy_df`: The dataset.
+/// - `dataset_name`: The name the dataset is registered under, along with `data`.
+/// - `query`: The SQL query, e.g. `SELECT name, SUM(amount) FROM data GROUP BY name`.
+///
+/// ### Returns
+/// - `Result<LazyFrame, QueryError>`: The query result, or an error.
+///
+/// ### Errors
+/// This function can return a QueryError if the query can't be parsed or references a missing
+/// table or column.
+pub fn run_query(
+    lazy_df: LazyFrame,
+    dataset_name: &str,
+    query: &str,
+) -> Result<LazyFrame, QueryError> {
+    let mut context = SQLContext::new();
+    if dataset_name != DATA_TABLE {
+        context.register(dataset_name, lazy_df.clone());
+    }
+    context.register(DATA_TABLE, lazy_df);
+    let mut result = context.execute(query).map_err(|source| QueryError::Query {
+        query: query.to_owned(),
+        source,
+    })?;
+    // Planning errors (e.g. a missing column) only surface with the schema.
+    result.schema().map_err(|source| QueryError::Query {
+        query: query.to_owned(),
+        source,
+    })?;
+    Ok(result)
+}
+
+/// Collects the first rows of a query result to print.
+///
+/// ### Parameters
+/// - `result`: The query result.
+/// - `n_rows`: The maximum number of rows.
+///
+/// ### Returns
+/// - `Result<DataFrame, QueryError>`: The first rows of the result, or an error.
+pub fn head(result: LazyFrame, n_rows: usize) -> Result<DataFrame, QueryError> {
+    Ok(result.limit(n_rows as IdxSize).collect()?)
+}
@@ -0,0 +1,232 @@
+//! # Recovery Module
+//!
+//! This module keeps a run going when one of the analyses fails, e.g. on a column with values it
+//! can't handle. The failed analysis falls back to its empty results and the error is recorded, so
+//! the report still gets generated with an "analysis unavailable" note in place of that section.
+//! Panics are caught and recorded like errors.
+//!
+//! The distribution statistics of the descriptive analysis (skewness, kurtosis, histogram, and
+//! normality test) are recovered per column instead, so a column they fail on only loses its own
+//! statistics.
+
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+
+/// The analyses whose failure is recorded instead of aborting the run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecoverableAnalysis {
+    /// The descriptive statistics of the features.
+    Descriptive,
+    /// The skewness, kurtosis, histogram, and normality test of a numerical feature.
+    DistributionShape,
+    /// The missing value counts and concentrations.
+    MissingValues,
+    /// The duplicate rows, key violations, and duplicate columns.
+    Duplicates,
+    /// The values missing from the reference lists.
+    ForeignValues,
+    /// The robust location and scale statistics of the numerical features.
+    Robust,
+    /// The transformation suggestions of the skewed features.
+    Transformations,
+    /// The rare category merge suggestions.
+    Categories,
+    /// The cross-tabulations of the categorical pairs.
+    Associations,
+    /// The variance inflation factors of the numerical features.
+    Collinearity,
+    /// The Pearson and Spearman correlations of the numerical features.
+    Correlations,
+    /// The correlation of each feature with the row index.
+    RowOrder,
+    /// The profile of the date, datetime, and time columns.
+    Temporal,
+    /// The outlier fences of the numerical features.
+    Outliers,
+    /// The text profiling of the string columns.
+    TextProfiling,
+    /// The principal component analysis.
+    Pca,
+    /// The samples of the rows behind the outlier and invalid format alerts.
+    ProblemRows,
+    /// The analysis of the features against the target column.
+    Target,
+    /// The analysis of the features over the time index.
+    TimeSeries,
+    /// The drift of the features from the baseline profile.
+    Drift,
+    /// The freshness of the records.
+    Freshness,
+    /// The sessions of the event log.
+    Sessions,
+}
+
+impl fmt::Display for RecoverableAnalysis {
+    /// Formats the analysis as the title of its report section.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RecoverableAnalysis::Descriptive => write!(f, "Descriptive Analysis"),
+            RecoverableAnalysis::DistributionShape => write!(f, "Distribution Shape"),
+            RecoverableAnalysis::MissingValues => write!(f, "Missing Values Analysis"),
+            RecoverableAnalysis::Duplicates => write!(f, "Duplicates"),
+            RecoverableAnalysis::ForeignValues => write!(f, "Foreign Values"),
+            RecoverableAnalysis::Robust => write!(f, "Robust Statistics"),
+            RecoverableAnalysis::Transformations => write!(f, "Transformation Suggestions"),
+            RecoverableAnalysis::Categories => write!(f, "Category Merges"),
+            RecoverableAnalysis::Associations => write!(f, "Categorical Associations"),
+            RecoverableAnalysis::Collinearity => write!(f, "Multicollinearity"),
+            RecoverableAnalysis::Correlations => write!(f, "Correlations"),
+            RecoverableAnalysis::RowOrder => write!(f, "Row Order"),
+            RecoverableAnalysis::Temporal => write!(f, "Temporal Columns"),
+            RecoverableAnalysis::Outliers => write!(f, "Outlier Analysis"),
+            RecoverableAnalysis::TextProfiling => write!(f, "Text Profiling"),
+            RecoverableAnalysis::Pca => write!(f, "Principal Component Analysis"),
+            RecoverableAnalysis::ProblemRows => write!(f, "Problem Rows"),
+            RecoverableAnalysis::Target => write!(f, "Target Analysis"),
+            RecoverableAnalysis::TimeSeries => write!(f, "Time Series Analysis"),
+            RecoverableAnalysis::Drift => write!(f, "Drift"),
+            RecoverableAnalysis::Freshness => write!(f, "Freshness"),
+            RecoverableAnalysis::Sessions => write!(f, "Sessions"),
+        }
+    }
+}
+
+/// An analysis that failed, with the error it failed with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnalysisFailure {
+    /// The failed analysis.
+    pub analysis: RecoverableAnalysis,
+    /// The column the analysis failed on, for the analyses recovered per column.
+    pub column: Option<String>,
+    /// The error message, or the panic message if the analysis panicked.
+    pub error: String,
+}
+
+/// Runs an analysis, falling back to its empty results and recording the failure if it returns
+/// an error or panics.
+///
+/// ### Parameters
+///
+/// - `analysis`: The analysis being run.
+/// - `failed_analyses`: The failures of the run so far, which a failure is appended to.
+/// - `run`: The analysis to run.
+///
+/// ### Returns
+///
+/// - `T`: The results of the analysis, or its empty results if it failed.
+pub fn run_recoverable<T, E, F>(
+    analysis: RecoverableAnalysis,
+    failed_analyses: &mut Vec<AnalysisFailure>,
+    run: F,
+) -> T
+where
+    T: Default,
+    E: fmt::Display,
+    F: FnOnce() -> Result<T, E>,
+{
+    run_recoverable_or_else(analysis, failed_analyses, run, T::default)
+}
+
+/// Runs an analysis, falling back to the results of `fallback` and recording the failure if it
+/// returns an error or panics. Used for the analyses whose empty results depend on the dataset,
+/// e.g. its row count.
+///
+/// ### Parameters
+///
+/// - `analysis`: The analysis being run.
+/// - `failed_analyses`: The failures of the run so far, which a failure is appended to.
+/// - `run`: The analysis to run.
+/// - `fallback`: Builds the results used if the analysis fails.
+///
+/// ### Returns
+///
+/// - `T`: The results of the analysis, or the fallback results if it failed.
+pub fn run_recoverable_or_else<T, E, F, D>(
+    analysis: RecoverableAnalysis,
+    failed_analyses: &mut Vec<AnalysisFailure>,
+    run: F,
+    fallback: D,
+) -> T
+where
+    E: fmt::Display,
+    F: FnOnce() -> Result<T, E>,
+    D: FnOnce() -> T,
+{
+    match catch_failure(run) {
+        Ok(result) => result,
+        Err(error) => {
+            failed_analyses.push(AnalysisFailure {
+                analysis,
+                column: None,
+                error,
+            });
+            fallback()
+        }
+    }
+}
+
+/// Runs an analysis of a single column, recording the failure if it returns an error or panics.
+///
+/// ### Parameters
+///
+/// - `analysis`: The analysis being run.
+/// - `column`: The column the analysis is run on.
+/// - `failed_analyses`: The failures so far, which a failure is appended to.
+/// - `run`: The analysis to run.
+///
+/// ### Returns
+///
+/// - `Option<T>`: The results of the analysis, or None if it failed.
+pub fn run_recoverable_column<T, E, F>(
+    analysis: RecoverableAnalysis,
+    column: &str,
+    failed_analyses: &mut Vec<AnalysisFailure>,
+    run: F,
+) -> Option<T>
+where
+    E: fmt::Display,
+    F: FnOnce() -> Result<T, E>,
+{
+    match catch_failure(run) {
+        Ok(result) => Some(result),
+        Err(error) => {
+            failed_analyses.push(AnalysisFailure {
+                analysis,
+                column: Some(column.to_owned()),
+                error,
+            });
+            None
+        }
+    }
+}
+
+/// Runs an analysis, catching its panics, without recording a failure. Used to retry a failed
+/// analysis in smaller parts.
+///
+/// ### Parameters
+///
+/// - `run`: The analysis to run.
+///
+/// ### Returns
+///
+/// - `Result<T, String>`: The results of the analysis, or its error or panic message.
+pub fn catch_failure<T, E, F>(run: F) -> Result<T, String>
+where
+    E: fmt::Display,
+    F: FnOnce() -> Result<T, E>,
+{
+    match panic::catch_unwind(AssertUnwindSafe(run)) {
+        Ok(Ok(result)) => Ok(result),
+        Ok(Err(error)) => Err(error.to_string()),
+        Err(panic) => Err(
+            match panic
+                .downcast_ref::<&str>()
+                .map(|message| message.to_string())
+                .or_else(|| panic.downcast_ref::<String>().cloned())
+            {
+                Some(message) => format!("panicked: {}", message),
+                None => "panicked".to_owned(),
+            },
+        ),
+    }
+}
@@ -0,0 +1,201 @@
+//! # References Module
+//!
+//! This module handles checking the values of columns against reference lists, e.g. a list of
+//! ISO country codes or of the product IDs in the catalog. Values missing from the reference are
+//! foreign values: typos, retired codes, or rows pointing at records that don't exist.
+//!
+//! A reference list is a text file with one value per line. Surrounding whitespace and blank
+//! lines are ignored. Columns are compared as text, so a numerical column matches the reference
+//! value `42` but not `42.0`. Missing values aren't checked, since they're counted by the missing
+//! value analysis.
+
+use indexmap::IndexMap;
+use polars::prelude::*;
+use std::collections::HashSet;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use thiserror::Error;
+
+/// The maximum number of foreign values listed for each column, the most frequent ones.
+pub const MAX_FOREIGN_VALUES: usize = 10;
+
+/// The error types for the references module.
+#[derive(Error, Debug)]
+pub enum ReferenceError {
+    /// Occurs when Polars fails to complete an operation.
+    #[error("Polars error: {0}")]
+    Polars(#[from] PolarsError),
+
+    /// Occurs when a reference list can't be read.
+    #[error("Unable to read the reference list {path}: {source}")]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+
+    /// Occurs when a reference list's column isn't in the dataset.
+    #[error("Reference column not found: {0}")]
+    MissingColumn(String),
+}
+
+/// A reference list of the valid values of a column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReferenceList {
+    /// The name of the column.
+    pub column: String,
+    /// The path of the reference list, with one value per line.
+    pub path: PathBuf,
+}
+
+impl FromStr for ReferenceList {
+    type Err = String;
+
+    /// Parses a `<column>=<path>` reference list, e.g. `country=iso_countries.txt`. The path is
+    /// everything after the first `=`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once('=') {
+            Some((column, path)) if !column.is_empty() && !path.is_empty() => Ok(ReferenceList {
+                column: column.to_owned(),
+                path: PathBuf::from(path),
+            }),
+            _ => Err(format!(
+                "Invalid reference list: {}. Expected <column>=<path>, e.g. \
+                 country=iso_countries.txt.",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for ReferenceList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}={}", self.column, self.path.display())
+    }
+}
+
+/// The values of a column missing from its reference list.
+#[derive(Debug, Clone)]
+pub struct ForeignValues {
+    /// The path of the reference list.
+    pub reference: PathBuf,
+    /// The number of distinct values in the reference list.
+    pub n_reference: usize,
+    /// The number of non-null values checked.
+    pub count: u64,
+    /// The number of values missing from the reference list.
+    pub n_foreign: u64,
+    /// The number of distinct values missing from the reference list.
+    pub n_distinct_foreign: usize,
+    /// The `MAX_FOREIGN_VALUES` most frequent foreign values with their counts, by descending
+    /// count.
+    pub top_foreign: Vec<(String, u64)>,
+}
+
+impl ForeignValues {
+    /// Gets the percentage of the non-null values missing from the reference list.
+    pub fn foreign_percentage(&self) -> f64 {
+        if self.count == 0 {
+            0.0
+        } else {
+            self.n_foreign as f64 / self.count as f64 * 100.0
+        }
+    }
+}
+
+/// Struct to hold the reference list checks of a dataset.
+#[derive(Debug)]
+pub struct ForeignValueAnalysis {
+    /// The foreign values keyed by column name, in reference list order.
+    pub columns: IndexMap<String, ForeignValues>,
+}
+
+impl ForeignValueAnalysis {
+    /// Constructor for the ForeignValueAnalysis struct.
+    ///
+    /// ### Parameters
+    ///
+    /// - `lazy_df`: Reference to the LazyFrame.
+    /// - `schema`: Reference to the lazy frame's schema.
+    /// - `references`: The reference lists. A later list for the same column replaces an earlier
+    ///   one.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<Self, ReferenceError>`: A new ForeignValueAnalysis instance or an error.
+    ///
+    /// ### Errors
+    ///
+    /// This function can return a ReferenceError if a reference list's column isn't in the
+    /// dataset or the list can't be read.
+    pub fn new(
+        lazy_df: &LazyFrame,
+        schema: &Schema,
+        references: &[ReferenceList],
+    ) -> Result<Self, ReferenceError> {
+        let mut columns = IndexMap::new();
+        for reference in references {
+            if schema.get(&reference.column).is_none() {
+                return Err(ReferenceError::MissingColumn(reference.column.clone()));
+            }
+            let valid_values = read_reference(&reference.path)?;
+
+            let counts = lazy_df
+                .clone()
+                .select([col(&reference.column).cast(DataType::String)])
+                .drop_nulls(None)
+                .group_by([col(&reference.column)])
+                .agg([len().cast(DataType::UInt64).alias("__count")])
+                .collect()?;
+            let mut count = 0;
+            let mut foreign: Vec<(String, u64)> = Vec::new();
+            for (value, n) in counts
+                .column(&reference.column)?
+                .str()?
+                .into_iter()
+                .zip(counts.column("__count")?.u64()?)
+            {
+                let (Some(value), Some(n)) = (value, n) else {
+                    continue;
+                };
+                count += n;
+                if !valid_values.contains(value.trim()) {
+                    foreign.push((value.to_owned(), n));
+                }
+            }
+            // Ties are broken by value, since the group order varies from run to run.
+            foreign.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+            let n_distinct_foreign = foreign.len();
+            let n_foreign = foreign.iter().map(|(_, n)| n).sum();
+            foreign.truncate(MAX_FOREIGN_VALUES);
+
+            columns.insert(
+                reference.column.clone(),
+                ForeignValues {
+                    reference: reference.path.clone(),
+                    n_reference: valid_values.len(),
+                    count,
+                    n_foreign,
+                    n_distinct_foreign,
+                    top_foreign: foreign,
+                },
+            );
+        }
+
+        Ok(Self { columns })
+    }
+}
+
+/// Reads the distinct values of a reference list, one per line, trimmed and without blank lines.
+fn read_reference(path: &Path) -> Result<HashSet<String>, ReferenceError> {
+    let contents = std::fs::read_to_string(path).map_err(|source| ReferenceError::Io {
+        path: path.to_owned(),
+        source,
+    })?;
+    Ok(contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(str::to_owned)
+        .collect())
+}
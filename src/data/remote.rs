@@ -0,0 +1,75 @@
+//! # Remote Module
+//!
+//! This module handles fetching datasets from `http(s)://` URLs and `s3://` URIs (with the
+//! `remote` feature), so datasets in object storage can be analyzed without a manual download
+//! step.
+//!
+//! S3 objects are read through the Polars cloud support, with the credentials and region taken
+//! from the standard AWS environment variables (`AWS_ACCESS_KEY_ID`, `AWS_SECRET_ACCESS_KEY`,
+//! `AWS_REGION`, `AWS_ENDPOINT_URL`, ...). Uncompressed CSV, TSV, and Parquet objects are scanned
+//! by Polars directly, while the other objects and HTTP(S) files are downloaded into memory.
+
+use polars::io::cloud::build_object_store;
+use polars::io::pl_async::get_runtime;
+use polars::prelude::*;
+use std::io::Read;
+use thiserror::Error;
+
+/// The error types for the remote module.
+#[derive(Error, Debug)]
+pub enum RemoteError {
+    /// Occurs when an HTTP(S) request fails.
+    #[error("HTTP error: {0}")]
+    Http(#[from] Box<ureq::Error>),
+
+    /// Occurs when the response body can't be read.
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// Occurs when the object store for an S3 URI can't be set up.
+    #[error("Polars error: {0}")]
+    Polars(#[from] PolarsError),
+
+    /// Occurs when an S3 object can't be fetched.
+    #[error("Object store error: {0}")]
+    ObjectStore(#[from] object_store::Error),
+}
+
+/// Checks whether a URI points to an S3 object.
+pub fn is_s3(uri: &str) -> bool {
+    uri.starts_with("s3://")
+}
+
+/// Downloads a remote file into memory.
+///
+/// ### Parameters
+///
+/// - `uri`: The `http(s)://` URL or `s3://` URI of the file.
+///
+/// ### Returns
+///
+/// - `Result<Vec<u8>, RemoteError>`: The contents of the file or an error.
+///
+/// ### Errors
+///
+/// This function can return a RemoteError if:
+/// - The request fails or the server responds with an error status.
+/// - The S3 credentials or region can't be resolved, or the object doesn't exist.
+pub fn download(uri: &str) -> Result<Vec<u8>, RemoteError> {
+    if is_s3(uri) {
+        return get_runtime().block_on(async {
+            let (location, store) = build_object_store(uri, None).await?;
+            let key = object_store::path::Path::from(location.prefix);
+            let bytes = store.get(&key).await?.bytes().await?;
+            Ok(bytes.to_vec())
+        });
+    }
+
+    let mut data = Vec::new();
+    ureq::get(uri)
+        .call()
+        .map_err(Box::new)?
+        .into_reader()
+        .read_to_end(&mut data)?;
+    Ok(data)
+}
@@ -0,0 +1,252 @@
+//! # Robust Statistics Module
+//!
+//! This module handles the outlier-robust statistics of the numerical features of a dataset, for
+//! datasets known to contain measurement spikes that distort the classical (mean and standard
+//! deviation based) statistics.
+//!
+//! - MAD: the median absolute deviation from the median, scaled by `MAD_SCALE` so it estimates
+//!   the standard deviation of normally distributed data.
+//! - Trimmed range: the `TRIM_FRACTION` and `1 - TRIM_FRACTION` quantiles, i.e. the range of the
+//!   values once the most extreme values on each side are trimmed.
+//! - Trimmed mean: the mean of the values within the trimmed range.
+//! - MAD fences: values whose robust z-score `(x - median) / MAD` is beyond
+//!   `ROBUST_Z_THRESHOLD` are outliers.
+//!
+//! The statistics mode decides whether the robust statistics are computed and whether they're
+//! shown alongside or instead of the classical ones.
+
+use indexmap::IndexMap;
+use polars::prelude::*;
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// The scale factor that makes the MAD a consistent estimator of the standard deviation.
+pub const MAD_SCALE: f64 = 1.4826;
+/// The fraction of the values trimmed on each side for the trimmed range and mean.
+pub const TRIM_FRACTION: f64 = 0.05;
+/// The robust z-score beyond which a value is an outlier (Iglewicz and Hoaglin).
+pub const ROBUST_Z_THRESHOLD: f64 = 3.5;
+
+/// The error types for the robust statistics module.
+#[derive(Error, Debug)]
+pub enum RobustError {
+    /// Occurs when Polars fails to complete an operation.
+    #[error("Polars error: {0}")]
+    Polars(#[from] PolarsError),
+}
+
+/// Which statistics are computed and shown for the numerical features.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum StatisticsMode {
+    /// Only the classical statistics.
+    #[default]
+    Classical,
+    /// The robust statistics alongside the classical ones.
+    Both,
+    /// The robust statistics instead of the classical mean, standard deviation, skewness,
+    /// kurtosis, and z-score fences.
+    Robust,
+}
+
+impl StatisticsMode {
+    /// Checks whether the robust statistics are computed.
+    pub fn shows_robust(&self) -> bool {
+        *self != StatisticsMode::Classical
+    }
+
+    /// Checks whether the classical statistics are shown.
+    pub fn shows_classical(&self) -> bool {
+        *self != StatisticsMode::Robust
+    }
+}
+
+impl FromStr for StatisticsMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "classical" => Ok(StatisticsMode::Classical),
+            "both" => Ok(StatisticsMode::Both),
+            "robust" => Ok(StatisticsMode::Robust),
+            _ => Err(format!(
+                "Invalid statistics mode: {}. Expected classical, both, or robust.",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for StatisticsMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mode = match self {
+            StatisticsMode::Classical => "classical",
+            StatisticsMode::Both => "both",
+            StatisticsMode::Robust => "robust",
+        };
+        write!(f, "{}", mode)
+    }
+}
+
+/// The robust statistics of a single feature.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RobustStats {
+    /// The median.
+    pub median: f64,
+    /// The scaled median absolute deviation.
+    pub mad: f64,
+    /// The lower bound of the trimmed range.
+    pub trimmed_min: f64,
+    /// The upper bound of the trimmed range.
+    pub trimmed_max: f64,
+    /// The mean of the values within the trimmed range.
+    pub trimmed_mean: f64,
+    /// The lower MAD fence.
+    pub mad_lower: f64,
+    /// The upper MAD fence.
+    pub mad_upper: f64,
+    /// The number of values beyond the MAD fences.
+    pub mad_count: u64,
+}
+
+/// Struct to hold the robust statistics of the numerical features of a dataset.
+#[derive(Debug, Default)]
+pub struct RobustAnalysis {
+    /// Whether the robust statistics are shown alongside or instead of the classical ones.
+    pub mode: StatisticsMode,
+    /// The robust statistics, keyed by feature name. Features without any non-null values are
+    /// omitted.
+    pub stats: IndexMap<String, RobustStats>,
+}
+
+impl RobustAnalysis {
+    /// Constructor for the RobustAnalysis struct.
+    ///
+    /// ### Parameters
+    ///
+    /// - `lazy_df`: Reference to the LazyFrame.
+    /// - `schema`: Reference to the lazy frame's schema.
+    /// - `mode`: The statistics mode the results are shown with.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<Self, RobustError>`: A new RobustAnalysis instance or an error.
+    pub fn new(
+        lazy_df: &LazyFrame,
+        schema: &Schema,
+        mode: StatisticsMode,
+    ) -> Result<Self, RobustError> {
+        let numeric_columns: Vec<&str> = schema
+            .iter()
+            .filter(|(_, dtype)| dtype.is_numeric() || dtype.is_decimal())
+            .map(|(name, _)| name.as_str())
+            .collect();
+        if numeric_columns.is_empty() {
+            return Ok(Self {
+                mode,
+                stats: IndexMap::new(),
+            });
+        }
+
+        let stats_df = lazy_df
+            .clone()
+            .select(
+                numeric_columns
+                    .iter()
+                    .flat_map(|name| {
+                        let column = col(name).cast(DataType::Float64);
+                        let median = column.clone().median();
+                        let lower = column
+                            .clone()
+                            .quantile(lit(TRIM_FRACTION), QuantileInterpolOptions::Linear);
+                        let upper = column
+                            .clone()
+                            .quantile(lit(1.0 - TRIM_FRACTION), QuantileInterpolOptions::Linear);
+                        let deviation = column.clone() - median.clone();
+                        let absolute_deviation = when(deviation.clone().lt(lit(0.0)))
+                            .then(lit(0.0) - deviation.clone())
+                            .otherwise(deviation);
+                        vec![
+                            median.alias(&format!("{}_median", name)),
+                            (absolute_deviation.median() * lit(MAD_SCALE))
+                                .alias(&format!("{}_mad", name)),
+                            lower.clone().alias(&format!("{}_trimmed_min", name)),
+                            upper.clone().alias(&format!("{}_trimmed_max", name)),
+                            column
+                                .clone()
+                                .filter(column.clone().gt_eq(lower).and(column.lt_eq(upper)))
+                                .mean()
+                                .alias(&format!("{}_trimmed_mean", name)),
+                        ]
+                    })
+                    .collect::<Vec<Expr>>(),
+            )
+            .collect()?;
+        let stat = |name: &str, statistic: &str| -> Result<Option<f64>, RobustError> {
+            Ok(stats_df
+                .column(&format!("{}_{}", name, statistic))?
+                .f64()?
+                .get(0))
+        };
+
+        let mut stats = IndexMap::new();
+        for name in numeric_columns {
+            let (Some(median), Some(mad), Some(trimmed_min), Some(trimmed_max)) = (
+                stat(name, "median")?,
+                stat(name, "mad")?,
+                stat(name, "trimmed_min")?,
+                stat(name, "trimmed_max")?,
+            ) else {
+                continue;
+            };
+            stats.insert(
+                name.to_owned(),
+                RobustStats {
+                    median,
+                    mad,
+                    trimmed_min,
+                    trimmed_max,
+                    trimmed_mean: stat(name, "trimmed_mean")?.unwrap_or(median),
+                    mad_lower: median - ROBUST_Z_THRESHOLD * mad,
+                    mad_upper: median + ROBUST_Z_THRESHOLD * mad,
+                    mad_count: 0,
+                },
+            );
+        }
+
+        // A MAD of zero (more than half the values are equal) would flag every other value, so
+        // such features have no MAD outliers.
+        let counts_df = lazy_df
+            .clone()
+            .select(
+                stats
+                    .iter()
+                    .map(|(name, stats)| {
+                        let column = col(name).cast(DataType::Float64);
+                        let beyond = if stats.mad > 0.0 {
+                            column
+                                .clone()
+                                .lt(lit(stats.mad_lower))
+                                .or(column.gt(lit(stats.mad_upper)))
+                        } else {
+                            lit(false)
+                        };
+                        beyond
+                            .cast(DataType::UInt64)
+                            .sum()
+                            .alias(&format!("{}_mad_count", name))
+                    })
+                    .collect::<Vec<Expr>>(),
+            )
+            .collect()?;
+        for (name, stats) in stats.iter_mut() {
+            stats.mad_count = counts_df
+                .column(&format!("{}_mad_count", name))?
+                .u64()?
+                .get(0)
+                .unwrap_or(0);
+        }
+
+        Ok(Self { mode, stats })
+    }
+}
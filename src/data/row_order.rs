@@ -0,0 +1,144 @@
+//! # Row Order Module
+//!
+//! This module handles the row order check of a dataset, which correlates each numerical and
+//! temporal feature with the implicit row index. A strong dependence means the rows are sorted
+//! (e.g. by time or by the outcome), so a naive head/tail train-test split would put different
+//! distributions in each split, and models can leak the ordering.
+//!
+//! The dependence is measured with the Spearman rank correlation, so any monotonic trend over the
+//! rows counts, not only linear ones. Missing values are skipped, keeping the relative order of the
+//! remaining rows, and constant features are left out since their correlation is undefined.
+
+use indexmap::IndexMap;
+use polars::prelude::*;
+use thiserror::Error;
+
+/// The default absolute Spearman correlation from which a feature is flagged as ordered.
+pub const DEFAULT_ROW_ORDER_THRESHOLD: f64 = 0.5;
+
+/// The error types for the row order module.
+#[derive(Error, Debug)]
+pub enum RowOrderError {
+    /// Occurs when Polars fails to complete an operation.
+    #[error("Polars error: {0}")]
+    Polars(#[from] PolarsError),
+}
+
+/// Struct to hold the row order check results for a dataset.
+#[derive(Debug, Default)]
+pub struct RowOrderAnalysis {
+    /// The Spearman correlation of each feature with the row index, keyed by feature name and
+    /// sorted by descending absolute correlation.
+    pub correlations: IndexMap<String, f64>,
+    /// The absolute correlation from which a feature is flagged.
+    pub threshold: f64,
+}
+
+impl RowOrderAnalysis {
+    /// Constructor for the RowOrderAnalysis struct.
+    ///
+    /// ### Parameters
+    ///
+    /// - `lazy_df`: Reference to the LazyFrame.
+    /// - `schema`: Reference to the lazy frame's schema.
+    /// - `threshold`: The absolute correlation from which a feature is flagged.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<Self, RowOrderError>`: A new RowOrderAnalysis instance or an error.
+    pub fn new(
+        lazy_df: &LazyFrame,
+        schema: &Schema,
+        threshold: f64,
+    ) -> Result<Self, RowOrderError> {
+        let columns: Vec<&str> = schema
+            .iter()
+            .filter(|(_, dtype)| {
+                dtype.is_numeric()
+                    || dtype.is_decimal()
+                    || matches!(dtype, DataType::Date | DataType::Datetime(_, _))
+            })
+            .map(|(name, _)| name.as_str())
+            .collect();
+        let df = lazy_df
+            .clone()
+            .select(
+                columns
+                    .iter()
+                    .map(|name| col(name).to_physical().cast(DataType::Float64))
+                    .collect::<Vec<Expr>>(),
+            )
+            .collect()?;
+
+        let mut correlations = Vec::with_capacity(columns.len());
+        for name in columns {
+            let values: Vec<f64> = df
+                .column(name)?
+                .f64()?
+                .into_iter()
+                .flatten()
+                .filter(|value| value.is_finite())
+                .collect();
+            if let Some(correlation) = index_correlation(&values) {
+                correlations.push((name.to_owned(), correlation));
+            }
+        }
+        correlations.sort_by(|a, b| b.1.abs().total_cmp(&a.1.abs()));
+
+        Ok(Self {
+            correlations: correlations.into_iter().collect(),
+            threshold,
+        })
+    }
+
+    /// Gets the features whose absolute correlation with the row index is at least the threshold.
+    pub fn ordered(&self) -> impl Iterator<Item = (&String, &f64)> {
+        self.correlations
+            .iter()
+            .filter(|(_, correlation)| correlation.abs() >= self.threshold)
+    }
+}
+
+/// Computes the Spearman correlation of the values with their position, or None if there are
+/// fewer than three values or the values are constant.
+fn index_correlation(values: &[f64]) -> Option<f64> {
+    if values.len() < 3 {
+        return None;
+    }
+    let ranks = ranks(values);
+    let n = ranks.len() as f64;
+    // The positions are already ranks, with mean (n - 1) / 2.
+    let mean = (n - 1.0) / 2.0;
+    let (mut covariance, mut rank_variance, mut position_variance) = (0.0, 0.0, 0.0);
+    for (position, rank) in ranks.iter().enumerate() {
+        let position = position as f64 - mean;
+        let rank = rank - mean;
+        covariance += position * rank;
+        rank_variance += rank * rank;
+        position_variance += position * position;
+    }
+    if rank_variance == 0.0 {
+        return None;
+    }
+    Some(covariance / (rank_variance * position_variance).sqrt())
+}
+
+/// Ranks the values from 0, giving tied values the average of their ranks.
+pub(crate) fn ranks(values: &[f64]) -> Vec<f64> {
+    let mut order: Vec<usize> = (0..values.len()).collect();
+    order.sort_by(|a, b| values[*a].total_cmp(&values[*b]));
+    let mut ranks = vec![0.0; values.len()];
+    let mut start = 0;
+    while start < order.len() {
+        let mut end = start + 1;
+        while end < order.len() && values[order[end]] == values[order[start]] {
+            end += 1;
+        }
+        let rank = (start + end - 1) as f64 / 2.0;
+        for index in &order[start..end] {
+            ranks[*index] = rank;
+        }
+        start = end;
+    }
+    ranks
+}
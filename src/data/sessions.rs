@@ -0,0 +1,311 @@
+//! # Sessions Module
+//!
+//! This module handles the sessionized profiling of event-log style datasets, where each row is
+//! an event of an entity (e.g. a user or device id) at a timestamp. The events of each entity are
+//! ordered by time and split into sessions wherever the time between two consecutive events is
+//! above the session gap.
+//!
+//! - Events per entity: how active the entities are, and how concentrated the events are.
+//! - Inter-event time: the time between consecutive events of the same entity, summarized by its
+//!   quantiles and counted in fixed duration buckets (`INTER_EVENT_BUCKETS`).
+//! - Sessions: the number of sessions, the sessions per entity, and the events and duration of
+//!   each session.
+//!
+//! The timestamp column can be a date, datetime, or string column, parsed the same way as the
+//! time series index. Rows with a missing entity or a missing or unparseable timestamp are
+//! excluded.
+
+use crate::data::time_series::{format_duration, parse_duration, timestamps, TimeSeriesError};
+use indexmap::IndexMap;
+use polars::prelude::*;
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// The default session gap (30 minutes), in milliseconds.
+pub const DEFAULT_SESSION_GAP: SessionGap = SessionGap(1_800_000);
+/// The quantiles of the session distributions, with their labels.
+pub const SESSION_QUANTILES: [(&str, f64); 5] = [
+    ("min", 0.0),
+    ("p50", 0.5),
+    ("p90", 0.9),
+    ("p99", 0.99),
+    ("max", 1.0),
+];
+/// The upper bounds of the inter-event time buckets in milliseconds (1s, 10s, 1m, 5m, 15m, 30m,
+/// 1h, 6h, 1d, and 7d), followed by an open-ended bucket.
+pub const INTER_EVENT_BUCKETS: [i64; 10] = [
+    1_000,
+    10_000,
+    60_000,
+    300_000,
+    900_000,
+    1_800_000,
+    3_600_000,
+    21_600_000,
+    86_400_000,
+    604_800_000,
+];
+
+/// The error types for the sessions module.
+#[derive(Error, Debug)]
+pub enum SessionError {
+    /// Occurs when Polars fails to complete an operation.
+    #[error("Polars error: {0}")]
+    Polars(#[from] PolarsError),
+
+    /// Occurs when the timestamp column can't be converted to timestamps.
+    #[error("Timestamp error: {0}")]
+    Timestamp(#[from] TimeSeriesError),
+
+    /// Occurs when the entity or timestamp column isn't in the dataset.
+    #[error("Session column not found in the dataset: {0}")]
+    UnknownColumn(String),
+
+    /// Occurs when the timestamp column can't hold timestamps.
+    #[error("Session timestamp column '{0}' has unsupported data type: {1}")]
+    UnsupportedColumn(String, DataType),
+}
+
+/// The maximum time between two consecutive events of the same session, in milliseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SessionGap(pub i64);
+
+impl Default for SessionGap {
+    fn default() -> Self {
+        DEFAULT_SESSION_GAP
+    }
+}
+
+impl FromStr for SessionGap {
+    type Err = String;
+
+    /// Parses a duration made of amounts with a unit (`d`, `h`, `m`, `s`, or `ms`), e.g. `30m` or
+    /// `1h 30m`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match parse_duration(s) {
+            Some(duration) if duration > 0 => Ok(SessionGap(duration)),
+            _ => Err(format!(
+                "Invalid session gap: {}. Expected a duration like 30m, 1h 30m, or 45s.",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for SessionGap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", format_duration(self.0))
+    }
+}
+
+/// Struct to hold the sessionized log profiling results.
+#[derive(Debug)]
+pub struct SessionAnalysis {
+    /// The entity column name.
+    pub entity_column: String,
+    /// The event timestamp column name.
+    pub timestamp_column: String,
+    /// The session gap, in milliseconds.
+    pub gap: i64,
+    /// The number of events with an entity and a valid timestamp.
+    pub n_events: usize,
+    /// The number of distinct entities.
+    pub n_entities: usize,
+    /// The number of sessions across all the entities.
+    pub n_sessions: usize,
+    /// The number of events of each entity, sorted in ascending order.
+    pub entity_events: Vec<u64>,
+    /// The quantiles of the number of sessions per entity, keyed by their `SESSION_QUANTILES`
+    /// label.
+    pub sessions_per_entity: IndexMap<String, i64>,
+    /// The quantiles of the number of events per session.
+    pub events_per_session: IndexMap<String, i64>,
+    /// The quantiles of the session durations (first to last event), in milliseconds.
+    pub session_durations: IndexMap<String, i64>,
+    /// The quantiles of the time between consecutive events of the same entity, in milliseconds.
+    /// Empty if no entity has more than one event.
+    pub inter_event_times: IndexMap<String, i64>,
+    /// The number of inter-event times in each `INTER_EVENT_BUCKETS` bucket, with the
+    /// open-ended bucket last.
+    pub inter_event_counts: Vec<u64>,
+}
+
+impl SessionAnalysis {
+    /// Constructor for the SessionAnalysis struct.
+    ///
+    /// ### Parameters
+    ///
+    /// - `lazy_df`: Reference to the LazyFrame.
+    /// - `schema`: Reference to the lazy frame's schema.
+    /// - `entity_column`: The entity column name.
+    /// - `timestamp_column`: The event timestamp column name.
+    /// - `gap`: The session gap.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<Self, SessionError>`: A new SessionAnalysis instance or an error.
+    pub fn new(
+        lazy_df: &LazyFrame,
+        schema: &Schema,
+        entity_column: &str,
+        timestamp_column: &str,
+        gap: SessionGap,
+    ) -> Result<Self, SessionError> {
+        if schema.get(entity_column).is_none() {
+            return Err(SessionError::UnknownColumn(entity_column.to_owned()));
+        }
+        let dtype = schema
+            .get(timestamp_column)
+            .ok_or_else(|| SessionError::UnknownColumn(timestamp_column.to_owned()))?;
+        if !matches!(
+            dtype,
+            DataType::Date | DataType::Datetime(_, _) | DataType::String
+        ) {
+            return Err(SessionError::UnsupportedColumn(
+                timestamp_column.to_owned(),
+                dtype.clone(),
+            ));
+        }
+
+        let df = lazy_df
+            .clone()
+            .select([
+                col(entity_column).cast(DataType::String).alias("entity"),
+                col(timestamp_column),
+            ])
+            .collect()?;
+        let event_times = Series::new(
+            "timestamp",
+            timestamps(df.column(timestamp_column)?, dtype)?,
+        );
+        let events = DataFrame::new(vec![df.column("entity")?.clone(), event_times])?
+            .drop_nulls::<String>(None)?
+            .sort(["entity", "timestamp"], SortMultipleOptions::default())?;
+
+        let mut entity_events = Vec::new();
+        let mut sessions_per_entity = Vec::new();
+        let mut events_per_session = Vec::new();
+        let mut session_durations = Vec::new();
+        let mut inter_event_times = Vec::new();
+        let mut previous: Option<(&str, i64)> = None;
+        let mut session_start = 0;
+        for (entity, timestamp) in events
+            .column("entity")?
+            .str()?
+            .into_no_null_iter()
+            .zip(events.column("timestamp")?.i64()?.into_no_null_iter())
+        {
+            match previous {
+                Some((previous_entity, previous_timestamp)) if previous_entity == entity => {
+                    let inter_event_time = timestamp - previous_timestamp;
+                    inter_event_times.push(inter_event_time);
+                    if let Some(events) = entity_events.last_mut() {
+                        *events += 1;
+                    }
+                    if inter_event_time > gap.0 {
+                        session_durations.push(previous_timestamp - session_start);
+                        session_start = timestamp;
+                        events_per_session.push(0);
+                        if let Some(sessions) = sessions_per_entity.last_mut() {
+                            *sessions += 1;
+                        }
+                    }
+                }
+                _ => {
+                    if let Some((_, previous_timestamp)) = previous {
+                        session_durations.push(previous_timestamp - session_start);
+                    }
+                    session_start = timestamp;
+                    entity_events.push(1);
+                    sessions_per_entity.push(1);
+                    events_per_session.push(0);
+                }
+            }
+            if let Some(events) = events_per_session.last_mut() {
+                *events += 1;
+            }
+            previous = Some((entity, timestamp));
+        }
+        if let Some((_, previous_timestamp)) = previous {
+            session_durations.push(previous_timestamp - session_start);
+        }
+
+        let mut inter_event_counts = vec![0; INTER_EVENT_BUCKETS.len() + 1];
+        for inter_event_time in &inter_event_times {
+            let bucket = INTER_EVENT_BUCKETS
+                .iter()
+                .position(|bound| inter_event_time < bound)
+                .unwrap_or(INTER_EVENT_BUCKETS.len());
+            inter_event_counts[bucket] += 1;
+        }
+        entity_events.sort_unstable();
+
+        Ok(Self {
+            entity_column: entity_column.to_owned(),
+            timestamp_column: timestamp_column.to_owned(),
+            gap: gap.0,
+            n_events: events.height(),
+            n_entities: entity_events.len(),
+            n_sessions: events_per_session.len(),
+            entity_events,
+            sessions_per_entity: quantiles(sessions_per_entity),
+            events_per_session: quantiles(events_per_session),
+            session_durations: quantiles(session_durations),
+            inter_event_times: quantiles(inter_event_times),
+            inter_event_counts,
+        })
+    }
+
+    /// Gets the quantiles of the number of events per entity, keyed by their `SESSION_QUANTILES`
+    /// label.
+    pub fn events_per_entity(&self) -> IndexMap<String, i64> {
+        quantiles(
+            self.entity_events
+                .iter()
+                .map(|events| *events as i64)
+                .collect(),
+        )
+    }
+
+    /// Gets the share of the events from the most active 1% of the entities (at least one
+    /// entity), or None if there are no events.
+    pub fn top_entity_share(&self) -> Option<f64> {
+        if self.n_events == 0 {
+            return None;
+        }
+        let n_top = self.n_entities.div_ceil(100);
+        let top_events: u64 = self.entity_events.iter().rev().take(n_top).sum();
+        Some(top_events as f64 / self.n_events as f64)
+    }
+}
+
+/// Gets the labels of the `INTER_EVENT_BUCKETS` buckets, e.g. `1m-5m` and `7d+`.
+pub fn inter_event_bucket_labels() -> Vec<String> {
+    let mut lower = 0;
+    let mut labels: Vec<String> = INTER_EVENT_BUCKETS
+        .iter()
+        .map(|upper| {
+            let label = format!("{}-{}", format_duration(lower), format_duration(*upper));
+            lower = *upper;
+            label
+        })
+        .collect();
+    labels.push(format!("{}+", format_duration(lower)));
+    labels
+}
+
+/// Computes the `SESSION_QUANTILES` of the values, or an empty map if there are no values.
+fn quantiles(mut values: Vec<i64>) -> IndexMap<String, i64> {
+    if values.is_empty() {
+        return IndexMap::new();
+    }
+    values.sort_unstable();
+    SESSION_QUANTILES
+        .iter()
+        .map(|(label, quantile)| {
+            let index = ((values.len() - 1) as f64 * quantile).round() as usize;
+            (label.to_string(), values[index])
+        })
+        .collect()
+}
@@ -0,0 +1,150 @@
+//! # Tables Module
+//!
+//! This module handles runs over several named tables, e.g. all the sheets of a workbook or
+//! several files given on the command line. Each table is read and analyzed on its own, with its
+//! plots in a subdirectory of the plot directory, and the report gets the analysis sections of
+//! each table after a combined overview of the tables.
+//!
+//! The overview compares the size and quality of the tables and lists the columns shared by
+//! several tables, which are the likely keys to join them on.
+
+use super::base::{dataset_title, workbook_sheets, DataInfo, DataOptions, ExcelSheet};
+use crate::LeadsError;
+use indexmap::IndexMap;
+use std::path::PathBuf;
+use thiserror::Error;
+
+/// The error types for the tables module.
+#[derive(Error, Debug)]
+pub enum TableError {
+    /// Occurs when a table set is read from no inputs.
+    #[error("No tables to read")]
+    NoTables,
+}
+
+/// Struct to hold the tables of a run, each with its own analysis results.
+pub struct TableSet {
+    /// Name of the run: the name of the input for a single input, or of the first input followed
+    /// by the number of other inputs.
+    pub title: String,
+    /// The tables in input order (and sheet order for workbooks), named after their input file
+    /// and sheet.
+    pub tables: Vec<DataInfo>,
+}
+
+impl From<DataInfo> for TableSet {
+    fn from(data_info: DataInfo) -> Self {
+        Self {
+            title: data_info.data_title.clone(),
+            tables: vec![data_info],
+        }
+    }
+}
+
+impl TableSet {
+    /// Constructs a new TableSet by reading and analyzing each input as its own table. With the
+    /// `all_sheets` option, every sheet of the Excel workbook inputs is a table.
+    ///
+    /// ### Parameters
+    /// - `paths`: The paths to the data files (or partitioned dataset directories and glob
+    ///   patterns), in table order.
+    /// - `options`: The options for reading and analyzing the tables.
+    ///
+    /// ### Returns
+    /// - `Result<Self, LeadsError>`: A new TableSet or an error.
+    ///
+    /// ### Errors
+    /// This method can return a LeadsError if:
+    /// - There are no inputs.
+    /// - A workbook's sheets can't be listed.
+    /// - The plot directory of a table can't be created.
+    /// - A table can't be read or analyzed (see `DataInfo::new`).
+    pub fn new(paths: &[PathBuf], options: &DataOptions) -> Result<Self, LeadsError> {
+        let first = paths.first().ok_or(TableError::NoTables)?;
+
+        let mut sources: Vec<(&PathBuf, Option<String>)> = Vec::new();
+        for path in paths {
+            let sheets = if options.all_sheets {
+                workbook_sheets(path, options.format)?
+            } else {
+                None
+            };
+            match sheets {
+                Some(sheets) => sources.extend(sheets.into_iter().map(|sheet| (path, Some(sheet)))),
+                None => sources.push((path, None)),
+            }
+        }
+
+        let mut tables: Vec<DataInfo> = Vec::with_capacity(sources.len());
+        for (i, (path, sheet)) in sources.into_iter().enumerate() {
+            let name = match &sheet {
+                Some(sheet) if paths.len() == 1 => sheet.clone(),
+                Some(sheet) => format!("{} - {}", dataset_title(path)?, sheet),
+                None => dataset_title(path)?,
+            };
+            let name = unique_name(&name, &tables);
+
+            let mut table_options = options.clone();
+            if let Some(sheet) = sheet {
+                table_options.sheet = Some(ExcelSheet::Name(sheet));
+            }
+            // The plots are saved under the same file names for every table.
+            if let Some(plot_dir) = &options.plot_dir {
+                let table_dir = plot_dir.join(format!("{}_{}", i + 1, file_name(&name)));
+                std::fs::create_dir_all(&table_dir)?;
+                table_options.plot_dir = Some(table_dir);
+            }
+
+            let mut data_info = DataInfo::new(path, &table_options)?;
+            data_info.data_title = name;
+            tables.push(data_info);
+        }
+
+        let title = match paths.len() {
+            1 => dataset_title(first)?,
+            n => format!("{} and {} more", dataset_title(first)?, n - 1),
+        };
+        Ok(Self { title, tables })
+    }
+
+    /// Gets the columns shared by several tables, with the tables that have them, in the order the
+    /// columns first appear.
+    pub fn shared_columns(&self) -> IndexMap<&str, Vec<&str>> {
+        let mut columns: IndexMap<&str, Vec<&str>> = IndexMap::new();
+        for table in &self.tables {
+            for column in table.column_types.keys() {
+                columns
+                    .entry(column.as_str())
+                    .or_default()
+                    .push(table.data_title.as_str());
+            }
+        }
+        columns.retain(|_, tables| tables.len() > 1);
+        columns
+    }
+}
+
+/// Makes a table name unique among the tables read so far, with a numbered suffix.
+fn unique_name(name: &str, tables: &[DataInfo]) -> String {
+    let taken = |candidate: &str| tables.iter().any(|table| table.data_title == candidate);
+    let mut unique = name.to_owned();
+    let mut n = 2;
+    while taken(&unique) {
+        unique = format!("{} ({})", name, n);
+        n += 1;
+    }
+    unique
+}
+
+/// Replaces the characters of a table name that aren't safe in a file name.
+fn file_name(name: &str) -> String {
+    name.chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
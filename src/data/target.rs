@@ -0,0 +1,802 @@
+//! # Target Module
+//!
+//! This module handles the supervised mode analysis of a dataset, where one column is designated
+//! as the prediction target. For classification targets the class distribution is computed so
+//! class imbalance can be spotted before modeling.
+//!
+//! A target with more than `MAX_CLASSES` distinct values is treated as continuous and no class
+//! distribution is computed. Missing target values are excluded from the class counts.
+//!
+//! ## Mutual Information
+//!
+//! The mutual information between each feature and the target is computed from their joint
+//! distribution, so both linear and non-linear dependencies are picked up:
+//!
+//! - Numerical features (and a continuous target) are discretized into `MI_BINS` equal frequency
+//!   bins.
+//! - All other features are treated as categorical, with each distinct value as a category.
+//! - Missing feature values form their own bin or category, so informative missingness counts.
+//!   Rows with a missing target are excluded.
+//!
+//! The mutual information is reported in nats. Note that it's biased upwards for features with
+//! many categories (e.g. identifiers), which can't generalize despite a high score.
+//!
+//! ## Association Tests
+//!
+//! Each feature is also tested for an association with the target, with the test picked from
+//! their types. Rows where either value is missing are left out of the test.
+//!
+//! - Numerical feature and continuous target: Pearson correlation, with a t-test of the
+//!   coefficient. The strength is the absolute coefficient.
+//! - Numerical feature and classification target, or categorical feature and continuous target:
+//!   one-way ANOVA of the numerical values grouped by the categories. The strength is the
+//!   correlation ratio (the square root of the share of the variance between the groups).
+//! - Categorical feature and classification target: chi-squared test of independence of their
+//!   contingency table. The strength is Cramér's V.
+//!
+//! Categorical features with more than `MAX_CLASSES` categories (e.g. identifiers) aren't tested,
+//! and neither are continuous targets that aren't numerical. The strengths all range from 0 (no
+//! association) to 1, so the features can be ranked across tests.
+//!
+//! ## Grouped Statistics
+//!
+//! For classification targets, the count, mean, and standard deviation of each numerical feature
+//! are computed per class, for the `MAX_GROUPED_CLASSES` most frequent classes.
+
+use indexmap::IndexMap;
+use polars::prelude::*;
+use std::collections::HashMap;
+use std::fmt;
+use thiserror::Error;
+
+/// Percentage of the labeled rows below which the minority class is flagged as imbalanced.
+pub const MINORITY_CLASS_THRESHOLD: f64 = 10.0;
+/// Maximum number of distinct values for a target to be treated as a classification target.
+pub const MAX_CLASSES: usize = 50;
+/// Number of equal frequency bins to discretize numerical values into for the mutual information.
+pub const MI_BINS: usize = 10;
+/// The maximum number of classes the grouped statistics are computed for, the most frequent ones.
+pub const MAX_GROUPED_CLASSES: usize = 5;
+/// The p-value below which a feature's association with the target is significant.
+pub const SIGNIFICANCE_LEVEL: f64 = 0.05;
+
+/// The error types for the target module.
+#[derive(Error, Debug)]
+pub enum TargetError {
+    /// Occurs when Polars fails to complete an operation.
+    #[error("Polars error: {0}")]
+    Polars(#[from] PolarsError),
+
+    /// Occurs when the target column isn't in the dataset.
+    #[error("Target column not found in the dataset: {0}")]
+    UnknownColumn(String),
+}
+
+/// The class distribution of a classification target.
+#[derive(Debug, Clone)]
+pub struct ClassDistribution {
+    /// The number of rows per class, sorted from the most to the least frequent class.
+    pub counts: IndexMap<String, u64>,
+    /// The ratio of the majority class count to the minority class count.
+    pub imbalance_ratio: f64,
+    /// The least frequent class.
+    pub minority_class: String,
+    /// The percentage of the labeled rows in the minority class.
+    pub minority_percentage: f64,
+}
+
+impl ClassDistribution {
+    /// Checks whether the minority class falls below `MINORITY_CLASS_THRESHOLD`.
+    pub fn is_imbalanced(&self) -> bool {
+        self.minority_percentage < MINORITY_CLASS_THRESHOLD
+    }
+
+    /// Gets the human readable imbalance warning, or None if the classes are balanced enough.
+    pub fn warning(&self) -> Option<String> {
+        self.is_imbalanced().then(|| {
+            format!(
+                "Minority class '{}' is only {:.2}% of the labeled rows (imbalance ratio {:.2}:1).",
+                self.minority_class, self.minority_percentage, self.imbalance_ratio
+            )
+        })
+    }
+}
+
+/// The test of the association between a feature and the target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TargetTest {
+    /// Pearson correlation between a numerical feature and a continuous target.
+    Pearson,
+    /// One-way ANOVA of numerical values grouped by categories.
+    Anova,
+    /// Chi-squared test of independence between a categorical feature and a classification target.
+    ChiSquared,
+}
+
+impl fmt::Display for TargetTest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TargetTest::Pearson => write!(f, "Pearson"),
+            TargetTest::Anova => write!(f, "ANOVA"),
+            TargetTest::ChiSquared => write!(f, "Chi-squared"),
+        }
+    }
+}
+
+/// The result of the association test between a feature and the target.
+#[derive(Debug, Clone)]
+pub struct TargetAssociation {
+    /// The test run.
+    pub test: TargetTest,
+    /// The test statistic: the correlation coefficient, the F statistic, or the chi-squared
+    /// statistic.
+    pub statistic: f64,
+    /// The p-value of the test.
+    pub p_value: f64,
+    /// The strength of the association from 0 to 1: the absolute correlation coefficient, the
+    /// correlation ratio, or Cramér's V.
+    pub strength: f64,
+    /// The number of rows with both values present.
+    pub n: usize,
+}
+
+impl TargetAssociation {
+    /// Checks whether the association is significant at `SIGNIFICANCE_LEVEL`.
+    pub fn is_significant(&self) -> bool {
+        self.p_value < SIGNIFICANCE_LEVEL
+    }
+
+    /// Formats the p-value, `< 0.0001` for very small p-values.
+    pub fn format_p_value(&self) -> String {
+        if self.p_value < 0.0001 {
+            "< 0.0001".to_owned()
+        } else {
+            format!("{:.4}", self.p_value)
+        }
+    }
+}
+
+/// The summary statistics of a numerical feature within a class of the target.
+#[derive(Debug, Clone)]
+pub struct GroupStats {
+    /// The number of non-missing values.
+    pub count: u64,
+    /// The mean, or None without values.
+    pub mean: Option<f64>,
+    /// The sample standard deviation, or None with fewer than two values.
+    pub std_dev: Option<f64>,
+}
+
+impl GroupStats {
+    /// Computes the summary statistics of the values of a class.
+    fn new(values: &[f64]) -> Self {
+        let n = values.len() as f64;
+        let mean = (!values.is_empty()).then(|| values.iter().sum::<f64>() / n);
+        let std_dev = mean.filter(|_| values.len() > 1).map(|mean| {
+            (values
+                .iter()
+                .map(|value| (value - mean).powi(2))
+                .sum::<f64>()
+                / (n - 1.0))
+                .sqrt()
+        });
+        Self {
+            count: values.len() as u64,
+            mean,
+            std_dev,
+        }
+    }
+
+    /// Formats the mean and standard deviation, e.g. `12.34 ± 5.67`, or `-` without values.
+    pub fn format(&self) -> String {
+        match (self.mean, self.std_dev) {
+            (Some(mean), Some(std_dev)) => format!("{:.2} ± {:.2}", mean, std_dev),
+            (Some(mean), None) => format!("{:.2}", mean),
+            _ => "-".to_owned(),
+        }
+    }
+}
+
+/// Struct to hold the target column analysis results.
+#[derive(Debug)]
+pub struct TargetAnalysis {
+    /// The target column name.
+    pub column: String,
+    /// The class distribution, or None if the target is continuous or entirely missing.
+    pub class_distribution: Option<ClassDistribution>,
+    /// The mutual information between each feature and the target in nats, sorted from the most
+    /// to the least informative feature. Nested list and array features are skipped.
+    pub mutual_information: IndexMap<String, f64>,
+    /// The association test result of each tested feature, sorted from the strongest to the
+    /// weakest association.
+    pub associations: IndexMap<String, TargetAssociation>,
+    /// The summary statistics of each numerical feature per class, for the
+    /// `MAX_GROUPED_CLASSES` most frequent classes of a classification target.
+    pub grouped_stats: IndexMap<String, IndexMap<String, GroupStats>>,
+}
+
+impl TargetAnalysis {
+    /// Constructor for the TargetAnalysis struct.
+    ///
+    /// ### Parameters
+    ///
+    /// - `lazy_df`: Reference to the LazyFrame.
+    /// - `schema`: Reference to the lazy frame's schema.
+    /// - `column`: The target column name.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<Self, TargetError>`: A new TargetAnalysis instance or an error.
+    pub fn new(lazy_df: &LazyFrame, schema: &Schema, column: &str) -> Result<Self, TargetError> {
+        if schema.get(column).is_none() {
+            return Err(TargetError::UnknownColumn(column.to_owned()));
+        }
+
+        let counts_df = lazy_df
+            .clone()
+            .select([col(column).cast(DataType::String)])
+            .drop_nulls(None)
+            .group_by([col(column)])
+            .agg([len().cast(DataType::UInt64).alias("count")])
+            .sort(
+                [column],
+                SortMultipleOptions::default().with_maintain_order(true),
+            )
+            .sort(
+                ["count"],
+                SortMultipleOptions::default()
+                    .with_order_descending(true)
+                    .with_maintain_order(true),
+            )
+            .collect()?;
+
+        let class_distribution = if counts_df.height() > MAX_CLASSES {
+            None
+        } else {
+            let counts: IndexMap<String, u64> = counts_df
+                .column(column)?
+                .str()?
+                .into_iter()
+                .zip(counts_df.column("count")?.u64()?)
+                .filter_map(|(class, count)| Some((class?.to_owned(), count?)))
+                .collect();
+            class_distribution(counts)
+        };
+
+        // Only the target and the features tested against it are collected, as nested columns are
+        // skipped by every test.
+        let tested_columns: Vec<Expr> = schema
+            .iter()
+            .filter(|(name, dtype)| name.as_str() == column || !dtype.is_nested())
+            .map(|(name, _)| col(name))
+            .collect();
+        let df = lazy_df
+            .clone()
+            .select(tested_columns)
+            .filter(col(column).is_not_null())
+            .collect()?;
+        let mutual_information =
+            feature_mutual_information(&df, schema, column, class_distribution.is_some())?;
+        let associations = feature_associations(&df, schema, column, class_distribution.is_some())?;
+        let grouped_stats = match &class_distribution {
+            Some(distribution) => grouped_stats(&df, schema, column, distribution)?,
+            None => IndexMap::new(),
+        };
+
+        Ok(Self {
+            column: column.to_owned(),
+            class_distribution,
+            mutual_information,
+            associations,
+            grouped_stats,
+        })
+    }
+
+    /// Gets the features significantly associated with the target, from the strongest to the
+    /// weakest association.
+    pub fn significant_features(&self) -> Vec<(&String, &TargetAssociation)> {
+        self.associations
+            .iter()
+            .filter(|(_, association)| association.is_significant())
+            .collect()
+    }
+}
+
+/// Computes the mutual information between every feature and the target.
+///
+/// ### Parameters
+///
+/// - `df`: The rows with a target value.
+/// - `schema`: Reference to the lazy frame's schema.
+/// - `target`: The target column name.
+/// - `categorical_target`: Whether the target is a classification target. Continuous targets are
+///   discretized like the numerical features.
+///
+/// ### Returns
+///
+/// - `Result<IndexMap<String, f64>, TargetError>`: The mutual information keyed by feature name,
+///   sorted in descending order, or an error.
+fn feature_mutual_information(
+    df: &DataFrame,
+    schema: &Schema,
+    target: &str,
+    categorical_target: bool,
+) -> Result<IndexMap<String, f64>, TargetError> {
+    let target_codes = if categorical_target {
+        category_codes(df.column(target)?)?
+    } else {
+        discretize(df.column(target)?, schema.get(target))?
+    };
+
+    let mut mutual_information: Vec<(String, f64)> = Vec::new();
+    for (name, dtype) in schema.iter() {
+        if name.as_str() == target || dtype.is_nested() {
+            continue;
+        }
+        let feature_codes = discretize(df.column(name)?, Some(dtype))?;
+        mutual_information.push((
+            name.to_string(),
+            mutual_information_score(&feature_codes, &target_codes),
+        ));
+    }
+    mutual_information.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+    Ok(mutual_information.into_iter().collect())
+}
+
+/// Tests every feature for an association with the target, with the test picked from their types
+/// (see the module documentation).
+///
+/// ### Parameters
+///
+/// - `df`: The rows with a target value.
+/// - `schema`: Reference to the lazy frame's schema.
+/// - `target`: The target column name.
+/// - `categorical_target`: Whether the target is a classification target.
+///
+/// ### Returns
+///
+/// - `Result<IndexMap<String, TargetAssociation>, TargetError>`: The test results keyed by
+///   feature name, sorted from the strongest association, or an error.
+fn feature_associations(
+    df: &DataFrame,
+    schema: &Schema,
+    target: &str,
+    categorical_target: bool,
+) -> Result<IndexMap<String, TargetAssociation>, TargetError> {
+    let numeric_target = schema.get(target).is_some_and(is_numeric);
+    if !categorical_target && !numeric_target {
+        return Ok(IndexMap::new());
+    }
+    let target_series = df.column(target)?;
+    let target_labels = labels(target_series)?;
+    let target_values = finite_values(target_series)?;
+
+    let mut associations: Vec<(String, TargetAssociation)> = Vec::new();
+    for (name, dtype) in schema.iter() {
+        if name.as_str() == target || dtype.is_nested() {
+            continue;
+        }
+        let series = df.column(name)?;
+        let association = match (is_numeric(dtype), categorical_target) {
+            (true, true) => anova(&finite_values(series)?, &target_labels),
+            (true, false) => pearson(&finite_values(series)?, &target_values),
+            (false, _) if series.n_unique()? > MAX_CLASSES + 1 => None,
+            (false, true) => chi_squared(&labels(series)?, &target_labels),
+            (false, false) => anova(&target_values, &labels(series)?),
+        };
+        if let Some(association) = association {
+            associations.push((name.to_string(), association));
+        }
+    }
+    associations.sort_by(|a, b| b.1.strength.total_cmp(&a.1.strength));
+
+    Ok(associations.into_iter().collect())
+}
+
+/// Computes the count, mean, and standard deviation of each numerical feature per class, for the
+/// `MAX_GROUPED_CLASSES` most frequent classes.
+fn grouped_stats(
+    df: &DataFrame,
+    schema: &Schema,
+    target: &str,
+    distribution: &ClassDistribution,
+) -> Result<IndexMap<String, IndexMap<String, GroupStats>>, TargetError> {
+    let target_labels = labels(df.column(target)?)?;
+    let classes: Vec<&String> = distribution
+        .counts
+        .keys()
+        .take(MAX_GROUPED_CLASSES)
+        .collect();
+
+    let mut grouped_stats = IndexMap::new();
+    for (name, dtype) in schema.iter() {
+        if name.as_str() == target || !is_numeric(dtype) {
+            continue;
+        }
+        let values = finite_values(df.column(name)?)?;
+        let mut class_values: IndexMap<&String, Vec<f64>> =
+            classes.iter().map(|class| (*class, Vec::new())).collect();
+        for (value, label) in values.iter().zip(&target_labels) {
+            if let (Some(value), Some(label)) = (value, label) {
+                if let Some(class_values) = class_values.get_mut(label) {
+                    class_values.push(*value);
+                }
+            }
+        }
+        grouped_stats.insert(
+            name.to_string(),
+            class_values
+                .into_iter()
+                .map(|(class, values)| (class.clone(), GroupStats::new(&values)))
+                .collect(),
+        );
+    }
+    Ok(grouped_stats)
+}
+
+/// Checks whether a data type is tested as numerical.
+fn is_numeric(dtype: &DataType) -> bool {
+    dtype.is_numeric() || dtype.is_decimal()
+}
+
+/// Gets the finite values of a column as floats, with missing and non-finite values as None.
+fn finite_values(series: &Series) -> Result<Vec<Option<f64>>, TargetError> {
+    Ok(series
+        .cast(&DataType::Float64)?
+        .f64()?
+        .into_iter()
+        .map(|value| value.filter(|value| value.is_finite()))
+        .collect())
+}
+
+/// Gets the values of a column as category labels, with missing values as None.
+fn labels(series: &Series) -> Result<Vec<Option<String>>, TargetError> {
+    Ok(series
+        .cast(&DataType::String)?
+        .str()?
+        .into_iter()
+        .map(|label| label.map(str::to_owned))
+        .collect())
+}
+
+/// Tests the Pearson correlation between two numerical variables with a two-sided t-test, or None
+/// with fewer than three complete rows or a constant variable.
+fn pearson(x: &[Option<f64>], y: &[Option<f64>]) -> Option<TargetAssociation> {
+    let (x, y): (Vec<f64>, Vec<f64>) = x
+        .iter()
+        .zip(y)
+        .filter_map(|(x, y)| Some(((*x)?, (*y)?)))
+        .unzip();
+    let n = x.len();
+    if n < 3 {
+        return None;
+    }
+    let mean_x = x.iter().sum::<f64>() / n as f64;
+    let mean_y = y.iter().sum::<f64>() / n as f64;
+    let (mut covariance, mut variance_x, mut variance_y) = (0.0, 0.0, 0.0);
+    for (x, y) in x.iter().zip(&y) {
+        covariance += (x - mean_x) * (y - mean_y);
+        variance_x += (x - mean_x).powi(2);
+        variance_y += (y - mean_y).powi(2);
+    }
+    if variance_x == 0.0 || variance_y == 0.0 {
+        return None;
+    }
+    let r = (covariance / (variance_x * variance_y).sqrt()).clamp(-1.0, 1.0);
+    let df = (n - 2) as f64;
+    let p_value = if r.abs() == 1.0 {
+        0.0
+    } else {
+        let t_squared = r * r * df / (1.0 - r * r);
+        regularized_beta(df / (df + t_squared), df / 2.0, 0.5)
+    };
+    Some(TargetAssociation {
+        test: TargetTest::Pearson,
+        statistic: r,
+        p_value,
+        strength: r.abs(),
+        n,
+    })
+}
+
+/// Runs a one-way ANOVA of numerical values grouped by category labels, or None with fewer than
+/// two groups, no more rows than groups, or no variance at all.
+fn anova(values: &[Option<f64>], groups: &[Option<String>]) -> Option<TargetAssociation> {
+    let mut group_values: HashMap<&str, Vec<f64>> = HashMap::new();
+    for (value, group) in values.iter().zip(groups) {
+        if let (Some(value), Some(group)) = (value, group) {
+            group_values.entry(group).or_default().push(*value);
+        }
+    }
+    let n: usize = group_values.values().map(Vec::len).sum();
+    let k = group_values.len();
+    if k < 2 || n <= k {
+        return None;
+    }
+    let grand_mean = group_values.values().flatten().sum::<f64>() / n as f64;
+    let (mut between, mut within) = (0.0, 0.0);
+    for values in group_values.values() {
+        let mean = values.iter().sum::<f64>() / values.len() as f64;
+        between += values.len() as f64 * (mean - grand_mean).powi(2);
+        within += values
+            .iter()
+            .map(|value| (value - mean).powi(2))
+            .sum::<f64>();
+    }
+    if between + within == 0.0 {
+        return None;
+    }
+    let (df_between, df_within) = ((k - 1) as f64, (n - k) as f64);
+    let (statistic, p_value) = if within == 0.0 {
+        (f64::INFINITY, 0.0)
+    } else {
+        let f = (between / df_between) / (within / df_within);
+        (
+            f,
+            regularized_beta(
+                df_within / (df_within + df_between * f),
+                df_within / 2.0,
+                df_between / 2.0,
+            ),
+        )
+    };
+    Some(TargetAssociation {
+        test: TargetTest::Anova,
+        statistic,
+        p_value,
+        strength: (between / (between + within)).sqrt(),
+        n,
+    })
+}
+
+/// Runs a chi-squared test of independence between two categorical variables, or None if either
+/// has a single category among the complete rows.
+fn chi_squared(x: &[Option<String>], y: &[Option<String>]) -> Option<TargetAssociation> {
+    let mut counts: HashMap<(&str, &str), u64> = HashMap::new();
+    let mut x_counts: HashMap<&str, u64> = HashMap::new();
+    let mut y_counts: HashMap<&str, u64> = HashMap::new();
+    for (x, y) in x.iter().zip(y) {
+        if let (Some(x), Some(y)) = (x, y) {
+            *counts.entry((x, y)).or_default() += 1;
+            *x_counts.entry(x).or_default() += 1;
+            *y_counts.entry(y).or_default() += 1;
+        }
+    }
+    let n: u64 = x_counts.values().sum();
+    if x_counts.len() < 2 || y_counts.len() < 2 {
+        return None;
+    }
+    let total = n as f64;
+    let mut statistic = 0.0;
+    for (x, x_count) in &x_counts {
+        for (y, y_count) in &y_counts {
+            let expected = *x_count as f64 * *y_count as f64 / total;
+            let observed = counts.get(&(*x, *y)).copied().unwrap_or(0) as f64;
+            statistic += (observed - expected).powi(2) / expected;
+        }
+    }
+    let df = ((x_counts.len() - 1) * (y_counts.len() - 1)) as f64;
+    let min_dimension = x_counts.len().min(y_counts.len()) - 1;
+    Some(TargetAssociation {
+        test: TargetTest::ChiSquared,
+        statistic,
+        p_value: regularized_gamma_upper(df / 2.0, statistic / 2.0),
+        strength: (statistic / (total * min_dimension as f64)).sqrt().min(1.0),
+        n: n as usize,
+    })
+}
+
+/// Computes the natural logarithm of the gamma function with the Lanczos approximation.
+fn ln_gamma(x: f64) -> f64 {
+    const COEFFICIENTS: [f64; 6] = [
+        76.18009172947146,
+        -86.50532032941677,
+        24.01409824083091,
+        -1.231739572450155,
+        0.1208650973866179e-2,
+        -0.5395239384953e-5,
+    ];
+    let tmp = x + 5.5;
+    let tmp = tmp - (x + 0.5) * tmp.ln();
+    let series = COEFFICIENTS
+        .iter()
+        .enumerate()
+        .fold(1.000000000190015, |series, (i, coefficient)| {
+            series + coefficient / (x + 1.0 + i as f64)
+        });
+    -tmp + (2.5066282746310005 * series / x).ln()
+}
+
+/// Computes the regularized upper incomplete gamma function Q(a, x), the survival function of a
+/// chi-squared variable with `2a` degrees of freedom at `2x`.
+fn regularized_gamma_upper(a: f64, x: f64) -> f64 {
+    const MAX_ITERATIONS: usize = 500;
+    const EPSILON: f64 = 1e-14;
+    if x <= 0.0 {
+        return 1.0;
+    }
+    let prefactor = (-x + a * x.ln() - ln_gamma(a)).exp();
+    if x < a + 1.0 {
+        // Series expansion of the lower function.
+        let (mut term, mut sum, mut denominator) = (1.0 / a, 1.0 / a, a);
+        for _ in 0..MAX_ITERATIONS {
+            denominator += 1.0;
+            term *= x / denominator;
+            sum += term;
+            if term.abs() < sum.abs() * EPSILON {
+                break;
+            }
+        }
+        (1.0 - sum * prefactor).clamp(0.0, 1.0)
+    } else {
+        // Continued fraction of the upper function, with the modified Lentz method.
+        let tiny = f64::MIN_POSITIVE / EPSILON;
+        let mut b = x + 1.0 - a;
+        let mut c = 1.0 / tiny;
+        let mut d = 1.0 / b;
+        let mut h = d;
+        for i in 1..=MAX_ITERATIONS {
+            let an = -(i as f64) * (i as f64 - a);
+            b += 2.0;
+            d = an * d + b;
+            if d.abs() < tiny {
+                d = tiny;
+            }
+            c = b + an / c;
+            if c.abs() < tiny {
+                c = tiny;
+            }
+            d = 1.0 / d;
+            let delta = d * c;
+            h *= delta;
+            if (delta - 1.0).abs() < EPSILON {
+                break;
+            }
+        }
+        (prefactor * h).clamp(0.0, 1.0)
+    }
+}
+
+/// Computes the regularized incomplete beta function I_x(a, b), which gives the p-values of the
+/// t and F tests.
+fn regularized_beta(x: f64, a: f64, b: f64) -> f64 {
+    if x <= 0.0 {
+        return 0.0;
+    }
+    if x >= 1.0 {
+        return 1.0;
+    }
+    let prefactor =
+        (ln_gamma(a + b) - ln_gamma(a) - ln_gamma(b) + a * x.ln() + b * (1.0 - x).ln()).exp();
+    // The continued fraction converges quickly below the mean of the distribution, so the
+    // symmetry I_x(a, b) = 1 - I_(1-x)(b, a) is used above it.
+    if x < (a + 1.0) / (a + b + 2.0) {
+        (prefactor * beta_continued_fraction(x, a, b) / a).clamp(0.0, 1.0)
+    } else {
+        (1.0 - prefactor * beta_continued_fraction(1.0 - x, b, a) / b).clamp(0.0, 1.0)
+    }
+}
+
+/// Evaluates the continued fraction of the incomplete beta function with the modified Lentz
+/// method.
+fn beta_continued_fraction(x: f64, a: f64, b: f64) -> f64 {
+    const MAX_ITERATIONS: usize = 500;
+    const EPSILON: f64 = 1e-14;
+    let tiny = f64::MIN_POSITIVE / EPSILON;
+    let mut c = 1.0;
+    let mut d = 1.0 - (a + b) * x / (a + 1.0);
+    if d.abs() < tiny {
+        d = tiny;
+    }
+    d = 1.0 / d;
+    let mut h = d;
+    for m in 1..=MAX_ITERATIONS {
+        let m = m as f64;
+        let even = m * (b - m) * x / ((a + 2.0 * m - 1.0) * (a + 2.0 * m));
+        let odd = -(a + m) * (a + b + m) * x / ((a + 2.0 * m) * (a + 2.0 * m + 1.0));
+        for coefficient in [even, odd] {
+            d = 1.0 + coefficient * d;
+            if d.abs() < tiny {
+                d = tiny;
+            }
+            c = 1.0 + coefficient / c;
+            if c.abs() < tiny {
+                c = tiny;
+            }
+            d = 1.0 / d;
+            h *= d * c;
+        }
+        if (d * c - 1.0).abs() < EPSILON {
+            break;
+        }
+    }
+    h
+}
+
+/// Discretizes numerical values into equal frequency bins (with missing values in their own
+/// bin), or falls back to category codes for any other data type.
+fn discretize(series: &Series, dtype: Option<&DataType>) -> Result<Vec<usize>, TargetError> {
+    if !dtype.is_some_and(|dtype| dtype.is_numeric() || dtype.is_decimal()) {
+        return category_codes(series);
+    }
+
+    let values: Vec<Option<f64>> = series
+        .cast(&DataType::Float64)?
+        .f64()?
+        .into_iter()
+        .map(|value| value.filter(|value| value.is_finite()))
+        .collect();
+    let mut sorted: Vec<f64> = values.iter().flatten().copied().collect();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+
+    // Use the inner quantiles as the bin edges, deduplicated so ties stay in the same bin.
+    let mut edges: Vec<f64> = (1..MI_BINS)
+        .filter_map(|bin| sorted.get(bin * sorted.len() / MI_BINS).copied())
+        .collect();
+    edges.dedup();
+
+    Ok(values
+        .iter()
+        .map(|value| match value {
+            Some(value) => edges.partition_point(|edge| edge <= value),
+            None => edges.len() + 1,
+        })
+        .collect())
+}
+
+/// Maps each distinct value (including missing) to an integer code.
+fn category_codes(series: &Series) -> Result<Vec<usize>, TargetError> {
+    let mut codes: HashMap<Option<&str>, usize> = HashMap::new();
+    let strings = series.cast(&DataType::String)?;
+    Ok(strings
+        .str()?
+        .into_iter()
+        .map(|value| {
+            let next_code = codes.len();
+            *codes.entry(value).or_insert(next_code)
+        })
+        .collect())
+}
+
+/// Computes the mutual information in nats between two discrete variables.
+fn mutual_information_score(x: &[usize], y: &[usize]) -> f64 {
+    let n = x.len() as f64;
+    if x.is_empty() {
+        return 0.0;
+    }
+
+    let mut joint: HashMap<(usize, usize), u64> = HashMap::new();
+    let mut x_counts: HashMap<usize, u64> = HashMap::new();
+    let mut y_counts: HashMap<usize, u64> = HashMap::new();
+    for (x, y) in x.iter().zip(y) {
+        *joint.entry((*x, *y)).or_default() += 1;
+        *x_counts.entry(*x).or_default() += 1;
+        *y_counts.entry(*y).or_default() += 1;
+    }
+
+    joint
+        .iter()
+        .map(|((x, y), count)| {
+            let p_xy = *count as f64 / n;
+            let p_x = x_counts[x] as f64 / n;
+            let p_y = y_counts[y] as f64 / n;
+            p_xy * (p_xy / (p_x * p_y)).ln()
+        })
+        .sum::<f64>()
+        .max(0.0)
+}
+
+/// Builds the class distribution from the class counts sorted in descending order, or None if
+/// there are no classes.
+fn class_distribution(counts: IndexMap<String, u64>) -> Option<ClassDistribution> {
+    let (_, majority_count) = counts.first()?;
+    let (minority_class, minority_count) = counts.last()?;
+    let total: u64 = counts.values().sum();
+    Some(ClassDistribution {
+        imbalance_ratio: *majority_count as f64 / *minority_count as f64,
+        minority_class: minority_class.clone(),
+        minority_percentage: *minority_count as f64 / total as f64 * 100.0,
+        counts,
+    })
+}
@@ -0,0 +1,261 @@
+//! # Temporal Module
+//!
+//! This module handles the analysis of the date, datetime, and time columns of a dataset, which
+//! the descriptive analysis skips since they aren't numerical. For each temporal column:
+//!
+//! - Range: the earliest and latest values, and the span between them.
+//! - Gaps: the expected interval between values is inferred as the median difference between
+//!   consecutive distinct values, and a gap is a difference longer than `GAP_TOLERANCE` times
+//!   that interval, as in the time series analysis. The `MAX_GAPS` longest gaps are kept.
+//! - Calendar distributions: the number of values on each day of the week and in each month,
+//!   which surface weekday-only collection or missing months. Time columns have no calendar.
+//! - Monotonicity: whether the values are sorted in row order, e.g. an append-only event log.
+//!
+//! Values are read as milliseconds since the Unix epoch (in UTC for time zone aware datetimes),
+//! or as milliseconds since midnight for time columns. Missing values are skipped.
+
+use crate::data::time_series::{
+    format_timestamp, median, timestamps, TimeGap, TimeSeriesError, GAP_TOLERANCE,
+};
+use chrono::{DateTime, Datelike, NaiveTime};
+use indexmap::IndexMap;
+use polars::prelude::*;
+use std::fmt;
+use thiserror::Error;
+
+/// The maximum number of gaps kept for each column, the longest ones.
+pub const MAX_GAPS: usize = 5;
+/// The names of the days of the week, in calendar distribution order.
+pub const WEEKDAYS: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+/// The names of the months, in calendar distribution order.
+pub const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// The error types for the temporal module.
+#[derive(Error, Debug)]
+pub enum TemporalError {
+    /// Occurs when Polars fails to complete an operation.
+    #[error("Polars error: {0}")]
+    Polars(#[from] PolarsError),
+
+    /// Occurs when a date or datetime column can't be converted to timestamps.
+    #[error("Timestamp error: {0}")]
+    Timestamp(#[from] TimeSeriesError),
+}
+
+/// The order of the values of a temporal column over the rows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Monotonicity {
+    /// Every value is later than the previous one.
+    StrictlyIncreasing,
+    /// Every value is the same as or later than the previous one.
+    Increasing,
+    /// Every value is earlier than the previous one.
+    StrictlyDecreasing,
+    /// Every value is the same as or earlier than the previous one.
+    Decreasing,
+    /// The values go back and forth in time.
+    Unordered,
+}
+
+impl fmt::Display for Monotonicity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let monotonicity = match self {
+            Monotonicity::StrictlyIncreasing => "strictly increasing",
+            Monotonicity::Increasing => "increasing",
+            Monotonicity::StrictlyDecreasing => "strictly decreasing",
+            Monotonicity::Decreasing => "decreasing",
+            Monotonicity::Unordered => "unordered",
+        };
+        write!(f, "{}", monotonicity)
+    }
+}
+
+/// The analysis results of a temporal column.
+#[derive(Debug, Clone)]
+pub struct TemporalStats {
+    /// The data type of the column.
+    pub dtype: DataType,
+    /// The number of non-null values.
+    pub count: usize,
+    /// The earliest value, in milliseconds since the Unix epoch (or since midnight for time
+    /// columns).
+    pub min: Option<i64>,
+    /// The latest value, in the same unit as `min`.
+    pub max: Option<i64>,
+    /// The median difference between consecutive distinct values in milliseconds, or None if
+    /// there are fewer than two distinct values.
+    pub interval: Option<i64>,
+    /// The number of gaps.
+    pub n_gaps: usize,
+    /// The `MAX_GAPS` longest gaps, in chronological order.
+    pub largest_gaps: Vec<TimeGap>,
+    /// The number of values on each day of the week, starting on Monday. None for time columns.
+    pub weekdays: Option<[u64; 7]>,
+    /// The number of values in each month, starting in January. None for time columns.
+    pub months: Option<[u64; 12]>,
+    /// The order of the values over the rows, or None if there are fewer than two values.
+    pub monotonicity: Option<Monotonicity>,
+}
+
+impl TemporalStats {
+    /// Gets the time between the earliest and latest values in milliseconds, or None if there
+    /// are no values.
+    pub fn span(&self) -> Option<i64> {
+        self.min.zip(self.max).map(|(min, max)| max - min)
+    }
+
+    /// Checks whether the column holds times of day rather than points in time.
+    pub fn is_time_of_day(&self) -> bool {
+        matches!(self.dtype, DataType::Time)
+    }
+
+    /// Formats a value of the column, as a time of day for time columns.
+    pub fn format_value(&self, value: i64) -> String {
+        if !self.is_time_of_day() {
+            return format_timestamp(value);
+        }
+        let seconds = value.div_euclid(1_000);
+        let nanoseconds = value.rem_euclid(1_000) * 1_000_000;
+        match NaiveTime::from_num_seconds_from_midnight_opt(seconds as u32, nanoseconds as u32) {
+            Some(time) if nanoseconds == 0 => time.format("%H:%M:%S").to_string(),
+            Some(time) => time.format("%H:%M:%S%.3f").to_string(),
+            None => value.to_string(),
+        }
+    }
+}
+
+/// Struct to hold the temporal column analysis of a dataset.
+#[derive(Debug, Default)]
+pub struct TemporalAnalysis {
+    /// The analysis results keyed by column name, in dataset order.
+    pub columns: IndexMap<String, TemporalStats>,
+}
+
+impl TemporalAnalysis {
+    /// Constructor for the TemporalAnalysis struct.
+    ///
+    /// ### Parameters
+    ///
+    /// - `lazy_df`: Reference to the LazyFrame.
+    /// - `schema`: Reference to the lazy frame's schema.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<Self, TemporalError>`: A new TemporalAnalysis instance or an error.
+    pub fn new(lazy_df: &LazyFrame, schema: &Schema) -> Result<Self, TemporalError> {
+        let columns: Vec<(&str, &DataType)> = schema
+            .iter()
+            .filter(|(_, dtype)| {
+                matches!(
+                    dtype,
+                    DataType::Date | DataType::Datetime(_, _) | DataType::Time
+                )
+            })
+            .map(|(name, dtype)| (name.as_str(), dtype))
+            .collect();
+        if columns.is_empty() {
+            return Ok(Self::default());
+        }
+        let df = lazy_df
+            .clone()
+            .select(
+                columns
+                    .iter()
+                    .map(|(name, _)| col(name))
+                    .collect::<Vec<Expr>>(),
+            )
+            .collect()?;
+
+        let mut analysis = IndexMap::new();
+        for (name, dtype) in columns {
+            let series = df.column(name)?;
+            let values: Vec<i64> = if matches!(dtype, DataType::Time) {
+                // Times are stored as nanoseconds since midnight.
+                series
+                    .to_physical_repr()
+                    .i64()?
+                    .into_iter()
+                    .flatten()
+                    .map(|value| value.div_euclid(1_000_000))
+                    .collect()
+            } else {
+                timestamps(series, dtype)?.into_iter().flatten().collect()
+            };
+            analysis.insert(name.to_owned(), column_stats(dtype, &values));
+        }
+
+        Ok(Self { columns: analysis })
+    }
+}
+
+/// Computes the analysis results of a temporal column from its non-null values, in row order.
+fn column_stats(dtype: &DataType, values: &[i64]) -> TemporalStats {
+    let monotonicity = (values.len() >= 2).then(|| {
+        let pairs = || values.windows(2).map(|pair| pair[1].cmp(&pair[0]));
+        if pairs().all(|order| order.is_gt()) {
+            Monotonicity::StrictlyIncreasing
+        } else if pairs().all(|order| order.is_ge()) {
+            Monotonicity::Increasing
+        } else if pairs().all(|order| order.is_lt()) {
+            Monotonicity::StrictlyDecreasing
+        } else if pairs().all(|order| order.is_le()) {
+            Monotonicity::Decreasing
+        } else {
+            Monotonicity::Unordered
+        }
+    });
+
+    let mut distinct = values.to_vec();
+    distinct.sort_unstable();
+    distinct.dedup();
+    let differences: Vec<i64> = distinct.windows(2).map(|pair| pair[1] - pair[0]).collect();
+    let interval = median(&differences);
+    let mut gaps: Vec<TimeGap> = Vec::new();
+    if let Some(interval) = interval.filter(|interval| *interval > 0) {
+        for (pair, difference) in distinct.windows(2).zip(&differences) {
+            let ratio = *difference as f64 / interval as f64;
+            if ratio > GAP_TOLERANCE {
+                gaps.push(TimeGap {
+                    start: pair[0],
+                    end: pair[1],
+                    missing_periods: (ratio.round() as u64).saturating_sub(1).max(1),
+                });
+            }
+        }
+    }
+    let n_gaps = gaps.len();
+    // Keep the longest gaps, earliest first among equally long ones, then restore their order.
+    gaps.sort_by(|a, b| b.duration().cmp(&a.duration()).then(a.start.cmp(&b.start)));
+    gaps.truncate(MAX_GAPS);
+    gaps.sort_by_key(|gap| gap.start);
+
+    let (weekdays, months) = if matches!(dtype, DataType::Time) {
+        (None, None)
+    } else {
+        let mut weekdays = [0; 7];
+        let mut months = [0; 12];
+        for datetime in values
+            .iter()
+            .filter_map(|value| DateTime::from_timestamp_millis(*value))
+        {
+            weekdays[datetime.weekday().num_days_from_monday() as usize] += 1;
+            months[datetime.month0() as usize] += 1;
+        }
+        (Some(weekdays), Some(months))
+    };
+
+    TemporalStats {
+        dtype: dtype.clone(),
+        count: values.len(),
+        min: distinct.first().copied(),
+        max: distinct.last().copied(),
+        interval,
+        n_gaps,
+        largest_gaps: gaps,
+        weekdays,
+        months,
+        monotonicity,
+    }
+}
@@ -0,0 +1,1081 @@
+//! # Text Module
+//!
+//! This module handles the profiling of the string columns of a dataset.
+//!
+//! ## Pattern Inference
+//!
+//! Each value is reduced to its character-class pattern, where uppercase letters become `A`,
+//! lowercase letters become `a`, digits become `9`, and every other character is kept as is (e.g.
+//! `ABC-1234` becomes `AAA-9999` and `12/31/2024` becomes `99/99/9999`). Patterns longer than
+//! `MAX_PATTERN_LENGTH` characters are truncated. The coverage of a pattern is the percentage of
+//! the non-missing values that match it. Patterns are also shown as regular expressions with the
+//! runs of a character class collapsed, e.g. `AAA-9999` as `[A-Z]{3}-\d{4}` (see `pattern_regex`).
+//!
+//! A column is flagged as having inconsistent formats when its dominant pattern covers at least
+//! `DOMINANT_PATTERN_THRESHOLD` percent of the values but not all of them, i.e. the values mostly
+//! follow one format with a few deviations.
+//!
+//! ## Length, Blanks, and Case
+//!
+//! The length of the values is summarized by its minimum, mean, and maximum in characters. Empty
+//! strings and whitespace-only strings are counted separately, since they aren't missing values
+//! to Polars but usually mean the same thing. The letter case of each value is classified as
+//! upper (`ABC`), lower (`abc`), title (`Abc Def`), mixed (`aBc`), or uncased when the value has
+//! no cased letters (e.g. `123`), so columns mixing `YES` and `yes` stand out.
+//!
+//! ## Validity
+//!
+//! Columns where at least `DETECTION_THRESHOLD` percent of the values are valid emails, URLs,
+//! UUIDs, or phone numbers are detected as that kind of structured string, and the values that
+//! fail validation are counted with a few examples. The validation is deliberately lightweight:
+//!
+//! - Emails have a single `@`, a non-empty local part, and a dotted domain with a 2+ letter TLD.
+//! - URLs have an `http`, `https`, or `ftp` scheme and a dotted (or `localhost`) host.
+//! - UUIDs are 32 hexadecimal digits in the hyphenated 8-4-4-4-12 layout.
+//! - Phone numbers have 7 to 15 digits with an optional leading `+` and space, `-`, `.`, or
+//!   parenthesis separators, with at least one `+` or separator so plain numbers aren't mistaken
+//!   for phone numbers.
+//!
+//! ## Case and Whitespace Variants
+//!
+//! Values that only differ by case or surrounding whitespace (e.g. `Male` and `male `) are
+//! usually the same category entered inconsistently. Each value is collapsed by trimming and
+//! lowercasing it, and the columns where the collapsed cardinality is lower than the raw
+//! cardinality get the `MAX_INVALID_EXAMPLES` groups of variants covering the most rows as
+//! examples.
+//!
+//! ## Numeric Strings
+//!
+//! Columns where at least `DETECTION_THRESHOLD` percent of the values look like numbers (an
+//! optional sign, digits, and an optional decimal part) are detected as numeric strings. Casting
+//! them to a number would corrupt the values that carry leading zeros (e.g. ZIP codes like
+//! `02134`) and, when the column would be cast to floats, the integers with more than
+//! `MAX_EXACT_DIGITS` significant digits (e.g. account numbers), since a 64-bit float can't hold
+//! them exactly. A column is cast to floats when it has decimals or integers that don't fit in a
+//! 64-bit integer, so e.g. a column of 19 digit IDs is safe to cast. Long decimals are left alone,
+//! as rounding their last digits is usually harmless.
+//!
+//! ## Dates
+//!
+//! Columns where at least `DETECTION_THRESHOLD` percent of the values parse as a date in one of
+//! the `DATE_FORMATS` are detected as string dates. A value is ambiguous when it parses in more
+//! than one format, e.g. `03/04/2024` is both March 4th (`MM/DD/YYYY`) and April 3rd
+//! (`DD/MM/YYYY`). The suggested parse format is the one that parses the most values, with ties
+//! broken by the number of values only that format parses. When the day and month order can't be
+//! told apart (every value is ambiguous), no format is suggested. Four digit year formats only
+//! accept years from 1000 on, so two digit years are matched by the `YY` formats instead.
+//!
+//! ## JSON
+//!
+//! Columns where at least `DETECTION_THRESHOLD` percent of the values look like JSON objects
+//! (i.e. start with `{`) are detected as JSON columns. The values that don't parse as JSON
+//! objects are counted as parse failures, and the presence of the top-level keys is reported as
+//! the percentage of the parsed objects that hold them. When expansion is enabled, the
+//! `MAX_JSON_KEYS` most common keys are expanded into virtual `<column>.<key>` columns that are
+//! profiled like any other column. The virtual columns are integers, floats, or booleans when
+//! every value of the key has that type, and strings otherwise (nested values are kept as JSON
+//! text).
+//!
+//! The profiling is compiled with the `text-profiling` feature, while the JSON expansion and the
+//! date and casting helpers used when loading the data are always compiled.
+
+#[cfg(feature = "text-profiling")]
+use chrono::{Datelike, NaiveDate};
+use indexmap::IndexMap;
+use polars::prelude::*;
+use serde_json::{Map, Value};
+#[cfg(feature = "text-profiling")]
+use std::collections::HashMap;
+use std::fmt;
+use thiserror::Error;
+
+/// Maximum number of patterns reported per column.
+pub const TOP_PATTERNS: usize = 5;
+/// Maximum number of characters in a pattern.
+pub const MAX_PATTERN_LENGTH: usize = 40;
+/// Coverage percentage from which the dominant pattern is expected to cover every value.
+pub const DOMINANT_PATTERN_THRESHOLD: f64 = 90.0;
+/// Percentage of valid values from which a column is detected as a structured string.
+pub const DETECTION_THRESHOLD: f64 = 50.0;
+/// Maximum number of invalid examples kept per column.
+pub const MAX_INVALID_EXAMPLES: usize = 5;
+/// Maximum number of top-level keys reported and expanded per JSON column.
+pub const MAX_JSON_KEYS: usize = 20;
+/// Maximum number of significant digits a 64-bit float holds exactly.
+pub const MAX_EXACT_DIGITS: usize = 15;
+/// The date formats that string dates are parsed with, as their label and `strftime` format.
+/// Month abbreviations also match full month names.
+pub const DATE_FORMATS: [(&str, &str); 11] = [
+    ("YYYY-MM-DD", "%Y-%m-%d"),
+    ("YYYY/MM/DD", "%Y/%m/%d"),
+    ("MM/DD/YYYY", "%m/%d/%Y"),
+    ("DD/MM/YYYY", "%d/%m/%Y"),
+    ("MM-DD-YYYY", "%m-%d-%Y"),
+    ("DD-MM-YYYY", "%d-%m-%Y"),
+    ("DD.MM.YYYY", "%d.%m.%Y"),
+    ("MM/DD/YY", "%m/%d/%y"),
+    ("DD/MM/YY", "%d/%m/%y"),
+    ("DD Mon YYYY", "%d %b %Y"),
+    ("Mon DD, YYYY", "%b %d, %Y"),
+];
+
+/// The error types for the text module.
+#[derive(Error, Debug)]
+pub enum TextError {
+    /// Occurs when Polars fails to complete an operation.
+    #[error("Polars error: {0}")]
+    Polars(#[from] PolarsError),
+}
+
+/// The kinds of structured strings that are validated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StructuredKind {
+    Email,
+    Url,
+    Uuid,
+    Phone,
+}
+
+impl StructuredKind {
+    /// All the structured string kinds, in detection priority order.
+    pub const ALL: [StructuredKind; 4] = [
+        StructuredKind::Email,
+        StructuredKind::Url,
+        StructuredKind::Uuid,
+        StructuredKind::Phone,
+    ];
+
+    /// Checks whether a value is a valid structured string of this kind.
+    pub fn validate(&self, value: &str) -> bool {
+        match self {
+            StructuredKind::Email => is_email(value),
+            StructuredKind::Url => is_url(value),
+            StructuredKind::Uuid => is_uuid(value),
+            StructuredKind::Phone => is_phone(value),
+        }
+    }
+}
+
+impl fmt::Display for StructuredKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match self {
+            StructuredKind::Email => "email",
+            StructuredKind::Url => "URL",
+            StructuredKind::Uuid => "UUID",
+            StructuredKind::Phone => "phone number",
+        };
+        write!(f, "{}", kind)
+    }
+}
+
+/// The validation results of a column detected as a structured string.
+#[derive(Debug, Clone)]
+pub struct Validity {
+    /// The detected kind of structured string.
+    pub kind: StructuredKind,
+    /// The percentage of the non-missing values that fail validation.
+    pub invalid_percentage: f64,
+    /// Up to `MAX_INVALID_EXAMPLES` distinct invalid values, in order of appearance.
+    pub invalid_examples: Vec<String>,
+}
+
+impl Validity {
+    /// Gets the human readable validity warning, or None if every value is valid.
+    pub fn warning(&self) -> Option<String> {
+        (self.invalid_percentage > 0.0).then(|| {
+            format!(
+                "Looks like {} values, but {:.2}% of the values are invalid.",
+                self.kind, self.invalid_percentage
+            )
+        })
+    }
+}
+
+/// The values of a column that only differ by case or surrounding whitespace.
+#[derive(Debug, Clone)]
+pub struct ValueVariants {
+    /// The number of distinct raw values.
+    pub n_unique: u64,
+    /// The number of distinct values after trimming and lowercasing them.
+    pub n_collapsed: u64,
+    /// Up to `MAX_INVALID_EXAMPLES` groups of raw values that collapse to the same value, from
+    /// the group covering the most rows to the least, with the values of each group from the most
+    /// to the least common.
+    pub examples: Vec<Vec<String>>,
+}
+
+impl ValueVariants {
+    /// Gets the human readable variant warning.
+    pub fn warning(&self) -> String {
+        format!(
+            "{} distinct values collapse to {} when ignoring case and surrounding whitespace.",
+            self.n_unique, self.n_collapsed
+        )
+    }
+}
+
+/// The precision risks of a column detected as holding numeric strings.
+#[derive(Debug, Clone)]
+pub struct NumericStrings {
+    /// The percentage of the non-missing values that look like numbers.
+    pub numeric_percentage: f64,
+    /// The percentage of the non-missing values with leading zeros.
+    pub leading_zero_percentage: f64,
+    /// The percentage of the non-missing values that are integers with more than
+    /// `MAX_EXACT_DIGITS` significant digits, when the column would be cast to floats.
+    pub long_digit_percentage: f64,
+    /// Up to `MAX_INVALID_EXAMPLES` distinct values that casting would corrupt, in order of
+    /// appearance.
+    pub examples: Vec<String>,
+}
+
+impl NumericStrings {
+    /// Gets the human readable casting warning, or None if every value can be cast safely.
+    pub fn warning(&self) -> Option<String> {
+        let mut risks = Vec::new();
+        if self.leading_zero_percentage > 0.0 {
+            risks.push(format!(
+                "{:.2}% of the values have leading zeros",
+                self.leading_zero_percentage
+            ));
+        }
+        if self.long_digit_percentage > 0.0 {
+            risks.push(format!(
+                "{:.2}% are integers with more than {} significant digits",
+                self.long_digit_percentage, MAX_EXACT_DIGITS
+            ));
+        }
+        (!risks.is_empty()).then(|| {
+            format!(
+                "Looks numeric, but {}. Casting the column to a number would corrupt them.",
+                risks.join(" and ")
+            )
+        })
+    }
+}
+
+/// The date formats of a column detected as holding string dates.
+#[derive(Debug, Clone)]
+pub struct DateFormats {
+    /// The percentage of the non-missing values that parse as a date.
+    pub date_percentage: f64,
+    /// The labels of the formats that parse at least one value and the percentage of the dates
+    /// they parse, sorted from the most to the least common.
+    pub formats: Vec<(String, f64)>,
+    /// The number of dates that parse in more than one format.
+    pub n_ambiguous: u64,
+    /// The percentage of the dates that parse in more than one format.
+    pub ambiguous_percentage: f64,
+    /// The label and `strftime` format of the suggested parse format, or None if the day and
+    /// month order is ambiguous.
+    pub suggested_format: Option<(String, String)>,
+}
+
+impl DateFormats {
+    /// Gets the human readable format mix warning, or None if every date parses in the suggested
+    /// format.
+    pub fn warning(&self) -> Option<String> {
+        let Some((label, _)) = &self.suggested_format else {
+            return Some(format!(
+                "The day and month order of the dates is ambiguous, {} values parse as either.",
+                self.n_ambiguous
+            ));
+        };
+        let coverage = self
+            .formats
+            .iter()
+            .find(|(format, _)| format == label)
+            .map_or(0.0, |(_, percentage)| *percentage);
+        (coverage < 100.0).then(|| {
+            format!(
+                "Dates mix {} formats, {:.2}% of them don't parse as '{}'.",
+                self.formats.len(),
+                100.0 - coverage,
+                label
+            )
+        })
+    }
+}
+
+/// The parsing results of a column detected as holding JSON objects.
+#[derive(Debug, Clone)]
+pub struct JsonProfile {
+    /// The percentage of the non-missing values that fail to parse as JSON objects.
+    pub parse_failure_percentage: f64,
+    /// The number of distinct top-level keys.
+    pub n_keys: usize,
+    /// The `MAX_JSON_KEYS` most common top-level keys and the percentage of the parsed objects
+    /// holding them, sorted from the most to the least common.
+    pub keys: Vec<(String, f64)>,
+}
+
+impl JsonProfile {
+    /// Gets the human readable parse failure warning, or None if every value parses.
+    pub fn warning(&self) -> Option<String> {
+        (self.parse_failure_percentage > 0.0).then(|| {
+            format!(
+                "{:.2}% of the JSON values fail to parse.",
+                self.parse_failure_percentage
+            )
+        })
+    }
+}
+
+/// The length statistics of a string column, in characters.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LengthStats {
+    /// The length of the shortest value.
+    pub min: usize,
+    /// The mean length of the values.
+    pub mean: f64,
+    /// The length of the longest value.
+    pub max: usize,
+}
+
+/// The number of values of a string column in each letter case.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CaseCounts {
+    /// Values whose cased letters are all uppercase, e.g. `ABC`.
+    pub upper: u64,
+    /// Values whose cased letters are all lowercase, e.g. `abc`.
+    pub lower: u64,
+    /// Values where every word starts with an uppercase letter followed by lowercase letters,
+    /// e.g. `Abc Def`.
+    pub title: u64,
+    /// Values mixing cases any other way, e.g. `aBc`.
+    pub mixed: u64,
+    /// Values without cased letters, e.g. `123`.
+    pub uncased: u64,
+}
+
+impl CaseCounts {
+    /// Gets the number of values in each case with its label, in display order.
+    pub fn labeled(&self) -> [(&'static str, u64); 5] {
+        [
+            ("upper", self.upper),
+            ("lower", self.lower),
+            ("title", self.title),
+            ("mixed", self.mixed),
+            ("uncased", self.uncased),
+        ]
+    }
+}
+
+/// The profile of a string column.
+#[derive(Debug, Clone)]
+pub struct TextColumnProfile {
+    /// The number of non-missing values.
+    pub count: u64,
+    /// The length statistics of the values.
+    pub lengths: LengthStats,
+    /// The number of empty strings.
+    pub n_empty: u64,
+    /// The number of non-empty strings made only of whitespace.
+    pub n_whitespace: u64,
+    /// The number of values in each letter case.
+    pub case: CaseCounts,
+    /// The number of distinct patterns.
+    pub n_patterns: usize,
+    /// The `TOP_PATTERNS` most common patterns and their coverage percentages, sorted from the
+    /// most to the least common.
+    pub patterns: Vec<(String, f64)>,
+    /// The validation results, or None if the column isn't detected as a structured string.
+    pub validity: Option<Validity>,
+    /// The JSON parsing results, or None if the column isn't detected as holding JSON objects.
+    pub json: Option<JsonProfile>,
+    /// The precision risks, or None if the column isn't detected as holding numeric strings.
+    pub numeric: Option<NumericStrings>,
+    /// The date formats, or None if the column isn't detected as holding string dates.
+    pub dates: Option<DateFormats>,
+    /// The case and whitespace variants, or None if no values only differ by case or whitespace.
+    pub variants: Option<ValueVariants>,
+}
+
+impl TextColumnProfile {
+    /// Gets the human readable warning about the empty and whitespace-only strings, or None if
+    /// there are none.
+    pub fn blank_warning(&self) -> Option<String> {
+        (self.n_empty + self.n_whitespace > 0).then(|| {
+            format!(
+                "{} empty and {} whitespace-only strings aren't counted as missing values.",
+                self.n_empty, self.n_whitespace
+            )
+        })
+    }
+
+    /// Gets the human readable format inconsistency warning, or None if the column doesn't have a
+    /// dominant pattern or every value matches it.
+    pub fn format_warning(&self) -> Option<String> {
+        let (pattern, coverage) = self.patterns.first()?;
+        (*coverage >= DOMINANT_PATTERN_THRESHOLD && *coverage < 100.0).then(|| {
+            format!(
+                "{:.2}% of the values don't follow the dominant pattern '{}'.",
+                100.0 - coverage,
+                pattern_regex(pattern)
+            )
+        })
+    }
+}
+
+/// Struct to hold the text profiling results for a dataset.
+#[derive(Debug, Default)]
+pub struct TextAnalysis {
+    /// The profiles keyed by string column name. Entirely missing columns are skipped.
+    pub columns: IndexMap<String, TextColumnProfile>,
+}
+
+#[cfg(feature = "text-profiling")]
+impl TextAnalysis {
+    /// Constructor for the TextAnalysis struct.
+    ///
+    /// ### Parameters
+    ///
+    /// - `lazy_df`: Reference to the LazyFrame.
+    /// - `schema`: Reference to the lazy frame's schema.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<Self, TextError>`: A new TextAnalysis instance or an error.
+    pub fn new(lazy_df: &LazyFrame, schema: &Schema) -> Result<Self, TextError> {
+        let string_columns: Vec<&str> = schema
+            .iter()
+            .filter(|(_, dtype)| matches!(dtype, DataType::String))
+            .map(|(name, _)| name.as_str())
+            .collect();
+        if string_columns.is_empty() {
+            return Ok(Self::default());
+        }
+
+        let df = lazy_df
+            .clone()
+            .select(
+                string_columns
+                    .iter()
+                    .map(|name| col(name))
+                    .collect::<Vec<Expr>>(),
+            )
+            .collect()?;
+
+        let mut columns = IndexMap::new();
+        for name in string_columns {
+            let values = df.column(name)?.str()?;
+            let mut counts: HashMap<String, u64> = HashMap::new();
+            let mut lengths = LengthStats {
+                min: usize::MAX,
+                ..Default::default()
+            };
+            let (mut n_empty, mut n_whitespace) = (0, 0);
+            let mut case = CaseCounts::default();
+            for value in values.into_iter().flatten() {
+                *counts.entry(pattern(value)).or_default() += 1;
+                let length = value.chars().count();
+                lengths.min = lengths.min.min(length);
+                lengths.max = lengths.max.max(length);
+                lengths.mean += length as f64;
+                if value.is_empty() {
+                    n_empty += 1;
+                } else if value.trim().is_empty() {
+                    n_whitespace += 1;
+                }
+                match letter_case(value) {
+                    LetterCase::Upper => case.upper += 1,
+                    LetterCase::Lower => case.lower += 1,
+                    LetterCase::Title => case.title += 1,
+                    LetterCase::Mixed => case.mixed += 1,
+                    LetterCase::Uncased => case.uncased += 1,
+                }
+            }
+            let count: u64 = counts.values().sum();
+            if count == 0 {
+                continue;
+            }
+            lengths.mean /= count as f64;
+
+            let validity = validity(values, count);
+            let json = json_objects(values, count).map(|objects| json_profile(&objects, count));
+            let numeric = numeric_strings(values, count);
+            let dates = date_formats(values, count);
+            let variants = value_variants(values);
+            let n_patterns = counts.len();
+            let mut patterns: Vec<(String, u64)> = counts.into_iter().collect();
+            patterns.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+            patterns.truncate(TOP_PATTERNS);
+            columns.insert(
+                name.to_owned(),
+                TextColumnProfile {
+                    count,
+                    lengths,
+                    n_empty,
+                    n_whitespace,
+                    case,
+                    n_patterns,
+                    patterns: patterns
+                        .into_iter()
+                        .map(|(pattern, matches)| (pattern, matches as f64 / count as f64 * 100.0))
+                        .collect(),
+                    validity,
+                    json,
+                    numeric,
+                    dates,
+                    variants,
+                },
+            );
+        }
+
+        Ok(Self { columns })
+    }
+}
+
+/// Expands the most common top-level keys of the JSON columns into virtual `<column>.<key>`
+/// columns. Keys whose virtual column name is already taken are skipped.
+///
+/// ### Parameters
+///
+/// - `lazy_df`: Reference to the LazyFrame.
+/// - `schema`: Reference to the lazy frame's schema.
+///
+/// ### Returns
+///
+/// - `Result<LazyFrame, TextError>`: The LazyFrame with the virtual columns appended or an error.
+pub fn expand_json_columns(lazy_df: &LazyFrame, schema: &Schema) -> Result<LazyFrame, TextError> {
+    let string_columns: Vec<&str> = schema
+        .iter()
+        .filter(|(_, dtype)| matches!(dtype, DataType::String))
+        .map(|(name, _)| name.as_str())
+        .collect();
+    if string_columns.is_empty() {
+        return Ok(lazy_df.clone());
+    }
+
+    let mut df = lazy_df.clone().collect()?;
+    let mut virtual_columns: Vec<Series> = Vec::new();
+    for name in string_columns {
+        let values = df.column(name)?.str()?;
+        let count = (values.len() - values.null_count()) as u64;
+        let Some(objects) = json_objects(values, count) else {
+            continue;
+        };
+
+        for (key, _) in json_profile(&objects, count).keys {
+            let column_name = format!("{}.{}", name, key);
+            if schema.contains(&column_name) {
+                continue;
+            }
+            let key_values: Vec<Option<&Value>> = objects
+                .iter()
+                .map(|object| {
+                    object
+                        .as_ref()
+                        .and_then(|object| object.get(&key))
+                        .filter(|value| !value.is_null())
+                })
+                .collect();
+            virtual_columns.push(virtual_column(&column_name, &key_values));
+        }
+    }
+    df.hstack_mut(&virtual_columns)?;
+
+    Ok(df.lazy())
+}
+
+/// Parses the values as JSON objects, or None if fewer than `DETECTION_THRESHOLD` percent of the
+/// values look like JSON objects. Missing values and parse failures are None.
+fn json_objects(values: &StringChunked, count: u64) -> Option<Vec<Option<Map<String, Value>>>> {
+    let looks_like_json = values
+        .into_iter()
+        .flatten()
+        .filter(|value| value.trim_start().starts_with('{'))
+        .count();
+    if (looks_like_json as f64 / count as f64 * 100.0) < DETECTION_THRESHOLD {
+        return None;
+    }
+
+    Some(
+        values
+            .into_iter()
+            .map(|value| match serde_json::from_str(value?) {
+                Ok(Value::Object(object)) => Some(object),
+                _ => None,
+            })
+            .collect(),
+    )
+}
+
+/// Summarizes the parsed JSON objects of a column with `count` non-missing values.
+fn json_profile(objects: &[Option<Map<String, Value>>], count: u64) -> JsonProfile {
+    let mut key_counts: IndexMap<&str, u64> = IndexMap::new();
+    let mut parsed = 0;
+    for object in objects.iter().flatten() {
+        parsed += 1;
+        for key in object.keys() {
+            *key_counts.entry(key).or_default() += 1;
+        }
+    }
+
+    let n_keys = key_counts.len();
+    let mut keys: Vec<(&str, u64)> = key_counts.into_iter().collect();
+    // The sort is stable, so ties keep the order of first appearance.
+    keys.sort_by_key(|(_, present)| std::cmp::Reverse(*present));
+    keys.truncate(MAX_JSON_KEYS);
+    JsonProfile {
+        parse_failure_percentage: (count - parsed) as f64 / count as f64 * 100.0,
+        n_keys,
+        keys: keys
+            .into_iter()
+            .map(|(key, present)| (key.to_owned(), present as f64 / parsed as f64 * 100.0))
+            .collect(),
+    }
+}
+
+/// Builds a virtual column from the values of a JSON key, typed as integers, floats, or booleans
+/// when every value has that type and as strings otherwise.
+fn virtual_column(name: &str, values: &[Option<&Value>]) -> Series {
+    let present = || values.iter().flatten();
+    if present().all(|value| value.is_i64()) {
+        let values: Vec<Option<i64>> = values
+            .iter()
+            .map(|value| value.and_then(Value::as_i64))
+            .collect();
+        Series::new(name, values)
+    } else if present().all(|value| value.is_number()) {
+        let values: Vec<Option<f64>> = values
+            .iter()
+            .map(|value| value.and_then(Value::as_f64))
+            .collect();
+        Series::new(name, values)
+    } else if present().all(|value| value.is_boolean()) {
+        let values: Vec<Option<bool>> = values
+            .iter()
+            .map(|value| value.and_then(Value::as_bool))
+            .collect();
+        Series::new(name, values)
+    } else {
+        let values: Vec<Option<String>> = values
+            .iter()
+            .map(|value| {
+                value.map(|value| match value {
+                    Value::String(string) => string.clone(),
+                    other => other.to_string(),
+                })
+            })
+            .collect();
+        Series::new(name, values)
+    }
+}
+
+/// Detects whether the values are a kind of structured string and validates them, or None if no
+/// kind reaches `DETECTION_THRESHOLD`.
+#[cfg(feature = "text-profiling")]
+fn validity(values: &StringChunked, count: u64) -> Option<Validity> {
+    let (kind, valid) = StructuredKind::ALL
+        .iter()
+        .map(|kind| {
+            let valid = values
+                .into_iter()
+                .flatten()
+                .filter(|value| kind.validate(value))
+                .count() as u64;
+            (*kind, valid)
+        })
+        .max_by_key(|(_, valid)| *valid)?;
+    if (valid as f64 / count as f64 * 100.0) < DETECTION_THRESHOLD {
+        return None;
+    }
+
+    let mut invalid_examples: Vec<String> = Vec::new();
+    for value in values.into_iter().flatten() {
+        if invalid_examples.len() == MAX_INVALID_EXAMPLES {
+            break;
+        }
+        if !kind.validate(value) && !invalid_examples.iter().any(|example| example == value) {
+            invalid_examples.push(value.to_owned());
+        }
+    }
+    Some(Validity {
+        kind,
+        invalid_percentage: (count - valid) as f64 / count as f64 * 100.0,
+        invalid_examples,
+    })
+}
+
+/// Detects whether the values are numeric strings and finds the values that casting would
+/// corrupt, or None if fewer than `DETECTION_THRESHOLD` percent of the values look numeric.
+#[cfg(feature = "text-profiling")]
+fn numeric_strings(values: &StringChunked, count: u64) -> Option<NumericStrings> {
+    // The long integers only lose digits when the column is cast to floats rather than integers.
+    let casts_to_float = values
+        .into_iter()
+        .flatten()
+        .filter_map(numeric_risks)
+        .any(|(_, _, is_float)| is_float);
+    let mut numeric = 0;
+    let mut leading_zeros = 0;
+    let mut long_digits = 0;
+    let mut examples: Vec<String> = Vec::new();
+    for value in values.into_iter().flatten() {
+        let Some((has_leading_zeros, has_long_digits, _)) = numeric_risks(value) else {
+            continue;
+        };
+        let has_long_digits = has_long_digits && casts_to_float;
+        numeric += 1;
+        leading_zeros += has_leading_zeros as u64;
+        long_digits += has_long_digits as u64;
+        if (has_leading_zeros || has_long_digits)
+            && examples.len() < MAX_INVALID_EXAMPLES
+            && !examples.iter().any(|example| example == value)
+        {
+            examples.push(value.to_owned());
+        }
+    }
+    let percentage = |n: u64| n as f64 / count as f64 * 100.0;
+    (percentage(numeric) >= DETECTION_THRESHOLD).then(|| NumericStrings {
+        numeric_percentage: percentage(numeric),
+        leading_zero_percentage: percentage(leading_zeros),
+        long_digit_percentage: percentage(long_digits),
+        examples,
+    })
+}
+
+/// Groups the values that only differ by case or surrounding whitespace, or None if every value
+/// collapses to a distinct value.
+#[cfg(feature = "text-profiling")]
+fn value_variants(values: &StringChunked) -> Option<ValueVariants> {
+    let mut frequencies: HashMap<&str, u64> = HashMap::new();
+    for value in values.into_iter().flatten() {
+        *frequencies.entry(value).or_default() += 1;
+    }
+    let mut groups: HashMap<String, Vec<(&str, u64)>> = HashMap::new();
+    for (value, frequency) in &frequencies {
+        groups
+            .entry(value.trim().to_lowercase())
+            .or_default()
+            .push((value, *frequency));
+    }
+    if groups.len() == frequencies.len() {
+        return None;
+    }
+
+    let mut variant_groups: Vec<(u64, Vec<(&str, u64)>)> = groups
+        .into_values()
+        .filter(|group| group.len() > 1)
+        .map(|mut group| {
+            group.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+            (group.iter().map(|(_, frequency)| frequency).sum(), group)
+        })
+        .collect();
+    variant_groups.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.1[0].0.cmp(b.1[0].0)));
+    Some(ValueVariants {
+        n_unique: frequencies.len() as u64,
+        n_collapsed: (frequencies.len()
+            - variant_groups
+                .iter()
+                .map(|(_, group)| group.len() - 1)
+                .sum::<usize>()) as u64,
+        examples: variant_groups
+            .into_iter()
+            .take(MAX_INVALID_EXAMPLES)
+            .map(|(_, group)| {
+                group
+                    .into_iter()
+                    .map(|(value, _)| value.to_owned())
+                    .collect()
+            })
+            .collect(),
+    })
+}
+
+/// Detects whether the values are string dates and counts the formats they parse in, or None if
+/// fewer than `DETECTION_THRESHOLD` percent of the values parse as a date.
+#[cfg(feature = "text-profiling")]
+fn date_formats(values: &StringChunked, count: u64) -> Option<DateFormats> {
+    let mut matches = [0u64; DATE_FORMATS.len()];
+    let mut exclusive_matches = [0u64; DATE_FORMATS.len()];
+    let mut n_dates = 0;
+    let mut n_ambiguous = 0;
+    for value in values.into_iter().flatten() {
+        let value = value.trim();
+        let parsed: Vec<usize> = DATE_FORMATS
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, format))| {
+                NaiveDate::parse_from_str(value, format).is_ok_and(|date| {
+                    !format.contains("%Y") || (1000..=9999).contains(&date.year())
+                })
+            })
+            .map(|(index, _)| index)
+            .collect();
+        match parsed.as_slice() {
+            [] => continue,
+            [index] => exclusive_matches[*index] += 1,
+            _ => n_ambiguous += 1,
+        }
+        n_dates += 1;
+        for index in parsed {
+            matches[index] += 1;
+        }
+    }
+    let percentage = |n: u64, total: u64| n as f64 / total as f64 * 100.0;
+    if percentage(n_dates, count) < DETECTION_THRESHOLD {
+        return None;
+    }
+
+    let mut ranked: Vec<usize> = (0..DATE_FORMATS.len())
+        .filter(|index| matches[*index] > 0)
+        .collect();
+    ranked.sort_by_key(|index| std::cmp::Reverse((matches[*index], exclusive_matches[*index])));
+    let suggested_format = match ranked.as_slice() {
+        [first, second, ..]
+            if (matches[*first], exclusive_matches[*first])
+                == (matches[*second], exclusive_matches[*second]) =>
+        {
+            None
+        }
+        [first, ..] => Some((
+            DATE_FORMATS[*first].0.to_owned(),
+            DATE_FORMATS[*first].1.to_owned(),
+        )),
+        [] => None,
+    };
+    Some(DateFormats {
+        date_percentage: percentage(n_dates, count),
+        formats: ranked
+            .into_iter()
+            .map(|index| {
+                (
+                    DATE_FORMATS[index].0.to_owned(),
+                    percentage(matches[index], n_dates),
+                )
+            })
+            .collect(),
+        n_ambiguous,
+        ambiguous_percentage: percentage(n_ambiguous, n_dates),
+        suggested_format,
+    })
+}
+
+/// Checks whether casting a value to a number would corrupt it, i.e. it looks like a number with
+/// leading zeros or an integer with more than `MAX_EXACT_DIGITS` significant digits that doesn't
+/// fit in a 64-bit integer (and so would be cast to a float).
+///
+/// ### Parameters
+///
+/// - `value`: The raw string value.
+///
+/// ### Returns
+///
+/// - `bool`: Whether the value would be corrupted by casting it to a number.
+pub fn casting_corrupts(value: &str) -> bool {
+    numeric_risks(value).is_some_and(|(has_leading_zeros, has_long_digits, is_float)| {
+        has_leading_zeros || (has_long_digits && is_float)
+    })
+}
+
+/// Checks whether a value that looks like a number has leading zeros, whether it's an integer
+/// with more than `MAX_EXACT_DIGITS` significant digits, and whether it would be cast to a float
+/// (it has a decimal part or doesn't fit in a 64-bit integer), or None if it doesn't look like a
+/// number.
+fn numeric_risks(value: &str) -> Option<(bool, bool, bool)> {
+    let unsigned = value.strip_prefix(['+', '-']).unwrap_or(value);
+    let (integer, fraction) = match unsigned.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (unsigned, None),
+    };
+    let fraction_digits = fraction.unwrap_or_default();
+    if (integer.is_empty() && fraction_digits.is_empty())
+        || !integer
+            .chars()
+            .chain(fraction_digits.chars())
+            .all(|c| c.is_ascii_digit())
+    {
+        return None;
+    }
+    Some((
+        integer.len() > 1 && integer.starts_with('0'),
+        fraction.is_none() && integer.trim_start_matches('0').len() > MAX_EXACT_DIGITS,
+        fraction.is_some() || value.parse::<i64>().is_err(),
+    ))
+}
+
+/// Checks whether a value is a valid email address.
+fn is_email(value: &str) -> bool {
+    let Some((local, domain)) = value.split_once('@') else {
+        return false;
+    };
+    !local.is_empty()
+        && !domain.contains('@')
+        && local
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "!#$%&'*+/=?^_`{|}~.-".contains(c))
+        && !local.starts_with('.')
+        && !local.ends_with('.')
+        && is_domain(domain)
+}
+
+/// Checks whether a value is a valid URL.
+fn is_url(value: &str) -> bool {
+    let Some((scheme, rest)) = value.split_once("://") else {
+        return false;
+    };
+    if !matches!(
+        scheme.to_ascii_lowercase().as_str(),
+        "http" | "https" | "ftp"
+    ) || rest.chars().any(char::is_whitespace)
+    {
+        return false;
+    }
+    let authority = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    let host_and_port = authority.rsplit('@').next().unwrap_or_default();
+    let host = match host_and_port.rsplit_once(':') {
+        Some((host, port)) if port.chars().all(|c| c.is_ascii_digit()) => host,
+        _ => host_and_port,
+    };
+    host.eq_ignore_ascii_case("localhost") || is_domain(host)
+}
+
+/// Checks whether a value is a dotted domain name with an alphabetic TLD of at least 2 letters.
+fn is_domain(value: &str) -> bool {
+    let labels: Vec<&str> = value.split('.').collect();
+    labels.len() >= 2
+        && labels.iter().all(|label| {
+            !label.is_empty()
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        })
+        && labels
+            .last()
+            .is_some_and(|tld| tld.len() >= 2 && tld.chars().all(|c| c.is_ascii_alphabetic()))
+}
+
+/// Checks whether a value is a hyphenated UUID.
+fn is_uuid(value: &str) -> bool {
+    value.len() == 36
+        && value.char_indices().all(|(i, c)| match i {
+            8 | 13 | 18 | 23 => c == '-',
+            _ => c.is_ascii_hexdigit(),
+        })
+}
+
+/// Checks whether a value is a phone number.
+fn is_phone(value: &str) -> bool {
+    let number = value.strip_prefix('+').unwrap_or(value);
+    let digits = number.chars().filter(|c| c.is_ascii_digit()).count();
+    let separators = number.chars().filter(|c| " -.()".contains(*c)).count();
+    (7..=15).contains(&digits)
+        && digits + separators == number.chars().count()
+        && (separators > 0 || number.len() < value.len())
+}
+
+/// The letter case of a value.
+#[cfg(feature = "text-profiling")]
+enum LetterCase {
+    Upper,
+    Lower,
+    Title,
+    Mixed,
+    Uncased,
+}
+
+/// Classifies the letter case of a value. A value with a single cased letter (e.g. `A`) is upper
+/// or lower rather than title case.
+#[cfg(feature = "text-profiling")]
+fn letter_case(value: &str) -> LetterCase {
+    let (mut upper, mut lower) = (false, false);
+    for c in value.chars() {
+        upper |= c.is_uppercase();
+        lower |= c.is_lowercase();
+    }
+    match (upper, lower) {
+        (false, false) => LetterCase::Uncased,
+        (true, false) => LetterCase::Upper,
+        (false, true) => LetterCase::Lower,
+        (true, true) => {
+            let is_title = value
+                .split(|c: char| !c.is_alphabetic())
+                .filter(|word| !word.is_empty())
+                .all(|word| {
+                    let mut chars = word.chars();
+                    chars.next().is_some_and(|c| !c.is_lowercase())
+                        && chars.all(|c| !c.is_uppercase())
+                });
+            if is_title {
+                LetterCase::Title
+            } else {
+                LetterCase::Mixed
+            }
+        }
+    }
+}
+
+/// Converts a character-class pattern into a regular expression, collapsing the runs of a class
+/// into a repetition, e.g. `AAA-9999` into `[A-Z]{3}-\d{4}`. The other characters are escaped,
+/// and a truncated pattern ends with `.*`.
+///
+/// ### Parameters
+///
+/// - `pattern`: The character-class pattern.
+///
+/// ### Returns
+///
+/// - `String`: The regular expression.
+pub fn pattern_regex(pattern: &str) -> String {
+    let truncated = pattern.chars().count() > MAX_PATTERN_LENGTH && pattern.ends_with("...");
+    let pattern = if truncated {
+        &pattern[..pattern.len() - 3]
+    } else {
+        pattern
+    };
+
+    let mut regex = String::new();
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        let class = match c {
+            'A' => "[A-Z]",
+            'a' => "[a-z]",
+            '9' => "\\d",
+            _ => {
+                if "\\.+*?()|[]{}^$".contains(c) {
+                    regex.push('\\');
+                }
+                regex.push(c);
+                continue;
+            }
+        };
+        let mut run = 1;
+        while chars.next_if_eq(&c).is_some() {
+            run += 1;
+        }
+        regex.push_str(class);
+        if run > 1 {
+            regex.push_str(&format!("{{{}}}", run));
+        }
+    }
+    if truncated {
+        regex.push_str(".*");
+    }
+    regex
+}
+
+/// Reduces a value to its character-class pattern.
+#[cfg(feature = "text-profiling")]
+fn pattern(value: &str) -> String {
+    let mut pattern: String = value
+        .chars()
+        .take(MAX_PATTERN_LENGTH)
+        .map(|c| {
+            if c.is_uppercase() {
+                'A'
+            } else if c.is_lowercase() {
+                'a'
+            } else if c.is_numeric() {
+                '9'
+            } else {
+                c
+            }
+        })
+        .collect();
+    if value.chars().nth(MAX_PATTERN_LENGTH).is_some() {
+        pattern.push_str("...");
+    }
+    pattern
+}
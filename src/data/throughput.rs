@@ -0,0 +1,124 @@
+//! # Throughput Module
+//!
+//! This module times the stages of a run that read, analyze, and plot the data, and derives their
+//! throughput in rows and bytes per second. It's enabled in verbose mode, to help judge whether
+//! sampling the plots (`--sample`) or the low memory mode is worth enabling for a dataset.
+//!
+//! The files are scanned lazily, so the reading stage only covers opening them and inferring the
+//! schema, and most of the actual reading happens within the analysis stage. The byte throughput
+//! is based on the size of the input files, so it's unavailable for inputs that weren't read from
+//! disk (the standard input, remote files, and database queries).
+
+use crate::data::provenance::format_file_size;
+use crate::data::time_series::format_duration;
+use crate::progress::Stage;
+use std::time::Duration;
+
+/// The time a stage of the run took.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StageTiming {
+    /// The stage.
+    pub stage: Stage,
+    /// The time the stage took.
+    pub duration: Duration,
+}
+
+/// The stage timings of a run and the amount of data it went through.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Throughput {
+    /// The number of rows analyzed.
+    pub n_rows: u64,
+    /// The total size of the input files in bytes, if they were read from disk.
+    pub n_bytes: Option<u64>,
+    /// The timed stages, in run order.
+    pub stages: Vec<StageTiming>,
+}
+
+impl Throughput {
+    /// Constructor for the Throughput struct.
+    ///
+    /// ### Parameters
+    ///
+    /// - `n_rows`: The number of rows analyzed.
+    /// - `n_bytes`: The total size of the input files in bytes, if they were read from disk.
+    ///
+    /// ### Returns
+    ///
+    /// - `Throughput`: A new Throughput instance without timed stages.
+    pub fn new(n_rows: u64, n_bytes: Option<u64>) -> Self {
+        Self {
+            n_rows,
+            n_bytes,
+            stages: Vec::new(),
+        }
+    }
+
+    /// Records the time a stage took.
+    pub fn record(&mut self, stage: Stage, duration: Duration) {
+        self.stages.push(StageTiming { stage, duration });
+    }
+
+    /// Gets the timing of a stage, if it was timed.
+    pub fn stage(&self, stage: Stage) -> Option<&StageTiming> {
+        self.stages.iter().find(|timing| timing.stage == stage)
+    }
+
+    /// Gets the total time of the timed stages.
+    pub fn total_duration(&self) -> Duration {
+        self.stages.iter().map(|timing| timing.duration).sum()
+    }
+
+    /// Gets the number of rows a stage went through per second, or None if it took no
+    /// measurable time.
+    pub fn rows_per_second(&self, timing: &StageTiming) -> Option<f64> {
+        per_second(self.n_rows, timing.duration)
+    }
+
+    /// Gets the number of input bytes a stage went through per second, or None if the input size
+    /// is unknown or the stage took no measurable time.
+    pub fn bytes_per_second(&self, timing: &StageTiming) -> Option<f64> {
+        self.n_bytes
+            .and_then(|n_bytes| per_second(n_bytes, timing.duration))
+    }
+
+    /// Formats the row throughput of a stage, e.g. `12345 rows/s`, or `-` if unavailable.
+    pub fn format_rows_per_second(&self, timing: &StageTiming) -> String {
+        self.rows_per_second(timing)
+            .map_or_else(|| "-".to_owned(), |rate| format!("{:.0} rows/s", rate))
+    }
+
+    /// Formats the byte throughput of a stage with a binary unit, e.g. `1.50 MiB/s`, or `-` if
+    /// unavailable.
+    pub fn format_bytes_per_second(&self, timing: &StageTiming) -> String {
+        self.bytes_per_second(timing).map_or_else(
+            || "-".to_owned(),
+            |rate| format!("{}/s", format_file_size(rate.round() as u64)),
+        )
+    }
+
+    /// Formats a one line summary of a stage for the verbose output, e.g.
+    /// `Analyzing data: 1s 250ms (80000 rows/s, 12.50 MiB/s)`.
+    pub fn summary(&self, timing: &StageTiming) -> String {
+        let mut rates = vec![self.format_rows_per_second(timing)];
+        if self.n_bytes.is_some() {
+            rates.push(self.format_bytes_per_second(timing));
+        }
+        format!(
+            "{}: {} ({})",
+            timing.stage,
+            format_stage_duration(timing.duration),
+            rates.join(", ")
+        )
+    }
+}
+
+/// Formats the time a stage took, with millisecond precision.
+pub fn format_stage_duration(duration: Duration) -> String {
+    format_duration(duration.as_millis() as i64)
+}
+
+/// Divides an amount by a duration in seconds, or None for durations under a millisecond, which
+/// would give meaningless rates.
+fn per_second(amount: u64, duration: Duration) -> Option<f64> {
+    (duration.as_millis() > 0).then(|| amount as f64 / duration.as_secs_f64())
+}
@@ -0,0 +1,812 @@
+//! # Time Series Module
+//!
+//! This module handles the time series mode analysis of a dataset, where one column is designated
+//! as the datetime index. The sampling regularity of the index is checked so gaps and duplicated
+//! timestamps (common in sensor and IoT datasets) can be spotted before modeling.
+//!
+//! The index can be a date, a datetime, or a string column. Strings are parsed as RFC 3339
+//! timestamps, `%Y-%m-%d %H:%M:%S` style datetimes (with a `T` or space separator and optional
+//! fractional seconds), or `%Y-%m-%d` dates. Missing and unparseable values are excluded.
+//!
+//! ## Sampling Interval
+//!
+//! The expected sampling interval is inferred as the median difference between consecutive
+//! distinct timestamps, so occasional gaps and jitter don't skew it. Then:
+//!
+//! - A gap is a difference longer than `GAP_TOLERANCE` times the interval.
+//! - An irregular interval is a difference off the interval by more than
+//!   `IRREGULARITY_TOLERANCE` (as a fraction of the interval) that isn't a gap.
+//! - A duplicated timestamp is a timestamp that appears in more than one row.
+//!
+//! ## Rolling Statistics
+//!
+//! The rolling mean and standard deviation of each numerical feature are computed over a window
+//! of consecutive samples (rows ordered by the index, skipping missing values), so drift and
+//! variance changes over time stand out. Two scores summarize them:
+//!
+//! - The mean drift is the change between the first and last rolling means, in standard
+//!   deviations of the whole feature.
+//! - The standard deviation ratio is the largest rolling standard deviation divided by the
+//!   smallest, where 1 means the variance is stable over time.
+//!
+//! Constant features are skipped. Long series are thinned to at most `MAX_ROLLING_POINTS` rolling
+//! windows for plotting, the scores use every window.
+//!
+//! ## Autocorrelation
+//!
+//! The autocorrelation (ACF) and partial autocorrelation (PACF) of each numerical feature are
+//! computed up to `MAX_LAGS` lags (or half the number of samples), with the PACF derived from the
+//! ACF with the Durbin-Levinson recursion. A coefficient is significant at the 5% level when it's
+//! outside of `±1.96 / sqrt(n)`, the approximate confidence band for white noise.
+//!
+//! ## Seasonal Decomposition
+//!
+//! The primary series (the first non-constant numerical feature) is split into trend, seasonal,
+//! and residual components with a classical additive decomposition: the trend is a centered
+//! moving average over one period, the seasonal component is the average detrended value at each
+//! position in the period, and the residual is what's left. The period is the natural calendar
+//! cycle for the sampling interval (e.g. 24 for hourly or 7 for daily samples), falling back to
+//! the strongest significant autocorrelation peak. At least two full periods are required.
+//!
+//! The strength of the trend and of the seasonality are scored as `1 - Var(R) / Var(T + R)` and
+//! `1 - Var(R) / Var(S + R)` (clamped to 0), so a score close to 1 means the component dominates
+//! the residual noise.
+//!
+//! The analysis is compiled with the `time-series` feature. The timestamp and duration helpers
+//! below are always compiled, since the freshness, session, and temporal analyses share them.
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime};
+use indexmap::IndexMap;
+use polars::prelude::*;
+use thiserror::Error;
+
+/// Multiple of the sampling interval above which a difference between timestamps is a gap.
+pub const GAP_TOLERANCE: f64 = 1.5;
+/// Relative deviation from the sampling interval above which a difference is irregular.
+pub const IRREGULARITY_TOLERANCE: f64 = 0.1;
+/// The default number of samples in a rolling window.
+pub const DEFAULT_ROLLING_WINDOW: usize = 30;
+/// Maximum number of rolling windows kept per feature for plotting.
+pub const MAX_ROLLING_POINTS: usize = 1000;
+/// Maximum number of lags for the autocorrelation.
+pub const MAX_LAGS: usize = 40;
+/// The standard normal quantile for the 95% autocorrelation confidence band.
+#[cfg(feature = "time-series")]
+const CONFIDENCE_Z: f64 = 1.96;
+/// The number of milliseconds in a day.
+const MS_PER_DAY: i64 = 86_400_000;
+/// The number of milliseconds in a week.
+#[cfg(feature = "time-series")]
+const MS_PER_WEEK: i64 = 7 * MS_PER_DAY;
+/// The datetime formats tried, in order, when parsing a string index.
+pub(crate) const DATETIME_FORMATS: [&str; 4] = [
+    "%Y-%m-%d %H:%M:%S%.f",
+    "%Y-%m-%dT%H:%M:%S%.f",
+    "%Y-%m-%d %H:%M",
+    "%Y-%m-%dT%H:%M",
+];
+
+/// The error types for the time series module.
+#[derive(Error, Debug)]
+pub enum TimeSeriesError {
+    /// Occurs when Polars fails to complete an operation.
+    #[error("Polars error: {0}")]
+    Polars(#[from] PolarsError),
+
+    /// Occurs when the index column isn't in the dataset.
+    #[error("Time index column not found in the dataset: {0}")]
+    UnknownColumn(String),
+
+    /// Occurs when the index column can't hold timestamps.
+    #[error("Time index column '{0}' has unsupported data type: {1}")]
+    UnsupportedIndex(String, DataType),
+
+    /// Occurs when the rolling window is too small to compute a standard deviation.
+    #[error("Rolling window must have at least 2 samples, got {0}")]
+    InvalidWindow(usize),
+}
+
+/// A gap in the time index.
+#[derive(Debug, Clone)]
+pub struct TimeGap {
+    /// The last timestamp before the gap, in milliseconds since the Unix epoch.
+    pub start: i64,
+    /// The first timestamp after the gap, in milliseconds since the Unix epoch.
+    pub end: i64,
+    /// The estimated number of missing samples in the gap.
+    pub missing_periods: u64,
+}
+
+impl TimeGap {
+    /// Gets the duration of the gap in milliseconds.
+    pub fn duration(&self) -> i64 {
+        self.end - self.start
+    }
+}
+
+/// The rolling statistics of a numerical feature over the time index.
+#[derive(Debug, Clone)]
+pub struct RollingStatistics {
+    /// The timestamp at the end of each kept window, in milliseconds since the Unix epoch.
+    pub timestamps: Vec<i64>,
+    /// The rolling mean of each kept window.
+    pub mean: Vec<f64>,
+    /// The rolling standard deviation of each kept window.
+    pub std_dev: Vec<f64>,
+    /// The change between the first and last rolling means, in standard deviations of the feature.
+    pub mean_drift: f64,
+    /// The largest rolling standard deviation divided by the smallest.
+    pub std_ratio: f64,
+}
+
+/// The autocorrelation of a numerical feature over the time index.
+#[derive(Debug, Clone)]
+pub struct Autocorrelation {
+    /// The autocorrelation at lags 1 to `acf.len()`.
+    pub acf: Vec<f64>,
+    /// The partial autocorrelation at lags 1 to `pacf.len()`.
+    pub pacf: Vec<f64>,
+    /// The half width of the 95% confidence band around 0.
+    pub confidence: f64,
+}
+
+impl Autocorrelation {
+    /// Gets the lags (starting at 1) where the autocorrelation is outside the confidence band.
+    pub fn significant_acf_lags(&self) -> Vec<usize> {
+        significant_lags(&self.acf, self.confidence)
+    }
+
+    /// Gets the lags (starting at 1) where the partial autocorrelation is outside the confidence
+    /// band.
+    pub fn significant_pacf_lags(&self) -> Vec<usize> {
+        significant_lags(&self.pacf, self.confidence)
+    }
+}
+
+/// The seasonal decomposition of the primary series.
+#[derive(Debug, Clone)]
+pub struct SeasonalDecomposition {
+    /// The decomposed feature.
+    pub feature: String,
+    /// The number of samples in a seasonal period.
+    pub period: usize,
+    /// The timestamp of each sample, in milliseconds since the Unix epoch.
+    pub timestamps: Vec<i64>,
+    /// The observed values.
+    pub observed: Vec<f64>,
+    /// The trend component, missing for the half period at each end of the series.
+    pub trend: Vec<Option<f64>>,
+    /// The seasonal component.
+    pub seasonal: Vec<f64>,
+    /// The residual component, missing where the trend is.
+    pub residual: Vec<Option<f64>>,
+    /// The strength of the trend, from 0 to 1.
+    pub trend_strength: f64,
+    /// The strength of the seasonality, from 0 to 1.
+    pub seasonal_strength: f64,
+    /// The fraction of the variance of the observed values left in the residual.
+    pub residual_share: f64,
+}
+
+/// Struct to hold the time series analysis results.
+#[derive(Debug)]
+pub struct TimeSeriesAnalysis {
+    /// The datetime index column name.
+    pub index: String,
+    /// The number of rows with a valid timestamp.
+    pub n_timestamps: usize,
+    /// The earliest timestamp, in milliseconds since the Unix epoch.
+    pub first: Option<i64>,
+    /// The latest timestamp, in milliseconds since the Unix epoch.
+    pub last: Option<i64>,
+    /// The inferred sampling interval in milliseconds, or None if there are fewer than two
+    /// distinct timestamps.
+    pub interval: Option<i64>,
+    /// The gaps in the index, in chronological order.
+    pub gaps: Vec<TimeGap>,
+    /// The number of differences between consecutive timestamps that are irregular but not gaps.
+    pub irregular_intervals: usize,
+    /// The timestamps that appear in more than one row with their row counts, in chronological
+    /// order.
+    pub duplicate_timestamps: Vec<(i64, u64)>,
+    /// The number of samples in a rolling window.
+    pub window: usize,
+    /// The rolling statistics keyed by numerical feature name. Features with fewer non-missing
+    /// values than the window are skipped.
+    pub rolling: IndexMap<String, RollingStatistics>,
+    /// The autocorrelation keyed by numerical feature name. Constant features and features with
+    /// fewer than 3 non-missing values are skipped.
+    pub autocorrelation: IndexMap<String, Autocorrelation>,
+    /// The seasonal decomposition of the primary series, or None if there's no primary series or
+    /// no period could be inferred.
+    pub decomposition: Option<SeasonalDecomposition>,
+}
+
+#[cfg(feature = "time-series")]
+impl TimeSeriesAnalysis {
+    /// Constructor for the TimeSeriesAnalysis struct.
+    ///
+    /// ### Parameters
+    ///
+    /// - `lazy_df`: Reference to the LazyFrame.
+    /// - `schema`: Reference to the lazy frame's schema.
+    /// - `index`: The datetime index column name.
+    /// - `window`: The number of samples in a rolling window.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<Self, TimeSeriesError>`: A new TimeSeriesAnalysis instance or an error.
+    pub fn new(
+        lazy_df: &LazyFrame,
+        schema: &Schema,
+        index: &str,
+        window: usize,
+    ) -> Result<Self, TimeSeriesError> {
+        let dtype = schema
+            .get(index)
+            .ok_or_else(|| TimeSeriesError::UnknownColumn(index.to_owned()))?;
+        if window < 2 {
+            return Err(TimeSeriesError::InvalidWindow(window));
+        }
+
+        let numeric_columns: Vec<&str> = schema
+            .iter()
+            .filter(|(name, dtype)| {
+                name.as_str() != index && (dtype.is_numeric() || dtype.is_decimal())
+            })
+            .map(|(name, _)| name.as_str())
+            .collect();
+        let df = lazy_df
+            .clone()
+            .select(
+                std::iter::once(col(index))
+                    .chain(
+                        numeric_columns
+                            .iter()
+                            .map(|name| col(name).cast(DataType::Float64)),
+                    )
+                    .collect::<Vec<Expr>>(),
+            )
+            .collect()?;
+
+        // Order the rows with a valid timestamp by the index, keeping ties in row order.
+        let mut rows: Vec<(i64, usize)> = timestamps(df.column(index)?, dtype)?
+            .into_iter()
+            .enumerate()
+            .filter_map(|(row, timestamp)| Some((timestamp?, row)))
+            .collect();
+        rows.sort_by_key(|(timestamp, _)| *timestamp);
+        let timestamps: Vec<i64> = rows.iter().map(|(timestamp, _)| *timestamp).collect();
+
+        let mut rolling = IndexMap::new();
+        let mut autocorrelation = IndexMap::new();
+        let mut primary_series: Option<(&str, Vec<(i64, f64)>)> = None;
+        for name in numeric_columns {
+            let values = df.column(name)?.f64()?;
+            let points: Vec<(i64, f64)> = rows
+                .iter()
+                .filter_map(|(timestamp, row)| Some((*timestamp, values.get(*row)?)))
+                .filter(|(_, value)| value.is_finite())
+                .collect();
+            if let Some(statistics) = rolling_statistics(&points, window) {
+                rolling.insert(name.to_owned(), statistics);
+            }
+            let values: Vec<f64> = points.iter().map(|(_, value)| *value).collect();
+            if let Some(correlations) = autocorrelation_function(&values) {
+                autocorrelation.insert(name.to_owned(), correlations);
+                if primary_series.is_none() {
+                    primary_series = Some((name, points));
+                }
+            }
+        }
+
+        // Collapse the sorted timestamps into distinct timestamps and their row counts.
+        let mut distinct: Vec<(i64, u64)> = Vec::new();
+        for timestamp in &timestamps {
+            match distinct.last_mut() {
+                Some((last, count)) if last == timestamp => *count += 1,
+                _ => distinct.push((*timestamp, 1)),
+            }
+        }
+        let differences: Vec<i64> = distinct
+            .windows(2)
+            .map(|window| window[1].0 - window[0].0)
+            .collect();
+
+        let interval = median(&differences);
+        let mut gaps = Vec::new();
+        let mut irregular_intervals = 0;
+        if let Some(interval) = interval.filter(|interval| *interval > 0) {
+            let interval_f64 = interval as f64;
+            for (window, difference) in distinct.windows(2).zip(&differences) {
+                let ratio = *difference as f64 / interval_f64;
+                if ratio > GAP_TOLERANCE {
+                    gaps.push(TimeGap {
+                        start: window[0].0,
+                        end: window[1].0,
+                        missing_periods: (ratio.round() as u64).saturating_sub(1).max(1),
+                    });
+                } else if (ratio - 1.0).abs() > IRREGULARITY_TOLERANCE {
+                    irregular_intervals += 1;
+                }
+            }
+        }
+
+        let decomposition = primary_series.and_then(|(name, points)| {
+            let period = seasonal_period(interval, autocorrelation.get(name))?;
+            seasonal_decomposition(name, &points, period)
+        });
+
+        Ok(Self {
+            index: index.to_owned(),
+            n_timestamps: timestamps.len(),
+            first: timestamps.first().copied(),
+            last: timestamps.last().copied(),
+            interval,
+            gaps,
+            irregular_intervals,
+            duplicate_timestamps: distinct
+                .into_iter()
+                .filter(|(_, count)| *count > 1)
+                .collect(),
+            window,
+            rolling,
+            autocorrelation,
+            decomposition,
+        })
+    }
+}
+
+impl TimeSeriesAnalysis {
+    /// Gets the total number of missing samples across all the gaps.
+    pub fn missing_periods(&self) -> u64 {
+        self.gaps.iter().map(|gap| gap.missing_periods).sum()
+    }
+
+    /// Gets the number of rows sharing a timestamp with an earlier row.
+    pub fn duplicate_rows(&self) -> u64 {
+        self.duplicate_timestamps
+            .iter()
+            .map(|(_, count)| count - 1)
+            .sum()
+    }
+}
+
+/// Computes the rolling mean and standard deviation of the time ordered values, or None if there
+/// are fewer values than the window or the values are constant.
+#[cfg(feature = "time-series")]
+fn rolling_statistics(points: &[(i64, f64)], window: usize) -> Option<RollingStatistics> {
+    if points.len() < window {
+        return None;
+    }
+    let n = points.len() as f64;
+    let overall_mean = points.iter().map(|(_, value)| value).sum::<f64>() / n;
+    let overall_std = (points
+        .iter()
+        .map(|(_, value)| (value - overall_mean).powi(2))
+        .sum::<f64>()
+        / (n - 1.0))
+        .sqrt();
+    if !overall_std.is_finite() || overall_std <= f64::EPSILON * overall_mean.abs().max(1.0) {
+        return None;
+    }
+
+    // Slide the window sums over the centered values to limit the cancellation error.
+    let centered: Vec<f64> = points
+        .iter()
+        .map(|(_, value)| value - overall_mean)
+        .collect();
+    let window_f64 = window as f64;
+    let n_windows = points.len() - window + 1;
+    let stride = n_windows.div_ceil(MAX_ROLLING_POINTS);
+
+    let (mut sum, mut sum_squares) = centered[..window]
+        .iter()
+        .fold((0.0, 0.0), |(sum, squares), value| {
+            (sum + value, squares + value * value)
+        });
+    let mut statistics = RollingStatistics {
+        timestamps: Vec::with_capacity(n_windows / stride + 1),
+        mean: Vec::with_capacity(n_windows / stride + 1),
+        std_dev: Vec::with_capacity(n_windows / stride + 1),
+        mean_drift: 0.0,
+        std_ratio: 1.0,
+    };
+    let (mut first_mean, mut last_mean) = (0.0, 0.0);
+    let (mut min_std, mut max_std) = (f64::INFINITY, 0.0_f64);
+    for start in 0..n_windows {
+        if start > 0 {
+            let (removed, added) = (centered[start - 1], centered[start + window - 1]);
+            sum += added - removed;
+            sum_squares += added * added - removed * removed;
+        }
+        let mean = sum / window_f64;
+        let std_dev = ((sum_squares - sum * mean) / (window_f64 - 1.0))
+            .max(0.0)
+            .sqrt();
+        if start == 0 {
+            first_mean = mean;
+        }
+        last_mean = mean;
+        min_std = min_std.min(std_dev);
+        max_std = max_std.max(std_dev);
+
+        if start % stride == 0 || start == n_windows - 1 {
+            statistics.timestamps.push(points[start + window - 1].0);
+            statistics.mean.push(mean + overall_mean);
+            statistics.std_dev.push(std_dev);
+        }
+    }
+
+    statistics.mean_drift = (last_mean - first_mean) / overall_std;
+    statistics.std_ratio = if min_std > 0.0 {
+        max_std / min_std
+    } else {
+        f64::INFINITY
+    };
+    Some(statistics)
+}
+
+/// Computes the autocorrelation and partial autocorrelation of the time ordered values, or None if
+/// there are fewer than 3 values or the values are constant.
+#[cfg(feature = "time-series")]
+fn autocorrelation_function(values: &[f64]) -> Option<Autocorrelation> {
+    let n = values.len();
+    let max_lag = MAX_LAGS.min(n / 2);
+    if max_lag == 0 || n < 3 {
+        return None;
+    }
+    let mean = values.iter().sum::<f64>() / n as f64;
+    let centered: Vec<f64> = values.iter().map(|value| value - mean).collect();
+    let variance: f64 = centered.iter().map(|value| value * value).sum();
+    if variance <= f64::EPSILON * mean.abs().max(1.0) {
+        return None;
+    }
+
+    let acf: Vec<f64> = (1..=max_lag)
+        .map(|lag| {
+            centered
+                .iter()
+                .zip(&centered[lag..])
+                .map(|(x, y)| x * y)
+                .sum::<f64>()
+                / variance
+        })
+        .collect();
+
+    // Durbin-Levinson recursion, where phi holds the coefficients of the AR(k) fit.
+    let mut pacf = Vec::with_capacity(max_lag);
+    let mut phi: Vec<f64> = Vec::with_capacity(max_lag);
+    for k in 0..max_lag {
+        let numerator = acf[k]
+            - phi
+                .iter()
+                .enumerate()
+                .map(|(j, coefficient)| coefficient * acf[k - 1 - j])
+                .sum::<f64>();
+        let denominator = 1.0
+            - phi
+                .iter()
+                .enumerate()
+                .map(|(j, coefficient)| coefficient * acf[j])
+                .sum::<f64>();
+        let reflection = if denominator.abs() > f64::EPSILON {
+            numerator / denominator
+        } else {
+            0.0
+        };
+        let previous = phi.clone();
+        for (j, coefficient) in phi.iter_mut().enumerate() {
+            *coefficient = previous[j] - reflection * previous[k - 1 - j];
+        }
+        phi.push(reflection);
+        pacf.push(reflection);
+    }
+
+    Some(Autocorrelation {
+        acf,
+        pacf,
+        confidence: CONFIDENCE_Z / (n as f64).sqrt(),
+    })
+}
+
+/// Infers the seasonal period in samples from the natural calendar cycle of the sampling interval,
+/// falling back to the lag of the highest significant autocorrelation peak.
+#[cfg(feature = "time-series")]
+fn seasonal_period(
+    interval: Option<i64>,
+    autocorrelation: Option<&Autocorrelation>,
+) -> Option<usize> {
+    let calendar_period = match interval? {
+        interval if interval <= 0 => None,
+        interval if interval < MS_PER_DAY && MS_PER_DAY % interval == 0 => {
+            Some((MS_PER_DAY / interval) as usize)
+        }
+        MS_PER_DAY => Some(7),
+        MS_PER_WEEK => Some(52),
+        interval if (28 * MS_PER_DAY..=31 * MS_PER_DAY).contains(&interval) => Some(12),
+        interval if (89 * MS_PER_DAY..=92 * MS_PER_DAY).contains(&interval) => Some(4),
+        _ => None,
+    };
+    calendar_period.or_else(|| {
+        let autocorrelation = autocorrelation?;
+        let acf = &autocorrelation.acf;
+        (1..acf.len())
+            .filter(|lag| {
+                acf[*lag] > autocorrelation.confidence
+                    && acf[*lag] > acf[lag - 1]
+                    && acf.get(lag + 1).is_none_or(|next| acf[*lag] >= *next)
+            })
+            .max_by(|a, b| acf[*a].total_cmp(&acf[*b]))
+            .map(|lag| lag + 1)
+    })
+}
+
+/// Decomposes the time ordered values into trend, seasonal, and residual components, or None if
+/// the series is shorter than two periods.
+#[cfg(feature = "time-series")]
+fn seasonal_decomposition(
+    feature: &str,
+    points: &[(i64, f64)],
+    period: usize,
+) -> Option<SeasonalDecomposition> {
+    let n = points.len();
+    if period < 2 || n < 2 * period {
+        return None;
+    }
+    let observed: Vec<f64> = points.iter().map(|(_, value)| *value).collect();
+
+    // Centered moving average, with half weights at the ends for an even period (2 x period MA).
+    let half = period / 2;
+    let mut prefix = Vec::with_capacity(n + 1);
+    prefix.push(0.0);
+    for value in &observed {
+        prefix.push(prefix.last().unwrap_or(&0.0) + value);
+    }
+    let trend: Vec<Option<f64>> = (0..n)
+        .map(|i| {
+            if i < half || i + half >= n {
+                return None;
+            }
+            let sum = prefix[i + half + 1] - prefix[i - half];
+            Some(if period.is_multiple_of(2) {
+                (sum - 0.5 * (observed[i - half] + observed[i + half])) / period as f64
+            } else {
+                sum / period as f64
+            })
+        })
+        .collect();
+
+    // Average the detrended values at each position in the period, centered to sum to 0.
+    let mut phase_sums = vec![0.0; period];
+    let mut phase_counts = vec![0usize; period];
+    for (i, (value, trend)) in observed.iter().zip(&trend).enumerate() {
+        if let Some(trend) = trend {
+            phase_sums[i % period] += value - trend;
+            phase_counts[i % period] += 1;
+        }
+    }
+    let phase_means: Vec<f64> = phase_sums
+        .iter()
+        .zip(&phase_counts)
+        .map(|(sum, count)| if *count > 0 { sum / *count as f64 } else { 0.0 })
+        .collect();
+    let phase_offset = phase_means.iter().sum::<f64>() / period as f64;
+    let seasonal: Vec<f64> = (0..n)
+        .map(|i| phase_means[i % period] - phase_offset)
+        .collect();
+
+    let residual: Vec<Option<f64>> = (0..n)
+        .map(|i| trend[i].map(|trend| observed[i] - trend - seasonal[i]))
+        .collect();
+
+    // Score the components over the samples with a trend.
+    let mut residuals = Vec::with_capacity(n);
+    let mut trend_and_residuals = Vec::with_capacity(n);
+    let mut seasonal_and_residuals = Vec::with_capacity(n);
+    let mut observed_with_trend = Vec::with_capacity(n);
+    for i in 0..n {
+        if let (Some(trend), Some(residual)) = (trend[i], residual[i]) {
+            residuals.push(residual);
+            trend_and_residuals.push(trend + residual);
+            seasonal_and_residuals.push(seasonal[i] + residual);
+            observed_with_trend.push(observed[i]);
+        }
+    }
+    let residual_variance = variance(&residuals);
+    let strength = |combined: &[f64]| {
+        let combined_variance = variance(combined);
+        if combined_variance > 0.0 {
+            (1.0 - residual_variance / combined_variance).max(0.0)
+        } else {
+            0.0
+        }
+    };
+    let trend_strength = strength(&trend_and_residuals);
+    let seasonal_strength = strength(&seasonal_and_residuals);
+    let observed_variance = variance(&observed_with_trend);
+    let residual_share = if observed_variance > 0.0 {
+        (residual_variance / observed_variance).min(1.0)
+    } else {
+        0.0
+    };
+
+    Some(SeasonalDecomposition {
+        feature: feature.to_owned(),
+        period,
+        timestamps: points.iter().map(|(timestamp, _)| *timestamp).collect(),
+        observed,
+        trend,
+        seasonal,
+        residual,
+        trend_strength,
+        seasonal_strength,
+        residual_share,
+    })
+}
+
+/// Computes the population variance of the values, or 0 if there are no values.
+#[cfg(feature = "time-series")]
+fn variance(values: &[f64]) -> f64 {
+    if values.is_empty() {
+        return 0.0;
+    }
+    let n = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / n;
+    values
+        .iter()
+        .map(|value| (value - mean).powi(2))
+        .sum::<f64>()
+        / n
+}
+
+/// Gets the lags (starting at 1) of the coefficients outside the confidence band.
+fn significant_lags(coefficients: &[f64], confidence: f64) -> Vec<usize> {
+    coefficients
+        .iter()
+        .enumerate()
+        .filter(|(_, coefficient)| coefficient.abs() > confidence)
+        .map(|(lag, _)| lag + 1)
+        .collect()
+}
+
+/// Converts the index values to milliseconds since the Unix epoch.
+pub(crate) fn timestamps(
+    series: &Series,
+    dtype: &DataType,
+) -> Result<Vec<Option<i64>>, TimeSeriesError> {
+    match dtype {
+        DataType::Date => Ok(series
+            .to_physical_repr()
+            .cast(&DataType::Int64)?
+            .i64()?
+            .into_iter()
+            .map(|days| days.map(|days| days * MS_PER_DAY))
+            .collect()),
+        DataType::Datetime(time_unit, _) => {
+            let divisor = match time_unit {
+                TimeUnit::Nanoseconds => 1_000_000,
+                TimeUnit::Microseconds => 1_000,
+                TimeUnit::Milliseconds => 1,
+            };
+            Ok(series
+                .to_physical_repr()
+                .cast(&DataType::Int64)?
+                .i64()?
+                .into_iter()
+                .map(|value| value.map(|value| value.div_euclid(divisor)))
+                .collect())
+        }
+        DataType::String => Ok(series
+            .str()?
+            .into_iter()
+            .map(|value| value.and_then(parse_timestamp))
+            .collect()),
+        _ => Err(TimeSeriesError::UnsupportedIndex(
+            series.name().to_owned(),
+            dtype.clone(),
+        )),
+    }
+}
+
+/// Parses a string timestamp into milliseconds since the Unix epoch.
+fn parse_timestamp(value: &str) -> Option<i64> {
+    let value = value.trim();
+    if let Ok(datetime) = DateTime::parse_from_rfc3339(value) {
+        return Some(datetime.timestamp_millis());
+    }
+    DATETIME_FORMATS
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(value, format).ok())
+        .or_else(|| {
+            NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .ok()
+                .and_then(|date| date.and_hms_opt(0, 0, 0))
+        })
+        .map(|datetime| datetime.and_utc().timestamp_millis())
+}
+
+/// Computes the median of the values, or None if there are no values.
+pub(crate) fn median(values: &[i64]) -> Option<i64> {
+    let mut sorted = values.to_vec();
+    sorted.sort_unstable();
+    let middle = sorted.len() / 2;
+    match sorted.len() {
+        0 => None,
+        n if n % 2 == 0 => Some((sorted[middle - 1] + sorted[middle]) / 2),
+        _ => Some(sorted[middle]),
+    }
+}
+
+/// Formats a timestamp in milliseconds since the Unix epoch, dropping the time of day at midnight.
+pub fn format_timestamp(timestamp: i64) -> String {
+    match DateTime::from_timestamp_millis(timestamp) {
+        Some(datetime) if timestamp.rem_euclid(MS_PER_DAY) == 0 => {
+            datetime.format("%Y-%m-%d").to_string()
+        }
+        Some(datetime) => datetime.format("%Y-%m-%d %H:%M:%S").to_string(),
+        None => timestamp.to_string(),
+    }
+}
+
+/// The units of the formatted and parsed durations, with their length in milliseconds.
+const DURATION_UNITS: [(&str, i64); 5] = [
+    ("d", MS_PER_DAY),
+    ("h", 3_600_000),
+    ("m", 60_000),
+    ("s", 1_000),
+    ("ms", 1),
+];
+
+/// Parses a duration made of amounts with a unit (`d`, `h`, `m`, `s`, or `ms`), e.g. `24h`,
+/// `1d 6h`, or `90m`, into milliseconds.
+///
+/// ### Parameters
+///
+/// - `value`: The duration to parse.
+///
+/// ### Returns
+///
+/// - `Option<i64>`: The duration in milliseconds, or None if the duration is malformed or
+///   overflows.
+pub fn parse_duration(value: &str) -> Option<i64> {
+    let mut duration: i64 = 0;
+    let mut rest = value.trim();
+    if rest.is_empty() {
+        return None;
+    }
+    while !rest.is_empty() {
+        let digits = rest.find(|c: char| !c.is_ascii_digit())?;
+        let amount: i64 = rest[..digits].parse().ok()?;
+        rest = &rest[digits..];
+        let letters = rest
+            .find(|c: char| !c.is_ascii_alphabetic())
+            .unwrap_or(rest.len());
+        let (_, unit) = DURATION_UNITS
+            .iter()
+            .find(|(unit, _)| *unit == &rest[..letters])?;
+        duration = duration.checked_add(amount.checked_mul(*unit)?)?;
+        rest = rest[letters..].trim_start();
+    }
+    Some(duration)
+}
+
+/// Formats a duration in milliseconds with its two largest units (e.g. `1d 6h` or `15m`).
+pub fn format_duration(duration: i64) -> String {
+    if duration == 0 {
+        return "0s".to_owned();
+    }
+
+    let mut remainder = duration.abs();
+    let parts: Vec<String> = DURATION_UNITS
+        .iter()
+        .filter_map(|(unit, size)| {
+            let amount = remainder / size;
+            remainder %= size;
+            (amount > 0).then(|| format!("{}{}", amount, unit))
+        })
+        .take(2)
+        .collect();
+    let sign = if duration < 0 { "-" } else { "" };
+    format!("{}{}", sign, parts.join(" "))
+}
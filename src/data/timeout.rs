@@ -0,0 +1,100 @@
+//! # Timeout Module
+//!
+//! This module handles the time budgets of the expensive analyses (outliers, principal component
+//! analysis, and text profiling), so pathological data degrades them to "skipped (timeout)"
+//! instead of stalling the whole run.
+//!
+//! A budgeted analysis runs on its own thread while the caller waits up to the budget. Polars
+//! queries can't be cancelled, so an analysis over its budget is abandoned rather than stopped: it
+//! keeps running in the background until it finishes or the process exits, and its result is
+//! dropped.
+
+use crate::data::time_series::{format_duration, parse_duration};
+use std::fmt;
+use std::panic;
+use std::str::FromStr;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::Duration;
+
+/// The maximum time an expensive analysis can run for before it's skipped, in milliseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AnalysisTimeout(pub i64);
+
+impl FromStr for AnalysisTimeout {
+    type Err = String;
+
+    /// Parses a duration made of amounts with a unit (`d`, `h`, `m`, `s`, or `ms`), e.g. `30s` or
+    /// `2m 30s`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match parse_duration(s) {
+            Some(duration) if duration > 0 => Ok(AnalysisTimeout(duration)),
+            _ => Err(format!(
+                "Invalid analysis timeout: {}. Expected a duration like 30s, 2m, or 500ms.",
+                s
+            )),
+        }
+    }
+}
+
+impl fmt::Display for AnalysisTimeout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", format_duration(self.0))
+    }
+}
+
+/// The analyses run within the analysis timeout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BudgetedAnalysis {
+    /// The outlier fences of the numerical features.
+    Outliers,
+    /// The principal component analysis.
+    Pca,
+    /// The text profiling of the string columns.
+    TextProfiling,
+}
+
+impl fmt::Display for BudgetedAnalysis {
+    /// Formats the analysis as the title of its report section.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BudgetedAnalysis::Outliers => write!(f, "Outlier Analysis"),
+            BudgetedAnalysis::Pca => write!(f, "Principal Component Analysis"),
+            BudgetedAnalysis::TextProfiling => write!(f, "Text Profiling"),
+        }
+    }
+}
+
+/// Runs an analysis within a time budget.
+///
+/// ### Parameters
+///
+/// - `timeout`: The time budget, or None to run the analysis without one.
+/// - `analysis`: The analysis to run.
+///
+/// ### Returns
+///
+/// - `Option<T>`: The result of the analysis, or None if it ran over the budget.
+pub fn run_with_timeout<T, F>(timeout: Option<AnalysisTimeout>, analysis: F) -> Option<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    let Some(timeout) = timeout else {
+        return Some(analysis());
+    };
+    let (sender, receiver) = mpsc::channel();
+    let handle = thread::spawn(move || {
+        // The receiver is gone if the analysis ran over the budget, so the result is dropped.
+        let _ = sender.send(analysis());
+    });
+    match receiver.recv_timeout(Duration::from_millis(timeout.0 as u64)) {
+        Ok(result) => Some(result),
+        Err(RecvTimeoutError::Timeout) => None,
+        // The sender is only dropped without sending if the analysis panicked.
+        Err(RecvTimeoutError::Disconnected) => match handle.join() {
+            Err(panic) => panic::resume_unwind(panic),
+            Ok(()) => None,
+        },
+    }
+}
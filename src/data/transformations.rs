@@ -0,0 +1,292 @@
+//! # Transformations Module
+//!
+//! This module handles suggesting variance stabilizing transformations for skewed numerical
+//! features, so downstream modelers get actionable preprocessing hints.
+//!
+//! ## Approach
+//!
+//! For each numerical feature with an absolute skewness above `SKEWNESS_THRESHOLD`, the candidate
+//! transformations valid for the feature's value range are applied and the one leaving the
+//! smallest absolute skewness is suggested:
+//!
+//! - Right skewed, strictly positive values: log, square root, and Box-Cox (with the lambda
+//!   estimated by maximum likelihood over a grid).
+//! - Right skewed, non-negative values: log1p and square root.
+//! - Right skewed, with negative values: log after shifting the minimum to 1.
+//! - Left skewed: square (for non-negative values) and log of the reflected values.
+//!
+//! A suggestion is only made when the transformation meaningfully reduces the skewness. The
+//! skewness and excess kurtosis are the biased sample moments.
+
+use indexmap::IndexMap;
+use polars::prelude::*;
+use thiserror::Error;
+
+/// Absolute skewness above which a feature is considered skewed.
+pub const SKEWNESS_THRESHOLD: f64 = 0.5;
+/// Absolute skewness above which a feature is considered strongly skewed.
+pub const STRONG_SKEWNESS_THRESHOLD: f64 = 1.0;
+/// Excess kurtosis above which a feature is considered heavy tailed.
+pub const HEAVY_TAIL_KURTOSIS: f64 = 3.0;
+/// Fraction of the absolute skewness a transformation has to remove to be suggested.
+pub const MIN_SKEWNESS_REDUCTION: f64 = 0.25;
+
+/// The error types for the transformations module.
+#[derive(Error, Debug)]
+pub enum TransformationError {
+    /// Occurs when Polars fails to complete an operation.
+    #[error("Polars error: {0}")]
+    Polars(#[from] PolarsError),
+}
+
+/// A transformation that can be applied to a numerical feature.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Transform {
+    /// Natural log, `ln(x)`.
+    Log,
+    /// Natural log of one plus the value, `ln(1 + x)`.
+    Log1p,
+    /// Square root, `sqrt(x)`.
+    Sqrt,
+    /// Box-Cox with the given lambda, `(x^lambda - 1) / lambda`.
+    BoxCox(f64),
+    /// Natural log after shifting the given minimum to 1, `ln(x - min + 1)`.
+    ShiftedLog(f64),
+    /// Square, `x^2`.
+    Square,
+    /// Natural log of the values reflected around the given maximum, `ln(max + 1 - x)`.
+    ReflectedLog(f64),
+}
+
+impl Transform {
+    /// Applies the transformation to a value.
+    pub fn apply(&self, x: f64) -> f64 {
+        match self {
+            Transform::Log => x.ln(),
+            Transform::Log1p => x.ln_1p(),
+            Transform::Sqrt => x.sqrt(),
+            Transform::BoxCox(lambda) => box_cox(x, *lambda),
+            Transform::ShiftedLog(min) => (x - min + 1.0).ln(),
+            Transform::Square => x * x,
+            Transform::ReflectedLog(max) => (max + 1.0 - x).ln(),
+        }
+    }
+
+    /// Gets the display name of the transformation.
+    pub fn name(&self) -> String {
+        match self {
+            Transform::Log => "Log".to_owned(),
+            Transform::Log1p => "Log1p".to_owned(),
+            Transform::Sqrt => "Square root".to_owned(),
+            Transform::BoxCox(lambda) => format!("Box-Cox (lambda = {:.2})", lambda),
+            Transform::ShiftedLog(_) => "Shifted log".to_owned(),
+            Transform::Square => "Square".to_owned(),
+            Transform::ReflectedLog(_) => "Reflected log".to_owned(),
+        }
+    }
+
+    /// Gets the formula of the transformation, written in terms of `x`.
+    pub fn formula(&self) -> String {
+        match self {
+            Transform::Log => "ln(x)".to_owned(),
+            Transform::Log1p => "ln(1 + x)".to_owned(),
+            Transform::Sqrt => "sqrt(x)".to_owned(),
+            Transform::BoxCox(lambda) if lambda.abs() < f64::EPSILON => "ln(x)".to_owned(),
+            Transform::BoxCox(lambda) => format!("(x^{:.2} - 1) / {:.2}", lambda, lambda),
+            Transform::ShiftedLog(min) => format!("ln(x - {} + 1)", min),
+            Transform::Square => "x^2".to_owned(),
+            Transform::ReflectedLog(max) => format!("ln({} + 1 - x)", max),
+        }
+    }
+}
+
+/// A transformation suggestion for a single feature.
+#[derive(Debug, Clone)]
+pub struct TransformationSuggestion {
+    /// The suggested transformation.
+    pub transform: Transform,
+    /// The skewness of the feature.
+    pub skewness: f64,
+    /// The skewness after the transformation.
+    pub transformed_skewness: f64,
+    /// The excess kurtosis of the feature.
+    pub kurtosis: f64,
+    /// The excess kurtosis after the transformation.
+    pub transformed_kurtosis: f64,
+    /// Human readable reason for the suggestion.
+    pub reason: String,
+}
+
+/// Struct to hold the transformation suggestions for a dataset.
+#[derive(Debug, Default)]
+pub struct TransformationAnalysis {
+    /// The suggestions, keyed by feature name. Features without a suggestion are omitted.
+    pub suggestions: IndexMap<String, TransformationSuggestion>,
+}
+
+impl TransformationAnalysis {
+    /// Constructor for the TransformationAnalysis struct.
+    ///
+    /// ### Parameters
+    ///
+    /// - `lazy_df`: Reference to the LazyFrame.
+    /// - `schema`: Reference to the lazy frame's schema.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<Self, TransformationError>`: A new TransformationAnalysis instance or an error.
+    pub fn new(lazy_df: &LazyFrame, schema: &Schema) -> Result<Self, TransformationError> {
+        let numeric_columns: Vec<&str> = schema
+            .iter()
+            .filter(|(_, dtype)| dtype.is_numeric() || dtype.is_decimal())
+            .map(|(name, _)| name.as_str())
+            .collect();
+        if numeric_columns.is_empty() {
+            return Ok(Self::default());
+        }
+
+        let df = lazy_df
+            .clone()
+            .select(
+                numeric_columns
+                    .iter()
+                    .map(|name| col(name).cast(DataType::Float64))
+                    .collect::<Vec<Expr>>(),
+            )
+            .collect()?;
+
+        let mut suggestions = IndexMap::new();
+        for name in numeric_columns {
+            let values: Vec<f64> = df
+                .column(name)?
+                .f64()?
+                .into_iter()
+                .flatten()
+                .filter(|value| value.is_finite())
+                .collect();
+            if let Some(suggestion) = suggest_transformation(&values) {
+                suggestions.insert(name.to_owned(), suggestion);
+            }
+        }
+
+        Ok(Self { suggestions })
+    }
+}
+
+/// Suggests a transformation for a feature's values.
+///
+/// ### Parameters
+///
+/// - `values`: The non-null, finite values of the feature.
+///
+/// ### Returns
+///
+/// - `Option<TransformationSuggestion>`: The suggestion, or None if the feature isn't skewed or no
+///   candidate meaningfully reduces the skewness.
+pub fn suggest_transformation(values: &[f64]) -> Option<TransformationSuggestion> {
+    let (skewness, kurtosis) = moments(values)?;
+    if skewness.abs() <= SKEWNESS_THRESHOLD {
+        return None;
+    }
+
+    let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let candidates = if skewness > 0.0 {
+        if min > 0.0 {
+            vec![
+                Transform::Log,
+                Transform::Sqrt,
+                Transform::BoxCox(box_cox_lambda(values)),
+            ]
+        } else if min == 0.0 {
+            vec![Transform::Log1p, Transform::Sqrt]
+        } else {
+            vec![Transform::ShiftedLog(min)]
+        }
+    } else if min >= 0.0 {
+        vec![Transform::Square, Transform::ReflectedLog(max)]
+    } else {
+        vec![Transform::ReflectedLog(max)]
+    };
+
+    let (transform, transformed_skewness, transformed_kurtosis) = candidates
+        .into_iter()
+        .filter_map(|transform| {
+            let transformed: Vec<f64> = values.iter().map(|x| transform.apply(*x)).collect();
+            moments(&transformed).map(|(skewness, kurtosis)| (transform, skewness, kurtosis))
+        })
+        .min_by(|a, b| a.1.abs().total_cmp(&b.1.abs()))?;
+
+    if transformed_skewness.abs() > (1.0 - MIN_SKEWNESS_REDUCTION) * skewness.abs() {
+        return None;
+    }
+
+    let strength = if skewness.abs() > STRONG_SKEWNESS_THRESHOLD {
+        "Strong"
+    } else {
+        "Moderate"
+    };
+    let direction = if skewness > 0.0 { "right" } else { "left" };
+    let mut reason = format!(
+        "{} {} skew (skewness {:.2}).",
+        strength, direction, skewness
+    );
+    if kurtosis > HEAVY_TAIL_KURTOSIS {
+        reason.push_str(&format!(" Heavy tails (excess kurtosis {:.2}).", kurtosis));
+    }
+
+    Some(TransformationSuggestion {
+        transform,
+        skewness,
+        transformed_skewness,
+        kurtosis,
+        transformed_kurtosis,
+        reason,
+    })
+}
+
+/// Computes the biased sample skewness and excess kurtosis, or None if there are fewer than three
+/// values or no variance.
+pub(crate) fn moments(values: &[f64]) -> Option<(f64, f64)> {
+    if values.len() < 3 || values.iter().any(|value| !value.is_finite()) {
+        return None;
+    }
+    let n = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / n;
+    let (m2, m3, m4) = values.iter().fold((0.0, 0.0, 0.0), |(m2, m3, m4), value| {
+        let d = value - mean;
+        (m2 + d * d, m3 + d * d * d, m4 + d * d * d * d)
+    });
+    let (m2, m3, m4) = (m2 / n, m3 / n, m4 / n);
+    if m2 <= f64::EPSILON * mean.abs().max(1.0) {
+        return None;
+    }
+    Some((m3 / m2.powf(1.5), m4 / (m2 * m2) - 3.0))
+}
+
+fn box_cox(x: f64, lambda: f64) -> f64 {
+    if lambda.abs() < f64::EPSILON {
+        x.ln()
+    } else {
+        (x.powf(lambda) - 1.0) / lambda
+    }
+}
+
+/// Estimates the Box-Cox lambda by maximizing the log-likelihood over a grid from -2 to 2.
+fn box_cox_lambda(values: &[f64]) -> f64 {
+    let n = values.len() as f64;
+    let log_sum: f64 = values.iter().map(|x| x.ln()).sum();
+    let log_likelihood = |lambda: f64| {
+        let transformed: Vec<f64> = values.iter().map(|x| box_cox(*x, lambda)).collect();
+        let mean = transformed.iter().sum::<f64>() / n;
+        let variance = transformed.iter().map(|y| (y - mean).powi(2)).sum::<f64>() / n;
+        (lambda - 1.0) * log_sum - n / 2.0 * variance.ln()
+    };
+
+    (-40..=40)
+        .map(|step| step as f64 * 0.05)
+        .map(|lambda| (lambda, log_likelihood(lambda)))
+        .filter(|(_, likelihood)| likelihood.is_finite())
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+        .map_or(0.0, |(lambda, _)| lambda)
+}
@@ -3,23 +3,157 @@
 //! This module serves as the entry point for generating visualizations using the
 //! [plotters](https://docs.rs/plotters/0.3.7/plotters/) crate. It manages the creation
 //! and organization of various plot types.
+//!
+//! The plots are saved to the plot directory as standalone PNG files at the exported resolution
+//! and embedded in the report at the embedded resolution. When the two differ, the plots are
+//! rendered at the higher resolution and downsampled to the lower one, with the embedded copies
+//! saved to the `EMBEDDED_PLOT_DIR` subdirectory.
 
-use super::viz_lib::missing_value_viz;
-use crate::data::missing_values::MissingValueAnalysis;
+#[cfg(feature = "pca")]
+use super::viz_lib::pca_viz;
+#[cfg(feature = "time-series")]
+use super::viz_lib::time_series_viz;
+use super::viz_lib::{
+    association_viz, category_viz, correlation_viz, distribution_viz, missing_value_viz,
+    outlier_viz, session_viz, target_viz, transformation_viz, PlotTheme, BASE_DPI,
+};
+use crate::{
+    data::{
+        associations::AssociationAnalysis, categories::CategoryMergeAnalysis,
+        correlation::CorrelationAnalysis, descriptive::DescriptiveAnalysis,
+        missing_values::MissingValueAnalysis, outliers::OutlierAnalysis, pca::PcaAnalysis,
+        sessions::SessionAnalysis, target::TargetAnalysis, time_series::TimeSeriesAnalysis,
+        transformations::TransformationAnalysis,
+    },
+    progress::{ProgressCallback, Stage, StageTracker},
+};
+use image::imageops::FilterType;
 use polars::prelude::*;
-use std::{collections::HashMap, path::PathBuf};
+use std::{
+    collections::HashMap,
+    fmt,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
 use thiserror::Error;
 
+/// Seed used to shuffle the rows for stratified sampling, so sampled plots are reproducible.
+pub const SAMPLE_SEED: u64 = 42;
+/// Default resolution of the plots, both embedded in the report and exported as PNG files.
+pub const DEFAULT_DPI: u32 = BASE_DPI;
+/// Subdirectory of the plot directory the embedded copies of the plots are saved to, when the
+/// embedded and exported resolutions differ.
+pub const EMBEDDED_PLOT_DIR: &str = "embedded";
+
+/// The resolutions of the plots embedded in the report and exported to the plot directory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PlotResolution {
+    /// The resolution of the plots embedded in the report, in dots per inch.
+    pub embedded_dpi: u32,
+    /// The resolution of the standalone PNG files in the plot directory, in dots per inch.
+    pub exported_dpi: u32,
+}
+
+impl Default for PlotResolution {
+    fn default() -> Self {
+        Self {
+            embedded_dpi: DEFAULT_DPI,
+            exported_dpi: DEFAULT_DPI,
+        }
+    }
+}
+
 /// Enum for the sample of the dataset to generate visualizations for.
+#[derive(Debug, Clone, PartialEq)]
 pub enum SampleModeEnum {
     /// Use a strict limit for sampling of the data.
     Limit(u64),
     /// Use a certain ratio for sampling of the data.
     Ratio(f64),
+    /// Sample up to `per_group` rows for each value of `column`, so rare categories or classes
+    /// are kept in the sample. Missing values of the column form their own group.
+    Stratified {
+        /// The column to stratify by.
+        column: String,
+        /// The maximum number of rows to keep for each group.
+        per_group: u32,
+    },
     /// Use the full dataset.
     Full,
 }
 
+impl FromStr for SampleModeEnum {
+    type Err = String;
+
+    /// Parses `full`, `limit:<rows>`, `ratio:<fraction>`, or `stratified:<column>:<per_group>`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (mode, value) = s.split_once(':').unwrap_or((s, ""));
+        match mode.to_lowercase().as_str() {
+            "full" => Ok(SampleModeEnum::Full),
+            "limit" => value
+                .parse()
+                .map(SampleModeEnum::Limit)
+                .map_err(|_| format!("invalid sample limit '{}'", value)),
+            "ratio" => value
+                .parse()
+                .map(SampleModeEnum::Ratio)
+                .map_err(|_| format!("invalid sample ratio '{}'", value)),
+            "stratified" => {
+                // Split on the last colon so column names can contain colons.
+                let (column, per_group) = value
+                    .rsplit_once(':')
+                    .ok_or_else(|| "expected stratified:<column>:<per_group>".to_owned())?;
+                Ok(SampleModeEnum::Stratified {
+                    column: column.to_owned(),
+                    per_group: per_group
+                        .parse()
+                        .map_err(|_| format!("invalid rows per group '{}'", per_group))?,
+                })
+            }
+            other => Err(format!(
+                "unsupported sample mode '{}', expected one of: full, limit:<rows>, \
+                 ratio:<fraction>, stratified:<column>:<per_group>",
+                other
+            )),
+        }
+    }
+}
+
+/// Enum for the column order of the heatmaps with a row or column per dataset column.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum HeatmapOrder {
+    /// Order the columns by similarity (hierarchical clustering), so block structure is visible.
+    #[default]
+    Clustered,
+    /// Keep the columns in schema order.
+    Schema,
+}
+
+impl FromStr for HeatmapOrder {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "clustered" => Ok(HeatmapOrder::Clustered),
+            "schema" => Ok(HeatmapOrder::Schema),
+            other => Err(format!(
+                "unsupported heatmap order '{}', expected one of: clustered, schema",
+                other
+            )),
+        }
+    }
+}
+
+impl fmt::Display for HeatmapOrder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let order = match self {
+            HeatmapOrder::Clustered => "clustered",
+            HeatmapOrder::Schema => "schema",
+        };
+        write!(f, "{}", order)
+    }
+}
+
 /// The error types for the visualization module.
 #[derive(Error, Debug)]
 pub enum VisualizationError {
@@ -27,16 +161,86 @@ pub enum VisualizationError {
     #[error("Error sampling the dataframe: {0}")]
     DataFrameSamplingError(String),
 
+    /// Occurs when reading or writing a plot file fails.
+    #[error("Plot file error: {0}")]
+    PlotFile(#[from] std::io::Error),
+
+    /// Occurs when downsampling a plot to the embedded or exported resolution fails.
+    #[error("Plot resampling error: {0}")]
+    PlotResampling(#[from] image::ImageError),
+
+    /// Occurs when creating the distribution plots fails.
+    #[error("Distribution plot error: {0}")]
+    DistributionPlotting(#[from] distribution_viz::DistributionPlotError),
+
     /// Occurs when creating the missing values plots fails.
     #[error("Missing values plot error: {0}")]
     MissingValuesPlotting(#[from] crate::data::viz_lib::missing_value_viz::MissingValuesPlotError),
+
+    /// Occurs when creating the transformation plots fails.
+    #[error("Transformation plot error: {0}")]
+    TransformationPlotting(#[from] transformation_viz::TransformationPlotError),
+
+    /// Occurs when creating the outlier plots fails.
+    #[error("Outlier plot error: {0}")]
+    OutlierPlotting(#[from] outlier_viz::OutlierPlotError),
+
+    /// Occurs when creating the target analysis plots fails.
+    #[error("Target plot error: {0}")]
+    TargetPlotting(#[from] target_viz::TargetPlotError),
+
+    /// Occurs when creating the PCA plots fails.
+    #[cfg(feature = "pca")]
+    #[error("PCA plot error: {0}")]
+    PcaPlotting(#[from] pca_viz::PcaPlotError),
+
+    /// Occurs when creating the cross-tabulation heatmaps fails.
+    #[error("Association plot error: {0}")]
+    AssociationPlotting(#[from] association_viz::AssociationPlotError),
+
+    /// Occurs when creating the correlation heatmaps fails.
+    #[error("Correlation plot error: {0}")]
+    CorrelationPlotting(#[from] correlation_viz::CorrelationPlotError),
+
+    /// Occurs when creating the time series plots fails.
+    #[cfg(feature = "time-series")]
+    #[error("Time series plot error: {0}")]
+    TimeSeriesPlotting(#[from] time_series_viz::TimeSeriesPlotError),
+
+    /// Occurs when creating the category merge plots fails.
+    #[error("Category plot error: {0}")]
+    CategoryPlotting(#[from] category_viz::CategoryPlotError),
+
+    /// Occurs when creating the session plots fails.
+    #[error("Session plot error: {0}")]
+    SessionPlotting(#[from] session_viz::SessionPlotError),
 }
 
-/// Enum to represent which section each visualization corresponds to.
+/// Enum to represent which report section each group of plots is drawn in.
 #[derive(Debug, Hash, PartialEq, Eq)]
-pub enum ReportSection {
+pub enum PlotGroup {
+    /// The distributions section.
+    Distributions,
     /// The missing values analysis section.
     MissingValues,
+    /// The transformation suggestions section.
+    Transformations,
+    /// The category merge suggestions section.
+    CategoryMerges,
+    /// The outlier analysis section.
+    Outliers,
+    /// The target analysis section (supervised mode only).
+    Target,
+    /// The principal component analysis section (only when PCA is enabled).
+    Pca,
+    /// The categorical association section.
+    Associations,
+    /// The numerical correlation section.
+    Correlations,
+    /// The time series analysis section (time series mode only).
+    TimeSeries,
+    /// The sessionized event log section (only when session columns are designated).
+    Sessions,
 }
 
 /// Manages the creation and storage of visualizations for different report sections.
@@ -46,9 +250,9 @@ pub enum ReportSection {
 #[derive(Debug)]
 pub struct VisualizationManager {
     /// A nested `HashMap` structure where:
-    /// - The outer `HashMap` maps `ReportSection` to an inner `HashMap`.
+    /// - The outer `HashMap` maps `PlotGroup` to an inner `HashMap`.
     /// - The inner `HashMap` maps visualization titles to their file paths.
-    pub visualizations: HashMap<ReportSection, HashMap<String, PathBuf>>,
+    pub visualizations: HashMap<PlotGroup, HashMap<String, PathBuf>>,
 }
 
 impl VisualizationManager {
@@ -59,43 +263,255 @@ impl VisualizationManager {
     /// - `plot_dir`: Directory where plot images will be saved.
     /// - `lazy_df`: The `LazyFrame` containing the dataset to visualize.
     /// - `shape`: The shape of the dataset (rows, columns).
+    /// - `descriptive_analysis`: The descriptive analysis, for the numerical features.
     /// - `missing_values_analysis`: Analysis results for missing values.
+    /// - `transformation_analysis`: Transformation suggestions for the skewed features.
+    /// - `category_analysis`: Merge suggestions for the categorical features with rare levels.
+    /// - `outlier_analysis`: Outlier fences for the numerical features.
+    /// - `target_analysis`: The target analysis, if running in supervised mode.
+    /// - `pca_analysis`: The principal component analysis, if enabled.
+    /// - `association_analysis`: The contingency tables between the categorical features.
+    /// - `correlation_analysis`: The correlations between the numerical features.
+    /// - `time_series_analysis`: The time series analysis, if running in time series mode.
+    /// - `session_analysis`: The sessionized event log profile, if session columns are designated.
+    /// - `sampling_mode`: The sample of the dataset to visualize.
+    /// - `heatmap_order`: The column order of the missingness and correlation heatmaps.
+    /// - `resolution`: The resolutions of the embedded and exported plots.
+    /// - `max_column_plots`: The maximum number of features with per-column plots (histograms,
+    ///   transformation histograms, category merge charts, and outlier strip plots), or None to plot every feature.
+    /// - `skipped_columns`: The columns opting out of the per-column plots.
+    /// - `progress`: The optional callback to report the plot sections to as they're generated.
     ///
     /// ### Returns
     ///
     /// - `Result<Self, VisualizationError>`: A new `VisualizationManager` instance or an error.
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
-        plot_dir: &PathBuf,
+        plot_dir: &Path,
         lazy_df: &LazyFrame,
-        shape: (u64, u64),
+        _shape: (u64, u64),
+        descriptive_analysis: &DescriptiveAnalysis,
         missing_values_analysis: &MissingValueAnalysis,
+        transformation_analysis: &TransformationAnalysis,
+        category_analysis: &CategoryMergeAnalysis,
+        outlier_analysis: &OutlierAnalysis,
+        target_analysis: Option<&TargetAnalysis>,
+        pca_analysis: Option<&PcaAnalysis>,
+        association_analysis: &AssociationAnalysis,
+        correlation_analysis: &CorrelationAnalysis,
+        time_series_analysis: Option<&TimeSeriesAnalysis>,
+        session_analysis: Option<&SessionAnalysis>,
         sampling_mode: SampleModeEnum,
+        heatmap_order: HeatmapOrder,
+        resolution: PlotResolution,
+        max_column_plots: Option<usize>,
+        skipped_columns: &[String],
+        progress: Option<&ProgressCallback>,
     ) -> Result<Self, VisualizationError> {
-        let mut visualizations: HashMap<ReportSection, HashMap<String, PathBuf>> = HashMap::new();
+        let mut visualizations: HashMap<PlotGroup, HashMap<String, PathBuf>> = HashMap::new();
+        let n_sections = 7
+            + usize::from(target_analysis.is_some())
+            + usize::from(pca_analysis.is_some())
+            + usize::from(time_series_analysis.is_some())
+            + usize::from(session_analysis.is_some());
+        let mut tracker =
+            StageTracker::start(progress, Stage::Visualizations, "plot sections", n_sections);
 
         let df = sample_dataframe(lazy_df, sampling_mode)?;
+        let theme = &PlotTheme::new(resolution.embedded_dpi.max(resolution.exported_dpi));
+        let max_features = max_column_plots.unwrap_or(usize::MAX);
+
+        // Generate the small multiples grids of the numerical feature histograms.
+        let distribution_plots = distribution_viz::build_all_visualizations(
+            &df,
+            descriptive_analysis,
+            max_features,
+            skipped_columns,
+            plot_dir,
+            theme,
+        )?;
+        visualizations.insert(PlotGroup::Distributions, distribution_plots);
+        tracker.step();
 
         // Generate missing values visualizations.
-        let missing_value_plots = missing_value_viz::build_all_visualizations(&df, missing_values_analysis, plot_dir)?;
-        visualizations.insert(ReportSection::MissingValues, missing_value_plots);
+        let missing_value_plots = missing_value_viz::build_all_visualizations(
+            &df,
+            missing_values_analysis,
+            heatmap_order,
+            plot_dir,
+            theme,
+        )?;
+        visualizations.insert(PlotGroup::MissingValues, missing_value_plots);
+        tracker.step();
+
+        // Generate the before and after histograms for the transformation suggestions.
+        let transformation_plots = transformation_viz::build_all_visualizations(
+            &df,
+            transformation_analysis,
+            max_features,
+            skipped_columns,
+            plot_dir,
+            theme,
+        )?;
+        visualizations.insert(PlotGroup::Transformations, transformation_plots);
+        tracker.step();
+
+        // Generate the before and after level charts for the category merge suggestions.
+        let category_plots = category_viz::build_all_visualizations(
+            category_analysis,
+            max_features,
+            skipped_columns,
+            plot_dir,
+            theme,
+        )?;
+        visualizations.insert(PlotGroup::CategoryMerges, category_plots);
+        tracker.step();
+
+        // Generate the outlier strip plots.
+        let outlier_plots = outlier_viz::build_all_visualizations(
+            &df,
+            outlier_analysis,
+            max_features,
+            skipped_columns,
+            plot_dir,
+            theme,
+        )?;
+        visualizations.insert(PlotGroup::Outliers, outlier_plots);
+        tracker.step();
+
+        // Generate the target analysis plots in supervised mode.
+        if let Some(target_analysis) = target_analysis {
+            let target_plots =
+                target_viz::build_all_visualizations(target_analysis, plot_dir, theme)?;
+            visualizations.insert(PlotGroup::Target, target_plots);
+            tracker.step();
+        }
+
+        // Generate the scree plot if PCA is enabled.
+        #[cfg(feature = "pca")]
+        if let Some(pca_analysis) = pca_analysis {
+            let pca_plots = pca_viz::build_all_visualizations(pca_analysis, plot_dir, theme)?;
+            visualizations.insert(PlotGroup::Pca, pca_plots);
+            tracker.step();
+        }
+
+        // Generate the cross-tabulation heatmaps.
+        let association_plots =
+            association_viz::build_all_visualizations(association_analysis, plot_dir, theme)?;
+        visualizations.insert(PlotGroup::Associations, association_plots);
+        tracker.step();
+
+        // Generate the Pearson and Spearman correlation heatmaps.
+        let correlation_plots = correlation_viz::build_all_visualizations(
+            correlation_analysis,
+            heatmap_order,
+            plot_dir,
+            theme,
+        )?;
+        visualizations.insert(PlotGroup::Correlations, correlation_plots);
+        tracker.step();
+
+        // Generate the time series plots in time series mode.
+        #[cfg(feature = "time-series")]
+        if let Some(time_series_analysis) = time_series_analysis {
+            let time_series_plots =
+                time_series_viz::build_all_visualizations(time_series_analysis, plot_dir, theme)?;
+            visualizations.insert(PlotGroup::TimeSeries, time_series_plots);
+            tracker.step();
+        }
+
+        // Generate the session plots if session columns are designated.
+        if let Some(session_analysis) = session_analysis {
+            let session_plots =
+                session_viz::build_all_visualizations(session_analysis, plot_dir, theme)?;
+            visualizations.insert(PlotGroup::Sessions, session_plots);
+            tracker.step();
+        }
+        tracker.finish();
+
+        if resolution.embedded_dpi != resolution.exported_dpi {
+            embed_at_resolution(&mut visualizations, plot_dir, resolution)?;
+        }
 
         Ok(Self { visualizations })
     }
 }
 
+/// Saves the embedded copies of the plots (rendered at the higher of the two resolutions) to the
+/// `EMBEDDED_PLOT_DIR` subdirectory, downsampling either the embedded copies or the exported
+/// plots to the lower resolution. The visualizations are updated to the embedded copies.
+///
+/// ### Parameters
+///
+/// - `visualizations`: The plots by section and title, rendered at the higher resolution.
+/// - `plot_dir`: Directory where the plots were saved.
+/// - `resolution`: The resolutions of the embedded and exported plots.
+///
+/// ### Returns
+///
+/// - `Result<(), VisualizationError>`: Unit type or a `VisualizationError`.
+fn embed_at_resolution(
+    visualizations: &mut HashMap<PlotGroup, HashMap<String, PathBuf>>,
+    plot_dir: &Path,
+    resolution: PlotResolution,
+) -> Result<(), VisualizationError> {
+    let embedded_dir = plot_dir.join(EMBEDDED_PLOT_DIR);
+    std::fs::create_dir_all(&embedded_dir)?;
+    let rendered_dpi = resolution.embedded_dpi.max(resolution.exported_dpi);
+
+    for plot_path in visualizations.values_mut().flat_map(HashMap::values_mut) {
+        let Some(file_name) = plot_path.file_name() else {
+            continue;
+        };
+        let embedded_path = embedded_dir.join(file_name);
+        if resolution.embedded_dpi < rendered_dpi {
+            resample(
+                plot_path,
+                &embedded_path,
+                resolution.embedded_dpi,
+                rendered_dpi,
+            )?;
+        } else {
+            std::fs::copy(&plot_path, &embedded_path)?;
+            resample(plot_path, plot_path, resolution.exported_dpi, rendered_dpi)?;
+        }
+        *plot_path = embedded_path;
+    }
+    Ok(())
+}
+
+/// Downsamples a plot rendered at `rendered_dpi` to `dpi`.
+fn resample(
+    path: &Path,
+    output_path: &Path,
+    dpi: u32,
+    rendered_dpi: u32,
+) -> Result<(), VisualizationError> {
+    let image = image::open(path)?;
+    let scale = dpi as f64 / rendered_dpi as f64;
+    let (width, height) = (
+        ((image.width() as f64 * scale).round() as u32).max(1),
+        ((image.height() as f64 * scale).round() as u32).max(1),
+    );
+    image
+        .resize_exact(width, height, FilterType::Lanczos3)
+        .save(output_path)?;
+    Ok(())
+}
+
 /// Collects a lazy frame into a dataframe and applies the sampling if applicable.
 ///
-/// Type of sampling depends on the global `SAMPLE_MODE` setting. TODO : Should this eventually be
-/// abstracted into a CLI argument?
+/// Type of sampling depends on the `sampling_mode`, which is set through the `DataOptions`.
 ///
 /// ### Parameters
 ///
 /// - `lazy_df`: Reference to the dataset `LazyFrame`.
+/// - `sampling_mode`: The sample of the dataset to collect.
 ///
 /// ### Returns
 ///
 /// - `Result<DataFrame, MissingValuesPlotError>`: The collected dataframe or the
-/// `MissingValuesPlotError` error.
+///   `MissingValuesPlotError` error.
 pub fn sample_dataframe(
     lazy_df: &LazyFrame,
     sampling_mode: SampleModeEnum,
@@ -127,6 +543,36 @@ pub fn sample_dataframe(
                 })?;
             Ok(df)
         }
+        SampleModeEnum::Stratified { column, per_group } => {
+            let columns: Vec<Expr> = lazy_df
+                .clone()
+                .schema()
+                .map_err(|e| {
+                    VisualizationError::DataFrameSamplingError(format!(
+                        "Couldn't infer schema for stratified sampling: {}",
+                        e
+                    ))
+                })?
+                .iter_names()
+                .map(|name| col(name))
+                .collect();
+            // Shuffle every column with the same seed so the rows stay aligned, then keep the
+            // first rows of each group.
+            let df = lazy_df
+                .clone()
+                .select([all().sample_frac(lit(1.0), false, true, Some(SAMPLE_SEED))])
+                .group_by_stable([col(&column)])
+                .head(Some(per_group as usize))
+                .select(columns)
+                .collect()
+                .map_err(|e| {
+                    VisualizationError::DataFrameSamplingError(format!(
+                        "Couldn't collect stratified ({}, {} per group) sampled dataframe: {}",
+                        column, per_group, e
+                    ))
+                })?;
+            Ok(df)
+        }
         SampleModeEnum::Full => {
             let df = lazy_df.clone().collect().map_err(|e| {
                 VisualizationError::DataFrameSamplingError(format!(
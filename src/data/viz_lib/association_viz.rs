@@ -0,0 +1,217 @@
+//! Association Visualizations Module
+//!
+//! This module handles the generation of the annotated cross-tabulation heatmaps for the
+//! categorical association analysis.
+
+use super::{
+    create_basic_chart_template, create_drawing_backend, fill_background, PlotTheme, _FONT,
+    LABEL_STYLE, PLOT_CAPTION_FONT, PLOT_HEIGHT, PLOT_MARGIN, PLOT_WIDTH, X_LABEL_AREA_SIZE,
+};
+use crate::data::associations::{AssociationAnalysis, ContingencyTable};
+use plotters::coord::Shift;
+use plotters::prelude::*;
+use plotters::style::text_anchor::{HPos, Pos, VPos};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// The width of the category label area on the y axis.
+pub const CATEGORY_LABEL_AREA_SIZE: u32 = 160;
+/// The font for the cell count annotations.
+pub const CELL_FONT: (&str, u32) = (_FONT, 18);
+
+#[derive(Error, Debug)]
+pub enum AssociationPlotError {
+    /// Occurs during failure to draw a chart.
+    #[error("Error building the plot: {0}")]
+    PlotDrawing(String),
+}
+
+/// Gets the visualization title for a cross-tabulation heatmap.
+pub fn crosstab_plot_title(rows: &str, columns: &str) -> String {
+    format!("{} vs {} Crosstab", rows, columns)
+}
+
+/// Creates the cross-tabulation heatmaps for every contingency table.
+///
+/// ### Parameters
+///
+/// - `association_analysis`: Reference to the association analysis for the dataset.
+/// - `plot_dir`: Directory where the plots should be saved.
+/// - `theme`: The plot theme to render the plots with.
+///
+/// ### Returns
+///
+/// - `Result<HashMap<String, PathBuf>, AssociationPlotError>`: Map of plot titles to the output
+///   file paths, or an `AssociationPlotError`.
+pub fn build_all_visualizations(
+    association_analysis: &AssociationAnalysis,
+    plot_dir: &Path,
+    theme: &PlotTheme,
+) -> Result<HashMap<String, PathBuf>, AssociationPlotError> {
+    let mut plots = HashMap::new();
+    for table in &association_analysis.tables {
+        let output_path = plot_dir.join(format!(
+            "{}_{}_crosstab.png",
+            table.pair.rows, table.pair.columns
+        ));
+        build_crosstab_heatmap(table, &output_path, theme)?;
+        plots.insert(
+            crosstab_plot_title(&table.pair.rows, &table.pair.columns),
+            output_path,
+        );
+    }
+    Ok(plots)
+}
+
+/// Creates a heatmap of a contingency table, with each cell shaded by and annotated with its
+/// count.
+///
+/// ### Parameters
+///
+/// - `table`: The contingency table.
+/// - `output_path`: Reference to the `PathBuf` where the plot should be saved.
+/// - `theme`: The plot theme to render the plot with.
+///
+/// ### Returns
+///
+/// - `Result<(), AssociationPlotError>`: Unit type or an `AssociationPlotError`.
+pub fn build_crosstab_heatmap(
+    table: &ContingencyTable,
+    output_path: &PathBuf,
+    theme: &PlotTheme,
+) -> Result<(), AssociationPlotError> {
+    let root = create_drawing_backend(output_path, (PLOT_WIDTH, PLOT_HEIGHT), theme);
+    draw_crosstab_heatmap(&root, table, theme)?;
+    root.present()
+        .map_err(|e| AssociationPlotError::PlotDrawing(e.to_string()))?;
+    Ok(())
+}
+
+/// Draws a heatmap of a contingency table onto a drawing area.
+///
+/// ### Parameters
+///
+/// - `root`: The drawing area to draw onto, laid out for `(PLOT_WIDTH, PLOT_HEIGHT)`.
+/// - `table`: The contingency table.
+/// - `theme`: The plot theme to render the plot with.
+///
+/// ### Returns
+///
+/// - `Result<(), AssociationPlotError>`: Unit type or an `AssociationPlotError`.
+pub fn draw_crosstab_heatmap<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
+    table: &ContingencyTable,
+    theme: &PlotTheme,
+) -> Result<(), AssociationPlotError> {
+    let n_rows = table.row_labels.len();
+    let n_columns = table.column_labels.len();
+    let max_count = table
+        .counts
+        .iter()
+        .flatten()
+        .copied()
+        .max()
+        .unwrap_or(0)
+        .max(1);
+
+    fill_background(root, &WHITE, None)
+        .map_err(|e| AssociationPlotError::PlotDrawing(e.to_string()))?;
+
+    let title = crosstab_plot_title(&table.pair.rows, &table.pair.columns);
+    // The segmented ranges include their end, so stop at the last category index. The first row
+    // category is drawn at the top.
+    let mut chart = create_basic_chart_template(
+        root,
+        theme,
+        &title,
+        PLOT_CAPTION_FONT,
+        PLOT_MARGIN,
+        X_LABEL_AREA_SIZE,
+        CATEGORY_LABEL_AREA_SIZE,
+        (
+            (0..n_columns.saturating_sub(1)).into_segmented(),
+            (0..n_rows.saturating_sub(1)).into_segmented(),
+        ),
+    )
+    .map_err(|e| AssociationPlotError::PlotDrawing(e.to_string()))?;
+
+    chart
+        .configure_mesh()
+        .disable_x_mesh()
+        .disable_y_mesh()
+        .x_desc(&table.pair.columns)
+        .x_label_style(theme.font(LABEL_STYLE))
+        .x_labels(n_columns)
+        .x_label_formatter(&|segment| match segment {
+            SegmentValue::CenterOf(index) => {
+                table.column_labels.get(*index).cloned().unwrap_or_default()
+            }
+            _ => String::new(),
+        })
+        .y_desc(&table.pair.rows)
+        .y_label_style(theme.font(LABEL_STYLE))
+        .y_labels(n_rows)
+        .y_label_formatter(&|segment| match segment {
+            SegmentValue::CenterOf(index) => n_rows
+                .checked_sub(index + 1)
+                .and_then(|row| table.row_labels.get(row))
+                .cloned()
+                .unwrap_or_default(),
+            _ => String::new(),
+        })
+        .draw()
+        .map_err(|e| AssociationPlotError::PlotDrawing(e.to_string()))?;
+
+    let cells: Vec<(usize, usize, u64)> = table
+        .counts
+        .iter()
+        .enumerate()
+        .flat_map(|(row, counts)| {
+            counts
+                .iter()
+                .enumerate()
+                .map(move |(column, count)| (n_rows - row - 1, column, *count))
+        })
+        .collect();
+
+    chart
+        .draw_series(cells.iter().map(|(y, x, count)| {
+            Rectangle::new(
+                [
+                    (SegmentValue::Exact(*x), SegmentValue::Exact(*y)),
+                    (SegmentValue::Exact(x + 1), SegmentValue::Exact(y + 1)),
+                ],
+                cell_color(*count as f64 / max_count as f64).filled(),
+            )
+        }))
+        .map_err(|e| AssociationPlotError::PlotDrawing(e.to_string()))?;
+
+    chart
+        .draw_series(cells.iter().map(|(y, x, count)| {
+            let intensity = *count as f64 / max_count as f64;
+            let color = if intensity > 0.5 { WHITE } else { BLACK };
+            Text::new(
+                count.to_string(),
+                (SegmentValue::CenterOf(*x), SegmentValue::CenterOf(*y)),
+                theme
+                    .font(CELL_FONT)
+                    .into_font()
+                    .color(&color)
+                    .pos(Pos::new(HPos::Center, VPos::Center)),
+            )
+        }))
+        .map_err(|e| AssociationPlotError::PlotDrawing(e.to_string()))?;
+
+    Ok(())
+}
+
+/// Interpolates from white (no rows) to dark blue (the largest cell).
+fn cell_color(intensity: f64) -> RGBColor {
+    let channel = |start: f64, end: f64| (start + (end - start) * intensity).round() as u8;
+    RGBColor(
+        channel(255.0, 8.0),
+        channel(255.0, 48.0),
+        channel(255.0, 107.0),
+    )
+}
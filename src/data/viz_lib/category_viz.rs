@@ -0,0 +1,242 @@
+//! Category Visualizations Module
+//!
+//! This module handles the generation of the before and after level frequency charts for the
+//! suggested rare category merges.
+
+use super::{
+    create_basic_chart_template, create_drawing_backend, fill_background, PlotTheme, _FONT,
+    LABEL_STYLE, PLOT_HEIGHT, PLOT_MARGIN, PLOT_WIDTH, X_LABEL_AREA_SIZE,
+};
+use crate::data::categories::{CategoryMergeAnalysis, CategoryMergeSuggestion};
+use plotters::coord::Shift;
+use plotters::prelude::*;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// The maximum number of levels drawn in each panel, the most frequent ones.
+pub const MAX_PLOTTED_LEVELS: usize = 15;
+/// The caption font for each chart panel.
+const PANEL_CAPTION_FONT: (&str, u32) = (_FONT, 28);
+/// The width of the level label area.
+const LEVEL_LABEL_AREA_SIZE: u32 = 140;
+/// The maximum length of a level label.
+const MAX_LABEL_LENGTH: usize = 18;
+
+#[derive(Error, Debug)]
+pub enum CategoryPlotError {
+    /// Occurs during failure to draw a chart.
+    #[error("Error building the plot: {0}")]
+    PlotDrawing(String),
+}
+
+/// Gets the visualization title for a feature's category merge charts.
+pub fn category_merge_plot_title(feature: &str) -> String {
+    format!("{} Category Merge", feature)
+}
+
+/// Creates the before and after level frequency charts for the features with a merge suggestion.
+///
+/// ### Parameters
+///
+/// - `category_analysis`: Reference to the category merging suggestions for the dataset.
+/// - `max_features`: The maximum number of features to plot, the first suggestions in column
+///   order.
+/// - `skipped_columns`: The features opting out of the plots.
+/// - `plot_dir`: Directory where the plots should be saved.
+/// - `theme`: The plot theme to render the plots with.
+///
+/// ### Returns
+///
+/// - `Result<HashMap<String, PathBuf>, CategoryPlotError>`: Map of plot titles to the output file
+///   paths, or a `CategoryPlotError`.
+pub fn build_all_visualizations(
+    category_analysis: &CategoryMergeAnalysis,
+    max_features: usize,
+    skipped_columns: &[String],
+    plot_dir: &Path,
+    theme: &PlotTheme,
+) -> Result<HashMap<String, PathBuf>, CategoryPlotError> {
+    let mut plots = HashMap::new();
+    for (feature, suggestion) in category_analysis
+        .suggestions
+        .iter()
+        .filter(|(feature, _)| !skipped_columns.contains(feature))
+        .take(max_features)
+    {
+        let output_path = plot_dir.join(format!("{}_category_merge.png", feature));
+        build_category_merge_charts(feature, suggestion, &output_path, theme)?;
+        plots.insert(category_merge_plot_title(feature), output_path);
+    }
+    Ok(plots)
+}
+
+/// Creates a side by side pair of level frequency charts of a feature before and after merging
+/// its rare levels.
+///
+/// ### Parameters
+///
+/// - `feature`: The feature name.
+/// - `suggestion`: The merge suggestion for the feature.
+/// - `output_path`: Reference to the `PathBuf` where the plot should be saved.
+/// - `theme`: The plot theme to render the plot with.
+///
+/// ### Returns
+///
+/// - `Result<(), CategoryPlotError>`: Unit type or a `CategoryPlotError`.
+pub fn build_category_merge_charts(
+    feature: &str,
+    suggestion: &CategoryMergeSuggestion,
+    output_path: &PathBuf,
+    theme: &PlotTheme,
+) -> Result<(), CategoryPlotError> {
+    let root = create_drawing_backend(output_path, (PLOT_WIDTH, PLOT_HEIGHT), theme);
+    draw_category_merge_charts(&root, feature, suggestion, theme)?;
+    root.present()
+        .map_err(|e| CategoryPlotError::PlotDrawing(e.to_string()))?;
+    Ok(())
+}
+
+/// Draws a side by side pair of level frequency charts of a feature before and after merging its
+/// rare levels onto a drawing area. The rare levels are red before the merge, and "Other" is red
+/// after it.
+///
+/// ### Parameters
+///
+/// - `root`: The drawing area to draw onto, laid out for `(PLOT_WIDTH, PLOT_HEIGHT)`.
+/// - `feature`: The feature name.
+/// - `suggestion`: The merge suggestion for the feature.
+/// - `theme`: The plot theme to render the plot with.
+///
+/// ### Returns
+///
+/// - `Result<(), CategoryPlotError>`: Unit type or a `CategoryPlotError`.
+pub fn draw_category_merge_charts<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
+    feature: &str,
+    suggestion: &CategoryMergeSuggestion,
+    theme: &PlotTheme,
+) -> Result<(), CategoryPlotError> {
+    fill_background(root, &WHITE, None)
+        .map_err(|e| CategoryPlotError::PlotDrawing(e.to_string()))?;
+    let panels = root.split_evenly((1, 2));
+
+    let before: Vec<(&str, usize, bool)> = suggestion
+        .levels
+        .iter()
+        .enumerate()
+        .take(MAX_PLOTTED_LEVELS)
+        .map(|(i, (level, count))| (level.as_str(), *count, i >= suggestion.n_kept))
+        .collect();
+    draw_level_bars(
+        &panels[0],
+        &format!("{} ({} levels)", feature, suggestion.n_levels()),
+        &before,
+        theme,
+    )?;
+
+    // "Other" is always drawn, in place of the least frequent kept levels if needed.
+    let merged = suggestion.merged_levels();
+    let n_merged = merged.len();
+    let after: Vec<(&str, usize, bool)> = merged
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| *i + 1 == n_merged || *i + 1 < MAX_PLOTTED_LEVELS)
+        .map(|(i, (level, count))| (level.as_str(), *count, i + 1 == n_merged))
+        .collect();
+    draw_level_bars(
+        &panels[1],
+        &format!("{} ({} levels)", feature, suggestion.n_merged_levels()),
+        &after,
+        theme,
+    )?;
+
+    Ok(())
+}
+
+/// Draws a horizontal bar chart of the level counts on a drawing area, the first level at the top.
+/// Highlighted levels are red.
+fn draw_level_bars<DB: DrawingBackend>(
+    area: &DrawingArea<DB, Shift>,
+    caption: &str,
+    levels: &[(&str, usize, bool)],
+    theme: &PlotTheme,
+) -> Result<(), CategoryPlotError> {
+    let n_levels = levels.len();
+    let max_count = levels
+        .iter()
+        .map(|(_, count, _)| *count)
+        .max()
+        .unwrap_or(0)
+        .max(1);
+
+    let mut chart = create_basic_chart_template(
+        area,
+        theme,
+        caption,
+        PANEL_CAPTION_FONT,
+        PLOT_MARGIN,
+        X_LABEL_AREA_SIZE,
+        LEVEL_LABEL_AREA_SIZE,
+        (
+            0usize..(max_count + max_count / 10 + 1),
+            // The segmented range includes its end, so stop at the last level index.
+            (0..n_levels.saturating_sub(1)).into_segmented(),
+        ),
+    )
+    .map_err(|e| CategoryPlotError::PlotDrawing(e.to_string()))?;
+
+    chart
+        .configure_mesh()
+        .disable_y_mesh()
+        .x_desc("Count")
+        .x_label_style(theme.font(LABEL_STYLE))
+        .x_labels(5)
+        .y_label_style(theme.font(LABEL_STYLE))
+        .y_labels(n_levels)
+        .y_label_formatter(&|segment| match segment {
+            SegmentValue::CenterOf(index) => n_levels
+                .checked_sub(index + 1)
+                .and_then(|row| levels.get(row))
+                .map(|(level, _, _)| truncate_label(level))
+                .unwrap_or_default(),
+            _ => String::new(),
+        })
+        .draw()
+        .map_err(|e| CategoryPlotError::PlotDrawing(e.to_string()))?;
+
+    chart
+        .draw_series(
+            levels
+                .iter()
+                .enumerate()
+                .map(|(i, (_, count, highlighted))| {
+                    let y = n_levels - i - 1;
+                    let color = if *highlighted { RED } else { BLUE };
+                    let mut bar = Rectangle::new(
+                        [
+                            (0, SegmentValue::Exact(y)),
+                            (*count, SegmentValue::Exact(y + 1)),
+                        ],
+                        color.mix(0.7).filled(),
+                    );
+                    bar.set_margin(theme.px(2), theme.px(2), 0, 0);
+                    bar
+                }),
+        )
+        .map_err(|e| CategoryPlotError::PlotDrawing(e.to_string()))?;
+
+    Ok(())
+}
+
+/// Truncates a level label to `MAX_LABEL_LENGTH` characters.
+fn truncate_label(level: &str) -> String {
+    if level.chars().count() > MAX_LABEL_LENGTH {
+        format!(
+            "{}...",
+            level.chars().take(MAX_LABEL_LENGTH - 3).collect::<String>()
+        )
+    } else {
+        level.to_owned()
+    }
+}
@@ -0,0 +1,263 @@
+//! Correlation Visualizations Module
+//!
+//! This module handles the generation of the Pearson and Spearman correlation heatmaps for the
+//! correlation analysis. Both heatmaps share the same feature order, either the schema order or
+//! the clustered order of the Pearson correlations, where correlated features are adjacent.
+
+use super::{
+    cluster_order, create_basic_chart_template, create_drawing_backend, fill_background, PlotTheme,
+    _FONT, LABEL_STYLE, PLOT_CAPTION_FONT, PLOT_HEIGHT, PLOT_MARGIN, PLOT_WIDTH,
+};
+use crate::data::{correlation::CorrelationAnalysis, visualizations::HeatmapOrder};
+use plotters::coord::Shift;
+use plotters::prelude::*;
+use plotters::style::text_anchor::{HPos, Pos, VPos};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// The title of the Pearson correlation heatmap.
+pub const PEARSON_HEATMAP_TITLE: &str = "Pearson Correlation Heatmap";
+/// The title of the Spearman correlation heatmap.
+pub const SPEARMAN_HEATMAP_TITLE: &str = "Spearman Correlation Heatmap";
+/// The maximum number of features whose heatmap cells are annotated with their correlation.
+pub const MAX_ANNOTATED_FEATURES: usize = 12;
+/// The width of the feature label areas.
+const FEATURE_LABEL_AREA_SIZE: u32 = 160;
+/// The font for the cell correlation annotations.
+const CELL_FONT: (&str, u32) = (_FONT, 14);
+
+#[derive(Error, Debug)]
+pub enum CorrelationPlotError {
+    /// Occurs during failure to draw a chart.
+    #[error("Error building the plot: {0}")]
+    PlotDrawing(String),
+}
+
+/// Creates the Pearson and Spearman correlation heatmaps, if at least two features were
+/// correlated.
+///
+/// ### Parameters
+///
+/// - `correlation_analysis`: Reference to the correlation analysis for the dataset.
+/// - `heatmap_order`: The feature order of the heatmaps.
+/// - `plot_dir`: Directory where the plots should be saved.
+/// - `theme`: The plot theme to render the plots with.
+///
+/// ### Returns
+///
+/// - `Result<HashMap<String, PathBuf>, CorrelationPlotError>`: Map of plot titles to the output
+///   file paths, or a `CorrelationPlotError`.
+pub fn build_all_visualizations(
+    correlation_analysis: &CorrelationAnalysis,
+    heatmap_order: HeatmapOrder,
+    plot_dir: &Path,
+    theme: &PlotTheme,
+) -> Result<HashMap<String, PathBuf>, CorrelationPlotError> {
+    let mut plots = HashMap::new();
+    if correlation_analysis.features.len() < 2 {
+        return Ok(plots);
+    }
+
+    let order: Vec<usize> = match heatmap_order {
+        HeatmapOrder::Clustered => cluster_order(&correlation_analysis.pearson),
+        HeatmapOrder::Schema => (0..correlation_analysis.features.len()).collect(),
+    };
+    let features: Vec<&str> = order
+        .iter()
+        .map(|i| correlation_analysis.features[*i].as_str())
+        .collect();
+    let reorder = |matrix: &[Vec<f64>]| -> Vec<Vec<f64>> {
+        order
+            .iter()
+            .map(|i| order.iter().map(|j| matrix[*i][*j]).collect())
+            .collect()
+    };
+
+    for (title, file_name, matrix) in [
+        (
+            PEARSON_HEATMAP_TITLE,
+            "pearson_correlation_heatmap.png",
+            &correlation_analysis.pearson,
+        ),
+        (
+            SPEARMAN_HEATMAP_TITLE,
+            "spearman_correlation_heatmap.png",
+            &correlation_analysis.spearman,
+        ),
+    ] {
+        let output_path = plot_dir.join(file_name);
+        build_correlation_heatmap(title, &features, &reorder(matrix), &output_path, theme)?;
+        plots.insert(title.to_owned(), output_path);
+    }
+    Ok(plots)
+}
+
+/// Creates a correlation heatmap, with each cell shaded from blue (-1) through white (0) to red
+/// (1).
+///
+/// ### Parameters
+///
+/// - `title`: The plot title.
+/// - `features`: The features, in heatmap order.
+/// - `matrix`: The correlations of the features, in the same order.
+/// - `output_path`: Reference to the `PathBuf` where the plot should be saved.
+/// - `theme`: The plot theme to render the plot with.
+///
+/// ### Returns
+///
+/// - `Result<(), CorrelationPlotError>`: Unit type or a `CorrelationPlotError`.
+pub fn build_correlation_heatmap(
+    title: &str,
+    features: &[&str],
+    matrix: &[Vec<f64>],
+    output_path: &PathBuf,
+    theme: &PlotTheme,
+) -> Result<(), CorrelationPlotError> {
+    let root = create_drawing_backend(output_path, (PLOT_WIDTH, PLOT_HEIGHT), theme);
+    draw_correlation_heatmap(&root, title, features, matrix, theme)?;
+    root.present()
+        .map_err(|e| CorrelationPlotError::PlotDrawing(e.to_string()))?;
+    Ok(())
+}
+
+/// Draws a correlation heatmap onto a drawing area. The cells are annotated with their
+/// correlation for up to `MAX_ANNOTATED_FEATURES` features, and undefined correlations are grey.
+///
+/// ### Parameters
+///
+/// - `root`: The drawing area to draw onto, laid out for `(PLOT_WIDTH, PLOT_HEIGHT)`.
+/// - `title`: The plot title.
+/// - `features`: The features, in heatmap order.
+/// - `matrix`: The correlations of the features, in the same order.
+/// - `theme`: The plot theme to render the plot with.
+///
+/// ### Returns
+///
+/// - `Result<(), CorrelationPlotError>`: Unit type or a `CorrelationPlotError`.
+pub fn draw_correlation_heatmap<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
+    title: &str,
+    features: &[&str],
+    matrix: &[Vec<f64>],
+    theme: &PlotTheme,
+) -> Result<(), CorrelationPlotError> {
+    let n_features = features.len();
+
+    fill_background(root, &WHITE, None)
+        .map_err(|e| CorrelationPlotError::PlotDrawing(e.to_string()))?;
+
+    // The segmented ranges include their end, so stop at the last feature index. The first
+    // feature is drawn at the top.
+    let mut chart = create_basic_chart_template(
+        root,
+        theme,
+        title,
+        PLOT_CAPTION_FONT,
+        PLOT_MARGIN,
+        FEATURE_LABEL_AREA_SIZE,
+        FEATURE_LABEL_AREA_SIZE,
+        (
+            (0..n_features.saturating_sub(1)).into_segmented(),
+            (0..n_features.saturating_sub(1)).into_segmented(),
+        ),
+    )
+    .map_err(|e| CorrelationPlotError::PlotDrawing(e.to_string()))?;
+
+    chart
+        .configure_mesh()
+        .disable_x_mesh()
+        .disable_y_mesh()
+        .x_label_style(
+            theme
+                .font(LABEL_STYLE)
+                .into_font()
+                .transform(FontTransform::Rotate90),
+        )
+        .x_labels(n_features)
+        .x_label_formatter(&|segment| match segment {
+            SegmentValue::CenterOf(index) => features
+                .get(*index)
+                .map(|feature| feature.to_string())
+                .unwrap_or_default(),
+            _ => String::new(),
+        })
+        .y_label_style(theme.font(LABEL_STYLE))
+        .y_labels(n_features)
+        .y_label_formatter(&|segment| match segment {
+            SegmentValue::CenterOf(index) => n_features
+                .checked_sub(index + 1)
+                .and_then(|row| features.get(row))
+                .map(|feature| feature.to_string())
+                .unwrap_or_default(),
+            _ => String::new(),
+        })
+        .draw()
+        .map_err(|e| CorrelationPlotError::PlotDrawing(e.to_string()))?;
+
+    let cells: Vec<(usize, usize, f64)> = matrix
+        .iter()
+        .enumerate()
+        .flat_map(|(row, correlations)| {
+            correlations
+                .iter()
+                .enumerate()
+                .map(move |(column, correlation)| (n_features - row - 1, column, *correlation))
+        })
+        .collect();
+
+    chart
+        .draw_series(cells.iter().map(|(y, x, correlation)| {
+            Rectangle::new(
+                [
+                    (SegmentValue::Exact(*x), SegmentValue::Exact(*y)),
+                    (SegmentValue::Exact(x + 1), SegmentValue::Exact(y + 1)),
+                ],
+                cell_color(*correlation).filled(),
+            )
+        }))
+        .map_err(|e| CorrelationPlotError::PlotDrawing(e.to_string()))?;
+
+    if n_features <= MAX_ANNOTATED_FEATURES {
+        chart
+            .draw_series(
+                cells
+                    .iter()
+                    .filter(|(_, _, correlation)| correlation.is_finite())
+                    .map(|(y, x, correlation)| {
+                        let color = if correlation.abs() > 0.6 {
+                            WHITE
+                        } else {
+                            BLACK
+                        };
+                        Text::new(
+                            format!("{:.2}", correlation),
+                            (SegmentValue::CenterOf(*x), SegmentValue::CenterOf(*y)),
+                            theme
+                                .font(CELL_FONT)
+                                .into_font()
+                                .color(&color)
+                                .pos(Pos::new(HPos::Center, VPos::Center)),
+                        )
+                    }),
+            )
+            .map_err(|e| CorrelationPlotError::PlotDrawing(e.to_string()))?;
+    }
+
+    Ok(())
+}
+
+/// Interpolates from dark blue (-1) through white (0) to dark red (1), grey if undefined.
+fn cell_color(correlation: f64) -> RGBColor {
+    if !correlation.is_finite() {
+        return RGBColor(200, 200, 200);
+    }
+    let intensity = correlation.abs().min(1.0);
+    let end = if correlation < 0.0 {
+        (33.0, 102.0, 172.0)
+    } else {
+        (178.0, 24.0, 43.0)
+    };
+    let channel = |end: f64| (255.0 + (end - 255.0) * intensity).round() as u8;
+    RGBColor(channel(end.0), channel(end.1), channel(end.2))
+}
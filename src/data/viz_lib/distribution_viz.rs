@@ -0,0 +1,170 @@
+//! Distribution Visualizations Module
+//!
+//! This module handles the generation of the histograms of the numerical features, arranged as
+//! small multiples grids so wide datasets get a few grid images instead of one image per feature.
+
+use super::{
+    build_small_multiples, create_basic_chart_template, transformation_viz::format_tick,
+    DrawingError, PlotTheme, _FONT, PLOT_MARGIN,
+};
+use crate::data::descriptive::{DescriptiveAnalysis, HISTOGRAM_BINS};
+use plotters::coord::Shift;
+use plotters::prelude::*;
+use polars::prelude::*;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// The caption font of each histogram panel.
+pub const PANEL_CAPTION_FONT: (&str, u32) = (_FONT, 20);
+/// The axis label style of each histogram panel.
+pub const PANEL_LABEL_STYLE: (&str, u32) = (_FONT, 12);
+/// The color of the histogram bars.
+pub const HISTOGRAM_COLOR: RGBColor = BLUE;
+
+#[derive(Error, Debug)]
+pub enum DistributionPlotError {
+    /// Occurs when the feature values can't be read from the dataframe.
+    #[error("Error reading feature values: {0}")]
+    FeatureValues(#[from] PolarsError),
+
+    /// Occurs when the grid image can't be set up or saved.
+    #[error("Error building the grid: {0}")]
+    Grid(#[from] DrawingError),
+
+    /// Occurs during failure to draw a chart.
+    #[error("Error building the plot: {0}")]
+    PlotDrawing(String),
+}
+
+/// Gets the visualization title for a grid of histograms, by its zero-based position.
+pub fn distribution_grid_title(grid: usize) -> String {
+    format!("Distributions {}", grid + 1)
+}
+
+/// Creates the small multiples grids of the histograms of the numerical features.
+///
+/// ### Parameters
+///
+/// - `df`: Reference to the (sampled) dataset.
+/// - `descriptive_analysis`: Reference to the descriptive analysis, for the numerical features.
+/// - `max_features`: The maximum number of features to plot, the first ones in column order.
+/// - `skipped_columns`: The features opting out of the plots.
+/// - `plot_dir`: Directory where the plots should be saved.
+/// - `theme`: The plot theme to render the plots with.
+///
+/// ### Returns
+///
+/// - `Result<HashMap<String, PathBuf>, DistributionPlotError>`: Map of plot titles to the output
+///   file paths, or a `DistributionPlotError`.
+pub fn build_all_visualizations(
+    df: &DataFrame,
+    descriptive_analysis: &DescriptiveAnalysis,
+    max_features: usize,
+    skipped_columns: &[String],
+    plot_dir: &Path,
+    theme: &PlotTheme,
+) -> Result<HashMap<String, PathBuf>, DistributionPlotError> {
+    let mut features = Vec::with_capacity(descriptive_analysis.sparklines.len().min(max_features));
+    for feature in descriptive_analysis
+        .sparklines
+        .keys()
+        .filter(|feature| !skipped_columns.contains(feature))
+        .take(max_features)
+    {
+        let values: Vec<f64> = df
+            .column(feature)?
+            .cast(&DataType::Float64)?
+            .f64()?
+            .into_iter()
+            .flatten()
+            .filter(|value| value.is_finite())
+            .collect();
+        features.push((feature.as_str(), values));
+    }
+
+    let grid_paths = build_small_multiples(
+        &features,
+        plot_dir,
+        "distributions",
+        theme,
+        |area, (feature, values)| draw_histogram_panel(area, feature, values, theme),
+    )?;
+    Ok(grid_paths
+        .into_iter()
+        .enumerate()
+        .map(|(grid, path)| (distribution_grid_title(grid), path))
+        .collect())
+}
+
+/// Draws a histogram of a feature's values on a small multiples panel.
+///
+/// ### Parameters
+///
+/// - `area`: The drawing area of the panel, laid out for `PANEL_SIZE`.
+/// - `feature`: The feature name, drawn as the panel caption.
+/// - `values`: The feature values.
+/// - `theme`: The plot theme to render the panel with.
+///
+/// ### Returns
+///
+/// - `Result<(), DistributionPlotError>`: Unit type or a `DistributionPlotError`.
+pub fn draw_histogram_panel<DB: DrawingBackend>(
+    area: &DrawingArea<DB, Shift>,
+    feature: &str,
+    values: &[f64],
+    theme: &PlotTheme,
+) -> Result<(), DistributionPlotError> {
+    let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let (min, max) = if values.is_empty() {
+        (0.0, 1.0)
+    } else if max > min {
+        (min, max)
+    } else {
+        (min - 0.5, max + 0.5)
+    };
+    let bin_width = (max - min) / HISTOGRAM_BINS as f64;
+
+    let mut counts = [0u32; HISTOGRAM_BINS];
+    for value in values {
+        let bin = (((value - min) / bin_width) as usize).min(HISTOGRAM_BINS - 1);
+        counts[bin] += 1;
+    }
+    let max_count = counts.iter().copied().max().unwrap_or(0).max(1);
+
+    let mut chart = create_basic_chart_template(
+        area,
+        theme,
+        feature,
+        PANEL_CAPTION_FONT,
+        PLOT_MARGIN,
+        30,
+        45,
+        (min..max, 0u32..(max_count + max_count / 10 + 1)),
+    )
+    .map_err(|e| DistributionPlotError::PlotDrawing(e.to_string()))?;
+
+    chart
+        .configure_mesh()
+        .disable_x_mesh()
+        .x_label_style(theme.font(PANEL_LABEL_STYLE))
+        .y_label_style(theme.font(PANEL_LABEL_STYLE))
+        .x_labels(4)
+        .y_labels(4)
+        .x_label_formatter(&|x| format_tick(*x))
+        .draw()
+        .map_err(|e| DistributionPlotError::PlotDrawing(e.to_string()))?;
+
+    chart
+        .draw_series(counts.iter().enumerate().map(|(bin, count)| {
+            let x0 = min + bin as f64 * bin_width;
+            Rectangle::new(
+                [(x0, 0), (x0 + bin_width, *count)],
+                HISTOGRAM_COLOR.mix(0.7).filled(),
+            )
+        }))
+        .map_err(|e| DistributionPlotError::PlotDrawing(e.to_string()))?;
+
+    Ok(())
+}
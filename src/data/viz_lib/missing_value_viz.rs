@@ -1,19 +1,30 @@
 //! Missing Value Visualizations Module
 //!
 //! This module handles the generation of the visualiations for the missing value analysis.
+//!
+//! Both heatmaps share the same column order, either the schema order or the clustered order of
+//! the missingness correlations, where columns that tend to be missing together are adjacent.
 
 use super::{
-    create_basic_chart_template, create_drawing_backend, fill_background, LABEL_STYLE,
-    PLOT_CAPTION_FONT, PLOT_HEIGHT, PLOT_MARGIN, PLOT_WIDTH, X_LABEL_AREA_SIZE, Y_LABEL_AREA_SIZE,
+    cluster_order, create_basic_chart_template, create_drawing_backend, fill_background, PlotTheme,
+    LABEL_STYLE, PLOT_CAPTION_FONT, PLOT_HEIGHT, PLOT_MARGIN, PLOT_WIDTH, X_LABEL_AREA_SIZE,
+    Y_LABEL_AREA_SIZE,
 };
-use crate::data::missing_values::MissingValueAnalysis;
+use crate::data::{missing_values::MissingValueAnalysis, visualizations::HeatmapOrder};
+use plotters::coord::Shift;
 use plotters::prelude::*;
-use polars::{lazy::dsl::*, prelude::*};
+use polars::prelude::*;
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use thiserror::Error;
 
+/// The title of the missing values heatmap.
+pub const MISSING_DATA_HEATMAP_TITLE: &str = "Missing Values Heatmap";
+/// The title of the missingness correlation heatmap.
+pub const MISSINGNESS_CORRELATION_HEATMAP_TITLE: &str = "Missingness Correlation Heatmap";
+
 #[derive(Error, Debug)]
+#[allow(clippy::enum_variant_names)]
 pub enum MissingValuesPlotError {
     /// Occurs during failure to build the missing matrix.
     #[error("Error building the missing matrix: {0}")]
@@ -28,17 +39,49 @@ pub enum MissingValuesPlotError {
     PlotDrawingError(String),
 }
 
+/// Creates the missing values heatmap and the missingness correlation heatmap.
+///
+/// ### Parameters
+///
+/// - `df`: Reference to the dataset `DataFrame`.
+/// - `missing_values_analysis`: Reference to the `MissingValueAnalysis` struct for the dataset.
+/// - `heatmap_order`: The column order of the heatmaps.
+/// - `plot_dir`: Reference to the `PathBuf` where the plots should be saved.
+/// - `theme`: The plot theme to render the plots with.
+///
+/// ### Returns
+///
+/// - `Result<HashMap<String, PathBuf>, MissingValuesPlotError>`: The plot titles and output file
+///   paths, or a `MissingValuesPlotError`.
 pub fn build_all_visualizations(
     df: &DataFrame,
     missing_values_analysis: &MissingValueAnalysis,
-    plot_dir: &PathBuf,
+    heatmap_order: HeatmapOrder,
+    plot_dir: &Path,
+    theme: &PlotTheme,
 ) -> Result<HashMap<String, PathBuf>, MissingValuesPlotError> {
-    let missing_data_heatmap = build_missing_data_heatmap(df, missing_values_analysis, plot_dir)?;
+    let columns: Vec<&str> = missing_values_analysis
+        .column_missing_values
+        .keys()
+        .map(String::as_str)
+        .collect();
+    let correlation_matrix = build_missingness_matrix(df, &columns)?;
+    let order: Vec<usize> = match heatmap_order {
+        HeatmapOrder::Clustered => cluster_order(&correlation_matrix),
+        HeatmapOrder::Schema => (0..columns.len()).collect(),
+    };
+    let columns: Vec<&str> = order.iter().map(|i| columns[*i]).collect();
+    let correlation_matrix: Vec<Vec<f64>> = order
+        .iter()
+        .map(|i| order.iter().map(|j| correlation_matrix[*i][*j]).collect())
+        .collect();
+
+    let missing_data_heatmap = build_missing_data_heatmap(df, &columns, plot_dir, theme)?;
     let missingness_correlation_heatmap =
-        build_missingness_correlation_heatmap(df, missing_values_analysis, plot_dir)?;
+        build_missingness_correlation_heatmap(&columns, &correlation_matrix, plot_dir, theme)?;
     let missing_value_plot_map =
         HashMap::from([missing_data_heatmap, missingness_correlation_heatmap]);
-    return Ok(missing_value_plot_map);
+    Ok(missing_value_plot_map)
 }
 
 /// Creates a heatmap visualization of missing values in the dataset.
@@ -46,40 +89,59 @@ pub fn build_all_visualizations(
 /// ### Parameters
 ///
 /// - `df`: Reference to the dataset `LazyFrame`.
-/// - `missing_values_analysis`: Reference to the `MissingValueAnalysis` struct for the dataset.
+/// - `columns`: The columns to draw, in heatmap order.
 /// - `plot_dir`: Reference to the `PathBuf` where the plot should be saved.
+/// - `theme`: The plot theme to render the plot with.
 ///
 /// ### Returns
 ///
 /// - `Result<(String, PathBuf), MissingValuesPlotError>`: Result containing a tuple with the plot
-/// title (String) and the output file path (PathBuf), or a `MissingValuesPlotError`.
+///   title (String) and the output file path (PathBuf), or a `MissingValuesPlotError`.
 pub fn build_missing_data_heatmap(
     df: &DataFrame,
-    missing_values_analysis: &MissingValueAnalysis,
-    plot_dir: &PathBuf,
+    columns: &[&str],
+    plot_dir: &Path,
+    theme: &PlotTheme,
 ) -> Result<(String, PathBuf), MissingValuesPlotError> {
-    let plot_title = "Missing Values Heatmap".to_owned();
+    let output_path = plot_dir.join("missing_values_heatmap.png");
+    let root = create_drawing_backend(&output_path, (PLOT_WIDTH, PLOT_HEIGHT), theme);
+    draw_missing_data_heatmap(&root, df, columns, theme)?;
+    root.present()
+        .map_err(|e| MissingValuesPlotError::PlotDrawingError(e.to_string()))?;
+    drop(root);
 
-    // Prepare the matrix.
-    let columns: Vec<&str> = missing_values_analysis
-        .column_missing_values
-        .keys()
-        .map(String::as_str)
-        .collect();
-    let matrix = build_missing_matrix(df, &columns)?;
+    Ok((MISSING_DATA_HEATMAP_TITLE.to_owned(), output_path))
+}
 
-    let output_path = plot_dir.join("missing_values_heatmap.png");
-    // There's probably a better way to do this.
-    let output_path_clone = output_path.clone();
+/// Draws a heatmap of the missing values in the dataset onto a drawing area.
+///
+/// ### Parameters
+///
+/// - `root`: The drawing area to draw onto, laid out for `(PLOT_WIDTH, PLOT_HEIGHT)`.
+/// - `df`: Reference to the dataset `DataFrame`.
+/// - `columns`: The columns to draw, in heatmap order.
+/// - `theme`: The plot theme to render the plot with.
+///
+/// ### Returns
+///
+/// - `Result<(), MissingValuesPlotError>`: Unit type or a `MissingValuesPlotError`.
+pub fn draw_missing_data_heatmap<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
+    df: &DataFrame,
+    columns: &[&str],
+    theme: &PlotTheme,
+) -> Result<(), MissingValuesPlotError> {
+    // Prepare the matrix.
+    let matrix = build_missing_matrix(df, columns)?;
 
-    let root = create_drawing_backend(&output_path_clone, (PLOT_WIDTH, PLOT_HEIGHT));
-    fill_background(&root, &WHITE, Some(0.95))
+    fill_background(root, &WHITE, Some(0.95))
         .map_err(|e| MissingValuesPlotError::PlotDrawingError(e.to_string()))?;
 
     // Create the chart builder for the heatmap.
     let mut chart = create_basic_chart_template(
-        &root,
-        &plot_title,
+        root,
+        theme,
+        MISSING_DATA_HEATMAP_TITLE,
         PLOT_CAPTION_FONT,
         PLOT_MARGIN,
         X_LABEL_AREA_SIZE,
@@ -93,9 +155,9 @@ pub fn build_missing_data_heatmap(
         .disable_x_mesh()
         .disable_y_mesh()
         .x_desc("Columns")
-        .x_label_style(LABEL_STYLE)
+        .x_label_style(theme.font(LABEL_STYLE))
         .y_desc("Rows")
-        .y_label_style(LABEL_STYLE)
+        .y_label_style(theme.font(LABEL_STYLE))
         .x_labels(columns.len())
         .x_label_formatter(&|x| {
             if *x < columns.len() {
@@ -129,35 +191,67 @@ pub fn build_missing_data_heatmap(
             ))
         })?;
 
-    Ok((plot_title.clone(), output_path))
+    Ok(())
 }
 
+/// Creates a heatmap of the correlations between the missingness of the columns.
+///
+/// ### Parameters
+///
+/// - `columns`: The columns to draw, in heatmap order.
+/// - `correlation_matrix`: The missingness correlations of the columns, in the same order.
+/// - `plot_dir`: Reference to the `PathBuf` where the plot should be saved.
+/// - `theme`: The plot theme to render the plot with.
+///
+/// ### Returns
+///
+/// - `Result<(String, PathBuf), MissingValuesPlotError>`: Result containing a tuple with the plot
+///   title (String) and the output file path (PathBuf), or a `MissingValuesPlotError`.
 pub fn build_missingness_correlation_heatmap(
-    df: &DataFrame,
-    missing_values_analysis: &MissingValueAnalysis,
-    plot_dir: &PathBuf,
+    columns: &[&str],
+    correlation_matrix: &[Vec<f64>],
+    plot_dir: &Path,
+    theme: &PlotTheme,
 ) -> Result<(String, PathBuf), MissingValuesPlotError> {
-    let plot_title = "Missingness Correlation Heatmap".to_owned();
-
-    // Sample the data and prepare the correlation matrix.
-    let columns: Vec<&str> = missing_values_analysis
-        .column_missing_values
-        .keys()
-        .map(String::as_str)
-        .collect();
-    let correlation_matrix = build_missingness_matrix(df, &columns)?;
-
     let output_path = plot_dir.join("missingness_correlation_heatmap.png");
-    // There's probably a better way to do this.
-    let output_path_clone = output_path.clone();
+    let root = create_drawing_backend(&output_path, (PLOT_WIDTH, PLOT_HEIGHT), theme);
+    draw_missingness_correlation_heatmap(&root, columns, correlation_matrix, theme)?;
+    root.present()
+        .map_err(|e| MissingValuesPlotError::PlotDrawingError(e.to_string()))?;
+    drop(root);
+
+    Ok((
+        MISSINGNESS_CORRELATION_HEATMAP_TITLE.to_owned(),
+        output_path,
+    ))
+}
 
-    let root = create_drawing_backend(&output_path_clone, (PLOT_WIDTH, PLOT_HEIGHT));
-    fill_background(&root, &WHITE, Some(0.95))
+/// Draws a heatmap of the correlations between the missingness of the columns onto a drawing
+/// area.
+///
+/// ### Parameters
+///
+/// - `root`: The drawing area to draw onto, laid out for `(PLOT_WIDTH, PLOT_HEIGHT)`.
+/// - `columns`: The columns to draw, in heatmap order.
+/// - `correlation_matrix`: The missingness correlations of the columns, in the same order.
+/// - `theme`: The plot theme to render the plot with.
+///
+/// ### Returns
+///
+/// - `Result<(), MissingValuesPlotError>`: Unit type or a `MissingValuesPlotError`.
+pub fn draw_missingness_correlation_heatmap<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
+    columns: &[&str],
+    correlation_matrix: &[Vec<f64>],
+    theme: &PlotTheme,
+) -> Result<(), MissingValuesPlotError> {
+    fill_background(root, &WHITE, Some(0.95))
         .map_err(|e| MissingValuesPlotError::PlotDrawingError(e.to_string()))?;
 
     let mut chart = create_basic_chart_template(
-        &root,
-        &plot_title,
+        root,
+        theme,
+        MISSINGNESS_CORRELATION_HEATMAP_TITLE,
         PLOT_CAPTION_FONT,
         PLOT_MARGIN,
         X_LABEL_AREA_SIZE,
@@ -171,9 +265,9 @@ pub fn build_missingness_correlation_heatmap(
         .disable_x_mesh()
         .disable_y_mesh()
         .x_desc("Columns")
-        .x_label_style(LABEL_STYLE)
+        .x_label_style(theme.font(LABEL_STYLE))
         .y_desc("Rows")
-        .y_label_style(LABEL_STYLE)
+        .y_label_style(theme.font(LABEL_STYLE))
         .x_labels(columns.len())
         .x_label_formatter(&|x| {
             if *x < columns.len() {
@@ -216,7 +310,7 @@ pub fn build_missingness_correlation_heatmap(
             ))
         })?;
 
-    Ok((plot_title.clone(), output_path))
+    Ok(())
 }
 
 /// Constructs a matrix representing missing values in the dataset.
@@ -229,11 +323,11 @@ pub fn build_missingness_correlation_heatmap(
 /// ### Returns
 ///
 /// - `Result<Vec<Vec<bool>>, MissingValuesPlotError>`: A Result containing a 2D vector
-/// of booleans representing missing (true) or present (false) values, or a MissingValuesPlotError
-/// if an error occurs during matrix construction.
+///   of booleans representing missing (true) or present (false) values, or a MissingValuesPlotError
+///   if an error occurs during matrix construction.
 fn build_missing_matrix(
     df: &DataFrame,
-    columns: &Vec<&str>,
+    columns: &[&str],
 ) -> Result<Vec<Vec<bool>>, MissingValuesPlotError> {
     let mut matrix = Vec::with_capacity(df.height());
     for col_name in columns {
@@ -317,7 +411,7 @@ fn calculate_pearson_coefficient(x: &[bool], y: &[bool]) -> f64 {
     let numerator: f64 = x
         .iter()
         .zip(y.iter())
-        .map(|(&a, &b)| ((a as u8 as f64 - mean_x) * (b as u8 as f64 - mean_y)))
+        .map(|(&a, &b)| (a as u8 as f64 - mean_x) * (b as u8 as f64 - mean_y))
         .sum();
 
     let denominator_x: f64 = x
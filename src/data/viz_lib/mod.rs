@@ -1,3 +1,14 @@
+//! Visualization Library Module
+//!
+//! This module holds the plot builders of each analysis and the drawing helpers they share.
+//!
+//! Each single plot has a `build_*` function, saving it as a PNG file for the report, and a
+//! generic `draw_*` function it's drawn with. Library users can call the `draw_*` functions with
+//! a drawing area of their own plotters backend (e.g. an SVG or Cairo surface, or a GUI canvas)
+//! to render the same plots elsewhere. The `draw_*` functions don't present the drawing area, so
+//! flushing it is left to the caller, and the plots are laid out for the dimensions their
+//! `build_*` function renders at.
+
 use plotters::{
     backend::{BitMapBackend, DrawingBackend},
     chart::{ChartBuilder, ChartContext},
@@ -5,10 +16,24 @@ use plotters::{
     drawing::{DrawingArea, IntoDrawingArea},
     style::Color,
 };
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use thiserror::Error;
 
+pub mod association_viz;
+pub mod category_viz;
+pub mod correlation_viz;
+pub mod distribution_viz;
 pub mod missing_value_viz;
+pub mod outlier_viz;
+#[cfg(feature = "pca")]
+pub mod pca_viz;
+pub mod session_viz;
+pub mod target_viz;
+#[cfg(feature = "time-series")]
+pub mod time_series_viz;
+pub mod transformation_viz;
+#[cfg(feature = "time-series")]
+pub mod trend_viz;
 
 pub const PLOT_WIDTH: u32 = 1200;
 pub const PLOT_HEIGHT: u32 = 800;
@@ -18,6 +43,55 @@ pub const PLOT_MARGIN: u32 = 10;
 pub const X_LABEL_AREA_SIZE: u32 = 50;
 pub const Y_LABEL_AREA_SIZE: u32 = 80;
 pub const LABEL_STYLE: (&str, u32) = (_FONT, 16);
+/// The number of panels in each row of a small multiples grid.
+pub const GRID_COLUMNS: usize = 3;
+/// The maximum number of panel rows in each small multiples grid image.
+pub const GRID_ROWS: usize = 4;
+/// The width and height of each panel of a small multiples grid.
+pub const PANEL_SIZE: (u32, u32) = (PLOT_WIDTH / GRID_COLUMNS as u32, 300);
+/// The resolution the plot dimensions, fonts, and line widths above are designed at.
+pub const BASE_DPI: u32 = 100;
+
+/// The plot theme, scaling the plot dimensions, fonts, and line widths (designed at `BASE_DPI`)
+/// to the resolution the plots are rendered at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PlotTheme {
+    /// The resolution to render the plots at, in dots per inch.
+    pub dpi: u32,
+}
+
+impl Default for PlotTheme {
+    fn default() -> Self {
+        Self { dpi: BASE_DPI }
+    }
+}
+
+impl PlotTheme {
+    /// Creates a plot theme rendering at the given resolution.
+    pub fn new(dpi: u32) -> Self {
+        Self { dpi }
+    }
+
+    /// Gets the factor the sizes designed at `BASE_DPI` are scaled by.
+    pub fn scale(&self) -> f64 {
+        self.dpi as f64 / BASE_DPI as f64
+    }
+
+    /// Scales a size in pixels (a line width, marker size, margin, or offset).
+    pub fn px(&self, size: u32) -> u32 {
+        (size as f64 * self.scale()).round() as u32
+    }
+
+    /// Scales the dimensions of an image.
+    pub fn size(&self, dimensions: (u32, u32)) -> (u32, u32) {
+        (self.px(dimensions.0), self.px(dimensions.1))
+    }
+
+    /// Scales a font style.
+    pub fn font<'a>(&self, font: (&'a str, u32)) -> (&'a str, u32) {
+        (font.0, self.px(font.1))
+    }
+}
 
 #[derive(Error, Debug)]
 pub enum DrawingError {
@@ -28,23 +102,29 @@ pub enum DrawingError {
     /// Error creating basic chart template.
     #[error("Error creating chart template: {0}")]
     ChartTemplateError(String),
+
+    /// Error saving an image to its file.
+    #[error("Error saving the image: {0}")]
+    Present(String),
 }
 
-pub fn create_drawing_backend(
-    path: &PathBuf,
+pub fn create_drawing_backend<'a>(
+    path: &'a PathBuf,
     dimensions: (u32, u32),
-) -> DrawingArea<BitMapBackend, Shift> {
-    let root = BitMapBackend::new(path, dimensions).into_drawing_area();
-    return root;
+    theme: &PlotTheme,
+) -> DrawingArea<BitMapBackend<'a>, Shift> {
+    let root = BitMapBackend::new(path, theme.size(dimensions)).into_drawing_area();
+    root
 }
 
-pub fn fill_background<T>(
-    root: &DrawingArea<BitMapBackend, Shift>,
+pub fn fill_background<T, DB>(
+    root: &DrawingArea<DB, Shift>,
     color: &T,
     mix_value: Option<f64>,
 ) -> Result<(), DrawingError>
 where
     T: Color,
+    DB: DrawingBackend,
 {
     match mix_value {
         Some(val) => root
@@ -56,27 +136,152 @@ where
     }
 }
 
+/// Cartesian chart context returned by `create_basic_chart_template`.
+pub type BasicChart<'a, DB, X, Y> = ChartContext<
+    'a,
+    DB,
+    Cartesian2d<<X as AsRangedCoord>::CoordDescType, <Y as AsRangedCoord>::CoordDescType>,
+>;
+
+#[allow(clippy::too_many_arguments)]
 pub fn create_basic_chart_template<'a, X, Y, DB>(
     root: &'a DrawingArea<DB, Shift>,
+    theme: &PlotTheme,
     caption: &'a str,
     font_style: (&'a str, u32),
     margin: u32,
     x_label_area_size: u32,
     y_label_area_size: u32,
     cartesian_dimensions: (X, Y),
-) -> Result<ChartContext<'a, DB, Cartesian2d<X::CoordDescType, Y::CoordDescType>>, DrawingError>
+) -> Result<BasicChart<'a, DB, X, Y>, DrawingError>
 where
     X: AsRangedCoord,
     Y: AsRangedCoord,
     DB: DrawingBackend + 'a,
 {
     let chart = ChartBuilder::on(root)
-        .caption(caption, font_style)
-        .margin(margin)
-        .x_label_area_size(x_label_area_size)
-        .y_label_area_size(y_label_area_size)
+        .caption(caption, theme.font(font_style))
+        .margin(theme.px(margin))
+        .x_label_area_size(theme.px(x_label_area_size))
+        .y_label_area_size(theme.px(y_label_area_size))
         .build_cartesian_2d(cartesian_dimensions.0, cartesian_dimensions.1)
         .map_err(|e| DrawingError::ChartTemplateError(e.to_string()))?;
 
     Ok(chart)
 }
+
+/// Arranges many small plots into small multiples grid images of up to `GRID_ROWS` rows of
+/// `GRID_COLUMNS` panels, so wide datasets don't need an image (and page space) per plot.
+///
+/// ### Parameters
+///
+/// - `items`: The items to draw a panel for, in panel order.
+/// - `plot_dir`: Directory where the grid images should be saved.
+/// - `file_prefix`: The file name prefix of the grid images, suffixed with the grid number.
+/// - `theme`: The plot theme to render the grid images with.
+/// - `draw_panel`: The function drawing the panel of an item onto its drawing area.
+///
+/// ### Returns
+///
+/// - `Result<Vec<PathBuf>, E>`: The paths of the grid images in order, or the error of the panel
+///   drawing function.
+pub fn build_small_multiples<T, E, F>(
+    items: &[T],
+    plot_dir: &Path,
+    file_prefix: &str,
+    theme: &PlotTheme,
+    mut draw_panel: F,
+) -> Result<Vec<PathBuf>, E>
+where
+    E: From<DrawingError>,
+    F: FnMut(&DrawingArea<BitMapBackend, Shift>, &T) -> Result<(), E>,
+{
+    let mut grid_paths = Vec::new();
+    for (grid, grid_items) in items.chunks(GRID_ROWS * GRID_COLUMNS).enumerate() {
+        let output_path = plot_dir.join(format!("{}_grid_{}.png", file_prefix, grid + 1));
+        let n_rows = grid_items.len().div_ceil(GRID_COLUMNS);
+        let root = create_drawing_backend(
+            &output_path,
+            (
+                PANEL_SIZE.0 * GRID_COLUMNS as u32,
+                PANEL_SIZE.1 * n_rows as u32,
+            ),
+            theme,
+        );
+        fill_background(&root, &plotters::style::WHITE, None)?;
+
+        for (area, item) in root
+            .split_evenly((n_rows, GRID_COLUMNS))
+            .iter()
+            .zip(grid_items)
+        {
+            draw_panel(area, item)?;
+        }
+
+        root.present()
+            .map_err(|e| DrawingError::Present(e.to_string()))?;
+        grid_paths.push(output_path.clone());
+    }
+    Ok(grid_paths)
+}
+
+/// Orders the rows and columns of a symmetric similarity matrix (e.g. a correlation matrix) so
+/// similar items are next to each other, by the leaf order of an average linkage hierarchical
+/// clustering with `1 - |similarity|` as the distance.
+///
+/// ### Parameters
+///
+/// - `similarity`: The square similarity matrix, with values from -1 to 1.
+///
+/// ### Returns
+///
+/// - `Vec<usize>`: The item indices in clustered order.
+pub fn cluster_order(similarity: &[Vec<f64>]) -> Vec<usize> {
+    let n = similarity.len();
+    let mut clusters: Vec<Option<Vec<usize>>> = (0..n).map(|i| Some(vec![i])).collect();
+    let mut distances: Vec<Vec<f64>> = similarity
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|value| {
+                    if value.is_finite() {
+                        1.0 - value.abs()
+                    } else {
+                        1.0
+                    }
+                })
+                .collect()
+        })
+        .collect();
+
+    for _ in 1..n {
+        let mut closest: Option<(usize, usize, f64)> = None;
+        for i in (0..n).filter(|i| clusters[*i].is_some()) {
+            for j in (i + 1..n).filter(|j| clusters[*j].is_some()) {
+                if closest.is_none_or(|(_, _, distance)| distances[i][j] < distance) {
+                    closest = Some((i, j, distances[i][j]));
+                }
+            }
+        }
+        let Some((i, j, _)) = closest else {
+            break;
+        };
+        let merged = clusters[j].take().unwrap_or_default();
+        let (size_i, size_j) = (
+            clusters[i].as_ref().map_or(0, Vec::len) as f64,
+            merged.len() as f64,
+        );
+        // Average linkage (Lance-Williams update), the merged cluster takes the place of `i`.
+        for k in (0..n).filter(|k| *k != i && clusters[*k].is_some()) {
+            let distance =
+                (size_i * distances[i][k] + size_j * distances[j][k]) / (size_i + size_j);
+            distances[i][k] = distance;
+            distances[k][i] = distance;
+        }
+        if let Some(cluster) = clusters[i].as_mut() {
+            cluster.extend(merged);
+        }
+    }
+
+    clusters.into_iter().flatten().flatten().collect()
+}
@@ -0,0 +1,201 @@
+//! Outlier Visualizations Module
+//!
+//! This module handles the generation of the strip plots for the outlier analysis, with the
+//! points beyond the IQR and z-score fences highlighted.
+
+use super::{
+    create_basic_chart_template, create_drawing_backend, fill_background, PlotTheme, LABEL_STYLE,
+    PLOT_CAPTION_FONT, PLOT_MARGIN, PLOT_WIDTH, X_LABEL_AREA_SIZE,
+};
+use crate::data::outliers::{OutlierAnalysis, OutlierFences};
+use plotters::coord::Shift;
+use plotters::prelude::*;
+use polars::prelude::*;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// The height of the strip plots.
+pub const STRIP_PLOT_HEIGHT: u32 = 400;
+/// The color of values inside the fences.
+pub const INLIER_COLOR: RGBColor = RGBColor(120, 120, 120);
+/// The color of values beyond the IQR fences only.
+pub const IQR_OUTLIER_COLOR: RGBColor = RGBColor(255, 140, 0);
+/// The color of values beyond the z-score fences.
+pub const Z_OUTLIER_COLOR: RGBColor = RED;
+
+#[derive(Error, Debug)]
+pub enum OutlierPlotError {
+    /// Occurs when the feature values can't be read from the dataframe.
+    #[error("Error reading feature values: {0}")]
+    FeatureValues(#[from] PolarsError),
+
+    /// Occurs during failure to draw a chart.
+    #[error("Error building the plot: {0}")]
+    PlotDrawing(String),
+}
+
+/// Gets the visualization title for a feature's outlier strip plot.
+pub fn outlier_plot_title(feature: &str) -> String {
+    format!("{} Outliers", feature)
+}
+
+/// Creates the outlier strip plots for the numerical features.
+///
+/// ### Parameters
+///
+/// - `df`: Reference to the (sampled) dataset.
+/// - `outlier_analysis`: Reference to the outlier analysis for the dataset.
+/// - `max_features`: The maximum number of features to plot, the first ones in column order.
+/// - `skipped_columns`: The features opting out of the plots.
+/// - `plot_dir`: Directory where the plots should be saved.
+/// - `theme`: The plot theme to render the plots with.
+///
+/// ### Returns
+///
+/// - `Result<HashMap<String, PathBuf>, OutlierPlotError>`: Map of plot titles to the output file
+///   paths, or an `OutlierPlotError`.
+pub fn build_all_visualizations(
+    df: &DataFrame,
+    outlier_analysis: &OutlierAnalysis,
+    max_features: usize,
+    skipped_columns: &[String],
+    plot_dir: &Path,
+    theme: &PlotTheme,
+) -> Result<HashMap<String, PathBuf>, OutlierPlotError> {
+    let mut plots = HashMap::new();
+    for (feature, fences) in outlier_analysis
+        .fences
+        .iter()
+        .filter(|(feature, _)| !skipped_columns.contains(feature))
+        .take(max_features)
+    {
+        let values: Vec<f64> = df
+            .column(feature)?
+            .cast(&DataType::Float64)?
+            .f64()?
+            .into_iter()
+            .flatten()
+            .filter(|value| value.is_finite())
+            .collect();
+        let output_path = plot_dir.join(format!("{}_outliers.png", feature));
+        build_outlier_strip_plot(feature, &values, fences, &output_path, theme)?;
+        plots.insert(outlier_plot_title(feature), output_path);
+    }
+    Ok(plots)
+}
+
+/// Creates a strip plot of a feature's values, with the fences drawn as vertical lines and the
+/// values beyond them highlighted.
+///
+/// ### Parameters
+///
+/// - `feature`: The feature name.
+/// - `values`: The feature values.
+/// - `fences`: The outlier fences for the feature.
+/// - `output_path`: Reference to the `PathBuf` where the plot should be saved.
+/// - `theme`: The plot theme to render the plot with.
+///
+/// ### Returns
+///
+/// - `Result<(), OutlierPlotError>`: Unit type or an `OutlierPlotError`.
+pub fn build_outlier_strip_plot(
+    feature: &str,
+    values: &[f64],
+    fences: &OutlierFences,
+    output_path: &PathBuf,
+    theme: &PlotTheme,
+) -> Result<(), OutlierPlotError> {
+    let root = create_drawing_backend(output_path, (PLOT_WIDTH, STRIP_PLOT_HEIGHT), theme);
+    draw_outlier_strip_plot(&root, feature, values, fences, theme)?;
+    root.present()
+        .map_err(|e| OutlierPlotError::PlotDrawing(e.to_string()))?;
+    Ok(())
+}
+
+/// Draws a strip plot of a feature's values onto a drawing area.
+///
+/// ### Parameters
+///
+/// - `root`: The drawing area to draw onto, laid out for `(PLOT_WIDTH, STRIP_PLOT_HEIGHT)`.
+/// - `feature`: The feature name.
+/// - `values`: The feature values.
+/// - `fences`: The outlier fences for the feature.
+/// - `theme`: The plot theme to render the plot with.
+///
+/// ### Returns
+///
+/// - `Result<(), OutlierPlotError>`: Unit type or an `OutlierPlotError`.
+pub fn draw_outlier_strip_plot<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
+    feature: &str,
+    values: &[f64],
+    fences: &OutlierFences,
+    theme: &PlotTheme,
+) -> Result<(), OutlierPlotError> {
+    // Include the fences in the x range so they're always drawn.
+    let (min, max) = values.iter().fold(
+        (
+            fences.iqr_lower.min(fences.z_lower),
+            fences.iqr_upper.max(fences.z_upper),
+        ),
+        |(min, max), value| (min.min(*value), max.max(*value)),
+    );
+    let padding = if max > min { (max - min) * 0.05 } else { 1.0 };
+
+    fill_background(root, &WHITE, None)
+        .map_err(|e| OutlierPlotError::PlotDrawing(e.to_string()))?;
+
+    let title = outlier_plot_title(feature);
+    let mut chart = create_basic_chart_template(
+        root,
+        theme,
+        &title,
+        PLOT_CAPTION_FONT,
+        PLOT_MARGIN,
+        X_LABEL_AREA_SIZE,
+        0,
+        ((min - padding)..(max + padding), 0.0..1.0),
+    )
+    .map_err(|e| OutlierPlotError::PlotDrawing(e.to_string()))?;
+
+    chart
+        .configure_mesh()
+        .disable_y_mesh()
+        .disable_y_axis()
+        .x_desc(feature)
+        .x_label_style(theme.font(LABEL_STYLE))
+        .draw()
+        .map_err(|e| OutlierPlotError::PlotDrawing(e.to_string()))?;
+
+    for (fence, color) in [
+        (fences.iqr_lower, IQR_OUTLIER_COLOR),
+        (fences.iqr_upper, IQR_OUTLIER_COLOR),
+        (fences.z_lower, Z_OUTLIER_COLOR),
+        (fences.z_upper, Z_OUTLIER_COLOR),
+    ] {
+        chart
+            .draw_series(LineSeries::new(
+                [(fence, 0.0), (fence, 1.0)],
+                color.stroke_width(theme.px(2)),
+            ))
+            .map_err(|e| OutlierPlotError::PlotDrawing(e.to_string()))?;
+    }
+
+    // Jitter the points vertically with a low discrepancy sequence so the plot is reproducible.
+    chart
+        .draw_series(values.iter().enumerate().map(|(index, value)| {
+            let jitter = 0.1 + 0.8 * ((index as f64 * 0.618_033_988_75) % 1.0);
+            let color = if fences.is_z_outlier(*value) {
+                Z_OUTLIER_COLOR.filled()
+            } else if fences.is_iqr_outlier(*value) {
+                IQR_OUTLIER_COLOR.filled()
+            } else {
+                INLIER_COLOR.mix(0.4).filled()
+            };
+            Circle::new((*value, jitter), theme.px(3), color)
+        }))
+        .map_err(|e| OutlierPlotError::PlotDrawing(e.to_string()))?;
+
+    Ok(())
+}
@@ -0,0 +1,212 @@
+//! PCA Visualizations Module
+//!
+//! This module handles the generation of the scree plot for the principal component analysis.
+
+use super::{
+    create_basic_chart_template, create_drawing_backend, fill_background, PlotTheme, LABEL_STYLE,
+    PLOT_CAPTION_FONT, PLOT_HEIGHT, PLOT_MARGIN, PLOT_WIDTH, X_LABEL_AREA_SIZE, Y_LABEL_AREA_SIZE,
+};
+use crate::data::pca::{PcaAnalysis, EXPLAINED_VARIANCE_TARGET};
+use plotters::coord::Shift;
+use plotters::prelude::*;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// The title of the scree plot.
+pub const SCREE_PLOT_TITLE: &str = "Scree Plot";
+
+#[derive(Error, Debug)]
+pub enum PcaPlotError {
+    /// Occurs during failure to draw a chart.
+    #[error("Error building the plot: {0}")]
+    PlotDrawing(String),
+}
+
+/// Creates the PCA plots.
+///
+/// ### Parameters
+///
+/// - `pca_analysis`: Reference to the PCA results for the dataset.
+/// - `plot_dir`: Directory where the plots should be saved.
+/// - `theme`: The plot theme to render the plots with.
+///
+/// ### Returns
+///
+/// - `Result<HashMap<String, PathBuf>, PcaPlotError>`: Map of plot titles to the output file
+///   paths, or a `PcaPlotError`.
+pub fn build_all_visualizations(
+    pca_analysis: &PcaAnalysis,
+    plot_dir: &Path,
+    theme: &PlotTheme,
+) -> Result<HashMap<String, PathBuf>, PcaPlotError> {
+    let mut plots = HashMap::new();
+    if !pca_analysis.components.is_empty() {
+        let output_path = plot_dir.join("scree_plot.png");
+        build_scree_plot(pca_analysis, &output_path, theme)?;
+        plots.insert(SCREE_PLOT_TITLE.to_owned(), output_path);
+    }
+    Ok(plots)
+}
+
+/// Creates a scree plot with the explained variance ratio of each component as bars and the
+/// cumulative explained variance ratio as a line, with the `EXPLAINED_VARIANCE_TARGET` marked.
+///
+/// ### Parameters
+///
+/// - `pca_analysis`: Reference to the PCA results for the dataset.
+/// - `output_path`: Reference to the `PathBuf` where the plot should be saved.
+/// - `theme`: The plot theme to render the plot with.
+///
+/// ### Returns
+///
+/// - `Result<(), PcaPlotError>`: Unit type or a `PcaPlotError`.
+pub fn build_scree_plot(
+    pca_analysis: &PcaAnalysis,
+    output_path: &PathBuf,
+    theme: &PlotTheme,
+) -> Result<(), PcaPlotError> {
+    let root = create_drawing_backend(output_path, (PLOT_WIDTH, PLOT_HEIGHT), theme);
+    draw_scree_plot(&root, pca_analysis, theme)?;
+    root.present()
+        .map_err(|e| PcaPlotError::PlotDrawing(e.to_string()))?;
+    Ok(())
+}
+
+/// Draws a scree plot with the explained variance ratio of each component as bars and the
+/// cumulative explained variance ratio as a line onto a drawing area.
+///
+/// ### Parameters
+///
+/// - `root`: The drawing area to draw onto, laid out for `(PLOT_WIDTH, PLOT_HEIGHT)`.
+/// - `pca_analysis`: Reference to the PCA results for the dataset.
+/// - `theme`: The plot theme to render the plot with.
+///
+/// ### Returns
+///
+/// - `Result<(), PcaPlotError>`: Unit type or a `PcaPlotError`.
+pub fn draw_scree_plot<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
+    pca_analysis: &PcaAnalysis,
+    theme: &PlotTheme,
+) -> Result<(), PcaPlotError> {
+    let n_components = pca_analysis.components.len();
+
+    fill_background(root, &WHITE, None).map_err(|e| PcaPlotError::PlotDrawing(e.to_string()))?;
+
+    let mut chart = create_basic_chart_template(
+        root,
+        theme,
+        SCREE_PLOT_TITLE,
+        PLOT_CAPTION_FONT,
+        PLOT_MARGIN,
+        X_LABEL_AREA_SIZE,
+        Y_LABEL_AREA_SIZE,
+        (0.5..(n_components as f64 + 0.5), 0.0..1.05),
+    )
+    .map_err(|e| PcaPlotError::PlotDrawing(e.to_string()))?;
+
+    chart
+        .configure_mesh()
+        .disable_x_mesh()
+        .x_desc("Principal component")
+        .x_label_style(theme.font(LABEL_STYLE))
+        .x_labels(n_components.min(20))
+        .x_label_formatter(&|x| {
+            if x.fract().abs() < f64::EPSILON {
+                format!("PC{}", *x as usize)
+            } else {
+                String::new()
+            }
+        })
+        .y_desc("Explained variance ratio")
+        .y_label_style(theme.font(LABEL_STYLE))
+        .draw()
+        .map_err(|e| PcaPlotError::PlotDrawing(e.to_string()))?;
+
+    chart
+        .draw_series(
+            pca_analysis
+                .components
+                .iter()
+                .enumerate()
+                .map(|(index, component)| {
+                    let x = index as f64 + 1.0;
+                    Rectangle::new(
+                        [
+                            (x - 0.35, 0.0),
+                            (x + 0.35, component.explained_variance_ratio),
+                        ],
+                        BLUE.mix(0.7).filled(),
+                    )
+                }),
+        )
+        .map_err(|e| PcaPlotError::PlotDrawing(e.to_string()))?
+        .label("Explained")
+        .legend(move |(x, y)| {
+            Rectangle::new(
+                [
+                    (x, y - theme.px(5) as i32),
+                    (x + theme.px(10) as i32, y + theme.px(5) as i32),
+                ],
+                BLUE.mix(0.7).filled(),
+            )
+        });
+
+    let cumulative: Vec<(f64, f64)> = pca_analysis
+        .components
+        .iter()
+        .enumerate()
+        .map(|(index, component)| (index as f64 + 1.0, component.cumulative_variance_ratio))
+        .collect();
+    chart
+        .draw_series(LineSeries::new(
+            cumulative.clone(),
+            RED.stroke_width(theme.px(2)),
+        ))
+        .map_err(|e| PcaPlotError::PlotDrawing(e.to_string()))?
+        .label("Cumulative")
+        .legend(move |(x, y)| {
+            PathElement::new(
+                [(x, y), (x + theme.px(10) as i32, y)],
+                RED.stroke_width(theme.px(2)),
+            )
+        });
+    chart
+        .draw_series(
+            cumulative
+                .into_iter()
+                .map(|point| Circle::new(point, theme.px(4), RED.filled())),
+        )
+        .map_err(|e| PcaPlotError::PlotDrawing(e.to_string()))?;
+
+    chart
+        .draw_series(DashedLineSeries::new(
+            [
+                (0.5, EXPLAINED_VARIANCE_TARGET),
+                (n_components as f64 + 0.5, EXPLAINED_VARIANCE_TARGET),
+            ],
+            8,
+            4,
+            BLACK.mix(0.5).stroke_width(theme.px(1)),
+        ))
+        .map_err(|e| PcaPlotError::PlotDrawing(e.to_string()))?
+        .label(format!(
+            "{:.0}% of the variance",
+            EXPLAINED_VARIANCE_TARGET * 100.0
+        ))
+        .legend(move |(x, y)| {
+            PathElement::new([(x, y), (x + theme.px(10) as i32, y)], BLACK.mix(0.5))
+        });
+
+    chart
+        .configure_series_labels()
+        .position(SeriesLabelPosition::MiddleRight)
+        .background_style(WHITE.mix(0.8))
+        .border_style(BLACK)
+        .label_font(theme.font(LABEL_STYLE))
+        .draw()
+        .map_err(|e| PcaPlotError::PlotDrawing(e.to_string()))?;
+
+    Ok(())
+}
@@ -0,0 +1,289 @@
+//! Session Visualizations Module
+//!
+//! This module handles the generation of the plots for the sessionized event log profile.
+
+use super::{
+    create_basic_chart_template, create_drawing_backend, fill_background, PlotTheme, LABEL_STYLE,
+    PLOT_CAPTION_FONT, PLOT_HEIGHT, PLOT_MARGIN, PLOT_WIDTH, X_LABEL_AREA_SIZE, Y_LABEL_AREA_SIZE,
+};
+use crate::data::sessions::{inter_event_bucket_labels, SessionAnalysis, INTER_EVENT_BUCKETS};
+use plotters::coord::Shift;
+use plotters::prelude::*;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// The number of bins of the events per entity histogram.
+pub const HISTOGRAM_BINS: u64 = 30;
+
+#[derive(Error, Debug)]
+pub enum SessionPlotError {
+    /// Occurs during failure to draw a chart.
+    #[error("Error building the plot: {0}")]
+    PlotDrawing(String),
+}
+
+/// Gets the visualization title for the events per entity histogram.
+pub fn events_per_entity_plot_title(entity: &str) -> String {
+    format!("Events per {}", entity)
+}
+
+/// Gets the visualization title for the inter-event time bar chart.
+pub fn inter_event_times_plot_title() -> String {
+    "Inter-Event Times".to_owned()
+}
+
+/// Creates the session plots.
+///
+/// ### Parameters
+///
+/// - `session_analysis`: Reference to the sessionized event log profile.
+/// - `plot_dir`: Directory where the plots should be saved.
+/// - `theme`: The plot theme to render the plots with.
+///
+/// ### Returns
+///
+/// - `Result<HashMap<String, PathBuf>, SessionPlotError>`: Map of plot titles to the output file
+///   paths, or a `SessionPlotError`.
+pub fn build_all_visualizations(
+    session_analysis: &SessionAnalysis,
+    plot_dir: &Path,
+    theme: &PlotTheme,
+) -> Result<HashMap<String, PathBuf>, SessionPlotError> {
+    let mut plots = HashMap::new();
+    if !session_analysis.entity_events.is_empty() {
+        let output_path = plot_dir.join(format!(
+            "{}_events_per_entity.png",
+            session_analysis.entity_column
+        ));
+        build_events_per_entity_plot(
+            &session_analysis.entity_column,
+            &session_analysis.entity_events,
+            &output_path,
+            theme,
+        )?;
+        plots.insert(
+            events_per_entity_plot_title(&session_analysis.entity_column),
+            output_path,
+        );
+    }
+    if !session_analysis.inter_event_times.is_empty() {
+        let output_path = plot_dir.join(format!(
+            "{}_inter_event_times.png",
+            session_analysis.timestamp_column
+        ));
+        build_inter_event_times_plot(
+            &session_analysis.inter_event_counts,
+            session_analysis.gap,
+            &output_path,
+            theme,
+        )?;
+        plots.insert(inter_event_times_plot_title(), output_path);
+    }
+    Ok(plots)
+}
+
+/// Creates a histogram of the number of events of each entity.
+///
+/// ### Parameters
+///
+/// - `entity`: The entity column name.
+/// - `entity_events`: The number of events of each entity, sorted in ascending order.
+/// - `output_path`: Reference to the `PathBuf` where the plot should be saved.
+/// - `theme`: The plot theme to render the plot with.
+///
+/// ### Returns
+///
+/// - `Result<(), SessionPlotError>`: Unit type or a `SessionPlotError`.
+pub fn build_events_per_entity_plot(
+    entity: &str,
+    entity_events: &[u64],
+    output_path: &PathBuf,
+    theme: &PlotTheme,
+) -> Result<(), SessionPlotError> {
+    let root = create_drawing_backend(output_path, (PLOT_WIDTH, PLOT_HEIGHT), theme);
+    draw_events_per_entity_plot(&root, entity, entity_events, theme)?;
+    root.present()
+        .map_err(|e| SessionPlotError::PlotDrawing(e.to_string()))?;
+    Ok(())
+}
+
+/// Draws a histogram of the number of events of each entity onto a drawing area.
+///
+/// ### Parameters
+///
+/// - `root`: The drawing area to draw onto, laid out for `(PLOT_WIDTH, PLOT_HEIGHT)`.
+/// - `entity`: The entity column name.
+/// - `entity_events`: The number of events of each entity, sorted in ascending order.
+/// - `theme`: The plot theme to render the plot with.
+///
+/// ### Returns
+///
+/// - `Result<(), SessionPlotError>`: Unit type or a `SessionPlotError`.
+pub fn draw_events_per_entity_plot<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
+    entity: &str,
+    entity_events: &[u64],
+    theme: &PlotTheme,
+) -> Result<(), SessionPlotError> {
+    let min = entity_events.first().copied().unwrap_or(0);
+    let max = entity_events.last().copied().unwrap_or(0);
+    // Integer bins, so each bin covers the same number of event counts.
+    let bin_width = (max - min + 1).div_ceil(HISTOGRAM_BINS).max(1);
+    let n_bins = ((max - min) / bin_width + 1) as usize;
+    let mut counts = vec![0u64; n_bins];
+    for events in entity_events {
+        counts[((events - min) / bin_width) as usize] += 1;
+    }
+    let max_count = counts.iter().copied().max().unwrap_or(0).max(1);
+
+    fill_background(root, &WHITE, None)
+        .map_err(|e| SessionPlotError::PlotDrawing(e.to_string()))?;
+
+    let title = events_per_entity_plot_title(entity);
+    let x_end = min + bin_width * n_bins as u64;
+    let mut chart = create_basic_chart_template(
+        root,
+        theme,
+        &title,
+        PLOT_CAPTION_FONT,
+        PLOT_MARGIN,
+        X_LABEL_AREA_SIZE,
+        Y_LABEL_AREA_SIZE,
+        (min..x_end, 0u64..(max_count + max_count / 10 + 1)),
+    )
+    .map_err(|e| SessionPlotError::PlotDrawing(e.to_string()))?;
+
+    chart
+        .configure_mesh()
+        .disable_x_mesh()
+        .x_desc("Events")
+        .x_label_style(theme.font(LABEL_STYLE))
+        .y_desc(format!("Count of {}", entity))
+        .y_label_style(theme.font(LABEL_STYLE))
+        .draw()
+        .map_err(|e| SessionPlotError::PlotDrawing(e.to_string()))?;
+
+    chart
+        .draw_series(counts.iter().enumerate().map(|(bin, count)| {
+            let lower = min + bin_width * bin as u64;
+            let mut bar = Rectangle::new(
+                [(lower, 0), (lower + bin_width, *count)],
+                BLUE.mix(0.7).filled(),
+            );
+            bar.set_margin(0, 0, theme.px(1), theme.px(1));
+            bar
+        }))
+        .map_err(|e| SessionPlotError::PlotDrawing(e.to_string()))?;
+
+    Ok(())
+}
+
+/// Creates a bar chart of the number of inter-event times in each `INTER_EVENT_BUCKETS` bucket.
+/// The buckets above the session gap (the times that start a new session) are drawn in red.
+///
+/// ### Parameters
+///
+/// - `inter_event_counts`: The number of inter-event times in each bucket.
+/// - `gap`: The session gap, in milliseconds.
+/// - `output_path`: Reference to the `PathBuf` where the plot should be saved.
+/// - `theme`: The plot theme to render the plot with.
+///
+/// ### Returns
+///
+/// - `Result<(), SessionPlotError>`: Unit type or a `SessionPlotError`.
+pub fn build_inter_event_times_plot(
+    inter_event_counts: &[u64],
+    gap: i64,
+    output_path: &PathBuf,
+    theme: &PlotTheme,
+) -> Result<(), SessionPlotError> {
+    let root = create_drawing_backend(output_path, (PLOT_WIDTH, PLOT_HEIGHT), theme);
+    draw_inter_event_times_plot(&root, inter_event_counts, gap, theme)?;
+    root.present()
+        .map_err(|e| SessionPlotError::PlotDrawing(e.to_string()))?;
+    Ok(())
+}
+
+/// Draws a bar chart of the number of inter-event times in each `INTER_EVENT_BUCKETS` bucket onto
+/// a drawing area.
+///
+/// ### Parameters
+///
+/// - `root`: The drawing area to draw onto, laid out for `(PLOT_WIDTH, PLOT_HEIGHT)`.
+/// - `inter_event_counts`: The number of inter-event times in each bucket.
+/// - `gap`: The session gap, in milliseconds.
+/// - `theme`: The plot theme to render the plot with.
+///
+/// ### Returns
+///
+/// - `Result<(), SessionPlotError>`: Unit type or a `SessionPlotError`.
+pub fn draw_inter_event_times_plot<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
+    inter_event_counts: &[u64],
+    gap: i64,
+    theme: &PlotTheme,
+) -> Result<(), SessionPlotError> {
+    let labels = inter_event_bucket_labels();
+    let max_count = inter_event_counts.iter().copied().max().unwrap_or(0).max(1);
+
+    fill_background(root, &WHITE, None)
+        .map_err(|e| SessionPlotError::PlotDrawing(e.to_string()))?;
+
+    let title = inter_event_times_plot_title();
+    let mut chart = create_basic_chart_template(
+        root,
+        theme,
+        &title,
+        PLOT_CAPTION_FONT,
+        PLOT_MARGIN,
+        X_LABEL_AREA_SIZE,
+        Y_LABEL_AREA_SIZE,
+        (
+            // The segmented range includes its end, so stop at the last bucket index.
+            (0..labels.len().saturating_sub(1)).into_segmented(),
+            0u64..(max_count + max_count / 10 + 1),
+        ),
+    )
+    .map_err(|e| SessionPlotError::PlotDrawing(e.to_string()))?;
+
+    chart
+        .configure_mesh()
+        .disable_x_mesh()
+        .x_desc("Time between consecutive events")
+        .x_label_style(theme.font(LABEL_STYLE))
+        .x_labels(labels.len())
+        .x_label_formatter(&|segment| match segment {
+            SegmentValue::CenterOf(index) => labels.get(*index).cloned().unwrap_or_default(),
+            _ => String::new(),
+        })
+        .y_desc("Count")
+        .y_label_style(theme.font(LABEL_STYLE))
+        .draw()
+        .map_err(|e| SessionPlotError::PlotDrawing(e.to_string()))?;
+
+    chart
+        .draw_series(inter_event_counts.iter().enumerate().map(|(index, count)| {
+            // A bucket is above the gap if its lower bound is at least the gap.
+            let lower = index
+                .checked_sub(1)
+                .map_or(0, |previous| INTER_EVENT_BUCKETS[previous]);
+            let color = if lower >= gap {
+                RED.mix(0.7).filled()
+            } else {
+                BLUE.mix(0.7).filled()
+            };
+            let mut bar = Rectangle::new(
+                [
+                    (SegmentValue::Exact(index), 0),
+                    (SegmentValue::Exact(index + 1), *count),
+                ],
+                color,
+            );
+            bar.set_margin(0, 0, theme.px(5), theme.px(5));
+            bar
+        }))
+        .map_err(|e| SessionPlotError::PlotDrawing(e.to_string()))?;
+
+    Ok(())
+}
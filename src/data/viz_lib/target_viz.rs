@@ -0,0 +1,323 @@
+//! Target Visualizations Module
+//!
+//! This module handles the generation of the plots for the target analysis in supervised mode.
+
+use super::{
+    create_basic_chart_template, create_drawing_backend, fill_background, PlotTheme, _FONT,
+    LABEL_STYLE, PLOT_CAPTION_FONT, PLOT_HEIGHT, PLOT_MARGIN, PLOT_WIDTH, X_LABEL_AREA_SIZE,
+    Y_LABEL_AREA_SIZE,
+};
+use crate::data::target::{ClassDistribution, TargetAnalysis, MINORITY_CLASS_THRESHOLD};
+use indexmap::IndexMap;
+use plotters::coord::Shift;
+use plotters::prelude::*;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// The font for the imbalance ratio annotation.
+pub const ANNOTATION_FONT: (&str, u32) = (_FONT, 22);
+/// The maximum number of features shown in the mutual information chart.
+pub const MAX_MI_FEATURES: usize = 20;
+/// The width of the feature name label area of the mutual information chart.
+pub const FEATURE_LABEL_AREA_SIZE: u32 = 200;
+
+#[derive(Error, Debug)]
+pub enum TargetPlotError {
+    /// Occurs during failure to draw a chart.
+    #[error("Error building the plot: {0}")]
+    PlotDrawing(String),
+}
+
+/// Gets the visualization title for the target's class distribution plot.
+pub fn class_distribution_plot_title(target: &str) -> String {
+    format!("{} Class Distribution", target)
+}
+
+/// Gets the visualization title for the mutual information ranking of the features.
+pub fn mutual_information_plot_title(target: &str) -> String {
+    format!("{} Mutual Information", target)
+}
+
+/// Creates the target analysis plots.
+///
+/// ### Parameters
+///
+/// - `target_analysis`: Reference to the target analysis for the dataset.
+/// - `plot_dir`: Directory where the plots should be saved.
+/// - `theme`: The plot theme to render the plots with.
+///
+/// ### Returns
+///
+/// - `Result<HashMap<String, PathBuf>, TargetPlotError>`: Map of plot titles to the output file
+///   paths, or a `TargetPlotError`.
+pub fn build_all_visualizations(
+    target_analysis: &TargetAnalysis,
+    plot_dir: &Path,
+    theme: &PlotTheme,
+) -> Result<HashMap<String, PathBuf>, TargetPlotError> {
+    let mut plots = HashMap::new();
+    if let Some(distribution) = &target_analysis.class_distribution {
+        let output_path =
+            plot_dir.join(format!("{}_class_distribution.png", target_analysis.column));
+        build_class_distribution_plot(&target_analysis.column, distribution, &output_path, theme)?;
+        plots.insert(
+            class_distribution_plot_title(&target_analysis.column),
+            output_path,
+        );
+    }
+    if !target_analysis.mutual_information.is_empty() {
+        let output_path =
+            plot_dir.join(format!("{}_mutual_information.png", target_analysis.column));
+        build_mutual_information_plot(
+            &target_analysis.column,
+            &target_analysis.mutual_information,
+            &output_path,
+            theme,
+        )?;
+        plots.insert(
+            mutual_information_plot_title(&target_analysis.column),
+            output_path,
+        );
+    }
+    Ok(plots)
+}
+
+/// Creates a bar chart of the number of rows per class, annotated with the imbalance ratio.
+/// Classes below `MINORITY_CLASS_THRESHOLD` are drawn in red.
+///
+/// ### Parameters
+///
+/// - `target`: The target column name.
+/// - `distribution`: The class distribution of the target.
+/// - `output_path`: Reference to the `PathBuf` where the plot should be saved.
+/// - `theme`: The plot theme to render the plot with.
+///
+/// ### Returns
+///
+/// - `Result<(), TargetPlotError>`: Unit type or a `TargetPlotError`.
+pub fn build_class_distribution_plot(
+    target: &str,
+    distribution: &ClassDistribution,
+    output_path: &PathBuf,
+    theme: &PlotTheme,
+) -> Result<(), TargetPlotError> {
+    let root = create_drawing_backend(output_path, (PLOT_WIDTH, PLOT_HEIGHT), theme);
+    draw_class_distribution_plot(&root, target, distribution, theme)?;
+    root.present()
+        .map_err(|e| TargetPlotError::PlotDrawing(e.to_string()))?;
+    Ok(())
+}
+
+/// Draws a bar chart of the number of rows per class onto a drawing area.
+///
+/// ### Parameters
+///
+/// - `root`: The drawing area to draw onto, laid out for `(PLOT_WIDTH, PLOT_HEIGHT)`.
+/// - `target`: The target column name.
+/// - `distribution`: The class distribution of the target.
+/// - `theme`: The plot theme to render the plot with.
+///
+/// ### Returns
+///
+/// - `Result<(), TargetPlotError>`: Unit type or a `TargetPlotError`.
+pub fn draw_class_distribution_plot<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
+    target: &str,
+    distribution: &ClassDistribution,
+    theme: &PlotTheme,
+) -> Result<(), TargetPlotError> {
+    let classes: Vec<&String> = distribution.counts.keys().collect();
+    let total: u64 = distribution.counts.values().sum();
+    let max_count = distribution
+        .counts
+        .values()
+        .copied()
+        .max()
+        .unwrap_or(0)
+        .max(1);
+
+    fill_background(root, &WHITE, None).map_err(|e| TargetPlotError::PlotDrawing(e.to_string()))?;
+
+    let title = class_distribution_plot_title(target);
+    let mut chart = create_basic_chart_template(
+        root,
+        theme,
+        &title,
+        PLOT_CAPTION_FONT,
+        PLOT_MARGIN,
+        X_LABEL_AREA_SIZE,
+        Y_LABEL_AREA_SIZE,
+        (
+            // The segmented range includes its end, so stop at the last class index.
+            (0..classes.len().saturating_sub(1)).into_segmented(),
+            0u64..(max_count + max_count / 10 + 1),
+        ),
+    )
+    .map_err(|e| TargetPlotError::PlotDrawing(e.to_string()))?;
+
+    chart
+        .configure_mesh()
+        .disable_x_mesh()
+        .x_desc(target)
+        .x_label_style(theme.font(LABEL_STYLE))
+        .x_labels(classes.len())
+        .x_label_formatter(&|segment| match segment {
+            SegmentValue::CenterOf(index) => classes
+                .get(*index)
+                .map(|class| class.to_string())
+                .unwrap_or_default(),
+            _ => String::new(),
+        })
+        .y_desc("Count")
+        .y_label_style(theme.font(LABEL_STYLE))
+        .draw()
+        .map_err(|e| TargetPlotError::PlotDrawing(e.to_string()))?;
+
+    chart
+        .draw_series(
+            distribution
+                .counts
+                .values()
+                .enumerate()
+                .map(|(index, count)| {
+                    let percentage = *count as f64 / total as f64 * 100.0;
+                    let color = if percentage < MINORITY_CLASS_THRESHOLD {
+                        RED.mix(0.7).filled()
+                    } else {
+                        BLUE.mix(0.7).filled()
+                    };
+                    let mut bar = Rectangle::new(
+                        [
+                            (SegmentValue::Exact(index), 0),
+                            (SegmentValue::Exact(index + 1), *count),
+                        ],
+                        color,
+                    );
+                    bar.set_margin(0, 0, theme.px(5), theme.px(5));
+                    bar
+                }),
+        )
+        .map_err(|e| TargetPlotError::PlotDrawing(e.to_string()))?;
+
+    // Annotate the imbalance ratio in the top right corner of the plotting area.
+    let (x_range, _) = chart.plotting_area().get_pixel_range();
+    root.draw(&Text::new(
+        format!("Imbalance ratio: {:.2}:1", distribution.imbalance_ratio),
+        (x_range.end - theme.px(300) as i32, theme.px(60) as i32),
+        theme.font(ANNOTATION_FONT).into_font(),
+    ))
+    .map_err(|e| TargetPlotError::PlotDrawing(e.to_string()))?;
+
+    Ok(())
+}
+
+/// Creates a horizontal bar chart ranking the features by their mutual information with the
+/// target. Only the `MAX_MI_FEATURES` most informative features are shown.
+///
+/// ### Parameters
+///
+/// - `target`: The target column name.
+/// - `mutual_information`: The mutual information keyed by feature name, sorted in descending
+///   order.
+/// - `output_path`: Reference to the `PathBuf` where the plot should be saved.
+/// - `theme`: The plot theme to render the plot with.
+///
+/// ### Returns
+///
+/// - `Result<(), TargetPlotError>`: Unit type or a `TargetPlotError`.
+pub fn build_mutual_information_plot(
+    target: &str,
+    mutual_information: &IndexMap<String, f64>,
+    output_path: &PathBuf,
+    theme: &PlotTheme,
+) -> Result<(), TargetPlotError> {
+    let root = create_drawing_backend(output_path, (PLOT_WIDTH, PLOT_HEIGHT), theme);
+    draw_mutual_information_plot(&root, target, mutual_information, theme)?;
+    root.present()
+        .map_err(|e| TargetPlotError::PlotDrawing(e.to_string()))?;
+    Ok(())
+}
+
+/// Draws a horizontal bar chart ranking the features by their mutual information with the target
+/// onto a drawing area.
+///
+/// ### Parameters
+///
+/// - `root`: The drawing area to draw onto, laid out for `(PLOT_WIDTH, PLOT_HEIGHT)`.
+/// - `target`: The target column name.
+/// - `mutual_information`: The mutual information keyed by feature name, sorted in descending
+///   order.
+/// - `theme`: The plot theme to render the plot with.
+///
+/// ### Returns
+///
+/// - `Result<(), TargetPlotError>`: Unit type or a `TargetPlotError`.
+pub fn draw_mutual_information_plot<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
+    target: &str,
+    mutual_information: &IndexMap<String, f64>,
+    theme: &PlotTheme,
+) -> Result<(), TargetPlotError> {
+    let features: Vec<(&String, &f64)> = mutual_information.iter().take(MAX_MI_FEATURES).collect();
+    let max_score = features
+        .iter()
+        .map(|(_, score)| **score)
+        .fold(0.0, f64::max)
+        .max(f64::EPSILON);
+
+    fill_background(root, &WHITE, None).map_err(|e| TargetPlotError::PlotDrawing(e.to_string()))?;
+
+    let title = mutual_information_plot_title(target);
+    // The most informative feature is drawn at the top.
+    let mut chart = create_basic_chart_template(
+        root,
+        theme,
+        &title,
+        PLOT_CAPTION_FONT,
+        PLOT_MARGIN,
+        X_LABEL_AREA_SIZE,
+        FEATURE_LABEL_AREA_SIZE,
+        (
+            0.0..(max_score * 1.1),
+            (0..features.len().saturating_sub(1)).into_segmented(),
+        ),
+    )
+    .map_err(|e| TargetPlotError::PlotDrawing(e.to_string()))?;
+
+    chart
+        .configure_mesh()
+        .disable_y_mesh()
+        .x_desc("Mutual information (nats)")
+        .x_label_style(theme.font(LABEL_STYLE))
+        .y_labels(features.len())
+        .y_label_style(theme.font(LABEL_STYLE))
+        .y_label_formatter(&|segment| match segment {
+            SegmentValue::CenterOf(index) => features
+                .len()
+                .checked_sub(index + 1)
+                .and_then(|rank| features.get(rank))
+                .map(|(feature, _)| feature.to_string())
+                .unwrap_or_default(),
+            _ => String::new(),
+        })
+        .draw()
+        .map_err(|e| TargetPlotError::PlotDrawing(e.to_string()))?;
+
+    chart
+        .draw_series(features.iter().enumerate().map(|(rank, (_, score))| {
+            let index = features.len() - rank - 1;
+            let mut bar = Rectangle::new(
+                [
+                    (0.0, SegmentValue::Exact(index)),
+                    (**score, SegmentValue::Exact(index + 1)),
+                ],
+                GREEN.mix(0.7).filled(),
+            );
+            bar.set_margin(theme.px(3), theme.px(3), 0, 0);
+            bar
+        }))
+        .map_err(|e| TargetPlotError::PlotDrawing(e.to_string()))?;
+
+    Ok(())
+}
@@ -0,0 +1,651 @@
+//! Time Series Visualizations Module
+//!
+//! This module handles the generation of the plots for the time series analysis.
+
+use super::{
+    create_basic_chart_template, create_drawing_backend, fill_background, PlotTheme, LABEL_STYLE,
+    PLOT_CAPTION_FONT, PLOT_HEIGHT, PLOT_MARGIN, PLOT_WIDTH, X_LABEL_AREA_SIZE, Y_LABEL_AREA_SIZE,
+};
+use crate::data::time_series::{
+    format_timestamp, Autocorrelation, RollingStatistics, SeasonalDecomposition, TimeSeriesAnalysis,
+};
+use plotters::coord::Shift;
+use plotters::prelude::*;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// The height of the gap timeline.
+pub const TIMELINE_PLOT_HEIGHT: u32 = 400;
+/// The number of timestamp labels on the timeline axis.
+pub const TIMELINE_X_LABELS: usize = 6;
+/// The color of the covered stretches of the timeline.
+pub const COVERED_COLOR: RGBColor = RGBColor(34, 139, 34);
+/// The color of the gaps.
+pub const GAP_COLOR: RGBColor = RED;
+/// The color of the duplicated timestamps.
+pub const DUPLICATE_COLOR: RGBColor = RGBColor(255, 140, 0);
+/// The color of the rolling mean line and standard deviation band.
+pub const ROLLING_COLOR: RGBColor = RGBColor(31, 119, 180);
+/// The height of the stacked decomposition plot.
+pub const DECOMPOSITION_PLOT_HEIGHT: u32 = 1200;
+/// Maximum number of samples drawn per decomposition panel.
+pub const MAX_DECOMPOSITION_POINTS: usize = 2000;
+
+#[derive(Error, Debug)]
+pub enum TimeSeriesPlotError {
+    /// Occurs during failure to draw a chart.
+    #[error("Error building the plot: {0}")]
+    PlotDrawing(String),
+}
+
+/// Gets the visualization title for the gap timeline of a time index.
+pub fn gap_timeline_plot_title(index: &str) -> String {
+    format!("{} Gap Timeline", index)
+}
+
+/// Gets the visualization title for the rolling statistics of a feature.
+pub fn rolling_plot_title(feature: &str) -> String {
+    format!("{} Rolling Statistics", feature)
+}
+
+/// Gets the visualization title for the autocorrelation plots of a feature.
+pub fn autocorrelation_plot_title(feature: &str) -> String {
+    format!("{} Autocorrelation", feature)
+}
+
+/// Gets the visualization title for the seasonal decomposition of a feature.
+pub fn decomposition_plot_title(feature: &str) -> String {
+    format!("{} Seasonal Decomposition", feature)
+}
+
+/// Creates the time series visualizations.
+///
+/// ### Parameters
+///
+/// - `time_series_analysis`: Reference to the time series analysis for the dataset.
+/// - `plot_dir`: Directory where the plots should be saved.
+/// - `theme`: The plot theme to render the plots with.
+///
+/// ### Returns
+///
+/// - `Result<HashMap<String, PathBuf>, TimeSeriesPlotError>`: Map of plot titles to the output
+///   file paths, or a `TimeSeriesPlotError`.
+pub fn build_all_visualizations(
+    time_series_analysis: &TimeSeriesAnalysis,
+    plot_dir: &Path,
+    theme: &PlotTheme,
+) -> Result<HashMap<String, PathBuf>, TimeSeriesPlotError> {
+    let mut plots = HashMap::new();
+    let index = &time_series_analysis.index;
+    if time_series_analysis.interval.is_some() {
+        let output_path = plot_dir.join(format!("{}_gap_timeline.png", index));
+        build_gap_timeline(time_series_analysis, &output_path, theme)?;
+        plots.insert(gap_timeline_plot_title(index), output_path);
+    }
+    for (feature, statistics) in &time_series_analysis.rolling {
+        let output_path = plot_dir.join(format!("{}_rolling.png", feature));
+        build_rolling_plot(
+            feature,
+            index,
+            time_series_analysis.window,
+            statistics,
+            &output_path,
+            theme,
+        )?;
+        plots.insert(rolling_plot_title(feature), output_path);
+    }
+    for (feature, autocorrelation) in &time_series_analysis.autocorrelation {
+        let output_path = plot_dir.join(format!("{}_autocorrelation.png", feature));
+        build_autocorrelation_plot(feature, autocorrelation, &output_path, theme)?;
+        plots.insert(autocorrelation_plot_title(feature), output_path);
+    }
+    if let Some(decomposition) = &time_series_analysis.decomposition {
+        let feature = &decomposition.feature;
+        let output_path = plot_dir.join(format!("{}_decomposition.png", feature));
+        build_decomposition_plot(index, decomposition, &output_path, theme)?;
+        plots.insert(decomposition_plot_title(feature), output_path);
+    }
+    Ok(plots)
+}
+
+/// Creates a timeline of the time index, with the covered stretches in green, the gaps in red,
+/// and the duplicated timestamps marked above.
+///
+/// ### Parameters
+///
+/// - `time_series_analysis`: Reference to the time series analysis for the dataset.
+/// - `output_path`: Reference to the `PathBuf` where the plot should be saved.
+/// - `theme`: The plot theme to render the plot with.
+///
+/// ### Returns
+///
+/// - `Result<(), TimeSeriesPlotError>`: Unit type or a `TimeSeriesPlotError`.
+pub fn build_gap_timeline(
+    time_series_analysis: &TimeSeriesAnalysis,
+    output_path: &PathBuf,
+    theme: &PlotTheme,
+) -> Result<(), TimeSeriesPlotError> {
+    if time_series_analysis.first.is_none() || time_series_analysis.last.is_none() {
+        return Ok(());
+    }
+    let root = create_drawing_backend(output_path, (PLOT_WIDTH, TIMELINE_PLOT_HEIGHT), theme);
+    draw_gap_timeline(&root, time_series_analysis, theme)?;
+    root.present()
+        .map_err(|e| TimeSeriesPlotError::PlotDrawing(e.to_string()))?;
+    Ok(())
+}
+
+/// Draws a timeline of the time index onto a drawing area.
+///
+/// ### Parameters
+///
+/// - `root`: The drawing area to draw onto, laid out for `(PLOT_WIDTH, TIMELINE_PLOT_HEIGHT)`.
+/// - `time_series_analysis`: Reference to the time series analysis for the dataset.
+/// - `theme`: The plot theme to render the plot with.
+///
+/// ### Returns
+///
+/// - `Result<(), TimeSeriesPlotError>`: Unit type or a `TimeSeriesPlotError`.
+pub fn draw_gap_timeline<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
+    time_series_analysis: &TimeSeriesAnalysis,
+    theme: &PlotTheme,
+) -> Result<(), TimeSeriesPlotError> {
+    let (Some(first), Some(last)) = (time_series_analysis.first, time_series_analysis.last) else {
+        return Ok(());
+    };
+    let padding = ((last - first) / 50).max(1);
+
+    fill_background(root, &WHITE, None)
+        .map_err(|e| TimeSeriesPlotError::PlotDrawing(e.to_string()))?;
+
+    let title = gap_timeline_plot_title(&time_series_analysis.index);
+    let mut chart = create_basic_chart_template(
+        root,
+        theme,
+        &title,
+        PLOT_CAPTION_FONT,
+        PLOT_MARGIN,
+        X_LABEL_AREA_SIZE,
+        0,
+        ((first - padding)..(last + padding), 0.0..1.0),
+    )
+    .map_err(|e| TimeSeriesPlotError::PlotDrawing(e.to_string()))?;
+
+    chart
+        .configure_mesh()
+        .disable_y_mesh()
+        .disable_y_axis()
+        .x_desc(&time_series_analysis.index)
+        .x_labels(TIMELINE_X_LABELS)
+        .x_label_formatter(&|timestamp| format_timestamp(*timestamp))
+        .x_label_style(theme.font(LABEL_STYLE))
+        .draw()
+        .map_err(|e| TimeSeriesPlotError::PlotDrawing(e.to_string()))?;
+
+    // The covered stretches run between the gaps.
+    let gaps = &time_series_analysis.gaps;
+    let mut covered = Vec::with_capacity(gaps.len() + 1);
+    let mut start = first;
+    for gap in gaps {
+        covered.push((start, gap.start));
+        start = gap.end;
+    }
+    covered.push((start, last));
+
+    chart
+        .draw_series(covered.iter().map(|(start, end)| {
+            Rectangle::new([(*start, 0.35), (*end, 0.65)], COVERED_COLOR.filled())
+        }))
+        .map_err(|e| TimeSeriesPlotError::PlotDrawing(e.to_string()))?
+        .label("Covered")
+        .legend(move |(x, y)| {
+            Rectangle::new(
+                [
+                    (x, y - theme.px(5) as i32),
+                    (x + theme.px(15) as i32, y + theme.px(5) as i32),
+                ],
+                COVERED_COLOR.filled(),
+            )
+        });
+
+    chart
+        .draw_series(
+            gaps.iter().map(|gap| {
+                Rectangle::new([(gap.start, 0.25), (gap.end, 0.75)], GAP_COLOR.filled())
+            }),
+        )
+        .map_err(|e| TimeSeriesPlotError::PlotDrawing(e.to_string()))?
+        .label("Gap")
+        .legend(move |(x, y)| {
+            Rectangle::new(
+                [
+                    (x, y - theme.px(5) as i32),
+                    (x + theme.px(15) as i32, y + theme.px(5) as i32),
+                ],
+                GAP_COLOR.filled(),
+            )
+        });
+
+    chart
+        .draw_series(
+            time_series_analysis
+                .duplicate_timestamps
+                .iter()
+                .map(|(timestamp, _)| {
+                    TriangleMarker::new((*timestamp, 0.85), theme.px(6), DUPLICATE_COLOR.filled())
+                }),
+        )
+        .map_err(|e| TimeSeriesPlotError::PlotDrawing(e.to_string()))?
+        .label("Duplicated timestamp")
+        .legend(move |(x, y)| {
+            TriangleMarker::new(
+                (x + theme.px(7) as i32, y),
+                theme.px(6),
+                DUPLICATE_COLOR.filled(),
+            )
+        });
+
+    chart
+        .configure_series_labels()
+        .position(SeriesLabelPosition::LowerRight)
+        .background_style(WHITE.mix(0.8))
+        .border_style(BLACK)
+        .label_font(theme.font(LABEL_STYLE))
+        .draw()
+        .map_err(|e| TimeSeriesPlotError::PlotDrawing(e.to_string()))?;
+
+    Ok(())
+}
+
+/// Creates a line plot of a feature's rolling mean over the time index, with a band of one
+/// rolling standard deviation around it.
+///
+/// ### Parameters
+///
+/// - `feature`: The feature name.
+/// - `index`: The time index column name.
+/// - `window`: The number of samples in a rolling window.
+/// - `statistics`: The rolling statistics of the feature.
+/// - `output_path`: Reference to the `PathBuf` where the plot should be saved.
+/// - `theme`: The plot theme to render the plot with.
+///
+/// ### Returns
+///
+/// - `Result<(), TimeSeriesPlotError>`: Unit type or a `TimeSeriesPlotError`.
+pub fn build_rolling_plot(
+    feature: &str,
+    index: &str,
+    window: usize,
+    statistics: &RollingStatistics,
+    output_path: &PathBuf,
+    theme: &PlotTheme,
+) -> Result<(), TimeSeriesPlotError> {
+    let root = create_drawing_backend(output_path, (PLOT_WIDTH, PLOT_HEIGHT), theme);
+    draw_rolling_plot(&root, feature, index, window, statistics, theme)?;
+    root.present()
+        .map_err(|e| TimeSeriesPlotError::PlotDrawing(e.to_string()))?;
+    Ok(())
+}
+
+/// Draws a line plot of a feature's rolling mean over the time index onto a drawing area.
+///
+/// ### Parameters
+///
+/// - `root`: The drawing area to draw onto, laid out for `(PLOT_WIDTH, PLOT_HEIGHT)`.
+/// - `feature`: The feature name.
+/// - `index`: The time index column name.
+/// - `window`: The number of samples in a rolling window.
+/// - `statistics`: The rolling statistics of the feature.
+/// - `theme`: The plot theme to render the plot with.
+///
+/// ### Returns
+///
+/// - `Result<(), TimeSeriesPlotError>`: Unit type or a `TimeSeriesPlotError`.
+pub fn draw_rolling_plot<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
+    feature: &str,
+    index: &str,
+    window: usize,
+    statistics: &RollingStatistics,
+    theme: &PlotTheme,
+) -> Result<(), TimeSeriesPlotError> {
+    let (Some(first), Some(last)) = (statistics.timestamps.first(), statistics.timestamps.last())
+    else {
+        return Ok(());
+    };
+    let (min, max) = statistics.mean.iter().zip(&statistics.std_dev).fold(
+        (f64::INFINITY, f64::NEG_INFINITY),
+        |(min, max), (mean, std_dev)| (min.min(mean - std_dev), max.max(mean + std_dev)),
+    );
+    let padding = if max > min { (max - min) * 0.05 } else { 1.0 };
+
+    fill_background(root, &WHITE, None)
+        .map_err(|e| TimeSeriesPlotError::PlotDrawing(e.to_string()))?;
+
+    let title = rolling_plot_title(feature);
+    let mut chart = create_basic_chart_template(
+        root,
+        theme,
+        &title,
+        PLOT_CAPTION_FONT,
+        PLOT_MARGIN,
+        X_LABEL_AREA_SIZE,
+        Y_LABEL_AREA_SIZE,
+        (
+            *first..(*last).max(first + 1),
+            (min - padding)..(max + padding),
+        ),
+    )
+    .map_err(|e| TimeSeriesPlotError::PlotDrawing(e.to_string()))?;
+
+    chart
+        .configure_mesh()
+        .x_desc(index)
+        .x_labels(TIMELINE_X_LABELS)
+        .x_label_formatter(&|timestamp| format_timestamp(*timestamp))
+        .x_label_style(theme.font(LABEL_STYLE))
+        .y_desc(feature)
+        .y_label_style(theme.font(LABEL_STYLE))
+        .draw()
+        .map_err(|e| TimeSeriesPlotError::PlotDrawing(e.to_string()))?;
+
+    // The band runs along the upper edge and back along the lower edge.
+    let points = || {
+        statistics
+            .timestamps
+            .iter()
+            .zip(statistics.mean.iter().zip(&statistics.std_dev))
+    };
+    let band: Vec<(i64, f64)> = points()
+        .map(|(timestamp, (mean, std_dev))| (*timestamp, mean + std_dev))
+        .chain(
+            points()
+                .rev()
+                .map(|(timestamp, (mean, std_dev))| (*timestamp, mean - std_dev)),
+        )
+        .collect();
+    chart
+        .draw_series(std::iter::once(Polygon::new(
+            band,
+            ROLLING_COLOR.mix(0.2).filled(),
+        )))
+        .map_err(|e| TimeSeriesPlotError::PlotDrawing(e.to_string()))?
+        .label(format!("Rolling std dev ({} samples)", window))
+        .legend(move |(x, y)| {
+            Rectangle::new(
+                [
+                    (x, y - theme.px(5) as i32),
+                    (x + theme.px(15) as i32, y + theme.px(5) as i32),
+                ],
+                ROLLING_COLOR.mix(0.2).filled(),
+            )
+        });
+
+    chart
+        .draw_series(LineSeries::new(
+            points().map(|(timestamp, (mean, _))| (*timestamp, *mean)),
+            ROLLING_COLOR.stroke_width(theme.px(2)),
+        ))
+        .map_err(|e| TimeSeriesPlotError::PlotDrawing(e.to_string()))?
+        .label(format!("Rolling mean ({} samples)", window))
+        .legend(move |(x, y)| {
+            PathElement::new(
+                [(x, y), (x + theme.px(15) as i32, y)],
+                ROLLING_COLOR.stroke_width(theme.px(2)),
+            )
+        });
+
+    chart
+        .configure_series_labels()
+        .position(SeriesLabelPosition::UpperRight)
+        .background_style(WHITE.mix(0.8))
+        .border_style(BLACK)
+        .label_font(theme.font(LABEL_STYLE))
+        .draw()
+        .map_err(|e| TimeSeriesPlotError::PlotDrawing(e.to_string()))?;
+
+    Ok(())
+}
+
+/// Creates the autocorrelation (top) and partial autocorrelation (bottom) plots of a feature,
+/// with the coefficients drawn as stems and the 95% confidence band shaded around 0.
+///
+/// ### Parameters
+///
+/// - `feature`: The feature name.
+/// - `autocorrelation`: The autocorrelation of the feature.
+/// - `output_path`: Reference to the `PathBuf` where the plot should be saved.
+/// - `theme`: The plot theme to render the plot with.
+///
+/// ### Returns
+///
+/// - `Result<(), TimeSeriesPlotError>`: Unit type or a `TimeSeriesPlotError`.
+pub fn build_autocorrelation_plot(
+    feature: &str,
+    autocorrelation: &Autocorrelation,
+    output_path: &PathBuf,
+    theme: &PlotTheme,
+) -> Result<(), TimeSeriesPlotError> {
+    let root = create_drawing_backend(output_path, (PLOT_WIDTH, PLOT_HEIGHT), theme);
+    draw_autocorrelation_plot(&root, feature, autocorrelation, theme)?;
+    root.present()
+        .map_err(|e| TimeSeriesPlotError::PlotDrawing(e.to_string()))?;
+    Ok(())
+}
+
+/// Draws the autocorrelation (top) and partial autocorrelation (bottom) plots of a feature onto a
+/// drawing area.
+///
+/// ### Parameters
+///
+/// - `root`: The drawing area to draw onto, laid out for `(PLOT_WIDTH, PLOT_HEIGHT)`.
+/// - `feature`: The feature name.
+/// - `autocorrelation`: The autocorrelation of the feature.
+/// - `theme`: The plot theme to render the plot with.
+///
+/// ### Returns
+///
+/// - `Result<(), TimeSeriesPlotError>`: Unit type or a `TimeSeriesPlotError`.
+pub fn draw_autocorrelation_plot<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
+    feature: &str,
+    autocorrelation: &Autocorrelation,
+    theme: &PlotTheme,
+) -> Result<(), TimeSeriesPlotError> {
+    fill_background(root, &WHITE, None)
+        .map_err(|e| TimeSeriesPlotError::PlotDrawing(e.to_string()))?;
+    let panels = root.split_evenly((2, 1));
+
+    for (panel, (name, coefficients)) in panels.iter().zip([
+        ("Autocorrelation", &autocorrelation.acf),
+        ("Partial Autocorrelation", &autocorrelation.pacf),
+    ]) {
+        let max_lag = coefficients.len() as i32;
+        let title = format!("{} {}", feature, name);
+        let mut chart = create_basic_chart_template(
+            panel,
+            theme,
+            &title,
+            (PLOT_CAPTION_FONT.0, PLOT_CAPTION_FONT.1 * 2 / 3),
+            PLOT_MARGIN,
+            X_LABEL_AREA_SIZE,
+            Y_LABEL_AREA_SIZE,
+            (0..max_lag + 1, -1.0..1.0),
+        )
+        .map_err(|e| TimeSeriesPlotError::PlotDrawing(e.to_string()))?;
+
+        chart
+            .configure_mesh()
+            .disable_x_mesh()
+            .x_desc("Lag")
+            .x_label_style(theme.font(LABEL_STYLE))
+            .y_desc(name)
+            .y_label_style(theme.font(LABEL_STYLE))
+            .draw()
+            .map_err(|e| TimeSeriesPlotError::PlotDrawing(e.to_string()))?;
+
+        chart
+            .draw_series(std::iter::once(Rectangle::new(
+                [
+                    (0, -autocorrelation.confidence),
+                    (max_lag + 1, autocorrelation.confidence),
+                ],
+                ROLLING_COLOR.mix(0.2).filled(),
+            )))
+            .map_err(|e| TimeSeriesPlotError::PlotDrawing(e.to_string()))?;
+
+        chart
+            .draw_series(LineSeries::new([(0, 0.0), (max_lag + 1, 0.0)], BLACK))
+            .map_err(|e| TimeSeriesPlotError::PlotDrawing(e.to_string()))?;
+
+        chart
+            .draw_series(coefficients.iter().enumerate().map(|(lag, coefficient)| {
+                PathElement::new(
+                    [(lag as i32 + 1, 0.0), (lag as i32 + 1, *coefficient)],
+                    ROLLING_COLOR.stroke_width(theme.px(2)),
+                )
+            }))
+            .map_err(|e| TimeSeriesPlotError::PlotDrawing(e.to_string()))?;
+
+        chart
+            .draw_series(coefficients.iter().enumerate().map(|(lag, coefficient)| {
+                let color = if coefficient.abs() > autocorrelation.confidence {
+                    GAP_COLOR
+                } else {
+                    ROLLING_COLOR
+                };
+                Circle::new((lag as i32 + 1, *coefficient), theme.px(4), color.filled())
+            }))
+            .map_err(|e| TimeSeriesPlotError::PlotDrawing(e.to_string()))?;
+    }
+
+    Ok(())
+}
+
+/// Creates the stacked plot of the observed, trend, seasonal, and residual components of the
+/// seasonal decomposition. Long series are thinned to `MAX_DECOMPOSITION_POINTS` samples.
+///
+/// ### Parameters
+///
+/// - `index`: The time index column name.
+/// - `decomposition`: The seasonal decomposition of the primary series.
+/// - `output_path`: Reference to the `PathBuf` where the plot should be saved.
+/// - `theme`: The plot theme to render the plot with.
+///
+/// ### Returns
+///
+/// - `Result<(), TimeSeriesPlotError>`: Unit type or a `TimeSeriesPlotError`.
+pub fn build_decomposition_plot(
+    index: &str,
+    decomposition: &SeasonalDecomposition,
+    output_path: &PathBuf,
+    theme: &PlotTheme,
+) -> Result<(), TimeSeriesPlotError> {
+    let root = create_drawing_backend(output_path, (PLOT_WIDTH, DECOMPOSITION_PLOT_HEIGHT), theme);
+    draw_decomposition_plot(&root, index, decomposition, theme)?;
+    root.present()
+        .map_err(|e| TimeSeriesPlotError::PlotDrawing(e.to_string()))?;
+    Ok(())
+}
+
+/// Draws the stacked plot of the observed, trend, seasonal, and residual components of the
+/// seasonal decomposition onto a drawing area.
+///
+/// ### Parameters
+///
+/// - `root`: The drawing area to draw onto, laid out for `(PLOT_WIDTH, DECOMPOSITION_PLOT_HEIGHT)`.
+/// - `index`: The time index column name.
+/// - `decomposition`: The seasonal decomposition of the primary series.
+/// - `theme`: The plot theme to render the plot with.
+///
+/// ### Returns
+///
+/// - `Result<(), TimeSeriesPlotError>`: Unit type or a `TimeSeriesPlotError`.
+pub fn draw_decomposition_plot<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
+    index: &str,
+    decomposition: &SeasonalDecomposition,
+    theme: &PlotTheme,
+) -> Result<(), TimeSeriesPlotError> {
+    let timestamps = &decomposition.timestamps;
+    let (Some(first), Some(last)) = (timestamps.first(), timestamps.last()) else {
+        return Ok(());
+    };
+    let stride = timestamps.len().div_ceil(MAX_DECOMPOSITION_POINTS);
+
+    fill_background(root, &WHITE, None)
+        .map_err(|e| TimeSeriesPlotError::PlotDrawing(e.to_string()))?;
+    let panels = root.split_evenly((4, 1));
+
+    let components: [(&str, Vec<Option<f64>>); 4] = [
+        (
+            "Observed",
+            decomposition.observed.iter().copied().map(Some).collect(),
+        ),
+        ("Trend", decomposition.trend.clone()),
+        (
+            "Seasonal",
+            decomposition.seasonal.iter().copied().map(Some).collect(),
+        ),
+        ("Residual", decomposition.residual.clone()),
+    ];
+    for (position, (panel, (name, values))) in panels.iter().zip(components).enumerate() {
+        let points: Vec<(i64, f64)> = timestamps
+            .iter()
+            .zip(&values)
+            .step_by(stride)
+            .filter_map(|(timestamp, value)| Some((*timestamp, (*value)?)))
+            .collect();
+        let (min, max) = points.iter().fold(
+            (f64::INFINITY, f64::NEG_INFINITY),
+            |(min, max), (_, value)| (min.min(*value), max.max(*value)),
+        );
+        let padding = if max > min { (max - min) * 0.05 } else { 1.0 };
+
+        // Only the top panel gets the title and only the bottom panel gets the time axis.
+        let title = if position == 0 {
+            decomposition_plot_title(&decomposition.feature)
+        } else {
+            String::new()
+        };
+        let is_bottom = position == 3;
+        let mut chart = create_basic_chart_template(
+            panel,
+            theme,
+            &title,
+            PLOT_CAPTION_FONT,
+            PLOT_MARGIN,
+            if is_bottom { X_LABEL_AREA_SIZE } else { 0 },
+            Y_LABEL_AREA_SIZE,
+            (
+                *first..(*last).max(first + 1),
+                (min - padding)..(max + padding),
+            ),
+        )
+        .map_err(|e| TimeSeriesPlotError::PlotDrawing(e.to_string()))?;
+
+        let mut mesh = chart.configure_mesh();
+        mesh.y_desc(name)
+            .y_labels(5)
+            .y_label_style(theme.font(LABEL_STYLE))
+            .x_labels(TIMELINE_X_LABELS)
+            .x_label_formatter(&|timestamp| format_timestamp(*timestamp))
+            .x_label_style(theme.font(LABEL_STYLE));
+        if is_bottom {
+            mesh.x_desc(index);
+        }
+        mesh.draw()
+            .map_err(|e| TimeSeriesPlotError::PlotDrawing(e.to_string()))?;
+
+        chart
+            .draw_series(LineSeries::new(
+                points,
+                ROLLING_COLOR.stroke_width(theme.px(1)),
+            ))
+            .map_err(|e| TimeSeriesPlotError::PlotDrawing(e.to_string()))?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,234 @@
+//! Transformation Visualizations Module
+//!
+//! This module handles the generation of the before and after histograms for the suggested
+//! feature transformations.
+
+use super::{
+    create_basic_chart_template, create_drawing_backend, fill_background, PlotTheme, _FONT,
+    LABEL_STYLE, PLOT_HEIGHT, PLOT_MARGIN, PLOT_WIDTH, X_LABEL_AREA_SIZE, Y_LABEL_AREA_SIZE,
+};
+use crate::data::transformations::{Transform, TransformationAnalysis};
+use plotters::coord::Shift;
+use plotters::prelude::*;
+use polars::prelude::*;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// The number of histogram bins.
+pub const HISTOGRAM_BINS: usize = 30;
+/// The caption font for each histogram panel.
+pub const PANEL_CAPTION_FONT: (&str, u32) = (_FONT, 28);
+
+#[derive(Error, Debug)]
+pub enum TransformationPlotError {
+    /// Occurs when the feature values can't be read from the dataframe.
+    #[error("Error reading feature values: {0}")]
+    FeatureValues(#[from] PolarsError),
+
+    /// Occurs during failure to draw a chart.
+    #[error("Error building the plot: {0}")]
+    PlotDrawing(String),
+}
+
+/// Gets the visualization title for a feature's transformation histograms.
+pub fn transformation_plot_title(feature: &str) -> String {
+    format!("{} Transformation", feature)
+}
+
+/// Creates the before and after histograms for the features with a transformation suggestion.
+///
+/// ### Parameters
+///
+/// - `df`: Reference to the (sampled) dataset.
+/// - `transformation_analysis`: Reference to the transformation suggestions for the dataset.
+/// - `max_features`: The maximum number of features to plot, the first suggestions in column
+///   order.
+/// - `skipped_columns`: The features opting out of the plots.
+/// - `plot_dir`: Directory where the plots should be saved.
+/// - `theme`: The plot theme to render the plots with.
+///
+/// ### Returns
+///
+/// - `Result<HashMap<String, PathBuf>, TransformationPlotError>`: Map of plot titles to the
+///   output file paths, or a `TransformationPlotError`.
+pub fn build_all_visualizations(
+    df: &DataFrame,
+    transformation_analysis: &TransformationAnalysis,
+    max_features: usize,
+    skipped_columns: &[String],
+    plot_dir: &Path,
+    theme: &PlotTheme,
+) -> Result<HashMap<String, PathBuf>, TransformationPlotError> {
+    let mut plots = HashMap::new();
+    for (feature, suggestion) in transformation_analysis
+        .suggestions
+        .iter()
+        .filter(|(feature, _)| !skipped_columns.contains(feature))
+        .take(max_features)
+    {
+        let values: Vec<f64> = df
+            .column(feature)?
+            .cast(&DataType::Float64)?
+            .f64()?
+            .into_iter()
+            .flatten()
+            .filter(|value| value.is_finite())
+            .collect();
+        let output_path = plot_dir.join(format!("{}_transformation.png", feature));
+        build_transformation_histograms(
+            feature,
+            &values,
+            &suggestion.transform,
+            &output_path,
+            theme,
+        )?;
+        plots.insert(transformation_plot_title(feature), output_path);
+    }
+    Ok(plots)
+}
+
+/// Creates a side by side pair of histograms of a feature before and after a transformation.
+///
+/// ### Parameters
+///
+/// - `feature`: The feature name.
+/// - `values`: The feature values.
+/// - `transform`: The transformation to apply.
+/// - `output_path`: Reference to the `PathBuf` where the plot should be saved.
+/// - `theme`: The plot theme to render the plot with.
+///
+/// ### Returns
+///
+/// - `Result<(), TransformationPlotError>`: Unit type or a `TransformationPlotError`.
+pub fn build_transformation_histograms(
+    feature: &str,
+    values: &[f64],
+    transform: &Transform,
+    output_path: &PathBuf,
+    theme: &PlotTheme,
+) -> Result<(), TransformationPlotError> {
+    let root = create_drawing_backend(output_path, (PLOT_WIDTH, PLOT_HEIGHT), theme);
+    draw_transformation_histograms(&root, feature, values, transform, theme)?;
+    root.present()
+        .map_err(|e| TransformationPlotError::PlotDrawing(e.to_string()))?;
+    Ok(())
+}
+
+/// Draws a side by side pair of histograms of a feature before and after a transformation onto a
+/// drawing area.
+///
+/// ### Parameters
+///
+/// - `root`: The drawing area to draw onto, laid out for `(PLOT_WIDTH, PLOT_HEIGHT)`.
+/// - `feature`: The feature name.
+/// - `values`: The feature values.
+/// - `transform`: The transformation to apply.
+/// - `theme`: The plot theme to render the plot with.
+///
+/// ### Returns
+///
+/// - `Result<(), TransformationPlotError>`: Unit type or a `TransformationPlotError`.
+pub fn draw_transformation_histograms<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
+    feature: &str,
+    values: &[f64],
+    transform: &Transform,
+    theme: &PlotTheme,
+) -> Result<(), TransformationPlotError> {
+    let transformed: Vec<f64> = values
+        .iter()
+        .map(|value| transform.apply(*value))
+        .filter(|value| value.is_finite())
+        .collect();
+
+    fill_background(root, &WHITE, None)
+        .map_err(|e| TransformationPlotError::PlotDrawing(e.to_string()))?;
+    let panels = root.split_evenly((1, 2));
+
+    draw_histogram(
+        &panels[0],
+        &format!("{} (original)", feature),
+        values,
+        &BLUE,
+        theme,
+    )?;
+    draw_histogram(
+        &panels[1],
+        &format!("{} ({})", feature, transform.formula()),
+        &transformed,
+        &GREEN,
+        theme,
+    )?;
+
+    Ok(())
+}
+
+/// Draws a histogram of the values on a drawing area.
+fn draw_histogram<DB: DrawingBackend>(
+    area: &DrawingArea<DB, Shift>,
+    caption: &str,
+    values: &[f64],
+    color: &RGBColor,
+    theme: &PlotTheme,
+) -> Result<(), TransformationPlotError> {
+    let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let (min, max) = if values.is_empty() {
+        (0.0, 1.0)
+    } else if max > min {
+        (min, max)
+    } else {
+        (min - 0.5, max + 0.5)
+    };
+    let bin_width = (max - min) / HISTOGRAM_BINS as f64;
+
+    let mut counts = [0u32; HISTOGRAM_BINS];
+    for value in values {
+        let bin = (((value - min) / bin_width) as usize).min(HISTOGRAM_BINS - 1);
+        counts[bin] += 1;
+    }
+    let max_count = counts.iter().copied().max().unwrap_or(0).max(1);
+
+    let mut chart = create_basic_chart_template(
+        area,
+        theme,
+        caption,
+        PANEL_CAPTION_FONT,
+        PLOT_MARGIN,
+        X_LABEL_AREA_SIZE,
+        Y_LABEL_AREA_SIZE,
+        (min..max, 0u32..(max_count + max_count / 10 + 1)),
+    )
+    .map_err(|e| TransformationPlotError::PlotDrawing(e.to_string()))?;
+
+    chart
+        .configure_mesh()
+        .disable_x_mesh()
+        .x_desc("Value")
+        .x_label_style(theme.font(LABEL_STYLE))
+        .y_desc("Count")
+        .y_label_style(theme.font(LABEL_STYLE))
+        .x_labels(6)
+        .x_label_formatter(&|x| format_tick(*x))
+        .draw()
+        .map_err(|e| TransformationPlotError::PlotDrawing(e.to_string()))?;
+
+    chart
+        .draw_series(counts.iter().enumerate().map(|(bin, count)| {
+            let x0 = min + bin as f64 * bin_width;
+            Rectangle::new([(x0, 0), (x0 + bin_width, *count)], color.mix(0.7).filled())
+        }))
+        .map_err(|e| TransformationPlotError::PlotDrawing(e.to_string()))?;
+
+    Ok(())
+}
+
+/// Formats an axis tick, switching to scientific notation for large magnitudes.
+pub(super) fn format_tick(value: f64) -> String {
+    if value != 0.0 && !(1e-2..1e4).contains(&value.abs()) {
+        format!("{:.1e}", value)
+    } else {
+        format!("{:.2}", value)
+    }
+}
@@ -0,0 +1,135 @@
+//! Trend Visualizations Module
+//!
+//! This module handles the generation of the line plots for metrics tracked across profiling runs.
+
+use super::{
+    create_basic_chart_template, create_drawing_backend, fill_background, PlotTheme, LABEL_STYLE,
+    PLOT_CAPTION_FONT, PLOT_HEIGHT, PLOT_MARGIN, PLOT_WIDTH, X_LABEL_AREA_SIZE, Y_LABEL_AREA_SIZE,
+};
+use plotters::coord::Shift;
+use plotters::prelude::*;
+use std::path::PathBuf;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum TrendPlotError {
+    /// Occurs when there are no points to plot.
+    #[error("No data points to plot")]
+    NoData,
+
+    /// Occurs during failure to draw a chart.
+    #[error("Error building the plot: {0}")]
+    PlotDrawing(String),
+}
+
+/// Creates a line plot of a metric across runs.
+///
+/// ### Parameters
+///
+/// - `points`: The run labels (e.g. timestamps) and metric values, in run order.
+/// - `title`: The plot title.
+/// - `y_desc`: The y axis description.
+/// - `output_path`: Reference to the `PathBuf` where the plot should be saved.
+/// - `theme`: The plot theme to render the plot with.
+///
+/// ### Returns
+///
+/// - `Result<(), TrendPlotError>`: Unit type or a `TrendPlotError`.
+pub fn build_trend_plot(
+    points: &[(String, f64)],
+    title: &str,
+    y_desc: &str,
+    output_path: &PathBuf,
+    theme: &PlotTheme,
+) -> Result<(), TrendPlotError> {
+    // Checked before creating the image so no blank file is left behind.
+    if points.is_empty() {
+        return Err(TrendPlotError::NoData);
+    }
+    let root = create_drawing_backend(output_path, (PLOT_WIDTH, PLOT_HEIGHT), theme);
+    draw_trend_plot(&root, points, title, y_desc, theme)?;
+    root.present()
+        .map_err(|e| TrendPlotError::PlotDrawing(e.to_string()))?;
+    Ok(())
+}
+
+/// Draws a line plot of a metric across runs onto a drawing area.
+///
+/// ### Parameters
+///
+/// - `root`: The drawing area to draw onto, laid out for `(PLOT_WIDTH, PLOT_HEIGHT)`.
+/// - `points`: The run labels (e.g. timestamps) and metric values, in run order.
+/// - `title`: The plot title.
+/// - `y_desc`: The y axis description.
+/// - `theme`: The plot theme to render the plot with.
+///
+/// ### Returns
+///
+/// - `Result<(), TrendPlotError>`: Unit type or a `TrendPlotError`.
+pub fn draw_trend_plot<DB: DrawingBackend>(
+    root: &DrawingArea<DB, Shift>,
+    points: &[(String, f64)],
+    title: &str,
+    y_desc: &str,
+    theme: &PlotTheme,
+) -> Result<(), TrendPlotError> {
+    if points.is_empty() {
+        return Err(TrendPlotError::NoData);
+    }
+
+    // Pad the y range so flat trends are still visible.
+    let (min, max) = points.iter().fold(
+        (f64::INFINITY, f64::NEG_INFINITY),
+        |(min, max), (_, value)| (min.min(*value), max.max(*value)),
+    );
+    let padding = if max > min { (max - min) * 0.1 } else { 1.0 };
+
+    fill_background(root, &WHITE, None).map_err(|e| TrendPlotError::PlotDrawing(e.to_string()))?;
+
+    let mut chart = create_basic_chart_template(
+        root,
+        theme,
+        title,
+        PLOT_CAPTION_FONT,
+        PLOT_MARGIN,
+        X_LABEL_AREA_SIZE,
+        Y_LABEL_AREA_SIZE,
+        (0..points.len().max(2) - 1, (min - padding)..(max + padding)),
+    )
+    .map_err(|e| TrendPlotError::PlotDrawing(e.to_string()))?;
+
+    chart
+        .configure_mesh()
+        .x_desc("Run")
+        .x_label_style(theme.font(LABEL_STYLE))
+        .y_desc(y_desc)
+        .y_label_style(theme.font(LABEL_STYLE))
+        .x_labels(points.len().min(10))
+        .x_label_formatter(&|x| {
+            points
+                .get(*x)
+                .map(|(label, _)| label.clone())
+                .unwrap_or_default()
+        })
+        .draw()
+        .map_err(|e| TrendPlotError::PlotDrawing(e.to_string()))?;
+
+    chart
+        .draw_series(LineSeries::new(
+            points
+                .iter()
+                .enumerate()
+                .map(|(index, (_, value))| (index, *value)),
+            &BLUE,
+        ))
+        .map_err(|e| TrendPlotError::PlotDrawing(e.to_string()))?;
+    chart
+        .draw_series(
+            points.iter().enumerate().map(|(index, (_, value))| {
+                Circle::new((index, *value), theme.px(4), BLUE.filled())
+            }),
+        )
+        .map_err(|e| TrendPlotError::PlotDrawing(e.to_string()))?;
+
+    Ok(())
+}
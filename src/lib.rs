@@ -12,29 +12,53 @@
 //!
 //! ## Direct Dependencies
 //!
+//! - [calamine-0.26](https://docs.rs/calamine/0.26/calamine/index.html) used for reading Excel
+//!   workbooks.
 //! - [chrono-0.4.38](https://docs.rs/chrono/0.4.38/chrono/index.html) used for date and time
-//! functionality.
+//!   functionality.
 //! - [clap-4.5.8](https://docs.rs/clap/4.5.8/clap/index.html) used for command line argument
-//! handling when run in comand line mode.
+//!   handling when run in comand line mode.
 //! - [colored-2.1.0](https://docs.rs/colored/2.1.0/colored/index.html) used for colored terminal text
-//! when run in command line mode.
+//!   when run in command line mode.
+//! - [flate2-1.0](https://docs.rs/flate2/1.0/flate2/index.html) used for reading gzip compressed
+//!   input files.
 //! - [indexmap-2.3.0](https://docs.rs/indexmap/2.3.0/indexmap/index.html) used for ordered hash tables
-//! for processing data columns in a consistent order.
+//!   for processing data columns in a consistent order.
 //! - [indicatif-0.17.8](https://docs.rs/indicatif/0.17.8/indicatif/index.html) used for progress bar
-//! functionality when run in command line mode.
+//!   functionality when run in command line mode.
+//! - [object_store-0.10](https://docs.rs/object_store/0.10/object_store/index.html) used for
+//!   fetching S3 objects (with the `remote` feature).
 //! - [pdfium-render-0.8.22](https://docs.rs/pdfium-render/0.8.22/pdfium_render/index.html) used for Rust
-//! bindings to Pdfium for creating PDF reports.
+//!   bindings to Pdfium for creating PDF reports.
 //! - [polars-0.41.3](https://docs.rs/polars/0.41.3/polars/index.html) used for performing
-//! operations on the dataset.
+//!   operations on the dataset.
 //!   - Opt-in features:
 //!     - [polars-lazy-0.41.3](https://docs.rs/polars-lazy/0.41.3/polars_lazy/index.html) for the
-//!   Polars lazy API.
+//!       Polars lazy API.
 //!     - [polars-parquet-0.41.3](https://docs.rs/polars-parquet/0.41.3/polars_parquet/index.html) for support for reading parquet files.
 //!     - **moment** for kurtosis and skew statistics.
 //!     - **dtype-array** for array data types.
+//!     - **dtype-decimal** for fixed point decimal data types.
+//!     - **dtype-categorical** for categorical and enum data types.
 //!     - **random** for random sampling of the dataset.
+//!     - **round_series** for rounding decimal statistics to the column scale.
+//!     - **aws** for scanning S3 objects (with the `remote` feature).
+//! - [serde-1.0](https://docs.rs/serde/1.0/serde/index.html) for serializing and deserializing
+//!   configuration and results.
+//! - [serde_json-1.0.121](https://docs.rs/serde_json/1.0.121/serde_json/index.html) for writing
+//!   the JSON run summary.
+//! - [serde_yaml-0.9](https://docs.rs/serde_yaml/0.9/serde_yaml/index.html) for reading YAML data
+//!   dictionaries.
+//! - [sqlx-0.8](https://docs.rs/sqlx/0.8/sqlx/index.html) used for reading query results from
+//!   Postgres, MySQL, and SQLite databases (with the `sql` feature).
 //! - [thiserror-1.0.63](https://docs.rs/thiserror/1.0.63/thiserror/index.html) for defining library errors.
 //! - [plotters-0.3.7](https://docs.rs/plotters/latest/plotters/) for generating visualizations.
+//! - [tokio-1](https://docs.rs/tokio/1/tokio/index.html) used as the async runtime for the
+//!   database connections (with the `sql` feature).
+//! - [ureq-2.12.1](https://docs.rs/ureq/2.12.1/ureq/index.html) for sending webhook
+//!   notifications.
+//! - [zstd-0.13](https://docs.rs/zstd/0.13/zstd/index.html) used for reading Zstandard compressed
+//!   input files.
 
 use thiserror::Error;
 
@@ -58,14 +82,148 @@ pub enum LeadsError {
     #[error("Report error -> {0}")]
     Report(#[from] report::pdf::PdfError),
 
+    /// Errors from the report glossary module.
+    #[error("Glossary error -> {0}")]
+    Glossary(#[from] report::glossary::GlossaryError),
+
+    /// Errors from the report history module.
+    #[error("History error -> {0}")]
+    History(#[from] report::history::HistoryError),
+
+    /// Errors from the report metrics module.
+    #[error("Metrics error -> {0}")]
+    Metrics(#[from] report::metrics::MetricsError),
+
+    /// Errors from the report sections module.
+    #[error("Report sections error -> {0}")]
+    Sections(#[from] report::sections::SectionError),
+
+    /// Errors from the report notify module.
+    #[error("Notification error -> {0}")]
+    Notify(#[from] report::notify::NotifyError),
+
     /// Errors from the descriptive analysis module.
     #[error("Descriptive analysis error -> {0}")]
     DescriptiveAnalysis(#[from] data::descriptive::DescriptiveError),
 
-    /// Errors from the missing values analysis module. 
+    /// Errors from the data dictionary module.
+    #[error("Data dictionary error -> {0}")]
+    DataDictionary(#[from] data::dictionary::DictionaryError),
+
+    /// Errors from the missing values analysis module.
     #[error("Missing values analysis error -> {0}")]
     MissingValuesAnalysis(#[from] data::missing_values::MissingValueError),
 
+    /// Errors from the text module.
+    #[error("Text analysis error -> {0}")]
+    TextAnalysis(#[from] data::text::TextError),
+
+    /// Errors from the SQL module.
+    #[cfg(feature = "sql")]
+    #[error("SQL error -> {0}")]
+    Sql(#[from] data::sql::SqlError),
+
+    /// Errors from the remote module.
+    #[cfg(feature = "remote")]
+    #[error("Remote error -> {0}")]
+    Remote(#[from] data::remote::RemoteError),
+
+    /// Errors from the partitions module.
+    #[error("Partition analysis error -> {0}")]
+    PartitionAnalysis(#[from] data::partitions::PartitionError),
+
+    /// Errors from the tables module.
+    #[error("Tables error -> {0}")]
+    Tables(#[from] data::tables::TableError),
+
+    /// Errors from the null-if module.
+    #[error("Null-if error -> {0}")]
+    NullIf(#[from] data::null_if::NullIfError),
+
+    /// Errors from the references module.
+    #[error("Reference list error -> {0}")]
+    References(#[from] data::references::ReferenceError),
+
+    /// Errors from the prep module.
+    #[error("Prep error -> {0}")]
+    Prep(#[from] data::prep::PrepError),
+
+    /// Errors from the provenance module.
+    #[error("Provenance error -> {0}")]
+    Provenance(#[from] data::provenance::ProvenanceError),
+
+    /// Errors from the query module.
+    #[error("Query error -> {0}")]
+    Query(#[from] data::query::QueryError),
+
+    /// Errors from the duplicates module.
+    #[error("Duplicate analysis error -> {0}")]
+    DuplicateAnalysis(#[from] data::duplicates::DuplicateError),
+
+    /// Errors from the drift module.
+    #[error("Drift analysis error -> {0}")]
+    DriftAnalysis(#[from] data::drift::DriftError),
+
+    /// Errors from the freshness module.
+    #[error("Freshness analysis error -> {0}")]
+    FreshnessAnalysis(#[from] data::freshness::FreshnessError),
+
+    /// Errors from the sessions module.
+    #[error("Session analysis error -> {0}")]
+    SessionAnalysis(#[from] data::sessions::SessionError),
+
+    /// Errors from the time series module.
+    #[error("Time series analysis error -> {0}")]
+    TimeSeries(#[from] data::time_series::TimeSeriesError),
+
+    /// Errors from the temporal module.
+    #[error("Temporal analysis error -> {0}")]
+    TemporalAnalysis(#[from] data::temporal::TemporalError),
+
+    /// Errors from the transformations module.
+    #[error("Transformation analysis error -> {0}")]
+    TransformationAnalysis(#[from] data::transformations::TransformationError),
+
+    /// Errors from the associations module.
+    #[error("Association analysis error -> {0}")]
+    AssociationAnalysis(#[from] data::associations::AssociationError),
+
+    /// Errors from the categories module.
+    #[error("Category analysis error -> {0}")]
+    CategoryAnalysis(#[from] data::categories::CategoryError),
+
+    /// Errors from the correlation module.
+    #[error("Correlation analysis error -> {0}")]
+    CorrelationAnalysis(#[from] data::correlation::CorrelationError),
+
+    /// Errors from the collinearity module.
+    #[error("Collinearity analysis error -> {0}")]
+    CollinearityAnalysis(#[from] data::collinearity::CollinearityError),
+
+    /// Errors from the row order module.
+    #[error("Row order analysis error -> {0}")]
+    RowOrderAnalysis(#[from] data::row_order::RowOrderError),
+
+    /// Errors from the outliers module.
+    #[error("Outlier analysis error -> {0}")]
+    OutlierAnalysis(#[from] data::outliers::OutlierError),
+
+    /// Errors from the problem rows module.
+    #[error("Problem rows analysis error -> {0}")]
+    ProblemRowAnalysis(#[from] data::problem_rows::ProblemRowError),
+
+    /// Errors from the robust statistics module.
+    #[error("Robust statistics error -> {0}")]
+    RobustAnalysis(#[from] data::robust::RobustError),
+
+    /// Errors from the PCA module.
+    #[error("PCA error -> {0}")]
+    Pca(#[from] data::pca::PcaError),
+
+    /// Errors from the target module.
+    #[error("Target analysis error -> {0}")]
+    TargetAnalysis(#[from] data::target::TargetError),
+
     /// Errors from the visualiztion module.
     #[error("Visualizations error -> {0}")]
     VisualizationError(#[from] data::visualizations::VisualizationError),
@@ -74,18 +232,33 @@ pub enum LeadsError {
 pub mod data;
 
 pub mod report {
+    pub mod ddl;
+    pub mod diff;
+    pub mod format;
     pub mod glossary;
+    pub mod history;
+    pub mod metrics;
+    pub mod narrative;
+    pub mod notify;
     pub mod pdf;
+    pub mod sections;
+    pub mod snippets;
+    pub mod summary;
+    pub mod tagging;
 }
 
-pub mod spinner;
+pub mod progress;
 
 pub mod prelude {
-    pub use crate::data::base::DataInfo;
+    pub use crate::data::base::{DataInfo, DataOptions};
     pub use crate::data::descriptive::DescriptiveAnalysis;
+    pub use crate::data::dictionary::DataDictionary;
     pub use crate::data::missing_values::MissingValueAnalysis;
+    pub use crate::data::tables::TableSet;
     pub use crate::data::visualizations::VisualizationManager;
-    pub use crate::report::pdf::PageManager;
+    pub use crate::progress::{ProgressCallback, ProgressEvent, Stage};
+    pub use crate::report::glossary::Glossary;
+    pub use crate::report::pdf::{PageContext, PageManager};
     pub use crate::{LeadsError, LeadsResult};
     /// Re-exports.
     pub use pdfium_render::pdfium::Pdfium;
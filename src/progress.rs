@@ -0,0 +1,324 @@
+//! # Progress Module
+//!
+//! Module that handles progress reporting.
+//!
+//! The library reports its progress through a `ProgressCallback`, which is called with a
+//! `ProgressEvent` when a `Stage` of the run starts or finishes and as the steps within a stage
+//! (e.g. the plots of the visualization stage) complete. The callback can be set on the
+//! `DataOptions` for reading and analyzing the dataset and on the `PageManager` for generating
+//! and saving the report.
+//!
+//! For command line mode, `StagedProgress` renders the events as a progress bar with the elapsed
+//! time and an ETA. Each stage is weighted by its rough share of the run time, so the bar
+//! advances proportionally through the stages and through the steps within the current stage.
+
+use colored::*;
+use indicatif::{ProgressBar, ProgressStyle};
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Number of ticks of a full staged progress bar.
+const PROGRESS_LENGTH: u64 = 1000;
+
+/// The stages of a LEADS run, in order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stage {
+    /// Reading the dataset.
+    Reading,
+    /// Running the analyses over the dataset.
+    Analysis,
+    /// Generating the plots.
+    Visualizations,
+    /// Generating the report pages.
+    Report,
+    /// Saving the report.
+    Saving,
+}
+
+impl Stage {
+    /// All the stages, in run order.
+    pub const ALL: [Stage; 5] = [
+        Stage::Reading,
+        Stage::Analysis,
+        Stage::Visualizations,
+        Stage::Report,
+        Stage::Saving,
+    ];
+
+    /// The relative weight of the stage, its rough share of the run time.
+    pub fn weight(&self) -> f64 {
+        match self {
+            Stage::Reading => 1.0,
+            Stage::Analysis => 3.0,
+            Stage::Visualizations => 4.0,
+            Stage::Report => 2.0,
+            Stage::Saving => 1.0,
+        }
+    }
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let stage = match self {
+            Stage::Reading => "Reading in file",
+            Stage::Analysis => "Analyzing data",
+            Stage::Visualizations => "Generating visualizations",
+            Stage::Report => "Generating report",
+            Stage::Saving => "Saving report",
+        };
+        write!(f, "{}", stage)
+    }
+}
+
+/// The progress events reported by the library.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ProgressEvent {
+    /// A stage started.
+    StageStarted(Stage),
+    /// A step within a stage completed.
+    StepCompleted {
+        /// The stage the step belongs to.
+        stage: Stage,
+        /// What the steps of the stage are (e.g. "plots").
+        label: &'static str,
+        /// The number of completed steps.
+        completed: usize,
+        /// The total number of steps in the stage.
+        total: usize,
+    },
+    /// A stage finished.
+    StageFinished(Stage),
+    /// A problem the run recovered from, e.g. a report saved without its accessibility tags.
+    Warning(String),
+}
+
+/// Callback receiving the library's progress events.
+#[derive(Clone)]
+pub struct ProgressCallback(Arc<dyn Fn(&ProgressEvent) + Send + Sync>);
+
+impl ProgressCallback {
+    /// Constructor for the ProgressCallback struct.
+    ///
+    /// ### Parameters
+    ///
+    /// - `callback`: The function to call with each progress event.
+    ///
+    /// ### Returns
+    ///
+    /// - `ProgressCallback`: The wrapped callback.
+    pub fn new(callback: impl Fn(&ProgressEvent) + Send + Sync + 'static) -> Self {
+        Self(Arc::new(callback))
+    }
+
+    /// Calls the callback with a progress event.
+    pub fn emit(&self, event: &ProgressEvent) {
+        (self.0)(event)
+    }
+}
+
+impl fmt::Debug for ProgressCallback {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ProgressCallback")
+    }
+}
+
+/// Tracks the steps of a stage, emitting the progress events to an optional callback.
+pub(crate) struct StageTracker<'a> {
+    callback: Option<&'a ProgressCallback>,
+    stage: Stage,
+    label: &'static str,
+    completed: usize,
+    total: usize,
+}
+
+impl<'a> StageTracker<'a> {
+    /// Starts tracking a stage, emitting its start event.
+    ///
+    /// ### Parameters
+    ///
+    /// - `callback`: The optional callback to emit the events to.
+    /// - `stage`: The stage to track.
+    /// - `label`: What the steps of the stage are.
+    /// - `total`: The total number of steps in the stage.
+    ///
+    /// ### Returns
+    ///
+    /// - `StageTracker`: The tracker for the stage.
+    pub(crate) fn start(
+        callback: Option<&'a ProgressCallback>,
+        stage: Stage,
+        label: &'static str,
+        total: usize,
+    ) -> Self {
+        if let Some(callback) = callback {
+            callback.emit(&ProgressEvent::StageStarted(stage));
+        }
+        Self {
+            callback,
+            stage,
+            label,
+            completed: 0,
+            total,
+        }
+    }
+
+    /// Marks a step of the stage as completed.
+    pub(crate) fn step(&mut self) {
+        self.completed = (self.completed + 1).min(self.total);
+        if let Some(callback) = self.callback {
+            callback.emit(&ProgressEvent::StepCompleted {
+                stage: self.stage,
+                label: self.label,
+                completed: self.completed,
+                total: self.total,
+            });
+        }
+    }
+
+    /// Marks the stage as finished.
+    pub(crate) fn finish(self) {
+        if let Some(callback) = self.callback {
+            callback.emit(&ProgressEvent::StageFinished(self.stage));
+        }
+    }
+}
+
+/// Progress bar rendering the library's progress events by stage, with the elapsed time and ETA.
+pub struct StagedProgress {
+    bar: ProgressBar,
+    stages: Vec<Stage>,
+    /// The summed weight of the finished stages.
+    finished_weight: Arc<Mutex<f64>>,
+}
+
+impl StagedProgress {
+    /// Constructor for the StagedProgress struct.
+    ///
+    /// ### Parameters
+    ///
+    /// - `stages`: The stages the run goes through. Events for other stages update the message
+    ///   but don't move the bar.
+    ///
+    /// ### Returns
+    ///
+    /// - `StagedProgress`: The configured staged progress bar.
+    pub fn new(stages: &[Stage]) -> Self {
+        let bar = ProgressBar::new(PROGRESS_LENGTH);
+        bar.enable_steady_tick(Duration::from_millis(120));
+        bar.set_style(
+            ProgressStyle::default_bar()
+                .tick_strings(&[".", "..", "...", "....", "....."])
+                .template(
+                    "{spinner:.blue} [{elapsed_precise}] [{bar:30.blue/white}] {percent:>3}% \
+                     (ETA {eta}) {msg}",
+                )
+                .unwrap()
+                .progress_chars("=> "),
+        );
+        Self {
+            bar,
+            stages: stages.to_vec(),
+            finished_weight: Arc::new(Mutex::new(0.0)),
+        }
+    }
+
+    /// Gets the underlying progress bar, e.g. to suspend it while printing status messages.
+    pub fn bar(&self) -> &ProgressBar {
+        &self.bar
+    }
+
+    /// Creates the callback driving the progress bar from the library's progress events.
+    pub fn callback(&self) -> ProgressCallback {
+        let bar = self.bar.clone();
+        let finished_weight = Arc::clone(&self.finished_weight);
+        let total_weight: f64 = self.stages.iter().map(Stage::weight).sum();
+        let stages = self.stages.clone();
+        ProgressCallback::new(move |event| {
+            let mut finished_weight = finished_weight.lock().unwrap_or_else(|e| e.into_inner());
+            let tracked = |stage: &Stage| stages.contains(stage);
+            let set_position = |weight: f64| {
+                if total_weight > 0.0 {
+                    bar.set_position((weight / total_weight * PROGRESS_LENGTH as f64) as u64);
+                }
+            };
+            match event {
+                ProgressEvent::StageStarted(stage) => {
+                    bar.set_message(format!("{}...", stage));
+                    set_position(*finished_weight);
+                }
+                ProgressEvent::StepCompleted {
+                    stage,
+                    label,
+                    completed,
+                    total,
+                } => {
+                    bar.set_message(format!("{} ({} {}/{})...", stage, label, completed, total));
+                    if tracked(stage) && *total > 0 {
+                        set_position(
+                            *finished_weight + stage.weight() * *completed as f64 / *total as f64,
+                        );
+                    }
+                }
+                ProgressEvent::StageFinished(stage) => {
+                    if tracked(stage) {
+                        *finished_weight += stage.weight();
+                    }
+                    set_position(*finished_weight);
+                }
+                ProgressEvent::Warning(message) => bar.println(format!("Warning: {}", message)),
+            }
+        })
+    }
+}
+
+/// Creates the progress indicator.
+///
+/// ### Parameters
+///
+/// - `message`: A message to display with the progress indicator.
+///
+/// ### Returns
+///
+/// - `ProgressBar`: The configured progress bar.
+pub fn setup_spinner(message: &str) -> ProgressBar {
+    let spinner = ProgressBar::new_spinner();
+    spinner.enable_steady_tick(Duration::from_millis(120));
+    spinner.set_style(
+        ProgressStyle::default_spinner()
+            .tick_strings(&[".", "..", "...", "....", "....."])
+            .template("{spinner:.blue} {msg}")
+            .unwrap(),
+    );
+    spinner.set_message(message.to_owned());
+    spinner
+}
+
+/// Suspends the spinner to print intermediate status messages.
+///
+/// ### Parameters
+///
+/// - `message`: The message to print.
+/// - `success`: Whether the message is a success or failure message.
+/// - `spinner`: Reference to the progress bar.
+///
+pub fn print_status(message: &str, success: bool, spinner: &ProgressBar) {
+    if success {
+        println!(
+            "{}{}{} {}",
+            "[".bold().white(),
+            "✓".bold().green(),
+            "]".bold().white(),
+            message.green()
+        );
+    } else {
+        eprintln!(
+            "{}{}{} {}",
+            "[".bold().white(),
+            "!".bold().red(),
+            "]".bold().white(),
+            message.red()
+        );
+        spinner.finish();
+    }
+}
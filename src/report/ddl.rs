@@ -0,0 +1,229 @@
+//! # DDL Module
+//!
+//! Handles exporting the inferred dataset schema as a SQL `CREATE TABLE` statement, so an ad-hoc
+//! file can be turned into a database table without hand writing the schema. Column nullability
+//! is derived from the missing values analysis, columns without any missing values are declared
+//! `NOT NULL`.
+//!
+//! Supported dialects are Postgres, BigQuery, and Snowflake.
+
+use crate::prelude::{DataInfo, LeadsError};
+use polars::datatypes::DataType;
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+
+/// The SQL dialects a `CREATE TABLE` statement can be generated for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SqlDialect {
+    /// PostgreSQL.
+    Postgres,
+    /// Google BigQuery.
+    BigQuery,
+    /// Snowflake.
+    Snowflake,
+}
+
+impl FromStr for SqlDialect {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "postgres" | "postgresql" => Ok(SqlDialect::Postgres),
+            "bigquery" => Ok(SqlDialect::BigQuery),
+            "snowflake" => Ok(SqlDialect::Snowflake),
+            other => Err(format!(
+                "unsupported SQL dialect '{}', expected one of: postgres, bigquery, snowflake",
+                other
+            )),
+        }
+    }
+}
+
+impl fmt::Display for SqlDialect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            SqlDialect::Postgres => "postgres",
+            SqlDialect::BigQuery => "bigquery",
+            SqlDialect::Snowflake => "snowflake",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl SqlDialect {
+    /// Quotes an identifier for the dialect, escaping any embedded quote characters.
+    pub fn quote_identifier(&self, identifier: &str) -> String {
+        match self {
+            SqlDialect::BigQuery => format!("`{}`", identifier.replace('`', "\\`")),
+            SqlDialect::Postgres | SqlDialect::Snowflake => {
+                format!("\"{}\"", identifier.replace('"', "\"\""))
+            }
+        }
+    }
+
+    /// Maps a Polars `DataType` to the closest column type in the dialect.
+    ///
+    /// ### Parameters
+    ///
+    /// - `data_type`: The Polars data type of the column.
+    ///
+    /// ### Returns
+    ///
+    /// - `String`: The SQL column type.
+    pub fn column_type(&self, data_type: &DataType) -> String {
+        match self {
+            SqlDialect::Postgres => postgres_type(data_type),
+            SqlDialect::BigQuery => bigquery_type(data_type),
+            SqlDialect::Snowflake => snowflake_type(data_type),
+        }
+    }
+}
+
+/// Generates a `CREATE TABLE` statement for the dataset.
+///
+/// ### Parameters
+///
+/// - `data_info`: The dataset information and analysis results.
+/// - `dialect`: The SQL dialect to generate the statement for.
+///
+/// ### Returns
+///
+/// - `String`: The `CREATE TABLE` statement.
+pub fn create_table_statement(data_info: &DataInfo, dialect: SqlDialect) -> String {
+    let table_name = dialect.quote_identifier(&data_info.data_title.replace(' ', "_"));
+    let columns: Vec<String> = data_info
+        .column_types
+        .iter()
+        .map(|(column_name, data_type)| {
+            let has_missing = data_info
+                .missing_value_analysis
+                .column_missing_values
+                .get(column_name)
+                .is_none_or(|(missing_count, _)| *missing_count > 0);
+            // BigQuery arrays can't be declared NOT NULL, they are never null.
+            let nullable = has_missing
+                || (dialect == SqlDialect::BigQuery
+                    && matches!(data_type, DataType::List(_) | DataType::Array(_, _)));
+            format!(
+                "    {} {}{}",
+                dialect.quote_identifier(column_name),
+                dialect.column_type(data_type),
+                if nullable { "" } else { " NOT NULL" }
+            )
+        })
+        .collect();
+
+    format!(
+        "CREATE TABLE {} (\n{}\n);\n",
+        table_name,
+        columns.join(",\n")
+    )
+}
+
+/// Writes the `CREATE TABLE` statement for the dataset to a file.
+///
+/// ### Parameters
+///
+/// - `data_info`: The dataset information and analysis results.
+/// - `dialect`: The SQL dialect to generate the statement for.
+/// - `path`: The path to write the SQL file to.
+///
+/// ### Returns
+///
+/// - `Result<(), LeadsError>`: Unit type or an IO error.
+pub fn save_to_file(
+    data_info: &DataInfo,
+    dialect: SqlDialect,
+    path: &Path,
+) -> Result<(), LeadsError> {
+    std::fs::write(path, create_table_statement(data_info, dialect))?;
+    Ok(())
+}
+
+fn postgres_type(data_type: &DataType) -> String {
+    match data_type {
+        DataType::Boolean => "BOOLEAN".to_owned(),
+        DataType::Int8 | DataType::Int16 | DataType::UInt8 => "SMALLINT".to_owned(),
+        DataType::Int32 | DataType::UInt16 => "INTEGER".to_owned(),
+        DataType::Int64 | DataType::UInt32 => "BIGINT".to_owned(),
+        DataType::UInt64 => "NUMERIC(20, 0)".to_owned(),
+        DataType::Float32 => "REAL".to_owned(),
+        DataType::Float64 => "DOUBLE PRECISION".to_owned(),
+        DataType::Decimal(precision, scale) => decimal_type("NUMERIC", *precision, *scale),
+        DataType::Binary => "BYTEA".to_owned(),
+        DataType::Date => "DATE".to_owned(),
+        DataType::Datetime(_, Some(_)) => "TIMESTAMPTZ".to_owned(),
+        DataType::Datetime(_, None) => "TIMESTAMP".to_owned(),
+        DataType::Time => "TIME".to_owned(),
+        DataType::Duration(_) => "INTERVAL".to_owned(),
+        DataType::List(inner) | DataType::Array(inner, _) => {
+            format!("{}[]", postgres_type(inner))
+        }
+        _ => "TEXT".to_owned(),
+    }
+}
+
+fn bigquery_type(data_type: &DataType) -> String {
+    match data_type {
+        DataType::Boolean => "BOOL".to_owned(),
+        DataType::Int8
+        | DataType::Int16
+        | DataType::Int32
+        | DataType::Int64
+        | DataType::UInt8
+        | DataType::UInt16
+        | DataType::UInt32 => "INT64".to_owned(),
+        DataType::UInt64 => "NUMERIC".to_owned(),
+        DataType::Float32 | DataType::Float64 => "FLOAT64".to_owned(),
+        // NUMERIC holds up to 29 integer digits, wider decimals need BIGNUMERIC.
+        DataType::Decimal(precision, scale) => {
+            let integer_digits = precision.unwrap_or(38) - scale.unwrap_or(0);
+            if integer_digits > 29 || scale.unwrap_or(0) > 9 {
+                decimal_type("BIGNUMERIC", *precision, *scale)
+            } else {
+                decimal_type("NUMERIC", *precision, *scale)
+            }
+        }
+        DataType::Binary => "BYTES".to_owned(),
+        DataType::Date => "DATE".to_owned(),
+        DataType::Datetime(_, Some(_)) => "TIMESTAMP".to_owned(),
+        DataType::Datetime(_, None) => "DATETIME".to_owned(),
+        DataType::Time => "TIME".to_owned(),
+        DataType::Duration(_) => "INTERVAL".to_owned(),
+        DataType::List(inner) | DataType::Array(inner, _) => {
+            format!("ARRAY<{}>", bigquery_type(inner))
+        }
+        _ => "STRING".to_owned(),
+    }
+}
+
+fn snowflake_type(data_type: &DataType) -> String {
+    match data_type {
+        DataType::Boolean => "BOOLEAN".to_owned(),
+        DataType::Int8 | DataType::Int16 | DataType::UInt8 => "SMALLINT".to_owned(),
+        DataType::Int32 | DataType::UInt16 => "INTEGER".to_owned(),
+        DataType::Int64 | DataType::UInt32 => "BIGINT".to_owned(),
+        DataType::UInt64 => "NUMBER(20, 0)".to_owned(),
+        DataType::Float32 | DataType::Float64 => "FLOAT".to_owned(),
+        DataType::Decimal(precision, scale) => decimal_type("NUMBER", *precision, *scale),
+        DataType::Binary => "BINARY".to_owned(),
+        DataType::Date => "DATE".to_owned(),
+        DataType::Datetime(_, Some(_)) => "TIMESTAMP_TZ".to_owned(),
+        DataType::Datetime(_, None) => "TIMESTAMP_NTZ".to_owned(),
+        DataType::Time => "TIME".to_owned(),
+        // Snowflake has no interval column type, durations are stored as a count of time units.
+        DataType::Duration(_) => "BIGINT".to_owned(),
+        DataType::List(_) | DataType::Array(_, _) => "ARRAY".to_owned(),
+        _ => "VARCHAR".to_owned(),
+    }
+}
+
+/// Formats a decimal type with its precision and scale, when known.
+fn decimal_type(name: &str, precision: Option<usize>, scale: Option<usize>) -> String {
+    match (precision, scale) {
+        (Some(precision), Some(scale)) => format!("{}({}, {})", name, precision, scale),
+        (None, Some(scale)) => format!("{}(38, {})", name, scale),
+        _ => name.to_owned(),
+    }
+}
@@ -0,0 +1,320 @@
+//! # Diff Module
+//!
+//! Handles comparing the report sections exported by two runs (`--export json`), so the changes
+//! between two versions of a dataset can be reviewed without re-reading either of them. The diff
+//! covers:
+//!
+//! - Schema changes: columns added, removed, or with a different data type.
+//! - Metric changes: the missing percentage of a column moving by at least the missing value
+//!   threshold (in percentage points), or its distinct count or a numerical statistic (min, max,
+//!   mean, median, and standard deviation) moving by at least the metric threshold (in percent).
+//! - Alert changes: alerts raised by the new run only, and alerts of the old run that are gone.
+//!
+//! The diff is printed as a plain text summary and can be rendered as a short changelog report
+//! through its `ReportSection`.
+
+use super::sections::{ReportBlock, ReportSection};
+use indexmap::IndexMap;
+use std::fmt;
+
+/// Default relative change of a metric, in percent, from which it's reported.
+pub const DEFAULT_METRIC_THRESHOLD: f64 = 10.0;
+/// Default change of the missing percentage of a column, in percentage points, from which it's
+/// reported.
+pub const DEFAULT_MISSING_THRESHOLD: f64 = 1.0;
+
+/// The column metrics compared by their relative change, in report order.
+const RELATIVE_METRICS: [&str; 6] = ["n_unique", "min", "max", "mean", "median", "std_dev"];
+
+/// The thresholds from which the metric changes are reported.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DiffThresholds {
+    /// The relative change of a metric, in percent.
+    pub metric: f64,
+    /// The change of the missing percentage of a column, in percentage points.
+    pub missing: f64,
+}
+
+impl Default for DiffThresholds {
+    fn default() -> Self {
+        Self {
+            metric: DEFAULT_METRIC_THRESHOLD,
+            missing: DEFAULT_MISSING_THRESHOLD,
+        }
+    }
+}
+
+/// A column whose data type changed between the runs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeChange {
+    /// The column name.
+    pub column: String,
+    /// The data type in the old run.
+    pub old: String,
+    /// The data type in the new run.
+    pub new: String,
+}
+
+/// A column metric that changed beyond its threshold between the runs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MetricChange {
+    /// The column name.
+    pub column: String,
+    /// The metric name, e.g. `missing` or `mean`.
+    pub metric: String,
+    /// The value in the old run.
+    pub old: f64,
+    /// The value in the new run.
+    pub new: f64,
+}
+
+impl MetricChange {
+    /// Formats the change: in percentage points for the missing percentage, and relative to the
+    /// old value for the other metrics.
+    pub fn change(&self) -> String {
+        if self.metric == "missing" {
+            format!("{:+.2} pp", self.new - self.old)
+        } else if self.old == 0.0 {
+            "from zero".to_owned()
+        } else {
+            format!("{:+.1}%", (self.new - self.old) / self.old.abs() * 100.0)
+        }
+    }
+
+    /// Formats a value of the metric, with a percent sign for the missing percentage.
+    pub fn format_value(&self, value: f64) -> String {
+        if self.metric == "missing" {
+            format!("{:.2}%", value)
+        } else {
+            format!("{}", value)
+        }
+    }
+}
+
+impl fmt::Display for MetricChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {}: {} -> {} ({})",
+            self.column,
+            self.metric,
+            self.format_value(self.old),
+            self.format_value(self.new),
+            self.change()
+        )
+    }
+}
+
+/// Struct to hold the changes between the report sections of two runs.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ReportDiff {
+    /// The columns only in the new run.
+    pub added_columns: Vec<String>,
+    /// The columns only in the old run.
+    pub removed_columns: Vec<String>,
+    /// The columns with a different data type, in the new run's column order.
+    pub type_changes: Vec<TypeChange>,
+    /// The metric changes beyond the thresholds, by column in the new run's column order.
+    pub metric_changes: Vec<MetricChange>,
+    /// The alerts only raised by the new run.
+    pub new_alerts: Vec<String>,
+    /// The alerts of the old run that the new run doesn't raise anymore.
+    pub resolved_alerts: Vec<String>,
+    /// The thresholds the metric changes were reported from.
+    pub thresholds: DiffThresholds,
+}
+
+impl ReportDiff {
+    /// Constructor for the ReportDiff struct.
+    ///
+    /// ### Parameters
+    ///
+    /// - `old`: The report sections of the old run.
+    /// - `new`: The report sections of the new run.
+    /// - `thresholds`: The thresholds from which the metric changes are reported.
+    ///
+    /// ### Returns
+    ///
+    /// - `ReportDiff`: The changes between the runs.
+    pub fn new(old: &[ReportSection], new: &[ReportSection], thresholds: DiffThresholds) -> Self {
+        let old_columns = column_values(old);
+        let new_columns = column_values(new);
+
+        let mut diff = Self {
+            added_columns: new_columns
+                .keys()
+                .filter(|name| !old_columns.contains_key(*name))
+                .cloned()
+                .collect(),
+            removed_columns: old_columns
+                .keys()
+                .filter(|name| !new_columns.contains_key(*name))
+                .cloned()
+                .collect(),
+            thresholds,
+            ..Default::default()
+        };
+        for (name, new_values) in &new_columns {
+            let Some(old_values) = old_columns.get(name) else {
+                continue;
+            };
+            if let (Some(old_type), Some(new_type)) =
+                (old_values.get("data_type"), new_values.get("data_type"))
+            {
+                if old_type != new_type {
+                    diff.type_changes.push(TypeChange {
+                        column: name.clone(),
+                        old: old_type.clone(),
+                        new: new_type.clone(),
+                    });
+                }
+            }
+
+            let old_missing = old_values
+                .get("missing")
+                .and_then(|value| missing_percentage(value));
+            let new_missing = new_values
+                .get("missing")
+                .and_then(|value| missing_percentage(value));
+            if let (Some(old), Some(new)) = (old_missing, new_missing) {
+                if (new - old).abs() >= thresholds.missing {
+                    diff.metric_changes.push(MetricChange {
+                        column: name.clone(),
+                        metric: "missing".to_owned(),
+                        old,
+                        new,
+                    });
+                }
+            }
+            for metric in RELATIVE_METRICS {
+                let old = old_values
+                    .get(metric)
+                    .and_then(|value| value.parse::<f64>().ok());
+                let new = new_values
+                    .get(metric)
+                    .and_then(|value| value.parse::<f64>().ok());
+                let (Some(old), Some(new)) = (old, new) else {
+                    continue;
+                };
+                let changed = if old == 0.0 {
+                    new != 0.0
+                } else {
+                    (new - old).abs() / old.abs() * 100.0 >= thresholds.metric
+                };
+                if changed {
+                    diff.metric_changes.push(MetricChange {
+                        column: name.clone(),
+                        metric: metric.to_owned(),
+                        old,
+                        new,
+                    });
+                }
+            }
+        }
+
+        let old_alerts = alerts(old);
+        let new_alerts = alerts(new);
+        diff.new_alerts = new_alerts
+            .iter()
+            .filter(|alert| !old_alerts.contains(alert))
+            .cloned()
+            .collect();
+        diff.resolved_alerts = old_alerts
+            .iter()
+            .filter(|alert| !new_alerts.contains(alert))
+            .cloned()
+            .collect();
+        diff
+    }
+
+    /// Checks whether the runs have no reported changes.
+    pub fn is_empty(&self) -> bool {
+        self.added_columns.is_empty()
+            && self.removed_columns.is_empty()
+            && self.type_changes.is_empty()
+            && self.metric_changes.is_empty()
+            && self.new_alerts.is_empty()
+            && self.resolved_alerts.is_empty()
+    }
+}
+
+impl fmt::Display for ReportDiff {
+    /// Formats the diff as a plain text summary, one change per line under a header per kind of
+    /// change.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return writeln!(f, "No changes found.");
+        }
+        let mut groups: Vec<(&str, Vec<String>)> = vec![
+            ("New columns", self.added_columns.clone()),
+            ("Removed columns", self.removed_columns.clone()),
+            (
+                "Data type changes",
+                self.type_changes
+                    .iter()
+                    .map(|change| format!("{}: {} -> {}", change.column, change.old, change.new))
+                    .collect(),
+            ),
+            (
+                "Metric changes",
+                self.metric_changes
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect(),
+            ),
+            ("New alerts", self.new_alerts.clone()),
+            ("Resolved alerts", self.resolved_alerts.clone()),
+        ];
+        groups.retain(|(_, lines)| !lines.is_empty());
+        for (i, (header, lines)) in groups.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            writeln!(f, "{} ({}):", header, lines.len())?;
+            for line in lines {
+                writeln!(f, "  {}", line)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Gets the detail values of each column (data type, missing values, distinct count, and
+/// statistics) from the columns section, in column order.
+fn column_values(sections: &[ReportSection]) -> IndexMap<String, IndexMap<String, String>> {
+    sections
+        .iter()
+        .filter(|section| section.title == "Columns")
+        .flat_map(|section| &section.blocks)
+        .filter_map(|block| match block {
+            ReportBlock::ColumnDetail { column, values, .. } => {
+                Some((column.clone(), values.clone()))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Gets the alerts from the alerts section, skipping its key insights.
+fn alerts(sections: &[ReportSection]) -> Vec<String> {
+    let Some(section) = sections.iter().find(|section| section.title == "Alerts") else {
+        return Vec::new();
+    };
+    let mut alerts = Vec::new();
+    let mut previous: Option<&ReportBlock> = None;
+    for block in &section.blocks {
+        let insights =
+            matches!(previous, Some(ReportBlock::Heading { text }) if text == "Key Insights");
+        if let (ReportBlock::List { items }, false) = (block, insights) {
+            alerts.extend(items.iter().cloned());
+        }
+        previous = Some(block);
+    }
+    alerts
+}
+
+/// Parses the missing percentage out of a missing values detail, e.g. `12 (2.40%)`.
+fn missing_percentage(value: &str) -> Option<f64> {
+    let (_, percentage) = value.split_once('(')?;
+    percentage.strip_suffix("%)")?.parse().ok()
+}
@@ -0,0 +1,505 @@
+//! # Format Module
+//!
+//! Handles the unit aware formatting of numerical values in the report. A column's unit is taken
+//! from its data dictionary entry, or detected from a unit suffix of the column name (e.g.
+//! `price_usd`, `weight_kg`, or `duration (s)`). Currency amounts are formatted with thousands
+//! separators and the currency symbol or code (e.g. "$1,234.50" or "1,234.50 CHF"), and measures
+//! with the unit after the value (e.g. "12.30 kg"). All the location and spread statistics of a
+//! column are formatted in the same unit and precision.
+//!
+//! The report text is formatted with the conventions of the default locale ("1,234.50" and
+//! ISO 8601 dates). A `Locale` rewrites the numbers and dates the report formats as it's rendered
+//! (e.g. "1.234,50" and "31.12.2024" for `de-DE`), so every report backend gets the same
+//! formatting without the analyses knowing about it. Values taken from the data are never
+//! rewritten: they're quoted in the report text (e.g. "Column 'price'" or "`1,5`"), and flagged in
+//! the tables and statistic blocks of the report sections.
+
+use crate::data::dictionary::{ColumnDefinition, ColumnTag};
+use chrono::NaiveDate;
+use std::fmt;
+use std::str::FromStr;
+
+/// The statistics formatted in the column unit.
+pub const UNIT_STATISTICS: [&str; 12] = [
+    "min",
+    "max",
+    "mean",
+    "median",
+    "std_dev",
+    "q1",
+    "q3",
+    "iqr",
+    "mad",
+    "trimmed_min",
+    "trimmed_max",
+    "trimmed_mean",
+];
+/// The number of decimals of formatted measures.
+pub const MEASURE_DECIMALS: usize = 2;
+
+/// The known currencies, as their code, symbol, and number of decimals.
+const CURRENCIES: [(&str, Option<&str>, usize); 10] = [
+    ("USD", Some("$"), 2),
+    ("EUR", Some("€"), 2),
+    ("GBP", Some("£"), 2),
+    ("JPY", Some("¥"), 0),
+    ("INR", Some("₹"), 2),
+    ("KRW", Some("₩"), 0),
+    ("CNY", None, 2),
+    ("CAD", None, 2),
+    ("AUD", None, 2),
+    ("CHF", None, 2),
+];
+
+/// The supported locales, as their tag, decimal separator, thousands separator, and date format.
+/// The first locale is the default.
+const LOCALES: [(&str, char, char, &str); 9] = [
+    ("en", '.', ',', "%Y-%m-%d"),
+    ("en-US", '.', ',', "%m/%d/%Y"),
+    ("en-GB", '.', ',', "%d/%m/%Y"),
+    ("de-DE", ',', '.', "%d.%m.%Y"),
+    ("fr-FR", ',', ' ', "%d/%m/%Y"),
+    ("es-ES", ',', '.', "%d/%m/%Y"),
+    ("it-IT", ',', '.', "%d/%m/%Y"),
+    ("pt-BR", ',', '.', "%d/%m/%Y"),
+    ("ja-JP", '.', ',', "%Y/%m/%d"),
+];
+
+/// The measure units detected from column name suffixes, as the suffix and the displayed unit.
+const MEASURE_SUFFIXES: [(&str, &str); 28] = [
+    ("kg", "kg"),
+    ("mg", "mg"),
+    ("lb", "lb"),
+    ("lbs", "lb"),
+    ("oz", "oz"),
+    ("km", "km"),
+    ("cm", "cm"),
+    ("mm", "mm"),
+    ("mi", "mi"),
+    ("ft", "ft"),
+    ("ms", "ms"),
+    ("sec", "s"),
+    ("secs", "s"),
+    ("seconds", "s"),
+    ("mins", "min"),
+    ("minutes", "min"),
+    ("hr", "h"),
+    ("hrs", "h"),
+    ("hours", "h"),
+    ("days", "days"),
+    ("pct", "%"),
+    ("percent", "%"),
+    ("kwh", "kWh"),
+    ("kb", "KB"),
+    ("mb", "MB"),
+    ("gb", "GB"),
+    ("bytes", "bytes"),
+    ("celsius", "°C"),
+];
+
+/// The unit of the values of a numerical column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValueUnit {
+    /// A currency, by its code (e.g. "USD"). Amounts without a known currency have no code.
+    Currency(Option<String>),
+    /// A unit of measurement (e.g. "kg").
+    Measure(String),
+}
+
+impl ValueUnit {
+    /// Parses a unit as written in a data dictionary. Known currency codes and symbols are
+    /// currencies, anything else is a measure.
+    ///
+    /// ### Parameters
+    ///
+    /// - `unit`: The unit.
+    ///
+    /// ### Returns
+    ///
+    /// - `ValueUnit`: The parsed unit.
+    pub fn from_unit(unit: &str) -> Self {
+        let unit = unit.trim();
+        match CURRENCIES
+            .iter()
+            .find(|(code, symbol, _)| code.eq_ignore_ascii_case(unit) || *symbol == Some(unit))
+        {
+            Some((code, _, _)) => ValueUnit::Currency(Some((*code).to_owned())),
+            None => ValueUnit::Measure(unit.to_owned()),
+        }
+    }
+
+    /// Detects the unit from the suffix of a column name, either the last `_`, `-`, or space
+    /// separated word when it's a known currency code or measure (e.g. `price_usd`), or any unit
+    /// in trailing parentheses or brackets (e.g. `weight (kg)`).
+    ///
+    /// ### Parameters
+    ///
+    /// - `column`: The column name.
+    ///
+    /// ### Returns
+    ///
+    /// - `Option<ValueUnit>`: The detected unit, or None if the name has no unit suffix.
+    pub fn detect(column: &str) -> Option<Self> {
+        let column = column.trim();
+        for (open, close) in [('(', ')'), ('[', ']')] {
+            if let Some(rest) = column.strip_suffix(close) {
+                if let Some((name, unit)) = rest.rsplit_once(open) {
+                    if !name.trim().is_empty() && !unit.trim().is_empty() {
+                        return Some(Self::from_unit(unit));
+                    }
+                }
+            }
+        }
+
+        let (name, suffix) = column.rsplit_once(['_', '-', ' '])?;
+        if name.is_empty() {
+            return None;
+        }
+        if let Some((code, _, _)) = CURRENCIES
+            .iter()
+            .find(|(code, _, _)| code.eq_ignore_ascii_case(suffix))
+        {
+            return Some(ValueUnit::Currency(Some((*code).to_owned())));
+        }
+        MEASURE_SUFFIXES
+            .iter()
+            .find(|(measure_suffix, _)| measure_suffix.eq_ignore_ascii_case(suffix))
+            .map(|(_, unit)| ValueUnit::Measure((*unit).to_owned()))
+    }
+
+    /// Formats a value in the unit.
+    ///
+    /// ### Parameters
+    ///
+    /// - `value`: The value.
+    ///
+    /// ### Returns
+    ///
+    /// - `String`: The formatted value (e.g. "$1,234.50", "1,234.50 CHF", or "12.30 kg").
+    pub fn format(&self, value: f64) -> String {
+        match self {
+            ValueUnit::Currency(code) => {
+                let currency = code
+                    .as_deref()
+                    .and_then(|code| CURRENCIES.iter().find(|(known, _, _)| *known == code));
+                let decimals = currency.map_or(2, |(_, _, decimals)| *decimals);
+                let amount = format_number(value, decimals);
+                match (currency.and_then(|(_, symbol, _)| *symbol), code) {
+                    (Some(symbol), _) => match amount.strip_prefix('-') {
+                        Some(amount) => format!("-{}{}", symbol, amount),
+                        None => format!("{}{}", symbol, amount),
+                    },
+                    (None, Some(code)) => format!("{} {}", amount, code),
+                    (None, None) => amount,
+                }
+            }
+            ValueUnit::Measure(unit) if unit == "%" => {
+                format!("{}%", format_number(value, MEASURE_DECIMALS))
+            }
+            ValueUnit::Measure(unit) => {
+                format!("{} {}", format_number(value, MEASURE_DECIMALS), unit)
+            }
+        }
+    }
+}
+
+impl fmt::Display for ValueUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValueUnit::Currency(Some(code)) => write!(f, "{}", code),
+            ValueUnit::Currency(None) => write!(f, "currency"),
+            ValueUnit::Measure(unit) => write!(f, "{}", unit),
+        }
+    }
+}
+
+/// The number and date formatting conventions of the report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Locale {
+    /// The locale tag (e.g. "de-DE").
+    pub tag: &'static str,
+    /// The separator between the integer and fractional parts of a number.
+    pub decimal_separator: char,
+    /// The separator between the thousands groups of a number.
+    pub thousands_separator: char,
+    /// The `chrono` format of dates.
+    pub date_format: &'static str,
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        let (tag, decimal_separator, thousands_separator, date_format) = LOCALES[0];
+        Self {
+            tag,
+            decimal_separator,
+            thousands_separator,
+            date_format,
+        }
+    }
+}
+
+impl FromStr for Locale {
+    type Err = String;
+
+    /// Parses a locale tag (e.g. `de-DE` or `de_DE`), case insensitively. A language without a
+    /// region (e.g. `de`) picks the first locale of the language.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tag = s.trim().replace('_', "-");
+        LOCALES
+            .iter()
+            .find(|(known, _, _, _)| known.eq_ignore_ascii_case(&tag))
+            .or_else(|| {
+                LOCALES.iter().find(|(known, _, _, _)| {
+                    known
+                        .split('-')
+                        .next()
+                        .is_some_and(|language| language.eq_ignore_ascii_case(&tag))
+                })
+            })
+            .map(
+                |(tag, decimal_separator, thousands_separator, date_format)| Locale {
+                    tag,
+                    decimal_separator: *decimal_separator,
+                    thousands_separator: *thousands_separator,
+                    date_format,
+                },
+            )
+            .ok_or_else(|| {
+                format!(
+                    "unsupported locale '{}', expected one of: {}",
+                    s,
+                    LOCALES
+                        .iter()
+                        .map(|(tag, _, _, _)| *tag)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            })
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.tag)
+    }
+}
+
+impl Locale {
+    /// Formats a number with the thousands and decimal separators of the locale.
+    ///
+    /// ### Parameters
+    ///
+    /// - `value`: The number.
+    /// - `decimals`: The number of decimals.
+    ///
+    /// ### Returns
+    ///
+    /// - `String`: The formatted number (e.g. "1.234,50" for `de-DE`).
+    pub fn format_number(&self, value: f64, decimals: usize) -> String {
+        self.localize_number(&format_number(value, decimals))
+    }
+
+    /// Formats a date in the date format of the locale.
+    pub fn format_date(&self, date: NaiveDate) -> String {
+        date.format(self.date_format).to_string()
+    }
+
+    /// Rewrites the numbers and ISO 8601 dates of a text formatted with the default locale (e.g.
+    /// "1,234.50" or "2024-12-31 08:00:00") in the locale. Numbers within words (e.g. `x1.5`),
+    /// version-like sequences (e.g. `1.2.3`), numbers in scientific notation, and the values of
+    /// the data quoted in single quotes or backticks (e.g. 'a 1.5' or `2024-01-31`) are left as
+    /// is.
+    ///
+    /// ### Parameters
+    ///
+    /// - `text`: The text to localize.
+    ///
+    /// ### Returns
+    ///
+    /// - `String`: The localized text.
+    pub fn localize(&self, text: &str) -> String {
+        if *self == Locale::default() {
+            return text.to_owned();
+        }
+        let mut output = String::with_capacity(text.len());
+        let mut rest = text;
+        // The two characters before the current one, to tell numbers from parts of words, paths,
+        // and hyphenated names (e.g. `part-2024-01-01.csv`).
+        let mut previous: (Option<char>, Option<char>) = (None, None);
+        while let Some(c) = rest.chars().next() {
+            // A single quote right after a letter is an apostrophe (e.g. "Cramér's V"), not a
+            // quoted value.
+            let quoted = match c {
+                '`' => rest[1..].find('`'),
+                '\'' if !previous.0.is_some_and(char::is_alphanumeric) => rest[1..]
+                    .match_indices('\'')
+                    .map(|(i, _)| i)
+                    .find(|i| !rest[i + 2..].starts_with(char::is_alphanumeric)),
+                _ => None,
+            };
+            if let Some(end) = quoted {
+                let span = &rest[..end + 2];
+                output.push_str(span);
+                previous = (Some(c), None);
+                rest = &rest[span.len()..];
+                continue;
+            }
+
+            let starts_token = c.is_ascii_digit()
+                && !previous.0.is_some_and(|p| {
+                    p.is_alphanumeric() || matches!(p, '_' | '.' | ',' | '/' | '\\')
+                })
+                && !(previous.0 == Some('-') && previous.1.is_some_and(char::is_alphanumeric));
+            if !starts_token {
+                output.push(c);
+                previous = (Some(c), previous.0);
+                rest = &rest[c.len_utf8()..];
+                continue;
+            }
+
+            if let Some(date) = rest
+                .get(..10)
+                .filter(|_| is_iso_date(rest) && !continues_word(&rest[10..]))
+            {
+                match NaiveDate::parse_from_str(date, "%Y-%m-%d") {
+                    Ok(date) => output.push_str(&self.format_date(date)),
+                    Err(_) => output.push_str(date),
+                }
+                previous = (date.chars().last(), None);
+                rest = &rest[10..];
+                continue;
+            }
+
+            let length = rest
+                .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == ','))
+                .unwrap_or(rest.len());
+            // Trailing separators are punctuation (e.g. "1.5." or "1, 2"), not part of the number.
+            let number = rest[..length].trim_end_matches(['.', ',']);
+            if continues_word(&rest[number.len()..]) || !is_formatted_number(number) {
+                output.push_str(number);
+            } else {
+                output.push_str(&self.localize_number(number));
+            }
+            previous = (number.chars().last(), None);
+            rest = &rest[number.len()..];
+        }
+        output
+    }
+
+    /// Swaps the separators of a number formatted with the default locale.
+    fn localize_number(&self, number: &str) -> String {
+        number
+            .chars()
+            .map(|c| match c {
+                ',' => self.thousands_separator,
+                '.' => self.decimal_separator,
+                c => c,
+            })
+            .collect()
+    }
+}
+
+/// Checks whether a text starts with an ISO 8601 date (`YYYY-MM-DD`).
+fn is_iso_date(text: &str) -> bool {
+    let bytes = text.as_bytes();
+    bytes.len() >= 10
+        && bytes[..10].iter().enumerate().all(|(i, byte)| match i {
+            4 | 7 => *byte == b'-',
+            _ => byte.is_ascii_digit(),
+        })
+}
+
+/// Checks whether the text after a number continues a word, e.g. `5m`, `2.5e-3`, or `1.csv`.
+fn continues_word(rest: &str) -> bool {
+    let mut chars = rest.chars();
+    match chars.next() {
+        Some(next) if next.is_alphanumeric() || next == '_' => true,
+        Some('.') => chars.next().is_some_and(char::is_alphanumeric),
+        _ => false,
+    }
+}
+
+/// Checks whether a number is formatted with the default locale, either with thousands separators
+/// (e.g. "1,234.50") or without (e.g. "1234.5"), and at most one decimal point.
+fn is_formatted_number(number: &str) -> bool {
+    let (integer, fraction) = match number.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (number, None),
+    };
+    if fraction.is_some_and(|fraction| {
+        fraction.is_empty() || !fraction.chars().all(|c| c.is_ascii_digit())
+    }) {
+        return false;
+    }
+    let mut groups = integer.split(',');
+    let first = groups.next().unwrap_or_default();
+    !first.is_empty()
+        && first.chars().all(|c| c.is_ascii_digit())
+        && (first.len() <= 3 || !integer.contains(','))
+        && groups.all(|group| group.len() == 3 && group.chars().all(|c| c.is_ascii_digit()))
+}
+
+/// Resolves the unit of a numerical column. The data dictionary unit takes precedence (as a
+/// currency for columns tagged as currency), then the currency tag, then the unit detected from
+/// the column name.
+///
+/// ### Parameters
+///
+/// - `column`: The column name.
+/// - `definition`: The data dictionary entry for the column, if any.
+///
+/// ### Returns
+///
+/// - `Option<(ValueUnit, bool)>`: The unit and whether it was detected from the column name, or
+///   None if the column has no unit.
+pub fn column_unit(
+    column: &str,
+    definition: Option<&ColumnDefinition>,
+) -> Option<(ValueUnit, bool)> {
+    let is_currency =
+        definition.is_some_and(|definition| definition.tags.contains(&ColumnTag::Currency));
+    match definition.and_then(|definition| definition.unit.as_deref()) {
+        Some(unit) => match ValueUnit::from_unit(unit) {
+            ValueUnit::Measure(unit) if is_currency => {
+                Some((ValueUnit::Currency(Some(unit)), false))
+            }
+            unit => Some((unit, false)),
+        },
+        None if is_currency => Some((ValueUnit::Currency(None), false)),
+        None => ValueUnit::detect(column).map(|unit| (unit, true)),
+    }
+}
+
+/// Formats a number with thousands separators and a fixed number of decimals.
+///
+/// ### Parameters
+///
+/// - `value`: The number.
+/// - `decimals`: The number of decimals.
+///
+/// ### Returns
+///
+/// - `String`: The formatted number (e.g. "1,234.50").
+pub fn format_number(value: f64, decimals: usize) -> String {
+    let formatted = format!("{:.*}", decimals, value.abs());
+    let (integer, fraction) = match formatted.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (formatted.as_str(), None),
+    };
+    let mut grouped = String::new();
+    for (i, digit) in integer.chars().enumerate() {
+        if i > 0 && (integer.len() - i) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    let sign = if value < 0.0 && formatted.chars().any(|c| c.is_ascii_digit() && c != '0') {
+        "-"
+    } else {
+        ""
+    };
+    match fraction {
+        Some(fraction) => format!("{}{}.{}", sign, grouped, fraction),
+        None => format!("{}{}", sign, grouped),
+    }
+}
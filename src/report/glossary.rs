@@ -1,69 +1,339 @@
 //! # Glossary Module
 //!
-//! This module defines the Glossary struct and related constants for generating
-//! a glossary of terms used in the data analysis report.
+//! This module defines the Glossary struct for generating a glossary of terms used in the data
+//! analysis report. The glossary starts with the built-in terms and supports adding or overriding
+//! terms at runtime, either directly or from a YAML or CSV file.
+//!
+//! Built-in terms are only rendered when they are referenced somewhere in the generated report,
+//! terms added by the user are always rendered. The rendered terms are grouped by the report
+//! section that first references them, in report order, with the unreferenced user terms grouped
+//! last under `ADDITIONAL_TERMS_SECTION`.
+//!
+//! ## File Formats
+//!
+//! YAML glossaries map each term to its definition:
+//!
+//! ```yaml
+//! churn: A customer that cancelled their subscription in the reporting period.
+//! ```
+//!
+//! CSV glossaries have a header row with `term` and `definition` columns.
+
+use indexmap::IndexMap;
+use polars::prelude::*;
+use std::collections::HashSet;
+use std::ffi::OsStr;
+use std::path::Path;
+use thiserror::Error;
+
+/// The glossary group of the user added terms that aren't referenced in the report.
+pub const ADDITIONAL_TERMS_SECTION: &str = "Additional Terms";
 
-use polars::datatypes::DataType;
+/// The error types for the glossary module.
+#[derive(Error, Debug)]
+pub enum GlossaryError {
+    /// Occurs when an I/O operation fails.
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// Occurs when Polars fails to read a CSV glossary.
+    #[error("Polars error: {0}")]
+    Polars(#[from] PolarsError),
+
+    /// Occurs when a YAML glossary can't be parsed.
+    #[error("YAML error: {0}")]
+    Yaml(#[from] serde_yaml::Error),
+
+    /// Occurs when a CSV glossary doesn't have a required column.
+    #[error("Glossary is missing the required column: {0}")]
+    MissingColumn(String),
+
+    /// Occurs when an unsupported file format is passed.
+    #[error("Unsupported glossary format: {0}")]
+    UnsupportedFormat(String),
+}
 
 /// A struct representing a glossary of data analysis terms and their definitions.
+#[derive(Debug, Clone)]
 pub struct Glossary {
-    /// An array representing data analysis terms.
-    pub terms: [&'static str; 15],
-    /// An array containing the definitions for the corresponding terms.
-    pub definitions: [&'static str; 15],
+    /// Map of terms to their definitions.
+    definitions: IndexMap<String, String>,
 }
 
 impl Glossary {
-    /// Creates a new `Glossary` instance with predefined terms and definitions.
+    /// Creates a new `Glossary` instance with the built-in terms and definitions.
     ///
     /// ### Returns
-    /// - `Glossary`: A new `Glossary` struct initialized with the `TERMS` and `DEFINITIONS` constants.
+    /// - `Glossary`: A new `Glossary` struct initialized with the `BUILTIN_TERMS` constant.
     pub fn new() -> Self {
         Self {
-            terms: TERMS,
-            definitions: DEFINITIONS,
+            definitions: BUILTIN_TERMS
+                .iter()
+                .map(|(term, definition)| (term.to_string(), definition.to_string()))
+                .collect(),
+        }
+    }
+
+    /// Reads a glossary file and adds (or overrides) its terms.
+    ///
+    /// ### Parameters
+    /// - `path`: The path to the `.yaml`, `.yml`, or `.csv` glossary file.
+    ///
+    /// ### Returns
+    /// - `Result<(), GlossaryError>`: Unit type or an error if the file can't be read.
+    pub fn load_file(&mut self, path: &Path) -> Result<(), GlossaryError> {
+        let terms: IndexMap<String, String> = match path.extension().and_then(OsStr::to_str) {
+            Some("yaml") | Some("yml") => serde_yaml::from_str(&std::fs::read_to_string(path)?)?,
+            Some("csv") => read_csv_terms(path)?,
+            Some(ext) => return Err(GlossaryError::UnsupportedFormat(ext.to_owned())),
+            None => {
+                return Err(GlossaryError::UnsupportedFormat(
+                    "No file extension".to_owned(),
+                ))
+            }
+        };
+        for (term, definition) in terms {
+            self.add_term(&term, &definition);
+        }
+        Ok(())
+    }
+
+    /// Adds a term to the glossary, overriding the definition if the term already exists.
+    ///
+    /// ### Parameters
+    /// - `term`: The term.
+    /// - `definition`: The definition of the term.
+    ///
+    /// ### Returns
+    /// - `Option<String>`: The previous definition, if the term was overridden.
+    pub fn add_term(&mut self, term: &str, definition: &str) -> Option<String> {
+        self.definitions
+            .insert(term.to_owned(), definition.to_owned())
+    }
+
+    /// Gets the definition of a term.
+    pub fn get(&self, term: &str) -> Option<&str> {
+        self.definitions.get(term).map(String::as_str)
+    }
+
+    /// Gets the terms that should be rendered in the report, sorted alphabetically. Built-in terms
+    /// are only included if they were referenced, user added terms are always included.
+    ///
+    /// ### Parameters
+    /// - `referenced`: The set of terms referenced in the report.
+    ///
+    /// ### Returns
+    /// - `Vec<(&str, &str)>`: The terms and their definitions.
+    pub fn entries(&self, referenced: &HashSet<String>) -> Vec<(&str, &str)> {
+        let mut entries: Vec<(&str, &str)> = self
+            .definitions
+            .iter()
+            .filter(|(term, _)| referenced.contains(*term) || !is_builtin_term(term))
+            .map(|(term, definition)| (term.as_str(), definition.as_str()))
+            .collect();
+        entries.sort_by_key(|(term, _)| term.to_lowercase());
+        entries
+    }
+
+    /// Gets the terms that should be rendered in the report grouped by the section that first
+    /// references them. Sections are in reference order and the terms within a section are sorted
+    /// alphabetically. User added terms that aren't referenced are grouped last under
+    /// `ADDITIONAL_TERMS_SECTION`.
+    ///
+    /// ### Parameters
+    /// - `referenced`: The terms referenced in the report, mapped to the section that first
+    ///   references them, in reference order.
+    ///
+    /// ### Returns
+    /// - `IndexMap<&str, Vec<(&str, &str)>>`: The terms and their definitions, keyed by section.
+    pub fn section_entries<'a>(
+        &'a self,
+        referenced: &'a IndexMap<String, String>,
+    ) -> IndexMap<&'a str, Vec<(&'a str, &'a str)>> {
+        let mut sections: IndexMap<&str, Vec<(&str, &str)>> = IndexMap::new();
+        for section in referenced.values() {
+            sections.entry(section.as_str()).or_default();
         }
+        for (term, definition) in &self.definitions {
+            let section = match referenced.get(term) {
+                Some(section) => section.as_str(),
+                None if !is_builtin_term(term) => ADDITIONAL_TERMS_SECTION,
+                None => continue,
+            };
+            sections
+                .entry(section)
+                .or_default()
+                .push((term.as_str(), definition.as_str()));
+        }
+
+        sections.retain(|_, entries| !entries.is_empty());
+        for entries in sections.values_mut() {
+            entries.sort_by_key(|(term, _)| term.to_lowercase());
+        }
+        sections
     }
 }
 
-/// Array of data analysis terms used in the glossary.
-pub const TERMS: [&'static str; 15] = [
-    "bool",
-    "count",
-    "i64",
-    "iqr",
-    "kurtosis",
-    "max",
-    "mean",
-    "median",
-    "min",
-    "q1",
-    "q3",
-    "std_dev",
-    "skewness_bias",
-    "skewness_raw",
-    "str",
-];
+impl Default for Glossary {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Checks whether a term is one of the built-in glossary terms.
+fn is_builtin_term(term: &str) -> bool {
+    BUILTIN_TERMS.iter().any(|(builtin, _)| *builtin == term)
+}
 
-/// Array of definitions corresponding to the terms in the `TERMS` array.
-pub const DEFINITIONS: [&'static str; 15] = [
-    "A boolean value, either true or false.", // bool
-    "The number of items in a dataset or column.", // count
-    "A 64-bit signed integer. An i64 can represent both positive and negative integers, with a max possible value of 9,223,372,036,854,775,807 and a minimum possible value of -9,223,372,036,854,775,808.", // i64
-    "Interquartile range, the difference between the third quartile (Q3) and the first quartile (Q1). The interquartile range is a measure of statistical dispersion, or the spread of the data.", // iqr
-    "A measure of the 'tailedness' of the probability distribution of a real-valued random variable. Kurtosis is the fourth central moment divided by the square of the variance. In this report's case, Fisher's definition is used, which results in `3.0` being subtracted from the result to give `0.0` for a normal distribution.", // kurtosis
-    "The highest value in a dataset or column.", // max
-    "The average value of a dataset or column, calculated by summing all values and dividing by the count.", // mean
-    "The middle value in a sorted dataset or column.", // median
-    "The lowest value in a dataset or column.", // min
-    "First quartile, the median of the lower half of the dataset or column.", // q1
-    "Third quartile, the median of the upper half of the dataset or column.", // q3
-    "Standard deviation, a measure of the amount of variation or dispersion of a set of values.", // std_dev
-    "Skewness calculated with a bias correction factor. Skewness is a metric for asymmetry or distortion, measuring the deviation of a given distribution of a random variable from a normal distribution.", // skewness_bias
-    "Skewness calculated without bias correction. Skewness is a metric for asymmetry or distortion, measuring the deviation of a given distribution of a random variable from a normal distribution.", // skewness_raw
-    "A string, or text value.", // str
+/// Reads the `term` and `definition` columns of a CSV glossary.
+fn read_csv_terms(path: &Path) -> Result<IndexMap<String, String>, GlossaryError> {
+    let df = LazyCsvReader::new(path)
+        .with_has_header(true)
+        .with_infer_schema_length(Some(0))
+        .finish()?
+        .collect()?;
+    let column = |name: &str| -> Result<&StringChunked, GlossaryError> {
+        Ok(df
+            .column(name)
+            .map_err(|_| GlossaryError::MissingColumn(name.to_owned()))?
+            .str()?)
+    };
+    Ok(column("term")?
+        .into_iter()
+        .zip(column("definition")?)
+        .filter_map(|(term, definition)| Some((term?.to_owned(), definition?.to_owned())))
+        .collect())
+}
+
+/// The built-in data analysis terms and their definitions.
+pub const BUILTIN_TERMS: [(&str, &str); 105] = [
+    ("acf", "Autocorrelation function, the correlation of a time series with a lagged copy of itself at each lag. Significant coefficients (outside the shaded 95% band) point to persistence or seasonality."),
+    ("ambiguous_dates", "String dates that parse in more than one date format, e.g. `03/04/2024` is March 4th as `MM/DD/YYYY` and April 3rd as `DD/MM/YYYY`. The values that only parse in one format decide the suggested parse format."),
+    ("anova", "One-way analysis of variance, a test of whether the mean of a numerical variable differs between the groups of a categorical one. The F statistic compares the variance between the group means to the variance within the groups, and a small p-value means at least one group mean differs."),
+    ("array", "A fixed size list, where every value holds the same number of elements of the inner data type."),
+    ("association_strength", "The strength of the association between a feature and the target, from 0 (none) to 1 (the feature fully determines the target or the other way around). It's the absolute Pearson correlation for two numerical variables, the correlation ratio (the square root of the share of the variance explained by the groups) for ANOVA, and Cramér's V for the chi-squared test. Unlike the p-value, it doesn't grow with the number of rows."),
+    ("blank_strings", "Empty strings and strings made only of whitespace. They aren't missing values to the profiler, but usually stand for a missing value, so they're best converted to nulls."),
+    ("bool", "A boolean value, either true or false."),
+    ("cat", "A categorical value, a string drawn from a set of categories that is stored as an integer code for each category."),
+    ("chi_squared_test", "Pearson's chi-squared test of independence between two categorical variables, comparing the counts of their contingency table to the counts expected if they were independent. A small p-value means the categories of one variable depend on the other."),
+    ("collapsed_cardinality", "The number of distinct values of a string column after trimming surrounding whitespace and lowercasing them. A collapsed cardinality below the number of distinct raw values means the same category is spelled inconsistently, e.g. `Male` and `male `."),
+    ("constant_column", "A column with a single distinct non-null value. It carries no information for modeling and is usually a leftover of an export or a filter."),
+    ("content_hash", "The SHA-256 digest of the bytes of the input file(s). Any change to the data, even a single character, changes the hash, so two reports with the same hash were generated from identical input."),
+    ("count", "The number of items in a dataset or column."),
+    ("coverage", "The percentage of the non-missing values of a string column that match a character-class pattern."),
+    ("cramers_v", "A measure of association between two categorical features, from 0 (independent) to 1 (each feature fully determines the other). It is derived from the chi-squared statistic of their contingency table."),
+    ("date", "A calendar date, without a time of day."),
+    ("datetime", "A date and time of day, stored with a fixed precision (e.g. microseconds) and an optional time zone."),
+    ("decimal", "A fixed point decimal number, stored with an exact number of digits after the decimal point (the scale). Decimal statistics are rounded to the column's scale."),
+    ("distribution", "A plain label of the shape of a numerical feature's values: normal, right- or left-skewed (a long tail of high or low values), heavy- or light-tailed (more or fewer extreme values than a normal distribution), bimodal (two separate clusters of values), or non-normal. Skewed and heavy-tailed features often call for a transformation or robust statistics, and bimodal ones usually mix two populations."),
+    ("duplicate_columns", "Columns whose values repeat an earlier column: identical, equal within a small relative tolerance (e.g. the same measure stored with a different precision), or a constant multiple of it (e.g. a unit conversion). Such derived columns add no information and inflate collinearity."),
+    ("duplicate_rows", "Rows identical to an earlier row in every column that can be compared. Full-row duplicates usually come from repeated loads or joins and inflate counts and statistics."),
+    ("duplicate_timestamps", "The number of timestamps in the time index that appear in more than one row. Duplicates usually come from repeated ingestion or merged sources and need to be aggregated or removed before resampling."),
+    ("empty_percentage", "The percentage of non-null list or array values that contain no elements."),
+    ("enum", "An enumerated value, a categorical value whose set of allowed categories is fixed ahead of time."),
+    ("explained_variance_ratio", "The fraction of the total variance of the standardized numerical features captured by a principal component. The cumulative ratio shows how many components are needed to summarize the data."),
+    ("extreme_values", "The distinct values outside the IQR fences furthest from the middle of the IQR, most extreme first. A few examples often reveal the cause of the outliers, e.g. a sentinel value like 999 or values in a different unit."),
+    ("f64", "A 64-bit floating point number. An f64 can represent fractional values with roughly 15 to 17 significant decimal digits of precision."),
+    ("foreign_values", "Values of a column missing from its reference list of valid values, such as unknown country codes or product IDs. They point to typos, retired codes, or references to records that don't exist."),
+    ("histogram_bins", "The number of equal width bins of a numerical feature's histogram in machine-readable exports, and the rule it was chosen with. Freedman-Diaconis sets the bin width to twice the interquartile range over the cube root of the count, so outliers don't widen the bins; Sturges' rule (log2 of the count plus one bins) is used when the interquartile range is zero."),
+    ("i64", "A 64-bit signed integer. An i64 can represent both positive and negative integers, with a max possible value of 9,223,372,036,854,775,807 and a minimum possible value of -9,223,372,036,854,775,808."),
+    ("imbalance_ratio", "The number of rows in the most frequent class of the target divided by the number of rows in the least frequent class. A ratio of 1:1 means the classes are perfectly balanced."),
+    ("ingestion_lag", "The time between a record timestamp and when the record was ingested (or, without an ingestion timestamp column, the time of the run). Records with a negative lag are timestamped after they were ingested, which points to clock skew or time zone issues."),
+    ("inter_event_time", "The time between two consecutive events of the same entity in an event log, e.g. between two actions of a user."),
+    ("invalid_percentage", "The percentage of the non-missing values of a column detected as emails, URLs, UUIDs, or phone numbers that fail a lightweight validation of that format. A column is detected as a format when at least half of its values are valid."),
+    ("iqr", "Interquartile range, the difference between the third quartile (Q3) and the first quartile (Q1). The interquartile range is a measure of statistical dispersion, or the spread of the data."),
+    ("iqr_fences", "The lower and upper IQR fences, Q1 - 1.5 * IQR and Q3 + 1.5 * IQR (Tukey's fences). Values outside the fences are flagged as outliers."),
+    ("iqr_outliers", "The number of values outside the IQR fences, with their percentage of the non-null values."),
+    ("irregular_intervals", "The number of differences between consecutive timestamps that are off the sampling interval by more than 10% without being long enough to be a gap, a sign of jitter or an unstable sampling rate."),
+    ("key_candidate", "A column, or pair of columns, whose values identify every row, making it a candidate primary key. Near candidates fail to identify at most 1% of the rows, because of duplicated or missing key values."),
+    ("key_presence", "The percentage of the values of a JSON column that parse as objects holding a top-level key. Keys present in only some of the objects point to optional fields or to several record layouts sharing a column."),
+    ("kurtosis", "A measure of the 'tailedness' of the probability distribution of a real-valued random variable. Kurtosis is the fourth central moment divided by the square of the variance. In this report's case, Fisher's definition is used, which results in `3.0` being subtracted from the result to give `0.0` for a normal distribution."),
+    ("leading_zeros", "Zeros before the first non-zero digit of a number, e.g. in the ZIP code `02134`. They're dropped when the value is cast to a number, so identifiers with leading zeros should stay strings."),
+    ("letter_case", "Whether the cased letters of a string value are all uppercase (upper), all lowercase (lower), capitalized words (title), or otherwise mixed. Values without letters are uncased. A column written in several cases usually holds the same categories entered inconsistently."),
+    ("likely_key", "A column whose values are all present and distinct, so each value identifies a single row, as expected of an identifier or primary key."),
+    ("list", "A variable size list, where each value holds any number of elements of the inner data type. List and array columns are summarized by the number of elements in each value and by statistics over the elements themselves."),
+    ("loading", "The weight of a feature in a principal component, between -1 and 1. Features with large absolute loadings drive the component, and the sign shows the direction of their contribution."),
+    ("mad", "Median absolute deviation, the median of the absolute differences between the values and their median, scaled by 1.4826 so it matches the standard deviation of normally distributed data. A robust measure of spread that measurement spikes barely move."),
+    ("mad_fences", "The lower and upper MAD fences, 3.5 scaled median absolute deviations below and above the median (a robust z-score of 3.5). Values outside the fences are flagged as outliers. Unlike z-score fences, the fences aren't widened by the outliers themselves."),
+    ("mad_outliers", "The number of values outside the MAD fences."),
+    ("max", "The highest value in a dataset or column."),
+    ("max_bytes", "The size in bytes of the largest value of a binary column."),
+    ("mean", "The average value of a dataset or column, calculated by summing all values and dividing by the count."),
+    ("mean_bytes", "The average size in bytes of the values of a binary column."),
+    ("mean_drift", "The change between the first and last rolling means of a feature over the time index, in standard deviations of the whole feature. Values far from 0 point to a trend or a level shift."),
+    ("median", "The middle value in a sorted dataset or column."),
+    ("min", "The lowest value in a dataset or column."),
+    ("min_bytes", "The size in bytes of the smallest value of a binary column."),
+    ("minority_class", "The least frequent class of the target."),
+    ("minority_percentage", "The percentage of the labeled rows of the target that are in the minority class. Models trained on data with a very small minority class tend to ignore it, so resampling or class weights may be needed."),
+    ("missing_periods", "The estimated number of samples missing from the gaps in the time index, based on the sampling interval."),
+    ("mode", "The most frequent value in a dataset or column."),
+    ("monotonicity", "Whether the values of an ID-like feature only go up (increasing) or only go down (decreasing) in row order. Values are ordered by length and then alphabetically. Monotonic IDs are usually generated sequentially, so gaps or reorderings can hint at deleted or shuffled rows."),
+    ("mutual_information", "A measure of how much knowing a feature reduces the uncertainty about the target, in nats. Unlike correlation, mutual information also picks up non-linear dependencies. A value of 0 means the feature and target are independent. Numerical values are grouped into equal frequency bins before computing it."),
+    ("n_classes", "The number of distinct classes of the target, excluding missing values."),
+    ("n_unique", "The number of distinct non-null values in a dataset or column."),
+    ("normality_p", "The p-value of a normality test: Shapiro-Wilk for up to 5000 values, and D'Agostino-Pearson (which combines the skewness and kurtosis) for more. Values below 0.05 mean the values are unlikely to come from a normal distribution. With many values, even negligible departures give tiny p-values, so the distribution label is the better guide."),
+    ("pacf", "Partial autocorrelation function, the correlation of a time series with a lagged copy of itself after removing the effect of the shorter lags. The last significant lag suggests the order of an autoregressive model."),
+    ("parse_failures", "The percentage of the non-missing values of a column detected as holding JSON objects that don't parse as JSON objects, e.g. truncated, malformed, or plain text values."),
+    ("partition", "One of the files of a dataset read from a directory or a glob pattern. A partition is flagged as anomalous when its schema differs from the schema shared by most partitions, it has no rows, its row count is less than half or more than twice the median, or its share of missing cells is more than 10 percentage points above the median."),
+    ("pattern", "The character-class shape of a string value, where uppercase letters become A, lowercase letters become a, digits become 9, and other characters are kept (e.g. ABC-1234 becomes AAA-9999). Patterns are shown as regular expressions, with each run of a class collapsed (e.g. [A-Z]{3}-\\d{4})."),
+    ("pearson_correlation", "The Pearson correlation between two numerical features, from -1 to 1, measuring how closely they follow a straight line. Values close to -1 or 1 mean one feature is nearly a linear function of the other, so one of them is largely redundant. It's sensitive to outliers."),
+    ("period", "The number of samples in one seasonal cycle of a time series, inferred from the sampling interval (e.g. 24 for hourly samples with a daily cycle) or from the strongest autocorrelation peak."),
+    ("psi", "Population stability index, a measure of how much the distribution of a column shifted from a baseline. The values are split into the baseline bins (deciles for numerical columns, the most frequent categories for categorical ones, and a bucket for missing values), and the PSI sums (current - baseline) * ln(current / baseline) over the bin proportions. Below 0.1 the column is stable, from 0.1 to 0.25 it moderately shifted, and from 0.25 it significantly shifted."),
+    ("q1", "First quartile, the median of the lower half of the dataset or column."),
+    ("q3", "Third quartile, the median of the upper half of the dataset or column."),
+    ("rare_level", "A level of a categorical feature holding less than the rare threshold share of the feature's non-missing values. Rare levels are often grouped into a single \"Other\" level before encoding, which shrinks one-hot encodings and handles levels that only show up in new data."),
+    ("residual_share", "The fraction of the variance of a time series left in the residual component of the seasonal decomposition, i.e. not explained by the trend or the seasonality."),
+    ("row_order_correlation", "The Spearman rank correlation between a feature and the row index, from -1 to 1. Values close to -1 or 1 mean the rows are sorted by the feature (e.g. by time), so splitting the rows by position gives train and test sets with different distributions."),
+    ("sampling_interval", "The expected time between consecutive samples of a time series, inferred as the median difference between consecutive distinct timestamps."),
+    ("seasonal_strength", "The strength of the seasonal component of a time series, from 0 to 1, computed as 1 - Var(residual) / Var(seasonal + residual). Values above 0.6 usually indicate clear seasonality."),
+    ("session", "A run of events of the same entity where the time between consecutive events is at most the session gap. A longer pause ends the session, and the next event starts a new one."),
+    ("significant_digits", "The digits of a number from its first non-zero digit, without the trailing zeros after the decimal point. A 64-bit float holds at most 15 significant digits exactly, so longer numbers (e.g. account numbers) are rounded when cast."),
+    ("skewness", "A metric for asymmetry or distortion, measuring the deviation of a given distribution of a random variable from a normal distribution. Positive values indicate a longer right tail and negative values a longer left tail."),
+    ("skewness_bias", "Skewness calculated with a bias correction factor. Skewness is a metric for asymmetry or distortion, measuring the deviation of a given distribution of a random variable from a normal distribution."),
+    ("skewness_raw", "Skewness calculated without bias correction. Skewness is a metric for asymmetry or distortion, measuring the deviation of a given distribution of a random variable from a normal distribution."),
+    ("spearman_correlation", "The Spearman rank correlation between two numerical features, from -1 to 1, i.e. the Pearson correlation of their ranks. It measures any monotonic relationship, linear or not, and is robust to outliers."),
+    ("staleness", "The time between the newest record timestamp and the time of the run. The data is stale when the staleness is above the freshness threshold."),
+    ("std_dev", "Standard deviation, a measure of the amount of variation or dispersion of a set of values."),
+    ("std_ratio", "The largest rolling standard deviation of a feature over the time index divided by the smallest. A ratio of 1 means the variance is stable over time, large ratios point to volatility changes."),
+    ("str", "A string, or text value."),
+    ("temporal_gaps", "The stretches between consecutive distinct values of a date, datetime, or time column that are more than 1.5 times the median difference between them. Gaps in an event or collection date usually mean missing data for that period."),
+    ("temporal_order", "Whether the values of a date, datetime, or time column only move forward (increasing) or only backward (decreasing) in row order, strictly if no value repeats the previous one. Sorted timestamps are common in append-only logs, and a split by position then separates periods."),
+    ("time_gaps", "The number of stretches in the time index where the difference between consecutive timestamps is more than 1.5 times the sampling interval."),
+    ("transform", "A function applied to every value of a feature to reduce its skewness before modeling. Log and square root transforms compress large values, Box-Cox picks the power transform (with parameter lambda) that makes the values most normal, and square or reflected log transforms correct a left skew."),
+    ("transformed_kurtosis", "The kurtosis of a feature after applying the suggested transform."),
+    ("transformed_skewness", "The skewness of a feature after applying the suggested transform."),
+    ("trend_strength", "The strength of the trend component of a time series, from 0 to 1, computed as 1 - Var(residual) / Var(trend + residual). Values close to 1 mean the series is dominated by its long term movement."),
+    ("trimmed_max", "The 95th percentile, the upper bound of the trimmed range that leaves out the 5% largest values."),
+    ("trimmed_mean", "The mean of the values within the trimmed range, i.e. without the 5% smallest and 5% largest values. A robust measure of central tendency."),
+    ("trimmed_min", "The 5th percentile, the lower bound of the trimmed range that leaves out the 5% smallest values."),
+    ("uniqueness_key", "One or more columns expected to identify every row, such as a primary key. The key is violated by rows that share their key values with another row or have a missing key value."),
+    ("uniqueness_ratio", "The number of distinct values of a feature divided by its number of non-null values. A ratio of 1 means every value is unique, as expected for identifiers."),
+    ("vif", "Variance inflation factor, `1 / (1 - R^2)` where `R^2` is from regressing a numerical feature on all the other numerical features. It measures how much the variance of a regression coefficient is inflated by correlated predictors. A VIF of 1 means no collinearity, and values above 5 to 10 are commonly considered problematic."),
+    ("z_fences", "The lower and upper z-score fences, three standard deviations below and above the mean. Values outside the fences are flagged as outliers."),
+    ("z_outliers", "The number of values outside the z-score fences, with their percentage of the non-null values."),
 ];
 
+/// Gets the glossary term that describes a `DataType`. Parameterized types (e.g. `list[i64]` or
+/// `decimal[10,2]`) map to their base type.
+///
+/// ### Parameters
+/// - `data_type`: A reference to a `DataType`.
+///
+/// ### Returns
+/// - `String`: The glossary term for the data type.
+pub fn get_data_type_term(data_type: &DataType) -> String {
+    match data_type {
+        DataType::List(_) => "list".to_owned(),
+        DataType::Array(_, _) => "array".to_owned(),
+        DataType::Decimal(_, _) => "decimal".to_owned(),
+        DataType::Datetime(_, _) => "datetime".to_owned(),
+        DataType::Categorical(_, _) => "cat".to_owned(),
+        DataType::Enum(_, _) => "enum".to_owned(),
+        _ => data_type.to_string(),
+    }
+}
+
 /// Categorizes a `DataType` into a high-level category.
 ///
 /// ### Parameters
@@ -84,6 +354,7 @@ pub fn get_data_type_category(data_type: &DataType) -> String {
         DataType::Int64 => "Numeric".to_owned(),
         DataType::Float32 => "Numeric".to_owned(),
         DataType::Float64 => "Numeric".to_owned(),
+        DataType::Decimal(_, _) => "Numeric".to_owned(),
         DataType::String => "Text".to_owned(),
         DataType::Binary => "Binary".to_owned(),
         DataType::Datetime(_, _) => "Date Time".to_owned(),
@@ -92,6 +363,8 @@ pub fn get_data_type_category(data_type: &DataType) -> String {
         DataType::Null => "Null".to_owned(),
         DataType::Array(_, _) => "Array".to_owned(),
         DataType::List(_) => "List".to_owned(),
+        DataType::Categorical(_, _) => "Categorical".to_owned(),
+        DataType::Enum(_, _) => "Categorical".to_owned(),
         _ => "Other or unknown".to_owned(),
     }
 }
@@ -0,0 +1,308 @@
+//! # History Module
+//!
+//! Handles the optional profiling history store, which records key metrics for each run so data
+//! quality can be tracked over time. The store is a JSON Lines file with one record per run, keyed
+//! by dataset name, which keeps it append-only and easy to inspect or load elsewhere.
+//!
+//! Each record holds the row and column counts, the overall missing percentage and quality score,
+//! and the missing percentage and quality score of each column.
+
+use super::summary::ReportSummary;
+#[cfg(feature = "time-series")]
+use crate::data::viz_lib::{
+    trend_viz::{self, TrendPlotError},
+    PlotTheme,
+};
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs::OpenOptions;
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use thiserror::Error;
+
+/// The error types for the history module.
+#[derive(Error, Debug)]
+pub enum HistoryError {
+    /// Occurs when an I/O operation fails.
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// Occurs when a record can't be serialized or a line of the store can't be parsed.
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+
+    /// Occurs when a column metric is queried for a column no record contains.
+    #[error("No history for column: {0}")]
+    UnknownColumn(String),
+
+    /// Occurs when the trend plot fails.
+    #[cfg(feature = "time-series")]
+    #[error("Trend plot error: {0}")]
+    Plot(#[from] TrendPlotError),
+}
+
+/// The metrics recorded for a single column in a run.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ColumnMetrics {
+    /// The percentage of missing values.
+    pub missing_percentage: f64,
+    /// The column quality score.
+    pub quality_score: f64,
+}
+
+/// The metrics recorded for a single run.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HistoryRecord {
+    /// Name of the dataset.
+    pub dataset: String,
+    /// When the run happened, as an RFC 3339 timestamp.
+    pub recorded_at: String,
+    /// The number of rows in the data.
+    pub n_rows: u64,
+    /// The number of columns in the data.
+    pub n_cols: u64,
+    /// The percentage of missing cells across the dataset.
+    pub missing_percentage: f64,
+    /// The mean column quality score.
+    pub quality_score: f64,
+    /// The per-column metrics, keyed by column name.
+    pub columns: IndexMap<String, ColumnMetrics>,
+}
+
+impl From<&ReportSummary> for HistoryRecord {
+    fn from(summary: &ReportSummary) -> Self {
+        Self {
+            dataset: summary.dataset.clone(),
+            recorded_at: summary.generated_at.clone(),
+            n_rows: summary.n_rows,
+            n_cols: summary.n_cols,
+            missing_percentage: summary.missing_percentage(),
+            quality_score: summary.quality_score(),
+            columns: summary
+                .columns
+                .iter()
+                .map(|column| {
+                    (
+                        column.name.clone(),
+                        ColumnMetrics {
+                            missing_percentage: column.missing_percentage,
+                            quality_score: column.quality_score,
+                        },
+                    )
+                })
+                .collect(),
+        }
+    }
+}
+
+/// The metrics that can be tracked over time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrendMetric {
+    /// The number of rows.
+    RowCount,
+    /// The missing percentage (for the dataset or a single column).
+    MissingPercentage,
+    /// The quality score (for the dataset or a single column).
+    QualityScore,
+}
+
+impl FromStr for TrendMetric {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "rows" | "row-count" => Ok(TrendMetric::RowCount),
+            "missing" | "missing-percentage" => Ok(TrendMetric::MissingPercentage),
+            "quality" | "quality-score" => Ok(TrendMetric::QualityScore),
+            other => Err(format!(
+                "unsupported metric '{}', expected one of: rows, missing, quality",
+                other
+            )),
+        }
+    }
+}
+
+impl fmt::Display for TrendMetric {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            TrendMetric::RowCount => "Row Count",
+            TrendMetric::MissingPercentage => "Missing Percentage",
+            TrendMetric::QualityScore => "Quality Score",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// A JSON Lines profiling history store.
+#[derive(Debug, Clone)]
+pub struct HistoryStore {
+    /// Path to the JSON Lines file.
+    path: PathBuf,
+}
+
+impl HistoryStore {
+    /// Constructor for the HistoryStore struct. The file is created on the first append.
+    ///
+    /// ### Parameters
+    ///
+    /// - `path`: Path to the JSON Lines file.
+    pub fn new(path: &Path) -> Self {
+        Self {
+            path: path.to_path_buf(),
+        }
+    }
+
+    /// Appends a run record to the store.
+    ///
+    /// ### Parameters
+    ///
+    /// - `record`: The run record.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<(), HistoryError>`: Unit type or a HistoryError.
+    pub fn append(&self, record: &HistoryRecord) -> Result<(), HistoryError> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        writeln!(file, "{}", serde_json::to_string(record)?)?;
+        Ok(())
+    }
+
+    /// Reads the run records for a dataset, in the order they were recorded.
+    ///
+    /// ### Parameters
+    ///
+    /// - `dataset`: The dataset name.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<Vec<HistoryRecord>, HistoryError>`: The records or a HistoryError.
+    pub fn records(&self, dataset: &str) -> Result<Vec<HistoryRecord>, HistoryError> {
+        if !self.path.exists() {
+            return Ok(Vec::new());
+        }
+        let reader = BufReader::new(std::fs::File::open(&self.path)?);
+        let mut records = Vec::new();
+        for line in reader.lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let record: HistoryRecord = serde_json::from_str(&line)?;
+            if record.dataset == dataset {
+                records.push(record);
+            }
+        }
+        Ok(records)
+    }
+
+    /// Reads the most recent run record for a dataset.
+    ///
+    /// ### Parameters
+    ///
+    /// - `dataset`: The dataset name.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<Option<HistoryRecord>, HistoryError>`: The record, None if the dataset has no
+    ///   runs, or a HistoryError.
+    pub fn latest(&self, dataset: &str) -> Result<Option<HistoryRecord>, HistoryError> {
+        Ok(self.records(dataset)?.pop())
+    }
+
+    /// Gets the value of a metric across the runs of a dataset.
+    ///
+    /// ### Parameters
+    ///
+    /// - `dataset`: The dataset name.
+    /// - `metric`: The metric to track.
+    /// - `column`: Optional column to track the metric for, instead of the whole dataset. Ignored
+    ///   for the row count.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<Vec<(String, f64)>, HistoryError>`: The run timestamps and metric values, or a
+    ///   HistoryError. Runs missing the column are skipped.
+    pub fn trend(
+        &self,
+        dataset: &str,
+        metric: TrendMetric,
+        column: Option<&str>,
+    ) -> Result<Vec<(String, f64)>, HistoryError> {
+        let records = self.records(dataset)?;
+        let points: Vec<(String, f64)> = records
+            .iter()
+            .filter_map(|record| {
+                let value = match (metric, column) {
+                    (TrendMetric::RowCount, _) => Some(record.n_rows as f64),
+                    (TrendMetric::MissingPercentage, None) => Some(record.missing_percentage),
+                    (TrendMetric::QualityScore, None) => Some(record.quality_score),
+                    (TrendMetric::MissingPercentage, Some(column)) => record
+                        .columns
+                        .get(column)
+                        .map(|metrics| metrics.missing_percentage),
+                    (TrendMetric::QualityScore, Some(column)) => record
+                        .columns
+                        .get(column)
+                        .map(|metrics| metrics.quality_score),
+                };
+                value.map(|value| (record.recorded_at.clone(), value))
+            })
+            .collect();
+
+        match column {
+            Some(column)
+                if metric != TrendMetric::RowCount && points.is_empty() && !records.is_empty() =>
+            {
+                Err(HistoryError::UnknownColumn(column.to_owned()))
+            }
+            _ => Ok(points),
+        }
+    }
+
+    /// Plots the value of a metric across the runs of a dataset.
+    ///
+    /// ### Parameters
+    ///
+    /// - `dataset`: The dataset name.
+    /// - `metric`: The metric to track.
+    /// - `column`: Optional column to track the metric for.
+    /// - `output_path`: Path to save the plot image to.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<(), HistoryError>`: Unit type or a HistoryError.
+    #[cfg(feature = "time-series")]
+    pub fn plot_trend(
+        &self,
+        dataset: &str,
+        metric: TrendMetric,
+        column: Option<&str>,
+        output_path: &PathBuf,
+    ) -> Result<(), HistoryError> {
+        let points: Vec<(String, f64)> = self
+            .trend(dataset, metric, column)?
+            .into_iter()
+            // Only keep the date portion of the timestamps for the axis labels.
+            .map(|(recorded_at, value)| (recorded_at.chars().take(10).collect(), value))
+            .collect();
+        let title = match column {
+            Some(column) if metric != TrendMetric::RowCount => {
+                format!("{} {} Trend", column, metric)
+            }
+            _ => format!("{} {} Trend", dataset, metric),
+        };
+        trend_viz::build_trend_plot(
+            &points,
+            &title,
+            &metric.to_string(),
+            output_path,
+            &PlotTheme::default(),
+        )?;
+        Ok(())
+    }
+}
@@ -0,0 +1,272 @@
+//! # Metrics Module
+//!
+//! Handles exporting per-column data quality metrics in the Prometheus text exposition format (or
+//! its OpenMetrics variant), so data quality dashboards can scrape and alert on LEADS output.
+//!
+//! ## Exported Metrics
+//!
+//! - `leads_rows`: The number of rows in the dataset.
+//! - `leads_columns`: The number of columns in the dataset.
+//! - `leads_column_missing_ratio`: The fraction of missing values per column.
+//! - `leads_column_distinct_count`: The number of distinct values per column (null counts as a
+//!   value).
+//! - `leads_column_drift_score`: The population stability index (PSI) of each column against the
+//!   baseline profile, only exported when a baseline is given.
+//! - `leads_column_missing_ratio_delta`: The absolute change in the missing ratio per column since
+//!   the previous run in the history store, only exported when a previous run is available.
+
+use super::{history::HistoryRecord, summary::ReportSummary};
+use crate::data::drift::DriftAnalysis;
+use crate::prelude::DataInfo;
+use indexmap::IndexMap;
+use polars::prelude::*;
+use std::fmt::{self, Write};
+use std::path::Path;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// The error types for the metrics module.
+#[derive(Error, Debug)]
+pub enum MetricsError {
+    /// Occurs when an I/O operation fails.
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// Occurs when Polars fails to compute the distinct counts.
+    #[error("Polars error: {0}")]
+    Polars(#[from] PolarsError),
+}
+
+/// The text formats the metrics can be exported in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MetricsFormat {
+    /// The Prometheus text exposition format.
+    Prometheus,
+    /// The OpenMetrics text format.
+    OpenMetrics,
+}
+
+impl FromStr for MetricsFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "prometheus" => Ok(MetricsFormat::Prometheus),
+            "openmetrics" => Ok(MetricsFormat::OpenMetrics),
+            other => Err(format!(
+                "unsupported metrics format '{}', expected one of: prometheus, openmetrics",
+                other
+            )),
+        }
+    }
+}
+
+impl fmt::Display for MetricsFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            MetricsFormat::Prometheus => "prometheus",
+            MetricsFormat::OpenMetrics => "openmetrics",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Computes the number of distinct values in each column. Nested list and array columns are
+/// skipped.
+///
+/// ### Parameters
+///
+/// - `data_info`: The dataset information.
+///
+/// ### Returns
+///
+/// - `Result<IndexMap<String, u64>, MetricsError>`: The distinct counts keyed by column name, or
+///   an error.
+pub fn distinct_counts(data_info: &DataInfo) -> Result<IndexMap<String, u64>, MetricsError> {
+    let columns: Vec<&String> = data_info
+        .column_types
+        .iter()
+        .filter(|(_, dtype)| !dtype.is_nested())
+        .map(|(name, _)| name)
+        .collect();
+    let df = data_info
+        .data
+        .clone()
+        .select(
+            columns
+                .iter()
+                .map(|name| col(name).n_unique().cast(DataType::UInt64))
+                .collect::<Vec<Expr>>(),
+        )
+        .collect()?;
+
+    let mut counts = IndexMap::with_capacity(columns.len());
+    for name in columns {
+        if let Some(count) = df.column(name)?.u64()?.get(0) {
+            counts.insert(name.to_owned(), count);
+        }
+    }
+    Ok(counts)
+}
+
+/// Renders the metrics for a run.
+///
+/// ### Parameters
+///
+/// - `summary`: The run summary.
+/// - `distinct_counts`: The distinct counts keyed by column name.
+/// - `drift_analysis`: The drift of the columns from the baseline profile, used for the drift
+///   score.
+/// - `previous_run`: The previous run of the dataset from the history store, used for the missing
+///   ratio delta.
+/// - `format`: The text format.
+///
+/// ### Returns
+///
+/// - `String`: The metrics text.
+pub fn render(
+    summary: &ReportSummary,
+    distinct_counts: &IndexMap<String, u64>,
+    drift_analysis: Option<&DriftAnalysis>,
+    previous_run: Option<&HistoryRecord>,
+    format: MetricsFormat,
+) -> String {
+    let dataset_label = format!("dataset=\"{}\"", escape_label(&summary.dataset));
+    let column_labels =
+        |column: &str| format!("{{{},column=\"{}\"}}", dataset_label, escape_label(column));
+    let mut output = String::new();
+
+    write_header(&mut output, "leads_rows", "Number of rows in the dataset.");
+    writeln!(output, "leads_rows{{{}}} {}", dataset_label, summary.n_rows).unwrap();
+    write_header(
+        &mut output,
+        "leads_columns",
+        "Number of columns in the dataset.",
+    );
+    writeln!(
+        output,
+        "leads_columns{{{}}} {}",
+        dataset_label, summary.n_cols
+    )
+    .unwrap();
+
+    write_header(
+        &mut output,
+        "leads_column_missing_ratio",
+        "Fraction of missing values in the column.",
+    );
+    for column in &summary.columns {
+        writeln!(
+            output,
+            "leads_column_missing_ratio{} {}",
+            column_labels(&column.name),
+            column.missing_percentage / 100.0
+        )
+        .unwrap();
+    }
+
+    write_header(
+        &mut output,
+        "leads_column_distinct_count",
+        "Number of distinct values in the column.",
+    );
+    for (column, count) in distinct_counts {
+        writeln!(
+            output,
+            "leads_column_distinct_count{} {}",
+            column_labels(column),
+            count
+        )
+        .unwrap();
+    }
+
+    if let Some(drift_analysis) = drift_analysis {
+        write_header(
+            &mut output,
+            "leads_column_drift_score",
+            "Population stability index of the column against the baseline profile.",
+        );
+        for (column, drift) in &drift_analysis.columns {
+            writeln!(
+                output,
+                "leads_column_drift_score{} {}",
+                column_labels(column),
+                drift.psi
+            )
+            .unwrap();
+        }
+    }
+
+    if let Some(previous_run) = previous_run {
+        write_header(
+            &mut output,
+            "leads_column_missing_ratio_delta",
+            "Absolute change in the column missing ratio since the previous run.",
+        );
+        for column in &summary.columns {
+            if let Some(previous) = previous_run.columns.get(&column.name) {
+                let delta = (column.missing_percentage - previous.missing_percentage).abs() / 100.0;
+                writeln!(
+                    output,
+                    "leads_column_missing_ratio_delta{} {}",
+                    column_labels(&column.name),
+                    delta
+                )
+                .unwrap();
+            }
+        }
+    }
+
+    if format == MetricsFormat::OpenMetrics {
+        output.push_str("# EOF\n");
+    }
+
+    output
+}
+
+/// Computes and writes the metrics for a run to a file.
+///
+/// ### Parameters
+///
+/// - `data_info`: The dataset information.
+/// - `summary`: The run summary.
+/// - `previous_run`: The previous run of the dataset from the history store.
+/// - `format`: The text format.
+/// - `path`: The path to write the metrics to.
+///
+/// ### Returns
+///
+/// - `Result<(), MetricsError>`: Unit type or a MetricsError.
+pub fn save_to_file(
+    data_info: &DataInfo,
+    summary: &ReportSummary,
+    previous_run: Option<&HistoryRecord>,
+    format: MetricsFormat,
+    path: &Path,
+) -> Result<(), MetricsError> {
+    let counts = distinct_counts(data_info)?;
+    std::fs::write(
+        path,
+        render(
+            summary,
+            &counts,
+            data_info.drift_analysis.as_ref(),
+            previous_run,
+            format,
+        ),
+    )?;
+    Ok(())
+}
+
+fn write_header(output: &mut String, name: &str, help: &str) {
+    writeln!(output, "# HELP {} {}", name, help).unwrap();
+    writeln!(output, "# TYPE {} gauge", name).unwrap();
+}
+
+/// Escapes a label value per the exposition format.
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
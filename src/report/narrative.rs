@@ -0,0 +1,312 @@
+//! # Narrative Module
+//!
+//! Handles generating short human readable insights from the analysis results. The insights are
+//! written by a fixed set of rules and grouped by the report section they're rendered under:
+//!
+//! - Descriptive Analysis: constant, ID-like, and bimodal columns.
+//! - Duplicates: full-row duplicates and the worst offender of an invalid uniqueness key.
+//! - Transformation Suggestions: moderately and highly skewed features, with the suggested
+//!   transformation.
+//! - Category Merges: categorical features with rare levels, with the levels left after merging
+//!   them.
+//! - Outlier Analysis: features with at least `OUTLIER_INSIGHT_PERCENTAGE` percent IQR outliers.
+//! - Multicollinearity: features with a variance inflation factor above the threshold.
+//! - Categorical Associations: pairs with a Cramér's V of at least `STRONG_ASSOCIATION`.
+//! - Drift: columns that significantly shifted from the baseline profile.
+//! - Missing Values Analysis: columns with at least `CONCENTRATION_MIN_MISSING` percent missing
+//!   values, with the group of rows their missing values are concentrated in (if any).
+
+use crate::data::dictionary::ColumnTag;
+use crate::data::drift::DriftLevel;
+use crate::data::missing_values::CONCENTRATION_MIN_MISSING;
+use crate::data::normality::DistributionLabel;
+use crate::data::transformations::STRONG_SKEWNESS_THRESHOLD;
+use crate::prelude::DataInfo;
+use indexmap::IndexMap;
+
+/// Percentage of IQR outliers from which a feature gets an outlier insight.
+pub const OUTLIER_INSIGHT_PERCENTAGE: f64 = 5.0;
+/// Cramér's V from which a pair of categorical features is strongly associated.
+pub const STRONG_ASSOCIATION: f64 = 0.5;
+
+/// The insights generated for a report, grouped by section title.
+#[derive(Debug, Default, Clone)]
+pub struct Narrative {
+    /// The insights of each section, keyed by section title, in report order.
+    pub insights: IndexMap<String, Vec<String>>,
+}
+
+impl Narrative {
+    /// Constructor for the Narrative struct, applying the insight rules to the analysis results.
+    ///
+    /// ### Parameters
+    ///
+    /// - `data_info`: The dataset information.
+    ///
+    /// ### Returns
+    ///
+    /// - `Narrative`: The insights for the report.
+    pub fn new(data_info: &DataInfo) -> Self {
+        let mut narrative = Self::default();
+        let descriptive_analysis = &data_info.descriptive_analysis;
+        let n_rows = descriptive_analysis.n_rows;
+
+        for name in descriptive_analysis.constant_columns() {
+            narrative.add(
+                "Descriptive Analysis",
+                format!(
+                    "Column `{}` has a single distinct value and carries no information; \
+                     consider dropping it.",
+                    name
+                ),
+            );
+        }
+        for (name, stats) in &descriptive_analysis.high_cardinality_stats {
+            if data_info.has_tag(name, ColumnTag::Id) {
+                continue;
+            }
+            narrative.add(
+                "Descriptive Analysis",
+                format!(
+                    "Column `{}` looks like an identifier ({:.2}% unique values); exclude it from \
+                     modeling features.",
+                    name,
+                    stats.uniqueness_ratio * 100.0
+                ),
+            );
+        }
+        for (name, stats) in &descriptive_analysis.normality {
+            if stats.label == DistributionLabel::Bimodal {
+                narrative.add(
+                    "Descriptive Analysis",
+                    format!(
+                        "Column `{}` has two separate clusters of values; it may mix two \
+                         populations (e.g. groups or units) worth splitting.",
+                        name
+                    ),
+                );
+            }
+        }
+
+        let duplicate_analysis = &data_info.duplicate_analysis;
+        if duplicate_analysis.n_duplicate_rows > 0 {
+            narrative.add(
+                "Duplicates",
+                format!(
+                    "{:.2}% of the rows are exact copies of an earlier row; deduplicate before \
+                     counting or modeling.",
+                    duplicate_analysis.duplicate_percentage()
+                ),
+            );
+        }
+        let duplicate_columns: Vec<String> = duplicate_analysis
+            .duplicate_columns
+            .iter()
+            .map(|duplicate| format!("`{}`", duplicate.column))
+            .collect();
+        match duplicate_columns.as_slice() {
+            [] => {}
+            [column] => narrative.add(
+                "Duplicates",
+                format!(
+                    "Column {} duplicates or is derived from an earlier column; drop it before \
+                     modeling to avoid a redundant feature.",
+                    column
+                ),
+            ),
+            columns => narrative.add(
+                "Duplicates",
+                format!(
+                    "Columns {} duplicate or are derived from earlier columns; drop them before \
+                     modeling to avoid redundant features.",
+                    columns.join(", ")
+                ),
+            ),
+        }
+        if duplicate_analysis.key_violations.is_none() {
+            if let Some(candidate) = duplicate_analysis.key_candidates.first() {
+                narrative.add(
+                    "Duplicates",
+                    if candidate.is_unique() {
+                        format!(
+                            "The key candidate ({}) uniquely identifies the rows; pass it as the \
+                             key to validate it on later extracts.",
+                            candidate.columns.join(", ")
+                        )
+                    } else {
+                        format!(
+                            "The key candidate ({}) nearly identifies the rows but for {} duplicated \
+                             and {} missing keys; check them before relying on it as a key.",
+                            candidate.columns.join(", "),
+                            candidate.n_duplicate_rows,
+                            candidate.n_null_keys
+                        )
+                    },
+                );
+            }
+        }
+        if let Some(key_violations) = &duplicate_analysis.key_violations {
+            if let Some((values, n_rows)) = key_violations.worst_offenders.first() {
+                narrative.add(
+                    "Duplicates",
+                    format!(
+                        "The key ({}) doesn't uniquely identify rows: {} key values are shared by \
+                         {} rows, the worst ({}) by {} rows.",
+                        key_violations.columns.join(", "),
+                        key_violations.n_duplicate_keys,
+                        key_violations.n_violating_rows,
+                        values.join(", "),
+                        n_rows
+                    ),
+                );
+            }
+        }
+
+        for (name, suggestion) in &data_info.transformation_analysis.suggestions {
+            let strength = if suggestion.skewness.abs() >= STRONG_SKEWNESS_THRESHOLD {
+                "highly"
+            } else {
+                "moderately"
+            };
+            let direction = if suggestion.skewness > 0.0 {
+                "right"
+            } else {
+                "left"
+            };
+            narrative.add(
+                "Transformation Suggestions",
+                format!(
+                    "Column `{}` is {} {}-skewed (skew {:.2}); consider a {} transform.",
+                    name,
+                    strength,
+                    direction,
+                    suggestion.skewness,
+                    suggestion.transform.name().to_lowercase()
+                ),
+            );
+        }
+
+        for (name, suggestion) in &data_info.category_analysis.suggestions {
+            narrative.add(
+                "Category Merges",
+                format!(
+                    "Column `{}` has {} rare levels out of {}; grouping them as \"Other\" leaves {} \
+                     levels.",
+                    name,
+                    suggestion.n_rare(),
+                    suggestion.n_levels(),
+                    suggestion.n_merged_levels()
+                ),
+            );
+        }
+
+        if n_rows > 0 {
+            for (name, fences) in &data_info.outlier_analysis.fences {
+                let percentage = fences.iqr_count as f64 / n_rows as f64 * 100.0;
+                if percentage >= OUTLIER_INSIGHT_PERCENTAGE {
+                    narrative.add(
+                        "Outlier Analysis",
+                        format!(
+                            "Column `{}` has {:.2}% of its values outside the IQR fences; check \
+                             for data entry errors or a heavy tail before modeling.",
+                            name, percentage
+                        ),
+                    );
+                }
+            }
+        }
+
+        let collinearity_analysis = &data_info.collinearity_analysis;
+        for (name, vif) in collinearity_analysis.high_vif() {
+            narrative.add(
+                "Multicollinearity",
+                format!(
+                    "Column `{}` is largely explained by the other numerical features (VIF \
+                     {:.2}); consider dropping or combining it.",
+                    name, vif
+                ),
+            );
+        }
+
+        for table in &data_info.association_analysis.tables {
+            if table.cramers_v >= STRONG_ASSOCIATION {
+                narrative.add(
+                    "Categorical Associations",
+                    format!(
+                        "Columns `{}` and `{}` are strongly associated (Cramér's V {:.2}); one \
+                         may be redundant.",
+                        table.pair.rows, table.pair.columns, table.cramers_v
+                    ),
+                );
+            }
+        }
+
+        if let Some(drift_analysis) = &data_info.drift_analysis {
+            for (name, drift) in drift_analysis.drifted() {
+                if drift.level == DriftLevel::Significant {
+                    narrative.add(
+                        "Drift",
+                        format!(
+                            "Column `{}` significantly shifted from the baseline (PSI {:.2}); \
+                             re-validate the models and rules that depend on it.",
+                            name, drift.psi
+                        ),
+                    );
+                }
+            }
+        }
+
+        let missing_value_analysis = &data_info.missing_value_analysis;
+        for (name, (_, missing_percentage)) in &missing_value_analysis.column_missing_values {
+            if *missing_percentage >= 100.0 {
+                narrative.add(
+                    "Missing Values Analysis",
+                    format!(
+                        "Column `{}` is entirely missing; consider dropping it.",
+                        name
+                    ),
+                );
+            } else if *missing_percentage >= CONCENTRATION_MIN_MISSING {
+                let insight = match missing_value_analysis.concentrations.get(name) {
+                    Some(concentration) => format!(
+                        "Column `{}` is {:.2}% missing, concentrated in rows where `{}` = '{}' \
+                         ({:.2}% of its missing values).",
+                        name,
+                        missing_percentage,
+                        concentration.group_column,
+                        concentration.group_value,
+                        concentration.share
+                    ),
+                    None => format!("Column `{}` is {:.2}% missing.", name, missing_percentage),
+                };
+                narrative.add("Missing Values Analysis", insight);
+            }
+        }
+
+        narrative
+    }
+
+    /// Gets the insights of a section.
+    ///
+    /// ### Parameters
+    ///
+    /// - `section`: The section title.
+    ///
+    /// ### Returns
+    ///
+    /// - `&[String]`: The insights of the section, empty if it has none.
+    pub fn section_insights(&self, section: &str) -> &[String] {
+        self.insights
+            .get(section)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Adds an insight to a section.
+    fn add(&mut self, section: &str, insight: String) {
+        self.insights
+            .entry(section.to_owned())
+            .or_default()
+            .push(insight);
+    }
+}
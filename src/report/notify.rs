@@ -0,0 +1,124 @@
+//! # Notify Module
+//!
+//! Handles the optional post-run hook, which POSTs the run summary to a webhook so scheduled
+//! profiling runs can notify data owners automatically. The payload is either the raw JSON summary
+//! or a Slack incoming webhook message with the key alerts and the report path.
+
+use super::summary::ReportSummary;
+use serde_json::json;
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Maximum number of alerts listed in a Slack message.
+pub const SLACK_MAX_ALERTS: usize = 10;
+
+/// The error types for the notify module.
+#[derive(Error, Debug)]
+pub enum NotifyError {
+    /// Occurs when the webhook request fails or returns an error status.
+    #[error("Webhook request error: {0}")]
+    Http(#[from] Box<ureq::Error>),
+
+    /// Occurs when the payload can't be serialized.
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+/// The payload formats a webhook can be sent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WebhookFormat {
+    /// The full JSON run summary.
+    Json,
+    /// A Slack incoming webhook message.
+    Slack,
+}
+
+impl FromStr for WebhookFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "json" => Ok(WebhookFormat::Json),
+            "slack" => Ok(WebhookFormat::Slack),
+            other => Err(format!(
+                "unsupported webhook format '{}', expected one of: json, slack",
+                other
+            )),
+        }
+    }
+}
+
+impl fmt::Display for WebhookFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            WebhookFormat::Json => "json",
+            WebhookFormat::Slack => "slack",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// Sends the run summary to a webhook.
+///
+/// ### Parameters
+///
+/// - `url`: The webhook URL.
+/// - `format`: The payload format.
+/// - `summary`: The run summary.
+///
+/// ### Returns
+///
+/// - `Result<(), NotifyError>`: Unit type or a NotifyError.
+pub fn send_webhook(
+    url: &str,
+    format: WebhookFormat,
+    summary: &ReportSummary,
+) -> Result<(), NotifyError> {
+    let payload = match format {
+        WebhookFormat::Json => serde_json::to_value(summary)?,
+        WebhookFormat::Slack => json!({ "text": slack_message(summary) }),
+    };
+    ureq::post(url).send_json(payload).map_err(Box::new)?;
+    Ok(())
+}
+
+/// Formats the Slack message text for a run summary.
+///
+/// ### Parameters
+///
+/// - `summary`: The run summary.
+///
+/// ### Returns
+///
+/// - `String`: The message in Slack mrkdwn.
+pub fn slack_message(summary: &ReportSummary) -> String {
+    let mut lines = vec![
+        format!("*LEADS report for `{}`*", summary.dataset),
+        format!("Rows: {} | Columns: {}", summary.n_rows, summary.n_cols),
+    ];
+    if let Some(report_path) = &summary.report_path {
+        lines.push(format!("Report: `{}`", report_path));
+    }
+
+    if summary.alerts.is_empty() {
+        lines.push("No alerts.".to_owned());
+    } else {
+        lines.push(format!("*Alerts ({}):*", summary.alerts.len()));
+        lines.extend(
+            summary
+                .alerts
+                .iter()
+                .take(SLACK_MAX_ALERTS)
+                .map(|alert| format!("• {}", alert)),
+        );
+        if summary.alerts.len() > SLACK_MAX_ALERTS {
+            lines.push(format!(
+                "…and {} more",
+                summary.alerts.len() - SLACK_MAX_ALERTS
+            ));
+        }
+    }
+
+    lines.join("\n")
+}
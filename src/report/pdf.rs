@@ -3,20 +3,59 @@
 //! Handles the base implementation of generating a comprehensive PDF report with the exploratory
 //! analysis findings.
 
+#[cfg(feature = "pca")]
+use crate::data::pca::PcaAnalysis;
+#[cfg(feature = "time-series")]
+use crate::data::time_series::TimeSeriesAnalysis;
 use crate::{
-    data::visualizations::ReportSection,
+    data::{
+        associations::AssociationAnalysis,
+        categories::CategoryMergeAnalysis,
+        collinearity::CollinearityAnalysis,
+        correlation::CorrelationAnalysis,
+        dictionary::ColumnDefinition,
+        drift::DriftAnalysis,
+        duplicates::DuplicateAnalysis,
+        freshness::FreshnessAnalysis,
+        null_if::NullIfAnalysis,
+        outliers::OutlierAnalysis,
+        partitions::PartitionAnalysis,
+        problem_rows::ProblemRowAnalysis,
+        provenance::{format_file_size, Provenance},
+        recovery::RecoverableAnalysis,
+        references::ForeignValueAnalysis,
+        robust::RobustAnalysis,
+        row_order::RowOrderAnalysis,
+        sessions::SessionAnalysis,
+        tables::TableSet,
+        target::TargetAnalysis,
+        temporal::TemporalAnalysis,
+        text::TextAnalysis,
+        throughput::Throughput,
+        timeout::BudgetedAnalysis,
+        transformations::TransformationAnalysis,
+        visualizations::PlotGroup,
+    },
     prelude::{
-        DataInfo, DescriptiveAnalysis, LeadsError, MissingValueAnalysis, VisualizationManager,
+        DataDictionary, DataInfo, DescriptiveAnalysis, LeadsError, MissingValueAnalysis,
+        VisualizationManager,
     },
+    progress::{ProgressCallback, ProgressEvent, Stage, StageTracker},
 };
 use image::{GenericImageView, ImageReader};
 use indexmap::IndexMap;
 use pdfium_render::prelude::*;
 use polars::datatypes::DataType;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use thiserror::Error;
 
-use super::glossary::{get_data_type_category, Glossary};
+use super::format::Locale;
+use super::glossary::{get_data_type_category, get_data_type_term, Glossary};
+use super::narrative::Narrative;
+use super::sections::{self, truncate_example, truncate_text, ReportBlock, ReportSection};
+use super::snippets;
+use super::summary::ReportSummary;
+use super::tagging::{self, image_alt_text, TableCell, TagRole, TaggedContent, TaggingError};
 
 /// The default paper size.
 pub const PAPER_SIZE: PdfPagePaperStandardSize = PdfPagePaperStandardSize::A4;
@@ -26,16 +65,28 @@ pub const FONT: PdfFontBuiltin = PdfFontBuiltin::TimesRoman;
 pub const BOLD_FONT: PdfFontBuiltin = PdfFontBuiltin::TimesBold;
 /// The default italic font.
 pub const ITALIC_FONT: PdfFontBuiltin = PdfFontBuiltin::TimesItalic;
+/// The monospaced font for code.
+pub const CODE_FONT: PdfFontBuiltin = PdfFontBuiltin::Courier;
 /// Section header font size.
 pub const SECTION_HEADER_FONT_SIZE: f32 = 24.0;
 /// Sub-header for feature names.
 pub const FEATURE_HEADER_FONT_SIZE: f32 = 14.0;
 /// Normal text font size.
 pub const FONT_SIZE: f32 = 12.0;
+/// Code font size.
+pub const CODE_FONT_SIZE: f32 = 9.0;
 /// Bottom page margin.
 pub const BOTTOM_MARGIN: f32 = 0.1;
 /// Padding between normal lines of text.
 pub const LINE_HEIGHT_PADDING: f32 = 0.005;
+/// The number of side by side column groups of the data types table in wide mode.
+pub const WIDE_TYPE_COLUMNS: usize = 3;
+/// The font size of the data types table in wide mode.
+pub const WIDE_TYPE_FONT_SIZE: f32 = 10.0;
+/// Maximum number of characters of a column name or data type in the wide mode data types table.
+pub const WIDE_TYPE_NAME_LENGTH: usize = 14;
+/// Number of column details from which a section gets a mini table of contents of its columns.
+pub const MIN_MINI_TOC_ENTRIES: usize = 10;
 
 /// The error types for the pdf modules.
 #[derive(Error, Debug)]
@@ -51,10 +102,45 @@ pub enum PdfError {
     /// Occurs during an image decoding error
     #[error("Image error: {0}")]
     Image(#[from] image::error::ImageError),
+
+    /// Occurs when the structure tree can't be added to the saved document.
+    #[error("Tagging error: {0}")]
+    Tagging(#[from] TaggingError),
+}
+
+/// How the text drawn next is tagged for screen readers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TextTag {
+    /// As a heading or paragraph, depending on its font size.
+    Flow,
+    /// As a table cell.
+    Cell(TableCell),
+    /// As an artifact, left out of the structure tree.
+    Artifact,
 }
 
+/// The mini table of contents of the section being drawn, inserted before its column details once
+/// the section is done, when their pages are known.
+#[derive(Debug)]
+struct MiniToc {
+    /// The index of the page the column details start on, where the mini table of contents is
+    /// inserted.
+    index: u32,
+    /// The index of the page of each column detail, in drawing order.
+    entries: IndexMap<String, u32>,
+}
+
+/// Builds a custom report section through its page context.
+type CustomSectionBuilder<'a> =
+    Box<dyn for<'p> FnOnce(&mut PageContext<'p, 'a>) -> Result<(), PdfError> + 'a>;
+
 /// Struct that keeps track of the current page position and number. Allows for manual page
 /// management, page break handling, and flow content across multiple pages.
+///
+/// Positions are fractions of the page size, with `(0, 0)` in the bottom left corner. Besides the
+/// built-in report sections, the drawing methods (`add_text`, `add_line`, `add_rectangle`,
+/// `add_image`, `add_table`, `add_stat_block`, ...) can be used to draw custom content, and
+/// `custom_section` registers whole custom sections that are rendered with the report.
 pub struct PageManager<'a> {
     /// The PDF document instance.
     document: PdfDocument<'a>,
@@ -70,8 +156,39 @@ pub struct PageManager<'a> {
     bold_font: PdfFontToken,
     /// The italic font.
     italic_font: PdfFontToken,
+    /// The monospaced code font.
+    code_font: PdfFontToken,
     /// Section page tracker for table of contents.
     section_page_map: IndexMap<String, u32>,
+    /// The page index of each section and plot, keyed by their stable ID, saved as the named
+    /// destinations of the document.
+    destinations: IndexMap<String, u32>,
+    /// The named destination of each section entry and plot path recorded so far, suffixed with
+    /// `-2`, `-3`, ... when the stable ID of another name already took it.
+    destination_ids: IndexMap<String, String>,
+    /// The glossary of terms used in the report.
+    glossary: Glossary,
+    /// The glossary terms referenced so far in the report, mapped to the section that first
+    /// referenced them.
+    referenced_terms: IndexMap<String, String>,
+    /// Optional callback to report the report generation and saving progress to.
+    progress: Option<ProgressCallback>,
+    /// The custom sections to render with the report, in registration order.
+    custom_sections: Vec<(String, CustomSectionBuilder<'a>)>,
+    /// The narrative insights rendered under their sections.
+    narrative: Narrative,
+    /// The locale the numbers and dates of the report text are formatted in.
+    locale: Locale,
+    /// The mini table of contents of the section being drawn, if it gets one.
+    mini_toc: Option<MiniToc>,
+    /// The tagged content of each page, in drawing order.
+    tags: Vec<Vec<TaggedContent>>,
+    /// How the text drawn next is tagged.
+    text_tag: TextTag,
+    /// The number of tables drawn so far.
+    n_tables: usize,
+    /// The name of the table whose sections are being drawn, in reports over several tables.
+    table: Option<String>,
 }
 
 impl<'a> PageManager<'a> {
@@ -89,6 +206,7 @@ impl<'a> PageManager<'a> {
         let font = document.fonts_mut().new_built_in(FONT);
         let bold_font = document.fonts_mut().new_built_in(BOLD_FONT);
         let italic_font = document.fonts_mut().new_built_in(ITALIC_FONT);
+        let code_font = document.fonts_mut().new_built_in(CODE_FONT);
         Ok(PageManager {
             document,
             current_page: 0,
@@ -97,25 +215,433 @@ impl<'a> PageManager<'a> {
             font,
             bold_font,
             italic_font,
+            code_font,
             section_page_map: IndexMap::new(),
+            destinations: IndexMap::new(),
+            destination_ids: IndexMap::new(),
+            glossary: Glossary::new(),
+            referenced_terms: IndexMap::new(),
+            progress: None,
+            custom_sections: Vec::new(),
+            narrative: Narrative::default(),
+            locale: Locale::default(),
+            mini_toc: None,
+            tags: Vec::new(),
+            text_tag: TextTag::Flow,
+            n_tables: 0,
+            table: None,
         })
     }
 
+    /// Sets the callback to report the report generation and saving progress to.
+    pub fn set_progress(&mut self, progress: ProgressCallback) {
+        self.progress = Some(progress);
+    }
+
+    /// Sets the locale the numbers and dates of the report sections are formatted in. The values
+    /// taken from the data, e.g. the example values of the data types overview, are left as is.
+    pub fn set_locale(&mut self, locale: Locale) {
+        self.locale = locale;
+    }
+
+    /// Gets a mutable reference to the report glossary, allowing terms to be added or overridden
+    /// before the report is generated.
+    pub fn glossary_mut(&mut self) -> &mut Glossary {
+        &mut self.glossary
+    }
+
+    /// Registers a custom section (e.g. business-specific KPIs) to render with the report. Custom
+    /// sections start on a new page with the title as the section header, are listed in the table
+    /// of contents, and are rendered in registration order after the built-in analysis sections
+    /// and before the appendix. Must be called before `generate_report`.
+    ///
+    /// ### Parameters
+    ///
+    /// - `title`: The section title.
+    /// - `build`: The function drawing the section content through its page context.
+    pub fn custom_section(
+        &mut self,
+        title: &str,
+        build: impl for<'p> FnOnce(&mut PageContext<'p, 'a>) -> Result<(), PdfError> + 'a,
+    ) {
+        self.custom_sections
+            .push((title.to_owned(), Box::new(build)));
+    }
+
+    /// Registers a section of the renderer independent report model to render with the report,
+    /// in the same position as the custom sections. Must be called before `generate_report`.
+    ///
+    /// ### Parameters
+    ///
+    /// - `section`: The section to render.
+    pub fn add_section(&mut self, section: ReportSection) {
+        let title = section.title.clone();
+        self.custom_section(&title, move |context| context.section(&section));
+    }
+
+    /// Gets the regular font.
+    pub fn font(&self) -> PdfFontToken {
+        self.font
+    }
+
+    /// Gets the bold font.
+    pub fn bold_font(&self) -> PdfFontToken {
+        self.bold_font
+    }
+
+    /// Gets the italic font.
+    pub fn italic_font(&self) -> PdfFontToken {
+        self.italic_font
+    }
+
+    /// Gets the monospaced code font.
+    pub fn code_font(&self) -> PdfFontToken {
+        self.code_font
+    }
+
+    /// Gets the height of a line of normal text as a fraction of the page height.
+    pub fn line_height_fraction(&self) -> f32 {
+        FONT_SIZE / self.page_height + LINE_HEIGHT_PADDING
+    }
+
     /// Generates the final report.
     ///
     /// ### Parameters
     ///
     /// - `data_info`: The dataset information.
     pub fn generate_report(&mut self, data_info: &DataInfo) -> Result<(), LeadsError> {
+        let n_sections = 5
+            + n_analysis_sections(data_info)
+            + self.custom_sections.len()
+            + usize::from(has_problem_rows_page(data_info))
+            + usize::from(data_info.throughput.is_some());
+        let progress = self.progress.clone();
+        let mut tracker =
+            StageTracker::start(progress.as_ref(), Stage::Report, "sections", n_sections);
+        self.narrative = Narrative::new(data_info);
+
         self.create_title_page(&data_info.data_title)?;
-        self.create_data_types_page(&data_info.column_types)?;
-        self.create_descriptive_analysis_page(&data_info.descriptive_analysis)?;
-        self.create_missing_values_page(
-            &data_info.missing_value_analysis,
-            &data_info.visualizations,
-        )?;
+        self.add_title_notes(data_info)?;
+        self.add_title_provenance(&data_info.provenance)?;
+        tracker.step();
+        self.create_analysis_pages(data_info, &mut tracker)?;
+        for (title, build) in std::mem::take(&mut self.custom_sections) {
+            self.create_custom_section_page(&title, build)?;
+            tracker.step();
+        }
+        if has_problem_rows_page(data_info) {
+            if !self.create_unavailable_page(data_info, RecoverableAnalysis::ProblemRows)? {
+                self.create_problem_rows_page(&data_info.problem_row_analysis)?;
+            }
+            tracker.step();
+        }
+        self.create_starter_code_page(data_info)?;
+        tracker.step();
+        self.create_provenance_page(&data_info.provenance)?;
+        tracker.step();
+        if let Some(throughput) = &data_info.throughput {
+            self.create_throughput_page(throughput)?;
+            tracker.step();
+        }
+        self.create_glossary_page()?;
+        tracker.step();
+        self.create_table_of_contents()?;
+        tracker.step();
+        tracker.finish();
+        Ok(())
+    }
+
+    /// Generates the report of a run over several tables: the title page, the tables overview,
+    /// and the analysis sections, problem rows, and starter code of each table in turn, followed
+    /// by the custom sections, the glossary, and the table of contents. The table of contents
+    /// entries of each table are prefixed with the table name, which is also shown at the top of
+    /// its pages. A single table gets the regular report of `generate_report`.
+    ///
+    /// ### Parameters
+    ///
+    /// - `table_set`: The tables of the run.
+    pub fn generate_tables_report(&mut self, table_set: &TableSet) -> Result<(), LeadsError> {
+        if let [data_info] = table_set.tables.as_slice() {
+            return self.generate_report(data_info);
+        }
+
+        let n_sections = 4
+            + self.custom_sections.len()
+            + table_set
+                .tables
+                .iter()
+                .map(|data_info| {
+                    n_analysis_sections(data_info)
+                        + 2
+                        + usize::from(has_problem_rows_page(data_info))
+                        + usize::from(data_info.throughput.is_some())
+                })
+                .sum::<usize>();
+        let progress = self.progress.clone();
+        let mut tracker =
+            StageTracker::start(progress.as_ref(), Stage::Report, "sections", n_sections);
+
+        self.create_title_page(&table_set.title)?;
+        // The tables are read with the same options.
+        if let Some(data_info) = table_set.tables.first() {
+            self.add_title_notes(data_info)?;
+            // The sheets of a single workbook share its fingerprint.
+            let provenance = &data_info.provenance;
+            if table_set
+                .tables
+                .iter()
+                .all(|table| table.provenance.content_hash == provenance.content_hash)
+            {
+                self.add_title_provenance(provenance)?;
+            }
+        }
+        tracker.step();
+        self.create_tables_overview_page(table_set)?;
+        tracker.step();
+        for data_info in &table_set.tables {
+            self.table = Some(data_info.data_title.clone());
+            self.narrative = Narrative::new(data_info);
+            self.create_analysis_pages(data_info, &mut tracker)?;
+            if has_problem_rows_page(data_info) {
+                if !self.create_unavailable_page(data_info, RecoverableAnalysis::ProblemRows)? {
+                    self.create_problem_rows_page(&data_info.problem_row_analysis)?;
+                }
+                tracker.step();
+            }
+            self.create_starter_code_page(data_info)?;
+            tracker.step();
+            self.create_provenance_page(&data_info.provenance)?;
+            tracker.step();
+            if let Some(throughput) = &data_info.throughput {
+                self.create_throughput_page(throughput)?;
+                tracker.step();
+            }
+        }
+        self.table = None;
+        self.narrative = Narrative::default();
+        for (title, build) in std::mem::take(&mut self.custom_sections) {
+            self.create_custom_section_page(&title, build)?;
+            tracker.step();
+        }
+        self.create_glossary_page()?;
+        tracker.step();
+        self.create_table_of_contents()?;
+        tracker.step();
+        tracker.finish();
+        Ok(())
+    }
+
+    /// Creates the analysis section pages of a dataset, from the alerts to the missing values,
+    /// stepping the report tracker after each one.
+    ///
+    /// ### Parameters
+    ///
+    /// - `data_info`: The dataset information.
+    /// - `tracker`: The tracker of the report stage.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<(), LeadsError>`: Unit type or a propagated LeadsError.
+    fn create_analysis_pages(
+        &mut self,
+        data_info: &DataInfo,
+        tracker: &mut StageTracker,
+    ) -> Result<(), LeadsError> {
+        self.create_alerts_page(&ReportSummary::new(data_info, None).alerts)?;
+        tracker.step();
+        if data_info.wide {
+            self.create_compact_data_types_page(&data_info.column_types)?;
+        } else {
+            self.create_data_types_page(
+                &data_info.column_types,
+                &data_info.example_values,
+                data_info.data_dictionary.as_ref(),
+            )?;
+        }
+        tracker.step();
+        if !data_info.skipped_analyses().is_empty() {
+            self.create_section_page(&ReportSection::skipped_analyses(data_info))?;
+            tracker.step();
+        }
+        if let Some(null_if_analysis) = &data_info.null_if_analysis {
+            self.create_null_if_page(null_if_analysis)?;
+            tracker.step();
+        }
+        if !self.create_unavailable_page(data_info, RecoverableAnalysis::Descriptive)? {
+            self.create_descriptive_analysis_page(
+                &data_info.descriptive_analysis,
+                data_info.robust_analysis.as_ref(),
+                data_info.data_dictionary.as_ref(),
+                data_info.wide,
+            )?;
+        }
+        tracker.step();
+        // The robust statistics are drawn within the descriptive and outlier pages, so their
+        // failure gets a page of its own.
+        if self.create_unavailable_page(data_info, RecoverableAnalysis::Robust)? {
+            tracker.step();
+        }
+        if has_distribution_plots(&data_info.visualizations) {
+            self.create_distributions_page(&data_info.visualizations)?;
+            tracker.step();
+        }
+        self.create_uniqueness_page(&data_info.descriptive_analysis)?;
+        tracker.step();
+        if !self.create_unavailable_page(data_info, RecoverableAnalysis::Duplicates)? {
+            self.create_duplicates_page(&data_info.duplicate_analysis)?;
+        }
+        tracker.step();
+        if let Some(foreign_value_analysis) = &data_info.foreign_value_analysis {
+            self.create_foreign_values_page(foreign_value_analysis)?;
+            tracker.step();
+        } else if self.create_unavailable_page(data_info, RecoverableAnalysis::ForeignValues)? {
+            tracker.step();
+        }
+        if !self.create_unavailable_page(data_info, RecoverableAnalysis::Correlations)? {
+            self.create_correlations_page(
+                &data_info.correlation_analysis,
+                &data_info.visualizations,
+            )?;
+        }
+        tracker.step();
+        if !self.create_unavailable_page(data_info, RecoverableAnalysis::Collinearity)? {
+            self.create_collinearity_page(&data_info.collinearity_analysis)?;
+        }
+        tracker.step();
+        if !self.create_unavailable_page(data_info, RecoverableAnalysis::RowOrder)? {
+            self.create_row_order_page(&data_info.row_order_analysis)?;
+        }
+        tracker.step();
+        if has_temporal_page(data_info) {
+            if !self.create_unavailable_page(data_info, RecoverableAnalysis::Temporal)? {
+                self.create_temporal_page(&data_info.temporal_analysis)?;
+            }
+            tracker.step();
+        }
+        if let Some(partition_analysis) = &data_info.partition_analysis {
+            self.create_partitions_page(partition_analysis)?;
+            tracker.step();
+        }
+        if let Some(drift_analysis) = &data_info.drift_analysis {
+            self.create_drift_page(drift_analysis)?;
+            tracker.step();
+        } else if self.create_unavailable_page(data_info, RecoverableAnalysis::Drift)? {
+            tracker.step();
+        }
+        if let Some(freshness_analysis) = &data_info.freshness_analysis {
+            self.create_freshness_page(freshness_analysis)?;
+            tracker.step();
+        } else if self.create_unavailable_page(data_info, RecoverableAnalysis::Freshness)? {
+            tracker.step();
+        }
+        if let Some(session_analysis) = &data_info.session_analysis {
+            self.create_sessions_page(session_analysis, &data_info.visualizations)?;
+            tracker.step();
+        } else if self.create_unavailable_page(data_info, RecoverableAnalysis::Sessions)? {
+            tracker.step();
+        }
+        #[cfg(feature = "pca")]
+        if let Some(pca_analysis) = &data_info.pca_analysis {
+            if !self.create_timed_out_page(data_info, BudgetedAnalysis::Pca)?
+                && !self.create_unavailable_page(data_info, RecoverableAnalysis::Pca)?
+            {
+                self.create_pca_page(pca_analysis, &data_info.visualizations)?;
+            }
+            tracker.step();
+        }
+        if let Some(target_analysis) = &data_info.target_analysis {
+            self.create_target_page(target_analysis, &data_info.visualizations)?;
+            tracker.step();
+        } else if self.create_unavailable_page(data_info, RecoverableAnalysis::Target)? {
+            tracker.step();
+        }
+        #[cfg(feature = "time-series")]
+        if let Some(time_series_analysis) = &data_info.time_series_analysis {
+            self.create_time_series_page(time_series_analysis, &data_info.visualizations)?;
+            tracker.step();
+        } else if self.create_unavailable_page(data_info, RecoverableAnalysis::TimeSeries)? {
+            tracker.step();
+        }
+        if !self.create_unavailable_page(data_info, RecoverableAnalysis::Transformations)? {
+            self.create_transformations_page(
+                &data_info.transformation_analysis,
+                &data_info.visualizations,
+            )?;
+        }
+        tracker.step();
+        if !self.create_unavailable_page(data_info, RecoverableAnalysis::Categories)? {
+            self.create_category_merges_page(
+                &data_info.category_analysis,
+                &data_info.visualizations,
+            )?;
+        }
+        tracker.step();
+        if !self.create_timed_out_page(data_info, BudgetedAnalysis::Outliers)?
+            && !self.create_unavailable_page(data_info, RecoverableAnalysis::Outliers)?
+        {
+            self.create_outliers_page(
+                &data_info.outlier_analysis,
+                data_info.robust_analysis.as_ref(),
+                &data_info.visualizations,
+                data_info.wide,
+            )?;
+        }
+        tracker.step();
+        if !self.create_unavailable_page(data_info, RecoverableAnalysis::Associations)? {
+            self.create_associations_page(
+                &data_info.association_analysis,
+                &data_info.visualizations,
+            )?;
+        }
+        tracker.step();
+        if !self.create_timed_out_page(data_info, BudgetedAnalysis::TextProfiling)?
+            && !self.create_unavailable_page(data_info, RecoverableAnalysis::TextProfiling)?
+        {
+            self.create_text_profiling_page(&data_info.text_analysis)?;
+        }
+        tracker.step();
+        if !self.create_unavailable_page(data_info, RecoverableAnalysis::MissingValues)? {
+            self.create_missing_values_page(
+                &data_info.missing_value_analysis,
+                &data_info.visualizations,
+            )?;
+        }
+        tracker.step();
+        Ok(())
+    }
+
+    /// Generates a report from previously exported sections of the renderer independent report
+    /// model, without the dataset: the title page, a page per section, the glossary of the terms
+    /// the sections reference, and the table of contents.
+    ///
+    /// ### Parameters
+    ///
+    /// - `data_title`: Title of the dataset.
+    /// - `sections`: The sections to render, in report order.
+    pub fn generate_sections_report(
+        &mut self,
+        data_title: &str,
+        sections: &[ReportSection],
+    ) -> Result<(), LeadsError> {
+        let progress = self.progress.clone();
+        let mut tracker = StageTracker::start(
+            progress.as_ref(),
+            Stage::Report,
+            "sections",
+            sections.len() + 3,
+        );
+
+        self.create_title_page(data_title)?;
+        tracker.step();
+        for section in sections {
+            self.create_section_page(section)?;
+            tracker.step();
+        }
         self.create_glossary_page()?;
+        tracker.step();
         self.create_table_of_contents()?;
+        tracker.step();
+        tracker.finish();
         Ok(())
     }
 
@@ -191,6 +717,103 @@ impl<'a> PageManager<'a> {
         Ok(())
     }
 
+    /// Adds the notes on the rows the report covers to the title page: the row limit of a partial
+    /// report, the row filter, and the SQL query the data is the result of, if any.
+    ///
+    /// ### Parameters
+    ///
+    /// - `data_info`: The dataset information.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<(), PdfError>`: Unit type or a propagated PdfError.
+    fn add_title_notes(&mut self, data_info: &DataInfo) -> Result<(), PdfError> {
+        if let Some(row_limit) = &data_info.row_limit {
+            self.add_text(
+                &format!("Partial report: {} only", row_limit),
+                self.bold_font,
+                16.0,
+                0.1,
+                0.70,
+                None,
+            )?;
+        }
+        if let Some(row_filter) = &data_info.row_filter {
+            self.add_text("Rows filtered to:", self.bold_font, 14.0, 0.1, 0.56, None)?;
+            self.add_text(
+                &truncate_text(&row_filter.expression, 80),
+                self.code_font,
+                12.0,
+                0.1,
+                0.53,
+                None,
+            )?;
+        }
+        if let Some(source_query) = &data_info.source_query {
+            self.add_text(
+                "Report of the SQL query:",
+                self.bold_font,
+                14.0,
+                0.1,
+                0.46,
+                None,
+            )?;
+            self.add_text(
+                &truncate_text(source_query, 80),
+                self.code_font,
+                12.0,
+                0.1,
+                0.43,
+                None,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Adds the fingerprint of the input to the bottom of the title page: the source, its size,
+    /// modification time, and content hash, and the options that differ from their defaults.
+    ///
+    /// ### Parameters
+    ///
+    /// - `provenance`: The fingerprint of the input.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<(), PdfError>`: Unit type or a propagated PdfError.
+    fn add_title_provenance(&mut self, provenance: &Provenance) -> Result<(), PdfError> {
+        let mut lines = vec![format!("Source: {}", truncate_text(&provenance.source, 80))];
+        if let (Some(file_size), Some(modified_at)) =
+            (provenance.file_size, &provenance.modified_at)
+        {
+            lines.push(format!(
+                "Size: {}, last modified: {}",
+                format_file_size(file_size),
+                modified_at
+            ));
+        }
+        lines.push(format!(
+            "Options: {}",
+            truncate_text(&provenance.format_options(), 80)
+        ));
+
+        let mut y_fraction = 0.36;
+        for line in lines {
+            self.add_text(&line, self.font, 12.0, 0.1, y_fraction, None)?;
+            y_fraction -= 0.03;
+        }
+        if let Some(content_hash) = &provenance.content_hash {
+            self.add_text(
+                &format!("SHA-256: {}", content_hash),
+                self.code_font,
+                10.0,
+                0.1,
+                y_fraction,
+                None,
+            )?;
+        }
+        Ok(())
+    }
+
     /// Creates the table of contents page(s).
     ///
     /// ### Returns
@@ -199,6 +822,8 @@ impl<'a> PageManager<'a> {
     pub fn create_table_of_contents(&mut self) -> Result<u32, PdfError> {
         let start_page = 1;
         self.insert_page_at(start_page)?;
+        let name = "Table of Contents";
+        self.record_destination(name, sections::stable_id(name), start_page.into());
         let mut pages_added = 1;
 
         self.add_text(
@@ -244,11 +869,7 @@ impl<'a> PageManager<'a> {
 
         for i in 0..pages_added {
             let page_num = to_roman_numeral((i + 1).into());
-            let mut page = self
-                .document
-                .pages()
-                .get(start_page as u16 + i as u16)
-                .unwrap();
+            let mut page = self.document.pages().get(start_page + i).unwrap();
             let mut text_object =
                 PdfPageTextObject::new(&self.document, &page_num, self.font, PdfPoints::new(10.0))?;
             text_object.set_fill_color(PdfColor::new(0, 0, 0, 255))?;
@@ -269,11 +890,119 @@ impl<'a> PageManager<'a> {
         Ok(pages_added as u32)
     }
 
+    /// Starts the column details of a long section on a new page, recording the page of each
+    /// column detail (`add_mini_toc_entry`) for the mini table of contents inserted before them by
+    /// `finish_mini_toc`.
+    ///
+    /// ### Parameters
+    ///
+    /// - `y_fraction`: The y fraction to continue the section at, reset to the top of the new
+    ///   page.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<(), PdfError>`: Unit type or a propagated PdfError.
+    pub fn start_mini_toc(&mut self, y_fraction: &mut f32) -> Result<(), PdfError> {
+        self.new_page()?;
+        *y_fraction = 0.9;
+        self.mini_toc = Some(MiniToc {
+            index: self.current_page,
+            entries: IndexMap::new(),
+        });
+        Ok(())
+    }
+
+    /// Records the current page as the page of a column detail in the mini table of contents, if
+    /// the section being drawn has one.
+    ///
+    /// ### Parameters
+    ///
+    /// - `column`: The column name.
+    pub fn add_mini_toc_entry(&mut self, column: &str) {
+        if let Some(mini_toc) = &mut self.mini_toc {
+            mini_toc
+                .entries
+                .entry(column.to_owned())
+                .or_insert(self.current_page);
+        }
+    }
+
+    /// Inserts the page(s) of the mini table of contents started with `start_mini_toc` before the
+    /// column details, listing each column with its page number like the table of contents, and
+    /// shifts the recorded section pages after it. Does nothing if no mini table of contents was
+    /// started.
+    ///
+    /// ### Parameters
+    ///
+    /// - `title`: The header of the mini table of contents.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<(), PdfError>`: Unit type or a propagated PdfError.
+    pub fn finish_mini_toc(&mut self, title: &str) -> Result<(), PdfError> {
+        let Some(mini_toc) = self.mini_toc.take() else {
+            return Ok(());
+        };
+        let line_height_fraction = self.line_height_fraction();
+
+        // Lay the entries out first, since the page numbers depend on the number of pages.
+        let mut positions = Vec::with_capacity(mini_toc.entries.len());
+        let (mut page_offset, mut y_fraction) = (0, 0.85);
+        for _ in &mini_toc.entries {
+            if self.need_new_page(y_fraction, line_height_fraction) {
+                page_offset += 1;
+                y_fraction = 0.9;
+            }
+            positions.push((page_offset, y_fraction));
+            y_fraction -= line_height_fraction;
+        }
+        let pages_added = page_offset + 1;
+
+        for offset in 0..pages_added {
+            self.insert_page_at((mini_toc.index + offset) as u16)?;
+        }
+        self.current_page = mini_toc.index;
+        self.add_text(
+            title,
+            self.bold_font,
+            FEATURE_HEADER_FONT_SIZE,
+            0.1,
+            0.9,
+            None,
+        )?;
+        for ((column, page), (page_offset, y_fraction)) in mini_toc.entries.iter().zip(positions) {
+            self.current_page = mini_toc.index + page_offset;
+            // The content pages are numbered from the first page after the title page, so the
+            // number of a page is its index once the mini table of contents is inserted.
+            let page_num_text = format!("{}", page + pages_added);
+            self.add_text(column, self.font, FONT_SIZE, 0.1, y_fraction, None)?;
+            self.add_text(&page_num_text, self.font, FONT_SIZE, 0.9, y_fraction, None)?;
+            let column_width = self.get_text_width(column, self.font, FONT_SIZE)?;
+            let page_num_width = self.get_text_width(&page_num_text, self.font, FONT_SIZE)?;
+            self.add_dotted_line(
+                0.1 + column_width + 0.01,
+                0.9 - page_num_width - 0.01,
+                y_fraction,
+            )?;
+        }
+
+        for page_number in self.section_page_map.values_mut() {
+            if *page_number + 1 >= mini_toc.index {
+                *page_number += pages_added;
+            }
+        }
+        self.current_page = self.document.pages().len() as u32 - 1;
+        Ok(())
+    }
+
     /// Creates the column type overview page.
     ///
     /// ### Parameters
     ///
     /// - `column_types`: The index map of the column names and corresponding data types.
+    /// - `example_values`: The example values of each column, truncated to `MAX_EXAMPLE_LENGTH`
+    ///   characters. The examples column is left out when empty.
+    /// - `data_dictionary`: The optional data dictionary with the column descriptions.
     ///
     /// ### Returns
     ///
@@ -281,11 +1010,12 @@ impl<'a> PageManager<'a> {
     pub fn create_data_types_page(
         &mut self,
         column_types: &IndexMap<String, DataType>,
+        example_values: &IndexMap<String, Vec<String>>,
+        data_dictionary: Option<&DataDictionary>,
     ) -> Result<(), PdfError> {
         self.new_page()?;
 
-        self.section_page_map
-            .insert("Data Types Overview".to_owned(), self.current_page - 1);
+        self.record_section("Data Types Overview");
         self.add_text(
             "Data Types Overview",
             self.bold_font,
@@ -297,8 +1027,11 @@ impl<'a> PageManager<'a> {
 
         let y_start = 0.85;
         let column1_x = 0.1;
-        let column2_x = 0.4;
-        let column3_x = 0.7;
+        let (column2_x, column3_x, column4_x) = if example_values.is_empty() {
+            (0.4, 0.7, 0.9)
+        } else {
+            (0.3, 0.45, 0.68)
+        };
         let line_height = FONT_SIZE / self.page_height + 2.0 * LINE_HEIGHT_PADDING;
 
         self.add_text(
@@ -325,6 +1058,16 @@ impl<'a> PageManager<'a> {
             y_start,
             None,
         )?;
+        if !example_values.is_empty() {
+            self.add_text(
+                "Examples",
+                self.bold_font,
+                FONT_SIZE,
+                column4_x,
+                y_start,
+                None,
+            )?;
+        }
 
         self.add_line(
             column1_x,
@@ -335,10 +1078,40 @@ impl<'a> PageManager<'a> {
         )?;
 
         let mut y_position = y_start - 2.0 * line_height;
-        let mut row_count = 0;
 
-        for (column_name, data_type) in column_types {
-            if self.need_new_page(y_position, 3.0 * line_height) {
+        for (row_count, (column_name, data_type)) in column_types.iter().enumerate() {
+            self.reference_term(&get_data_type_term(data_type));
+            let description = get_data_type_category(data_type);
+            let wrapped_description = self.wrap_text(
+                &description,
+                column3_x,
+                column4_x - 0.01,
+                self.font,
+                FONT_SIZE,
+            );
+            let examples = example_values
+                .get(column_name)
+                .map(|examples| {
+                    examples
+                        .iter()
+                        .map(|example| truncate_example(example))
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                })
+                .unwrap_or_default();
+            let wrapped_examples = if examples.is_empty() {
+                Vec::new()
+            } else {
+                self.wrap_text(&examples, column4_x, 0.9, self.font, FONT_SIZE)
+            };
+            let definition_lines = data_dictionary
+                .and_then(|dictionary| dictionary.get(column_name))
+                .map(|definition| self.definition_lines(definition, column1_x + 0.01, 0.9))
+                .unwrap_or_default();
+            let type_lines = wrapped_description.len().max(wrapped_examples.len()).max(1);
+            let row_lines = type_lines + definition_lines.len();
+
+            if self.need_new_page(y_position, (row_lines as f32 + 2.0) * line_height) {
                 self.new_page()?;
                 y_position = 0.9;
             }
@@ -348,7 +1121,7 @@ impl<'a> PageManager<'a> {
                     column1_x,
                     y_position + line_height,
                     0.9,
-                    y_position - line_height,
+                    y_position - row_lines as f32 * line_height,
                     PdfColor::new(240, 240, 240, 255),
                 )?;
             }
@@ -370,10 +1143,6 @@ impl<'a> PageManager<'a> {
                 None,
             )?;
 
-            let description = get_data_type_category(data_type);
-            let wrapped_description =
-                self.wrap_text(&description, column3_x, 0.9, self.font, FONT_SIZE);
-
             for (i, line) in wrapped_description.iter().enumerate() {
                 self.add_text(
                     line,
@@ -384,32 +1153,781 @@ impl<'a> PageManager<'a> {
                     None,
                 )?;
             }
+            for (i, line) in wrapped_examples.iter().enumerate() {
+                self.add_text(
+                    line,
+                    self.font,
+                    FONT_SIZE,
+                    column4_x,
+                    y_position - i as f32 * line_height,
+                    None,
+                )?;
+            }
+
+            // Add the data dictionary entry below the row.
+            for (i, (line, font)) in definition_lines.iter().enumerate() {
+                self.add_text(
+                    line,
+                    *font,
+                    FONT_SIZE,
+                    column1_x + 0.01,
+                    y_position - (type_lines + i) as f32 * line_height,
+                    None,
+                )?;
+            }
 
-            y_position -= (wrapped_description.len() as f32 + 1.0) * line_height;
-            row_count += 1;
+            y_position -= (row_lines as f32 + 1.0) * line_height;
         }
 
         Ok(())
     }
 
-    /// Creates the report pages with the results of the basic descriptie analysis.
+    /// Creates the column type overview page in the wide mode layout, listing the name and data
+    /// type of the columns in `WIDE_TYPE_COLUMNS` side by side column groups per page, filled top to
+    /// bottom. The categories, examples, and data dictionary entries are left out.
     ///
     /// ### Parameters
     ///
-    /// - `descriptive_analysis`: The descriptive analysis results.
+    /// - `column_types`: The index map of the column names and corresponding data types.
     ///
     /// ### Returns
     ///
-    /// - `Result<() PdfError>`: Unit type or the propogated PdfError.
-    pub fn create_descriptive_analysis_page(
+    /// - `Result<(), PdfError>`: Unit type or the propagated PdfError.
+    pub fn create_compact_data_types_page(
+        &mut self,
+        column_types: &IndexMap<String, DataType>,
+    ) -> Result<(), PdfError> {
+        let mut y_fraction = self.start_section("Data Types Overview")?;
+        let line_height_fraction = FONT_SIZE / self.page_height + LINE_HEIGHT_PADDING;
+        let note = format!(
+            "Wide mode: the {} columns are listed side by side, without their categories, \
+             examples, and data dictionary entries.",
+            column_types.len()
+        );
+        for line in self.wrap_text(&note, 0.1, 0.9, self.italic_font, FONT_SIZE) {
+            self.add_text(&line, self.italic_font, FONT_SIZE, 0.1, y_fraction, None)?;
+            y_fraction -= line_height_fraction;
+        }
+        y_fraction -= line_height_fraction;
+
+        let row_height_fraction = WIDE_TYPE_FONT_SIZE / self.page_height + LINE_HEIGHT_PADDING;
+        let group_width = 0.8 / WIDE_TYPE_COLUMNS as f32;
+        let mut columns = column_types.iter().peekable();
+        while columns.peek().is_some() {
+            if self.need_new_page(y_fraction, 3.0 * row_height_fraction) {
+                self.new_page()?;
+                y_fraction = 0.9;
+            }
+            let rows_per_group =
+                ((y_fraction - BOTTOM_MARGIN) / row_height_fraction).floor() as usize - 1;
+            for group in 0..WIDE_TYPE_COLUMNS {
+                let x_fraction = 0.1 + group as f32 * group_width;
+                let mut row_y_fraction = y_fraction;
+                for (column_name, data_type) in columns.by_ref().take(rows_per_group) {
+                    self.reference_term(&get_data_type_term(data_type));
+                    self.add_text(
+                        &truncate_text(column_name, WIDE_TYPE_NAME_LENGTH),
+                        self.font,
+                        WIDE_TYPE_FONT_SIZE,
+                        x_fraction,
+                        row_y_fraction,
+                        None,
+                    )?;
+                    self.add_text(
+                        &truncate_text(&data_type.to_string(), WIDE_TYPE_NAME_LENGTH),
+                        self.italic_font,
+                        WIDE_TYPE_FONT_SIZE,
+                        x_fraction + 0.5 * group_width,
+                        row_y_fraction,
+                        None,
+                    )?;
+                    row_y_fraction -= row_height_fraction;
+                }
+            }
+            // The next page starts at the top.
+            y_fraction = BOTTOM_MARGIN;
+        }
+
+        Ok(())
+    }
+
+    /// Creates the report pages with the results of the basic descriptie analysis.
+    ///
+    /// ### Parameters
+    ///
+    /// - `descriptive_analysis`: The descriptive analysis results.
+    /// - `robust_analysis`: The optional robust statistics, shown alongside or instead of the
+    ///   classical statistics.
+    /// - `data_dictionary`: The optional data dictionary with the column descriptions.
+    /// - `wide`: Whether to summarize the features in compact tables (wide mode) instead of a
+    ///   statistic block per feature.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<() PdfError>`: Unit type or the propogated PdfError.
+    pub fn create_descriptive_analysis_page(
+        &mut self,
+        descriptive_analysis: &DescriptiveAnalysis,
+        robust_analysis: Option<&RobustAnalysis>,
+        data_dictionary: Option<&DataDictionary>,
+        wide: bool,
+    ) -> Result<(), LeadsError> {
+        self.create_section_page(&ReportSection::descriptive(
+            descriptive_analysis,
+            robust_analysis,
+            data_dictionary,
+            wide,
+        ))?;
+        Ok(())
+    }
+
+    /// Creates the alerts page, listing the data quality issues worth a look up front.
+    ///
+    /// ### Parameters
+    ///
+    /// - `alerts`: The human readable alerts from the run summary.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<(), PdfError>`: Unit type or a propagated PdfError.
+    pub fn create_alerts_page(&mut self, alerts: &[String]) -> Result<(), PdfError> {
+        self.create_section_page(&ReportSection::alerts(alerts))
+    }
+
+    /// Creates the tables overview page of a run over several tables, with the size and quality
+    /// of each table and the columns shared by several tables.
+    ///
+    /// ### Parameters
+    ///
+    /// - `table_set`: The tables of the run.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<(), PdfError>`: Unit type or a propagated PdfError.
+    pub fn create_tables_overview_page(&mut self, table_set: &TableSet) -> Result<(), PdfError> {
+        self.create_section_page(&ReportSection::from(table_set))
+    }
+
+    /// Creates the duplicates page, with the full-row duplicates and the violations of the
+    /// uniqueness key (if one was specified) along with its worst offenders.
+    ///
+    /// ### Parameters
+    ///
+    /// - `duplicate_analysis`: The duplicate detection results for the dataset.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<(), PdfError>`: Unit type or a propagated PdfError.
+    pub fn create_duplicates_page(
+        &mut self,
+        duplicate_analysis: &DuplicateAnalysis,
+    ) -> Result<(), PdfError> {
+        self.create_section_page(&ReportSection::from(duplicate_analysis))
+    }
+
+    /// Creates the foreign values page, with the values of each column missing from its reference
+    /// list and the most frequent ones.
+    ///
+    /// ### Parameters
+    ///
+    /// - `foreign_value_analysis`: The reference list checks of the dataset.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<(), PdfError>`: Unit type or a propagated PdfError.
+    pub fn create_foreign_values_page(
+        &mut self,
+        foreign_value_analysis: &ForeignValueAnalysis,
+    ) -> Result<(), PdfError> {
+        self.create_section_page(&ReportSection::from(foreign_value_analysis))
+    }
+
+    /// Creates the uniqueness page, with the distinct value count and uniqueness ratio of each
+    /// column. Constant columns are highlighted.
+    ///
+    /// ### Parameters
+    ///
+    /// - `descriptive_analysis`: The descriptive analysis for the dataset.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<(), PdfError>`: Unit type or a propagated PdfError.
+    pub fn create_uniqueness_page(
+        &mut self,
+        descriptive_analysis: &DescriptiveAnalysis,
+    ) -> Result<(), PdfError> {
+        self.create_section_page(&ReportSection::uniqueness(descriptive_analysis))
+    }
+
+    /// Creates the null-if rules page, with the number of values each rule converted to null.
+    ///
+    /// ### Parameters
+    ///
+    /// - `null_if_analysis`: The null-if rules applied to the dataset.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<(), PdfError>`: Unit type or a propagated PdfError.
+    pub fn create_null_if_page(
+        &mut self,
+        null_if_analysis: &NullIfAnalysis,
+    ) -> Result<(), PdfError> {
+        self.create_section_page(&ReportSection::from(null_if_analysis))
+    }
+
+    /// Creates the category merges page, with the rare levels of each long-tail categorical feature
+    /// and the cardinality merging them into "Other" removes, followed by the before and after
+    /// level charts (if visualizations were generated).
+    ///
+    /// ### Parameters
+    ///
+    /// - `category_analysis`: The category merge suggestions for the dataset.
+    /// - `visualizations`: The optional visualizations for the dataset.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<(), PdfError>`: Unit type or a propagated PdfError.
+    pub fn create_category_merges_page(
+        &mut self,
+        category_analysis: &CategoryMergeAnalysis,
+        visualizations: &Option<VisualizationManager>,
+    ) -> Result<(), PdfError> {
+        self.create_section_page(&ReportSection::category_merges(
+            category_analysis,
+            visualizations,
+        ))
+    }
+
+    /// Creates the correlations page, with the most correlated pairs of numerical features
+    /// followed by the Pearson and Spearman heatmaps (if visualizations were generated). Pairs at
+    /// or above the threshold are highlighted.
+    ///
+    /// ### Parameters
+    ///
+    /// - `correlation_analysis`: The correlation analysis for the dataset.
+    /// - `visualizations`: The optional visualizations for the dataset.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<(), PdfError>`: Unit type or a propagated PdfError.
+    pub fn create_correlations_page(
+        &mut self,
+        correlation_analysis: &CorrelationAnalysis,
+        visualizations: &Option<VisualizationManager>,
+    ) -> Result<(), PdfError> {
+        self.create_section_page(&ReportSection::correlations(
+            correlation_analysis,
+            visualizations,
+        ))
+    }
+
+    /// Creates the multicollinearity page, with the variance inflation factor of each numerical
+    /// feature. Features above the threshold are highlighted.
+    ///
+    /// ### Parameters
+    ///
+    /// - `collinearity_analysis`: The collinearity analysis for the dataset.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<(), PdfError>`: Unit type or a propagated PdfError.
+    pub fn create_collinearity_page(
+        &mut self,
+        collinearity_analysis: &CollinearityAnalysis,
+    ) -> Result<(), PdfError> {
+        self.create_section_page(&ReportSection::from(collinearity_analysis))
+    }
+
+    /// Creates the row order page, with the Spearman correlation of each numerical and temporal
+    /// feature with the row index. Features at or above the threshold are highlighted.
+    ///
+    /// ### Parameters
+    ///
+    /// - `row_order_analysis`: The row order check for the dataset.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<(), PdfError>`: Unit type or a propagated PdfError.
+    pub fn create_row_order_page(
+        &mut self,
+        row_order_analysis: &RowOrderAnalysis,
+    ) -> Result<(), PdfError> {
+        self.create_section_page(&ReportSection::from(row_order_analysis))
+    }
+
+    /// Creates the temporal columns page, with the range, gaps, calendar distributions, and row
+    /// order of each date, datetime, and time column.
+    ///
+    /// ### Parameters
+    ///
+    /// - `temporal_analysis`: The temporal column analysis for the dataset.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<(), PdfError>`: Unit type or a propagated PdfError.
+    pub fn create_temporal_page(
+        &mut self,
+        temporal_analysis: &TemporalAnalysis,
+    ) -> Result<(), PdfError> {
+        self.create_section_page(&ReportSection::from(temporal_analysis))
+    }
+
+    /// Creates the problem rows appendix page, with a sample of the offending rows of each
+    /// outlier and invalid format alert.
+    ///
+    /// ### Parameters
+    ///
+    /// - `problem_row_analysis`: The problem row samples for the dataset.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<(), PdfError>`: Unit type or a propagated PdfError.
+    pub fn create_problem_rows_page(
+        &mut self,
+        problem_row_analysis: &ProblemRowAnalysis,
+    ) -> Result<(), PdfError> {
+        self.create_section_page(&ReportSection::from(problem_row_analysis))
+    }
+
+    /// Creates the partitions page, with the row count, missingness, and schema consistency of
+    /// each file of a partitioned dataset. Anomalous partitions are highlighted and the reasons
+    /// they were flagged are listed below the table.
+    ///
+    /// ### Parameters
+    ///
+    /// - `partition_analysis`: The per-partition quality breakdown for the dataset.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<(), PdfError>`: Unit type or a propagated PdfError.
+    pub fn create_partitions_page(
+        &mut self,
+        partition_analysis: &PartitionAnalysis,
+    ) -> Result<(), PdfError> {
+        self.create_section_page(&ReportSection::from(partition_analysis))
+    }
+
+    /// Creates the drift page, with the population stability index of each column against the
+    /// baseline profile. Drifted columns are highlighted and the schema changes since the
+    /// baseline are listed below the table.
+    ///
+    /// ### Parameters
+    ///
+    /// - `drift_analysis`: The drift of the columns from the baseline profile.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<(), PdfError>`: Unit type or a propagated PdfError.
+    pub fn create_drift_page(&mut self, drift_analysis: &DriftAnalysis) -> Result<(), PdfError> {
+        self.create_section_page(&ReportSection::from(drift_analysis))
+    }
+
+    /// Creates the freshness page, with the staleness of the newest record against the
+    /// freshness threshold and the distribution of the record lags.
+    ///
+    /// ### Parameters
+    ///
+    /// - `freshness_analysis`: The freshness of the records.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<(), PdfError>`: Unit type or a propagated PdfError.
+    pub fn create_freshness_page(
+        &mut self,
+        freshness_analysis: &FreshnessAnalysis,
+    ) -> Result<(), PdfError> {
+        self.create_section_page(&ReportSection::from(freshness_analysis))
+    }
+
+    /// Creates the sessions page, with the events per entity, session, and inter-event time
+    /// distributions of the event log followed by the session plots (if visualizations were
+    /// generated).
+    ///
+    /// ### Parameters
+    ///
+    /// - `session_analysis`: The sessionized event log profile.
+    /// - `visualizations`: The optional visualizations for the dataset.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<(), PdfError>`: Unit type or a propagated PdfError.
+    pub fn create_sessions_page(
+        &mut self,
+        session_analysis: &SessionAnalysis,
+        visualizations: &Option<VisualizationManager>,
+    ) -> Result<(), PdfError> {
+        self.create_section_page(&ReportSection::sessions(session_analysis, visualizations))
+    }
+
+    /// Creates the principal component analysis pages, with the explained variance and top
+    /// loadings of each component followed by the scree plot (if visualizations were generated).
+    ///
+    /// ### Parameters
+    ///
+    /// - `pca_analysis`: The PCA results for the dataset.
+    /// - `visualizations`: The optional visualizations for the dataset.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<(), PdfError>`: Unit type or a propagated PdfError.
+    #[cfg(feature = "pca")]
+    pub fn create_pca_page(
+        &mut self,
+        pca_analysis: &PcaAnalysis,
+        visualizations: &Option<VisualizationManager>,
+    ) -> Result<(), PdfError> {
+        self.create_section_page(&ReportSection::pca(pca_analysis, visualizations))
+    }
+
+    /// Creates the target analysis pages for supervised mode, with the class distribution of the
+    /// target, the mutual information ranking and association tests of the features, and the
+    /// feature statistics per class, along with the target plots (if visualizations were
+    /// generated).
+    ///
+    /// ### Parameters
+    ///
+    /// - `target_analysis`: The target column analysis.
+    /// - `visualizations`: The optional visualizations for the dataset.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<(), PdfError>`: Unit type or a propagated PdfError.
+    pub fn create_target_page(
+        &mut self,
+        target_analysis: &TargetAnalysis,
+        visualizations: &Option<VisualizationManager>,
+    ) -> Result<(), PdfError> {
+        self.create_section_page(&ReportSection::target(target_analysis, visualizations))
+    }
+
+    /// Creates the transformation suggestions pages, with the before and after histograms for each
+    /// skewed feature if visualizations were generated.
+    ///
+    /// ### Parameters
+    ///
+    /// - `transformation_analysis`: The transformation suggestions for the dataset.
+    /// - `visualizations`: The optional visualizations for the dataset.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<(), PdfError>`: Unit type or a propagated PdfError.
+    pub fn create_transformations_page(
+        &mut self,
+        transformation_analysis: &TransformationAnalysis,
+        visualizations: &Option<VisualizationManager>,
+    ) -> Result<(), PdfError> {
+        self.create_section_page(&ReportSection::transformations(
+            transformation_analysis,
+            visualizations,
+        ))
+    }
+
+    /// Creates the distributions pages, with the histograms of the numerical features arranged
+    /// as small multiples grids.
+    ///
+    /// ### Parameters
+    ///
+    /// - `visualizations`: The optional visualizations for the dataset.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<(), PdfError>`: Unit type or a propagated PdfError.
+    pub fn create_distributions_page(
+        &mut self,
+        visualizations: &Option<VisualizationManager>,
+    ) -> Result<(), PdfError> {
+        self.create_section_page(&ReportSection::distributions(visualizations))
+    }
+
+    /// Create the outlier analysis pages, with the fences, outlier counts, and most extreme values
+    /// of each numerical feature followed by its strip plot (if visualizations were generated).
+    ///
+    /// ### Parameters
+    ///
+    /// - `outlier_analysis`: The outlier analysis for the dataset.
+    /// - `robust_analysis`: The optional robust statistics, whose MAD fences are shown alongside
+    ///   or instead of the z-score fences.
+    /// - `visualizations`: The optional visualizations for the dataset.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<(), PdfError>`: Unit type or a propagated PdfError.
+    pub fn create_outliers_page(
+        &mut self,
+        outlier_analysis: &OutlierAnalysis,
+        robust_analysis: Option<&RobustAnalysis>,
+        visualizations: &Option<VisualizationManager>,
+        wide: bool,
+    ) -> Result<(), PdfError> {
+        self.create_section_page(&ReportSection::outliers(
+            outlier_analysis,
+            robust_analysis,
+            visualizations,
+            wide,
+        ))
+    }
+
+    /// Creates the time series analysis pages for time series mode, with the sampling regularity of
+    /// the datetime index, the gaps and duplicated timestamps, and the gap timeline (if
+    /// visualizations were generated).
+    ///
+    /// ### Parameters
+    ///
+    /// - `time_series_analysis`: The time series analysis.
+    /// - `visualizations`: The optional visualizations for the dataset.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<(), PdfError>`: Unit type or a propagated PdfError.
+    #[cfg(feature = "time-series")]
+    pub fn create_time_series_page(
+        &mut self,
+        time_series_analysis: &TimeSeriesAnalysis,
+        visualizations: &Option<VisualizationManager>,
+    ) -> Result<(), PdfError> {
+        self.create_section_page(&ReportSection::time_series(
+            time_series_analysis,
+            visualizations,
+        ))
+    }
+
+    /// Create the categorical association pages, with Cramér's V for each cross-tabulated pair
+    /// followed by its annotated heatmap (if visualizations were generated).
+    ///
+    /// ### Parameters
+    ///
+    /// - `association_analysis`: The association analysis for the dataset.
+    /// - `visualizations`: The optional visualizations for the dataset.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<(), PdfError>`: Unit type or a propagated PdfError.
+    pub fn create_associations_page(
+        &mut self,
+        association_analysis: &AssociationAnalysis,
+        visualizations: &Option<VisualizationManager>,
+    ) -> Result<(), PdfError> {
+        self.create_section_page(&ReportSection::associations(
+            association_analysis,
+            visualizations,
+        ))
+    }
+
+    /// Create the text profiling pages, with the most common character-class patterns of each
+    /// string column and a warning for the columns with inconsistent formats.
+    ///
+    /// ### Parameters
+    ///
+    /// - `text_analysis`: The text profiling results for the dataset.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<(), PdfError>`: Unit type or a propagated PdfError.
+    pub fn create_text_profiling_page(
+        &mut self,
+        text_analysis: &TextAnalysis,
+    ) -> Result<(), PdfError> {
+        self.create_section_page(&ReportSection::text_profiling(text_analysis))
+    }
+
+    /// Create the missing values analysis pages.
+    pub fn create_missing_values_page(
+        &mut self,
+        missing_values_analysis: &MissingValueAnalysis,
+        visualizations: &Option<VisualizationManager>,
+    ) -> Result<(), PdfError> {
+        self.create_section_page(&ReportSection::missing_values(
+            missing_values_analysis,
+            visualizations,
+        ))
+    }
+
+    /// Creates the provenance page, with the fingerprint of the input and every option that
+    /// differs from its default.
+    ///
+    /// ### Parameters
+    ///
+    /// - `provenance`: The fingerprint of the input.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<(), PdfError>`: Unit type or a propagated PdfError.
+    pub fn create_provenance_page(&mut self, provenance: &Provenance) -> Result<(), PdfError> {
+        self.create_section_page(&ReportSection::from(provenance))
+    }
+
+    /// Creates the run timings appendix, with the time and throughput of the reading, analysis,
+    /// and visualization stages.
+    ///
+    /// ### Parameters
+    ///
+    /// - `throughput`: The stage timings of the run.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<(), PdfError>`: Unit type or a propagated PdfError.
+    pub fn create_throughput_page(&mut self, throughput: &Throughput) -> Result<(), PdfError> {
+        self.create_section_page(&ReportSection::from(throughput))
+    }
+
+    /// Creates the starter code appendix with ready-to-copy Polars and pandas code for loading the
+    /// dataset with the detected options.
+    ///
+    /// ### Parameters
+    ///
+    /// - `data_info`: The dataset information.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<(), PdfError>`: Unit type or a propagated PdfError.
+    pub fn create_starter_code_page(&mut self, data_info: &DataInfo) -> Result<(), PdfError> {
+        self.new_page()?;
+        self.record_section("Appendix: Starter Code");
+
+        self.add_text(
+            "Appendix: Starter Code",
+            self.bold_font,
+            SECTION_HEADER_FONT_SIZE,
+            0.1,
+            0.9,
+            None,
+        )?;
+
+        let mut y_fraction = 0.85;
+        let code_line_height_fraction = CODE_FONT_SIZE / self.page_height + LINE_HEIGHT_PADDING;
+        // Courier glyphs are 0.6 em wide, so long lines are split at a fixed character count.
+        let max_chars = ((0.8 * self.page_width) / (0.6 * CODE_FONT_SIZE)) as usize;
+
+        let snippets = [
+            ("Polars", snippets::polars_snippet(data_info)),
+            ("pandas", snippets::pandas_snippet(data_info)),
+        ];
+        for (library, code) in &snippets {
+            self.add_subsection_header(library, &mut y_fraction)?;
+            for line in code.lines() {
+                let chars: Vec<char> = line.chars().collect();
+                let chunks: Vec<String> = if chars.is_empty() {
+                    vec![String::new()]
+                } else {
+                    chars
+                        .chunks(max_chars)
+                        .map(|chunk| chunk.iter().collect())
+                        .collect()
+                };
+                for chunk in chunks {
+                    if self.need_new_page(y_fraction, code_line_height_fraction) {
+                        self.new_page()?;
+                        y_fraction = 0.9;
+                    }
+                    if !chunk.is_empty() {
+                        self.add_text(
+                            &chunk,
+                            self.code_font,
+                            CODE_FONT_SIZE,
+                            0.1,
+                            y_fraction,
+                            None,
+                        )?;
+                    }
+                    y_fraction -= code_line_height_fraction;
+                }
+            }
+            y_fraction -= 2.0 * code_line_height_fraction;
+        }
+
+        Ok(())
+    }
+
+    /// Creates the page of an analysis in place of its results if it failed.
+    ///
+    /// ### Parameters
+    ///
+    /// - `data_info`: The dataset information.
+    /// - `analysis`: The analysis whose page to create.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<bool, PdfError>`: Whether the analysis failed (and its page was created) or a
+    ///   propagated PdfError.
+    pub fn create_unavailable_page(
         &mut self,
-        descriptive_analysis: &DescriptiveAnalysis,
-    ) -> Result<(), LeadsError> {
+        data_info: &DataInfo,
+        analysis: RecoverableAnalysis,
+    ) -> Result<bool, PdfError> {
+        match data_info.failure(analysis) {
+            Some(failure) => {
+                self.create_section_page(&ReportSection::unavailable(failure))?;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
+    /// Creates the page of an analysis in place of its results if it was skipped for running over
+    /// the analysis timeout.
+    ///
+    /// ### Parameters
+    ///
+    /// - `data_info`: The dataset information.
+    /// - `analysis`: The analysis whose page to create.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<bool, PdfError>`: Whether the analysis timed out (and its page was created) or a
+    ///   propagated PdfError.
+    pub fn create_timed_out_page(
+        &mut self,
+        data_info: &DataInfo,
+        analysis: BudgetedAnalysis,
+    ) -> Result<bool, PdfError> {
+        match data_info.analysis_timeout {
+            Some(timeout) if data_info.timed_out_analyses.contains(&analysis) => {
+                self.create_section_page(&ReportSection::timed_out(analysis, timeout))?;
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    /// Creates a page for a section of the renderer independent report model, with the title as
+    /// the section header followed by the section blocks.
+    ///
+    /// ### Parameters
+    ///
+    /// - `section`: The section to render.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<(), PdfError>`: Unit type or a propagated PdfError.
+    pub fn create_section_page(&mut self, section: &ReportSection) -> Result<(), PdfError> {
+        let y_fraction = self.start_section(&section.title)?;
+        let mut context = PageContext {
+            manager: self,
+            y_fraction,
+        };
+        context.section(section)
+    }
+
+    /// Creates a custom section page, with the title as the section header, and hands the page
+    /// context to the section builder.
+    ///
+    /// ### Parameters
+    ///
+    /// - `title`: The section title.
+    /// - `build`: The function drawing the section content.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<(), PdfError>`: Unit type or a propagated PdfError.
+    fn create_custom_section_page(
+        &mut self,
+        title: &str,
+        build: CustomSectionBuilder<'a>,
+    ) -> Result<(), PdfError> {
         self.new_page()?;
-        self.section_page_map
-            .insert("Descriptive Analysis".to_owned(), self.current_page - 1);
+        self.record_section(title);
         self.add_text(
-            "Descriptive Analysis",
+            title,
             self.bold_font,
             SECTION_HEADER_FONT_SIZE,
             0.1,
@@ -417,123 +1935,193 @@ impl<'a> PageManager<'a> {
             None,
         )?;
 
-        let mut y_fraction = 0.86;
-        let line_height_fraction = FONT_SIZE / self.page_height + LINE_HEIGHT_PADDING;
-        let feature_line_height_fraction = FEATURE_HEADER_FONT_SIZE / self.page_height;
+        let mut context = PageContext {
+            manager: self,
+            y_fraction: 0.86,
+        };
+        build(&mut context)
+    }
+
+    /// Creates the term glossary pages, only including the terms referenced in the report grouped
+    /// by the section that first references them. The section is skipped if there are no terms to
+    /// define.
+    pub fn create_glossary_page(&mut self) -> Result<(), PdfError> {
+        let sections: Vec<(String, Vec<(String, String)>)> = self
+            .glossary
+            .section_entries(&self.referenced_terms)
+            .into_iter()
+            .map(|(section, entries)| {
+                (
+                    section.to_owned(),
+                    entries
+                        .into_iter()
+                        .map(|(term, definition)| (term.to_owned(), definition.to_owned()))
+                        .collect(),
+                )
+            })
+            .collect();
+        if sections.is_empty() {
+            return Ok(());
+        }
+
+        self.new_page()?;
+        self.record_section("Glossary");
 
-        self.add_text("Shape:", self.bold_font, FONT_SIZE, 0.1, y_fraction, None)?;
-        let shape_txt_width = self.get_text_width("Shape:", self.bold_font, FONT_SIZE)?;
         self.add_text(
-            &format!(
-                "{} rows, {} columns",
-                descriptive_analysis.n_rows, descriptive_analysis.n_cols
-            ),
-            self.font,
-            FONT_SIZE,
-            0.1 + shape_txt_width + 0.005,
-            y_fraction,
+            "Glossary",
+            self.bold_font,
+            SECTION_HEADER_FONT_SIZE,
+            0.1,
+            0.9,
             None,
         )?;
-        y_fraction -= 2.0 * line_height_fraction;
-
-        let analysis_values = descriptive_analysis.column_stats.get_analysis_values(
-            &descriptive_analysis.feature_indices,
-            &descriptive_analysis.column_map,
-        )?;
-
-        for feature_stats in analysis_values {
-            if self.need_new_page(
-                y_fraction,
-                feature_line_height_fraction + 7.0 * line_height_fraction,
-            ) {
-                self.new_page()?;
-                y_fraction = 0.9;
-            }
-
-            // Add feature sub-header.
-            let feature_name = &feature_stats["column_name"];
-            self.add_text(
-                feature_name,
-                self.bold_font,
-                FEATURE_HEADER_FONT_SIZE,
-                0.1,
-                y_fraction,
-                None,
-            )?;
-            y_fraction -= feature_line_height_fraction;
-
-            self.add_line(
-                0.1,
-                y_fraction + LINE_HEIGHT_PADDING,
-                0.9,
-                y_fraction + LINE_HEIGHT_PADDING,
-                0.5,
-            )?;
-            y_fraction -= line_height_fraction;
 
-            // Format metrics in two columns.
-            let left_column = 0.15;
-            let right_column = 0.55;
-            let mut counter = 0;
-
-            for (stat_name, stat_value) in feature_stats.iter() {
-                if stat_name == "column_name" {
-                    continue;
-                }
+        let mut y_fraction = 0.85;
+        let term_line_height_fraction = 12.0 / self.page_height + LINE_HEIGHT_PADDING;
+        let definition_line_height_fraction = 10.0 / self.page_height + LINE_HEIGHT_PADDING;
 
-                let x_position = if counter % 2 == 0 {
-                    left_column
-                } else {
-                    right_column
-                };
+        let term_offset = 0.1;
+        let definition_offset = 0.15;
 
-                self.add_text(
-                    &format!("{}:", stat_name),
-                    self.bold_font,
-                    FONT_SIZE,
-                    x_position,
+        for (section, entries) in &sections {
+            self.add_subsection_header(section, &mut y_fraction)?;
+            for (term, definition) in entries {
+                if self.need_new_page(
                     y_fraction,
-                    None,
-                )?;
-
-                let value_x = x_position + 0.2;
-                self.add_text(&stat_value, self.font, FONT_SIZE, value_x, y_fraction, None)?;
-
-                if counter % 2 == 1 {
-                    y_fraction -= line_height_fraction;
-                }
-                counter += 1;
-
-                if counter % 2 == 0
-                    && self.need_new_page(y_fraction - line_height_fraction, line_height_fraction)
-                {
+                    term_line_height_fraction + definition_line_height_fraction,
+                ) {
                     self.new_page()?;
                     y_fraction = 0.9;
                 }
+
+                self.add_text(term, self.bold_font, 12.0, term_offset, y_fraction, None)?;
+                y_fraction -= term_line_height_fraction;
+
+                // Set max width for glossary definitions as 70% of the page.
+                let max_width = 0.9;
+                let wrapped_lines =
+                    self.wrap_text(definition, definition_offset, max_width, self.font, 10.0);
+
+                for line in wrapped_lines {
+                    if self.need_new_page(y_fraction, definition_line_height_fraction) {
+                        self.new_page()?;
+                        y_fraction = 0.9;
+                    }
+                    self.add_text(&line, self.font, 10.0, definition_offset, y_fraction, None)?;
+                    y_fraction -= definition_line_height_fraction;
+                }
+
+                y_fraction -= 0.5 * definition_line_height_fraction;
             }
+        }
 
-            if counter % 2 == 1 {
-                y_fraction -= line_height_fraction;
+        Ok(())
+    }
+
+    /// Saves the document to disk as a tagged PDF: the untagged content is marked as artifacts and
+    /// the structure tree of the tagged content and the named destinations of the sections and
+    /// plots are appended to the saved document. If they can't be appended, the document is saved
+    /// untagged with a warning.
+    ///
+    /// ### Parameters
+    ///
+    /// - `path`: Path to save the file.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<(), PdfError>`: Unit type of a propagated PdfError.
+    pub fn save_to_file(&self, path: &PathBuf) -> Result<(), PdfError> {
+        let tracker = StageTracker::start(self.progress.as_ref(), Stage::Saving, "files", 1);
+        self.mark_artifacts()?;
+        let mut tags = self.tags.clone();
+        tags.resize_with(self.document.pages().len() as usize, Vec::new);
+        let pdf = self.document.save_to_bytes()?;
+        // The report is still useful without its tags, so it's saved untagged if they can't be
+        // added.
+        let pdf = match tagging::add_structure_tree(
+            pdf.clone(),
+            &tags,
+            &self.destinations,
+            self.locale.tag,
+        ) {
+            Ok(tagged) => tagged,
+            Err(e) => {
+                let message = format!("the report is saved without accessibility tags: {}", e);
+                match &self.progress {
+                    Some(progress) => progress.emit(&ProgressEvent::Warning(message)),
+                    None => eprintln!("Warning: {}", message),
+                }
+                pdf
             }
+        };
+        std::fs::write(path, pdf)?;
+        tracker.finish();
+        Ok(())
+    }
 
-            y_fraction -= 1.5 * line_height_fraction;
+    /// Records that a glossary term is referenced in the report, so it's included in the glossary.
+    fn reference_term(&mut self, term: &str) {
+        if !self.referenced_terms.contains_key(term) {
+            let section = self
+                .section_page_map
+                .keys()
+                .last()
+                .cloned()
+                .unwrap_or_default();
+            self.referenced_terms.insert(term.to_owned(), section);
         }
+    }
 
-        Ok(())
+    /// Records the current page as the page of a section for the table of contents, with the
+    /// section title prefixed with the name of the table being drawn, if any.
+    ///
+    /// ### Parameters
+    ///
+    /// - `title`: The section title.
+    fn record_section(&mut self, title: &str) {
+        let entry = match &self.table {
+            Some(table) => format!("{}: {}", table, title),
+            None => title.to_owned(),
+        };
+        self.record_destination(&entry, sections::stable_id(&entry), self.current_page);
+        self.section_page_map.insert(entry, self.current_page - 1);
     }
 
-    /// Create the missing values analysis pages.
-    pub fn create_missing_values_page(
-        &mut self,
-        missing_values_analysis: &MissingValueAnalysis,
-        visualizations: &Option<VisualizationManager>,
-    ) -> Result<(), PdfError> {
-        self.new_page()?;
-        self.section_page_map
-            .insert("Missing Values Analysis".to_owned(), self.current_page - 1);
+    /// Records a page as the named destination of a section entry or plot, kept at the page the
+    /// name was first recorded at. The ID is suffixed with `-2`, `-3`, ... if another name already
+    /// took it.
+    ///
+    /// ### Parameters
+    ///
+    /// - `name`: The section entry or plot path.
+    /// - `id`: The stable ID of the name.
+    /// - `page`: The page index.
+    fn record_destination(&mut self, name: &str, id: String, page: u32) {
+        if self.destination_ids.contains_key(name) {
+            return;
+        }
+        let id = sections::unique_id(&id, |id| self.destinations.contains_key(id));
+        self.destinations.insert(id.clone(), page);
+        self.destination_ids.insert(name.to_owned(), id);
+    }
 
+    /// Helper function to start a section on a new page, adding it to the table of contents,
+    /// drawing the section header, and listing the narrative insights of the section (if any)
+    /// below it.
+    ///
+    /// ### Parameters
+    ///
+    /// - `title`: The section title.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<f32, PdfError>`: The y fraction to continue the section at or a propagated
+    ///   PdfError.
+    fn start_section(&mut self, title: &str) -> Result<f32, PdfError> {
+        self.new_page()?;
+        self.record_section(title);
         self.add_text(
-            "Missing Values Analysis",
+            title,
             self.bold_font,
             SECTION_HEADER_FONT_SIZE,
             0.1,
@@ -541,155 +2129,334 @@ impl<'a> PageManager<'a> {
             None,
         )?;
 
-        let mut y_fraction = 0.85;
-        let line_height_fraction = FONT_SIZE / self.page_height + (LINE_HEIGHT_PADDING + 0.005);
-
-        // Add table headers (Feature, Missing Count, Missing Percentage).
-        self.add_text("Feature", self.bold_font, FONT_SIZE, 0.1, y_fraction, None)?;
-        self.add_text(
-            "Missing Count",
-            self.bold_font,
-            FONT_SIZE,
-            0.4,
-            y_fraction,
-            None,
-        )?;
-        self.add_text(
-            "Missing Percentage",
-            self.bold_font,
-            FONT_SIZE,
-            0.7,
-            y_fraction,
-            None,
-        )?;
-
-        // Draw a separator line
-        self.add_line(0.1, y_fraction - 0.02, 0.9, y_fraction - 0.02, 1.0)?;
-
-        y_fraction -= 2.0 * line_height_fraction;
+        let insights: Vec<String> = self
+            .narrative
+            .section_insights(title)
+            .iter()
+            .map(|insight| self.locale.localize(insight))
+            .collect();
+        let mut context = PageContext {
+            manager: self,
+            y_fraction: 0.86,
+        };
+        if !insights.is_empty() {
+            context.heading("Key Insights")?;
+            context.list(&insights)?;
+            context.y_fraction -= context.manager.line_height_fraction();
+        }
+        Ok(context.y_fraction)
+    }
 
-        // Iterate over the missing values data and display.
-        for (column, (missing_count, missing_percentage)) in
-            &missing_values_analysis.column_missing_values
-        {
-            // Add column name.
-            self.add_text(column, self.font, FONT_SIZE, 0.1, y_fraction, None)?;
+    /// Adds a table followed by a distribution sparkline in each row, e.g. the column overview of
+    /// the descriptive analysis. The text columns are evenly spaced before the sparklines, and
+    /// rows flow onto new pages as needed.
+    ///
+    /// ### Parameters
+    ///
+    /// - `headers`: The column headers, the last one being the header of the sparklines.
+    /// - `rows`: The rows, with a value per column before the sparkline.
+    /// - `counts`: The counts of the sparkline of each row.
+    /// - `y_fraction`: The current y fraction, updated to the position below the table.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<(), PdfError>`: Unit type or a propagated PdfError.
+    pub fn add_sparkline_table(
+        &mut self,
+        headers: &[&str],
+        rows: &[Vec<String>],
+        counts: &[Vec<u64>],
+        y_fraction: &mut f32,
+    ) -> Result<(), PdfError> {
+        let line_height_fraction = FONT_SIZE / self.page_height + LINE_HEIGHT_PADDING;
+        let sparkline_height = FONT_SIZE / self.page_height;
+        let sparkline_x = 0.72;
+        let column_width = (sparkline_x - 0.1) / headers.len().saturating_sub(1).max(1) as f32;
+        let x_fraction = |i: usize| {
+            if i + 1 == headers.len() {
+                sparkline_x
+            } else {
+                0.1 + i as f32 * column_width
+            }
+        };
 
-            // Add missing count.
+        for (i, header) in headers.iter().enumerate() {
             self.add_text(
-                &format!("{}", missing_count),
-                self.font,
+                header,
+                self.bold_font,
                 FONT_SIZE,
-                0.4,
-                y_fraction,
+                x_fraction(i),
+                *y_fraction,
                 None,
             )?;
+        }
+        self.add_line(0.1, *y_fraction - 0.01, 0.9, *y_fraction - 0.01, 1.0)?;
+        *y_fraction -= 1.5 * line_height_fraction;
+
+        for (row, counts) in rows.iter().zip(counts) {
+            if self.need_new_page(*y_fraction, line_height_fraction) {
+                self.new_page()?;
+                *y_fraction = 0.9;
+            }
+            for (i, value) in row.iter().enumerate() {
+                self.add_text(
+                    value,
+                    self.font,
+                    FONT_SIZE,
+                    x_fraction(i),
+                    *y_fraction,
+                    None,
+                )?;
+            }
+            self.add_sparkline(
+                counts,
+                sparkline_x,
+                *y_fraction,
+                0.9,
+                *y_fraction + sparkline_height,
+            )?;
+            *y_fraction -= 1.5 * line_height_fraction;
+        }
+        Ok(())
+    }
+
+    /// Adds a sub-header that groups several blocks within a section, starting a new page if
+    /// there isn't room for the sub-header and a few lines below it.
+    ///
+    /// ### Parameters
+    ///
+    /// - `title`: The sub-header text.
+    /// - `y_fraction`: The current y fraction, updated to the position below the sub-header.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<(), PdfError>`: Unit type or a propagated PdfError.
+    pub fn add_subsection_header(
+        &mut self,
+        title: &str,
+        y_fraction: &mut f32,
+    ) -> Result<(), PdfError> {
+        let line_height_fraction = FONT_SIZE / self.page_height + LINE_HEIGHT_PADDING;
+        if self.need_new_page(*y_fraction, 3.0 * line_height_fraction) {
+            self.new_page()?;
+            *y_fraction = 0.9;
+        }
+        self.add_text(
+            title,
+            self.bold_font,
+            FEATURE_HEADER_FONT_SIZE,
+            0.1,
+            *y_fraction,
+            None,
+        )?;
+        *y_fraction -= 2.0 * line_height_fraction;
+        Ok(())
+    }
+
+    /// Adds a simple table, with a note when only the first rows are listed. Rows flow onto new
+    /// pages as needed.
+    ///
+    /// ### Parameters
+    ///
+    /// - `headers`: The column headers and their x fractions.
+    /// - `rows`: The listed rows, with a value per column.
+    /// - `total`: The total number of rows, including the ones that aren't listed.
+    /// - `y_fraction`: The current y fraction, updated to the position below the table.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<(), PdfError>`: Unit type or a propagated PdfError.
+    pub fn add_table(
+        &mut self,
+        headers: &[(&str, f32)],
+        rows: &[Vec<String>],
+        total: usize,
+        y_fraction: &mut f32,
+    ) -> Result<(), PdfError> {
+        self.add_highlighted_table(headers, rows, total, &[], y_fraction)
+    }
 
-            // Add missing percentage.
+    /// Adds a table like `add_table`, with the highlighted rows drawn in bold red.
+    ///
+    /// ### Parameters
+    ///
+    /// - `headers`: The column headers and their x fractions.
+    /// - `rows`: The listed rows, with a value per column.
+    /// - `total`: The total number of rows, including the ones that aren't listed.
+    /// - `highlighted_rows`: The indices of the rows to highlight.
+    /// - `y_fraction`: The current y fraction, updated to the position below the table.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<(), PdfError>`: Unit type or a propagated PdfError.
+    pub fn add_highlighted_table(
+        &mut self,
+        headers: &[(&str, f32)],
+        rows: &[Vec<String>],
+        total: usize,
+        highlighted_rows: &[usize],
+        y_fraction: &mut f32,
+    ) -> Result<(), PdfError> {
+        let line_height_fraction = FONT_SIZE / self.page_height + LINE_HEIGHT_PADDING;
+        let table = self.n_tables;
+        self.n_tables += 1;
+        self.text_tag = TextTag::Cell(TableCell { table, row: 0 });
+        for (header, x_fraction) in headers {
             self.add_text(
-                &format!("{:.2}%", missing_percentage),
-                self.font,
+                header,
+                self.bold_font,
                 FONT_SIZE,
-                0.7,
-                y_fraction,
+                *x_fraction,
+                *y_fraction,
                 None,
             )?;
+        }
+        self.add_line(0.1, *y_fraction - 0.01, 0.9, *y_fraction - 0.01, 1.0)?;
+        *y_fraction -= 1.5 * line_height_fraction;
 
-            // Move to the next line, and add page breaks if necessary.
-            y_fraction -= line_height_fraction;
-            if self.need_new_page(y_fraction, line_height_fraction) {
+        for (i, row) in rows.iter().enumerate() {
+            self.text_tag = TextTag::Cell(TableCell { table, row: i + 1 });
+            if self.need_new_page(*y_fraction, line_height_fraction) {
                 self.new_page()?;
-                y_fraction = 0.9;
+                *y_fraction = 0.9;
+            }
+            let (font, color) = if highlighted_rows.contains(&i) {
+                (self.bold_font, Some(PdfColor::new(200, 0, 0, 255)))
+            } else {
+                (self.font, None)
+            };
+            for (value, (_, x_fraction)) in row.iter().zip(headers) {
+                self.add_text(value, font, FONT_SIZE, *x_fraction, *y_fraction, color)?;
             }
+            *y_fraction -= line_height_fraction;
         }
+        self.text_tag = TextTag::Flow;
 
-        // Add visualizations if applicable.
-        if let Some(viz_manager) = visualizations {
-            if let Some(missing_values_plots) = viz_manager
-                .visualizations
-                .get(&ReportSection::MissingValues)
-            {
-                for (_, plot_path) in missing_values_plots {
-                    y_fraction -= 0.5 * line_height_fraction;
-                    self.add_image(plot_path, 0.8, 0.6, &mut y_fraction)?;
-                    y_fraction -= 0.5 * line_height_fraction;
-                }
-            }
+        if total > rows.len() {
+            self.add_text(
+                &format!("... and {} more.", total - rows.len()),
+                self.italic_font,
+                FONT_SIZE,
+                0.1,
+                *y_fraction,
+                None,
+            )?;
+            *y_fraction -= line_height_fraction;
         }
-
         Ok(())
     }
 
-    /// Creates the term glossary pages.
-    pub fn create_glossary_page(&mut self) -> Result<(), PdfError> {
-        self.new_page()?;
-        self.section_page_map
-            .insert("Glossary".to_owned(), self.current_page - 1);
+    /// Adds a feature sub-header followed by its statistics laid out in two columns, i.e. a
+    /// key-value grid. Statistic names that are glossary terms are added to the glossary.
+    ///
+    /// ### Parameters
+    ///
+    /// - `feature_name`: The feature name used as the sub-header.
+    /// - `definition`: The optional data dictionary entry, shown below the sub-header.
+    /// - `feature_stats`: The map of statistic names to their formatted values.
+    /// - `y_fraction`: The current y fraction, updated to the position below the block.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<(), PdfError>`: Unit type or a propagated PdfError.
+    pub fn add_stat_block(
+        &mut self,
+        feature_name: &str,
+        definition: Option<&ColumnDefinition>,
+        feature_stats: &IndexMap<String, String>,
+        y_fraction: &mut f32,
+    ) -> Result<(), PdfError> {
+        let line_height_fraction = FONT_SIZE / self.page_height + LINE_HEIGHT_PADDING;
+        let feature_line_height_fraction = FEATURE_HEADER_FONT_SIZE / self.page_height;
+        let definition_lines = definition
+            .map(|definition| self.definition_lines(definition, 0.1, 0.9))
+            .unwrap_or_default();
+
+        if self.need_new_page(
+            *y_fraction,
+            feature_line_height_fraction
+                + (7.0 + definition_lines.len() as f32) * line_height_fraction,
+        ) {
+            self.new_page()?;
+            *y_fraction = 0.9;
+        }
 
+        // Add feature sub-header.
         self.add_text(
-            "Glossary",
+            feature_name,
             self.bold_font,
-            SECTION_HEADER_FONT_SIZE,
+            FEATURE_HEADER_FONT_SIZE,
             0.1,
-            0.9,
+            *y_fraction,
             None,
         )?;
+        *y_fraction -= feature_line_height_fraction;
 
-        let mut y_fraction = 0.85;
-        let term_line_height_fraction = 12.0 / self.page_height + LINE_HEIGHT_PADDING;
-        let definition_line_height_fraction = 10.0 / self.page_height + LINE_HEIGHT_PADDING;
+        self.add_line(
+            0.1,
+            *y_fraction + LINE_HEIGHT_PADDING,
+            0.9,
+            *y_fraction + LINE_HEIGHT_PADDING,
+            0.5,
+        )?;
+        *y_fraction -= line_height_fraction;
 
-        let glossary = Glossary::new();
-        let term_offset = 0.1;
-        let definition_offset = 0.15;
+        for (line, font) in &definition_lines {
+            self.add_text(line, *font, FONT_SIZE, 0.1, *y_fraction, None)?;
+            *y_fraction -= line_height_fraction;
+        }
+        if !definition_lines.is_empty() {
+            *y_fraction -= 0.5 * line_height_fraction;
+        }
 
-        for (term, definition) in glossary.terms.iter().zip(glossary.definitions.iter()) {
-            if self.need_new_page(
-                y_fraction,
-                term_line_height_fraction + definition_line_height_fraction,
-            ) {
-                self.new_page()?;
-                y_fraction = 0.9;
-            }
+        // Format metrics in two columns.
+        let left_column = 0.15;
+        let right_column = 0.55;
+        let mut counter = 0;
+
+        for (stat_name, stat_value) in feature_stats.iter() {
+            self.reference_term(stat_name);
+            let x_position = if counter % 2 == 0 {
+                left_column
+            } else {
+                right_column
+            };
 
-            self.add_text(term, self.bold_font, 12.0, term_offset, y_fraction, None)?;
-            y_fraction -= term_line_height_fraction;
+            self.add_text(
+                &format!("{}:", stat_name),
+                self.bold_font,
+                FONT_SIZE,
+                x_position,
+                *y_fraction,
+                None,
+            )?;
 
-            // Set max width for glossary definitions as 70% of the page.
-            let max_width = 0.9;
-            let wrapped_lines =
-                self.wrap_text(definition, definition_offset, max_width, self.font, 10.0);
+            let value_x = x_position + 0.2;
+            self.add_text(stat_value, self.font, FONT_SIZE, value_x, *y_fraction, None)?;
 
-            for line in wrapped_lines {
-                if self.need_new_page(y_fraction, definition_line_height_fraction) {
-                    self.new_page()?;
-                    y_fraction = 0.9;
-                }
-                self.add_text(&line, self.font, 10.0, definition_offset, y_fraction, None)?;
-                y_fraction -= definition_line_height_fraction;
+            if counter % 2 == 1 {
+                *y_fraction -= line_height_fraction;
             }
+            counter += 1;
 
-            y_fraction -= 0.5 * definition_line_height_fraction;
+            if counter % 2 == 0
+                && self.need_new_page(*y_fraction - line_height_fraction, line_height_fraction)
+            {
+                self.new_page()?;
+                *y_fraction = 0.9;
+            }
         }
 
-        Ok(())
-    }
+        if counter % 2 == 1 {
+            *y_fraction -= line_height_fraction;
+        }
+
+        *y_fraction -= 1.5 * line_height_fraction;
 
-    /// Saves the document to disk.
-    ///
-    /// ### Parameters
-    ///
-    /// - `path`: Path to save the file.
-    ///
-    /// ### Returns
-    ///
-    /// - `Result<(), PdfError>`: Unit type of a propagated PdfError.
-    pub fn save_to_file(&self, path: &PathBuf) -> Result<(), PdfError> {
-        self.document.save_to_file(path)?;
         Ok(())
     }
 
-    /// Helper function to add text to a page.
+    /// Adds a single line of text to the current page. The text isn't wrapped, see `wrap_text`.
     ///
     /// ### Parameters
     ///
@@ -702,9 +2469,8 @@ impl<'a> PageManager<'a> {
     ///
     /// ### Returns
     ///
-    /// - `Result<PdfPageTextObject, PdfError>`: The created and positioned text object or a PDF
-    /// error.
-    fn add_text(
+    /// - `Result<(), PdfError>`: Unit type or a propagated PdfError.
+    pub fn add_text(
         &mut self,
         text: &str,
         font: PdfFontToken,
@@ -720,15 +2486,39 @@ impl<'a> PageManager<'a> {
             PdfPoints::new(self.page_width * x_fraction),
             PdfPoints::new(self.page_height * y_fraction),
         )?;
+        let text_object = PdfPageObject::from(text_object);
+        let role = match self.text_tag {
+            TextTag::Flow if font_size == SECTION_HEADER_FONT_SIZE => Some(TagRole::H1),
+            TextTag::Flow if font_size == FEATURE_HEADER_FONT_SIZE => Some(TagRole::H2),
+            TextTag::Flow => Some(TagRole::P),
+            TextTag::Cell(TableCell { row: 0, .. }) => Some(TagRole::Th),
+            TextTag::Cell(_) => Some(TagRole::Td),
+            TextTag::Artifact => None,
+        };
+        if let Some(role) = role {
+            self.tag_object(&text_object, role, None);
+        }
         let mut current_page = self.document.pages().get(self.current_page as u16).unwrap();
-        current_page.objects_mut().add_text_object(text_object)?;
+        current_page.objects_mut().add_object(text_object)?;
         Ok(())
     }
 
-    /// Helper function to add an image to a page.
-    fn add_image(
+    /// Adds an image centered below the current position, scaled down to fit the maximum size
+    /// while preserving its aspect ratio. A new page is started if the image doesn't fit.
+    ///
+    /// ### Parameters
+    ///
+    /// - `path`: The path to the image file.
+    /// - `max_width`: The maximum width as a fraction of the page width.
+    /// - `max_height`: The maximum height as a fraction of the page height.
+    /// - `y_fraction`: The current y fraction, updated to the position below the image.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<(), PdfError>`: Unit type or a propagated PdfError.
+    pub fn add_image(
         &mut self,
-        path: &PathBuf,
+        path: &Path,
         max_width: f32,
         max_height: f32,
         y_fraction: &mut f32,
@@ -750,6 +2540,11 @@ impl<'a> PageManager<'a> {
             self.new_page()?;
             *y_fraction = 0.9;
         }
+        self.record_destination(
+            &path.to_string_lossy(),
+            sections::plot_id(path),
+            self.current_page,
+        );
 
         let mut image_object = PdfPageImageObject::new(&self.document, &img)?;
 
@@ -767,25 +2562,151 @@ impl<'a> PageManager<'a> {
             PdfPoints::new(self.page_height * y),
         )?;
 
+        let image_object = PdfPageObject::from(image_object);
+        self.tag_object(&image_object, TagRole::Figure, Some(image_alt_text(path)));
         let mut current_page = self.document.pages().get(self.current_page as u16).unwrap();
-        current_page.objects_mut().add_image_object(image_object)?;
+        current_page.objects_mut().add_object(image_object)?;
 
         *y_fraction = y;
 
         Ok(())
     }
 
-    /// Creates a new page at the end of the document.
-    fn new_page(&mut self) -> Result<(), PdfError> {
+    /// Creates a new page at the end of the document, which becomes the current page.
+    pub fn new_page(&mut self) -> Result<(), PdfError> {
         self.document
             .pages_mut()
             .create_page_at_end(PdfPagePaperSize::new_portrait(PAPER_SIZE))?;
         self.current_page = self.document.pages().len() as u32 - 1;
+        self.tags
+            .resize_with(self.current_page as usize + 1, Vec::new);
+
+        // Running header with the table name, so each page can be told apart.
+        if let Some(table) = self.table.clone() {
+            let text_tag = std::mem::replace(&mut self.text_tag, TextTag::Artifact);
+            self.add_text(
+                &format!("Table: {}", table),
+                self.italic_font,
+                10.0,
+                0.1,
+                0.96,
+                None,
+            )?;
+            self.text_tag = text_tag;
+        }
+        Ok(())
+    }
+
+    /// Marks a page object as the next tagged content of the current page, with its index among
+    /// the tagged content of the page as marked content ID.
+    ///
+    /// ### Parameters
+    ///
+    /// - `object`: The page object, before it's added to the current page.
+    /// - `role`: The structure role of the object.
+    /// - `alt`: The alternative text of a figure.
+    fn tag_object(&mut self, object: &PdfPageObject, role: TagRole, alt: Option<String>) {
+        let page = self.current_page as usize;
+        if self.tags.len() <= page {
+            self.tags.resize_with(page + 1, Vec::new);
+        }
+        let bindings = self.document.bindings();
+        let handle = bindings.get_handle_from_object(object);
+        let mark = bindings.FPDFPageObj_AddMark(handle, role.name());
+        bindings.FPDFPageObjMark_SetIntParam(
+            bindings.get_handle_from_document(&self.document),
+            handle,
+            mark,
+            "MCID",
+            self.tags[page].len() as i32,
+        );
+        self.tags[page].push(TaggedContent {
+            role,
+            alt,
+            cell: match self.text_tag {
+                TextTag::Cell(cell) if role != TagRole::Figure => Some(cell),
+                _ => None,
+            },
+        });
+    }
+
+    /// Marks the untagged page objects (rules, rectangles, page numbers, ...) as artifacts, so
+    /// screen readers skip them.
+    fn mark_artifacts(&self) -> Result<(), PdfError> {
+        let bindings = self.document.bindings();
+        for mut page in self.document.pages().iter() {
+            for object in page.objects().iter() {
+                let handle = bindings.get_handle_from_object(&object);
+                if bindings.FPDFPageObj_CountMarks(handle) == 0 {
+                    bindings.FPDFPageObj_AddMark(handle, "Artifact");
+                }
+            }
+            page.regenerate_content()?;
+        }
         Ok(())
     }
 
-    // Adds a horizontal line.
-    fn add_line(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, width: f32) -> Result<(), PdfError> {
+    /// Adds a sparkline to the current page, as bars spread evenly across the box with heights
+    /// proportional to the counts, over a thin baseline.
+    ///
+    /// ### Parameters
+    ///
+    /// - `counts`: The bar counts, from left to right.
+    /// - `x1`: The x fraction of the left of the box.
+    /// - `y1`: The y fraction of the bottom of the box.
+    /// - `x2`: The x fraction of the right of the box.
+    /// - `y2`: The y fraction of the top of the box.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<(), PdfError>`: Unit type or a propagated PdfError.
+    pub fn add_sparkline(
+        &mut self,
+        counts: &[u64],
+        x1: f32,
+        y1: f32,
+        x2: f32,
+        y2: f32,
+    ) -> Result<(), PdfError> {
+        let max_count = counts.iter().copied().max().unwrap_or(0);
+        if max_count == 0 {
+            return Ok(());
+        }
+        let bar_width = (x2 - x1) / counts.len() as f32;
+        for (i, count) in counts.iter().enumerate().filter(|(_, count)| **count > 0) {
+            let x = x1 + i as f32 * bar_width;
+            self.add_rectangle(
+                x + 0.1 * bar_width,
+                y1,
+                x + 0.9 * bar_width,
+                y1 + (y2 - y1) * (*count as f32 / max_count as f32),
+                PdfColor::new(70, 130, 180, 255),
+            )?;
+        }
+        self.add_line(x1, y1, x2, y1, 0.25)
+    }
+
+    /// Adds a black line to the current page.
+    ///
+    /// ### Parameters
+    ///
+    /// - `x1`: The x fraction of the start of the line.
+    /// - `y1`: The y fraction of the start of the line.
+    /// - `x2`: The x fraction of the end of the line.
+    /// - `y2`: The y fraction of the end of the line.
+    /// - `width`: The stroke width in points.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<(), PdfError>`: Unit type or a propagated PdfError.
+    pub fn add_line(
+        &mut self,
+        x1: f32,
+        y1: f32,
+        x2: f32,
+        y2: f32,
+        width: f32,
+    ) -> Result<(), PdfError> {
         let mut path = PdfPagePathObject::new(
             &self.document,
             PdfPoints::new(self.page_width * x1),
@@ -811,12 +2732,21 @@ impl<'a> PageManager<'a> {
             .pages_mut()
             .create_page_at_index(PdfPagePaperSize::new_portrait(PAPER_SIZE), index)?;
         self.current_page = index as u32;
+        if self.tags.len() < index as usize {
+            self.tags.resize_with(index as usize, Vec::new);
+        }
+        self.tags.insert(index as usize, Vec::new());
+        for page in self.destinations.values_mut() {
+            if *page >= index as u32 {
+                *page += 1;
+            }
+        }
         Ok(())
     }
 
     /// Based on the Y coordinate page fraction and the content height fraction determine whether a
     /// new page is needed.
-    fn need_new_page(&self, y_fraction: f32, content_height_fraction: f32) -> bool {
+    pub fn need_new_page(&self, y_fraction: f32, content_height_fraction: f32) -> bool {
         y_fraction - content_height_fraction < BOTTOM_MARGIN
     }
 
@@ -824,9 +2754,8 @@ impl<'a> PageManager<'a> {
     fn add_page_numbers(&mut self) -> Result<(), PdfError> {
         let total_pages = self.document.pages().len() as u32;
         let toc_pages = *self.section_page_map.get("Table of Contents").unwrap_or(&0) + 1;
-        let mut current_page = 1;
-        for page_index in toc_pages..total_pages {
-            let text = format!("{}", current_page);
+        for (current_page, page_index) in (toc_pages..total_pages).enumerate() {
+            let text = format!("{}", current_page + 1);
 
             let mut text_object =
                 PdfPageTextObject::new(&self.document, &text, self.font, PdfPoints::new(12.0))?;
@@ -838,8 +2767,6 @@ impl<'a> PageManager<'a> {
 
             let mut page = self.document.pages().get(page_index as u16).unwrap();
             page.objects_mut().add_text_object(text_object)?;
-
-            current_page += 1;
         }
 
         Ok(())
@@ -852,11 +2779,13 @@ impl<'a> PageManager<'a> {
         let mut current_width = 0.0;
 
         while current_width < total_width {
-            dotted_line.push_str(".");
+            dotted_line.push('.');
             current_width = self.get_text_width(&dotted_line, self.font, FONT_SIZE)?;
         }
 
+        self.text_tag = TextTag::Artifact;
         self.add_text(&dotted_line, self.font, FONT_SIZE, start_x, y, None)?;
+        self.text_tag = TextTag::Flow;
 
         Ok(())
     }
@@ -898,8 +2827,9 @@ impl<'a> PageManager<'a> {
         Ok(total_width / self.page_width)
     }
 
-    /// Wrap text lines to prevent page overflows.
-    fn wrap_text(
+    /// Wraps text into lines that fit between the offset and the maximum width, both as fractions
+    /// of the page width, to prevent page overflows.
+    pub fn wrap_text(
         &self,
         text: &str,
         offset: f32,
@@ -936,8 +2866,56 @@ impl<'a> PageManager<'a> {
         lines
     }
 
-    // Helper function to add a filled rectangle
-    fn add_rectangle(
+    /// Wraps a data dictionary entry into lines of text, with the description in italics followed
+    /// by the unit and owner details.
+    ///
+    /// ### Parameters
+    ///
+    /// - `definition`: The data dictionary entry for the column.
+    /// - `offset`: The x fraction the lines start at.
+    /// - `max_width`: The x fraction the lines should wrap at.
+    ///
+    /// ### Returns
+    ///
+    /// - `Vec<(String, PdfFontToken)>`: The wrapped lines and the font for each line.
+    fn definition_lines(
+        &self,
+        definition: &ColumnDefinition,
+        offset: f32,
+        max_width: f32,
+    ) -> Vec<(String, PdfFontToken)> {
+        let mut lines = Vec::new();
+        if let Some(description) = &definition.description {
+            lines.extend(
+                self.wrap_text(description, offset, max_width, self.italic_font, FONT_SIZE)
+                    .into_iter()
+                    .map(|line| (line, self.italic_font)),
+            );
+        }
+        if let Some(details) = definition.details() {
+            lines.extend(
+                self.wrap_text(&details, offset, max_width, self.font, FONT_SIZE)
+                    .into_iter()
+                    .map(|line| (line, self.font)),
+            );
+        }
+        lines
+    }
+
+    /// Adds a filled rectangle to the current page.
+    ///
+    /// ### Parameters
+    ///
+    /// - `x1`: The x fraction of one corner.
+    /// - `y1`: The y fraction of one corner.
+    /// - `x2`: The x fraction of the opposite corner.
+    /// - `y2`: The y fraction of the opposite corner.
+    /// - `color`: The fill color.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<(), PdfError>`: Unit type or a propagated PdfError.
+    pub fn add_rectangle(
         &mut self,
         x1: f32,
         y1: f32,
@@ -974,6 +2952,291 @@ impl<'a> PageManager<'a> {
     }
 }
 
+/// The drawing context handed to custom section builders. The context keeps track of the current
+/// y fraction, so its flowing helpers lay out content top to bottom and start new pages as
+/// needed. The underlying `PageManager` is available for low-level drawing.
+pub struct PageContext<'p, 'a> {
+    /// The page manager the section is drawn with.
+    manager: &'p mut PageManager<'a>,
+    /// The current y fraction.
+    y_fraction: f32,
+}
+
+impl<'p, 'a> PageContext<'p, 'a> {
+    /// Gets the page manager for low-level drawing.
+    pub fn manager(&mut self) -> &mut PageManager<'a> {
+        self.manager
+    }
+
+    /// Gets the current y fraction.
+    pub fn y_fraction(&self) -> f32 {
+        self.y_fraction
+    }
+
+    /// Sets the current y fraction, e.g. after drawing with the page manager directly.
+    pub fn set_y_fraction(&mut self, y_fraction: f32) {
+        self.y_fraction = y_fraction;
+    }
+
+    /// Adds a sub-header.
+    pub fn heading(&mut self, text: &str) -> Result<(), PdfError> {
+        self.manager
+            .add_subsection_header(text, &mut self.y_fraction)
+    }
+
+    /// Adds a paragraph of wrapped text, followed by a blank half line.
+    pub fn paragraph(&mut self, text: &str) -> Result<(), PdfError> {
+        let font = self.manager.font();
+        let line_height_fraction = self.manager.line_height_fraction();
+        for line in self.manager.wrap_text(text, 0.1, 0.9, font, FONT_SIZE) {
+            if self
+                .manager
+                .need_new_page(self.y_fraction, line_height_fraction)
+            {
+                self.manager.new_page()?;
+                self.y_fraction = 0.9;
+            }
+            self.manager
+                .add_text(&line, font, FONT_SIZE, 0.1, self.y_fraction, None)?;
+            self.y_fraction -= line_height_fraction;
+        }
+        self.y_fraction -= 0.5 * line_height_fraction;
+        Ok(())
+    }
+
+    /// Adds a line of italic text, followed by a blank line.
+    pub fn note(&mut self, text: &str) -> Result<(), PdfError> {
+        let font = self.manager.italic_font();
+        let line_height_fraction = self.manager.line_height_fraction();
+        for line in self.manager.wrap_text(text, 0.1, 0.9, font, FONT_SIZE) {
+            if self
+                .manager
+                .need_new_page(self.y_fraction, line_height_fraction)
+            {
+                self.manager.new_page()?;
+                self.y_fraction = 0.9;
+            }
+            self.manager
+                .add_text(&line, font, FONT_SIZE, 0.1, self.y_fraction, None)?;
+            self.y_fraction -= line_height_fraction;
+        }
+        self.y_fraction -= line_height_fraction;
+        Ok(())
+    }
+
+    /// Adds a bulleted list, with the items wrapped and kept on a single page each.
+    pub fn list(&mut self, items: &[String]) -> Result<(), PdfError> {
+        let font = self.manager.font();
+        let line_height_fraction = self.manager.line_height_fraction();
+        for item in items {
+            let lines = self.manager.wrap_text(item, 0.13, 0.9, font, FONT_SIZE);
+            if self
+                .manager
+                .need_new_page(self.y_fraction, lines.len() as f32 * line_height_fraction)
+            {
+                self.manager.new_page()?;
+                self.y_fraction = 0.9;
+            }
+            self.manager
+                .add_text("-", font, FONT_SIZE, 0.1, self.y_fraction, None)?;
+            for line in lines {
+                self.manager
+                    .add_text(&line, font, FONT_SIZE, 0.13, self.y_fraction, None)?;
+                self.y_fraction -= line_height_fraction;
+            }
+            self.y_fraction -= 0.5 * line_height_fraction;
+        }
+        Ok(())
+    }
+
+    /// Adds a table with evenly spaced columns, followed by a blank line.
+    ///
+    /// ### Parameters
+    ///
+    /// - `headers`: The column headers.
+    /// - `rows`: The rows, with a value per column.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<(), PdfError>`: Unit type or a propagated PdfError.
+    pub fn table(&mut self, headers: &[&str], rows: &[Vec<String>]) -> Result<(), PdfError> {
+        self.highlighted_table(headers, rows, &[])
+    }
+
+    /// Adds a table with evenly spaced columns and the highlighted rows drawn in bold red,
+    /// followed by a blank line.
+    ///
+    /// ### Parameters
+    ///
+    /// - `headers`: The column headers.
+    /// - `rows`: The rows, with a value per column.
+    /// - `highlighted_rows`: The indices of the rows to highlight.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<(), PdfError>`: Unit type or a propagated PdfError.
+    pub fn highlighted_table(
+        &mut self,
+        headers: &[&str],
+        rows: &[Vec<String>],
+        highlighted_rows: &[usize],
+    ) -> Result<(), PdfError> {
+        let column_width = 0.8 / headers.len().max(1) as f32;
+        let headers: Vec<(&str, f32)> = headers
+            .iter()
+            .enumerate()
+            .map(|(i, header)| (*header, 0.1 + i as f32 * column_width))
+            .collect();
+        self.manager.add_highlighted_table(
+            &headers,
+            rows,
+            rows.len(),
+            highlighted_rows,
+            &mut self.y_fraction,
+        )?;
+        self.y_fraction -= self.manager.line_height_fraction();
+        Ok(())
+    }
+
+    /// Adds a table with evenly spaced columns followed by a distribution sparkline in each row,
+    /// and a blank line.
+    ///
+    /// ### Parameters
+    ///
+    /// - `headers`: The column headers, the last one being the header of the sparklines.
+    /// - `rows`: The rows, with a value per column before the sparkline.
+    /// - `counts`: The counts of the sparkline of each row.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<(), PdfError>`: Unit type or a propagated PdfError.
+    pub fn sparkline_table(
+        &mut self,
+        headers: &[&str],
+        rows: &[Vec<String>],
+        counts: &[Vec<u64>],
+    ) -> Result<(), PdfError> {
+        self.manager
+            .add_sparkline_table(headers, rows, counts, &mut self.y_fraction)?;
+        self.y_fraction -= self.manager.line_height_fraction();
+        Ok(())
+    }
+
+    /// Adds a titled key-value grid laid out in two columns.
+    ///
+    /// ### Parameters
+    ///
+    /// - `title`: The title of the grid.
+    /// - `values`: The map of names to their formatted values.
+    ///
+    /// ### Returns
+    ///
+    /// - `Result<(), PdfError>`: Unit type or a propagated PdfError.
+    pub fn key_values(
+        &mut self,
+        title: &str,
+        values: &IndexMap<String, String>,
+    ) -> Result<(), PdfError> {
+        self.manager
+            .add_stat_block(title, None, values, &mut self.y_fraction)
+    }
+
+    /// Adds a block of the renderer independent report model.
+    pub fn block(&mut self, block: &ReportBlock) -> Result<(), PdfError> {
+        match block {
+            ReportBlock::Heading { text } => self.heading(text),
+            ReportBlock::Paragraph { text } => self.paragraph(text),
+            ReportBlock::Note { text } => self.note(text),
+            ReportBlock::List { items } => self.list(items),
+            ReportBlock::Table {
+                headers,
+                rows,
+                highlighted_rows,
+                ..
+            } => {
+                let headers: Vec<&str> = headers.iter().map(String::as_str).collect();
+                self.highlighted_table(&headers, rows, highlighted_rows)
+            }
+            ReportBlock::SparklineTable {
+                headers,
+                rows,
+                counts,
+            } => {
+                let headers: Vec<&str> = headers.iter().map(String::as_str).collect();
+                self.sparkline_table(&headers, rows, counts)
+            }
+            ReportBlock::KeyValues {
+                title,
+                values,
+                definition,
+                ..
+            } => {
+                self.manager.add_stat_block(
+                    title,
+                    definition.as_ref(),
+                    values,
+                    &mut self.y_fraction,
+                )?;
+                self.manager.add_mini_toc_entry(title);
+                Ok(())
+            }
+            ReportBlock::Image(plot) => self.image(&plot.path),
+            ReportBlock::ColumnDetail {
+                column,
+                values,
+                images,
+                ..
+            } => {
+                self.key_values(column, values)?;
+                self.manager.add_mini_toc_entry(column);
+                for plot in images {
+                    self.image(&plot.path)?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Adds the blocks of a section of the renderer independent report model and references its
+    /// glossary terms. The section header is not drawn. Sections with at least
+    /// `MIN_MINI_TOC_ENTRIES` key-value grids or column details get a mini table of contents
+    /// before them. The numbers and dates of the section are formatted in the report locale.
+    pub fn section(&mut self, section: &ReportSection) -> Result<(), PdfError> {
+        let section = section.localize(self.manager.locale);
+        for term in &section.terms {
+            self.manager.reference_term(term);
+        }
+        let is_detail = |block: &ReportBlock| {
+            matches!(
+                block,
+                ReportBlock::KeyValues { .. } | ReportBlock::ColumnDetail { .. }
+            )
+        };
+        let mini_toc = section
+            .blocks
+            .iter()
+            .filter(|block| is_detail(block))
+            .count()
+            >= MIN_MINI_TOC_ENTRIES;
+        for block in &section.blocks {
+            if mini_toc && is_detail(block) && self.manager.mini_toc.is_none() {
+                self.manager.start_mini_toc(&mut self.y_fraction)?;
+            }
+            self.block(block)?;
+        }
+        self.manager.finish_mini_toc("Column Details")
+    }
+
+    /// Adds an image centered below the current position, at most 80% of the page width and 40%
+    /// of the page height, followed by a blank line.
+    pub fn image(&mut self, path: &Path) -> Result<(), PdfError> {
+        self.manager
+            .add_image(path, 0.8, 0.4, &mut self.y_fraction)?;
+        self.y_fraction -= self.manager.line_height_fraction();
+        Ok(())
+    }
+}
+
 /// Converts a number to a roman numeral.
 fn to_roman_numeral(num: u32) -> String {
     let symbols = [
@@ -992,3 +3255,57 @@ fn to_roman_numeral(num: u32) -> String {
 
     result
 }
+
+/// Counts the analysis section pages of a dataset drawn by `create_analysis_pages`.
+fn n_analysis_sections(data_info: &DataInfo) -> usize {
+    // The optional analyses that failed get a page in place of their results.
+    let n_failed_optional = [
+        RecoverableAnalysis::Robust,
+        RecoverableAnalysis::ForeignValues,
+        RecoverableAnalysis::Drift,
+        RecoverableAnalysis::Freshness,
+        RecoverableAnalysis::Sessions,
+        RecoverableAnalysis::Target,
+        RecoverableAnalysis::TimeSeries,
+    ]
+    .into_iter()
+    .filter(|analysis| data_info.failure(*analysis).is_some())
+    .count();
+    14 + n_failed_optional
+        + usize::from(!data_info.skipped_analyses().is_empty())
+        + usize::from(data_info.null_if_analysis.is_some())
+        + usize::from(data_info.foreign_value_analysis.is_some())
+        + usize::from(has_temporal_page(data_info))
+        + usize::from(data_info.partition_analysis.is_some())
+        + usize::from(data_info.drift_analysis.is_some())
+        + usize::from(data_info.freshness_analysis.is_some())
+        + usize::from(data_info.session_analysis.is_some())
+        + usize::from(data_info.pca_analysis.is_some())
+        + usize::from(data_info.target_analysis.is_some())
+        + usize::from(data_info.time_series_analysis.is_some())
+        + usize::from(has_distribution_plots(&data_info.visualizations))
+}
+
+/// Checks whether the temporal columns page is drawn: if there are temporal columns, or in place
+/// of the results if the analysis failed.
+fn has_temporal_page(data_info: &DataInfo) -> bool {
+    !data_info.temporal_analysis.columns.is_empty()
+        || data_info.failure(RecoverableAnalysis::Temporal).is_some()
+}
+
+/// Checks whether the problem rows appendix is drawn: if rows were sampled, or in place of the
+/// samples if the analysis failed.
+fn has_problem_rows_page(data_info: &DataInfo) -> bool {
+    !data_info.problem_row_analysis.samples.is_empty()
+        || data_info
+            .failure(RecoverableAnalysis::ProblemRows)
+            .is_some()
+}
+
+/// Checks whether any histogram grids were generated for the distributions section.
+fn has_distribution_plots(visualizations: &Option<VisualizationManager>) -> bool {
+    visualizations
+        .as_ref()
+        .and_then(|viz_manager| viz_manager.visualizations.get(&PlotGroup::Distributions))
+        .is_some_and(|plots| !plots.is_empty())
+}
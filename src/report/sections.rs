@@ -0,0 +1,4223 @@
+//! # Sections Module
+//!
+//! Handles the renderer independent model of the report. Analyses are converted into
+//! `ReportSection`s, each an ordered list of `ReportBlock`s (headings, paragraphs, notes, lists,
+//! tables, sparkline tables, key-value grids, and images) along with the glossary terms the section references.
+//!
+//! The PDF report draws the sections with the `PageManager`, and this module renders them as a
+//! standalone HTML page or as JSON. An analysis that can be converted into a `ReportSection` gets
+//! all three outputs without any rendering code of its own.
+//!
+//! In the HTML page, the column names in the first cell of each table row link to the drill-down
+//! details of the column (its statistics and lazy-loaded plots), and a search box filters the
+//! column details and table rows by column name.
+//!
+//! Every section and plot has a stable ID derived from its title or file name (e.g. `duplicates`
+//! or `plot-age-outliers`), listed in the JSON sections and used as the HTML anchors and the PDF
+//! named destinations, so other tools can link into a report.
+//!
+//! Exported JSON sections can be loaded back to render the report again (as a PDF or HTML page)
+//! without re-reading the raw data, e.g. with a different glossary or on a machine without access
+//! to the data.
+
+use super::diff::ReportDiff;
+use super::format::{column_unit, Locale, UNIT_STATISTICS};
+use super::narrative::Narrative;
+use super::summary::{ReportSummary, MISSING_ALERT_THRESHOLD};
+use super::tagging::image_alt_text;
+use crate::data::associations::AssociationAnalysis;
+use crate::data::categories::CategoryMergeAnalysis;
+use crate::data::collinearity::CollinearityAnalysis;
+use crate::data::correlation::CorrelationAnalysis;
+use crate::data::descriptive::{DescriptiveAnalysis, Histogram};
+use crate::data::dictionary::{ColumnAnalysis, ColumnDefinition, DataDictionary};
+use crate::data::drift::{DriftAnalysis, DriftLevel, MODERATE_DRIFT};
+use crate::data::duplicates::DuplicateAnalysis;
+use crate::data::freshness::FreshnessAnalysis;
+use crate::data::missing_values::MissingValueAnalysis;
+use crate::data::null_if::NullIfAnalysis;
+use crate::data::outliers::{OutlierAnalysis, IQR_MULTIPLIER, Z_SCORE_THRESHOLD};
+use crate::data::partitions::PartitionAnalysis;
+#[cfg(feature = "pca")]
+use crate::data::pca::{PcaAnalysis, EXPLAINED_VARIANCE_TARGET};
+use crate::data::problem_rows::{ProblemRowAnalysis, MAX_VALUE_LENGTH};
+use crate::data::provenance::{format_file_size, Provenance};
+use crate::data::recovery::{AnalysisFailure, RecoverableAnalysis};
+use crate::data::references::ForeignValueAnalysis;
+use crate::data::robust::RobustAnalysis;
+use crate::data::row_order::RowOrderAnalysis;
+use crate::data::sessions::SessionAnalysis;
+use crate::data::tables::TableSet;
+use crate::data::target::{
+    GroupStats, TargetAnalysis, TargetTest, MAX_CLASSES, MAX_GROUPED_CLASSES, SIGNIFICANCE_LEVEL,
+};
+use crate::data::temporal::{TemporalAnalysis, MONTHS, WEEKDAYS};
+use crate::data::text::{pattern_regex, TextAnalysis, MAX_EXACT_DIGITS};
+use crate::data::throughput::{format_stage_duration, Throughput};
+#[cfg(feature = "time-series")]
+use crate::data::time_series::TimeSeriesAnalysis;
+use crate::data::time_series::{format_duration, format_timestamp};
+use crate::data::timeout::{AnalysisTimeout, BudgetedAnalysis};
+use crate::data::transformations::TransformationAnalysis;
+use crate::data::visualizations::{PlotGroup, VisualizationManager};
+#[cfg(feature = "pca")]
+use crate::data::viz_lib::pca_viz::SCREE_PLOT_TITLE;
+#[cfg(feature = "time-series")]
+use crate::data::viz_lib::time_series_viz::{
+    autocorrelation_plot_title, decomposition_plot_title, gap_timeline_plot_title,
+    rolling_plot_title,
+};
+use crate::data::viz_lib::{
+    association_viz::crosstab_plot_title,
+    category_viz::category_merge_plot_title,
+    correlation_viz::{PEARSON_HEATMAP_TITLE, SPEARMAN_HEATMAP_TITLE},
+    distribution_viz::distribution_grid_title,
+    outlier_viz::outlier_plot_title,
+    session_viz::{events_per_entity_plot_title, inter_event_times_plot_title},
+    target_viz::{class_distribution_plot_title, mutual_information_plot_title},
+    transformation_viz::transformation_plot_title,
+    GRID_COLUMNS, GRID_ROWS,
+};
+use crate::prelude::DataInfo;
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fmt::{self, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use thiserror::Error;
+
+/// The maximum number of feature pairs in the top correlations table.
+const MAX_CORRELATION_PAIRS: usize = 20;
+/// Maximum number of gaps and duplicated timestamps listed in the time series section.
+pub const MAX_LISTED_TIMESTAMPS: usize = 20;
+/// The classical numerical statistics left out when only robust statistics are shown.
+pub const CLASSICAL_STATISTICS: [&str; 5] = [
+    "mean",
+    "std_dev",
+    "skewness_bias",
+    "skewness_raw",
+    "kurtosis",
+];
+/// Maximum number of characters of an example value in the data types overview and the compact
+/// tables of wide mode.
+pub const MAX_EXAMPLE_LENGTH: usize = 20;
+
+/// The script of the HTML page that hides the column details and table rows whose column name
+/// doesn't contain the text of the column filter box.
+const COLUMN_FILTER_SCRIPT: &str = r#"
+document.getElementById("column-filter").addEventListener("input", function () {
+  var query = this.value.toLowerCase();
+  document.querySelectorAll("[data-column]").forEach(function (element) {
+    element.hidden = !element.dataset.column.toLowerCase().includes(query);
+  });
+});
+"#;
+
+/// The error types for the sections module.
+#[derive(Error, Debug)]
+pub enum SectionError {
+    /// Occurs when an I/O operation fails.
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// Occurs when the sections can't be serialized to or deserialized from JSON.
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+
+    /// Occurs when the sections are rendered to an unsupported report format.
+    #[error("Unsupported report format: {0}, expected pdf or html")]
+    UnsupportedFormat(String),
+}
+
+/// The formats the report sections can be exported in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SectionFormat {
+    /// A standalone HTML page.
+    Html,
+    /// A JSON array of the sections.
+    Json,
+}
+
+impl SectionFormat {
+    /// The file extension for the format.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            SectionFormat::Html => "html",
+            SectionFormat::Json => "json",
+        }
+    }
+}
+
+impl FromStr for SectionFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "html" => Ok(SectionFormat::Html),
+            "json" => Ok(SectionFormat::Json),
+            other => Err(format!(
+                "unsupported export format '{}', expected one of: html, json",
+                other
+            )),
+        }
+    }
+}
+
+impl fmt::Display for SectionFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.extension())
+    }
+}
+
+/// A renderer independent block of report content.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ReportBlock {
+    /// A sub-header within the section.
+    Heading { text: String },
+    /// A paragraph of text.
+    Paragraph { text: String },
+    /// A short emphasized note, e.g. explaining how to read the content below it.
+    Note { text: String },
+    /// A bulleted list.
+    List { items: Vec<String> },
+    /// A table with a value per column in each row. The columns of values taken from the data
+    /// (e.g. categories or example rows), which are never localized, and the highlighted rows are
+    /// flagged by their index.
+    Table {
+        headers: Vec<String>,
+        rows: Vec<Vec<String>>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        data_columns: Vec<usize>,
+        highlighted_rows: Vec<usize>,
+    },
+    /// A titled grid of names and their formatted values, with the names of the values taken from
+    /// the data (e.g. the mode), which are never localized, and the data dictionary entry of the
+    /// column it describes (if any).
+    KeyValues {
+        title: String,
+        values: IndexMap<String, String>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        data_values: Vec<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        definition: Option<ColumnDefinition>,
+    },
+    /// A table with a value per column in each row, followed by a distribution sparkline drawn
+    /// from the counts of the row. The last header is the header of the sparklines.
+    SparklineTable {
+        headers: Vec<String>,
+        rows: Vec<Vec<String>>,
+        counts: Vec<Vec<u64>>,
+    },
+    /// An image, e.g. a generated plot.
+    Image(Plot),
+    /// The drill-down details of a single column: its statistics, plots, and the bin edges and
+    /// counts of numerical columns.
+    ColumnDetail {
+        column: String,
+        values: IndexMap<String, String>,
+        images: Vec<Plot>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        histogram: Option<Histogram>,
+    },
+}
+
+/// A generated plot of the report.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(from = "PlotEntry")]
+pub struct Plot {
+    /// The stable ID of the plot, see `plot_id`.
+    pub id: String,
+    /// The path to the plot image.
+    pub path: PathBuf,
+}
+
+impl Plot {
+    /// Constructor for the Plot struct, with the ID derived from the file name.
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            id: plot_id(&path),
+            path,
+        }
+    }
+}
+
+/// A serialized plot. Sections exported before plots had IDs only list their paths.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum PlotEntry {
+    Plot {
+        #[serde(default)]
+        id: String,
+        path: PathBuf,
+    },
+    Path(PathBuf),
+}
+
+impl From<PlotEntry> for Plot {
+    fn from(entry: PlotEntry) -> Self {
+        match entry {
+            PlotEntry::Plot { id, path } if !id.is_empty() => Plot { id, path },
+            PlotEntry::Plot { path, .. } | PlotEntry::Path(path) => Plot::new(path),
+        }
+    }
+}
+
+/// A renderer independent report section.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReportSection {
+    /// The stable ID of the section, derived from the title.
+    #[serde(default)]
+    pub id: String,
+    /// The section title.
+    pub title: String,
+    /// The content blocks, in order.
+    pub blocks: Vec<ReportBlock>,
+    /// The glossary terms the section references.
+    pub terms: Vec<String>,
+}
+
+impl ReportSection {
+    /// Constructor for the ReportSection struct.
+    ///
+    /// ### Parameters
+    ///
+    /// - `title`: The section title.
+    ///
+    /// ### Returns
+    ///
+    /// - `ReportSection`: The new, empty section.
+    pub fn new(title: &str) -> Self {
+        Self {
+            id: stable_id(title),
+            title: title.to_owned(),
+            blocks: Vec::new(),
+            terms: Vec::new(),
+        }
+    }
+
+    /// Creates the alerts section from the human readable alerts of the run summary.
+    pub fn alerts(alerts: &[String]) -> Self {
+        let mut section = Self::new("Alerts");
+        if alerts.is_empty() {
+            section.push(ReportBlock::Paragraph {
+                text: "No data quality issues found.".to_owned(),
+            });
+        } else {
+            section.push(ReportBlock::List {
+                items: alerts.to_vec(),
+            });
+        }
+        section
+    }
+
+    /// Creates the columns section: an overview table with the data type and quality of each
+    /// column (columns above the missing value alert threshold are highlighted), followed by the
+    /// drill-down details of each column with its statistics and the plots generated for it.
+    pub fn columns(data_info: &DataInfo) -> Self {
+        let mut section = Self::new("Columns");
+        let columns = ReportSummary::new(data_info, None).columns;
+        section.push(ReportBlock::Table {
+            headers: vec![
+                "Column".to_owned(),
+                "Data Type".to_owned(),
+                "Missing".to_owned(),
+                "Distinct".to_owned(),
+                "Quality Score".to_owned(),
+            ],
+            rows: columns
+                .iter()
+                .map(|column| {
+                    vec![
+                        column.name.clone(),
+                        column.data_type.clone(),
+                        format!("{:.2}%", column.missing_percentage),
+                        column
+                            .n_unique
+                            .map_or_else(|| "-".to_owned(), |n_unique| n_unique.to_string()),
+                        format!("{:.2}", column.quality_score),
+                    ]
+                })
+                .collect(),
+            data_columns: Vec::new(),
+            highlighted_rows: columns
+                .iter()
+                .enumerate()
+                .filter(|(_, column)| column.missing_percentage > MISSING_ALERT_THRESHOLD)
+                .map(|(i, _)| i)
+                .collect(),
+        });
+
+        let descriptive_analysis = &data_info.descriptive_analysis;
+        let numeric_stats: IndexMap<String, IndexMap<String, String>> = descriptive_analysis
+            .column_stats
+            .get_analysis_values(
+                &descriptive_analysis.feature_indices,
+                &descriptive_analysis.column_map,
+            )
+            .unwrap_or_default()
+            .into_iter()
+            .map(|mut stats| {
+                let name = stats.shift_remove("column_name").unwrap_or_default();
+                (name, stats)
+            })
+            .collect();
+        for column in &columns {
+            let mut values = IndexMap::from([
+                ("data_type".to_owned(), column.data_type.clone()),
+                (
+                    "missing".to_owned(),
+                    format!(
+                        "{} ({:.2}%)",
+                        column.missing_count, column.missing_percentage
+                    ),
+                ),
+            ]);
+            if let Some(n_unique) = column.n_unique {
+                values.insert("n_unique".to_owned(), n_unique.to_string());
+            }
+            if let Some(stats) = numeric_stats.get(&column.name) {
+                for statistic in ["min", "max", "mean", "median", "std_dev"] {
+                    if let Some(value) = stats.get(statistic) {
+                        values.insert(statistic.to_owned(), value.clone());
+                    }
+                }
+            }
+            if let Some(normality) = descriptive_analysis.normality.get(&column.name) {
+                section.reference_term("distribution");
+                values.insert("distribution".to_owned(), normality.label.to_string());
+                values.insert("normality_test".to_owned(), normality.format_test());
+            }
+            if let Some(skipped) = data_info.skipped_analyses().get(column.name.as_str()) {
+                values.insert("skipped_analyses".to_owned(), format_analyses(skipped));
+            }
+            let histogram = descriptive_analysis.histograms.get(&column.name).cloned();
+            if let Some(histogram) = &histogram {
+                section.reference_term("histogram_bins");
+                values.insert("histogram_bins".to_owned(), histogram.format_bins());
+            }
+            section.push(ReportBlock::ColumnDetail {
+                column: column.name.clone(),
+                values,
+                images: column_plots(data_info, &column.name)
+                    .into_iter()
+                    .map(Plot::new)
+                    .collect(),
+                histogram,
+            });
+        }
+        section
+    }
+
+    /// Creates the skipped analyses section, listing the analyses each column opts out of in the
+    /// data dictionary, so the columns missing from those sections aren't mistaken for clean
+    /// results.
+    pub fn skipped_analyses(data_info: &DataInfo) -> Self {
+        let mut section = Self::new("Skipped Analyses");
+        section.push(ReportBlock::Note {
+            text: "These columns opt out of the listed analyses (in the data dictionary or with \
+                   --skip) and are left out of their sections and plots."
+                .to_owned(),
+        });
+        section.push(ReportBlock::Table {
+            headers: vec!["Column".to_owned(), "Skipped analyses".to_owned()],
+            rows: data_info
+                .skipped_analyses()
+                .into_iter()
+                .map(|(column, analyses)| vec![column.to_owned(), format_analyses(analyses)])
+                .collect(),
+            data_columns: Vec::new(),
+            highlighted_rows: Vec::new(),
+        });
+        section
+    }
+
+    /// Creates the section of an analysis skipped for running over its time budget, in place of
+    /// its results.
+    ///
+    /// ### Parameters
+    ///
+    /// - `analysis`: The skipped analysis.
+    /// - `timeout`: The time budget of the analysis.
+    ///
+    /// ### Returns
+    ///
+    /// - `ReportSection`: The section of the analysis, with the timeout note.
+    pub fn timed_out(analysis: BudgetedAnalysis, timeout: AnalysisTimeout) -> Self {
+        let mut section = Self::new(&analysis.to_string());
+        section.push(ReportBlock::Note {
+            text: format!(
+                "Skipped (timeout): the analysis ran over its {} time budget. Raise or remove \
+                 --analysis-timeout to include it.",
+                timeout
+            ),
+        });
+        section
+    }
+
+    /// Creates the section of an analysis that failed, in place of its results.
+    ///
+    /// ### Parameters
+    ///
+    /// - `failure`: The failed analysis and its error.
+    ///
+    /// ### Returns
+    ///
+    /// - `ReportSection`: The section of the analysis, with the analysis unavailable note.
+    pub fn unavailable(failure: &AnalysisFailure) -> Self {
+        let mut section = Self::new(&failure.analysis.to_string());
+        section.push(ReportBlock::Note {
+            text: format!(
+                "Analysis unavailable: the analysis failed ({}), so its results are left out of \
+                 the report.",
+                failure.error
+            ),
+        });
+        section
+    }
+
+    /// Creates the sessions section from the sessionized event log profile, with the session
+    /// plots (if visualizations were generated).
+    ///
+    /// ### Parameters
+    ///
+    /// - `session_analysis`: The sessionized event log profile.
+    /// - `visualizations`: The optional visualizations for the dataset.
+    ///
+    /// ### Returns
+    ///
+    /// - `ReportSection`: The sessions section.
+    pub fn sessions(
+        session_analysis: &SessionAnalysis,
+        visualizations: &Option<VisualizationManager>,
+    ) -> Self {
+        let mut section = Self::new("Sessions");
+        section.push(ReportBlock::Note {
+            text: format!(
+                "Events of each {} ordered by {}. A new session starts when the time between two \
+                 consecutive events is above {}.",
+                session_analysis.entity_column,
+                session_analysis.timestamp_column,
+                format_duration(session_analysis.gap)
+            ),
+        });
+        if session_analysis.n_events == 0 {
+            section.push(ReportBlock::Paragraph {
+                text: "No events with an entity and a valid timestamp to sessionize.".to_owned(),
+            });
+            return section;
+        }
+        section.reference_term("session");
+        section.reference_term("inter_event_time");
+
+        let mut overview = IndexMap::from([
+            ("events".to_owned(), session_analysis.n_events.to_string()),
+            (
+                "entities".to_owned(),
+                session_analysis.n_entities.to_string(),
+            ),
+            (
+                "sessions".to_owned(),
+                session_analysis.n_sessions.to_string(),
+            ),
+        ]);
+        if let Some(share) = session_analysis.top_entity_share() {
+            overview.insert(
+                "top_1%_entity_share".to_owned(),
+                format!("{:.2}%", share * 100.0),
+            );
+        }
+        section.push(ReportBlock::KeyValues {
+            title: "Sessions".to_owned(),
+            values: overview,
+            data_values: Vec::new(),
+            definition: None,
+        });
+
+        let distributions = [
+            (
+                "Events per entity",
+                session_analysis.events_per_entity(),
+                false,
+            ),
+            (
+                "Sessions per entity",
+                session_analysis.sessions_per_entity.clone(),
+                false,
+            ),
+            (
+                "Events per session",
+                session_analysis.events_per_session.clone(),
+                false,
+            ),
+            (
+                "Session duration",
+                session_analysis.session_durations.clone(),
+                true,
+            ),
+            (
+                "Inter-event time",
+                session_analysis.inter_event_times.clone(),
+                true,
+            ),
+        ];
+        let quantiles: Vec<&String> = distributions
+            .iter()
+            .flat_map(|(_, values, _)| values.keys())
+            .fold(Vec::new(), |mut quantiles, quantile| {
+                if !quantiles.contains(&quantile) {
+                    quantiles.push(quantile);
+                }
+                quantiles
+            });
+        section.push(ReportBlock::Heading {
+            text: "Distributions".to_owned(),
+        });
+        section.push(ReportBlock::Table {
+            headers: ["Distribution"]
+                .into_iter()
+                .map(str::to_owned)
+                .chain(quantiles.iter().map(|quantile| quantile.to_string()))
+                .collect(),
+            rows: distributions
+                .iter()
+                .map(|(name, values, is_duration)| {
+                    [name.to_string()]
+                        .into_iter()
+                        .chain(
+                            quantiles
+                                .iter()
+                                .map(|quantile| match values.get(*quantile) {
+                                    Some(value) if *is_duration => format_duration(*value),
+                                    Some(value) => value.to_string(),
+                                    None => "-".to_owned(),
+                                }),
+                        )
+                        .collect()
+                })
+                .collect(),
+            data_columns: Vec::new(),
+            highlighted_rows: Vec::new(),
+        });
+
+        let plots = visualizations
+            .as_ref()
+            .and_then(|visualizations| visualizations.visualizations.get(&PlotGroup::Sessions))
+            .into_iter()
+            .flat_map(|plots| {
+                [
+                    events_per_entity_plot_title(&session_analysis.entity_column),
+                    inter_event_times_plot_title(),
+                ]
+                .into_iter()
+                .filter_map(|title| plots.get(&title).cloned())
+                .collect::<Vec<_>>()
+            });
+        for path in plots {
+            section.push(ReportBlock::Image(Plot::new(path)));
+        }
+        section
+    }
+
+    /// Creates the target analysis section for supervised mode, with the class balance of the
+    /// target, the mutual information ranking and association tests of the features, and their
+    /// summary statistics per class, along with the target plots (if visualizations were
+    /// generated).
+    ///
+    /// ### Parameters
+    ///
+    /// - `target_analysis`: The target column analysis.
+    /// - `visualizations`: The optional visualizations for the dataset.
+    ///
+    /// ### Returns
+    ///
+    /// - `ReportSection`: The target analysis section.
+    pub fn target(
+        target_analysis: &TargetAnalysis,
+        visualizations: &Option<VisualizationManager>,
+    ) -> Self {
+        let mut section = Self::new("Target Analysis");
+        let target = &target_analysis.column;
+        let target_plots = visualizations
+            .as_ref()
+            .and_then(|visualizations| visualizations.visualizations.get(&PlotGroup::Target));
+        let target_plot = |title: String| {
+            target_plots
+                .and_then(|plots| plots.get(&title))
+                .map(|path| ReportBlock::Image(Plot::new(path.clone())))
+        };
+
+        match &target_analysis.class_distribution {
+            Some(distribution) => {
+                section.push(ReportBlock::KeyValues {
+                    title: target.to_owned(),
+                    values: IndexMap::from([
+                        (
+                            "n_classes".to_owned(),
+                            distribution.counts.len().to_string(),
+                        ),
+                        (
+                            "imbalance_ratio".to_owned(),
+                            format!("{:.2}:1", distribution.imbalance_ratio),
+                        ),
+                        (
+                            "minority_class".to_owned(),
+                            distribution.minority_class.clone(),
+                        ),
+                        (
+                            "minority_percentage".to_owned(),
+                            format!("{:.2}%", distribution.minority_percentage),
+                        ),
+                    ]),
+                    data_values: Vec::new(),
+                    definition: None,
+                });
+                if let Some(warning) = distribution.warning() {
+                    section.push(ReportBlock::Note { text: warning });
+                }
+                let total: u64 = distribution.counts.values().sum();
+                section.push(ReportBlock::Table {
+                    headers: ["Class", "Count", "Percentage"]
+                        .into_iter()
+                        .map(str::to_owned)
+                        .collect(),
+                    rows: distribution
+                        .counts
+                        .iter()
+                        .map(|(class, count)| {
+                            vec![
+                                class.clone(),
+                                count.to_string(),
+                                format!("{:.2}%", *count as f64 / total as f64 * 100.0),
+                            ]
+                        })
+                        .collect(),
+                    data_columns: Vec::new(),
+                    highlighted_rows: Vec::new(),
+                });
+                section
+                    .blocks
+                    .extend(target_plot(class_distribution_plot_title(target)));
+            }
+            None => section.push(ReportBlock::Note {
+                text: format!(
+                    "Target '{}' has no labeled rows or more than {} distinct values, so it is \
+                     treated as continuous and no class distribution is shown.",
+                    target, MAX_CLASSES
+                ),
+            }),
+        }
+
+        if !target_analysis.mutual_information.is_empty() {
+            section.reference_term("mutual_information");
+            section.push(ReportBlock::Heading {
+                text: "Mutual Information".to_owned(),
+            });
+            section.push(ReportBlock::Table {
+                headers: ["Rank", "Feature", "Mutual Information (nats)"]
+                    .into_iter()
+                    .map(str::to_owned)
+                    .collect(),
+                rows: target_analysis
+                    .mutual_information
+                    .iter()
+                    .enumerate()
+                    .map(|(rank, (feature, score))| {
+                        vec![
+                            (rank + 1).to_string(),
+                            feature.clone(),
+                            format!("{:.4}", score),
+                        ]
+                    })
+                    .collect(),
+                data_columns: Vec::new(),
+                highlighted_rows: Vec::new(),
+            });
+            section
+                .blocks
+                .extend(target_plot(mutual_information_plot_title(target)));
+        }
+
+        if !target_analysis.associations.is_empty() {
+            section.reference_term("association_strength");
+            for association in target_analysis.associations.values() {
+                let term = match association.test {
+                    TargetTest::Pearson => "pearson_correlation",
+                    TargetTest::Anova => "anova",
+                    TargetTest::ChiSquared => "chi_squared_test",
+                };
+                section.reference_term(term);
+            }
+            section.push(ReportBlock::Heading {
+                text: "Feature Associations".to_owned(),
+            });
+            section.push(ReportBlock::Note {
+                text: format!(
+                    "Each feature is tested against '{}' over the rows where both are present. \
+                     Associations with a p-value below {} are highlighted.",
+                    target, SIGNIFICANCE_LEVEL
+                ),
+            });
+            section.push(ReportBlock::Table {
+                headers: ["Feature", "Test", "Statistic", "p-value", "Strength"]
+                    .into_iter()
+                    .map(str::to_owned)
+                    .collect(),
+                rows: target_analysis
+                    .associations
+                    .iter()
+                    .map(|(feature, association)| {
+                        vec![
+                            feature.clone(),
+                            association.test.to_string(),
+                            format!("{:.4}", association.statistic),
+                            association.format_p_value(),
+                            format!("{:.4}", association.strength),
+                        ]
+                    })
+                    .collect(),
+                data_columns: Vec::new(),
+                highlighted_rows: target_analysis
+                    .associations
+                    .values()
+                    .enumerate()
+                    .filter(|(_, association)| association.is_significant())
+                    .map(|(i, _)| i)
+                    .collect(),
+            });
+        }
+
+        let classes: Vec<&String> = target_analysis
+            .grouped_stats
+            .values()
+            .next()
+            .map(|stats| stats.keys().collect())
+            .unwrap_or_default();
+        if !classes.is_empty() {
+            section.push(ReportBlock::Heading {
+                text: "Statistics by Class".to_owned(),
+            });
+            let mut note = "Mean ± standard deviation of each numerical feature per class of the \
+                            target."
+                .to_owned();
+            if target_analysis
+                .class_distribution
+                .as_ref()
+                .is_some_and(|distribution| distribution.counts.len() > MAX_GROUPED_CLASSES)
+            {
+                write!(
+                    note,
+                    " Only the {} most frequent classes are shown.",
+                    MAX_GROUPED_CLASSES
+                )
+                .unwrap();
+            }
+            section.push(ReportBlock::Note { text: note });
+            section.push(ReportBlock::Table {
+                headers: [String::from("Feature")]
+                    .into_iter()
+                    .chain(classes.iter().map(|class| class.to_string()))
+                    .collect(),
+                rows: target_analysis
+                    .grouped_stats
+                    .iter()
+                    .map(|(feature, stats)| {
+                        [feature.clone()]
+                            .into_iter()
+                            .chain(stats.values().map(GroupStats::format))
+                            .collect()
+                    })
+                    .collect(),
+                data_columns: Vec::new(),
+                highlighted_rows: Vec::new(),
+            });
+        }
+        section
+    }
+
+    /// Creates the uniqueness section from the descriptive analysis, with the distinct value
+    /// count and uniqueness ratio of each non-nested column. Constant columns are highlighted and
+    /// likely keys are marked.
+    ///
+    /// ### Parameters
+    ///
+    /// - `descriptive_analysis`: The descriptive analysis for the dataset.
+    ///
+    /// ### Returns
+    ///
+    /// - `ReportSection`: The uniqueness section.
+    pub fn uniqueness(descriptive_analysis: &DescriptiveAnalysis) -> Self {
+        let mut section = Self::new("Uniqueness");
+        if descriptive_analysis.distinct_stats.is_empty() {
+            section.push(ReportBlock::Paragraph {
+                text: "No columns to count the distinct values of.".to_owned(),
+            });
+            return section;
+        }
+        section.reference_term("n_unique");
+        section.reference_term("uniqueness_ratio");
+        let constant_columns = descriptive_analysis.constant_columns();
+        let likely_keys = descriptive_analysis.likely_keys();
+        if !constant_columns.is_empty() {
+            section.reference_term("constant_column");
+        }
+        if !likely_keys.is_empty() {
+            section.reference_term("likely_key");
+        }
+        section.push(ReportBlock::Paragraph {
+            text: format!(
+                "{} constant column(s) and {} likely key(s) out of {} columns.",
+                constant_columns.len(),
+                likely_keys.len(),
+                descriptive_analysis.distinct_stats.len()
+            ),
+        });
+        section.push(ReportBlock::Table {
+            headers: vec![
+                "Column".to_owned(),
+                "Distinct".to_owned(),
+                "Non-Null".to_owned(),
+                "Uniqueness".to_owned(),
+                "Flag".to_owned(),
+            ],
+            rows: descriptive_analysis
+                .distinct_stats
+                .iter()
+                .map(|(name, stats)| {
+                    let flag = if stats.is_constant() {
+                        "constant"
+                    } else if stats.is_likely_key() {
+                        "likely key"
+                    } else {
+                        ""
+                    };
+                    vec![
+                        name.clone(),
+                        stats.n_unique.to_string(),
+                        stats.count.to_string(),
+                        format!("{:.2}%", stats.uniqueness_ratio() * 100.0),
+                        flag.to_owned(),
+                    ]
+                })
+                .collect(),
+            data_columns: Vec::new(),
+            highlighted_rows: descriptive_analysis
+                .distinct_stats
+                .values()
+                .enumerate()
+                .filter(|(_, stats)| stats.is_constant())
+                .map(|(i, _)| i)
+                .collect(),
+        });
+        section
+    }
+
+    /// Creates the correlations section from the correlation analysis, with the top correlated
+    /// pairs and the correlation heatmaps (if visualizations were generated).
+    ///
+    /// ### Parameters
+    ///
+    /// - `correlation_analysis`: The correlation analysis for the dataset.
+    /// - `visualizations`: The optional visualizations for the dataset.
+    ///
+    /// ### Returns
+    ///
+    /// - `ReportSection`: The correlations section.
+    pub fn correlations(
+        correlation_analysis: &CorrelationAnalysis,
+        visualizations: &Option<VisualizationManager>,
+    ) -> Self {
+        let mut section = Self::new("Correlations");
+        if correlation_analysis.features.len() < 2 {
+            section.push(ReportBlock::Paragraph {
+                text: "Not enough non-constant numerical features to correlate.".to_owned(),
+            });
+            return section;
+        }
+        section.reference_term("pearson_correlation");
+        section.reference_term("spearman_correlation");
+
+        let mut note = format!(
+            "Pairs with an absolute Pearson or Spearman correlation of at least {} are \
+             highlighted. Each pair is correlated over the rows where both features are present.",
+            correlation_analysis.threshold
+        );
+        if correlation_analysis.n_skipped > 0 {
+            write!(
+                note,
+                " Only the first {} numerical features are correlated, {} were left out.",
+                correlation_analysis.features.len(),
+                correlation_analysis.n_skipped
+            )
+            .unwrap();
+        }
+        section.push(ReportBlock::Note { text: note });
+
+        let format_correlation = |correlation: f64| {
+            if correlation.is_finite() {
+                format!("{:.4}", correlation)
+            } else {
+                "-".to_owned()
+            }
+        };
+        let pairs: Vec<_> = correlation_analysis
+            .pairs()
+            .into_iter()
+            .take(MAX_CORRELATION_PAIRS)
+            .collect();
+        section.push(ReportBlock::Heading {
+            text: "Top Correlations".to_owned(),
+        });
+        section.push(ReportBlock::Table {
+            headers: ["Feature", "Feature", "Pearson", "Spearman"]
+                .into_iter()
+                .map(str::to_owned)
+                .collect(),
+            rows: pairs
+                .iter()
+                .map(|pair| {
+                    vec![
+                        pair.first.to_owned(),
+                        pair.second.to_owned(),
+                        format_correlation(pair.pearson),
+                        format_correlation(pair.spearman),
+                    ]
+                })
+                .collect(),
+            data_columns: Vec::new(),
+            highlighted_rows: pairs
+                .iter()
+                .enumerate()
+                .filter(|(_, pair)| pair.strength() >= correlation_analysis.threshold)
+                .map(|(i, _)| i)
+                .collect(),
+        });
+
+        let plots = visualizations
+            .as_ref()
+            .and_then(|visualizations| visualizations.visualizations.get(&PlotGroup::Correlations))
+            .into_iter()
+            .flat_map(|plots| {
+                [PEARSON_HEATMAP_TITLE, SPEARMAN_HEATMAP_TITLE]
+                    .into_iter()
+                    .filter_map(|title| plots.get(title).cloned())
+                    .collect::<Vec<_>>()
+            });
+        for path in plots {
+            section.push(ReportBlock::Image(Plot::new(path)));
+        }
+        section
+    }
+
+    /// Creates the category merges section from the merge suggestions, with the before and after
+    /// level charts of each feature (if visualizations were generated).
+    ///
+    /// ### Parameters
+    ///
+    /// - `category_analysis`: The category merge suggestions for the dataset.
+    /// - `visualizations`: The optional visualizations for the dataset.
+    ///
+    /// ### Returns
+    ///
+    /// - `ReportSection`: The category merges section.
+    pub fn category_merges(
+        category_analysis: &CategoryMergeAnalysis,
+        visualizations: &Option<VisualizationManager>,
+    ) -> Self {
+        let mut section = Self::new("Category Merges");
+        if category_analysis.suggestions.is_empty() {
+            section.push(ReportBlock::Paragraph {
+                text: "No categorical features with a long tail of rare levels.".to_owned(),
+            });
+            return section;
+        }
+        section.reference_term("rare_level");
+
+        section.push(ReportBlock::Note {
+            text: format!(
+                "Levels holding less than {:.2}% of the non-missing values of a feature are rare. \
+                 Grouping them as \"Other\" shrinks the encoded feature and gives unseen levels a \
+                 home.",
+                category_analysis.threshold * 100.0
+            ),
+        });
+        section.push(ReportBlock::Table {
+            headers: [
+                "Feature",
+                "Levels",
+                "Rare Levels",
+                "Merged Levels",
+                "Reduction",
+                "Other Share",
+            ]
+            .into_iter()
+            .map(str::to_owned)
+            .collect(),
+            rows: category_analysis
+                .suggestions
+                .iter()
+                .map(|(feature, suggestion)| {
+                    vec![
+                        feature.clone(),
+                        suggestion.n_levels().to_string(),
+                        suggestion.n_rare().to_string(),
+                        suggestion.n_merged_levels().to_string(),
+                        format!("{:.2}%", suggestion.cardinality_reduction() * 100.0),
+                        format!("{:.2}%", suggestion.other_share() * 100.0),
+                    ]
+                })
+                .collect(),
+            data_columns: Vec::new(),
+            highlighted_rows: Vec::new(),
+        });
+
+        let plots = visualizations
+            .as_ref()
+            .and_then(|visualizations| {
+                visualizations
+                    .visualizations
+                    .get(&PlotGroup::CategoryMerges)
+            })
+            .into_iter()
+            .flat_map(|plots| {
+                category_analysis
+                    .suggestions
+                    .keys()
+                    .filter_map(|feature| plots.get(&category_merge_plot_title(feature)).cloned())
+                    .collect::<Vec<_>>()
+            });
+        for path in plots {
+            section.push(ReportBlock::Image(Plot::new(path)));
+        }
+        section
+    }
+
+    /// Creates the descriptive analysis section: the shape of the dataset, an overview table with
+    /// a distribution sparkline of each numerical and categorical feature, and the statistics of
+    /// each feature grouped by kind. In wide mode, each feature is summarized in a single table
+    /// row instead of a statistic block.
+    ///
+    /// ### Parameters
+    ///
+    /// - `descriptive_analysis`: The descriptive analysis results.
+    /// - `robust_analysis`: The optional robust statistics, shown alongside or instead of the
+    ///   classical ones.
+    /// - `data_dictionary`: The optional data dictionary, whose entries are shown with the
+    ///   statistics of their features.
+    /// - `wide`: Whether the features are summarized in tables.
+    ///
+    /// ### Returns
+    ///
+    /// - `ReportSection`: The descriptive analysis section.
+    pub fn descriptive(
+        descriptive_analysis: &DescriptiveAnalysis,
+        robust_analysis: Option<&RobustAnalysis>,
+        data_dictionary: Option<&DataDictionary>,
+        wide: bool,
+    ) -> Self {
+        let mut section = Self::new("Descriptive Analysis");
+        section.push(ReportBlock::Paragraph {
+            text: format!(
+                "Shape: {} rows, {} columns",
+                descriptive_analysis.n_rows, descriptive_analysis.n_cols
+            ),
+        });
+        if let Some(robust_analysis) = robust_analysis {
+            let note = if robust_analysis.mode.shows_classical() {
+                "Robust statistics (median and MAD based) are shown alongside the classical ones."
+            } else {
+                "Robust statistics (median and MAD based) are shown instead of the mean, standard \
+                 deviation, skewness, and kurtosis."
+            };
+            section.push(ReportBlock::Note {
+                text: note.to_owned(),
+            });
+        }
+
+        let numeric_features =
+            numeric_feature_stats(descriptive_analysis, robust_analysis, data_dictionary);
+        push_column_overview(&mut section, descriptive_analysis, &numeric_features);
+        if wide {
+            push_compact_summaries(&mut section, descriptive_analysis, &numeric_features);
+            return section;
+        }
+
+        let key_values =
+            |name: &str, values: IndexMap<String, String>, data_values: Vec<String>| {
+                ReportBlock::KeyValues {
+                    title: name.to_owned(),
+                    values,
+                    data_values,
+                    definition: data_dictionary
+                        .and_then(|dictionary| dictionary.get(name))
+                        .cloned(),
+                }
+            };
+        for (feature_name, feature_stats) in numeric_features {
+            section.push(key_values(&feature_name, feature_stats, Vec::new()));
+        }
+
+        if !descriptive_analysis.categorical_stats.is_empty() {
+            section.push(ReportBlock::Heading {
+                text: "Categorical Features".to_owned(),
+            });
+            for (feature_name, categorical_stats) in &descriptive_analysis.categorical_stats {
+                let mut feature_stats = IndexMap::from([
+                    ("count".to_owned(), categorical_stats.count.to_string()),
+                    (
+                        "n_unique".to_owned(),
+                        categorical_stats.n_unique.to_string(),
+                    ),
+                ]);
+                let mut data_values = Vec::new();
+                // Frequencies of ID-like features are summarized in the high-cardinality block.
+                if !descriptive_analysis
+                    .high_cardinality_stats
+                    .contains_key(feature_name)
+                {
+                    if let Some((mode, mode_count)) = categorical_stats.mode() {
+                        feature_stats.insert("mode".to_owned(), mode.to_owned());
+                        data_values.push("mode".to_owned());
+                        feature_stats.insert("mode_frequency".to_owned(), mode_count.to_string());
+                    }
+                    for (rank, (category, frequency)) in
+                        categorical_stats.top_frequencies.iter().enumerate()
+                    {
+                        let name = format!("top_{}", rank + 1);
+                        feature_stats.insert(name.clone(), format!("{} ({})", category, frequency));
+                        data_values.push(name);
+                    }
+                }
+                section.push(key_values(feature_name, feature_stats, data_values));
+            }
+        }
+
+        if !descriptive_analysis.high_cardinality_stats.is_empty() {
+            section.push(ReportBlock::Heading {
+                text: "High-Cardinality Features".to_owned(),
+            });
+            for (feature_name, stats) in &descriptive_analysis.high_cardinality_stats {
+                let mut feature_stats = IndexMap::from([
+                    ("count".to_owned(), stats.count.to_string()),
+                    ("n_unique".to_owned(), stats.n_unique.to_string()),
+                    (
+                        "uniqueness_ratio".to_owned(),
+                        format!("{:.4}", stats.uniqueness_ratio),
+                    ),
+                    ("monotonicity".to_owned(), stats.monotonicity.to_string()),
+                    ("min_length".to_owned(), stats.min_length.to_string()),
+                    (
+                        "mean_length".to_owned(),
+                        format!("{:.2}", stats.mean_length),
+                    ),
+                    ("max_length".to_owned(), stats.max_length.to_string()),
+                ]);
+                for (rank, (length, share)) in stats.top_lengths.iter().enumerate() {
+                    feature_stats.insert(
+                        format!("top_length_{}", rank + 1),
+                        format!("{} ({:.2}%)", length, share),
+                    );
+                }
+                for (rank, (prefix, share)) in stats.top_prefixes.iter().enumerate() {
+                    feature_stats.insert(
+                        format!("top_prefix_{}", rank + 1),
+                        format!("'{}' ({:.2}%)", prefix, share),
+                    );
+                }
+                for (rank, (suffix, share)) in stats.top_suffixes.iter().enumerate() {
+                    feature_stats.insert(
+                        format!("top_suffix_{}", rank + 1),
+                        format!("'{}' ({:.2}%)", suffix, share),
+                    );
+                }
+                section.push(key_values(feature_name, feature_stats, Vec::new()));
+            }
+        }
+
+        if !descriptive_analysis.nested_stats.is_empty() {
+            section.push(ReportBlock::Heading {
+                text: "List and Array Features".to_owned(),
+            });
+            for (feature_name, nested_stats) in &descriptive_analysis.nested_stats {
+                let format_length = |length: Option<u64>| {
+                    length.map_or("null".to_owned(), |length| length.to_string())
+                };
+                let mut feature_stats = IndexMap::from([
+                    ("inner_type".to_owned(), nested_stats.inner_type.to_string()),
+                    ("count".to_owned(), nested_stats.count.to_string()),
+                    (
+                        "min_length".to_owned(),
+                        format_length(nested_stats.min_length),
+                    ),
+                    (
+                        "mean_length".to_owned(),
+                        nested_stats
+                            .mean_length
+                            .map_or("null".to_owned(), |mean| format!("{:.2}", mean)),
+                    ),
+                    (
+                        "max_length".to_owned(),
+                        format_length(nested_stats.max_length),
+                    ),
+                    (
+                        "empty_percentage".to_owned(),
+                        format!("{:.2}%", nested_stats.empty_percentage),
+                    ),
+                    ("n_elements".to_owned(), nested_stats.n_elements.to_string()),
+                ]);
+                if let Some(n_unique) = nested_stats.element_n_unique {
+                    feature_stats.insert("element_n_unique".to_owned(), n_unique.to_string());
+                }
+                for (stat_name, value) in [
+                    ("element_min", nested_stats.element_min),
+                    ("element_mean", nested_stats.element_mean),
+                    ("element_max", nested_stats.element_max),
+                ] {
+                    if let Some(value) = value {
+                        feature_stats.insert(stat_name.to_owned(), value.to_string());
+                    }
+                }
+                section.push(key_values(feature_name, feature_stats, Vec::new()));
+            }
+        }
+
+        if !descriptive_analysis.binary_stats.is_empty() {
+            section.push(ReportBlock::Heading {
+                text: "Binary Features".to_owned(),
+            });
+            for (feature_name, binary_stats) in &descriptive_analysis.binary_stats {
+                let format_bytes =
+                    |bytes: Option<u64>| bytes.map_or("null".to_owned(), |bytes| bytes.to_string());
+                let mut feature_stats = IndexMap::from([
+                    ("count".to_owned(), binary_stats.count.to_string()),
+                    ("min_bytes".to_owned(), format_bytes(binary_stats.min_bytes)),
+                    (
+                        "mean_bytes".to_owned(),
+                        binary_stats
+                            .mean_bytes
+                            .map_or("null".to_owned(), |mean| format!("{:.2}", mean)),
+                    ),
+                    ("max_bytes".to_owned(), format_bytes(binary_stats.max_bytes)),
+                    (
+                        "total_bytes".to_owned(),
+                        binary_stats.total_bytes.to_string(),
+                    ),
+                    (
+                        "empty_count".to_owned(),
+                        binary_stats.empty_count.to_string(),
+                    ),
+                ]);
+                for (rank, (content_type, share)) in binary_stats.content_types.iter().enumerate() {
+                    feature_stats.insert(
+                        format!("content_type_{}", rank + 1),
+                        format!("{} ({:.2}%)", content_type, share),
+                    );
+                }
+                section.push(key_values(feature_name, feature_stats, Vec::new()));
+            }
+        }
+        section
+    }
+
+    /// Creates the distributions section, with the histograms of the numerical features arranged
+    /// as small multiples grids.
+    ///
+    /// ### Parameters
+    ///
+    /// - `visualizations`: The optional visualizations for the dataset.
+    ///
+    /// ### Returns
+    ///
+    /// - `ReportSection`: The distributions section.
+    pub fn distributions(visualizations: &Option<VisualizationManager>) -> Self {
+        let mut section = Self::new("Distributions");
+        section.push(ReportBlock::Paragraph {
+            text: format!(
+                "Histograms of the numerical features, up to {} per grid.",
+                GRID_ROWS * GRID_COLUMNS
+            ),
+        });
+        let plots = plot_group(visualizations, PlotGroup::Distributions);
+        for path in (0..)
+            .map_while(|grid| plots.and_then(|plots| plots.get(&distribution_grid_title(grid))))
+        {
+            section.push(ReportBlock::Image(Plot::new(path.clone())));
+        }
+        section
+    }
+
+    /// Creates the principal component analysis section, with the explained variance and top
+    /// loadings of each component followed by the scree plot (if visualizations were generated).
+    ///
+    /// ### Parameters
+    ///
+    /// - `pca_analysis`: The principal component analysis.
+    /// - `visualizations`: The optional visualizations for the dataset.
+    ///
+    /// ### Returns
+    ///
+    /// - `ReportSection`: The principal component analysis section.
+    #[cfg(feature = "pca")]
+    pub fn pca(pca_analysis: &PcaAnalysis, visualizations: &Option<VisualizationManager>) -> Self {
+        let mut section = Self::new("Principal Component Analysis");
+        section.reference_term("explained_variance_ratio");
+        section.reference_term("loading");
+        let Some(dimensionality) = pca_analysis.intrinsic_dimensionality() else {
+            section.push(ReportBlock::Paragraph {
+                text: "Not enough non-constant numerical features or complete rows to run the \
+                       principal component analysis."
+                    .to_owned(),
+            });
+            return section;
+        };
+
+        section.push(ReportBlock::Paragraph {
+            text: format!(
+                "PCA over {} standardized numerical features. The first {} component(s) explain \
+                 {:.0}% of the total variance.",
+                pca_analysis.features.len(),
+                dimensionality,
+                EXPLAINED_VARIANCE_TARGET * 100.0
+            ),
+        });
+        section.push(ReportBlock::Table {
+            headers: ["Component", "Explained", "Cumulative", "Top Loadings"]
+                .into_iter()
+                .map(str::to_owned)
+                .collect(),
+            rows: pca_analysis
+                .components
+                .iter()
+                .enumerate()
+                .map(|(index, component)| {
+                    vec![
+                        format!("PC{}", index + 1),
+                        format!("{:.2}%", component.explained_variance_ratio * 100.0),
+                        format!("{:.2}%", component.cumulative_variance_ratio * 100.0),
+                        component
+                            .top_loadings
+                            .iter()
+                            .map(|(feature, loading)| format!("{} ({:.2})", feature, loading))
+                            .collect::<Vec<String>>()
+                            .join(", "),
+                    ]
+                })
+                .collect(),
+            data_columns: Vec::new(),
+            highlighted_rows: Vec::new(),
+        });
+        if let Some(path) =
+            plot_group(visualizations, PlotGroup::Pca).and_then(|plots| plots.get(SCREE_PLOT_TITLE))
+        {
+            section.push(ReportBlock::Image(Plot::new(path.clone())));
+        }
+        section
+    }
+
+    /// Creates the transformation suggestions section, with the before and after histograms of
+    /// each skewed feature (if visualizations were generated).
+    ///
+    /// ### Parameters
+    ///
+    /// - `transformation_analysis`: The transformation suggestions for the dataset.
+    /// - `visualizations`: The optional visualizations for the dataset.
+    ///
+    /// ### Returns
+    ///
+    /// - `ReportSection`: The transformation suggestions section.
+    pub fn transformations(
+        transformation_analysis: &TransformationAnalysis,
+        visualizations: &Option<VisualizationManager>,
+    ) -> Self {
+        let mut section = Self::new("Transformation Suggestions");
+        if transformation_analysis.suggestions.is_empty() {
+            section.push(ReportBlock::Paragraph {
+                text: "No skewed numerical features found.".to_owned(),
+            });
+            return section;
+        }
+
+        let plots = plot_group(visualizations, PlotGroup::Transformations);
+        for (feature_name, suggestion) in &transformation_analysis.suggestions {
+            section.push(ReportBlock::KeyValues {
+                title: feature_name.clone(),
+                values: IndexMap::from([
+                    ("transform".to_owned(), suggestion.transform.name()),
+                    ("formula".to_owned(), suggestion.transform.formula()),
+                    ("skewness".to_owned(), format!("{:.4}", suggestion.skewness)),
+                    (
+                        "transformed_skewness".to_owned(),
+                        format!("{:.4}", suggestion.transformed_skewness),
+                    ),
+                    ("kurtosis".to_owned(), format!("{:.4}", suggestion.kurtosis)),
+                    (
+                        "transformed_kurtosis".to_owned(),
+                        format!("{:.4}", suggestion.transformed_kurtosis),
+                    ),
+                ]),
+                data_values: Vec::new(),
+                definition: None,
+            });
+            section.push(ReportBlock::Note {
+                text: suggestion.reason.clone(),
+            });
+            if let Some(path) =
+                plots.and_then(|plots| plots.get(&transformation_plot_title(feature_name)))
+            {
+                section.push(ReportBlock::Image(Plot::new(path.clone())));
+            }
+        }
+        section
+    }
+
+    /// Creates the outlier analysis section, with the fences, outlier counts, and most extreme
+    /// values of each numerical feature followed by its strip plot (if visualizations were
+    /// generated). In wide mode, the outlier counts are summarized in a table with a row per
+    /// feature instead.
+    ///
+    /// ### Parameters
+    ///
+    /// - `outlier_analysis`: The outlier analysis for the dataset.
+    /// - `robust_analysis`: The optional robust statistics, whose MAD fences are shown alongside
+    ///   or instead of the z-score fences.
+    /// - `visualizations`: The optional visualizations for the dataset.
+    /// - `wide`: Whether the features are summarized in a table.
+    ///
+    /// ### Returns
+    ///
+    /// - `ReportSection`: The outlier analysis section.
+    pub fn outliers(
+        outlier_analysis: &OutlierAnalysis,
+        robust_analysis: Option<&RobustAnalysis>,
+        visualizations: &Option<VisualizationManager>,
+        wide: bool,
+    ) -> Self {
+        let mut section = Self::new("Outlier Analysis");
+        if outlier_analysis.fences.is_empty() {
+            section.push(ReportBlock::Paragraph {
+                text: "No numerical features found.".to_owned(),
+            });
+            return section;
+        }
+        section.reference_term("iqr_outliers");
+        section.reference_term("z_outliers");
+        section.reference_term("extreme_values");
+
+        let plots = plot_group(visualizations, PlotGroup::Outliers);
+        let shows_classical =
+            robust_analysis.is_none_or(|robust_analysis| robust_analysis.mode.shows_classical());
+        if wide {
+            section.push(ReportBlock::Note {
+                text: format!(
+                    "Values beyond {} IQRs from the quartiles or {} standard deviations from the \
+                     mean are outliers. Features with IQR outliers are highlighted.",
+                    IQR_MULTIPLIER, Z_SCORE_THRESHOLD
+                ),
+            });
+            section.push(ReportBlock::Table {
+                headers: [
+                    "Feature",
+                    "IQR Outliers",
+                    "Z-score Outliers",
+                    "MAD Outliers",
+                    "Extreme Values",
+                ]
+                .into_iter()
+                .map(str::to_owned)
+                .collect(),
+                rows: outlier_analysis
+                    .fences
+                    .iter()
+                    .map(|(feature_name, fences)| {
+                        vec![
+                            feature_name.clone(),
+                            format!("{} ({:.2}%)", fences.iqr_count, fences.iqr_percentage()),
+                            if shows_classical {
+                                format!("{} ({:.2}%)", fences.z_count, fences.z_percentage())
+                            } else {
+                                "-".to_owned()
+                            },
+                            robust_analysis
+                                .and_then(|robust_analysis| robust_analysis.stats.get(feature_name))
+                                .map_or("-".to_owned(), |stats| stats.mad_count.to_string()),
+                            format_extreme_values(&fences.extreme_values),
+                        ]
+                    })
+                    .collect(),
+                data_columns: Vec::new(),
+                highlighted_rows: outlier_analysis
+                    .fences
+                    .values()
+                    .enumerate()
+                    .filter(|(_, fences)| fences.iqr_count > 0)
+                    .map(|(i, _)| i)
+                    .collect(),
+            });
+            // Only the first features are plotted in wide mode.
+            for feature_name in outlier_analysis.fences.keys() {
+                if let Some(path) =
+                    plots.and_then(|plots| plots.get(&outlier_plot_title(feature_name)))
+                {
+                    section.push(ReportBlock::Image(Plot::new(path.clone())));
+                }
+            }
+            return section;
+        }
+
+        for (feature_name, fences) in &outlier_analysis.fences {
+            let mut values = IndexMap::from([
+                (
+                    "iqr_fences".to_owned(),
+                    format!("[{:.4}, {:.4}]", fences.iqr_lower, fences.iqr_upper),
+                ),
+                (
+                    "iqr_outliers".to_owned(),
+                    format!("{} ({:.2}%)", fences.iqr_count, fences.iqr_percentage()),
+                ),
+            ]);
+            if shows_classical {
+                values.insert(
+                    "z_fences".to_owned(),
+                    format!("[{:.4}, {:.4}]", fences.z_lower, fences.z_upper),
+                );
+                values.insert(
+                    "z_outliers".to_owned(),
+                    format!("{} ({:.2}%)", fences.z_count, fences.z_percentage()),
+                );
+            }
+            if !fences.extreme_values.is_empty() {
+                values.insert(
+                    "extreme_values".to_owned(),
+                    format_extreme_values(&fences.extreme_values),
+                );
+            }
+            if let Some(stats) =
+                robust_analysis.and_then(|robust_analysis| robust_analysis.stats.get(feature_name))
+            {
+                values.insert(
+                    "mad_fences".to_owned(),
+                    format!("[{:.4}, {:.4}]", stats.mad_lower, stats.mad_upper),
+                );
+                values.insert("mad_outliers".to_owned(), stats.mad_count.to_string());
+            }
+            section.push(ReportBlock::KeyValues {
+                title: feature_name.clone(),
+                values,
+                data_values: Vec::new(),
+                definition: None,
+            });
+            if let Some(path) = plots.and_then(|plots| plots.get(&outlier_plot_title(feature_name)))
+            {
+                section.push(ReportBlock::Image(Plot::new(path.clone())));
+            }
+        }
+        section
+    }
+
+    /// Creates the time series analysis section for time series mode, with the sampling
+    /// regularity of the datetime index, the gaps and duplicated timestamps, the rolling
+    /// statistics, autocorrelation, and seasonal decomposition of the numerical features, and
+    /// their plots (if visualizations were generated).
+    ///
+    /// ### Parameters
+    ///
+    /// - `time_series_analysis`: The time series analysis.
+    /// - `visualizations`: The optional visualizations for the dataset.
+    ///
+    /// ### Returns
+    ///
+    /// - `ReportSection`: The time series analysis section.
+    #[cfg(feature = "time-series")]
+    pub fn time_series(
+        time_series_analysis: &TimeSeriesAnalysis,
+        visualizations: &Option<VisualizationManager>,
+    ) -> Self {
+        let mut section = Self::new("Time Series Analysis");
+        let Some(interval) = time_series_analysis.interval else {
+            section.push(ReportBlock::Paragraph {
+                text: format!(
+                    "The time index '{}' has fewer than two distinct valid timestamps.",
+                    time_series_analysis.index
+                ),
+            });
+            return section;
+        };
+
+        section.push(ReportBlock::KeyValues {
+            title: time_series_analysis.index.clone(),
+            values: IndexMap::from([
+                (
+                    "count".to_owned(),
+                    time_series_analysis.n_timestamps.to_string(),
+                ),
+                ("sampling_interval".to_owned(), format_duration(interval)),
+                (
+                    "first".to_owned(),
+                    time_series_analysis
+                        .first
+                        .map(format_timestamp)
+                        .unwrap_or_default(),
+                ),
+                (
+                    "last".to_owned(),
+                    time_series_analysis
+                        .last
+                        .map(format_timestamp)
+                        .unwrap_or_default(),
+                ),
+                (
+                    "time_gaps".to_owned(),
+                    time_series_analysis.gaps.len().to_string(),
+                ),
+                (
+                    "missing_periods".to_owned(),
+                    time_series_analysis.missing_periods().to_string(),
+                ),
+                (
+                    "irregular_intervals".to_owned(),
+                    time_series_analysis.irregular_intervals.to_string(),
+                ),
+                (
+                    "duplicate_timestamps".to_owned(),
+                    time_series_analysis.duplicate_timestamps.len().to_string(),
+                ),
+            ]),
+            data_values: Vec::new(),
+            definition: None,
+        });
+
+        if !time_series_analysis.gaps.is_empty() {
+            section.push(ReportBlock::Heading {
+                text: "Gaps".to_owned(),
+            });
+            section.push(ReportBlock::Table {
+                headers: ["Last Before", "First After", "Duration", "Missing"]
+                    .into_iter()
+                    .map(str::to_owned)
+                    .collect(),
+                rows: time_series_analysis
+                    .gaps
+                    .iter()
+                    .take(MAX_LISTED_TIMESTAMPS)
+                    .map(|gap| {
+                        vec![
+                            format_timestamp(gap.start),
+                            format_timestamp(gap.end),
+                            format_duration(gap.duration()),
+                            gap.missing_periods.to_string(),
+                        ]
+                    })
+                    .collect(),
+                data_columns: Vec::new(),
+                highlighted_rows: Vec::new(),
+            });
+            push_remaining_note(
+                &mut section,
+                time_series_analysis.gaps.len(),
+                MAX_LISTED_TIMESTAMPS,
+            );
+        }
+
+        if !time_series_analysis.duplicate_timestamps.is_empty() {
+            section.push(ReportBlock::Heading {
+                text: "Duplicated Timestamps".to_owned(),
+            });
+            section.push(ReportBlock::Table {
+                headers: vec!["Timestamp".to_owned(), "Rows".to_owned()],
+                rows: time_series_analysis
+                    .duplicate_timestamps
+                    .iter()
+                    .take(MAX_LISTED_TIMESTAMPS)
+                    .map(|(timestamp, count)| vec![format_timestamp(*timestamp), count.to_string()])
+                    .collect(),
+                data_columns: Vec::new(),
+                highlighted_rows: Vec::new(),
+            });
+            push_remaining_note(
+                &mut section,
+                time_series_analysis.duplicate_timestamps.len(),
+                MAX_LISTED_TIMESTAMPS,
+            );
+        }
+
+        let plots = plot_group(visualizations, PlotGroup::TimeSeries);
+        let push_plot = |section: &mut Self, title: &str| {
+            if let Some(path) = plots.and_then(|plots| plots.get(title)) {
+                section.push(ReportBlock::Image(Plot::new(path.clone())));
+            }
+        };
+        push_plot(
+            &mut section,
+            &gap_timeline_plot_title(&time_series_analysis.index),
+        );
+
+        if !time_series_analysis.rolling.is_empty() {
+            section.reference_term("mean_drift");
+            section.reference_term("std_ratio");
+            section.push(ReportBlock::Heading {
+                text: format!(
+                    "Rolling Statistics ({} Sample Window)",
+                    time_series_analysis.window
+                ),
+            });
+            section.push(ReportBlock::Table {
+                headers: ["Feature", "Mean Drift", "Std Dev Ratio"]
+                    .into_iter()
+                    .map(str::to_owned)
+                    .collect(),
+                rows: time_series_analysis
+                    .rolling
+                    .iter()
+                    .map(|(feature, statistics)| {
+                        vec![
+                            feature.clone(),
+                            format!("{:.4}", statistics.mean_drift),
+                            format!("{:.4}", statistics.std_ratio),
+                        ]
+                    })
+                    .collect(),
+                data_columns: Vec::new(),
+                highlighted_rows: Vec::new(),
+            });
+            for feature in time_series_analysis.rolling.keys() {
+                push_plot(&mut section, &rolling_plot_title(feature));
+            }
+        }
+
+        if !time_series_analysis.autocorrelation.is_empty() {
+            section.reference_term("acf");
+            section.reference_term("pacf");
+            section.push(ReportBlock::Heading {
+                text: "Autocorrelation".to_owned(),
+            });
+            section.push(ReportBlock::Table {
+                headers: [
+                    "Feature",
+                    "Lag 1 ACF",
+                    "Significant ACF",
+                    "Significant PACF",
+                ]
+                .into_iter()
+                .map(str::to_owned)
+                .collect(),
+                rows: time_series_analysis
+                    .autocorrelation
+                    .iter()
+                    .map(|(feature, autocorrelation)| {
+                        vec![
+                            feature.clone(),
+                            format!("{:.4}", autocorrelation.acf[0]),
+                            format_lags(&autocorrelation.significant_acf_lags()),
+                            format_lags(&autocorrelation.significant_pacf_lags()),
+                        ]
+                    })
+                    .collect(),
+                data_columns: Vec::new(),
+                highlighted_rows: Vec::new(),
+            });
+            for feature in time_series_analysis.autocorrelation.keys() {
+                push_plot(&mut section, &autocorrelation_plot_title(feature));
+            }
+        }
+
+        if let Some(decomposition) = &time_series_analysis.decomposition {
+            section.push(ReportBlock::Heading {
+                text: "Seasonal Decomposition".to_owned(),
+            });
+            section.push(ReportBlock::KeyValues {
+                title: decomposition.feature.clone(),
+                values: IndexMap::from([
+                    ("period".to_owned(), decomposition.period.to_string()),
+                    (
+                        "trend_strength".to_owned(),
+                        format!("{:.4}", decomposition.trend_strength),
+                    ),
+                    (
+                        "seasonal_strength".to_owned(),
+                        format!("{:.4}", decomposition.seasonal_strength),
+                    ),
+                    (
+                        "residual_share".to_owned(),
+                        format!("{:.4}", decomposition.residual_share),
+                    ),
+                ]),
+                data_values: Vec::new(),
+                definition: None,
+            });
+            push_plot(
+                &mut section,
+                &decomposition_plot_title(&decomposition.feature),
+            );
+        }
+        section
+    }
+
+    /// Creates the categorical associations section, with Cramér's V for each cross-tabulated
+    /// pair followed by its annotated heatmap (if visualizations were generated).
+    ///
+    /// ### Parameters
+    ///
+    /// - `association_analysis`: The association analysis for the dataset.
+    /// - `visualizations`: The optional visualizations for the dataset.
+    ///
+    /// ### Returns
+    ///
+    /// - `ReportSection`: The categorical associations section.
+    pub fn associations(
+        association_analysis: &AssociationAnalysis,
+        visualizations: &Option<VisualizationManager>,
+    ) -> Self {
+        let mut section = Self::new("Categorical Associations");
+        if association_analysis.tables.is_empty() {
+            section.push(ReportBlock::Paragraph {
+                text: "No pairs of low-cardinality categorical features found.".to_owned(),
+            });
+            return section;
+        }
+
+        let plots = plot_group(visualizations, PlotGroup::Associations);
+        for table in &association_analysis.tables {
+            let title = crosstab_plot_title(&table.pair.rows, &table.pair.columns);
+            section.push(ReportBlock::KeyValues {
+                title: title.clone(),
+                values: IndexMap::from([
+                    ("cramers_v".to_owned(), format!("{:.4}", table.cramers_v)),
+                    (
+                        "count".to_owned(),
+                        table.counts.iter().flatten().sum::<u64>().to_string(),
+                    ),
+                ]),
+                data_values: Vec::new(),
+                definition: None,
+            });
+            if let Some(path) = plots.and_then(|plots| plots.get(&title)) {
+                section.push(ReportBlock::Image(Plot::new(path.clone())));
+            }
+        }
+        section
+    }
+
+    /// Creates the text profiling section, with the length, letter case, detected format, and
+    /// most common character-class patterns of each string column, and a warning for the
+    /// columns with blank values or inconsistent formats.
+    ///
+    /// ### Parameters
+    ///
+    /// - `text_analysis`: The text profiling results for the dataset.
+    ///
+    /// ### Returns
+    ///
+    /// - `ReportSection`: The text profiling section.
+    pub fn text_profiling(text_analysis: &TextAnalysis) -> Self {
+        let mut section = Self::new("Text Profiling");
+        if text_analysis.columns.is_empty() {
+            section.push(ReportBlock::Paragraph {
+                text: if cfg!(feature = "text-profiling") {
+                    "No string features found."
+                } else {
+                    "Text profiling was left out of this build (text-profiling feature)."
+                }
+                .to_owned(),
+            });
+            return section;
+        }
+
+        section.reference_term("pattern");
+        section.reference_term("coverage");
+        section.reference_term("letter_case");
+        let list = |items: Vec<String>| ReportBlock::List { items };
+        // The example values are quoted, so they're left as is when the section is localized.
+        let quoted_list = |values: &[String]| ReportBlock::List {
+            items: values.iter().map(|value| format!("'{}'", value)).collect(),
+        };
+        for (feature_name, profile) in &text_analysis.columns {
+            section.push(ReportBlock::Heading {
+                text: feature_name.clone(),
+            });
+            let case = profile
+                .case
+                .labeled()
+                .iter()
+                .map(|(label, count)| format!("{} {}", label, count))
+                .collect::<Vec<String>>()
+                .join(", ");
+            section.push(ReportBlock::Paragraph {
+                text: format!(
+                    "Length: {} to {} characters (mean {:.2}). Empty: {}, whitespace-only: {}. \
+                     Case: {}.",
+                    profile.lengths.min,
+                    profile.lengths.max,
+                    profile.lengths.mean,
+                    profile.n_empty,
+                    profile.n_whitespace,
+                    case
+                ),
+            });
+            if let Some(warning) = profile.blank_warning() {
+                section.reference_term("blank_strings");
+                section.push(ReportBlock::Note { text: warning });
+            }
+            if let Some(warning) = profile.format_warning() {
+                section.push(ReportBlock::Note { text: warning });
+            }
+            if let Some(validity) = &profile.validity {
+                section.reference_term("invalid_percentage");
+                section.push(ReportBlock::Paragraph {
+                    text: format!(
+                        "Detected format: {} ({:.2}% invalid)",
+                        validity.kind, validity.invalid_percentage
+                    ),
+                });
+                if !validity.invalid_examples.is_empty() {
+                    section.push(quoted_list(&validity.invalid_examples));
+                }
+            }
+            if let Some(variants) = &profile.variants {
+                section.reference_term("collapsed_cardinality");
+                section.push(ReportBlock::Note {
+                    text: variants.warning(),
+                });
+                section.push(list(
+                    variants
+                        .examples
+                        .iter()
+                        .map(|group| {
+                            group
+                                .iter()
+                                .map(|value| format!("'{}'", value))
+                                .collect::<Vec<String>>()
+                                .join(" / ")
+                        })
+                        .collect(),
+                ));
+            }
+            if let Some(numeric) = &profile.numeric {
+                section.reference_term("leading_zeros");
+                section.reference_term("significant_digits");
+                section.push(ReportBlock::Paragraph {
+                    text: format!(
+                        "Detected format: numeric strings ({:.2}% leading zeros, {:.2}% over {} \
+                         significant digits)",
+                        numeric.leading_zero_percentage,
+                        numeric.long_digit_percentage,
+                        MAX_EXACT_DIGITS
+                    ),
+                });
+                if let Some(warning) = numeric.warning() {
+                    section.push(ReportBlock::Note { text: warning });
+                }
+                if !numeric.examples.is_empty() {
+                    section.push(quoted_list(&numeric.examples));
+                }
+            }
+            if let Some(dates) = &profile.dates {
+                section.reference_term("ambiguous_dates");
+                let mut text = format!(
+                    "Detected format: dates ({:.2}% parse, {} ambiguous)",
+                    dates.date_percentage, dates.n_ambiguous
+                );
+                if let Some((label, format)) = &dates.suggested_format {
+                    write!(text, ". Suggested parse format: {} ({})", label, format).unwrap();
+                }
+                section.push(ReportBlock::Paragraph { text });
+                if let Some(warning) = dates.warning() {
+                    section.push(ReportBlock::Note { text: warning });
+                }
+                section.push(ReportBlock::Table {
+                    headers: vec!["Date Format".to_owned(), "Parses".to_owned()],
+                    rows: dates
+                        .formats
+                        .iter()
+                        .map(|(format, share)| vec![format.clone(), format!("{:.2}%", share)])
+                        .collect(),
+                    data_columns: Vec::new(),
+                    highlighted_rows: Vec::new(),
+                });
+            }
+            if let Some(json) = &profile.json {
+                section.reference_term("parse_failures");
+                section.reference_term("key_presence");
+                section.push(ReportBlock::Paragraph {
+                    text: format!(
+                        "Detected format: JSON objects ({:.2}% fail to parse)",
+                        json.parse_failure_percentage
+                    ),
+                });
+                section.push(ReportBlock::Table {
+                    headers: vec!["Key".to_owned(), "Presence".to_owned()],
+                    rows: json
+                        .keys
+                        .iter()
+                        .map(|(key, presence)| vec![key.clone(), format!("{:.2}%", presence)])
+                        .collect(),
+                    data_columns: Vec::new(),
+                    highlighted_rows: Vec::new(),
+                });
+                push_remaining_note(&mut section, json.n_keys, json.keys.len());
+            }
+
+            section.push(ReportBlock::Table {
+                headers: vec!["Pattern".to_owned(), "Coverage".to_owned()],
+                rows: profile
+                    .patterns
+                    .iter()
+                    .map(|(pattern, coverage)| {
+                        vec![pattern_regex(pattern), format!("{:.2}%", coverage)]
+                    })
+                    .collect(),
+                data_columns: Vec::new(),
+                highlighted_rows: Vec::new(),
+            });
+            push_remaining_note(&mut section, profile.n_patterns, profile.patterns.len());
+        }
+        section
+    }
+
+    /// Creates the missing values section, with the missing count and percentage of each column
+    /// followed by the missing values plots (if visualizations were generated).
+    ///
+    /// ### Parameters
+    ///
+    /// - `missing_value_analysis`: The missing values analysis for the dataset.
+    /// - `visualizations`: The optional visualizations for the dataset.
+    ///
+    /// ### Returns
+    ///
+    /// - `ReportSection`: The missing values section.
+    pub fn missing_values(
+        missing_value_analysis: &MissingValueAnalysis,
+        visualizations: &Option<VisualizationManager>,
+    ) -> Self {
+        let mut section = Self::new("Missing Values Analysis");
+        section.push(ReportBlock::Table {
+            headers: ["Feature", "Missing Count", "Missing Percentage"]
+                .into_iter()
+                .map(str::to_owned)
+                .collect(),
+            rows: missing_value_analysis
+                .column_missing_values
+                .iter()
+                .map(|(column, (missing_count, missing_percentage))| {
+                    vec![
+                        column.clone(),
+                        missing_count.to_string(),
+                        format!("{:.2}%", missing_percentage),
+                    ]
+                })
+                .collect(),
+            data_columns: Vec::new(),
+            highlighted_rows: Vec::new(),
+        });
+        // The plots are drawn by title, since the plots of a group aren't kept in order.
+        let mut plots: Vec<(&String, &PathBuf)> =
+            plot_group(visualizations, PlotGroup::MissingValues)
+                .into_iter()
+                .flatten()
+                .collect();
+        plots.sort();
+        for (_, path) in plots {
+            section.push(ReportBlock::Image(Plot::new(path.clone())));
+        }
+        section
+    }
+
+    /// Formats the numbers and dates of the section text in a locale. The table headers, column
+    /// names, image paths, and values taken from the data are left as is.
+    ///
+    /// ### Parameters
+    ///
+    /// - `locale`: The locale.
+    ///
+    /// ### Returns
+    ///
+    /// - `ReportSection`: The localized section.
+    pub fn localize(&self, locale: Locale) -> Self {
+        let localize_values = |values: &IndexMap<String, String>, data_values: &[String]| {
+            values
+                .iter()
+                .map(|(name, value)| {
+                    if data_values.contains(name) {
+                        (name.clone(), value.clone())
+                    } else {
+                        (name.clone(), locale.localize(value))
+                    }
+                })
+                .collect()
+        };
+        let localize_rows = |rows: &Vec<Vec<String>>, data_columns: &[usize]| {
+            rows.iter()
+                .map(|row| {
+                    row.iter()
+                        .enumerate()
+                        .map(|(i, value)| match i {
+                            // The first cell links to the column details by name.
+                            0 => value.clone(),
+                            i if data_columns.contains(&i) => value.clone(),
+                            _ => locale.localize(value),
+                        })
+                        .collect()
+                })
+                .collect()
+        };
+        let blocks = self
+            .blocks
+            .iter()
+            .map(|block| match block {
+                ReportBlock::Paragraph { text } => ReportBlock::Paragraph {
+                    text: locale.localize(text),
+                },
+                ReportBlock::Note { text } => ReportBlock::Note {
+                    text: locale.localize(text),
+                },
+                ReportBlock::List { items } => ReportBlock::List {
+                    items: items.iter().map(|item| locale.localize(item)).collect(),
+                },
+                ReportBlock::Table {
+                    headers,
+                    rows,
+                    data_columns,
+                    highlighted_rows,
+                } => ReportBlock::Table {
+                    headers: headers.clone(),
+                    rows: localize_rows(rows, data_columns),
+                    data_columns: data_columns.clone(),
+                    highlighted_rows: highlighted_rows.clone(),
+                },
+                ReportBlock::SparklineTable {
+                    headers,
+                    rows,
+                    counts,
+                } => ReportBlock::SparklineTable {
+                    headers: headers.clone(),
+                    rows: localize_rows(rows, &[]),
+                    counts: counts.clone(),
+                },
+                ReportBlock::KeyValues {
+                    title,
+                    values,
+                    data_values,
+                    definition,
+                } => ReportBlock::KeyValues {
+                    title: title.clone(),
+                    values: localize_values(values, data_values),
+                    data_values: data_values.clone(),
+                    definition: definition.clone(),
+                },
+                ReportBlock::ColumnDetail {
+                    column,
+                    values,
+                    images,
+                    histogram,
+                } => ReportBlock::ColumnDetail {
+                    column: column.clone(),
+                    values: localize_values(values, &[]),
+                    images: images.clone(),
+                    histogram: histogram.clone(),
+                },
+                block => block.clone(),
+            })
+            .collect();
+        Self {
+            id: self.id.clone(),
+            title: self.title.clone(),
+            blocks,
+            terms: self.terms.clone(),
+        }
+    }
+
+    /// Adds a block to the end of the section.
+    pub fn push(&mut self, block: ReportBlock) {
+        self.blocks.push(block);
+    }
+
+    /// Adds a glossary term referenced by the section, ignoring duplicates.
+    pub fn reference_term(&mut self, term: &str) {
+        if !self.terms.iter().any(|existing| existing == term) {
+            self.terms.push(term.to_owned());
+        }
+    }
+}
+
+impl From<&CollinearityAnalysis> for ReportSection {
+    fn from(collinearity_analysis: &CollinearityAnalysis) -> Self {
+        let mut section = Self::new("Multicollinearity");
+        if collinearity_analysis.vif.is_empty() {
+            section.push(ReportBlock::Paragraph {
+                text: "Not enough non-constant numerical features or complete rows to compute \
+                       the variance inflation factors."
+                    .to_owned(),
+            });
+            return section;
+        }
+        section.reference_term("vif");
+
+        section.push(ReportBlock::Note {
+            text: format!(
+                "Features with a VIF above {} are highlighted.",
+                collinearity_analysis.threshold
+            ),
+        });
+        section.push(ReportBlock::Table {
+            headers: vec!["Feature".to_owned(), "VIF".to_owned()],
+            rows: collinearity_analysis
+                .vif
+                .iter()
+                .map(|(feature, vif)| vec![feature.clone(), format!("{:.2}", vif)])
+                .collect(),
+            data_columns: Vec::new(),
+            highlighted_rows: collinearity_analysis
+                .vif
+                .values()
+                .enumerate()
+                .filter(|(_, vif)| **vif > collinearity_analysis.threshold)
+                .map(|(i, _)| i)
+                .collect(),
+        });
+        section
+    }
+}
+
+impl From<&RowOrderAnalysis> for ReportSection {
+    fn from(row_order_analysis: &RowOrderAnalysis) -> Self {
+        let mut section = Self::new("Row Order");
+        if row_order_analysis.correlations.is_empty() {
+            section.push(ReportBlock::Paragraph {
+                text: "No non-constant numerical or temporal features to correlate with the row \
+                       order."
+                    .to_owned(),
+            });
+            return section;
+        }
+        section.reference_term("row_order_correlation");
+
+        section.push(ReportBlock::Note {
+            text: format!(
+                "Features with an absolute correlation of at least {} with the row index are \
+                 highlighted. The rows are sorted by them, so split the data randomly rather \
+                 than by position.",
+                row_order_analysis.threshold
+            ),
+        });
+        section.push(ReportBlock::Table {
+            headers: vec!["Feature".to_owned(), "Spearman".to_owned()],
+            rows: row_order_analysis
+                .correlations
+                .iter()
+                .map(|(feature, correlation)| vec![feature.clone(), format!("{:.4}", correlation)])
+                .collect(),
+            data_columns: Vec::new(),
+            highlighted_rows: row_order_analysis
+                .correlations
+                .values()
+                .enumerate()
+                .filter(|(_, correlation)| correlation.abs() >= row_order_analysis.threshold)
+                .map(|(i, _)| i)
+                .collect(),
+        });
+        section
+    }
+}
+
+impl From<&TemporalAnalysis> for ReportSection {
+    fn from(temporal_analysis: &TemporalAnalysis) -> Self {
+        let mut section = Self::new("Temporal Columns");
+        section.reference_term("temporal_gaps");
+        section.reference_term("temporal_order");
+        section.push(ReportBlock::Note {
+            text: "Columns with gaps are highlighted. Datetimes are shown in UTC.".to_owned(),
+        });
+        section.push(ReportBlock::Table {
+            headers: [
+                "Column", "Type", "Values", "Earliest", "Latest", "Span", "Gaps", "Order",
+            ]
+            .map(str::to_owned)
+            .to_vec(),
+            rows: temporal_analysis
+                .columns
+                .iter()
+                .map(|(column, stats)| {
+                    let format = |value: Option<i64>| {
+                        value.map_or("-".to_owned(), |value| stats.format_value(value))
+                    };
+                    vec![
+                        column.clone(),
+                        stats.dtype.to_string(),
+                        stats.count.to_string(),
+                        format(stats.min),
+                        format(stats.max),
+                        stats.span().map_or("-".to_owned(), format_duration),
+                        stats.n_gaps.to_string(),
+                        stats
+                            .monotonicity
+                            .map_or("-".to_owned(), |monotonicity| monotonicity.to_string()),
+                    ]
+                })
+                .collect(),
+            data_columns: Vec::new(),
+            highlighted_rows: temporal_analysis
+                .columns
+                .values()
+                .enumerate()
+                .filter(|(_, stats)| stats.n_gaps > 0)
+                .map(|(i, _)| i)
+                .collect(),
+        });
+
+        for (column, stats) in &temporal_analysis.columns {
+            if stats.largest_gaps.is_empty() {
+                continue;
+            }
+            section.push(ReportBlock::Heading {
+                text: format!("Largest Gaps: {}", column),
+            });
+            if let Some(interval) = stats.interval {
+                section.push(ReportBlock::Note {
+                    text: format!(
+                        "{} gaps against a median difference of {}.",
+                        stats.n_gaps,
+                        format_duration(interval)
+                    ),
+                });
+            }
+            section.push(ReportBlock::Table {
+                headers: ["Start", "End", "Duration"].map(str::to_owned).to_vec(),
+                rows: stats
+                    .largest_gaps
+                    .iter()
+                    .map(|gap| {
+                        vec![
+                            stats.format_value(gap.start),
+                            stats.format_value(gap.end),
+                            format_duration(gap.duration()),
+                        ]
+                    })
+                    .collect(),
+                data_columns: Vec::new(),
+                highlighted_rows: Vec::new(),
+            });
+        }
+
+        let calendars: Vec<(&String, [u64; 7], [u64; 12])> = temporal_analysis
+            .columns
+            .iter()
+            .filter(|(_, stats)| stats.count > 0)
+            .filter_map(|(column, stats)| Some((column, stats.weekdays?, stats.months?)))
+            .collect();
+        if !calendars.is_empty() {
+            section.push(ReportBlock::Heading {
+                text: "Day of Week".to_owned(),
+            });
+            section.push(ReportBlock::Table {
+                headers: std::iter::once("Column")
+                    .chain(WEEKDAYS)
+                    .map(str::to_owned)
+                    .collect(),
+                rows: calendars
+                    .iter()
+                    .map(|(column, weekdays, _)| {
+                        std::iter::once(column.to_string())
+                            .chain(weekdays.iter().map(u64::to_string))
+                            .collect()
+                    })
+                    .collect(),
+                data_columns: Vec::new(),
+                highlighted_rows: Vec::new(),
+            });
+            section.push(ReportBlock::Heading {
+                text: "Month".to_owned(),
+            });
+            section.push(ReportBlock::Table {
+                headers: std::iter::once("Column")
+                    .chain(MONTHS)
+                    .map(str::to_owned)
+                    .collect(),
+                rows: calendars
+                    .iter()
+                    .map(|(column, _, months)| {
+                        std::iter::once(column.to_string())
+                            .chain(months.iter().map(u64::to_string))
+                            .collect()
+                    })
+                    .collect(),
+                data_columns: Vec::new(),
+                highlighted_rows: Vec::new(),
+            });
+        }
+        section
+    }
+}
+
+impl From<&NullIfAnalysis> for ReportSection {
+    fn from(null_if_analysis: &NullIfAnalysis) -> Self {
+        let mut section = Self::new("Null-If Rules");
+        section.push(ReportBlock::Note {
+            text: format!(
+                "{} values were converted to null before the analyses, so they count as missing \
+                 values in the rest of the report.",
+                null_if_analysis.total_nulled()
+            ),
+        });
+        section.push(ReportBlock::Table {
+            headers: ["Column", "Value", "Nulled"].map(str::to_owned).to_vec(),
+            rows: null_if_analysis
+                .rules
+                .iter()
+                .map(|(rule, count)| {
+                    vec![rule.column.clone(), rule.value.clone(), count.to_string()]
+                })
+                .collect(),
+            data_columns: vec![1],
+            highlighted_rows: Vec::new(),
+        });
+        section
+    }
+}
+
+impl From<&ForeignValueAnalysis> for ReportSection {
+    fn from(foreign_value_analysis: &ForeignValueAnalysis) -> Self {
+        let mut section = Self::new("Foreign Values");
+        section.reference_term("foreign_values");
+        section.push(ReportBlock::Table {
+            headers: [
+                "Column",
+                "Reference",
+                "Checked",
+                "Foreign",
+                "Distinct Foreign",
+            ]
+            .map(str::to_owned)
+            .to_vec(),
+            rows: foreign_value_analysis
+                .columns
+                .iter()
+                .map(|(column, foreign_values)| {
+                    vec![
+                        column.clone(),
+                        format!(
+                            "{} ({} values)",
+                            foreign_values
+                                .reference
+                                .file_name()
+                                .unwrap_or(foreign_values.reference.as_os_str())
+                                .to_string_lossy(),
+                            foreign_values.n_reference
+                        ),
+                        foreign_values.count.to_string(),
+                        format!(
+                            "{} ({:.2}%)",
+                            foreign_values.n_foreign,
+                            foreign_values.foreign_percentage()
+                        ),
+                        foreign_values.n_distinct_foreign.to_string(),
+                    ]
+                })
+                .collect(),
+            data_columns: Vec::new(),
+            highlighted_rows: foreign_value_analysis
+                .columns
+                .values()
+                .enumerate()
+                .filter(|(_, foreign_values)| foreign_values.n_foreign > 0)
+                .map(|(i, _)| i)
+                .collect(),
+        });
+        for (column, foreign_values) in &foreign_value_analysis.columns {
+            if foreign_values.top_foreign.is_empty() {
+                continue;
+            }
+            section.push(ReportBlock::Heading {
+                text: format!("Top Foreign Values: {}", column),
+            });
+            section.push(ReportBlock::Table {
+                headers: vec!["Value".to_owned(), "Count".to_owned()],
+                rows: foreign_values
+                    .top_foreign
+                    .iter()
+                    .map(|(value, count)| vec![value.clone(), count.to_string()])
+                    .collect(),
+                data_columns: Vec::new(),
+                highlighted_rows: Vec::new(),
+            });
+        }
+        section
+    }
+}
+
+impl From<&PartitionAnalysis> for ReportSection {
+    fn from(partition_analysis: &PartitionAnalysis) -> Self {
+        let mut section = Self::new("Partitions");
+        section.reference_term("partition");
+
+        section.push(ReportBlock::Note {
+            text: format!(
+                "{} partitions, median of {} rows and {:.2}% missing cells. Anomalous partitions \
+                 are highlighted.",
+                partition_analysis.partitions.len(),
+                partition_analysis.median_rows,
+                partition_analysis.median_missing_percentage
+            ),
+        });
+        section.push(ReportBlock::Table {
+            headers: ["Partition", "Rows", "Missing", "Schema"]
+                .map(str::to_owned)
+                .to_vec(),
+            rows: partition_analysis
+                .partitions
+                .iter()
+                .map(|partition| {
+                    vec![
+                        partition
+                            .path
+                            .file_name()
+                            .map(|name| name.to_string_lossy().into_owned())
+                            .unwrap_or_default(),
+                        partition.n_rows.to_string(),
+                        format!("{:.2}%", partition.missing_percentage),
+                        if partition.schema_matches() {
+                            "consistent".to_owned()
+                        } else {
+                            "differs".to_owned()
+                        },
+                    ]
+                })
+                .collect(),
+            data_columns: Vec::new(),
+            highlighted_rows: partition_analysis
+                .partitions
+                .iter()
+                .enumerate()
+                .filter(|(_, partition)| partition.is_anomalous())
+                .map(|(i, _)| i)
+                .collect(),
+        });
+
+        let anomalies: Vec<String> = partition_analysis
+            .anomalous_partitions()
+            .map(|partition| {
+                format!(
+                    "{}: {}.",
+                    partition.path.display(),
+                    partition.anomalies.join("; ")
+                )
+            })
+            .collect();
+        if !anomalies.is_empty() {
+            section.push(ReportBlock::Heading {
+                text: "Anomalous Partitions".to_owned(),
+            });
+            section.push(ReportBlock::List { items: anomalies });
+        }
+        section
+    }
+}
+
+impl From<&TableSet> for ReportSection {
+    fn from(table_set: &TableSet) -> Self {
+        let mut section = Self::new("Tables Overview");
+
+        section.push(ReportBlock::Note {
+            text: format!(
+                "{} tables, each analyzed on its own in the sections below. Tables with alerts are \
+                 highlighted.",
+                table_set.tables.len()
+            ),
+        });
+        let summaries: Vec<ReportSummary> = table_set
+            .tables
+            .iter()
+            .map(|table| ReportSummary::new(table, None))
+            .collect();
+        section.push(ReportBlock::Table {
+            headers: [
+                "Table",
+                "Rows",
+                "Columns",
+                "Missing",
+                "Duplicate Rows",
+                "Alerts",
+            ]
+            .map(str::to_owned)
+            .to_vec(),
+            rows: table_set
+                .tables
+                .iter()
+                .zip(&summaries)
+                .map(|(table, summary)| {
+                    let n_cells = summary.n_rows * summary.n_cols;
+                    let n_missing: u64 = summary
+                        .columns
+                        .iter()
+                        .map(|column| column.missing_count)
+                        .sum();
+                    vec![
+                        table.data_title.clone(),
+                        summary.n_rows.to_string(),
+                        summary.n_cols.to_string(),
+                        format!(
+                            "{:.2}%",
+                            if n_cells == 0 {
+                                0.0
+                            } else {
+                                n_missing as f64 / n_cells as f64 * 100.0
+                            }
+                        ),
+                        table.duplicate_analysis.n_duplicate_rows.to_string(),
+                        summary.alerts.len().to_string(),
+                    ]
+                })
+                .collect(),
+            data_columns: Vec::new(),
+            highlighted_rows: summaries
+                .iter()
+                .enumerate()
+                .filter(|(_, summary)| !summary.alerts.is_empty())
+                .map(|(i, _)| i)
+                .collect(),
+        });
+
+        let shared_columns = table_set.shared_columns();
+        if !shared_columns.is_empty() {
+            section.push(ReportBlock::Heading {
+                text: "Shared Columns".to_owned(),
+            });
+            section.push(ReportBlock::Note {
+                text: "Columns found in several tables, the likely keys to join them on."
+                    .to_owned(),
+            });
+            section.push(ReportBlock::Table {
+                headers: ["Column", "Tables"].map(str::to_owned).to_vec(),
+                rows: shared_columns
+                    .iter()
+                    .map(|(column, tables)| vec![column.to_string(), tables.join(", ")])
+                    .collect(),
+                data_columns: vec![1],
+                highlighted_rows: Vec::new(),
+            });
+        }
+        section
+    }
+}
+
+impl From<&DuplicateAnalysis> for ReportSection {
+    fn from(duplicate_analysis: &DuplicateAnalysis) -> Self {
+        let mut section = Self::new("Duplicates");
+        section.reference_term("duplicate_rows");
+        section.push(ReportBlock::Paragraph {
+            text: if duplicate_analysis.n_duplicate_rows == 0 {
+                "No row is a full-row duplicate of an earlier row.".to_owned()
+            } else {
+                format!(
+                    "{} of {} rows ({:.2}%) are full-row duplicates of an earlier row.",
+                    duplicate_analysis.n_duplicate_rows,
+                    duplicate_analysis.n_rows,
+                    duplicate_analysis.duplicate_percentage()
+                )
+            },
+        });
+        if !duplicate_analysis.skipped_columns.is_empty() {
+            section.push(ReportBlock::Note {
+                text: format!(
+                    "Left out of the full-row comparison: {}.",
+                    duplicate_analysis.skipped_columns.join(", ")
+                ),
+            });
+        }
+        if !duplicate_analysis.duplicate_columns.is_empty() {
+            section.reference_term("duplicate_columns");
+            section.push(ReportBlock::Heading {
+                text: "Duplicate Columns".to_owned(),
+            });
+            section.push(ReportBlock::Table {
+                headers: vec![
+                    "Column".to_owned(),
+                    "Duplicates".to_owned(),
+                    "Relation".to_owned(),
+                ],
+                rows: duplicate_analysis
+                    .duplicate_columns
+                    .iter()
+                    .map(|duplicate| {
+                        vec![
+                            duplicate.column.clone(),
+                            duplicate.source.clone(),
+                            duplicate.relation.to_string(),
+                        ]
+                    })
+                    .collect(),
+                data_columns: vec![1],
+                highlighted_rows: Vec::new(),
+            });
+            section.push(ReportBlock::Note {
+                text: format!(
+                    "Numerical values are compared within a relative tolerance of {}.",
+                    duplicate_analysis.column_tolerance
+                ),
+            });
+        }
+        if !duplicate_analysis.key_candidates.is_empty() {
+            section.reference_term("key_candidate");
+            section.push(ReportBlock::Heading {
+                text: "Key Candidates".to_owned(),
+            });
+            section.push(ReportBlock::Table {
+                headers: vec![
+                    "Columns".to_owned(),
+                    "Duplicate Rows".to_owned(),
+                    "Missing Keys".to_owned(),
+                    "Status".to_owned(),
+                ],
+                rows: duplicate_analysis
+                    .key_candidates
+                    .iter()
+                    .map(|candidate| {
+                        vec![
+                            candidate.columns.join(", "),
+                            candidate.n_duplicate_rows.to_string(),
+                            candidate.n_null_keys.to_string(),
+                            if candidate.is_unique() {
+                                "unique".to_owned()
+                            } else {
+                                "near".to_owned()
+                            },
+                        ]
+                    })
+                    .collect(),
+                data_columns: Vec::new(),
+                highlighted_rows: duplicate_analysis
+                    .key_candidates
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, candidate)| !candidate.is_unique())
+                    .map(|(i, _)| i)
+                    .collect(),
+            });
+        }
+
+        let Some(key_violations) = &duplicate_analysis.key_violations else {
+            return section;
+        };
+        section.reference_term("uniqueness_key");
+        section.push(ReportBlock::KeyValues {
+            title: format!("Uniqueness Key: {}", key_violations.columns.join(", ")),
+            values: IndexMap::from([
+                (
+                    "duplicate_keys".to_owned(),
+                    key_violations.n_duplicate_keys.to_string(),
+                ),
+                (
+                    "violating_rows".to_owned(),
+                    key_violations.n_violating_rows.to_string(),
+                ),
+                (
+                    "null_keys".to_owned(),
+                    key_violations.n_null_keys.to_string(),
+                ),
+            ]),
+            data_values: Vec::new(),
+            definition: None,
+        });
+        if key_violations.is_valid() {
+            section.push(ReportBlock::Paragraph {
+                text: "The key uniquely identifies every row.".to_owned(),
+            });
+        }
+        if !key_violations.worst_offenders.is_empty() {
+            section.push(ReportBlock::Heading {
+                text: "Worst Offenders".to_owned(),
+            });
+            section.push(ReportBlock::Table {
+                headers: key_violations
+                    .columns
+                    .iter()
+                    .cloned()
+                    .chain(["Rows".to_owned()])
+                    .collect(),
+                rows: key_violations
+                    .worst_offenders
+                    .iter()
+                    .map(|(values, n_rows)| {
+                        values.iter().cloned().chain([n_rows.to_string()]).collect()
+                    })
+                    .collect(),
+                data_columns: (1..key_violations.columns.len()).collect(),
+                highlighted_rows: Vec::new(),
+            });
+        }
+        section
+    }
+}
+
+impl From<&ProblemRowAnalysis> for ReportSection {
+    fn from(problem_row_analysis: &ProblemRowAnalysis) -> Self {
+        let mut section = Self::new("Problem Rows");
+        section.push(ReportBlock::Paragraph {
+            text: "A random sample of the rows behind each outlier and invalid format alert, in \
+                   row order, with the row number (from 1) and a few other columns for context."
+                .to_owned(),
+        });
+        section.push(ReportBlock::Note {
+            text: format!(
+                "Values longer than {} characters are truncated.",
+                MAX_VALUE_LENGTH
+            ),
+        });
+        for sample in &problem_row_analysis.samples {
+            section.push(ReportBlock::Heading {
+                text: format!("{}: {}", sample.column, sample.kind),
+            });
+            section.push(ReportBlock::Paragraph {
+                text: format!("{} of {} rows.", sample.rows.len(), sample.n_rows),
+            });
+            section.push(ReportBlock::Table {
+                headers: sample.headers.clone(),
+                rows: sample.rows.clone(),
+                // The row number is followed by the values of the row.
+                data_columns: (1..sample.headers.len()).collect(),
+                highlighted_rows: Vec::new(),
+            });
+        }
+        section
+    }
+}
+
+impl From<&DriftAnalysis> for ReportSection {
+    fn from(drift_analysis: &DriftAnalysis) -> Self {
+        let mut section = Self::new("Drift");
+        section.push(ReportBlock::Note {
+            text: format!(
+                "Compared against the baseline profile of {} ({} rows, created {}).",
+                drift_analysis.baseline,
+                drift_analysis.baseline_rows,
+                drift_analysis.baseline_created_at
+            ),
+        });
+        if drift_analysis.columns.is_empty() {
+            section.push(ReportBlock::Paragraph {
+                text: "No columns of the baseline profile could be compared with the current data."
+                    .to_owned(),
+            });
+        } else {
+            section.reference_term("psi");
+            let mut columns: Vec<_> = drift_analysis.columns.iter().collect();
+            columns.sort_by(|a, b| b.1.psi.total_cmp(&a.1.psi));
+            section.push(ReportBlock::Paragraph {
+                text: format!(
+                    "Columns with a PSI of at least {} are highlighted.",
+                    MODERATE_DRIFT
+                ),
+            });
+            section.push(ReportBlock::Table {
+                headers: ["Column", "PSI", "Drift"].map(str::to_owned).to_vec(),
+                rows: columns
+                    .iter()
+                    .map(|(name, drift)| {
+                        vec![
+                            (*name).clone(),
+                            format!("{:.4}", drift.psi),
+                            drift.level.to_string(),
+                        ]
+                    })
+                    .collect(),
+                data_columns: Vec::new(),
+                highlighted_rows: columns
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, (_, drift))| drift.level > DriftLevel::Stable)
+                    .map(|(i, _)| i)
+                    .collect(),
+            });
+        }
+
+        let changes: Vec<String> = drift_analysis
+            .missing_columns
+            .iter()
+            .map(|name| format!("{}: in the baseline but not in the current data.", name))
+            .chain(
+                drift_analysis
+                    .new_columns
+                    .iter()
+                    .map(|name| format!("{}: not in the baseline.", name)),
+            )
+            .chain(drift_analysis.changed_columns.iter().map(|(name, kind)| {
+                format!("{}: {} in the baseline, but not anymore.", name, kind)
+            }))
+            .collect();
+        if !changes.is_empty() {
+            section.push(ReportBlock::Heading {
+                text: "Schema Changes".to_owned(),
+            });
+            section.push(ReportBlock::List { items: changes });
+        }
+        section
+    }
+}
+
+impl From<&ReportDiff> for ReportSection {
+    fn from(diff: &ReportDiff) -> Self {
+        let mut section = Self::new("Changes");
+        section.push(ReportBlock::Note {
+            text: format!(
+                "Metrics are listed when they changed by at least {}%, and missing percentages \
+                 when they changed by at least {} pp (percentage points).",
+                diff.thresholds.metric, diff.thresholds.missing
+            ),
+        });
+        if diff.is_empty() {
+            section.push(ReportBlock::Paragraph {
+                text: "No changes found.".to_owned(),
+            });
+            return section;
+        }
+
+        let schema_changes: Vec<String> = diff
+            .added_columns
+            .iter()
+            .map(|name| format!("{}: new column.", name))
+            .chain(
+                diff.removed_columns
+                    .iter()
+                    .map(|name| format!("{}: removed.", name)),
+            )
+            .chain(diff.type_changes.iter().map(|change| {
+                format!(
+                    "{}: data type changed from {} to {}.",
+                    change.column, change.old, change.new
+                )
+            }))
+            .collect();
+        if !schema_changes.is_empty() {
+            section.push(ReportBlock::Heading {
+                text: "Schema Changes".to_owned(),
+            });
+            section.push(ReportBlock::List {
+                items: schema_changes,
+            });
+        }
+        if !diff.metric_changes.is_empty() {
+            section.push(ReportBlock::Heading {
+                text: "Metric Changes".to_owned(),
+            });
+            section.push(ReportBlock::Table {
+                headers: ["Column", "Metric", "Old", "New", "Change"]
+                    .map(str::to_owned)
+                    .to_vec(),
+                rows: diff
+                    .metric_changes
+                    .iter()
+                    .map(|change| {
+                        vec![
+                            change.column.clone(),
+                            change.metric.clone(),
+                            change.format_value(change.old),
+                            change.format_value(change.new),
+                            change.change(),
+                        ]
+                    })
+                    .collect(),
+                data_columns: Vec::new(),
+                highlighted_rows: Vec::new(),
+            });
+        }
+        for (heading, alerts) in [
+            ("New Alerts", &diff.new_alerts),
+            ("Resolved Alerts", &diff.resolved_alerts),
+        ] {
+            if !alerts.is_empty() {
+                section.push(ReportBlock::Heading {
+                    text: heading.to_owned(),
+                });
+                section.push(ReportBlock::List {
+                    items: alerts.clone(),
+                });
+            }
+        }
+        section
+    }
+}
+
+impl From<&Provenance> for ReportSection {
+    fn from(provenance: &Provenance) -> Self {
+        let mut section = Self::new("Provenance");
+        section.reference_term("content_hash");
+
+        let mut input = IndexMap::from([("source".to_owned(), provenance.source.clone())]);
+        if provenance.n_files > 1 {
+            input.insert("files".to_owned(), provenance.n_files.to_string());
+        }
+        if let Some(file_size) = provenance.file_size {
+            input.insert("file_size".to_owned(), format_file_size(file_size));
+        }
+        if let Some(modified_at) = &provenance.modified_at {
+            input.insert("modified_at".to_owned(), modified_at.clone());
+        }
+        if let Some(content_hash) = &provenance.content_hash {
+            input.insert("sha256".to_owned(), content_hash.clone());
+        }
+        input.insert("leads_version".to_owned(), provenance.leads_version.clone());
+        section.push(ReportBlock::KeyValues {
+            title: "Input".to_owned(),
+            values: input,
+            data_values: vec!["source".to_owned(), "sha256".to_owned()],
+            definition: None,
+        });
+
+        if provenance.options.is_empty() {
+            section.push(ReportBlock::Note {
+                text: "The report was generated with the default options.".to_owned(),
+            });
+        } else {
+            section.push(ReportBlock::Table {
+                headers: vec!["Option".to_owned(), "Value".to_owned()],
+                rows: provenance
+                    .options
+                    .iter()
+                    .map(|(name, value)| vec![name.clone(), value.clone()])
+                    .collect(),
+                data_columns: vec![1],
+                highlighted_rows: Vec::new(),
+            });
+        }
+        section
+    }
+}
+
+impl From<&Throughput> for ReportSection {
+    fn from(throughput: &Throughput) -> Self {
+        let mut section = Self::new("Run Timings");
+        section.push(ReportBlock::Note {
+            text: "The input is scanned lazily, so most of the reading happens within the \
+                   analysis stage. A slow analysis of a large input is where sampling or the low \
+                   memory mode pays off."
+                .to_owned(),
+        });
+
+        let mut run = IndexMap::from([("rows".to_owned(), throughput.n_rows.to_string())]);
+        if let Some(n_bytes) = throughput.n_bytes {
+            run.insert("input_size".to_owned(), format_file_size(n_bytes));
+        }
+        run.insert(
+            "total_time".to_owned(),
+            format_stage_duration(throughput.total_duration()),
+        );
+        section.push(ReportBlock::KeyValues {
+            title: "Run".to_owned(),
+            values: run,
+            data_values: Vec::new(),
+            definition: None,
+        });
+
+        section.push(ReportBlock::Table {
+            headers: ["Stage", "Time", "Rows/s", "Bytes/s"]
+                .into_iter()
+                .map(str::to_owned)
+                .collect(),
+            rows: throughput
+                .stages
+                .iter()
+                .map(|timing| {
+                    vec![
+                        timing.stage.to_string(),
+                        format_stage_duration(timing.duration),
+                        throughput.format_rows_per_second(timing),
+                        throughput.format_bytes_per_second(timing),
+                    ]
+                })
+                .collect(),
+            data_columns: Vec::new(),
+            highlighted_rows: Vec::new(),
+        });
+        section
+    }
+}
+
+impl From<&FreshnessAnalysis> for ReportSection {
+    fn from(freshness_analysis: &FreshnessAnalysis) -> Self {
+        let mut section = Self::new("Freshness");
+        let lag_reference = match &freshness_analysis.ingested_column {
+            Some(ingested_column) => format!("the ingestion timestamp column {}", ingested_column),
+            None => "the time of the run".to_owned(),
+        };
+        section.push(ReportBlock::Note {
+            text: format!(
+                "Record timestamp column: {}. Lags are measured against {} ({}).",
+                freshness_analysis.column,
+                lag_reference,
+                format_timestamp(freshness_analysis.reference_time)
+            ),
+        });
+        let (Some(newest), Some(oldest), Some(staleness)) = (
+            freshness_analysis.newest,
+            freshness_analysis.oldest,
+            freshness_analysis.staleness(),
+        ) else {
+            section.push(ReportBlock::Paragraph {
+                text: "No valid record timestamps to measure the freshness of the data.".to_owned(),
+            });
+            return section;
+        };
+        section.reference_term("staleness");
+        section.reference_term("ingestion_lag");
+
+        section.push(ReportBlock::KeyValues {
+            title: "Freshness".to_owned(),
+            values: IndexMap::from([
+                ("newest_record".to_owned(), format_timestamp(newest)),
+                ("oldest_record".to_owned(), format_timestamp(oldest)),
+                ("staleness".to_owned(), format_duration(staleness)),
+                (
+                    "threshold".to_owned(),
+                    format_duration(freshness_analysis.threshold),
+                ),
+                (
+                    "status".to_owned(),
+                    if freshness_analysis.is_stale() {
+                        "Stale"
+                    } else {
+                        "Fresh"
+                    }
+                    .to_owned(),
+                ),
+            ]),
+            data_values: Vec::new(),
+            definition: None,
+        });
+        if freshness_analysis.is_stale() {
+            section.push(ReportBlock::Paragraph {
+                text: format!(
+                    "The newest record is {} old, above the freshness threshold of {}.",
+                    format_duration(staleness),
+                    format_duration(freshness_analysis.threshold)
+                ),
+            });
+        }
+
+        section.push(ReportBlock::Heading {
+            text: "Lag Distribution".to_owned(),
+        });
+        section.push(ReportBlock::Table {
+            headers: ["Quantile", "Lag"].map(str::to_owned).to_vec(),
+            rows: freshness_analysis
+                .lag_quantiles
+                .iter()
+                .map(|(quantile, lag)| vec![quantile.clone(), format_duration(*lag)])
+                .collect(),
+            data_columns: Vec::new(),
+            highlighted_rows: freshness_analysis
+                .lag_quantiles
+                .values()
+                .enumerate()
+                .filter(|(_, lag)| **lag > freshness_analysis.threshold)
+                .map(|(i, _)| i)
+                .collect(),
+        });
+        section.push(ReportBlock::KeyValues {
+            title: "Records".to_owned(),
+            values: IndexMap::from([
+                (
+                    "with_timestamps".to_owned(),
+                    freshness_analysis.n_timestamps.to_string(),
+                ),
+                (
+                    "lag_above_threshold".to_owned(),
+                    freshness_analysis.n_late.to_string(),
+                ),
+                (
+                    "timestamped_in_future".to_owned(),
+                    freshness_analysis.n_future.to_string(),
+                ),
+            ]),
+            data_values: Vec::new(),
+            definition: None,
+        });
+        if freshness_analysis.n_future > 0 {
+            section.push(ReportBlock::Note {
+                text: "Records timestamped after they were ingested point to clock skew or time \
+                       zone issues."
+                    .to_owned(),
+            });
+        }
+        section
+    }
+}
+
+/// Gets the plots generated for a column, in report order.
+fn column_plots(data_info: &DataInfo, column: &str) -> Vec<PathBuf> {
+    let Some(visualizations) = &data_info.visualizations else {
+        return Vec::new();
+    };
+    let titles = [
+        transformation_plot_title(column),
+        outlier_plot_title(column),
+    ]
+    .into_iter();
+    #[cfg(feature = "time-series")]
+    let titles = titles.chain([
+        rolling_plot_title(column),
+        autocorrelation_plot_title(column),
+        decomposition_plot_title(column),
+    ]);
+    titles
+        .filter_map(|title| {
+            visualizations
+                .visualizations
+                .values()
+                .find_map(|plots| plots.get(&title))
+                .cloned()
+        })
+        .collect()
+}
+
+/// Builds the sections of the report that are backed by the section model, in report order. The
+/// narrative insights of each section are listed at its top.
+///
+/// ### Parameters
+///
+/// - `data_info`: The dataset information.
+///
+/// ### Returns
+///
+/// - `Vec<ReportSection>`: The report sections.
+pub fn report_sections(data_info: &DataInfo) -> Vec<ReportSection> {
+    let mut sections = vec![
+        ReportSection::alerts(&ReportSummary::new(data_info, None).alerts),
+        ReportSection::columns(data_info),
+    ];
+    if !data_info.skipped_analyses().is_empty() {
+        sections.push(ReportSection::skipped_analyses(data_info));
+    }
+    if let Some(null_if_analysis) = &data_info.null_if_analysis {
+        sections.push(ReportSection::from(null_if_analysis));
+    }
+    sections.push(ReportSection::descriptive(
+        &data_info.descriptive_analysis,
+        data_info.robust_analysis.as_ref(),
+        data_info.data_dictionary.as_ref(),
+        data_info.wide,
+    ));
+    if plot_group(&data_info.visualizations, PlotGroup::Distributions)
+        .is_some_and(|plots| !plots.is_empty())
+    {
+        sections.push(ReportSection::distributions(&data_info.visualizations));
+    }
+    sections.extend([
+        ReportSection::uniqueness(&data_info.descriptive_analysis),
+        ReportSection::from(&data_info.duplicate_analysis),
+    ]);
+    if let Some(foreign_value_analysis) = &data_info.foreign_value_analysis {
+        sections.push(ReportSection::from(foreign_value_analysis));
+    }
+    sections.extend([
+        ReportSection::correlations(&data_info.correlation_analysis, &data_info.visualizations),
+        ReportSection::from(&data_info.collinearity_analysis),
+        ReportSection::from(&data_info.row_order_analysis),
+    ]);
+    if !data_info.temporal_analysis.columns.is_empty()
+        || data_info.failure(RecoverableAnalysis::Temporal).is_some()
+    {
+        sections.push(ReportSection::from(&data_info.temporal_analysis));
+    }
+    if let Some(partition_analysis) = &data_info.partition_analysis {
+        sections.push(ReportSection::from(partition_analysis));
+    }
+    if let Some(drift_analysis) = &data_info.drift_analysis {
+        sections.push(ReportSection::from(drift_analysis));
+    }
+    if let Some(freshness_analysis) = &data_info.freshness_analysis {
+        sections.push(ReportSection::from(freshness_analysis));
+    }
+    if let Some(session_analysis) = &data_info.session_analysis {
+        sections.push(ReportSection::sessions(
+            session_analysis,
+            &data_info.visualizations,
+        ));
+    }
+    #[cfg(feature = "pca")]
+    if let Some(pca_analysis) = &data_info.pca_analysis {
+        sections.push(ReportSection::pca(pca_analysis, &data_info.visualizations));
+    }
+    if let Some(target_analysis) = &data_info.target_analysis {
+        sections.push(ReportSection::target(
+            target_analysis,
+            &data_info.visualizations,
+        ));
+    }
+    #[cfg(feature = "time-series")]
+    if let Some(time_series_analysis) = &data_info.time_series_analysis {
+        sections.push(ReportSection::time_series(
+            time_series_analysis,
+            &data_info.visualizations,
+        ));
+    }
+    sections.extend([
+        ReportSection::transformations(
+            &data_info.transformation_analysis,
+            &data_info.visualizations,
+        ),
+        ReportSection::category_merges(&data_info.category_analysis, &data_info.visualizations),
+        ReportSection::outliers(
+            &data_info.outlier_analysis,
+            data_info.robust_analysis.as_ref(),
+            &data_info.visualizations,
+            data_info.wide,
+        ),
+        ReportSection::associations(&data_info.association_analysis, &data_info.visualizations),
+        ReportSection::text_profiling(&data_info.text_analysis),
+        ReportSection::missing_values(&data_info.missing_value_analysis, &data_info.visualizations),
+    ]);
+    if !data_info.problem_row_analysis.samples.is_empty()
+        || data_info
+            .failure(RecoverableAnalysis::ProblemRows)
+            .is_some()
+    {
+        sections.push(ReportSection::from(&data_info.problem_row_analysis));
+    }
+    sections.push(ReportSection::from(&data_info.provenance));
+    if let Some(throughput) = &data_info.throughput {
+        sections.push(ReportSection::from(throughput));
+    }
+
+    // The sections of the analyses skipped for running over their time budget only hold the
+    // timeout note.
+    if let Some(timeout) = data_info.analysis_timeout {
+        for analysis in &data_info.timed_out_analyses {
+            let title = analysis.to_string();
+            if let Some(section) = sections.iter_mut().find(|section| section.title == title) {
+                *section = ReportSection::timed_out(*analysis, timeout);
+            }
+        }
+    }
+
+    // The sections of the failed analyses only hold the failure note. The analyses without a
+    // section of their own, or whose section is left out without results, get it ahead of the
+    // provenance.
+    for failure in &data_info.failed_analyses {
+        if failure.column.is_some() {
+            continue;
+        }
+        let title = failure.analysis.to_string();
+        match sections.iter_mut().find(|section| section.title == title) {
+            Some(section) => *section = ReportSection::unavailable(failure),
+            None => {
+                let index = sections
+                    .iter()
+                    .position(|section| section.title == "Provenance")
+                    .unwrap_or(sections.len());
+                sections.insert(index, ReportSection::unavailable(failure));
+            }
+        }
+    }
+
+    let narrative = Narrative::new(data_info);
+    for section in &mut sections {
+        let insights = narrative.section_insights(&section.title);
+        if !insights.is_empty() {
+            section.blocks.splice(
+                0..0,
+                [
+                    ReportBlock::Heading {
+                        text: "Key Insights".to_owned(),
+                    },
+                    ReportBlock::List {
+                        items: insights.to_vec(),
+                    },
+                ],
+            );
+        }
+    }
+    sections
+}
+
+/// Gets the plots of a group, keyed by title.
+fn plot_group(
+    visualizations: &Option<VisualizationManager>,
+    group: PlotGroup,
+) -> Option<&HashMap<String, PathBuf>> {
+    visualizations
+        .as_ref()
+        .and_then(|visualizations| visualizations.visualizations.get(&group))
+}
+
+/// Adds a note after a table that only lists the first rows, e.g. "... and 3 more.".
+fn push_remaining_note(section: &mut ReportSection, total: usize, n_listed: usize) {
+    if total > n_listed {
+        section.push(ReportBlock::Note {
+            text: format!("... and {} more.", total - n_listed),
+        });
+    }
+}
+
+/// Formats the statistics of each numerical feature for the descriptive analysis section: the
+/// classical statistics are dropped when only robust ones are shown, the robust statistics and
+/// shape diagnostics are added, and the statistics in the unit of the feature are formatted with
+/// it.
+///
+/// ### Parameters
+///
+/// - `descriptive_analysis`: The descriptive analysis results.
+/// - `robust_analysis`: The optional robust statistics.
+/// - `data_dictionary`: The optional data dictionary, with the units of the features.
+///
+/// ### Returns
+///
+/// - `Vec<(String, IndexMap<String, String>)>`: The name and formatted statistics of each
+///   numerical feature.
+fn numeric_feature_stats(
+    descriptive_analysis: &DescriptiveAnalysis,
+    robust_analysis: Option<&RobustAnalysis>,
+    data_dictionary: Option<&DataDictionary>,
+) -> Vec<(String, IndexMap<String, String>)> {
+    let analysis_values = descriptive_analysis
+        .column_stats
+        .get_analysis_values(
+            &descriptive_analysis.feature_indices,
+            &descriptive_analysis.column_map,
+        )
+        .unwrap_or_default();
+
+    let mut numeric_features = Vec::with_capacity(analysis_values.len());
+    for mut feature_stats in analysis_values {
+        let feature_name = feature_stats
+            .shift_remove("column_name")
+            .unwrap_or_default();
+        if let Some(robust_analysis) = robust_analysis {
+            if !robust_analysis.mode.shows_classical() {
+                for statistic in CLASSICAL_STATISTICS {
+                    feature_stats.shift_remove(statistic);
+                }
+            }
+            if let Some(stats) = robust_analysis.stats.get(&feature_name) {
+                feature_stats.extend([
+                    ("mad".to_owned(), stats.mad.to_string()),
+                    ("trimmed_min".to_owned(), stats.trimmed_min.to_string()),
+                    ("trimmed_max".to_owned(), stats.trimmed_max.to_string()),
+                    ("trimmed_mean".to_owned(), stats.trimmed_mean.to_string()),
+                ]);
+            }
+        }
+        // The shape diagnostics follow the skewness and kurtosis when they're shown.
+        if let Some(normality) = descriptive_analysis.normality.get(&feature_name) {
+            let index = feature_stats
+                .get_index_of("kurtosis")
+                .map_or(feature_stats.len(), |index| index + 1);
+            feature_stats.shift_insert(index, "normality_p".to_owned(), normality.format_p_value());
+            feature_stats.shift_insert(
+                index + 1,
+                "distribution".to_owned(),
+                normality.label.to_string(),
+            );
+        }
+        let definition = data_dictionary.and_then(|dictionary| dictionary.get(&feature_name));
+        if let Some((unit, detected)) = column_unit(&feature_name, definition) {
+            for statistic in UNIT_STATISTICS {
+                if let Some(value) = feature_stats.get_mut(statistic) {
+                    if let Ok(amount) = value.parse::<f64>() {
+                        *value = unit.format(amount);
+                    }
+                }
+            }
+            if detected {
+                feature_stats.insert(
+                    "unit".to_owned(),
+                    format!("{} (detected from the column name)", unit),
+                );
+            }
+        }
+        numeric_features.push((feature_name, feature_stats));
+    }
+    numeric_features
+}
+
+/// Adds the column overview table of the descriptive analysis section, with the kind, range or
+/// number of categories, and a distribution sparkline of each numerical and categorical feature.
+/// The numerical sparklines are the feature histograms and the categorical ones the frequencies of
+/// the most frequent categories.
+///
+/// ### Parameters
+///
+/// - `section`: The descriptive analysis section.
+/// - `descriptive_analysis`: The descriptive analysis results.
+/// - `numeric_features`: The name and formatted statistics of each numerical feature.
+fn push_column_overview(
+    section: &mut ReportSection,
+    descriptive_analysis: &DescriptiveAnalysis,
+    numeric_features: &[(String, IndexMap<String, String>)],
+) {
+    let mut rows = Vec::new();
+    let mut counts = Vec::new();
+    for (feature_name, feature_stats) in numeric_features {
+        let bound = |statistic: &str| {
+            feature_stats
+                .get(statistic)
+                .map(String::as_str)
+                .unwrap_or("null")
+        };
+        rows.push(vec![
+            feature_name.clone(),
+            "numerical".to_owned(),
+            format!("{} to {}", bound("min"), bound("max")),
+        ]);
+        counts.push(
+            descriptive_analysis
+                .sparklines
+                .get(feature_name)
+                .cloned()
+                .unwrap_or_default(),
+        );
+    }
+    for (feature_name, categorical_stats) in &descriptive_analysis.categorical_stats {
+        rows.push(vec![
+            feature_name.clone(),
+            "categorical".to_owned(),
+            format!("{} categories", categorical_stats.n_unique),
+        ]);
+        counts.push(
+            categorical_stats
+                .top_frequencies
+                .iter()
+                .map(|(_, frequency)| *frequency)
+                .collect(),
+        );
+    }
+    if rows.is_empty() {
+        return;
+    }
+
+    section.push(ReportBlock::Heading {
+        text: "Column Overview".to_owned(),
+    });
+    section.push(ReportBlock::SparklineTable {
+        headers: ["Column", "Kind", "Range", "Distribution"]
+            .into_iter()
+            .map(str::to_owned)
+            .collect(),
+        rows,
+        counts,
+    });
+}
+
+/// Adds the wide mode summary tables of the descriptive analysis section, with a row per feature
+/// instead of a statistic block: the numerical, categorical, list and array, and binary features
+/// each get a table of their key statistics.
+///
+/// ### Parameters
+///
+/// - `section`: The descriptive analysis section.
+/// - `descriptive_analysis`: The descriptive analysis results.
+/// - `numeric_features`: The name and formatted statistics of each numerical feature.
+fn push_compact_summaries(
+    section: &mut ReportSection,
+    descriptive_analysis: &DescriptiveAnalysis,
+    numeric_features: &[(String, IndexMap<String, String>)],
+) {
+    section.push(ReportBlock::Note {
+        text: "Wide mode: each feature is summarized in a single table row instead of a \
+               statistic block."
+            .to_owned(),
+    });
+    let mut push_table =
+        |title: &str, headers: &[&str], rows: Vec<Vec<String>>, data_columns: Vec<usize>| {
+            if rows.is_empty() {
+                return;
+            }
+            section.push(ReportBlock::Heading {
+                text: title.to_owned(),
+            });
+            section.push(ReportBlock::Table {
+                headers: headers.iter().map(|header| header.to_string()).collect(),
+                rows,
+                data_columns,
+                highlighted_rows: Vec::new(),
+            });
+        };
+
+    push_table(
+        "Numerical Features",
+        &[
+            "Feature", "Count", "Mean", "Median", "Std Dev", "Min", "Max",
+        ],
+        numeric_features
+            .iter()
+            .map(|(feature_name, feature_stats)| {
+                let mut row = vec![feature_name.clone()];
+                row.extend(
+                    ["count", "mean", "median", "std_dev", "min", "max"]
+                        .into_iter()
+                        .map(|statistic| {
+                            feature_stats
+                                .get(statistic)
+                                .map_or("-".to_owned(), |value| compact_number(value))
+                        }),
+                );
+                row
+            })
+            .collect(),
+        Vec::new(),
+    );
+    push_table(
+        "Categorical Features",
+        &["Feature", "Count", "Unique", "Mode", "Mode Count"],
+        descriptive_analysis
+            .categorical_stats
+            .iter()
+            .map(|(feature_name, categorical_stats)| {
+                let (mode, mode_count) = categorical_stats
+                    .mode()
+                    .map_or(("-".to_owned(), "-".to_owned()), |(mode, mode_count)| {
+                        (truncate_example(mode), mode_count.to_string())
+                    });
+                vec![
+                    feature_name.clone(),
+                    categorical_stats.count.to_string(),
+                    categorical_stats.n_unique.to_string(),
+                    mode,
+                    mode_count,
+                ]
+            })
+            .collect(),
+        vec![3],
+    );
+    push_table(
+        "List and Array Features",
+        &["Feature", "Inner Type", "Count", "Mean Length", "Empty"],
+        descriptive_analysis
+            .nested_stats
+            .iter()
+            .map(|(feature_name, nested_stats)| {
+                vec![
+                    feature_name.clone(),
+                    truncate_example(&nested_stats.inner_type.to_string()),
+                    nested_stats.count.to_string(),
+                    nested_stats
+                        .mean_length
+                        .map_or("-".to_owned(), |mean| format!("{:.2}", mean)),
+                    format!("{:.2}%", nested_stats.empty_percentage),
+                ]
+            })
+            .collect(),
+        Vec::new(),
+    );
+    push_table(
+        "Binary Features",
+        &["Feature", "Count", "Mean Bytes", "Total Bytes", "Empty"],
+        descriptive_analysis
+            .binary_stats
+            .iter()
+            .map(|(feature_name, binary_stats)| {
+                vec![
+                    feature_name.clone(),
+                    binary_stats.count.to_string(),
+                    binary_stats
+                        .mean_bytes
+                        .map_or("-".to_owned(), |mean| format!("{:.2}", mean)),
+                    binary_stats.total_bytes.to_string(),
+                    binary_stats.empty_count.to_string(),
+                ]
+            })
+            .collect(),
+        Vec::new(),
+    );
+}
+
+/// Formats the significant lags of an autocorrelation, listing up to `MAX_LISTED_LAGS` of them.
+#[cfg(feature = "time-series")]
+fn format_lags(lags: &[usize]) -> String {
+    const MAX_LISTED_LAGS: usize = 5;
+    if lags.is_empty() {
+        return "None".to_owned();
+    }
+    let listed = lags
+        .iter()
+        .take(MAX_LISTED_LAGS)
+        .map(|lag| lag.to_string())
+        .collect::<Vec<String>>()
+        .join(", ");
+    if lags.len() > MAX_LISTED_LAGS {
+        format!("{} (+{})", listed, lags.len() - MAX_LISTED_LAGS)
+    } else {
+        listed
+    }
+}
+
+/// Truncates an example value to `MAX_EXAMPLE_LENGTH` characters, on a single line.
+pub(crate) fn truncate_example(value: &str) -> String {
+    truncate_text(value, MAX_EXAMPLE_LENGTH)
+}
+
+/// Shortens a formatted statistic for a compact table cell: numbers are rounded to two decimals,
+/// or in scientific notation if they're very large or small, and other values are kept as is.
+fn compact_number(value: &str) -> String {
+    match value.parse::<f64>() {
+        Ok(number) if number.fract() == 0.0 && number.abs() < 1e9 => format!("{}", number),
+        Ok(number) if number != 0.0 && !(0.01..1e6).contains(&number.abs()) => {
+            format!("{:.2e}", number)
+        }
+        Ok(number) => format!("{:.2}", number),
+        Err(_) => truncate_example(value),
+    }
+}
+
+/// Truncates a value to a maximum number of characters, on a single line.
+pub(crate) fn truncate_text(value: &str, max_length: usize) -> String {
+    let value = value.replace(['\n', '\r', '\t'], " ");
+    if value.chars().count() > max_length {
+        let truncated: String = value.chars().take(max_length - 3).collect();
+        format!("{}...", truncated)
+    } else {
+        value
+    }
+}
+
+/// Formats the analyses a column opts out of as comma separated names.
+fn format_analyses(analyses: &[ColumnAnalysis]) -> String {
+    analyses
+        .iter()
+        .map(ColumnAnalysis::to_string)
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+/// Formats the example extreme values of a feature, whole numbers without decimals, or `-` if
+/// there are none.
+pub(crate) fn format_extreme_values(values: &[f64]) -> String {
+    if values.is_empty() {
+        return "-".to_owned();
+    }
+    values
+        .iter()
+        .map(|value| {
+            if value.fract() == 0.0 && value.abs() < 1e15 {
+                format!("{}", value)
+            } else {
+                format!("{:.4}", value)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Renders the sections as a standalone HTML page. Column names link to the column details, and
+/// a search box filters them when the sections include any column details.
+///
+/// ### Parameters
+///
+/// - `title`: The page title.
+/// - `sections`: The sections to render.
+/// - `locale`: The locale to format the numbers and dates of the sections in.
+///
+/// ### Returns
+///
+/// - `String`: The HTML page.
+pub fn render_html(title: &str, sections: &[ReportSection], locale: Locale) -> String {
+    let sections: Vec<ReportSection> = sections
+        .iter()
+        .map(|section| section.localize(locale))
+        .collect();
+    let mut output = String::new();
+    writeln!(output, "<!DOCTYPE html>").unwrap();
+    writeln!(output, "<html lang=\"en\">").unwrap();
+    writeln!(output, "<head>").unwrap();
+    writeln!(output, "<meta charset=\"utf-8\">").unwrap();
+    writeln!(output, "<title>{}</title>", escape_html(title)).unwrap();
+    writeln!(
+        output,
+        "<style>body {{ font-family: serif; max-width: 60em; margin: auto; }} \
+         table {{ border-collapse: collapse; }} th, td {{ padding: 0.2em 1em; text-align: left; }} \
+         th {{ border-bottom: 1px solid black; }} .highlighted {{ color: #c80000; \
+         font-weight: bold; }} img {{ max-width: 100%; }} .column-detail {{ border-top: 1px \
+         solid #ccc; }}</style>"
+    )
+    .unwrap();
+    writeln!(output, "</head>").unwrap();
+    writeln!(output, "<body>").unwrap();
+    writeln!(output, "<h1>{}</h1>", escape_html(title)).unwrap();
+
+    // The element IDs in use, so the anchors stay unique even if an ID matches another, e.g. for
+    // titles that only differ in case or punctuation.
+    let mut ids: HashSet<String> = HashSet::from(["column-filter".to_owned()]);
+    let section_ids: Vec<String> = sections
+        .iter()
+        .map(|section| {
+            let id = unique_id(&section.id, |id| ids.contains(id));
+            ids.insert(id.clone());
+            id
+        })
+        .collect();
+    // The anchor of each column with details.
+    let mut anchors: IndexMap<&str, String> = IndexMap::new();
+    for block in sections.iter().flat_map(|section| &section.blocks) {
+        if let ReportBlock::ColumnDetail { column, .. } = block {
+            if !anchors.contains_key(column.as_str()) {
+                let anchor = unique_id(&format!("column-{}", stable_id(column)), |id| {
+                    ids.contains(id)
+                });
+                ids.insert(anchor.clone());
+                anchors.insert(column, anchor);
+            }
+        }
+    }
+    if !anchors.is_empty() {
+        writeln!(
+            output,
+            "<input type=\"search\" id=\"column-filter\" placeholder=\"Filter columns\" \
+             aria-label=\"Filter columns\">"
+        )
+        .unwrap();
+    }
+
+    // The plots already anchored, as a plot drawn more than once is anchored at its first
+    // occurrence.
+    let mut anchored_plots: HashSet<&Path> = HashSet::new();
+    for (section, id) in sections.iter().zip(&section_ids) {
+        writeln!(output, "<section id=\"{}\">", escape_html(id)).unwrap();
+        writeln!(output, "<h2>{}</h2>", escape_html(&section.title)).unwrap();
+        for block in &section.blocks {
+            render_html_block(&mut output, block, &anchors, &mut ids, &mut anchored_plots);
+        }
+        writeln!(output, "</section>").unwrap();
+    }
+
+    if !anchors.is_empty() {
+        writeln!(output, "<script>{}</script>", COLUMN_FILTER_SCRIPT).unwrap();
+    }
+    writeln!(output, "</body>").unwrap();
+    writeln!(output, "</html>").unwrap();
+    output
+}
+
+/// Renders the sections as a pretty printed JSON array.
+///
+/// ### Parameters
+///
+/// - `sections`: The sections to render.
+///
+/// ### Returns
+///
+/// - `Result<String, SectionError>`: The JSON array or a serialization error.
+pub fn render_json(sections: &[ReportSection]) -> Result<String, SectionError> {
+    Ok(serde_json::to_string_pretty(sections)?)
+}
+
+/// Writes the sections to a file in the given format. The JSON sections are written as is, so
+/// they can be rendered again in any locale.
+///
+/// ### Parameters
+///
+/// - `title`: The report title.
+/// - `sections`: The sections to write.
+/// - `format`: The export format.
+/// - `locale`: The locale to format the numbers and dates of the HTML page in.
+/// - `path`: The output path.
+///
+/// ### Returns
+///
+/// - `Result<(), SectionError>`: Unit type or an IO or serialization error.
+pub fn save_to_file(
+    title: &str,
+    sections: &[ReportSection],
+    format: SectionFormat,
+    locale: Locale,
+    path: &Path,
+) -> Result<(), SectionError> {
+    let output = match format {
+        SectionFormat::Html => render_html(title, sections, locale),
+        SectionFormat::Json => render_json(sections)?,
+    };
+    std::fs::write(path, output)?;
+    Ok(())
+}
+
+/// Loads the sections from a JSON file written by `save_to_file`.
+///
+/// ### Parameters
+///
+/// - `path`: The path to the JSON file.
+///
+/// ### Returns
+///
+/// - `Result<Vec<ReportSection>, SectionError>`: The sections or an IO or deserialization error.
+pub fn load_from_file(path: &Path) -> Result<Vec<ReportSection>, SectionError> {
+    let contents = std::fs::read_to_string(path)?;
+    let mut sections: Vec<ReportSection> = serde_json::from_str(&contents)?;
+    // Sections exported before sections had IDs get the ID of their title.
+    for section in sections.iter_mut().filter(|section| section.id.is_empty()) {
+        section.id = stable_id(&section.title);
+    }
+    Ok(sections)
+}
+
+/// Renders a block as HTML, linking the column names in the first cell of the table rows to the
+/// anchors of their column details.
+fn render_html_block<'a>(
+    output: &mut String,
+    block: &'a ReportBlock,
+    anchors: &IndexMap<&str, String>,
+    ids: &mut HashSet<String>,
+    anchored_plots: &mut HashSet<&'a Path>,
+) {
+    match block {
+        ReportBlock::Heading { text } => {
+            writeln!(output, "<h3>{}</h3>", escape_html(text)).unwrap();
+        }
+        ReportBlock::Paragraph { text } => {
+            writeln!(output, "<p>{}</p>", escape_html(text)).unwrap();
+        }
+        ReportBlock::Note { text } => {
+            writeln!(output, "<p><em>{}</em></p>", escape_html(text)).unwrap();
+        }
+        ReportBlock::List { items } => {
+            writeln!(output, "<ul>").unwrap();
+            for item in items {
+                writeln!(output, "<li>{}</li>", escape_html(item)).unwrap();
+            }
+            writeln!(output, "</ul>").unwrap();
+        }
+        ReportBlock::Table {
+            headers,
+            rows,
+            highlighted_rows,
+            ..
+        } => {
+            writeln!(output, "<table>").unwrap();
+            write!(output, "<tr>").unwrap();
+            for header in headers {
+                write!(output, "<th>{}</th>", escape_html(header)).unwrap();
+            }
+            writeln!(output, "</tr>").unwrap();
+            for (i, row) in rows.iter().enumerate() {
+                let anchor = row.first().and_then(|column| anchors.get(column.as_str()));
+                write!(output, "<tr").unwrap();
+                if highlighted_rows.contains(&i) {
+                    write!(output, " class=\"highlighted\"").unwrap();
+                }
+                if anchor.is_some() {
+                    write!(output, " data-column=\"{}\"", escape_html(&row[0])).unwrap();
+                }
+                write!(output, ">").unwrap();
+                for (j, value) in row.iter().enumerate() {
+                    match anchor {
+                        Some(anchor) if j == 0 => write!(
+                            output,
+                            "<td><a href=\"#{}\">{}</a></td>",
+                            escape_html(anchor),
+                            escape_html(value)
+                        )
+                        .unwrap(),
+                        _ => write!(output, "<td>{}</td>", escape_html(value)).unwrap(),
+                    }
+                }
+                writeln!(output, "</tr>").unwrap();
+            }
+            writeln!(output, "</table>").unwrap();
+        }
+        ReportBlock::SparklineTable {
+            headers,
+            rows,
+            counts,
+        } => {
+            writeln!(output, "<table>").unwrap();
+            write!(output, "<tr>").unwrap();
+            for header in headers {
+                write!(output, "<th>{}</th>", escape_html(header)).unwrap();
+            }
+            writeln!(output, "</tr>").unwrap();
+            for (row, counts) in rows.iter().zip(counts) {
+                let anchor = row.first().and_then(|column| anchors.get(column.as_str()));
+                match anchor {
+                    Some(_) => write!(output, "<tr data-column=\"{}\">", escape_html(&row[0])),
+                    None => write!(output, "<tr>"),
+                }
+                .unwrap();
+                for (j, value) in row.iter().enumerate() {
+                    match anchor {
+                        Some(anchor) if j == 0 => write!(
+                            output,
+                            "<td><a href=\"#{}\">{}</a></td>",
+                            escape_html(anchor),
+                            escape_html(value)
+                        )
+                        .unwrap(),
+                        _ => write!(output, "<td>{}</td>", escape_html(value)).unwrap(),
+                    }
+                }
+                writeln!(output, "<td>{}</td></tr>", sparkline_svg(counts)).unwrap();
+            }
+            writeln!(output, "</table>").unwrap();
+        }
+        ReportBlock::KeyValues {
+            title,
+            values,
+            definition,
+            ..
+        } => {
+            writeln!(output, "<h4>{}</h4>", escape_html(title)).unwrap();
+            if let Some(description) = definition
+                .as_ref()
+                .and_then(|definition| definition.description.as_ref())
+            {
+                writeln!(output, "<p><em>{}</em></p>", escape_html(description)).unwrap();
+            }
+            if let Some(details) = definition.as_ref().and_then(ColumnDefinition::details) {
+                writeln!(output, "<p>{}</p>", escape_html(&details)).unwrap();
+            }
+            writeln!(output, "<table>").unwrap();
+            for (name, value) in values {
+                writeln!(
+                    output,
+                    "<tr><th>{}</th><td>{}</td></tr>",
+                    escape_html(name),
+                    escape_html(value)
+                )
+                .unwrap();
+            }
+            writeln!(output, "</table>").unwrap();
+        }
+        ReportBlock::Image(plot) => render_html_image(output, plot, ids, anchored_plots),
+        ReportBlock::ColumnDetail {
+            column,
+            values,
+            images,
+            ..
+        } => {
+            writeln!(
+                output,
+                "<div class=\"column-detail\" id=\"{}\" data-column=\"{}\">",
+                escape_html(anchors.get(column.as_str()).map_or("", String::as_str)),
+                escape_html(column)
+            )
+            .unwrap();
+            writeln!(output, "<h3>{}</h3>", escape_html(column)).unwrap();
+            writeln!(output, "<table>").unwrap();
+            for (name, value) in values {
+                writeln!(
+                    output,
+                    "<tr><th>{}</th><td>{}</td></tr>",
+                    escape_html(name),
+                    escape_html(value)
+                )
+                .unwrap();
+            }
+            writeln!(output, "</table>").unwrap();
+            for plot in images {
+                render_html_image(output, plot, ids, anchored_plots);
+            }
+            writeln!(output, "</div>").unwrap();
+        }
+    }
+}
+
+/// Renders an image as HTML, loaded only once it's scrolled near. A plot drawn more than once is
+/// anchored at its first occurrence.
+fn render_html_image<'a>(
+    output: &mut String,
+    plot: &'a Plot,
+    ids: &mut HashSet<String>,
+    anchored_plots: &mut HashSet<&'a Path>,
+) {
+    write!(output, "<img").unwrap();
+    if anchored_plots.insert(&plot.path) {
+        let id = unique_id(&plot.id, |id| ids.contains(id));
+        write!(output, " id=\"{}\"", escape_html(&id)).unwrap();
+        ids.insert(id);
+    }
+    writeln!(
+        output,
+        " src=\"{}\" alt=\"{}\" loading=\"lazy\">",
+        escape_html(&plot.path.to_string_lossy()),
+        escape_html(&image_alt_text(&plot.path))
+    )
+    .unwrap();
+}
+
+/// Renders the counts of a distribution sparkline as an inline SVG bar chart, scaled to the
+/// largest count.
+fn sparkline_svg(counts: &[u64]) -> String {
+    let max_count = counts.iter().copied().max().unwrap_or(0);
+    let mut svg = format!(
+        "<svg width=\"80\" height=\"14\" viewBox=\"0 0 {} 1\" preserveAspectRatio=\"none\">",
+        counts.len().max(1)
+    );
+    if max_count > 0 {
+        for (i, count) in counts.iter().enumerate().filter(|(_, count)| **count > 0) {
+            let height = *count as f64 / max_count as f64;
+            write!(
+                svg,
+                "<rect x=\"{}.1\" y=\"{:.3}\" width=\"0.8\" height=\"{:.3}\" fill=\"#4682b4\"/>",
+                i,
+                1.0 - height,
+                height
+            )
+            .unwrap();
+        }
+    }
+    svg.push_str("</svg>");
+    svg
+}
+
+/// Derives a stable ID from a section title or any other name, for anchors and named
+/// destinations. ASCII letters are lowercased, other letters and digits are written as their
+/// code point (e.g. `u00e9`), and every run of other characters becomes a single `-`. For
+/// example, `Top Foreign Values: country` becomes `top-foreign-values-country`.
+///
+/// ### Parameters
+///
+/// - `name`: The name to derive the ID from.
+///
+/// ### Returns
+///
+/// - `String`: The ID, only made of `[a-z0-9-]`.
+pub fn stable_id(name: &str) -> String {
+    let mut id = String::with_capacity(name.len());
+    for c in name.chars() {
+        if c.is_ascii_alphanumeric() {
+            id.push(c.to_ascii_lowercase());
+        } else if c.is_alphanumeric() {
+            write!(id, "u{:04x}", c as u32).unwrap();
+        } else if !id.is_empty() && !id.ends_with('-') {
+            id.push('-');
+        }
+    }
+    if id.ends_with('-') {
+        id.pop();
+    }
+    id
+}
+
+/// Makes an ID unique by suffixing it with `-2`, `-3`, ... until it's no longer taken, as
+/// `stable_id` maps names that only differ in case or punctuation to the same ID.
+///
+/// ### Parameters
+///
+/// - `id`: The ID to make unique.
+/// - `is_taken`: Whether an ID is already in use.
+///
+/// ### Returns
+///
+/// - `String`: The ID, or the first suffixed ID that isn't taken.
+pub fn unique_id(id: &str, is_taken: impl Fn(&str) -> bool) -> String {
+    let mut unique = id.to_owned();
+    let mut n = 1;
+    while is_taken(&unique) {
+        n += 1;
+        unique = format!("{}-{}", id, n);
+    }
+    unique
+}
+
+/// Derives the stable ID of a plot from its file name, e.g. `plot-age-outliers` for
+/// `age_outliers.png`. The embedded and exported copies of a plot share their ID.
+pub fn plot_id(path: &Path) -> String {
+    let name = path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy())
+        .unwrap_or_default();
+    format!("plot-{}", stable_id(&name))
+}
+
+/// Escapes the HTML special characters of a text.
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -0,0 +1,667 @@
+//! # Snippets Module
+//!
+//! Generates ready-to-copy Python code for loading the dataset with the same options LEADS used to
+//! read it (separator, quoting, comments, skipped lines, headers, column data types, and null
+//! tokens, or the database query), so
+//! readers can jump straight into deeper analysis in Polars or pandas. The data type overrides of
+//! formats read with their own types (Excel, Parquet, and JSON) are applied as casts after reading.
+//! Inputs transcoded from another encoding are decoded with it: pandas readers take the encoding,
+//! while the Polars readers are given the contents decoded by Python.
+
+use crate::data::base::{
+    format_extension, Compression, ExcelSheet, FixedWidthSpec, InputFormat, STDIN_PATH,
+};
+use crate::prelude::DataInfo;
+use polars::datatypes::{DataType, TimeUnit};
+use std::path::Path;
+
+/// Values the reader always treats as missing in delimited files, matching the Polars CSV reader
+/// defaults (only empty fields are read as null). The `--null-value` tokens are added to them.
+pub const NULL_TOKENS: [&str; 1] = [""];
+
+/// Generates the Polars code for loading the dataset.
+///
+/// ### Parameters
+///
+/// - `data_info`: The dataset information.
+///
+/// ### Returns
+///
+/// - `String`: The Python code.
+pub fn polars_snippet(data_info: &DataInfo) -> String {
+    let path = python_source(data_info);
+    let text_source = polars_text_source(data_info);
+    let mut lines = imports(data_info, "import polars as pl");
+    lines.extend(query_note(data_info));
+    lines.extend(filter_note(data_info));
+    lines.extend(prep_note(data_info));
+
+    if let Some(query) = &data_info.query {
+        lines.extend(password_note(data_info));
+        lines.push(format!(
+            "df = pl.read_database_uri({}, {})",
+            python_string(query),
+            path
+        ));
+        return lines.join("\n");
+    }
+
+    if is_excel(data_info) {
+        let mut arguments = vec![path];
+        match &data_info.sheet {
+            Some(ExcelSheet::Name(name)) => {
+                arguments.push(format!("sheet_name={}", python_string(name)))
+            }
+            // Polars sheet IDs are one-based.
+            Some(ExcelSheet::Index(index)) => arguments.push(format!("sheet_id={}", index + 1)),
+            None => {}
+        }
+        let mut read_options = Vec::new();
+        if !data_info.headers {
+            read_options.push("\"has_header\": False".to_owned());
+        }
+        if data_info.skip_rows > 0 {
+            read_options.push(format!("\"skip_rows\": {}", data_info.skip_rows));
+        }
+        if !read_options.is_empty() {
+            arguments.push(format!("read_options={{{}}}", read_options.join(", ")));
+        }
+        lines.push(format!("df = pl.read_excel({})", arguments.join(", ")));
+        lines.extend(polars_casts(data_info));
+        return lines.join("\n");
+    }
+
+    if let Some(spec) = fixed_width_spec(data_info) {
+        // Polars has no fixed-width reader, so the lines are read whole and sliced into columns.
+        lines.push("df = pl.read_csv(".to_owned());
+        lines.push(format!("    {},", text_source));
+        lines.push("    has_header=False,".to_owned());
+        lines.push("    new_columns=[\"line\"],".to_owned());
+        lines.push("    separator=\"\\x1f\",".to_owned());
+        lines.push("    quote_char=None,".to_owned());
+        let skip_rows = data_info.skip_rows + usize::from(data_info.headers);
+        if skip_rows > 0 {
+            lines.push(format!("    skip_rows={},", skip_rows));
+        }
+        lines.push(").select(".to_owned());
+        for column in &spec.columns {
+            let dtype = match data_info.column_types.get(&column.name) {
+                Some(data_type) if !data_info.parsed_dates.contains_key(&column.name) => {
+                    polars_dtype(data_type)
+                }
+                _ => polars_dtype(&DataType::String),
+            };
+            lines.push(format!(
+                "    pl.col(\"line\").str.slice({}, {}).str.strip_chars().cast({}, strict=False).alias({}),",
+                column.start,
+                column.width,
+                dtype,
+                python_string(&column.name)
+            ));
+        }
+        lines.push(")".to_owned());
+        lines.extend(polars_date_parsing(data_info));
+        return lines.join("\n");
+    }
+
+    match separator(data_info) {
+        Some(separator) => {
+            lines.push("df = pl.read_csv(".to_owned());
+            lines.push(format!("    {},", text_source));
+            lines.push(format!("    separator={},", python_string(&separator)));
+            match data_info.csv.quote_char {
+                Some(b'"') => {}
+                Some(quote_char) => lines.push(format!(
+                    "    quote_char={},",
+                    python_string(&char::from(quote_char).to_string())
+                )),
+                None => lines.push("    quote_char=None,".to_owned()),
+            }
+            if let Some(comment_char) = data_info.csv.comment_char {
+                lines.push(format!(
+                    "    comment_prefix={},",
+                    python_string(&char::from(comment_char).to_string())
+                ));
+            }
+            if data_info.skip_rows > 0 {
+                lines.push(format!("    skip_rows={},", data_info.skip_rows));
+            }
+            if data_info.csv.low_memory {
+                lines.push("    low_memory=True,".to_owned());
+            }
+            lines.push(format!(
+                "    has_header={},",
+                python_bool(data_info.headers)
+            ));
+            lines.push(format!(
+                "    null_values={},",
+                python_list(&null_tokens(data_info))
+            ));
+            lines.push("    schema_overrides={".to_owned());
+            for (column, data_type) in &data_info.column_types {
+                // Parsed date columns are read as strings and parsed below.
+                let dtype = if data_info.parsed_dates.contains_key(column) {
+                    polars_dtype(&DataType::String)
+                } else {
+                    polars_dtype(data_type)
+                };
+                lines.push(format!("        {}: {},", python_string(column), dtype));
+            }
+            lines.push("    },".to_owned());
+            lines.push(")".to_owned());
+            lines.extend(polars_date_parsing(data_info));
+        }
+        None => {
+            // Only CSV, TSV, and JSON files are read compressed.
+            if is_compressed(data_info) {
+                lines.push(
+                    "# Polars only decompresses CSV files, decompress the file first.".to_owned(),
+                );
+            }
+            lines.push(match format(data_info) {
+                Some(InputFormat::Json) => format!("df = pl.read_json({})", text_source),
+                Some(InputFormat::Ndjson) => format!("df = pl.read_ndjson({})", text_source),
+                _ => format!("df = pl.read_parquet({})", path),
+            });
+            lines.extend(polars_casts(data_info));
+        }
+    }
+
+    lines.join("\n")
+}
+
+/// Generates the pandas code for loading the dataset.
+///
+/// ### Parameters
+///
+/// - `data_info`: The dataset information.
+///
+/// ### Returns
+///
+/// - `String`: The Python code.
+pub fn pandas_snippet(data_info: &DataInfo) -> String {
+    let path = python_source(data_info);
+    let mut lines = imports(data_info, "import pandas as pd");
+    lines.extend(query_note(data_info));
+    lines.extend(filter_note(data_info));
+    lines.extend(prep_note(data_info));
+
+    if let Some(query) = &data_info.query {
+        lines.extend(password_note(data_info));
+        lines.push(format!(
+            "df = pd.read_sql({}, {})",
+            python_string(query),
+            path
+        ));
+        return lines.join("\n");
+    }
+
+    if is_excel(data_info) {
+        let mut arguments = vec![path];
+        match &data_info.sheet {
+            Some(ExcelSheet::Name(name)) => {
+                arguments.push(format!("sheet_name={}", python_string(name)))
+            }
+            Some(ExcelSheet::Index(index)) => arguments.push(format!("sheet_name={}", index)),
+            None => {}
+        }
+        if data_info.skip_rows > 0 {
+            arguments.push(format!("skiprows={}", data_info.skip_rows));
+        }
+        if !data_info.headers {
+            arguments.push("header=None".to_owned());
+        }
+        lines.push(format!("df = pd.read_excel({})", arguments.join(", ")));
+        lines.extend(pandas_casts(data_info));
+        return lines.join("\n");
+    }
+
+    if let Some(spec) = fixed_width_spec(data_info) {
+        let colspecs: Vec<String> = spec
+            .columns
+            .iter()
+            .map(|column| format!("({}, {})", column.start, column.start + column.width))
+            .collect();
+        let names: Vec<&str> = spec
+            .columns
+            .iter()
+            .map(|column| column.name.as_str())
+            .collect();
+        lines.push("df = pd.read_fwf(".to_owned());
+        lines.push(format!("    {},", path));
+        lines.extend(pandas_encoding(data_info));
+        lines.push(format!("    colspecs=[{}],", colspecs.join(", ")));
+        lines.push(format!("    names={},", python_list(&names)));
+        lines.push("    header=None,".to_owned());
+        let skip_rows = data_info.skip_rows + usize::from(data_info.headers);
+        if skip_rows > 0 {
+            lines.push(format!("    skiprows={},", skip_rows));
+        }
+        lines.push(format!(
+            "    na_values={},",
+            python_list(&null_tokens(data_info))
+        ));
+        lines.push("    keep_default_na=False,".to_owned());
+        lines.push("    dtype={".to_owned());
+        for (column, data_type) in &data_info.column_types {
+            if data_type.is_temporal() || data_info.parsed_dates.contains_key(column) {
+                continue;
+            }
+            if let Some(dtype) = pandas_dtype(data_type) {
+                lines.push(format!("        {}: {},", python_string(column), dtype));
+            }
+        }
+        lines.push("    },".to_owned());
+        lines.push(")".to_owned());
+        lines.extend(pandas_casts(data_info));
+        return lines.join("\n");
+    }
+
+    match separator(data_info) {
+        Some(separator) => {
+            // Temporal columns are parsed with `parse_dates` rather than a dtype.
+            // Parsed date columns are read as strings and parsed below.
+            let (date_columns, typed_columns): (Vec<_>, Vec<_>) = data_info
+                .column_types
+                .iter()
+                .filter(|(column, _)| !data_info.parsed_dates.contains_key(*column))
+                .partition(|(_, data_type)| data_type.is_temporal());
+
+            lines.push("df = pd.read_csv(".to_owned());
+            lines.push(format!("    {},", path));
+            lines.extend(pandas_encoding(data_info));
+            lines.push(format!("    sep={},", python_string(&separator)));
+            match data_info.csv.quote_char {
+                Some(b'"') => {}
+                Some(quote_char) => lines.push(format!(
+                    "    quotechar={},",
+                    python_string(&char::from(quote_char).to_string())
+                )),
+                None => lines.push("    quoting=3,  # csv.QUOTE_NONE".to_owned()),
+            }
+            if let Some(comment_char) = data_info.csv.comment_char {
+                lines.push(format!(
+                    "    comment={},",
+                    python_string(&char::from(comment_char).to_string())
+                ));
+            }
+            if data_info.skip_rows > 0 {
+                lines.push(format!("    skiprows={},", data_info.skip_rows));
+            }
+            lines.push(format!(
+                "    header={},",
+                if data_info.headers { "0" } else { "None" }
+            ));
+            if !data_info.headers {
+                // Match the generated column names so the dtypes below line up.
+                let names: Vec<&str> = data_info.column_types.keys().map(String::as_str).collect();
+                lines.push(format!("    names={},", python_list(&names)));
+            }
+            lines.push(format!(
+                "    na_values={},",
+                python_list(&null_tokens(data_info))
+            ));
+            lines.push("    keep_default_na=False,".to_owned());
+            lines.push("    dtype={".to_owned());
+            for (column, data_type) in typed_columns {
+                if let Some(dtype) = pandas_dtype(data_type) {
+                    lines.push(format!("        {}: {},", python_string(column), dtype));
+                }
+            }
+            lines.push("    },".to_owned());
+            if !date_columns.is_empty() {
+                let names: Vec<&str> = date_columns.iter().map(|(name, _)| name.as_str()).collect();
+                lines.push(format!("    parse_dates={},", python_list(&names)));
+            }
+            lines.push(")".to_owned());
+            lines.extend(pandas_date_parsing(data_info));
+        }
+        None => {
+            lines.push(match format(data_info) {
+                Some(InputFormat::Json) => format!(
+                    "df = pd.read_json({}{})",
+                    path,
+                    pandas_encoding_argument(data_info)
+                ),
+                Some(InputFormat::Ndjson) => format!(
+                    "df = pd.read_json({}, lines=True{})",
+                    path,
+                    pandas_encoding_argument(data_info)
+                ),
+                _ => format!("df = pd.read_parquet({})", path),
+            });
+            lines.extend(pandas_casts(data_info));
+        }
+    }
+
+    lines.join("\n")
+}
+
+/// Gets a comment asking for the password of a database connection string, which is redacted
+/// from the report.
+fn password_note(data_info: &DataInfo) -> Option<String> {
+    data_info
+        .source_path
+        .to_string_lossy()
+        .contains(":***@")
+        .then(|| "# Replace *** with the database password.".to_owned())
+}
+
+/// Gets the Polars code casting the columns with a data type override, if any.
+fn polars_casts(data_info: &DataInfo) -> Vec<String> {
+    let mut lines = Vec::new();
+    if !data_info.dtype_overrides.is_empty() {
+        lines.push("df = df.with_columns(".to_owned());
+        for dtype_override in &data_info.dtype_overrides {
+            lines.push(format!(
+                "    pl.col({}).cast({}, strict=False),",
+                python_string(&dtype_override.column),
+                polars_dtype(&dtype_override.dtype)
+            ));
+        }
+        lines.push(")".to_owned());
+    }
+    lines.extend(polars_date_parsing(data_info));
+    lines
+}
+
+/// Gets the Polars code parsing the string columns detected as dates, if any. Polars uses the
+/// chrono formats as is.
+fn polars_date_parsing(data_info: &DataInfo) -> Vec<String> {
+    if data_info.parsed_dates.is_empty() {
+        return Vec::new();
+    }
+    let mut lines = vec!["df = df.with_columns(".to_owned()];
+    for (column, format) in &data_info.parsed_dates {
+        let function = match data_info.column_types.get(column) {
+            Some(DataType::Date) => "to_date",
+            _ => "to_datetime",
+        };
+        lines.push(format!(
+            "    pl.col({}).str.{}({}, strict=False),",
+            python_string(column),
+            function,
+            python_string(format)
+        ));
+    }
+    lines.push(")".to_owned());
+    lines
+}
+
+/// Gets the pandas code parsing the string columns detected as dates, if any.
+fn pandas_date_parsing(data_info: &DataInfo) -> Vec<String> {
+    data_info
+        .parsed_dates
+        .iter()
+        .map(|(column, format)| {
+            let column = python_string(column);
+            // strftime has no optional fraction of a second, so whole seconds are assumed.
+            let format = python_string(&format.replace("%.f", ""));
+            format!(
+                "df[{}] = pd.to_datetime(df[{}], format={}, errors=\"coerce\")",
+                column, column, format
+            )
+        })
+        .collect()
+}
+
+/// Gets the pandas code casting the columns with a data type override, if any.
+fn pandas_casts(data_info: &DataInfo) -> Vec<String> {
+    // Temporal columns are converted with `to_datetime` rather than a dtype.
+    let (date_overrides, typed_overrides): (Vec<_>, Vec<_>) = data_info
+        .dtype_overrides
+        .iter()
+        .partition(|dtype_override| dtype_override.dtype.is_temporal());
+
+    let mut lines = Vec::new();
+    let typed_columns: Vec<String> = typed_overrides
+        .iter()
+        .filter_map(|dtype_override| {
+            pandas_dtype(&dtype_override.dtype)
+                .map(|dtype| format!("    {}: {},", python_string(&dtype_override.column), dtype))
+        })
+        .collect();
+    if !typed_columns.is_empty() {
+        lines.push("df = df.astype({".to_owned());
+        lines.extend(typed_columns);
+        lines.push("})".to_owned());
+    }
+    for dtype_override in date_overrides {
+        let column = python_string(&dtype_override.column);
+        lines.push(format!(
+            "df[{}] = pd.to_datetime(df[{}], errors=\"coerce\")",
+            column, column
+        ));
+    }
+    lines.extend(pandas_date_parsing(data_info));
+    lines
+}
+
+/// Gets a comment with the SQL query the report data is the result of. The query isn't
+/// reproduced, and the data types below are the ones of its result.
+fn query_note(data_info: &DataInfo) -> Option<String> {
+    data_info.source_query.as_ref().map(|query| {
+        format!(
+            "# The report covers the result of the SQL query `{}`, not reproduced here, so the \
+             data types below are the ones of its result.",
+            query
+        )
+    })
+}
+
+/// Gets a comment with the row filter the report data was restricted with, which the snippets
+/// read all rows without.
+fn filter_note(data_info: &DataInfo) -> Option<String> {
+    data_info
+        .row_filter
+        .as_ref()
+        .map(|row_filter| format!("# The report only covers the rows matching: {}", row_filter))
+}
+
+/// Gets a comment noting the prep steps applied to the report data. The steps aren't reproduced,
+/// and the data types below are the ones after the prep.
+fn prep_note(data_info: &DataInfo) -> Option<String> {
+    (data_info.prep_steps > 0).then(|| {
+        format!(
+            "# The report data went through {} prep step(s) not reproduced here, so the data \
+             types below are the prepared ones.",
+            data_info.prep_steps
+        )
+    })
+}
+
+/// Gets the separator used to read the dataset, or None for non-delimited formats.
+fn separator(data_info: &DataInfo) -> Option<String> {
+    let default = match format(data_info) {
+        Some(InputFormat::Csv) => b',',
+        Some(InputFormat::Tsv) => b'\t',
+        _ => return None,
+    };
+    Some(char::from(data_info.csv.delimiter.unwrap_or(default)).to_string())
+}
+
+/// Gets the values read as missing in the delimited dataset.
+fn null_tokens(data_info: &DataInfo) -> Vec<&str> {
+    NULL_TOKENS
+        .into_iter()
+        .chain(data_info.csv.null_values.iter().map(String::as_str))
+        .collect()
+}
+
+/// Gets the column layout of the dataset if it was read from a fixed-width file.
+fn fixed_width_spec(data_info: &DataInfo) -> Option<&FixedWidthSpec> {
+    (format(data_info) == Some(InputFormat::FixedWidth))
+        .then_some(data_info.fixed_width.as_ref())
+        .flatten()
+}
+
+/// Checks whether the dataset was read from an Excel workbook.
+fn is_excel(data_info: &DataInfo) -> bool {
+    format(data_info) == Some(InputFormat::Excel)
+}
+
+/// Gets the format the dataset was read as, given explicitly or detected from the extension of the
+/// dataset path (without the compression extension).
+fn format(data_info: &DataInfo) -> Option<InputFormat> {
+    data_info
+        .format
+        .or_else(|| format_extension(&data_info.source_path).and_then(InputFormat::from_extension))
+}
+
+/// Checks whether the dataset was read from the standard input.
+fn is_stdin(data_info: &DataInfo) -> bool {
+    data_info.query.is_none() && data_info.source_path == Path::new(STDIN_PATH)
+}
+
+/// Gets the Python expression for the source of the dataset: the quoted path, or the standard
+/// input stream.
+fn python_source(data_info: &DataInfo) -> String {
+    if is_stdin(data_info) {
+        "sys.stdin.buffer".to_owned()
+    } else {
+        python_string(&data_info.source_path.to_string_lossy())
+    }
+}
+
+/// Gets the Python expression for the source of a Polars text reader: the source itself for
+/// UTF-8 inputs, or its contents decoded from their encoding and re-encoded as UTF-8.
+fn polars_text_source(data_info: &DataInfo) -> String {
+    let path = python_source(data_info);
+    match data_info.encoding {
+        Some(encoding) if is_stdin(data_info) => format!(
+            "{}.read().decode({}).encode()",
+            path,
+            python_string(&encoding.to_string())
+        ),
+        Some(encoding) => format!(
+            "open({}, encoding={}).read().encode()",
+            path,
+            python_string(&encoding.to_string())
+        ),
+        None => path,
+    }
+}
+
+/// Gets the `encoding` argument line of a pandas reader, for inputs that weren't UTF-8.
+fn pandas_encoding(data_info: &DataInfo) -> Option<String> {
+    data_info
+        .encoding
+        .map(|encoding| format!("    encoding={},", python_string(&encoding.to_string())))
+}
+
+/// Gets the `encoding` argument of a single-line pandas reader call, for inputs that weren't
+/// UTF-8.
+fn pandas_encoding_argument(data_info: &DataInfo) -> String {
+    data_info
+        .encoding
+        .map(|encoding| format!(", encoding={}", python_string(&encoding.to_string())))
+        .unwrap_or_default()
+}
+
+/// Gets the import lines of a snippet, followed by a blank line.
+fn imports(data_info: &DataInfo, import: &str) -> Vec<String> {
+    let mut lines = Vec::with_capacity(3);
+    if is_stdin(data_info) {
+        lines.push("import sys".to_owned());
+    }
+    lines.push(import.to_owned());
+    lines.push(String::new());
+    lines
+}
+
+/// Checks whether the dataset was read from a gzip or zstd compressed file.
+fn is_compressed(data_info: &DataInfo) -> bool {
+    Compression::from_path(&data_info.source_path).is_some()
+}
+
+/// Maps a Polars data type to its Python Polars equivalent.
+fn polars_dtype(data_type: &DataType) -> String {
+    match data_type {
+        DataType::Boolean => "pl.Boolean".to_owned(),
+        DataType::Int8 => "pl.Int8".to_owned(),
+        DataType::Int16 => "pl.Int16".to_owned(),
+        DataType::Int32 => "pl.Int32".to_owned(),
+        DataType::Int64 => "pl.Int64".to_owned(),
+        DataType::UInt8 => "pl.UInt8".to_owned(),
+        DataType::UInt16 => "pl.UInt16".to_owned(),
+        DataType::UInt32 => "pl.UInt32".to_owned(),
+        DataType::UInt64 => "pl.UInt64".to_owned(),
+        DataType::Float32 => "pl.Float32".to_owned(),
+        DataType::Float64 => "pl.Float64".to_owned(),
+        DataType::Decimal(precision, scale) => format!(
+            "pl.Decimal({}, {})",
+            precision.map_or("None".to_owned(), |p| p.to_string()),
+            scale.map_or("None".to_owned(), |s| s.to_string())
+        ),
+        DataType::Date => "pl.Date".to_owned(),
+        DataType::Datetime(time_unit, _) => {
+            format!("pl.Datetime({})", python_string(time_unit_str(time_unit)))
+        }
+        DataType::Duration(time_unit) => {
+            format!("pl.Duration({})", python_string(time_unit_str(time_unit)))
+        }
+        DataType::Time => "pl.Time".to_owned(),
+        DataType::Categorical(_, _) => "pl.Categorical".to_owned(),
+        DataType::List(inner) => format!("pl.List({})", polars_dtype(inner)),
+        DataType::Array(inner, width) => format!("pl.Array({}, {})", polars_dtype(inner), width),
+        DataType::Binary => "pl.Binary".to_owned(),
+        _ => "pl.String".to_owned(),
+    }
+}
+
+/// Maps a Polars data type to a pandas dtype, or None if pandas should infer it.
+fn pandas_dtype(data_type: &DataType) -> Option<&'static str> {
+    // Nullable extension dtypes are used so integer and boolean columns with missing values load.
+    match data_type {
+        DataType::Boolean => Some("\"boolean\""),
+        DataType::Int8 => Some("\"Int8\""),
+        DataType::Int16 => Some("\"Int16\""),
+        DataType::Int32 => Some("\"Int32\""),
+        DataType::Int64 => Some("\"Int64\""),
+        DataType::UInt8 => Some("\"UInt8\""),
+        DataType::UInt16 => Some("\"UInt16\""),
+        DataType::UInt32 => Some("\"UInt32\""),
+        DataType::UInt64 => Some("\"UInt64\""),
+        DataType::Float32 => Some("\"float32\""),
+        DataType::Float64 => Some("\"float64\""),
+        DataType::Categorical(_, _) | DataType::Enum(_, _) => Some("\"category\""),
+        DataType::String => Some("\"string\""),
+        _ => None,
+    }
+}
+
+fn time_unit_str(time_unit: &TimeUnit) -> &'static str {
+    match time_unit {
+        TimeUnit::Nanoseconds => "ns",
+        TimeUnit::Microseconds => "us",
+        TimeUnit::Milliseconds => "ms",
+    }
+}
+
+fn python_bool(value: bool) -> &'static str {
+    if value {
+        "True"
+    } else {
+        "False"
+    }
+}
+
+/// Formats a value as a double quoted Python string literal.
+fn python_string(value: &str) -> String {
+    let escaped: String = value
+        .chars()
+        .map(|c| match c {
+            '\\' => "\\\\".to_owned(),
+            '"' => "\\\"".to_owned(),
+            '\t' => "\\t".to_owned(),
+            '\n' => "\\n".to_owned(),
+            c => c.to_string(),
+        })
+        .collect();
+    format!("\"{}\"", escaped)
+}
+
+fn python_list(values: &[&str]) -> String {
+    let items: Vec<String> = values.iter().map(|value| python_string(value)).collect();
+    format!("[{}]", items.join(", "))
+}
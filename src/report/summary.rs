@@ -0,0 +1,367 @@
+//! # Summary Module
+//!
+//! Handles condensing the analysis results into a small serializable summary of the run (dataset
+//! shape, per-column quality, and key alerts). The summary is the machine readable counterpart of
+//! the PDF report and is what gets sent to notification hooks.
+
+use crate::data::dictionary::ColumnTag;
+use crate::data::duplicates::{round_factor, ColumnRelation};
+use crate::data::provenance::Provenance;
+use crate::data::time_series::format_duration;
+use crate::prelude::DataInfo;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Missing value percentage above which a column is flagged in the alerts.
+pub const MISSING_ALERT_THRESHOLD: f64 = 20.0;
+
+/// Summary of a single column.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ColumnSummary {
+    /// The column name.
+    pub name: String,
+    /// The column data type.
+    pub data_type: String,
+    /// The number of missing values.
+    pub missing_count: u64,
+    /// The percentage of missing values.
+    pub missing_percentage: f64,
+    /// The number of distinct values, when computed for the column type.
+    pub n_unique: Option<u64>,
+    /// Quality score from 0 to 100, currently the percentage of non-missing values.
+    pub quality_score: f64,
+}
+
+/// Summary of a profiling run.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReportSummary {
+    /// Name of the dataset.
+    pub dataset: String,
+    /// Path the dataset was read from.
+    pub source_path: String,
+    /// Path of the generated report, if one was saved.
+    pub report_path: Option<String>,
+    /// When the summary was generated, as an RFC 3339 timestamp.
+    pub generated_at: String,
+    /// The fingerprint of the input and the options the run used. Empty in summaries saved
+    /// before it was recorded.
+    #[serde(default)]
+    pub provenance: Provenance,
+    /// The number of rows in the data.
+    pub n_rows: u64,
+    /// The number of columns in the data.
+    pub n_cols: u64,
+    /// The per-column summaries.
+    pub columns: Vec<ColumnSummary>,
+    /// Human readable alerts for data quality issues worth a look.
+    pub alerts: Vec<String>,
+}
+
+impl ReportSummary {
+    /// Constructor for the ReportSummary struct.
+    ///
+    /// ### Parameters
+    ///
+    /// - `data_info`: The dataset information and analysis results.
+    /// - `report_path`: The path the report was saved to, if any.
+    ///
+    /// ### Returns
+    ///
+    /// - `ReportSummary`: The new ReportSummary.
+    pub fn new(data_info: &DataInfo, report_path: Option<&Path>) -> Self {
+        let descriptive_analysis = &data_info.descriptive_analysis;
+        let columns: Vec<ColumnSummary> = data_info
+            .column_types
+            .iter()
+            .map(|(name, data_type)| {
+                let (missing_count, missing_percentage) = data_info
+                    .missing_value_analysis
+                    .column_missing_values
+                    .get(name)
+                    .copied()
+                    .unwrap_or_default();
+                ColumnSummary {
+                    name: name.to_owned(),
+                    data_type: data_type.to_string(),
+                    missing_count,
+                    missing_percentage,
+                    n_unique: descriptive_analysis
+                        .distinct_stats
+                        .get(name)
+                        .map(|stats| stats.n_unique),
+                    quality_score: (100.0 - missing_percentage).max(0.0),
+                }
+            })
+            .collect();
+
+        let mut alerts = Vec::new();
+        if let Some(row_limit) = &data_info.row_limit {
+            alerts.push(format!(
+                "Partial report: only {} of the dataset were analyzed.",
+                row_limit
+            ));
+        }
+        if let Some(row_filter) = &data_info.row_filter {
+            alerts.push(format!(
+                "Filtered report: only the rows matching `{}` were analyzed.",
+                row_filter
+            ));
+        }
+        for column in &columns {
+            if column.missing_percentage >= 100.0 {
+                alerts.push(format!("Column '{}' is entirely missing.", column.name));
+            } else if column.missing_percentage > MISSING_ALERT_THRESHOLD {
+                alerts.push(format!(
+                    "Column '{}' has {:.2}% missing values.",
+                    column.name, column.missing_percentage
+                ));
+            }
+            if column.n_unique == Some(1) {
+                alerts.push(format!(
+                    "Column '{}' is constant (a single distinct value).",
+                    column.name
+                ));
+            }
+        }
+        let duplicate_analysis = &data_info.duplicate_analysis;
+        if duplicate_analysis.n_duplicate_rows > 0 {
+            alerts.push(format!(
+                "Dataset has {} full-row duplicate rows ({:.2}%).",
+                duplicate_analysis.n_duplicate_rows,
+                duplicate_analysis.duplicate_percentage()
+            ));
+        }
+        for duplicate in &duplicate_analysis.duplicate_columns {
+            alerts.push(match duplicate.relation {
+                ColumnRelation::Scaled(factor) => format!(
+                    "Column '{}' looks derived from '{}' ({} times its values).",
+                    duplicate.column,
+                    duplicate.source,
+                    round_factor(factor)
+                ),
+                _ => format!(
+                    "Column '{}' duplicates '{}' ({}).",
+                    duplicate.column, duplicate.source, duplicate.relation
+                ),
+            });
+        }
+        if let Some(key_violations) = &duplicate_analysis.key_violations {
+            let key = key_violations.columns.join(", ");
+            if key_violations.n_duplicate_keys > 0 {
+                alerts.push(format!(
+                    "Key ({}) has {} duplicated values across {} rows.",
+                    key, key_violations.n_duplicate_keys, key_violations.n_violating_rows
+                ));
+            }
+            if key_violations.n_null_keys > 0 {
+                alerts.push(format!(
+                    "Key ({}) is missing in {} rows.",
+                    key, key_violations.n_null_keys
+                ));
+            }
+        }
+        if let Some(foreign_value_analysis) = &data_info.foreign_value_analysis {
+            for (column, foreign_values) in &foreign_value_analysis.columns {
+                if foreign_values.n_foreign > 0 {
+                    alerts.push(format!(
+                        "Column '{}' has {} values ({:.2}%) missing from its reference list.",
+                        column,
+                        foreign_values.n_foreign,
+                        foreign_values.foreign_percentage()
+                    ));
+                }
+            }
+        }
+        for pair in data_info.correlation_analysis.high_pairs() {
+            alerts.push(format!(
+                "Columns '{}' and '{}' are highly correlated (Pearson {:.2}, Spearman {:.2}).",
+                pair.first, pair.second, pair.pearson, pair.spearman
+            ));
+        }
+        let collinearity_analysis = &data_info.collinearity_analysis;
+        for (name, vif) in collinearity_analysis.high_vif() {
+            alerts.push(format!(
+                "Column '{}' has a variance inflation factor of {:.2} (above {}).",
+                name, vif, collinearity_analysis.threshold
+            ));
+        }
+        for (name, correlation) in data_info.row_order_analysis.ordered() {
+            alerts.push(format!(
+                "Column '{}' is correlated with the row order (Spearman {:.2}); shuffle the rows \
+                 before splitting the data.",
+                name, correlation
+            ));
+        }
+        if let Some(warning) = data_info
+            .target_analysis
+            .as_ref()
+            .and_then(|target| target.class_distribution.as_ref())
+            .and_then(|distribution| distribution.warning())
+        {
+            alerts.push(warning);
+        }
+        if let Some(partition_analysis) = &data_info.partition_analysis {
+            for partition in partition_analysis.anomalous_partitions() {
+                alerts.push(format!(
+                    "Partition '{}' is anomalous: {}.",
+                    partition.path.display(),
+                    partition.anomalies.join("; ")
+                ));
+            }
+        }
+        if let Some(drift_analysis) = &data_info.drift_analysis {
+            for (name, drift) in drift_analysis.drifted() {
+                alerts.push(format!(
+                    "Column '{}' has a {} drift from the baseline (PSI {:.2}).",
+                    name, drift.level, drift.psi
+                ));
+            }
+            for name in &drift_analysis.missing_columns {
+                alerts.push(format!(
+                    "Column '{}' is in the baseline profile but not in the data.",
+                    name
+                ));
+            }
+        }
+        if let Some(freshness_analysis) = &data_info.freshness_analysis {
+            if let Some(staleness) = freshness_analysis
+                .staleness()
+                .filter(|_| freshness_analysis.is_stale())
+            {
+                alerts.push(format!(
+                    "Newest record in '{}' is {} old, above the freshness threshold of {}.",
+                    freshness_analysis.column,
+                    format_duration(staleness),
+                    format_duration(freshness_analysis.threshold)
+                ));
+            }
+        }
+        for (name, stats) in &data_info.descriptive_analysis.high_cardinality_stats {
+            // Columns tagged as identifiers are expected to be unique.
+            if data_info.has_tag(name, ColumnTag::Id) {
+                continue;
+            }
+            alerts.push(format!(
+                "Column '{}' is ID-like with {:.2}% unique values.",
+                name,
+                stats.uniqueness_ratio * 100.0
+            ));
+        }
+        for (name, profile) in &data_info.text_analysis.columns {
+            if let Some(warning) = profile.format_warning() {
+                alerts.push(format!(
+                    "Column '{}' has inconsistent formats: {}",
+                    name, warning
+                ));
+            }
+            if let Some(warning) = profile.blank_warning() {
+                alerts.push(format!("Column '{}': {}", name, warning));
+            }
+            if let Some(warning) = profile
+                .validity
+                .as_ref()
+                .and_then(|validity| validity.warning())
+            {
+                alerts.push(format!("Column '{}': {}", name, warning));
+            }
+            if let Some(warning) = profile.json.as_ref().and_then(|json| json.warning()) {
+                alerts.push(format!("Column '{}': {}", name, warning));
+            }
+            if let Some(warning) = profile
+                .numeric
+                .as_ref()
+                .and_then(|numeric| numeric.warning())
+            {
+                alerts.push(format!("Column '{}': {}", name, warning));
+            }
+            if let Some(warning) = profile.dates.as_ref().and_then(|dates| dates.warning()) {
+                alerts.push(format!("Column '{}': {}", name, warning));
+            }
+            if let Some(variants) = &profile.variants {
+                alerts.push(format!("Column '{}': {}", name, variants.warning()));
+            }
+        }
+        if let Some(time_series) = &data_info.time_series_analysis {
+            if !time_series.gaps.is_empty() {
+                alerts.push(format!(
+                    "Time index '{}' has {} gap(s) with an estimated {} missing period(s).",
+                    time_series.index,
+                    time_series.gaps.len(),
+                    time_series.missing_periods()
+                ));
+            }
+            if !time_series.duplicate_timestamps.is_empty() {
+                alerts.push(format!(
+                    "Time index '{}' has {} duplicated timestamp(s).",
+                    time_series.index,
+                    time_series.duplicate_timestamps.len()
+                ));
+            }
+        }
+        for (name, stats) in &descriptive_analysis.nested_stats {
+            if stats.count > 0 && stats.empty_count == stats.count {
+                alerts.push(format!("Column '{}' only contains empty lists.", name));
+            }
+        }
+        if let Some(timeout) = data_info.analysis_timeout {
+            for analysis in &data_info.timed_out_analyses {
+                alerts.push(format!(
+                    "{} was skipped (timeout) after running over its {} time budget.",
+                    analysis, timeout
+                ));
+            }
+        }
+        for failure in &data_info.failed_analyses {
+            alerts.push(match &failure.column {
+                Some(column) => format!(
+                    "{} of column '{}' is unavailable, the analysis failed: {}",
+                    failure.analysis, column, failure.error
+                ),
+                None => format!(
+                    "{} is unavailable, the analysis failed: {}",
+                    failure.analysis, failure.error
+                ),
+            });
+        }
+
+        Self {
+            dataset: data_info.data_title.clone(),
+            source_path: data_info.source_path.to_string_lossy().into_owned(),
+            report_path: report_path.map(|path| path.to_string_lossy().into_owned()),
+            generated_at: Utc::now().to_rfc3339(),
+            provenance: data_info.provenance.clone(),
+            n_rows: descriptive_analysis.n_rows,
+            n_cols: descriptive_analysis.n_cols,
+            columns,
+            alerts,
+        }
+    }
+
+    /// Gets the percentage of missing cells across the whole dataset.
+    pub fn missing_percentage(&self) -> f64 {
+        mean(self.columns.iter().map(|column| column.missing_percentage))
+    }
+
+    /// Gets the mean column quality score.
+    pub fn quality_score(&self) -> f64 {
+        mean(self.columns.iter().map(|column| column.quality_score))
+    }
+
+    /// Serializes the summary to pretty printed JSON.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+}
+
+/// Mean of the values, or 0 if there are none.
+fn mean(values: impl Iterator<Item = f64>) -> f64 {
+    let (sum, count) = values.fold((0.0, 0usize), |(sum, count), value| {
+        (sum + value, count + 1)
+    });
+    if count == 0 {
+        0.0
+    } else {
+        sum / count as f64
+    }
+}
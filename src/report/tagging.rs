@@ -0,0 +1,826 @@
+//! # Tagging Module
+//!
+//! Handles the accessibility tagging of the PDF report, so screen readers can announce its
+//! headings, read its paragraphs and tables in order, and describe its plots. The report content
+//! is tagged in two steps:
+//!
+//! - While drawing, each text and image object is wrapped in marked content with a marked content
+//!   ID (MCID) unique to its page, and its structure role (heading, paragraph, table cell, or
+//!   figure) is recorded per page. Everything else (rules, page numbers, dotted lines, ...) is
+//!   marked as an artifact before saving.
+//! - After saving, the structure tree is appended to the file as an incremental update: a
+//!   `Document` structure element with the tagged content in reading order (table cells grouped
+//!   into their rows and tables), the parent tree mapping the marked content back to its
+//!   structure elements, and the catalog flagged as a tagged PDF in the report language.
+//!
+//! The same update adds the named destinations of the report (the page of each section and plot,
+//! by stable ID) to the catalog, so other tools can link to e.g. `report.pdf#duplicates`.
+//!
+//! Only PDFs with a classic cross-reference table (as written by Pdfium) can be tagged.
+
+use indexmap::IndexMap;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::Path;
+use thiserror::Error;
+
+/// The error types for the tagging module.
+#[derive(Error, Debug)]
+pub enum TaggingError {
+    /// Occurs when a PDF object needed to attach the structure tree can't be read.
+    #[error("Malformed PDF: {0}")]
+    Malformed(String),
+
+    /// Occurs when the number of pages of the saved PDF doesn't match the tagged pages.
+    #[error("Tagged {tagged} pages but the PDF has {found} pages")]
+    PageCount { tagged: usize, found: usize },
+}
+
+/// The structure role of a piece of tagged content.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TagRole {
+    /// A section header.
+    H1,
+    /// A sub-header, e.g. a feature name.
+    H2,
+    /// A paragraph, or any other line of text.
+    P,
+    /// A table header cell.
+    Th,
+    /// A table data cell.
+    Td,
+    /// An image, described by its alternative text.
+    Figure,
+}
+
+impl TagRole {
+    /// Gets the standard structure type name of the role, also used as the marked content tag.
+    pub fn name(&self) -> &'static str {
+        match self {
+            TagRole::H1 => "H1",
+            TagRole::H2 => "H2",
+            TagRole::P => "P",
+            TagRole::Th => "TH",
+            TagRole::Td => "TD",
+            TagRole::Figure => "Figure",
+        }
+    }
+}
+
+/// The position of a table cell, grouping it into its row and table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TableCell {
+    /// The index of the table in the document.
+    pub table: usize,
+    /// The index of the row in the table, the header row being row `0`.
+    pub row: usize,
+}
+
+/// A piece of tagged content: a marked text or image object. Its marked content ID is its index
+/// among the tagged content of its page.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaggedContent {
+    /// The structure role.
+    pub role: TagRole,
+    /// The alternative text of a figure.
+    pub alt: Option<String>,
+    /// The position of a table cell.
+    pub cell: Option<TableCell>,
+}
+
+/// Builds the alternative text of a plot from its file name, e.g. `Plot: age outliers` for
+/// `age_outliers.png`.
+///
+/// ### Parameters
+///
+/// - `path`: The path to the plot image.
+///
+/// ### Returns
+///
+/// - `String`: The alternative text.
+pub fn image_alt_text(path: &Path) -> String {
+    let name = path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().replace('_', " "))
+        .unwrap_or_default();
+    format!("Plot: {}", name.trim())
+}
+
+/// Appends the structure tree of the tagged content and the named destinations to a saved PDF, as
+/// an incremental update.
+///
+/// ### Parameters
+///
+/// - `pdf`: The bytes of the saved PDF, with its content marked.
+/// - `pages`: The tagged content of each page, in page order.
+/// - `destinations`: The page index of each named destination, keyed by names only made of
+///   `[a-z0-9-]`.
+/// - `language`: The language tag of the document text, e.g. `en-US`.
+///
+/// ### Returns
+///
+/// - `Result<Vec<u8>, TaggingError>`: The bytes of the tagged PDF or an error.
+pub fn add_structure_tree(
+    mut pdf: Vec<u8>,
+    pages: &[Vec<TaggedContent>],
+    destinations: &IndexMap<String, u32>,
+    language: &str,
+) -> Result<Vec<u8>, TaggingError> {
+    let startxref = find_startxref(&pdf)?;
+    let (mut offsets, trailer) = read_xref(&pdf, startxref)?;
+    // Objects of earlier updates are only listed in their own cross-reference section.
+    let mut previous = entry(&trailer, "/Prev")
+        .ok()
+        .and_then(|prev| prev.parse().ok());
+    while let Some(offset) = previous {
+        let (earlier, earlier_trailer) = read_xref(&pdf, offset)?;
+        for (number, offset) in earlier {
+            offsets.entry(number).or_insert(offset);
+        }
+        previous = entry(&earlier_trailer, "/Prev")
+            .ok()
+            .and_then(|prev| prev.parse().ok());
+    }
+    let object = |reference: (u32, u32)| -> Result<Vec<(String, String)>, TaggingError> {
+        let offset = offsets.get(&reference.0).ok_or_else(|| {
+            TaggingError::Malformed(format!("object {} isn't in the xref table", reference.0))
+        })?;
+        object_dictionary(&pdf, *offset)
+    };
+
+    let catalog_ref = entry_reference(&trailer, "/Root")?;
+    let size: u32 = entry(&trailer, "/Size")?
+        .parse()
+        .map_err(|_| TaggingError::Malformed("invalid trailer size".to_owned()))?;
+    let catalog = object(catalog_ref)?;
+    let mut page_refs = Vec::new();
+    let mut pending = vec![entry_reference(&catalog, "/Pages")?];
+    while let Some(reference) = pending.pop() {
+        let node = object(reference)?;
+        match entry(&node, "/Kids") {
+            Ok(kids) => pending.extend(parse_references(kids).into_iter().rev()),
+            Err(_) => page_refs.push((reference, node)),
+        }
+    }
+    if page_refs.len() != pages.len() {
+        return Err(TaggingError::PageCount {
+            tagged: pages.len(),
+            found: page_refs.len(),
+        });
+    }
+
+    let mut tree = StructureTree::new(size);
+    for (page, contents) in pages.iter().enumerate() {
+        for (mcid, content) in contents.iter().enumerate() {
+            tree.add(page, mcid, content);
+        }
+    }
+
+    if !pdf.ends_with(b"\n") {
+        pdf.push(b'\n');
+    }
+    let mut objects: BTreeMap<u32, (u32, String)> = BTreeMap::new();
+    let mut catalog_entries = without_keys(
+        &catalog,
+        &["/MarkInfo", "/StructTreeRoot", "/Lang", "/Dests"],
+    );
+    catalog_entries.push_str(&format!(
+        " /MarkInfo << /Marked true >> /StructTreeRoot {} 0 R /Lang {}",
+        tree.root,
+        text_string(language)
+    ));
+    if !destinations.is_empty() {
+        let dests = tree.allocate();
+        let mut entries = String::new();
+        for (name, page) in destinations {
+            let (number, generation) =
+                page_refs
+                    .get(*page as usize)
+                    .map(|(r, _)| *r)
+                    .ok_or_else(|| {
+                        TaggingError::Malformed(format!(
+                            "destination {} is past the last page",
+                            name
+                        ))
+                    })?;
+            write!(entries, " /{} [{} {} R /Fit]", name, number, generation).unwrap();
+        }
+        objects.insert(dests, (0, entries));
+        write!(catalog_entries, " /Dests {} 0 R", dests).unwrap();
+    }
+    objects.insert(catalog_ref.0, (catalog_ref.1, catalog_entries));
+    for (page, ((reference, dictionary), contents)) in page_refs.iter().zip(pages).enumerate() {
+        if contents.is_empty() {
+            continue;
+        }
+        let mut entries = without_keys(dictionary, &["/StructParents"]);
+        entries.push_str(&format!(" /StructParents {}", page));
+        objects.insert(reference.0, (reference.1, entries));
+    }
+    for (number, entries) in tree.objects(&page_refs.iter().map(|(r, _)| *r).collect::<Vec<_>>()) {
+        objects.insert(number, (0, entries));
+    }
+
+    let mut xref = String::from("xref\n");
+    let mut subsection: Vec<(u32, usize, u32)> = Vec::new();
+    let flush = |xref: &mut String, subsection: &mut Vec<(u32, usize, u32)>| {
+        if let Some((first, _, _)) = subsection.first() {
+            writeln!(xref, "{} {}", first, subsection.len()).unwrap();
+            for (_, offset, generation) in subsection.drain(..) {
+                write!(xref, "{:010} {:05} n\r\n", offset, generation).unwrap();
+            }
+        }
+    };
+    for (number, (generation, entries)) in &objects {
+        if subsection
+            .last()
+            .is_some_and(|(last, _, _)| last + 1 != *number)
+        {
+            flush(&mut xref, &mut subsection);
+        }
+        subsection.push((*number, pdf.len(), *generation));
+        pdf.extend_from_slice(
+            format!("{} {} obj\n<<{} >>\nendobj\n", number, generation, entries).as_bytes(),
+        );
+    }
+    flush(&mut xref, &mut subsection);
+
+    let xref_offset = pdf.len();
+    let mut trailer_entries = format!(
+        " /Size {} /Root {} {} R /Prev {}",
+        tree.next.max(size),
+        catalog_ref.0,
+        catalog_ref.1,
+        startxref
+    );
+    for key in ["/Info", "/ID"] {
+        if let Ok(value) = entry(&trailer, key) {
+            write!(trailer_entries, " {} {}", key, value).unwrap();
+        }
+    }
+    pdf.extend_from_slice(
+        format!(
+            "{}trailer\n<<{} >>\nstartxref\n{}\n%%EOF\n",
+            xref, trailer_entries, xref_offset
+        )
+        .as_bytes(),
+    );
+    Ok(pdf)
+}
+
+/// A structure element pointing at a piece of marked content.
+#[derive(Debug)]
+struct ContentElement {
+    /// The object number of the element.
+    number: u32,
+    /// The object number of the parent element.
+    parent: u32,
+    /// The index of the page of the marked content.
+    page: usize,
+    /// The marked content ID on the page.
+    mcid: usize,
+    /// The structure role.
+    role: TagRole,
+    /// The alternative text of a figure.
+    alt: Option<String>,
+}
+
+/// A table structure element, with the object numbers of its rows and their cells.
+#[derive(Debug)]
+struct TableElement {
+    /// The object number of the table.
+    number: u32,
+    /// The object number and cells of each row, by row index.
+    rows: IndexMap<usize, (u32, Vec<u32>)>,
+}
+
+/// The structure tree being built, with the object numbers of its elements allocated from the
+/// size of the original PDF.
+#[derive(Debug)]
+struct StructureTree {
+    /// The object number of the structure tree root.
+    root: u32,
+    /// The object number of the `Document` element.
+    document: u32,
+    /// The object number of the parent tree.
+    parent_tree: u32,
+    /// The next free object number.
+    next: u32,
+    /// The object numbers of the children of the `Document` element, in reading order.
+    children: Vec<u32>,
+    /// The elements of the marked content, in reading order.
+    contents: Vec<ContentElement>,
+    /// The tables, by table index.
+    tables: IndexMap<usize, TableElement>,
+}
+
+impl StructureTree {
+    /// Constructor for the StructureTree struct.
+    fn new(size: u32) -> Self {
+        Self {
+            root: size,
+            document: size + 1,
+            parent_tree: size + 2,
+            next: size + 3,
+            children: Vec::new(),
+            contents: Vec::new(),
+            tables: IndexMap::new(),
+        }
+    }
+
+    /// Allocates the next free object number.
+    fn allocate(&mut self) -> u32 {
+        self.next += 1;
+        self.next - 1
+    }
+
+    /// Adds the element of a piece of marked content, under its row and table for table cells.
+    fn add(&mut self, page: usize, mcid: usize, content: &TaggedContent) {
+        let number = self.allocate();
+        let parent = match content.cell {
+            Some(cell) => {
+                if !self.tables.contains_key(&cell.table) {
+                    let table = self.allocate();
+                    self.children.push(table);
+                    self.tables.insert(
+                        cell.table,
+                        TableElement {
+                            number: table,
+                            rows: IndexMap::new(),
+                        },
+                    );
+                }
+                if !self.tables[&cell.table].rows.contains_key(&cell.row) {
+                    let row = self.allocate();
+                    self.tables[&cell.table]
+                        .rows
+                        .insert(cell.row, (row, Vec::new()));
+                }
+                let (row, cells) = &mut self.tables[&cell.table].rows[&cell.row];
+                cells.push(number);
+                *row
+            }
+            None => {
+                self.children.push(number);
+                self.document
+            }
+        };
+        self.contents.push(ContentElement {
+            number,
+            parent,
+            page,
+            mcid,
+            role: content.role,
+            alt: content.alt.clone(),
+        });
+    }
+
+    /// Serializes the entries of the structure tree objects.
+    ///
+    /// ### Parameters
+    ///
+    /// - `page_refs`: The object number and generation of each page.
+    ///
+    /// ### Returns
+    ///
+    /// - `Vec<(u32, String)>`: The object numbers and dictionary entries of the objects.
+    fn objects(&self, page_refs: &[(u32, u32)]) -> Vec<(u32, String)> {
+        let mut objects = vec![
+            (
+                self.root,
+                format!(
+                    " /Type /StructTreeRoot /K {} 0 R /ParentTree {} 0 R /ParentTreeNextKey {}",
+                    self.document,
+                    self.parent_tree,
+                    page_refs.len()
+                ),
+            ),
+            (
+                self.document,
+                format!(
+                    " /Type /StructElem /S /Document /P {} 0 R /K {}",
+                    self.root,
+                    references(&self.children)
+                ),
+            ),
+        ];
+
+        let mut parent_tree: BTreeMap<usize, Vec<u32>> = BTreeMap::new();
+        for content in &self.contents {
+            let (page_number, page_generation) = page_refs[content.page];
+            let mut entries = format!(
+                " /Type /StructElem /S /{} /P {} 0 R /Pg {} {} R /K {}",
+                content.role.name(),
+                content.parent,
+                page_number,
+                page_generation,
+                content.mcid
+            );
+            if let Some(alt) = &content.alt {
+                write!(entries, " /Alt {}", text_string(alt)).unwrap();
+            }
+            objects.push((content.number, entries));
+            parent_tree
+                .entry(content.page)
+                .or_default()
+                .push(content.number);
+        }
+        for table in self.tables.values() {
+            let rows: Vec<u32> = table.rows.values().map(|(row, _)| *row).collect();
+            objects.push((
+                table.number,
+                format!(
+                    " /Type /StructElem /S /Table /P {} 0 R /K {}",
+                    self.document,
+                    references(&rows)
+                ),
+            ));
+            for (row, cells) in table.rows.values() {
+                objects.push((
+                    *row,
+                    format!(
+                        " /Type /StructElem /S /TR /P {} 0 R /K {}",
+                        table.number,
+                        references(cells)
+                    ),
+                ));
+            }
+        }
+
+        let nums: Vec<String> = parent_tree
+            .iter()
+            .map(|(page, elements)| format!("{} {}", page, references(elements)))
+            .collect();
+        objects.push((self.parent_tree, format!(" /Nums [{}]", nums.join(" "))));
+        objects
+    }
+}
+
+/// Formats an array of indirect references to objects of generation `0`.
+fn references(numbers: &[u32]) -> String {
+    let references: Vec<String> = numbers
+        .iter()
+        .map(|number| format!("{} 0 R", number))
+        .collect();
+    format!("[{}]", references.join(" "))
+}
+
+/// Formats a text string as a UTF-16BE hex string, so any text is encoded unambiguously.
+fn text_string(text: &str) -> String {
+    let mut hex = String::from("<FEFF");
+    for unit in text.encode_utf16() {
+        write!(hex, "{:04X}", unit).unwrap();
+    }
+    hex.push('>');
+    hex
+}
+
+/// Serializes dictionary entries, leaving out the given keys.
+fn without_keys(entries: &[(String, String)], keys: &[&str]) -> String {
+    entries
+        .iter()
+        .filter(|(key, _)| !keys.contains(&key.as_str()))
+        .map(|(key, value)| format!(" {} {}", key, value))
+        .collect()
+}
+
+/// Gets the raw value of a dictionary entry.
+fn entry<'e>(entries: &'e [(String, String)], key: &str) -> Result<&'e str, TaggingError> {
+    entries
+        .iter()
+        .find(|(name, _)| name == key)
+        .map(|(_, value)| value.as_str())
+        .ok_or_else(|| TaggingError::Malformed(format!("missing {} entry", key)))
+}
+
+/// Gets the object number and generation of a dictionary entry holding an indirect reference.
+fn entry_reference(entries: &[(String, String)], key: &str) -> Result<(u32, u32), TaggingError> {
+    parse_references(entry(entries, key)?)
+        .first()
+        .copied()
+        .ok_or_else(|| TaggingError::Malformed(format!("{} isn't a reference", key)))
+}
+
+/// Parses the indirect references (`number generation R`) of a value, e.g. a `Kids` array.
+fn parse_references(value: &str) -> Vec<(u32, u32)> {
+    let tokens: Vec<&str> = value
+        .split(|c: char| c.is_ascii_whitespace() || c == '[' || c == ']')
+        .filter(|token| !token.is_empty())
+        .collect();
+    tokens
+        .windows(3)
+        .filter(|window| window[2] == "R")
+        .filter_map(|window| Some((window[0].parse().ok()?, window[1].parse().ok()?)))
+        .collect()
+}
+
+/// Finds the offset of the last cross-reference section.
+fn find_startxref(pdf: &[u8]) -> Result<usize, TaggingError> {
+    let position = pdf
+        .windows(9)
+        .rposition(|window| window == b"startxref")
+        .ok_or_else(|| TaggingError::Malformed("missing startxref".to_owned()))?;
+    String::from_utf8_lossy(&pdf[position + 9..])
+        .split_ascii_whitespace()
+        .next()
+        .and_then(|offset| offset.parse().ok())
+        .ok_or_else(|| TaggingError::Malformed("invalid startxref".to_owned()))
+}
+
+/// The byte offset of each object in use, by object number.
+type XrefOffsets = BTreeMap<u32, usize>;
+
+/// Reads a cross-reference table and its trailer.
+fn read_xref(
+    pdf: &[u8],
+    offset: usize,
+) -> Result<(XrefOffsets, Vec<(String, String)>), TaggingError> {
+    if !pdf
+        .get(offset..)
+        .is_some_and(|rest| rest.starts_with(b"xref"))
+    {
+        return Err(TaggingError::Malformed(
+            "only cross-reference tables are supported".to_owned(),
+        ));
+    }
+    let trailer_position = pdf[offset..]
+        .windows(7)
+        .position(|window| window == b"trailer")
+        .map(|position| offset + position)
+        .ok_or_else(|| TaggingError::Malformed("missing trailer".to_owned()))?;
+    let table = String::from_utf8_lossy(&pdf[offset + 4..trailer_position]);
+    let tokens: Vec<&str> = table.split_ascii_whitespace().collect();
+    let mut offsets = BTreeMap::new();
+    let mut i = 0;
+    while i + 1 < tokens.len() {
+        let invalid = || TaggingError::Malformed("invalid xref subsection".to_owned());
+        let first: u32 = tokens[i].parse().map_err(|_| invalid())?;
+        let count: usize = tokens[i + 1].parse().map_err(|_| invalid())?;
+        i += 2;
+        for number in first..first + count as u32 {
+            let entry = tokens.get(i..i + 3).ok_or_else(invalid)?;
+            if entry[2] == "n" {
+                offsets.insert(number, entry[0].parse().map_err(|_| invalid())?);
+            }
+            i += 3;
+        }
+    }
+    let (trailer, _) = dictionary(pdf, skip_whitespace(pdf, trailer_position + 7))?;
+    Ok((offsets, trailer))
+}
+
+/// Reads the dictionary of the indirect object at an offset.
+fn object_dictionary(pdf: &[u8], offset: usize) -> Result<Vec<(String, String)>, TaggingError> {
+    let start = pdf[offset..]
+        .windows(3)
+        .position(|window| window == b"obj")
+        .map(|position| skip_whitespace(pdf, offset + position + 3))
+        .ok_or_else(|| TaggingError::Malformed(format!("no object at offset {}", offset)))?;
+    Ok(dictionary(pdf, start)?.0)
+}
+
+/// Reads the top level entries of the dictionary starting at a position, with their raw values.
+///
+/// ### Parameters
+///
+/// - `pdf`: The bytes of the PDF.
+/// - `start`: The position of the opening `<<`.
+///
+/// ### Returns
+///
+/// - `Result<(Vec<(String, String)>, usize), TaggingError>`: The entries and the position after
+///   the closing `>>`, or an error.
+fn dictionary(pdf: &[u8], start: usize) -> Result<(Vec<(String, String)>, usize), TaggingError> {
+    let malformed = || TaggingError::Malformed(format!("invalid dictionary at offset {}", start));
+    if !pdf[start..].starts_with(b"<<") {
+        return Err(malformed());
+    }
+    let mut entries = Vec::new();
+    let mut position = start + 2;
+    loop {
+        position = skip_whitespace(pdf, position);
+        if pdf[position..].starts_with(b">>") {
+            return Ok((entries, position + 2));
+        }
+        if pdf.get(position) != Some(&b'/') {
+            return Err(malformed());
+        }
+        let key_end = token_end(pdf, position + 1);
+        let value_start = skip_whitespace(pdf, key_end);
+        let value_end = value_end(pdf, value_start).ok_or_else(malformed)?;
+        entries.push((
+            String::from_utf8_lossy(&pdf[position..key_end]).into_owned(),
+            String::from_utf8_lossy(&pdf[value_start..value_end]).into_owned(),
+        ));
+        position = value_end;
+    }
+}
+
+/// Finds the end of the value starting at a position, keeping indirect references whole.
+fn value_end(pdf: &[u8], start: usize) -> Option<usize> {
+    match *pdf.get(start)? {
+        b'/' => Some(token_end(pdf, start + 1)),
+        b'(' => {
+            let mut depth = 0;
+            let mut position = start;
+            loop {
+                match *pdf.get(position)? {
+                    b'\\' => position += 1,
+                    b'(' => depth += 1,
+                    b')' => {
+                        depth -= 1;
+                        if depth == 0 {
+                            return Some(position + 1);
+                        }
+                    }
+                    _ => {}
+                }
+                position += 1;
+            }
+        }
+        b'<' if pdf.get(start + 1) == Some(&b'<') => {
+            dictionary(pdf, start).ok().map(|(_, end)| end)
+        }
+        b'<' => pdf[start..]
+            .iter()
+            .position(|byte| *byte == b'>')
+            .map(|position| start + position + 1),
+        b'[' => {
+            let mut position = skip_whitespace(pdf, start + 1);
+            while *pdf.get(position)? != b']' {
+                position = skip_whitespace(pdf, value_end(pdf, position)?);
+            }
+            Some(position + 1)
+        }
+        _ => {
+            let end = token_end(pdf, start);
+            if end == start {
+                return None;
+            }
+            // An indirect reference is a number followed by a generation and `R`.
+            let generation_start = skip_whitespace(pdf, end);
+            let generation_end = token_end(pdf, generation_start);
+            let r_start = skip_whitespace(pdf, generation_end);
+            let is_number =
+                |bytes: &[u8]| !bytes.is_empty() && bytes.iter().all(u8::is_ascii_digit);
+            if is_number(&pdf[start..end])
+                && is_number(&pdf[generation_start..generation_end])
+                && token_end(pdf, r_start) == r_start + 1
+                && pdf.get(r_start) == Some(&b'R')
+            {
+                Some(r_start + 1)
+            } else {
+                Some(end)
+            }
+        }
+    }
+}
+
+/// Finds the end of a regular token, at the next whitespace or delimiter.
+fn token_end(pdf: &[u8], start: usize) -> usize {
+    pdf[start..]
+        .iter()
+        .position(|byte| byte.is_ascii_whitespace() || b"()<>[]{}/%".contains(byte))
+        .map_or(pdf.len(), |position| start + position)
+}
+
+/// Skips the whitespace and comments from a position.
+fn skip_whitespace(pdf: &[u8], mut position: usize) -> usize {
+    while let Some(byte) = pdf.get(position) {
+        if *byte == b'%' {
+            while pdf
+                .get(position)
+                .is_some_and(|byte| *byte != b'\n' && *byte != b'\r')
+            {
+                position += 1;
+            }
+        } else if byte.is_ascii_whitespace() || *byte == 0 {
+            position += 1;
+        } else {
+            break;
+        }
+    }
+    position
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A one page PDF with a classic cross-reference table, as written by Pdfium.
+    const MINIMAL_PDF: &[u8] = include_bytes!("../../tests/fixtures/minimal.pdf");
+
+    /// Finds the position of the first occurrence of a byte string in the fixture.
+    fn position(needle: &[u8]) -> usize {
+        MINIMAL_PDF
+            .windows(needle.len())
+            .position(|window| window == needle)
+            .unwrap()
+    }
+
+    #[test]
+    fn reads_the_cross_reference_table() {
+        let startxref = find_startxref(MINIMAL_PDF).unwrap();
+        assert_eq!(startxref, position(b"xref"));
+
+        let (offsets, trailer) = read_xref(MINIMAL_PDF, startxref).unwrap();
+        // The free head of the list (object 0) isn't in use.
+        assert_eq!(
+            offsets.keys().copied().collect::<Vec<_>>(),
+            [1, 2, 3, 4, 5, 6]
+        );
+        for (number, offset) in &offsets {
+            assert!(MINIMAL_PDF[*offset..].starts_with(format!("{} 0 obj", number).as_bytes()));
+        }
+        assert_eq!(entry(&trailer, "/Size").unwrap(), "7");
+        assert_eq!(entry_reference(&trailer, "/Root").unwrap(), (1, 0));
+        assert_eq!(entry_reference(&trailer, "/Info").unwrap(), (6, 0));
+    }
+
+    #[test]
+    fn rejects_an_offset_without_a_cross_reference_table() {
+        assert!(matches!(
+            read_xref(MINIMAL_PDF, position(b"1 0 obj")),
+            Err(TaggingError::Malformed(_))
+        ));
+    }
+
+    #[test]
+    fn reads_nested_dictionaries_and_references() {
+        let (entries, end) = dictionary(MINIMAL_PDF, position(b"<< /Type /Page ")).unwrap();
+        assert_eq!(
+            entries,
+            [
+                ("/Type", "/Page"),
+                ("/Parent", "2 0 R"),
+                ("/MediaBox", "[0 0 612 792]"),
+                ("/Contents", "4 0 R"),
+                ("/Resources", "<< /Font << /F1 5 0 R >> >>"),
+            ]
+            .map(|(key, value)| (key.to_owned(), value.to_owned()))
+        );
+        assert!(MINIMAL_PDF[end..].starts_with(b"\nendobj"));
+        assert!(dictionary(MINIMAL_PDF, position(b"/Type")).is_err());
+    }
+
+    #[test]
+    fn finds_the_end_of_each_value() {
+        let (entries, _) = dictionary(MINIMAL_PDF, position(b"<< /Title")).unwrap();
+        assert_eq!(
+            entries,
+            [
+                ("/Title", r"(Report \(draft\) of (nested) text)"),
+                ("/Producer", "<4C454144>"),
+                ("/CreationDate", "(D:20240131)"),
+            ]
+            .map(|(key, value)| (key.to_owned(), value.to_owned()))
+        );
+
+        let value_at = |needle: &[u8]| {
+            let start = position(needle);
+            value_end(MINIMAL_PDF, start).map(|end| &MINIMAL_PDF[start..end])
+        };
+        assert_eq!(value_at(b"612 792]"), Some(&b"612"[..]));
+        assert_eq!(value_at(b"2 0 R /MediaBox"), Some(&b"2 0 R"[..]));
+        assert_eq!(value_at(b"[3 0 R]"), Some(&b"[3 0 R]"[..]));
+        assert_eq!(value_at(b"/Helvetica"), Some(&b"/Helvetica"[..]));
+        // An unterminated string has no end.
+        assert_eq!(value_end(b"(unterminated", 0), None);
+    }
+
+    #[test]
+    fn appends_the_structure_tree_as_an_update() {
+        let pages = vec![vec![TaggedContent {
+            role: TagRole::P,
+            alt: None,
+            cell: None,
+        }]];
+        let destinations = IndexMap::from([("report".to_owned(), 0)]);
+        let pdf = add_structure_tree(MINIMAL_PDF.to_vec(), &pages, &destinations, "en-US").unwrap();
+        assert!(pdf.starts_with(MINIMAL_PDF));
+
+        let (offsets, trailer) = read_xref(&pdf, find_startxref(&pdf).unwrap()).unwrap();
+        assert_eq!(
+            entry(&trailer, "/Prev").unwrap(),
+            position(b"xref").to_string()
+        );
+        let catalog = object_dictionary(&pdf, offsets[&1]).unwrap();
+        assert_eq!(entry(&catalog, "/Lang").unwrap(), text_string("en-US"));
+        assert!(entry(&catalog, "/StructTreeRoot").is_ok());
+        assert!(entry(&catalog, "/Dests").is_ok());
+
+        let too_many_pages = vec![Vec::new(), Vec::new()];
+        assert!(matches!(
+            add_structure_tree(
+                MINIMAL_PDF.to_vec(),
+                &too_many_pages,
+                &destinations,
+                "en-US"
+            ),
+            Err(TaggingError::PageCount {
+                tagged: 2,
+                found: 1
+            })
+        ));
+    }
+}
@@ -0,0 +1,46 @@
+use leads::prelude::DescriptiveAnalysis;
+use polars::prelude::*;
+
+#[test]
+fn frame_without_numerical_features() {
+    let df = df!(
+        "id" => [101i64, 102, 103],
+        "name" => ["a", "b", "c"],
+    )
+    .unwrap();
+    let schema = df.schema();
+    let lazy_df = df.lazy();
+
+    for id_columns in [
+        vec!["id".to_owned()],
+        vec!["id".to_owned(), "name".to_owned()],
+    ] {
+        let mut failed_analyses = Vec::new();
+        let analysis =
+            DescriptiveAnalysis::new(&lazy_df, &schema, &id_columns, &mut failed_analyses).unwrap();
+
+        assert_eq!(analysis.n_rows, 3);
+        assert_eq!(analysis.n_cols, 2);
+        assert!(analysis.feature_indices.is_empty());
+        assert!(analysis
+            .column_stats
+            .get_analysis_values(&analysis.feature_indices, &analysis.column_map)
+            .unwrap()
+            .is_empty());
+        assert!(analysis.high_cardinality_stats.contains_key("id"));
+    }
+}
+
+#[test]
+fn frame_of_strings() {
+    let df = df!("name" => ["a", "b", "c", "d"]).unwrap();
+    let schema = df.schema();
+
+    let mut failed_analyses = Vec::new();
+    let analysis =
+        DescriptiveAnalysis::new(&df.lazy(), &schema, &[], &mut failed_analyses).unwrap();
+
+    assert_eq!(analysis.n_rows, 4);
+    assert!(analysis.feature_indices.is_empty());
+    assert!(analysis.histograms.is_empty());
+}
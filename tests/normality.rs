@@ -0,0 +1,96 @@
+use leads::data::normality::{NormalityStats, NormalityTest};
+use std::f64::consts::PI;
+
+/// Checks that a value is within a relative tolerance of the reference value.
+fn assert_close(value: f64, reference: f64, tolerance: f64) {
+    assert!(
+        (value - reference).abs() <= tolerance * reference.abs(),
+        "{} is not within {} of {}",
+        value,
+        tolerance,
+        reference
+    );
+}
+
+/// Draws standard normal values with the Box-Muller transform of a linear congruential generator,
+/// so the values are the same on every run.
+fn normal_values(n: usize, seed: u64) -> Vec<f64> {
+    let mut state = seed;
+    let mut uniform = || {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        ((state >> 11) as f64 + 0.5) / (1u64 << 53) as f64
+    };
+    let mut values = Vec::with_capacity(n);
+    while values.len() < n {
+        let (u1, u2) = (uniform(), uniform());
+        let radius = (-2.0 * u1.ln()).sqrt();
+        let angle = 2.0 * PI * u2;
+        values.extend([radius * angle.cos(), radius * angle.sin()]);
+    }
+    values.truncate(n);
+    values
+}
+
+#[test]
+fn shapiro_wilk_of_three_values() {
+    // R: shapiro.test(c(1, 2, 4)) gives W = 0.96429, p-value = 0.6369.
+    let stats = NormalityStats::new(&[1.0, 2.0, 4.0], &[]).unwrap();
+
+    assert_eq!(stats.test, NormalityTest::ShapiroWilk);
+    assert_close(stats.statistic, 0.96429, 1e-5);
+    assert_close(stats.p_value, 0.6369, 1e-4);
+}
+
+#[test]
+fn shapiro_wilk_of_a_small_sample() {
+    // The weights of 11 men from Shapiro and Wilk (1965). R: shapiro.test gives W = 0.78881,
+    // p-value = 0.006704.
+    let weights = [
+        148.0, 154.0, 158.0, 160.0, 161.0, 162.0, 166.0, 170.0, 182.0, 195.0, 236.0,
+    ];
+    let stats = NormalityStats::new(&weights, &[]).unwrap();
+
+    assert_eq!(stats.test, NormalityTest::ShapiroWilk);
+    assert_close(stats.statistic, 0.78881, 1e-5);
+    assert_close(stats.p_value, 0.006704, 1e-3);
+    assert!(!stats.is_normal());
+}
+
+#[test]
+fn shapiro_wilk_of_a_larger_sample() {
+    // R: shapiro.test(mtcars$mpg) gives W = 0.94756, p-value = 0.1229.
+    let mpg = [
+        21.0, 21.0, 22.8, 21.4, 18.7, 18.1, 14.3, 24.4, 22.8, 19.2, 17.8, 16.4, 17.3, 15.2, 10.4,
+        10.4, 14.7, 32.4, 30.4, 33.9, 21.5, 15.5, 15.2, 13.3, 19.2, 27.3, 26.0, 30.4, 15.8, 19.7,
+        15.0, 21.4,
+    ];
+    let stats = NormalityStats::new(&mpg, &[]).unwrap();
+
+    assert_eq!(stats.test, NormalityTest::ShapiroWilk);
+    assert_close(stats.statistic, 0.94756, 1e-5);
+    assert_close(stats.p_value, 0.1229, 1e-3);
+    assert!(stats.is_normal());
+}
+
+#[test]
+fn dagostino_pearson_above_the_shapiro_wilk_limit() {
+    // The references are the K² and p-value of scipy.stats.normaltest, with its skewness and
+    // kurtosis z-scores evaluated in 50 digit arithmetic.
+    let values = normal_values(6000, 4);
+    let stats = NormalityStats::new(&values, &[]).unwrap();
+
+    assert_eq!(stats.test, NormalityTest::DAgostinoPearson);
+    assert_close(stats.statistic, 1.94018600075, 1e-8);
+    assert_close(stats.p_value, 0.379047784877, 1e-8);
+    assert!(stats.is_normal());
+
+    let skewed: Vec<f64> = values.iter().map(|x| x + 0.02 * x * x).collect();
+    let stats = NormalityStats::new(&skewed, &[]).unwrap();
+
+    assert_eq!(stats.test, NormalityTest::DAgostinoPearson);
+    assert_close(stats.statistic, 19.224882101, 1e-8);
+    assert_close(stats.p_value, 6.68913397088e-5, 1e-8);
+    assert!(!stats.is_normal());
+}